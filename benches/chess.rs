@@ -1,9 +1,10 @@
 //! Criterion benchmarks measure time of move generation and perft calculation.
 
-use std::fs;
-
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use pabi::chess::position::Position;
+use pabi::chess::random;
+
+const NUM_POSITIONS: usize = 10_000;
 
 fn generate_moves(positions: &[Position]) {
     for position in positions {
@@ -11,15 +12,15 @@ fn generate_moves(positions: &[Position]) {
     }
 }
 
+fn generate_pseudo_legal_moves(positions: &[Position]) {
+    for position in positions {
+        std::hint::black_box(position.generate_pseudo_legal_moves());
+    }
+}
+
+/// The same positions on every machine, see [`random::generate`].
 fn load_positions() -> Vec<Position> {
-    fs::read_to_string(concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/tests/data/positions.fen"
-    ))
-    .unwrap()
-    .lines()
-    .map(|line| Position::try_from(line).unwrap())
-    .collect()
+    random::generate(NUM_POSITIONS, 0)
 }
 
 fn bench_movegen(c: &mut Criterion) {
@@ -37,6 +38,16 @@ fn bench_movegen(c: &mut Criterion) {
             b.iter(|| generate_moves(positions));
         },
     );
+    group.bench_with_input(
+        BenchmarkId::new(
+            "movegen_pseudo_legal",
+            format!("{} arbitrary positions", positions.len()),
+        ),
+        &positions,
+        |b, positions| {
+            b.iter(|| generate_pseudo_legal_moves(positions));
+        },
+    );
     group.finish();
 }
 
@@ -99,6 +110,23 @@ fn bench_perft(c: &mut Criterion) {
                 });
             },
         );
+        // Filtering the illegal moves when making them instead of generating
+        // strictly legal moves.
+        group.bench_with_input(
+            BenchmarkId::new(
+                "perft_pseudo_legal",
+                format!("position {position}, depth {depth}, nodes {nodes}"),
+            ),
+            &depth,
+            |b, &depth| {
+                b.iter(|| {
+                    assert_eq!(
+                        pabi::chess::position::perft_pseudo_legal(&position, depth),
+                        nodes
+                    );
+                });
+            },
+        );
     }
     group.finish();
 }
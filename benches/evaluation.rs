@@ -0,0 +1,74 @@
+//! Criterion benchmarks compare the throughput of the evaluation backends.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use candle_core::{Device, Tensor};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use pabi::chess::position::Position;
+use pabi::chess::random;
+use pabi::evaluation::Backend;
+
+const NUM_POSITIONS: usize = 1000;
+
+/// The same positions on every machine, see [`random::generate`].
+fn load_positions() -> Vec<Position> {
+    random::generate(NUM_POSITIONS, 0)
+}
+
+/// Writes random weights in the network format: 768 input features, 256
+/// hidden neurons, 1 value output and 4096 policy outputs.
+fn random_weights() -> PathBuf {
+    let shapes = [
+        ("hidden.weight", vec![256, 768]),
+        ("hidden.bias", vec![256]),
+        ("value.weight", vec![1, 256]),
+        ("value.bias", vec![1]),
+        ("policy.weight", vec![4096, 256]),
+        ("policy.bias", vec![4096]),
+    ];
+    let tensors: HashMap<String, Tensor> = shapes
+        .into_iter()
+        .map(|(name, shape)| {
+            (
+                name.to_string(),
+                Tensor::randn(0f32, 0.05, shape, &Device::Cpu).unwrap(),
+            )
+        })
+        .collect();
+    let path = std::env::temp_dir().join("pabi-bench-network.safetensors");
+    candle_core::safetensors::save(&tensors, &path).unwrap();
+    path
+}
+
+fn bench_evaluation(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Evaluation");
+    let positions = load_positions();
+    let weights = random_weights();
+
+    group.throughput(Throughput::Elements(positions.len() as u64));
+    for backend in [Backend::Pesto, Backend::Candle, Backend::Quantized] {
+        let evaluator = backend.create(Some(&weights)).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new(
+                backend.to_string(),
+                format!("{} positions", positions.len()),
+            ),
+            &positions,
+            |b, positions| {
+                b.iter(|| std::hint::black_box(evaluator.evaluate(positions).unwrap()));
+            },
+        );
+    }
+    group.finish();
+    fs::remove_file(weights).unwrap();
+}
+
+criterion_group! {
+    name = evaluation;
+    config = Criterion::default().sample_size(10);
+    targets = bench_evaluation
+}
+
+criterion_main!(evaluation);
@@ -1,6 +1,11 @@
 //! Retrieves information about the version of the engine from Git and the build
 //! environment. This information is then written to a file in the output
 //! directory and can be accessed at runtime by the engine.
+//!
+//! Also generates the precomputed tables: attacks, rays, Zobrist keys, PeSTO
+//! and the KPK bitbase. The output only depends on the source (the random
+//! generators are seeded and nothing iterates over hash maps), so two builds
+//! from the same commit produce identical tables.
 
 fn generate_file(filename: &str, contents: &str) {
     let out_dir = std::env::var_os("OUT_DIR").unwrap();
@@ -8,42 +13,107 @@ fn generate_file(filename: &str, contents: &str) {
     std::fs::write(dest_path, contents).unwrap();
 }
 
-// TODO: Add AVX, SSE, potentially MKL and other Candle features support.
+// TODO: Add potentially MKL and other Candle features support.
 fn generate_build_info() {
-    let features = format!(
-        "{}bmi2",
-        if cfg!(target_feature = "bmi2") {
-            "+"
-        } else {
-            "-"
-        }
-    );
+    // Build scripts are compiled for the host, so the target features are
+    // only available through the environment.
+    let target_features = std::env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let features = ["bmi2", "avx2"]
+        .iter()
+        .map(|feature| {
+            let enabled = target_features
+                .split(',')
+                .any(|enabled| enabled == *feature);
+            format!("{}{feature}", if enabled { "+" } else { "-" })
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
     generate_file("features", &features);
 }
 
-type ZobristKey = u64;
+/// Deterministic [SplitMix64] generator for the Zobrist keys and the magic
+/// numbers. It is defined here instead of using `rand`, so that the generated
+/// tables only depend on the seeds and not on the versions of the
+/// dependencies or the host.
+///
+/// [SplitMix64]: https://prng.di.unimi.it/splitmix64.c
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Checksum of all generated chess tables, recomputed by
+/// `chess::verify_tables` at startup. The values are hashed in the order they
+/// are generated with FNV-1a over 64-bit words.
+struct Checksum(u64);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(0xCBF2_9CE4_8422_2325)
+    }
+
+    fn update(&mut self, values: &[u64]) {
+        for &value in values {
+            self.0 = (self.0 ^ value).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    }
+}
+
+fn bitboards(values: &[u64]) -> String {
+    let mut result = String::from("[");
+    for bits in values {
+        result.push_str(&format!("Bitboard::from_bits({bits:#018x}),"));
+    }
+    result.push(']');
+    result
+}
+
+/// Writes a table of integers and adds it to the checksum.
+fn generate_table<T: Copy + Into<u64> + std::fmt::Debug>(
+    checksum: &mut Checksum,
+    filename: &str,
+    values: &[T],
+) {
+    checksum.update(&values.iter().map(|&value| value.into()).collect::<Vec<_>>());
+    generate_file(filename, &format!("{values:?}"));
+}
+
+/// Writes a table of bitboards and adds it to the checksum.
+fn generate_bitboards(checksum: &mut Checksum, filename: &str, values: &[u64]) {
+    checksum.update(values);
+    generate_file(filename, &bitboards(values));
+}
 
-fn generate_zobrist_keys() {
+fn generate_zobrist_keys(checksum: &mut Checksum) {
     const NUM_COLORS: usize = 2;
     const NUM_PIECES: usize = 6;
     const NUM_SQUARES: usize = 64;
 
-    let mut rng = rand::thread_rng();
+    let mut rng = SplitMix64(0x2_0B15_7E5D);
 
-    let piece_keys: [ZobristKey; NUM_COLORS * NUM_PIECES * NUM_SQUARES] =
-        std::array::from_fn(|_| rand::Rng::r#gen(&mut rng));
-    generate_file("pieces_zobrist_keys", &format!("{piece_keys:?}"));
+    let piece_keys: [u64; NUM_COLORS * NUM_PIECES * NUM_SQUARES] =
+        std::array::from_fn(|_| rng.next());
+    generate_table(checksum, "pieces_zobrist_keys", &piece_keys);
 
-    let en_passant_keys: [ZobristKey; 8] = std::array::from_fn(|_| rand::Rng::r#gen(&mut rng));
-    generate_file("en_passant_zobrist_keys", &format!("{en_passant_keys:?}"));
+    let en_passant_keys: [u64; 8] = std::array::from_fn(|_| rng.next());
+    generate_table(checksum, "en_passant_zobrist_keys", &en_passant_keys);
 }
 
 // PeSTO tables with modified encoding for easier serialization.
 // Piece indices match the order of PieceKind, the planes match the order of
-// Piece.
+// Piece. The piece-square tables below are written from White's point of view
+// with rank 8 at the top (i.e. index 0 is A8), while the engine uses
+// Little-Endian Rank-File Mapping (index 0 is A1).
 
-const MIDDLEGAME_VALUE: [i32; 6] = [0, 1025, 477, 365, 337, 82];
-const ENDGAME_VALUE: [i32; 6] = [0, 936, 512, 297, 281, 94];
+const MIDDLEGAME_VALUE: [i32; 6] = [82, 337, 365, 477, 1025, 0];
+const ENDGAME_VALUE: [i32; 6] = [94, 281, 297, 512, 936, 0];
 
 #[rustfmt::skip]
 const MIDDLEGAME_PAWN_TABLE: [i32; 64] = [
@@ -194,8 +264,8 @@ fn populate_piece_values(
     output: &mut [[i32; 64]; 12],
 ) {
     for square in 0..64 {
-        output[piece_index][square] = phase_values[piece_index] + piece_values[square];
-        output[6 + piece_index][square] = phase_values[piece_index] + piece_values[flip(square)];
+        output[piece_index][square] = phase_values[piece_index] + piece_values[flip(square)];
+        output[6 + piece_index][square] = phase_values[piece_index] + piece_values[square];
     }
 }
 
@@ -204,12 +274,12 @@ fn generate_pesto_tables() {
     let mut endgame_table = [[0; 64]; 12];
 
     for (piece_index, (middlegame_piece_table, endgame_piece_table)) in [
-        (&MIDDLEGAME_KING_TABLE, &ENDGAME_KING_TABLE),
-        (&MIDDLEGAME_QUEEN_TABLE, &ENDGAME_QUEEN_TABLE),
-        (&MIDDLEGAME_ROOK_TABLE, &ENDGAME_ROOK_TABLE),
-        (&MIDDLEGAME_BISHOP_TABLE, &ENDGAME_BISHOP_TABLE),
-        (&MIDDLEGAME_KNIGHT_TABLE, &ENDGAME_KNIGHT_TABLE),
         (&MIDDLEGAME_PAWN_TABLE, &ENDGAME_PAWN_TABLE),
+        (&MIDDLEGAME_KNIGHT_TABLE, &ENDGAME_KNIGHT_TABLE),
+        (&MIDDLEGAME_BISHOP_TABLE, &ENDGAME_BISHOP_TABLE),
+        (&MIDDLEGAME_ROOK_TABLE, &ENDGAME_ROOK_TABLE),
+        (&MIDDLEGAME_QUEEN_TABLE, &ENDGAME_QUEEN_TABLE),
+        (&MIDDLEGAME_KING_TABLE, &ENDGAME_KING_TABLE),
     ]
     .iter()
     .enumerate()
@@ -232,9 +302,358 @@ fn generate_pesto_tables() {
     generate_file("pesto_endgame_table", &format!("{endgame_table:?}"));
 }
 
+// King and Pawn vs King bitbase: for each position with White pawn on files A
+// to D (the other files are mirrored), stores whether White wins. The result
+// is computed by retrograde analysis: the positions are classified iteratively
+// starting from the immediate wins (safe promotion) and draws (stalemate or
+// capture of the pawn).
+
+const KPK_SIZE: usize = 2 * 24 * 64 * 64;
+const KPK_INVALID: u8 = 0;
+const KPK_UNKNOWN: u8 = 1;
+const KPK_DRAW: u8 = 2;
+const KPK_WIN: u8 = 4;
+
+fn kpk_index(black_to_move: bool, black_king: u8, white_king: u8, pawn: u8) -> usize {
+    usize::from(white_king)
+        | usize::from(black_king) << 6
+        | usize::from(black_to_move) << 12
+        | usize::from(pawn % 8) << 13
+        | usize::from(6 - pawn / 8) << 15
+}
+
+fn kpk_distance(from: u8, to: u8) -> u8 {
+    (from % 8).abs_diff(to % 8).max((from / 8).abs_diff(to / 8))
+}
+
+fn kpk_king_moves(from: u8) -> impl Iterator<Item = u8> {
+    (0..64).filter(move |&to| kpk_distance(from, to) == 1)
+}
+
+fn kpk_pawn_attacks(pawn: u8, square: u8) -> bool {
+    square / 8 == pawn / 8 + 1 && (square % 8).abs_diff(pawn % 8) == 1
+}
+
+fn kpk_initial(black_to_move: bool, black_king: u8, white_king: u8, pawn: u8) -> u8 {
+    let push = pawn + 8;
+    if kpk_distance(white_king, black_king) <= 1
+        || white_king == pawn
+        || black_king == pawn
+        || (!black_to_move && kpk_pawn_attacks(pawn, black_king))
+    {
+        return KPK_INVALID;
+    }
+    // Promotion that the black king can not capture.
+    if !black_to_move
+        && pawn / 8 == 6
+        && white_king != push
+        && black_king != push
+        && (kpk_distance(black_king, push) > 1 || kpk_distance(white_king, push) == 1)
+    {
+        return KPK_WIN;
+    }
+    if black_to_move {
+        let attacked =
+            |square: u8| kpk_distance(white_king, square) <= 1 || kpk_pawn_attacks(pawn, square);
+        // Stalemate or undefended pawn.
+        if kpk_king_moves(black_king).all(attacked)
+            || (kpk_distance(black_king, pawn) == 1 && kpk_distance(white_king, pawn) > 1)
+        {
+            return KPK_DRAW;
+        }
+    }
+    KPK_UNKNOWN
+}
+
+fn kpk_classify(db: &[u8], black_to_move: bool, black_king: u8, white_king: u8, pawn: u8) -> u8 {
+    let (good, bad) = if black_to_move {
+        (KPK_DRAW, KPK_WIN)
+    } else {
+        (KPK_WIN, KPK_DRAW)
+    };
+    let mut result = KPK_INVALID;
+    if black_to_move {
+        for king in kpk_king_moves(black_king) {
+            result |= db[kpk_index(false, king, white_king, pawn)];
+        }
+    } else {
+        for king in kpk_king_moves(white_king) {
+            result |= db[kpk_index(true, black_king, king, pawn)];
+        }
+        let push = pawn + 8;
+        if pawn / 8 < 6 {
+            result |= db[kpk_index(true, black_king, white_king, push)];
+        }
+        if pawn / 8 == 1 && push != white_king && push != black_king {
+            result |= db[kpk_index(true, black_king, white_king, push + 8)];
+        }
+    }
+    if result & good != 0 {
+        good
+    } else if result & KPK_UNKNOWN != 0 {
+        KPK_UNKNOWN
+    } else {
+        bad
+    }
+}
+
+fn kpk_decode(index: usize) -> (bool, u8, u8, u8) {
+    let white_king = (index & 0x3F) as u8;
+    let black_king = ((index >> 6) & 0x3F) as u8;
+    let black_to_move = (index >> 12) & 1 == 1;
+    let file = ((index >> 13) & 3) as u8;
+    let rank = 6 - ((index >> 15) & 7) as u8;
+    (black_to_move, black_king, white_king, rank * 8 + file)
+}
+
+fn generate_kpk_bitbase() {
+    let mut db: Vec<u8> = (0..KPK_SIZE)
+        .map(|index| {
+            let (black_to_move, black_king, white_king, pawn) = kpk_decode(index);
+            kpk_initial(black_to_move, black_king, white_king, pawn)
+        })
+        .collect();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for index in 0..KPK_SIZE {
+            if db[index] != KPK_UNKNOWN {
+                continue;
+            }
+            let (black_to_move, black_king, white_king, pawn) = kpk_decode(index);
+            let result = kpk_classify(&db, black_to_move, black_king, white_king, pawn);
+            if result != KPK_UNKNOWN {
+                db[index] = result;
+                changed = true;
+            }
+        }
+    }
+    let mut bits = [0u64; KPK_SIZE / 64];
+    for (index, &result) in db.iter().enumerate() {
+        if result == KPK_WIN {
+            bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+    generate_file("kpk_bitbase", &format!("{bits:?}"));
+}
+
+const ROOK_DIRECTIONS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const BISHOP_DIRECTIONS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+const KNIGHT_DIRECTIONS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// Squares reachable from `square` in at most `limit` steps in each of the
+/// `directions`, stopping at the first occupied square.
+fn cast(square: u8, occupancy: u64, directions: &[(i8, i8)], limit: usize) -> u64 {
+    let mut attacks = 0;
+    for &(file_delta, rank_delta) in directions {
+        let (mut file, mut rank) = ((square % 8) as i8, (square / 8) as i8);
+        for _ in 0..limit {
+            file += file_delta;
+            rank += rank_delta;
+            if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+                break;
+            }
+            let target = 1u64 << (rank * 8 + file);
+            attacks |= target;
+            if occupancy & target != 0 {
+                break;
+            }
+        }
+    }
+    attacks
+}
+
+fn slider_attacks(square: u8, occupancy: u64, directions: &[(i8, i8); 4]) -> u64 {
+    cast(square, occupancy, directions, 8)
+}
+
+/// Squares from `from` (inclusive) to `to` (exclusive) if they are on the same
+/// line in one of the `directions`, empty otherwise.
+fn ray(from: u8, to: u8, directions: &[(i8, i8); 4]) -> u64 {
+    for &(file_delta, rank_delta) in directions {
+        let mut ray = 1 << from;
+        let (mut file, mut rank) = ((from % 8) as i8, (from / 8) as i8);
+        loop {
+            file += file_delta;
+            rank += rank_delta;
+            if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+                break;
+            }
+            let square = (rank * 8 + file) as u8;
+            if square == to {
+                return ray;
+            }
+            ray |= 1 << square;
+        }
+    }
+    0
+}
+
+/// Occupancy bits that affect the slider attacks: the board edges are
+/// excluded unless the piece is on them.
+fn relevant_occupancy(square: u8, directions: &[(i8, i8); 4]) -> u64 {
+    let (file, rank) = (square % 8, square / 8);
+    let mut edges = 0u64;
+    if file != 0 {
+        edges |= 0x0101_0101_0101_0101;
+    }
+    if file != 7 {
+        edges |= 0x8080_8080_8080_8080;
+    }
+    if rank != 0 {
+        edges |= 0xFF;
+    }
+    if rank != 7 {
+        edges |= 0xFF << 56;
+    }
+    slider_attacks(square, 0, directions) & !edges
+}
+
+/// Software PDEP: deposits the low bits of `index` into the set bits of
+/// `mask`, i.e. the inverse of PEXT.
+fn deposit(mut index: u64, mut mask: u64) -> u64 {
+    let mut result = 0;
+    while mask != 0 {
+        if index & 1 != 0 {
+            result |= mask & mask.wrapping_neg();
+        }
+        index >>= 1;
+        mask &= mask - 1;
+    }
+    result
+}
+
+/// Generates the leaper attacks and the rays, see `src/chess/attacks.rs`.
+fn generate_attack_tables(checksum: &mut Checksum) {
+    let queen_directions = [ROOK_DIRECTIONS, BISHOP_DIRECTIONS].concat();
+    let knight: Vec<u64> = (0..64)
+        .map(|square| cast(square, 0, &KNIGHT_DIRECTIONS, 1))
+        .collect();
+    let king: Vec<u64> = (0..64)
+        .map(|square| cast(square, 0, &queen_directions, 1))
+        .collect();
+    // Pawns never stand on the first and the last ranks.
+    let pawn = |square: u8, rank_delta: i8| {
+        if (1..7).contains(&(square / 8)) {
+            cast(square, 0, &[(-1, rank_delta), (1, rank_delta)], 1)
+        } else {
+            0
+        }
+    };
+    let white_pawn: Vec<u64> = (0..64).map(|square| pawn(square, 1)).collect();
+    let black_pawn: Vec<u64> = (0..64).map(|square| pawn(square, -1)).collect();
+    generate_bitboards(checksum, "knight_attacks", &knight);
+    generate_bitboards(checksum, "king_attacks", &king);
+    generate_bitboards(checksum, "white_pawn_attacks", &white_pawn);
+    generate_bitboards(checksum, "black_pawn_attacks", &black_pawn);
+
+    let mut rays = Vec::with_capacity(64 * 64);
+    let mut bishop_rays = Vec::with_capacity(64 * 64);
+    let mut rook_rays = Vec::with_capacity(64 * 64);
+    for from in 0..64 {
+        for to in 0..64 {
+            let bishop = ray(from, to, &BISHOP_DIRECTIONS);
+            let rook = ray(from, to, &ROOK_DIRECTIONS);
+            rays.push(bishop | rook);
+            bishop_rays.push(bishop);
+            rook_rays.push(rook);
+        }
+    }
+    generate_bitboards(checksum, "rays", &rays);
+    generate_bitboards(checksum, "bishop_rays", &bishop_rays);
+    generate_bitboards(checksum, "rook_rays", &rook_rays);
+}
+
+/// Finds a magic number that maps all subsets of the `mask` to distinct
+/// attack sets (or the same index for equal attacks) with the fixed shift, so
+/// that the table has the same size and offsets as the PEXT one.
+fn find_magic(rng: &mut SplitMix64, mask: u64, subsets: &[(u64, u64)]) -> u64 {
+    let bits = mask.count_ones();
+    let mut table: Vec<Option<u64>> = vec![None; 1 << bits];
+    loop {
+        // Sparse candidates are much more likely to be magic.
+        let magic = rng.next() & rng.next() & rng.next();
+        if (mask.wrapping_mul(magic) >> 56).count_ones() < 6 {
+            continue;
+        }
+        table.fill(None);
+        let found = subsets.iter().all(|&(subset, attacks)| {
+            let index = (subset.wrapping_mul(magic) >> (64 - bits)) as usize;
+            match table[index] {
+                None => {
+                    table[index] = Some(attacks);
+                    true
+                },
+                Some(existing) => existing == attacks,
+            }
+        });
+        if found {
+            return magic;
+        }
+    }
+}
+
+/// Generates the sliding piece attack tables for both PEXT and magic
+/// bitboards, see `src/chess/attacks.rs`. Both share the relevant occupancies
+/// and offsets. The magic search is seeded, so the output is the same for
+/// every build.
+fn generate_slider_tables(checksum: &mut Checksum) {
+    let mut rng = SplitMix64(0x5EED);
+    for (name, directions) in [("bishop", &BISHOP_DIRECTIONS), ("rook", &ROOK_DIRECTIONS)] {
+        let masks: [u64; 64] =
+            std::array::from_fn(|square| relevant_occupancy(square as u8, directions));
+        let mut offsets = [0u64; 64];
+        let mut pext_attacks = Vec::new();
+        let mut magics = [0u64; 64];
+        let shifts: [u32; 64] = std::array::from_fn(|square| 64 - masks[square].count_ones());
+        let mut magic_attacks = Vec::new();
+        for square in 0..64u8 {
+            let mask = masks[square as usize];
+            offsets[square as usize] = pext_attacks.len() as u64;
+            // Subsets of the mask in the PEXT order.
+            let subsets: Vec<(u64, u64)> = (0..1u64 << mask.count_ones())
+                .map(|index| {
+                    let occupancy = deposit(index, mask);
+                    (occupancy, slider_attacks(square, occupancy, directions))
+                })
+                .collect();
+            pext_attacks.extend(subsets.iter().map(|&(_, attacks)| attacks));
+
+            let magic = find_magic(&mut rng, mask, &subsets);
+            magics[square as usize] = magic;
+            let mut table = vec![0; subsets.len()];
+            for &(occupancy, attacks) in &subsets {
+                table[(occupancy.wrapping_mul(magic) >> shifts[square as usize]) as usize] =
+                    attacks;
+            }
+            magic_attacks.extend(table);
+        }
+        generate_table(checksum, &format!("{name}_relevant_occupancies"), &masks);
+        generate_table(checksum, &format!("{name}_attack_offsets"), &offsets);
+        generate_bitboards(checksum, &format!("{name}_attacks"), &pext_attacks);
+        generate_table(checksum, &format!("{name}_magics"), &magics);
+        generate_table(checksum, &format!("{name}_magic_shifts"), &shifts);
+        generate_bitboards(checksum, &format!("{name}_magic_attacks"), &magic_attacks);
+    }
+}
+
 fn main() -> shadow_rs::SdResult<()> {
-    generate_zobrist_keys();
+    let mut checksum = Checksum::new();
+    generate_zobrist_keys(&mut checksum);
+    generate_attack_tables(&mut checksum);
+    generate_slider_tables(&mut checksum);
+    generate_file("tables_checksum", &format!("{:#018x}", checksum.0));
     generate_pesto_tables();
+    generate_kpk_bitbase();
     generate_build_info();
     shadow_rs::new()
 }
@@ -0,0 +1,27 @@
+//! Counts the leaf nodes of the move generation tree, which is the standard
+//! way to verify move generators against the known results.
+//!
+//! ```shell
+//! cargo run --release --example perft -- 5 "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1"
+//! ```
+
+use pabi::chess::position::{self, Position};
+
+fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let depth = match args.next() {
+        Some(depth) => depth.parse()?,
+        None => 4,
+    };
+    let position = match args.next() {
+        Some(fen) => Position::from_fen(&fen)?,
+        None => Position::starting(),
+    };
+
+    // The number of nodes in the whole tree.
+    println!("perft({depth}) = {}", position::perft(&position, depth));
+    // Breakdown by the first move, useful for finding the bugs by comparing
+    // against another move generator.
+    println!("{}", position::divide(&position, depth));
+    Ok(())
+}
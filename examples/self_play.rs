@@ -0,0 +1,63 @@
+//! Plays a game of the engine against itself without a GUI and prints the
+//! board after each move.
+//!
+//! ```shell
+//! cargo run --release --example self_play -- [nodes per move]
+//! ```
+
+use std::sync::atomic::AtomicBool;
+
+use pabi::chess::game::Game;
+use pabi::chess::position::Position;
+use pabi::evaluation::Pesto;
+use pabi::search::{mcts, Limits};
+
+fn main() -> anyhow::Result<()> {
+    let nodes = match std::env::args().nth(1) {
+        Some(nodes) => nodes.parse()?,
+        None => 400,
+    };
+    let mut game = Game::new(Position::starting()).with_draw_claims(true);
+    // Positions since the last irreversible move, so that the search knows
+    // about the repetitions.
+    let mut history = Vec::new();
+    let stop = AtomicBool::new(false);
+
+    while game.outcome().is_none() {
+        let position = game.position().clone();
+        let limits = Limits {
+            nodes: Some(nodes),
+            history: history.clone(),
+            ..Limits::default()
+        };
+        let result = mcts::search(&position, &limits, &mcts::Config::default(), &Pesto, &stop)?;
+        let best_move = result
+            .best_move
+            .expect("the game is not over, so there are legal moves");
+
+        println!(
+            "{}{} {} (score {})",
+            position.fullmove_counter(),
+            if game.history().len() % 2 == 0 {
+                "."
+            } else {
+                "..."
+            },
+            position.to_san(&best_move),
+            result.score
+        );
+        game.make_move(&best_move, None)?;
+        println!("{:?}", game.position());
+
+        history.push(position.hash());
+        if game.position().halfmove_clock() == 0 {
+            history.clear();
+        }
+    }
+
+    let outcome = game.outcome().expect("the game is over");
+    let moves: Vec<_> = game.history().iter().map(|record| record.played).collect();
+    println!("{}", game.root().san_line(&moves));
+    println!("Result: {outcome} ({:?})", outcome.termination);
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Drives the engine through the UCI protocol from the same process, the way a
+//! GUI or a tournament manager would talk to it over stdin and stdout.
+//!
+//! ```shell
+//! cargo run --release --example uci_client
+//! ```
+
+use std::io::{BufReader, Read, Write};
+use std::sync::mpsc;
+use std::thread;
+
+use pabi::Engine;
+
+/// Blocks until the client sends the next command, like stdin. The stream is
+/// closed when the client drops the sender.
+struct CommandStream {
+    commands: mpsc::Receiver<String>,
+    pending: Vec<u8>,
+}
+
+impl Read for CommandStream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pending.is_empty() {
+            match self.commands.recv() {
+                Ok(command) => self.pending = format!("{command}\n").into_bytes(),
+                Err(_) => return Ok(0),
+            }
+        }
+        let size = buf.len().min(self.pending.len());
+        buf[..size].copy_from_slice(&self.pending[..size]);
+        let _ = self.pending.drain(..size);
+        Ok(size)
+    }
+}
+
+/// Sends each line of the engine output to the client.
+struct ResponseStream {
+    responses: mpsc::Sender<String>,
+    buffer: Vec<u8>,
+}
+
+impl Write for ResponseStream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        while let Some(end) = self.buffer.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            // The client might have stopped listening already.
+            let _ = self
+                .responses
+                .send(String::from_utf8_lossy(&line[..end]).into_owned());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let (commands, receiver) = mpsc::channel();
+    let (sender, responses) = mpsc::channel();
+    let engine = thread::spawn(move || {
+        let mut input = BufReader::new(CommandStream {
+            commands: receiver,
+            pending: Vec::new(),
+        });
+        let output = ResponseStream {
+            responses: sender,
+            buffer: Vec::new(),
+        };
+        Engine::new(&mut input, output).uci_loop()
+    });
+
+    // Sends the command and prints the responses until the expected one.
+    let exchange = |command: &str, expected: &str| -> anyhow::Result<String> {
+        println!("> {command}");
+        commands.send(command.to_string())?;
+        loop {
+            let response = responses.recv()?;
+            println!("< {response}");
+            if response.starts_with(expected) {
+                return Ok(response);
+            }
+        }
+    };
+
+    let _ = exchange("uci", "uciok")?;
+    let _ = exchange("isready", "readyok")?;
+    commands.send("position startpos moves e2e4 e7e5".to_string())?;
+    let response = exchange("go nodes 2000", "bestmove")?;
+    let best_move = response.split_whitespace().nth(1).unwrap_or_default();
+    println!("The engine plays {best_move}");
+
+    commands.send("quit".to_string())?;
+    engine.join().expect("engine thread should not panic")?;
+    Ok(())
+}
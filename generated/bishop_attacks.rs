@@ -1,5250 +0,0 @@
-[
-    Bitboard::from_bits(0x8040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0080_4020_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0020_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_4020_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0020_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_1008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0008_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_8040_2011_0a00),
-    Bitboard::from_bits(0x0000_8040_2010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_0011_0a00),
-    Bitboard::from_bits(0x0000_0000_0010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_2011_0a00),
-    Bitboard::from_bits(0x0000_0000_2010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_0011_0a00),
-    Bitboard::from_bits(0x0000_0000_0010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0040_2011_0a00),
-    Bitboard::from_bits(0x0000_0040_2010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_0011_0a00),
-    Bitboard::from_bits(0x0000_0000_0010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_2011_0a00),
-    Bitboard::from_bits(0x0000_0000_2010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_0011_0a00),
-    Bitboard::from_bits(0x0000_0000_0010_0a00),
-    Bitboard::from_bits(0x0000_0000_0001_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0080_4122_1400),
-    Bitboard::from_bits(0x0000_0080_4020_1400),
-    Bitboard::from_bits(0x0000_0000_0102_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0080_4022_1400),
-    Bitboard::from_bits(0x0000_0080_4020_1400),
-    Bitboard::from_bits(0x0000_0000_0002_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_0122_1400),
-    Bitboard::from_bits(0x0000_0000_0020_1400),
-    Bitboard::from_bits(0x0000_0000_0102_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_0022_1400),
-    Bitboard::from_bits(0x0000_0000_0020_1400),
-    Bitboard::from_bits(0x0000_0000_0002_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_4122_1400),
-    Bitboard::from_bits(0x0000_0000_4020_1400),
-    Bitboard::from_bits(0x0000_0000_0102_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_4022_1400),
-    Bitboard::from_bits(0x0000_0000_4020_1400),
-    Bitboard::from_bits(0x0000_0000_0002_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_0122_1400),
-    Bitboard::from_bits(0x0000_0000_0020_1400),
-    Bitboard::from_bits(0x0000_0000_0102_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_0022_1400),
-    Bitboard::from_bits(0x0000_0000_0020_1400),
-    Bitboard::from_bits(0x0000_0000_0002_1400),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0001_8244_2800),
-    Bitboard::from_bits(0x0000_0000_8040_2800),
-    Bitboard::from_bits(0x0000_0001_0204_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_8044_2800),
-    Bitboard::from_bits(0x0000_0000_8040_2800),
-    Bitboard::from_bits(0x0000_0000_0004_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0001_0244_2800),
-    Bitboard::from_bits(0x0000_0000_0040_2800),
-    Bitboard::from_bits(0x0000_0001_0204_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_0044_2800),
-    Bitboard::from_bits(0x0000_0000_0040_2800),
-    Bitboard::from_bits(0x0000_0000_0004_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_8244_2800),
-    Bitboard::from_bits(0x0000_0000_8040_2800),
-    Bitboard::from_bits(0x0000_0000_0204_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_8044_2800),
-    Bitboard::from_bits(0x0000_0000_8040_2800),
-    Bitboard::from_bits(0x0000_0000_0004_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_0244_2800),
-    Bitboard::from_bits(0x0000_0000_0040_2800),
-    Bitboard::from_bits(0x0000_0000_0204_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_0044_2800),
-    Bitboard::from_bits(0x0000_0000_0040_2800),
-    Bitboard::from_bits(0x0000_0000_0004_2800),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0102_0488_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0102_0408_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0088_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0008_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0488_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0408_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0088_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0008_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0002_0488_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0002_0408_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0088_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0008_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0488_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0408_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0088_5000),
-    Bitboard::from_bits(0x0000_0000_0080_5000),
-    Bitboard::from_bits(0x0000_0000_0008_5000),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0001_0204_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0004_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0204_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0004_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0810_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0010_a000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0102_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x4020_1008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_1008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0020_1008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_1008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0008_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x0000_0000_0402_0002),
-    Bitboard::from_bits(0x0000_0000_0002_0002),
-    Bitboard::from_bits(0x8040_2010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_2010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0040_2010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_2010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0010_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0000_0000_0805_0005),
-    Bitboard::from_bits(0x0000_0000_0005_0005),
-    Bitboard::from_bits(0x0080_4020_110a_000a),
-    Bitboard::from_bits(0x0080_4020_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0000_110a_000a),
-    Bitboard::from_bits(0x0000_0000_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0020_110a_000a),
-    Bitboard::from_bits(0x0000_0020_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0000_110a_000a),
-    Bitboard::from_bits(0x0000_0000_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_4020_110a_000a),
-    Bitboard::from_bits(0x0000_4020_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0000_110a_000a),
-    Bitboard::from_bits(0x0000_0000_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0020_110a_000a),
-    Bitboard::from_bits(0x0000_0020_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_0000_110a_000a),
-    Bitboard::from_bits(0x0000_0000_100a_000a),
-    Bitboard::from_bits(0x0000_0000_010a_000a),
-    Bitboard::from_bits(0x0000_0000_000a_000a),
-    Bitboard::from_bits(0x0000_8041_2214_0014),
-    Bitboard::from_bits(0x0000_8040_2014_0014),
-    Bitboard::from_bits(0x0000_0001_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_8040_2214_0014),
-    Bitboard::from_bits(0x0000_8040_2014_0014),
-    Bitboard::from_bits(0x0000_0000_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0001_2214_0014),
-    Bitboard::from_bits(0x0000_0000_2014_0014),
-    Bitboard::from_bits(0x0000_0001_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0000_2214_0014),
-    Bitboard::from_bits(0x0000_0000_2014_0014),
-    Bitboard::from_bits(0x0000_0000_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0041_2214_0014),
-    Bitboard::from_bits(0x0000_0040_2014_0014),
-    Bitboard::from_bits(0x0000_0001_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0040_2214_0014),
-    Bitboard::from_bits(0x0000_0040_2014_0014),
-    Bitboard::from_bits(0x0000_0000_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0001_2214_0014),
-    Bitboard::from_bits(0x0000_0000_2014_0014),
-    Bitboard::from_bits(0x0000_0001_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0000_2214_0014),
-    Bitboard::from_bits(0x0000_0000_2014_0014),
-    Bitboard::from_bits(0x0000_0000_0214_0014),
-    Bitboard::from_bits(0x0000_0000_0014_0014),
-    Bitboard::from_bits(0x0000_0182_4428_0028),
-    Bitboard::from_bits(0x0000_0080_4028_0028),
-    Bitboard::from_bits(0x0000_0102_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0080_4428_0028),
-    Bitboard::from_bits(0x0000_0080_4028_0028),
-    Bitboard::from_bits(0x0000_0000_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0102_4428_0028),
-    Bitboard::from_bits(0x0000_0000_4028_0028),
-    Bitboard::from_bits(0x0000_0102_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0000_4428_0028),
-    Bitboard::from_bits(0x0000_0000_4028_0028),
-    Bitboard::from_bits(0x0000_0000_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0082_4428_0028),
-    Bitboard::from_bits(0x0000_0080_4028_0028),
-    Bitboard::from_bits(0x0000_0002_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0080_4428_0028),
-    Bitboard::from_bits(0x0000_0080_4028_0028),
-    Bitboard::from_bits(0x0000_0000_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0002_4428_0028),
-    Bitboard::from_bits(0x0000_0000_4028_0028),
-    Bitboard::from_bits(0x0000_0002_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0000_0000_4428_0028),
-    Bitboard::from_bits(0x0000_0000_4028_0028),
-    Bitboard::from_bits(0x0000_0000_0428_0028),
-    Bitboard::from_bits(0x0000_0000_0028_0028),
-    Bitboard::from_bits(0x0001_0204_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0001_0204_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0000_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0000_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0004_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0004_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0000_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0000_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0204_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0204_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0000_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0000_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0004_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0004_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0000_0000_8850_0050),
-    Bitboard::from_bits(0x0000_0000_8050_0050),
-    Bitboard::from_bits(0x0000_0000_0850_0050),
-    Bitboard::from_bits(0x0000_0000_0050_0050),
-    Bitboard::from_bits(0x0102_0408_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0008_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0408_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0008_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0002_0408_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0008_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0408_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0008_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0000_0000_10a0_00a0),
-    Bitboard::from_bits(0x0000_0000_00a0_00a0),
-    Bitboard::from_bits(0x0204_0810_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0010_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0810_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0010_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0004_0810_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0010_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0810_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0010_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x0000_0000_2040_0040),
-    Bitboard::from_bits(0x0000_0000_0040_0040),
-    Bitboard::from_bits(0x2010_0804_0200_0204),
-    Bitboard::from_bits(0x2010_0804_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0004_0200_0204),
-    Bitboard::from_bits(0x0000_0004_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0804_0200_0204),
-    Bitboard::from_bits(0x0000_0804_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0004_0200_0204),
-    Bitboard::from_bits(0x0000_0004_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0010_0804_0200_0204),
-    Bitboard::from_bits(0x0010_0804_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0004_0200_0204),
-    Bitboard::from_bits(0x0000_0004_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0804_0200_0204),
-    Bitboard::from_bits(0x0000_0804_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x0000_0004_0200_0204),
-    Bitboard::from_bits(0x0000_0004_0200_0200),
-    Bitboard::from_bits(0x0000_0000_0200_0204),
-    Bitboard::from_bits(0x0000_0000_0200_0200),
-    Bitboard::from_bits(0x4020_1008_0500_0508),
-    Bitboard::from_bits(0x4020_1008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_0008_0500_0508),
-    Bitboard::from_bits(0x0000_0008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_1008_0500_0508),
-    Bitboard::from_bits(0x0000_1008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_0008_0500_0508),
-    Bitboard::from_bits(0x0000_0008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0020_1008_0500_0508),
-    Bitboard::from_bits(0x0020_1008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_0008_0500_0508),
-    Bitboard::from_bits(0x0000_0008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_1008_0500_0508),
-    Bitboard::from_bits(0x0000_1008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x0000_0008_0500_0508),
-    Bitboard::from_bits(0x0000_0008_0500_0500),
-    Bitboard::from_bits(0x0000_0000_0500_0508),
-    Bitboard::from_bits(0x0000_0000_0500_0500),
-    Bitboard::from_bits(0x8040_2011_0a00_0a11),
-    Bitboard::from_bits(0x8040_2011_0a00_0a10),
-    Bitboard::from_bits(0x8040_2011_0a00_0a01),
-    Bitboard::from_bits(0x8040_2011_0a00_0a00),
-    Bitboard::from_bits(0x8040_2010_0a00_0a11),
-    Bitboard::from_bits(0x8040_2010_0a00_0a10),
-    Bitboard::from_bits(0x8040_2010_0a00_0a01),
-    Bitboard::from_bits(0x8040_2010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_0011_0a00_0a11),
-    Bitboard::from_bits(0x0000_0011_0a00_0a10),
-    Bitboard::from_bits(0x0000_0011_0a00_0a01),
-    Bitboard::from_bits(0x0000_0011_0a00_0a00),
-    Bitboard::from_bits(0x0000_0010_0a00_0a11),
-    Bitboard::from_bits(0x0000_0010_0a00_0a10),
-    Bitboard::from_bits(0x0000_0010_0a00_0a01),
-    Bitboard::from_bits(0x0000_0010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_2011_0a00_0a11),
-    Bitboard::from_bits(0x0000_2011_0a00_0a10),
-    Bitboard::from_bits(0x0000_2011_0a00_0a01),
-    Bitboard::from_bits(0x0000_2011_0a00_0a00),
-    Bitboard::from_bits(0x0000_2010_0a00_0a11),
-    Bitboard::from_bits(0x0000_2010_0a00_0a10),
-    Bitboard::from_bits(0x0000_2010_0a00_0a01),
-    Bitboard::from_bits(0x0000_2010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_0011_0a00_0a11),
-    Bitboard::from_bits(0x0000_0011_0a00_0a10),
-    Bitboard::from_bits(0x0000_0011_0a00_0a01),
-    Bitboard::from_bits(0x0000_0011_0a00_0a00),
-    Bitboard::from_bits(0x0000_0010_0a00_0a11),
-    Bitboard::from_bits(0x0000_0010_0a00_0a10),
-    Bitboard::from_bits(0x0000_0010_0a00_0a01),
-    Bitboard::from_bits(0x0000_0010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0040_2011_0a00_0a11),
-    Bitboard::from_bits(0x0040_2011_0a00_0a10),
-    Bitboard::from_bits(0x0040_2011_0a00_0a01),
-    Bitboard::from_bits(0x0040_2011_0a00_0a00),
-    Bitboard::from_bits(0x0040_2010_0a00_0a11),
-    Bitboard::from_bits(0x0040_2010_0a00_0a10),
-    Bitboard::from_bits(0x0040_2010_0a00_0a01),
-    Bitboard::from_bits(0x0040_2010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_0011_0a00_0a11),
-    Bitboard::from_bits(0x0000_0011_0a00_0a10),
-    Bitboard::from_bits(0x0000_0011_0a00_0a01),
-    Bitboard::from_bits(0x0000_0011_0a00_0a00),
-    Bitboard::from_bits(0x0000_0010_0a00_0a11),
-    Bitboard::from_bits(0x0000_0010_0a00_0a10),
-    Bitboard::from_bits(0x0000_0010_0a00_0a01),
-    Bitboard::from_bits(0x0000_0010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_2011_0a00_0a11),
-    Bitboard::from_bits(0x0000_2011_0a00_0a10),
-    Bitboard::from_bits(0x0000_2011_0a00_0a01),
-    Bitboard::from_bits(0x0000_2011_0a00_0a00),
-    Bitboard::from_bits(0x0000_2010_0a00_0a11),
-    Bitboard::from_bits(0x0000_2010_0a00_0a10),
-    Bitboard::from_bits(0x0000_2010_0a00_0a01),
-    Bitboard::from_bits(0x0000_2010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0000_0011_0a00_0a11),
-    Bitboard::from_bits(0x0000_0011_0a00_0a10),
-    Bitboard::from_bits(0x0000_0011_0a00_0a01),
-    Bitboard::from_bits(0x0000_0011_0a00_0a00),
-    Bitboard::from_bits(0x0000_0010_0a00_0a11),
-    Bitboard::from_bits(0x0000_0010_0a00_0a10),
-    Bitboard::from_bits(0x0000_0010_0a00_0a01),
-    Bitboard::from_bits(0x0000_0010_0a00_0a00),
-    Bitboard::from_bits(0x0000_0001_0a00_0a11),
-    Bitboard::from_bits(0x0000_0001_0a00_0a10),
-    Bitboard::from_bits(0x0000_0001_0a00_0a01),
-    Bitboard::from_bits(0x0000_0001_0a00_0a00),
-    Bitboard::from_bits(0x0000_0000_0a00_0a11),
-    Bitboard::from_bits(0x0000_0000_0a00_0a10),
-    Bitboard::from_bits(0x0000_0000_0a00_0a01),
-    Bitboard::from_bits(0x0000_0000_0a00_0a00),
-    Bitboard::from_bits(0x0080_4122_1400_1422),
-    Bitboard::from_bits(0x0080_4122_1400_1420),
-    Bitboard::from_bits(0x0080_4122_1400_1402),
-    Bitboard::from_bits(0x0080_4122_1400_1400),
-    Bitboard::from_bits(0x0080_4020_1400_1422),
-    Bitboard::from_bits(0x0080_4020_1400_1420),
-    Bitboard::from_bits(0x0080_4020_1400_1402),
-    Bitboard::from_bits(0x0080_4020_1400_1400),
-    Bitboard::from_bits(0x0000_0102_1400_1422),
-    Bitboard::from_bits(0x0000_0102_1400_1420),
-    Bitboard::from_bits(0x0000_0102_1400_1402),
-    Bitboard::from_bits(0x0000_0102_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0080_4022_1400_1422),
-    Bitboard::from_bits(0x0080_4022_1400_1420),
-    Bitboard::from_bits(0x0080_4022_1400_1402),
-    Bitboard::from_bits(0x0080_4022_1400_1400),
-    Bitboard::from_bits(0x0080_4020_1400_1422),
-    Bitboard::from_bits(0x0080_4020_1400_1420),
-    Bitboard::from_bits(0x0080_4020_1400_1402),
-    Bitboard::from_bits(0x0080_4020_1400_1400),
-    Bitboard::from_bits(0x0000_0002_1400_1422),
-    Bitboard::from_bits(0x0000_0002_1400_1420),
-    Bitboard::from_bits(0x0000_0002_1400_1402),
-    Bitboard::from_bits(0x0000_0002_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_0122_1400_1422),
-    Bitboard::from_bits(0x0000_0122_1400_1420),
-    Bitboard::from_bits(0x0000_0122_1400_1402),
-    Bitboard::from_bits(0x0000_0122_1400_1400),
-    Bitboard::from_bits(0x0000_0020_1400_1422),
-    Bitboard::from_bits(0x0000_0020_1400_1420),
-    Bitboard::from_bits(0x0000_0020_1400_1402),
-    Bitboard::from_bits(0x0000_0020_1400_1400),
-    Bitboard::from_bits(0x0000_0102_1400_1422),
-    Bitboard::from_bits(0x0000_0102_1400_1420),
-    Bitboard::from_bits(0x0000_0102_1400_1402),
-    Bitboard::from_bits(0x0000_0102_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_0022_1400_1422),
-    Bitboard::from_bits(0x0000_0022_1400_1420),
-    Bitboard::from_bits(0x0000_0022_1400_1402),
-    Bitboard::from_bits(0x0000_0022_1400_1400),
-    Bitboard::from_bits(0x0000_0020_1400_1422),
-    Bitboard::from_bits(0x0000_0020_1400_1420),
-    Bitboard::from_bits(0x0000_0020_1400_1402),
-    Bitboard::from_bits(0x0000_0020_1400_1400),
-    Bitboard::from_bits(0x0000_0002_1400_1422),
-    Bitboard::from_bits(0x0000_0002_1400_1420),
-    Bitboard::from_bits(0x0000_0002_1400_1402),
-    Bitboard::from_bits(0x0000_0002_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_4122_1400_1422),
-    Bitboard::from_bits(0x0000_4122_1400_1420),
-    Bitboard::from_bits(0x0000_4122_1400_1402),
-    Bitboard::from_bits(0x0000_4122_1400_1400),
-    Bitboard::from_bits(0x0000_4020_1400_1422),
-    Bitboard::from_bits(0x0000_4020_1400_1420),
-    Bitboard::from_bits(0x0000_4020_1400_1402),
-    Bitboard::from_bits(0x0000_4020_1400_1400),
-    Bitboard::from_bits(0x0000_0102_1400_1422),
-    Bitboard::from_bits(0x0000_0102_1400_1420),
-    Bitboard::from_bits(0x0000_0102_1400_1402),
-    Bitboard::from_bits(0x0000_0102_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_4022_1400_1422),
-    Bitboard::from_bits(0x0000_4022_1400_1420),
-    Bitboard::from_bits(0x0000_4022_1400_1402),
-    Bitboard::from_bits(0x0000_4022_1400_1400),
-    Bitboard::from_bits(0x0000_4020_1400_1422),
-    Bitboard::from_bits(0x0000_4020_1400_1420),
-    Bitboard::from_bits(0x0000_4020_1400_1402),
-    Bitboard::from_bits(0x0000_4020_1400_1400),
-    Bitboard::from_bits(0x0000_0002_1400_1422),
-    Bitboard::from_bits(0x0000_0002_1400_1420),
-    Bitboard::from_bits(0x0000_0002_1400_1402),
-    Bitboard::from_bits(0x0000_0002_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_0122_1400_1422),
-    Bitboard::from_bits(0x0000_0122_1400_1420),
-    Bitboard::from_bits(0x0000_0122_1400_1402),
-    Bitboard::from_bits(0x0000_0122_1400_1400),
-    Bitboard::from_bits(0x0000_0020_1400_1422),
-    Bitboard::from_bits(0x0000_0020_1400_1420),
-    Bitboard::from_bits(0x0000_0020_1400_1402),
-    Bitboard::from_bits(0x0000_0020_1400_1400),
-    Bitboard::from_bits(0x0000_0102_1400_1422),
-    Bitboard::from_bits(0x0000_0102_1400_1420),
-    Bitboard::from_bits(0x0000_0102_1400_1402),
-    Bitboard::from_bits(0x0000_0102_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0000_0022_1400_1422),
-    Bitboard::from_bits(0x0000_0022_1400_1420),
-    Bitboard::from_bits(0x0000_0022_1400_1402),
-    Bitboard::from_bits(0x0000_0022_1400_1400),
-    Bitboard::from_bits(0x0000_0020_1400_1422),
-    Bitboard::from_bits(0x0000_0020_1400_1420),
-    Bitboard::from_bits(0x0000_0020_1400_1402),
-    Bitboard::from_bits(0x0000_0020_1400_1400),
-    Bitboard::from_bits(0x0000_0002_1400_1422),
-    Bitboard::from_bits(0x0000_0002_1400_1420),
-    Bitboard::from_bits(0x0000_0002_1400_1402),
-    Bitboard::from_bits(0x0000_0002_1400_1400),
-    Bitboard::from_bits(0x0000_0000_1400_1422),
-    Bitboard::from_bits(0x0000_0000_1400_1420),
-    Bitboard::from_bits(0x0000_0000_1400_1402),
-    Bitboard::from_bits(0x0000_0000_1400_1400),
-    Bitboard::from_bits(0x0001_8244_2800_2844),
-    Bitboard::from_bits(0x0001_8244_2800_2840),
-    Bitboard::from_bits(0x0001_8244_2800_2804),
-    Bitboard::from_bits(0x0001_8244_2800_2800),
-    Bitboard::from_bits(0x0000_8040_2800_2844),
-    Bitboard::from_bits(0x0000_8040_2800_2840),
-    Bitboard::from_bits(0x0000_8040_2800_2804),
-    Bitboard::from_bits(0x0000_8040_2800_2800),
-    Bitboard::from_bits(0x0001_0204_2800_2844),
-    Bitboard::from_bits(0x0001_0204_2800_2840),
-    Bitboard::from_bits(0x0001_0204_2800_2804),
-    Bitboard::from_bits(0x0001_0204_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_8044_2800_2844),
-    Bitboard::from_bits(0x0000_8044_2800_2840),
-    Bitboard::from_bits(0x0000_8044_2800_2804),
-    Bitboard::from_bits(0x0000_8044_2800_2800),
-    Bitboard::from_bits(0x0000_8040_2800_2844),
-    Bitboard::from_bits(0x0000_8040_2800_2840),
-    Bitboard::from_bits(0x0000_8040_2800_2804),
-    Bitboard::from_bits(0x0000_8040_2800_2800),
-    Bitboard::from_bits(0x0000_0004_2800_2844),
-    Bitboard::from_bits(0x0000_0004_2800_2840),
-    Bitboard::from_bits(0x0000_0004_2800_2804),
-    Bitboard::from_bits(0x0000_0004_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0001_0244_2800_2844),
-    Bitboard::from_bits(0x0001_0244_2800_2840),
-    Bitboard::from_bits(0x0001_0244_2800_2804),
-    Bitboard::from_bits(0x0001_0244_2800_2800),
-    Bitboard::from_bits(0x0000_0040_2800_2844),
-    Bitboard::from_bits(0x0000_0040_2800_2840),
-    Bitboard::from_bits(0x0000_0040_2800_2804),
-    Bitboard::from_bits(0x0000_0040_2800_2800),
-    Bitboard::from_bits(0x0001_0204_2800_2844),
-    Bitboard::from_bits(0x0001_0204_2800_2840),
-    Bitboard::from_bits(0x0001_0204_2800_2804),
-    Bitboard::from_bits(0x0001_0204_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_0044_2800_2844),
-    Bitboard::from_bits(0x0000_0044_2800_2840),
-    Bitboard::from_bits(0x0000_0044_2800_2804),
-    Bitboard::from_bits(0x0000_0044_2800_2800),
-    Bitboard::from_bits(0x0000_0040_2800_2844),
-    Bitboard::from_bits(0x0000_0040_2800_2840),
-    Bitboard::from_bits(0x0000_0040_2800_2804),
-    Bitboard::from_bits(0x0000_0040_2800_2800),
-    Bitboard::from_bits(0x0000_0004_2800_2844),
-    Bitboard::from_bits(0x0000_0004_2800_2840),
-    Bitboard::from_bits(0x0000_0004_2800_2804),
-    Bitboard::from_bits(0x0000_0004_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_8244_2800_2844),
-    Bitboard::from_bits(0x0000_8244_2800_2840),
-    Bitboard::from_bits(0x0000_8244_2800_2804),
-    Bitboard::from_bits(0x0000_8244_2800_2800),
-    Bitboard::from_bits(0x0000_8040_2800_2844),
-    Bitboard::from_bits(0x0000_8040_2800_2840),
-    Bitboard::from_bits(0x0000_8040_2800_2804),
-    Bitboard::from_bits(0x0000_8040_2800_2800),
-    Bitboard::from_bits(0x0000_0204_2800_2844),
-    Bitboard::from_bits(0x0000_0204_2800_2840),
-    Bitboard::from_bits(0x0000_0204_2800_2804),
-    Bitboard::from_bits(0x0000_0204_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_8044_2800_2844),
-    Bitboard::from_bits(0x0000_8044_2800_2840),
-    Bitboard::from_bits(0x0000_8044_2800_2804),
-    Bitboard::from_bits(0x0000_8044_2800_2800),
-    Bitboard::from_bits(0x0000_8040_2800_2844),
-    Bitboard::from_bits(0x0000_8040_2800_2840),
-    Bitboard::from_bits(0x0000_8040_2800_2804),
-    Bitboard::from_bits(0x0000_8040_2800_2800),
-    Bitboard::from_bits(0x0000_0004_2800_2844),
-    Bitboard::from_bits(0x0000_0004_2800_2840),
-    Bitboard::from_bits(0x0000_0004_2800_2804),
-    Bitboard::from_bits(0x0000_0004_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_0244_2800_2844),
-    Bitboard::from_bits(0x0000_0244_2800_2840),
-    Bitboard::from_bits(0x0000_0244_2800_2804),
-    Bitboard::from_bits(0x0000_0244_2800_2800),
-    Bitboard::from_bits(0x0000_0040_2800_2844),
-    Bitboard::from_bits(0x0000_0040_2800_2840),
-    Bitboard::from_bits(0x0000_0040_2800_2804),
-    Bitboard::from_bits(0x0000_0040_2800_2800),
-    Bitboard::from_bits(0x0000_0204_2800_2844),
-    Bitboard::from_bits(0x0000_0204_2800_2840),
-    Bitboard::from_bits(0x0000_0204_2800_2804),
-    Bitboard::from_bits(0x0000_0204_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0000_0044_2800_2844),
-    Bitboard::from_bits(0x0000_0044_2800_2840),
-    Bitboard::from_bits(0x0000_0044_2800_2804),
-    Bitboard::from_bits(0x0000_0044_2800_2800),
-    Bitboard::from_bits(0x0000_0040_2800_2844),
-    Bitboard::from_bits(0x0000_0040_2800_2840),
-    Bitboard::from_bits(0x0000_0040_2800_2804),
-    Bitboard::from_bits(0x0000_0040_2800_2800),
-    Bitboard::from_bits(0x0000_0004_2800_2844),
-    Bitboard::from_bits(0x0000_0004_2800_2840),
-    Bitboard::from_bits(0x0000_0004_2800_2804),
-    Bitboard::from_bits(0x0000_0004_2800_2800),
-    Bitboard::from_bits(0x0000_0000_2800_2844),
-    Bitboard::from_bits(0x0000_0000_2800_2840),
-    Bitboard::from_bits(0x0000_0000_2800_2804),
-    Bitboard::from_bits(0x0000_0000_2800_2800),
-    Bitboard::from_bits(0x0102_0488_5000_5088),
-    Bitboard::from_bits(0x0102_0488_5000_5080),
-    Bitboard::from_bits(0x0102_0488_5000_5008),
-    Bitboard::from_bits(0x0102_0488_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0102_0408_5000_5088),
-    Bitboard::from_bits(0x0102_0408_5000_5080),
-    Bitboard::from_bits(0x0102_0408_5000_5008),
-    Bitboard::from_bits(0x0102_0408_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0088_5000_5088),
-    Bitboard::from_bits(0x0000_0088_5000_5080),
-    Bitboard::from_bits(0x0000_0088_5000_5008),
-    Bitboard::from_bits(0x0000_0088_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0008_5000_5088),
-    Bitboard::from_bits(0x0000_0008_5000_5080),
-    Bitboard::from_bits(0x0000_0008_5000_5008),
-    Bitboard::from_bits(0x0000_0008_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0488_5000_5088),
-    Bitboard::from_bits(0x0000_0488_5000_5080),
-    Bitboard::from_bits(0x0000_0488_5000_5008),
-    Bitboard::from_bits(0x0000_0488_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0408_5000_5088),
-    Bitboard::from_bits(0x0000_0408_5000_5080),
-    Bitboard::from_bits(0x0000_0408_5000_5008),
-    Bitboard::from_bits(0x0000_0408_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0088_5000_5088),
-    Bitboard::from_bits(0x0000_0088_5000_5080),
-    Bitboard::from_bits(0x0000_0088_5000_5008),
-    Bitboard::from_bits(0x0000_0088_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0008_5000_5088),
-    Bitboard::from_bits(0x0000_0008_5000_5080),
-    Bitboard::from_bits(0x0000_0008_5000_5008),
-    Bitboard::from_bits(0x0000_0008_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0002_0488_5000_5088),
-    Bitboard::from_bits(0x0002_0488_5000_5080),
-    Bitboard::from_bits(0x0002_0488_5000_5008),
-    Bitboard::from_bits(0x0002_0488_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0002_0408_5000_5088),
-    Bitboard::from_bits(0x0002_0408_5000_5080),
-    Bitboard::from_bits(0x0002_0408_5000_5008),
-    Bitboard::from_bits(0x0002_0408_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0088_5000_5088),
-    Bitboard::from_bits(0x0000_0088_5000_5080),
-    Bitboard::from_bits(0x0000_0088_5000_5008),
-    Bitboard::from_bits(0x0000_0088_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0008_5000_5088),
-    Bitboard::from_bits(0x0000_0008_5000_5080),
-    Bitboard::from_bits(0x0000_0008_5000_5008),
-    Bitboard::from_bits(0x0000_0008_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0488_5000_5088),
-    Bitboard::from_bits(0x0000_0488_5000_5080),
-    Bitboard::from_bits(0x0000_0488_5000_5008),
-    Bitboard::from_bits(0x0000_0488_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0408_5000_5088),
-    Bitboard::from_bits(0x0000_0408_5000_5080),
-    Bitboard::from_bits(0x0000_0408_5000_5008),
-    Bitboard::from_bits(0x0000_0408_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0000_0088_5000_5088),
-    Bitboard::from_bits(0x0000_0088_5000_5080),
-    Bitboard::from_bits(0x0000_0088_5000_5008),
-    Bitboard::from_bits(0x0000_0088_5000_5000),
-    Bitboard::from_bits(0x0000_0080_5000_5088),
-    Bitboard::from_bits(0x0000_0080_5000_5080),
-    Bitboard::from_bits(0x0000_0080_5000_5008),
-    Bitboard::from_bits(0x0000_0080_5000_5000),
-    Bitboard::from_bits(0x0000_0008_5000_5088),
-    Bitboard::from_bits(0x0000_0008_5000_5080),
-    Bitboard::from_bits(0x0000_0008_5000_5008),
-    Bitboard::from_bits(0x0000_0008_5000_5000),
-    Bitboard::from_bits(0x0000_0000_5000_5088),
-    Bitboard::from_bits(0x0000_0000_5000_5080),
-    Bitboard::from_bits(0x0000_0000_5000_5008),
-    Bitboard::from_bits(0x0000_0000_5000_5000),
-    Bitboard::from_bits(0x0204_0810_a000_a010),
-    Bitboard::from_bits(0x0204_0810_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0010_a000_a010),
-    Bitboard::from_bits(0x0000_0010_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0810_a000_a010),
-    Bitboard::from_bits(0x0000_0810_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0010_a000_a010),
-    Bitboard::from_bits(0x0000_0010_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0004_0810_a000_a010),
-    Bitboard::from_bits(0x0004_0810_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0010_a000_a010),
-    Bitboard::from_bits(0x0000_0010_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0810_a000_a010),
-    Bitboard::from_bits(0x0000_0810_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0000_0010_a000_a010),
-    Bitboard::from_bits(0x0000_0010_a000_a000),
-    Bitboard::from_bits(0x0000_0000_a000_a010),
-    Bitboard::from_bits(0x0000_0000_a000_a000),
-    Bitboard::from_bits(0x0408_1020_4000_4020),
-    Bitboard::from_bits(0x0408_1020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_0020_4000_4020),
-    Bitboard::from_bits(0x0000_0020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_1020_4000_4020),
-    Bitboard::from_bits(0x0000_1020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_0020_4000_4020),
-    Bitboard::from_bits(0x0000_0020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0008_1020_4000_4020),
-    Bitboard::from_bits(0x0008_1020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_0020_4000_4020),
-    Bitboard::from_bits(0x0000_0020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_1020_4000_4020),
-    Bitboard::from_bits(0x0000_1020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x0000_0020_4000_4020),
-    Bitboard::from_bits(0x0000_0020_4000_4000),
-    Bitboard::from_bits(0x0000_0000_4000_4020),
-    Bitboard::from_bits(0x0000_0000_4000_4000),
-    Bitboard::from_bits(0x1008_0402_0002_0408),
-    Bitboard::from_bits(0x1008_0402_0002_0400),
-    Bitboard::from_bits(0x1008_0402_0002_0000),
-    Bitboard::from_bits(0x1008_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0408),
-    Bitboard::from_bits(0x0000_0002_0002_0400),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0402_0002_0408),
-    Bitboard::from_bits(0x0000_0402_0002_0400),
-    Bitboard::from_bits(0x0000_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0408),
-    Bitboard::from_bits(0x0000_0002_0002_0400),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0008_0402_0002_0408),
-    Bitboard::from_bits(0x0008_0402_0002_0400),
-    Bitboard::from_bits(0x0008_0402_0002_0000),
-    Bitboard::from_bits(0x0008_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0408),
-    Bitboard::from_bits(0x0000_0002_0002_0400),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0402_0002_0408),
-    Bitboard::from_bits(0x0000_0402_0002_0400),
-    Bitboard::from_bits(0x0000_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0402_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0408),
-    Bitboard::from_bits(0x0000_0002_0002_0400),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x0000_0002_0002_0000),
-    Bitboard::from_bits(0x2010_0805_0005_0810),
-    Bitboard::from_bits(0x2010_0805_0005_0800),
-    Bitboard::from_bits(0x2010_0805_0005_0000),
-    Bitboard::from_bits(0x2010_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0810),
-    Bitboard::from_bits(0x0000_0005_0005_0800),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0805_0005_0810),
-    Bitboard::from_bits(0x0000_0805_0005_0800),
-    Bitboard::from_bits(0x0000_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0810),
-    Bitboard::from_bits(0x0000_0005_0005_0800),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0010_0805_0005_0810),
-    Bitboard::from_bits(0x0010_0805_0005_0800),
-    Bitboard::from_bits(0x0010_0805_0005_0000),
-    Bitboard::from_bits(0x0010_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0810),
-    Bitboard::from_bits(0x0000_0005_0005_0800),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0805_0005_0810),
-    Bitboard::from_bits(0x0000_0805_0005_0800),
-    Bitboard::from_bits(0x0000_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0805_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0810),
-    Bitboard::from_bits(0x0000_0005_0005_0800),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x0000_0005_0005_0000),
-    Bitboard::from_bits(0x4020_110a_000a_1120),
-    Bitboard::from_bits(0x4020_110a_000a_1100),
-    Bitboard::from_bits(0x4020_110a_000a_1020),
-    Bitboard::from_bits(0x4020_110a_000a_1000),
-    Bitboard::from_bits(0x4020_110a_000a_0100),
-    Bitboard::from_bits(0x4020_110a_000a_0100),
-    Bitboard::from_bits(0x4020_110a_000a_0000),
-    Bitboard::from_bits(0x4020_110a_000a_0000),
-    Bitboard::from_bits(0x4020_100a_000a_1120),
-    Bitboard::from_bits(0x4020_100a_000a_1100),
-    Bitboard::from_bits(0x4020_100a_000a_1020),
-    Bitboard::from_bits(0x4020_100a_000a_1000),
-    Bitboard::from_bits(0x4020_100a_000a_0100),
-    Bitboard::from_bits(0x4020_100a_000a_0100),
-    Bitboard::from_bits(0x4020_100a_000a_0000),
-    Bitboard::from_bits(0x4020_100a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_1120),
-    Bitboard::from_bits(0x0000_010a_000a_1100),
-    Bitboard::from_bits(0x0000_010a_000a_1020),
-    Bitboard::from_bits(0x0000_010a_000a_1000),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_1120),
-    Bitboard::from_bits(0x0000_000a_000a_1100),
-    Bitboard::from_bits(0x0000_000a_000a_1020),
-    Bitboard::from_bits(0x0000_000a_000a_1000),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_110a_000a_1120),
-    Bitboard::from_bits(0x0000_110a_000a_1100),
-    Bitboard::from_bits(0x0000_110a_000a_1020),
-    Bitboard::from_bits(0x0000_110a_000a_1000),
-    Bitboard::from_bits(0x0000_110a_000a_0100),
-    Bitboard::from_bits(0x0000_110a_000a_0100),
-    Bitboard::from_bits(0x0000_110a_000a_0000),
-    Bitboard::from_bits(0x0000_110a_000a_0000),
-    Bitboard::from_bits(0x0000_100a_000a_1120),
-    Bitboard::from_bits(0x0000_100a_000a_1100),
-    Bitboard::from_bits(0x0000_100a_000a_1020),
-    Bitboard::from_bits(0x0000_100a_000a_1000),
-    Bitboard::from_bits(0x0000_100a_000a_0100),
-    Bitboard::from_bits(0x0000_100a_000a_0100),
-    Bitboard::from_bits(0x0000_100a_000a_0000),
-    Bitboard::from_bits(0x0000_100a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_1120),
-    Bitboard::from_bits(0x0000_010a_000a_1100),
-    Bitboard::from_bits(0x0000_010a_000a_1020),
-    Bitboard::from_bits(0x0000_010a_000a_1000),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_1120),
-    Bitboard::from_bits(0x0000_000a_000a_1100),
-    Bitboard::from_bits(0x0000_000a_000a_1020),
-    Bitboard::from_bits(0x0000_000a_000a_1000),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0020_110a_000a_1120),
-    Bitboard::from_bits(0x0020_110a_000a_1100),
-    Bitboard::from_bits(0x0020_110a_000a_1020),
-    Bitboard::from_bits(0x0020_110a_000a_1000),
-    Bitboard::from_bits(0x0020_110a_000a_0100),
-    Bitboard::from_bits(0x0020_110a_000a_0100),
-    Bitboard::from_bits(0x0020_110a_000a_0000),
-    Bitboard::from_bits(0x0020_110a_000a_0000),
-    Bitboard::from_bits(0x0020_100a_000a_1120),
-    Bitboard::from_bits(0x0020_100a_000a_1100),
-    Bitboard::from_bits(0x0020_100a_000a_1020),
-    Bitboard::from_bits(0x0020_100a_000a_1000),
-    Bitboard::from_bits(0x0020_100a_000a_0100),
-    Bitboard::from_bits(0x0020_100a_000a_0100),
-    Bitboard::from_bits(0x0020_100a_000a_0000),
-    Bitboard::from_bits(0x0020_100a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_1120),
-    Bitboard::from_bits(0x0000_010a_000a_1100),
-    Bitboard::from_bits(0x0000_010a_000a_1020),
-    Bitboard::from_bits(0x0000_010a_000a_1000),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_1120),
-    Bitboard::from_bits(0x0000_000a_000a_1100),
-    Bitboard::from_bits(0x0000_000a_000a_1020),
-    Bitboard::from_bits(0x0000_000a_000a_1000),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_110a_000a_1120),
-    Bitboard::from_bits(0x0000_110a_000a_1100),
-    Bitboard::from_bits(0x0000_110a_000a_1020),
-    Bitboard::from_bits(0x0000_110a_000a_1000),
-    Bitboard::from_bits(0x0000_110a_000a_0100),
-    Bitboard::from_bits(0x0000_110a_000a_0100),
-    Bitboard::from_bits(0x0000_110a_000a_0000),
-    Bitboard::from_bits(0x0000_110a_000a_0000),
-    Bitboard::from_bits(0x0000_100a_000a_1120),
-    Bitboard::from_bits(0x0000_100a_000a_1100),
-    Bitboard::from_bits(0x0000_100a_000a_1020),
-    Bitboard::from_bits(0x0000_100a_000a_1000),
-    Bitboard::from_bits(0x0000_100a_000a_0100),
-    Bitboard::from_bits(0x0000_100a_000a_0100),
-    Bitboard::from_bits(0x0000_100a_000a_0000),
-    Bitboard::from_bits(0x0000_100a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_1120),
-    Bitboard::from_bits(0x0000_010a_000a_1100),
-    Bitboard::from_bits(0x0000_010a_000a_1020),
-    Bitboard::from_bits(0x0000_010a_000a_1000),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0100),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_010a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_1120),
-    Bitboard::from_bits(0x0000_000a_000a_1100),
-    Bitboard::from_bits(0x0000_000a_000a_1020),
-    Bitboard::from_bits(0x0000_000a_000a_1000),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0100),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x0000_000a_000a_0000),
-    Bitboard::from_bits(0x8041_2214_0014_2241),
-    Bitboard::from_bits(0x8041_2214_0014_2240),
-    Bitboard::from_bits(0x8041_2214_0014_2201),
-    Bitboard::from_bits(0x8041_2214_0014_2200),
-    Bitboard::from_bits(0x8041_2214_0014_2040),
-    Bitboard::from_bits(0x8041_2214_0014_2040),
-    Bitboard::from_bits(0x8041_2214_0014_2000),
-    Bitboard::from_bits(0x8041_2214_0014_2000),
-    Bitboard::from_bits(0x8041_2214_0014_0201),
-    Bitboard::from_bits(0x8041_2214_0014_0200),
-    Bitboard::from_bits(0x8041_2214_0014_0201),
-    Bitboard::from_bits(0x8041_2214_0014_0200),
-    Bitboard::from_bits(0x8041_2214_0014_0000),
-    Bitboard::from_bits(0x8041_2214_0014_0000),
-    Bitboard::from_bits(0x8041_2214_0014_0000),
-    Bitboard::from_bits(0x8041_2214_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_2241),
-    Bitboard::from_bits(0x8040_2014_0014_2240),
-    Bitboard::from_bits(0x8040_2014_0014_2201),
-    Bitboard::from_bits(0x8040_2014_0014_2200),
-    Bitboard::from_bits(0x8040_2014_0014_2040),
-    Bitboard::from_bits(0x8040_2014_0014_2040),
-    Bitboard::from_bits(0x8040_2014_0014_2000),
-    Bitboard::from_bits(0x8040_2014_0014_2000),
-    Bitboard::from_bits(0x8040_2014_0014_0201),
-    Bitboard::from_bits(0x8040_2014_0014_0200),
-    Bitboard::from_bits(0x8040_2014_0014_0201),
-    Bitboard::from_bits(0x8040_2014_0014_0200),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_2241),
-    Bitboard::from_bits(0x0001_0214_0014_2240),
-    Bitboard::from_bits(0x0001_0214_0014_2201),
-    Bitboard::from_bits(0x0001_0214_0014_2200),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x8040_2214_0014_2241),
-    Bitboard::from_bits(0x8040_2214_0014_2240),
-    Bitboard::from_bits(0x8040_2214_0014_2201),
-    Bitboard::from_bits(0x8040_2214_0014_2200),
-    Bitboard::from_bits(0x8040_2214_0014_2040),
-    Bitboard::from_bits(0x8040_2214_0014_2040),
-    Bitboard::from_bits(0x8040_2214_0014_2000),
-    Bitboard::from_bits(0x8040_2214_0014_2000),
-    Bitboard::from_bits(0x8040_2214_0014_0201),
-    Bitboard::from_bits(0x8040_2214_0014_0200),
-    Bitboard::from_bits(0x8040_2214_0014_0201),
-    Bitboard::from_bits(0x8040_2214_0014_0200),
-    Bitboard::from_bits(0x8040_2214_0014_0000),
-    Bitboard::from_bits(0x8040_2214_0014_0000),
-    Bitboard::from_bits(0x8040_2214_0014_0000),
-    Bitboard::from_bits(0x8040_2214_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_2241),
-    Bitboard::from_bits(0x8040_2014_0014_2240),
-    Bitboard::from_bits(0x8040_2014_0014_2201),
-    Bitboard::from_bits(0x8040_2014_0014_2200),
-    Bitboard::from_bits(0x8040_2014_0014_2040),
-    Bitboard::from_bits(0x8040_2014_0014_2040),
-    Bitboard::from_bits(0x8040_2014_0014_2000),
-    Bitboard::from_bits(0x8040_2014_0014_2000),
-    Bitboard::from_bits(0x8040_2014_0014_0201),
-    Bitboard::from_bits(0x8040_2014_0014_0200),
-    Bitboard::from_bits(0x8040_2014_0014_0201),
-    Bitboard::from_bits(0x8040_2014_0014_0200),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x8040_2014_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_2241),
-    Bitboard::from_bits(0x0000_0214_0014_2240),
-    Bitboard::from_bits(0x0000_0214_0014_2201),
-    Bitboard::from_bits(0x0000_0214_0014_2200),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_2241),
-    Bitboard::from_bits(0x0001_2214_0014_2240),
-    Bitboard::from_bits(0x0001_2214_0014_2201),
-    Bitboard::from_bits(0x0001_2214_0014_2200),
-    Bitboard::from_bits(0x0001_2214_0014_2040),
-    Bitboard::from_bits(0x0001_2214_0014_2040),
-    Bitboard::from_bits(0x0001_2214_0014_2000),
-    Bitboard::from_bits(0x0001_2214_0014_2000),
-    Bitboard::from_bits(0x0001_2214_0014_0201),
-    Bitboard::from_bits(0x0001_2214_0014_0200),
-    Bitboard::from_bits(0x0001_2214_0014_0201),
-    Bitboard::from_bits(0x0001_2214_0014_0200),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_2241),
-    Bitboard::from_bits(0x0000_2014_0014_2240),
-    Bitboard::from_bits(0x0000_2014_0014_2201),
-    Bitboard::from_bits(0x0000_2014_0014_2200),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_2241),
-    Bitboard::from_bits(0x0001_0214_0014_2240),
-    Bitboard::from_bits(0x0001_0214_0014_2201),
-    Bitboard::from_bits(0x0001_0214_0014_2200),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_2241),
-    Bitboard::from_bits(0x0000_2214_0014_2240),
-    Bitboard::from_bits(0x0000_2214_0014_2201),
-    Bitboard::from_bits(0x0000_2214_0014_2200),
-    Bitboard::from_bits(0x0000_2214_0014_2040),
-    Bitboard::from_bits(0x0000_2214_0014_2040),
-    Bitboard::from_bits(0x0000_2214_0014_2000),
-    Bitboard::from_bits(0x0000_2214_0014_2000),
-    Bitboard::from_bits(0x0000_2214_0014_0201),
-    Bitboard::from_bits(0x0000_2214_0014_0200),
-    Bitboard::from_bits(0x0000_2214_0014_0201),
-    Bitboard::from_bits(0x0000_2214_0014_0200),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_2241),
-    Bitboard::from_bits(0x0000_2014_0014_2240),
-    Bitboard::from_bits(0x0000_2014_0014_2201),
-    Bitboard::from_bits(0x0000_2014_0014_2200),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_2241),
-    Bitboard::from_bits(0x0000_0214_0014_2240),
-    Bitboard::from_bits(0x0000_0214_0014_2201),
-    Bitboard::from_bits(0x0000_0214_0014_2200),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0041_2214_0014_2241),
-    Bitboard::from_bits(0x0041_2214_0014_2240),
-    Bitboard::from_bits(0x0041_2214_0014_2201),
-    Bitboard::from_bits(0x0041_2214_0014_2200),
-    Bitboard::from_bits(0x0041_2214_0014_2040),
-    Bitboard::from_bits(0x0041_2214_0014_2040),
-    Bitboard::from_bits(0x0041_2214_0014_2000),
-    Bitboard::from_bits(0x0041_2214_0014_2000),
-    Bitboard::from_bits(0x0041_2214_0014_0201),
-    Bitboard::from_bits(0x0041_2214_0014_0200),
-    Bitboard::from_bits(0x0041_2214_0014_0201),
-    Bitboard::from_bits(0x0041_2214_0014_0200),
-    Bitboard::from_bits(0x0041_2214_0014_0000),
-    Bitboard::from_bits(0x0041_2214_0014_0000),
-    Bitboard::from_bits(0x0041_2214_0014_0000),
-    Bitboard::from_bits(0x0041_2214_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_2241),
-    Bitboard::from_bits(0x0040_2014_0014_2240),
-    Bitboard::from_bits(0x0040_2014_0014_2201),
-    Bitboard::from_bits(0x0040_2014_0014_2200),
-    Bitboard::from_bits(0x0040_2014_0014_2040),
-    Bitboard::from_bits(0x0040_2014_0014_2040),
-    Bitboard::from_bits(0x0040_2014_0014_2000),
-    Bitboard::from_bits(0x0040_2014_0014_2000),
-    Bitboard::from_bits(0x0040_2014_0014_0201),
-    Bitboard::from_bits(0x0040_2014_0014_0200),
-    Bitboard::from_bits(0x0040_2014_0014_0201),
-    Bitboard::from_bits(0x0040_2014_0014_0200),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_2241),
-    Bitboard::from_bits(0x0001_0214_0014_2240),
-    Bitboard::from_bits(0x0001_0214_0014_2201),
-    Bitboard::from_bits(0x0001_0214_0014_2200),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0040_2214_0014_2241),
-    Bitboard::from_bits(0x0040_2214_0014_2240),
-    Bitboard::from_bits(0x0040_2214_0014_2201),
-    Bitboard::from_bits(0x0040_2214_0014_2200),
-    Bitboard::from_bits(0x0040_2214_0014_2040),
-    Bitboard::from_bits(0x0040_2214_0014_2040),
-    Bitboard::from_bits(0x0040_2214_0014_2000),
-    Bitboard::from_bits(0x0040_2214_0014_2000),
-    Bitboard::from_bits(0x0040_2214_0014_0201),
-    Bitboard::from_bits(0x0040_2214_0014_0200),
-    Bitboard::from_bits(0x0040_2214_0014_0201),
-    Bitboard::from_bits(0x0040_2214_0014_0200),
-    Bitboard::from_bits(0x0040_2214_0014_0000),
-    Bitboard::from_bits(0x0040_2214_0014_0000),
-    Bitboard::from_bits(0x0040_2214_0014_0000),
-    Bitboard::from_bits(0x0040_2214_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_2241),
-    Bitboard::from_bits(0x0040_2014_0014_2240),
-    Bitboard::from_bits(0x0040_2014_0014_2201),
-    Bitboard::from_bits(0x0040_2014_0014_2200),
-    Bitboard::from_bits(0x0040_2014_0014_2040),
-    Bitboard::from_bits(0x0040_2014_0014_2040),
-    Bitboard::from_bits(0x0040_2014_0014_2000),
-    Bitboard::from_bits(0x0040_2014_0014_2000),
-    Bitboard::from_bits(0x0040_2014_0014_0201),
-    Bitboard::from_bits(0x0040_2014_0014_0200),
-    Bitboard::from_bits(0x0040_2014_0014_0201),
-    Bitboard::from_bits(0x0040_2014_0014_0200),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0040_2014_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_2241),
-    Bitboard::from_bits(0x0000_0214_0014_2240),
-    Bitboard::from_bits(0x0000_0214_0014_2201),
-    Bitboard::from_bits(0x0000_0214_0014_2200),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_2241),
-    Bitboard::from_bits(0x0001_2214_0014_2240),
-    Bitboard::from_bits(0x0001_2214_0014_2201),
-    Bitboard::from_bits(0x0001_2214_0014_2200),
-    Bitboard::from_bits(0x0001_2214_0014_2040),
-    Bitboard::from_bits(0x0001_2214_0014_2040),
-    Bitboard::from_bits(0x0001_2214_0014_2000),
-    Bitboard::from_bits(0x0001_2214_0014_2000),
-    Bitboard::from_bits(0x0001_2214_0014_0201),
-    Bitboard::from_bits(0x0001_2214_0014_0200),
-    Bitboard::from_bits(0x0001_2214_0014_0201),
-    Bitboard::from_bits(0x0001_2214_0014_0200),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0001_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_2241),
-    Bitboard::from_bits(0x0000_2014_0014_2240),
-    Bitboard::from_bits(0x0000_2014_0014_2201),
-    Bitboard::from_bits(0x0000_2014_0014_2200),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_2241),
-    Bitboard::from_bits(0x0001_0214_0014_2240),
-    Bitboard::from_bits(0x0001_0214_0014_2201),
-    Bitboard::from_bits(0x0001_0214_0014_2200),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2040),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_2000),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0201),
-    Bitboard::from_bits(0x0001_0214_0014_0200),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0001_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_2241),
-    Bitboard::from_bits(0x0000_2214_0014_2240),
-    Bitboard::from_bits(0x0000_2214_0014_2201),
-    Bitboard::from_bits(0x0000_2214_0014_2200),
-    Bitboard::from_bits(0x0000_2214_0014_2040),
-    Bitboard::from_bits(0x0000_2214_0014_2040),
-    Bitboard::from_bits(0x0000_2214_0014_2000),
-    Bitboard::from_bits(0x0000_2214_0014_2000),
-    Bitboard::from_bits(0x0000_2214_0014_0201),
-    Bitboard::from_bits(0x0000_2214_0014_0200),
-    Bitboard::from_bits(0x0000_2214_0014_0201),
-    Bitboard::from_bits(0x0000_2214_0014_0200),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2214_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_2241),
-    Bitboard::from_bits(0x0000_2014_0014_2240),
-    Bitboard::from_bits(0x0000_2014_0014_2201),
-    Bitboard::from_bits(0x0000_2014_0014_2200),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2040),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_2000),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0201),
-    Bitboard::from_bits(0x0000_2014_0014_0200),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_2014_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_2241),
-    Bitboard::from_bits(0x0000_0214_0014_2240),
-    Bitboard::from_bits(0x0000_0214_0014_2201),
-    Bitboard::from_bits(0x0000_0214_0014_2200),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2040),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_2000),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0201),
-    Bitboard::from_bits(0x0000_0214_0014_0200),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0214_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_2241),
-    Bitboard::from_bits(0x0000_0014_0014_2240),
-    Bitboard::from_bits(0x0000_0014_0014_2201),
-    Bitboard::from_bits(0x0000_0014_0014_2200),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2040),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_2000),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0201),
-    Bitboard::from_bits(0x0000_0014_0014_0200),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0000_0014_0014_0000),
-    Bitboard::from_bits(0x0182_4428_0028_4482),
-    Bitboard::from_bits(0x0182_4428_0028_4480),
-    Bitboard::from_bits(0x0182_4428_0028_4402),
-    Bitboard::from_bits(0x0182_4428_0028_4400),
-    Bitboard::from_bits(0x0182_4428_0028_4080),
-    Bitboard::from_bits(0x0182_4428_0028_4080),
-    Bitboard::from_bits(0x0182_4428_0028_4000),
-    Bitboard::from_bits(0x0182_4428_0028_4000),
-    Bitboard::from_bits(0x0182_4428_0028_0402),
-    Bitboard::from_bits(0x0182_4428_0028_0400),
-    Bitboard::from_bits(0x0182_4428_0028_0402),
-    Bitboard::from_bits(0x0182_4428_0028_0400),
-    Bitboard::from_bits(0x0182_4428_0028_0000),
-    Bitboard::from_bits(0x0182_4428_0028_0000),
-    Bitboard::from_bits(0x0182_4428_0028_0000),
-    Bitboard::from_bits(0x0182_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_4482),
-    Bitboard::from_bits(0x0080_4028_0028_4480),
-    Bitboard::from_bits(0x0080_4028_0028_4402),
-    Bitboard::from_bits(0x0080_4028_0028_4400),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_4482),
-    Bitboard::from_bits(0x0102_0428_0028_4480),
-    Bitboard::from_bits(0x0102_0428_0028_4402),
-    Bitboard::from_bits(0x0102_0428_0028_4400),
-    Bitboard::from_bits(0x0102_0428_0028_4080),
-    Bitboard::from_bits(0x0102_0428_0028_4080),
-    Bitboard::from_bits(0x0102_0428_0028_4000),
-    Bitboard::from_bits(0x0102_0428_0028_4000),
-    Bitboard::from_bits(0x0102_0428_0028_0402),
-    Bitboard::from_bits(0x0102_0428_0028_0400),
-    Bitboard::from_bits(0x0102_0428_0028_0402),
-    Bitboard::from_bits(0x0102_0428_0028_0400),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_4482),
-    Bitboard::from_bits(0x0080_4428_0028_4480),
-    Bitboard::from_bits(0x0080_4428_0028_4402),
-    Bitboard::from_bits(0x0080_4428_0028_4400),
-    Bitboard::from_bits(0x0080_4428_0028_4080),
-    Bitboard::from_bits(0x0080_4428_0028_4080),
-    Bitboard::from_bits(0x0080_4428_0028_4000),
-    Bitboard::from_bits(0x0080_4428_0028_4000),
-    Bitboard::from_bits(0x0080_4428_0028_0402),
-    Bitboard::from_bits(0x0080_4428_0028_0400),
-    Bitboard::from_bits(0x0080_4428_0028_0402),
-    Bitboard::from_bits(0x0080_4428_0028_0400),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_4482),
-    Bitboard::from_bits(0x0080_4028_0028_4480),
-    Bitboard::from_bits(0x0080_4028_0028_4402),
-    Bitboard::from_bits(0x0080_4028_0028_4400),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_4482),
-    Bitboard::from_bits(0x0000_0428_0028_4480),
-    Bitboard::from_bits(0x0000_0428_0028_4402),
-    Bitboard::from_bits(0x0000_0428_0028_4400),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0102_4428_0028_4482),
-    Bitboard::from_bits(0x0102_4428_0028_4480),
-    Bitboard::from_bits(0x0102_4428_0028_4402),
-    Bitboard::from_bits(0x0102_4428_0028_4400),
-    Bitboard::from_bits(0x0102_4428_0028_4080),
-    Bitboard::from_bits(0x0102_4428_0028_4080),
-    Bitboard::from_bits(0x0102_4428_0028_4000),
-    Bitboard::from_bits(0x0102_4428_0028_4000),
-    Bitboard::from_bits(0x0102_4428_0028_0402),
-    Bitboard::from_bits(0x0102_4428_0028_0400),
-    Bitboard::from_bits(0x0102_4428_0028_0402),
-    Bitboard::from_bits(0x0102_4428_0028_0400),
-    Bitboard::from_bits(0x0102_4428_0028_0000),
-    Bitboard::from_bits(0x0102_4428_0028_0000),
-    Bitboard::from_bits(0x0102_4428_0028_0000),
-    Bitboard::from_bits(0x0102_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_4482),
-    Bitboard::from_bits(0x0000_4028_0028_4480),
-    Bitboard::from_bits(0x0000_4028_0028_4402),
-    Bitboard::from_bits(0x0000_4028_0028_4400),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_4482),
-    Bitboard::from_bits(0x0102_0428_0028_4480),
-    Bitboard::from_bits(0x0102_0428_0028_4402),
-    Bitboard::from_bits(0x0102_0428_0028_4400),
-    Bitboard::from_bits(0x0102_0428_0028_4080),
-    Bitboard::from_bits(0x0102_0428_0028_4080),
-    Bitboard::from_bits(0x0102_0428_0028_4000),
-    Bitboard::from_bits(0x0102_0428_0028_4000),
-    Bitboard::from_bits(0x0102_0428_0028_0402),
-    Bitboard::from_bits(0x0102_0428_0028_0400),
-    Bitboard::from_bits(0x0102_0428_0028_0402),
-    Bitboard::from_bits(0x0102_0428_0028_0400),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0102_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_4482),
-    Bitboard::from_bits(0x0000_4428_0028_4480),
-    Bitboard::from_bits(0x0000_4428_0028_4402),
-    Bitboard::from_bits(0x0000_4428_0028_4400),
-    Bitboard::from_bits(0x0000_4428_0028_4080),
-    Bitboard::from_bits(0x0000_4428_0028_4080),
-    Bitboard::from_bits(0x0000_4428_0028_4000),
-    Bitboard::from_bits(0x0000_4428_0028_4000),
-    Bitboard::from_bits(0x0000_4428_0028_0402),
-    Bitboard::from_bits(0x0000_4428_0028_0400),
-    Bitboard::from_bits(0x0000_4428_0028_0402),
-    Bitboard::from_bits(0x0000_4428_0028_0400),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_4482),
-    Bitboard::from_bits(0x0000_4028_0028_4480),
-    Bitboard::from_bits(0x0000_4028_0028_4402),
-    Bitboard::from_bits(0x0000_4028_0028_4400),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_4482),
-    Bitboard::from_bits(0x0000_0428_0028_4480),
-    Bitboard::from_bits(0x0000_0428_0028_4402),
-    Bitboard::from_bits(0x0000_0428_0028_4400),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0082_4428_0028_4482),
-    Bitboard::from_bits(0x0082_4428_0028_4480),
-    Bitboard::from_bits(0x0082_4428_0028_4402),
-    Bitboard::from_bits(0x0082_4428_0028_4400),
-    Bitboard::from_bits(0x0082_4428_0028_4080),
-    Bitboard::from_bits(0x0082_4428_0028_4080),
-    Bitboard::from_bits(0x0082_4428_0028_4000),
-    Bitboard::from_bits(0x0082_4428_0028_4000),
-    Bitboard::from_bits(0x0082_4428_0028_0402),
-    Bitboard::from_bits(0x0082_4428_0028_0400),
-    Bitboard::from_bits(0x0082_4428_0028_0402),
-    Bitboard::from_bits(0x0082_4428_0028_0400),
-    Bitboard::from_bits(0x0082_4428_0028_0000),
-    Bitboard::from_bits(0x0082_4428_0028_0000),
-    Bitboard::from_bits(0x0082_4428_0028_0000),
-    Bitboard::from_bits(0x0082_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_4482),
-    Bitboard::from_bits(0x0080_4028_0028_4480),
-    Bitboard::from_bits(0x0080_4028_0028_4402),
-    Bitboard::from_bits(0x0080_4028_0028_4400),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_4482),
-    Bitboard::from_bits(0x0002_0428_0028_4480),
-    Bitboard::from_bits(0x0002_0428_0028_4402),
-    Bitboard::from_bits(0x0002_0428_0028_4400),
-    Bitboard::from_bits(0x0002_0428_0028_4080),
-    Bitboard::from_bits(0x0002_0428_0028_4080),
-    Bitboard::from_bits(0x0002_0428_0028_4000),
-    Bitboard::from_bits(0x0002_0428_0028_4000),
-    Bitboard::from_bits(0x0002_0428_0028_0402),
-    Bitboard::from_bits(0x0002_0428_0028_0400),
-    Bitboard::from_bits(0x0002_0428_0028_0402),
-    Bitboard::from_bits(0x0002_0428_0028_0400),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_4482),
-    Bitboard::from_bits(0x0080_4428_0028_4480),
-    Bitboard::from_bits(0x0080_4428_0028_4402),
-    Bitboard::from_bits(0x0080_4428_0028_4400),
-    Bitboard::from_bits(0x0080_4428_0028_4080),
-    Bitboard::from_bits(0x0080_4428_0028_4080),
-    Bitboard::from_bits(0x0080_4428_0028_4000),
-    Bitboard::from_bits(0x0080_4428_0028_4000),
-    Bitboard::from_bits(0x0080_4428_0028_0402),
-    Bitboard::from_bits(0x0080_4428_0028_0400),
-    Bitboard::from_bits(0x0080_4428_0028_0402),
-    Bitboard::from_bits(0x0080_4428_0028_0400),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4428_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_4482),
-    Bitboard::from_bits(0x0080_4028_0028_4480),
-    Bitboard::from_bits(0x0080_4028_0028_4402),
-    Bitboard::from_bits(0x0080_4028_0028_4400),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4080),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_4000),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0402),
-    Bitboard::from_bits(0x0080_4028_0028_0400),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0080_4028_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_4482),
-    Bitboard::from_bits(0x0000_0428_0028_4480),
-    Bitboard::from_bits(0x0000_0428_0028_4402),
-    Bitboard::from_bits(0x0000_0428_0028_4400),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0002_4428_0028_4482),
-    Bitboard::from_bits(0x0002_4428_0028_4480),
-    Bitboard::from_bits(0x0002_4428_0028_4402),
-    Bitboard::from_bits(0x0002_4428_0028_4400),
-    Bitboard::from_bits(0x0002_4428_0028_4080),
-    Bitboard::from_bits(0x0002_4428_0028_4080),
-    Bitboard::from_bits(0x0002_4428_0028_4000),
-    Bitboard::from_bits(0x0002_4428_0028_4000),
-    Bitboard::from_bits(0x0002_4428_0028_0402),
-    Bitboard::from_bits(0x0002_4428_0028_0400),
-    Bitboard::from_bits(0x0002_4428_0028_0402),
-    Bitboard::from_bits(0x0002_4428_0028_0400),
-    Bitboard::from_bits(0x0002_4428_0028_0000),
-    Bitboard::from_bits(0x0002_4428_0028_0000),
-    Bitboard::from_bits(0x0002_4428_0028_0000),
-    Bitboard::from_bits(0x0002_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_4482),
-    Bitboard::from_bits(0x0000_4028_0028_4480),
-    Bitboard::from_bits(0x0000_4028_0028_4402),
-    Bitboard::from_bits(0x0000_4028_0028_4400),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_4482),
-    Bitboard::from_bits(0x0002_0428_0028_4480),
-    Bitboard::from_bits(0x0002_0428_0028_4402),
-    Bitboard::from_bits(0x0002_0428_0028_4400),
-    Bitboard::from_bits(0x0002_0428_0028_4080),
-    Bitboard::from_bits(0x0002_0428_0028_4080),
-    Bitboard::from_bits(0x0002_0428_0028_4000),
-    Bitboard::from_bits(0x0002_0428_0028_4000),
-    Bitboard::from_bits(0x0002_0428_0028_0402),
-    Bitboard::from_bits(0x0002_0428_0028_0400),
-    Bitboard::from_bits(0x0002_0428_0028_0402),
-    Bitboard::from_bits(0x0002_0428_0028_0400),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0002_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_4482),
-    Bitboard::from_bits(0x0000_4428_0028_4480),
-    Bitboard::from_bits(0x0000_4428_0028_4402),
-    Bitboard::from_bits(0x0000_4428_0028_4400),
-    Bitboard::from_bits(0x0000_4428_0028_4080),
-    Bitboard::from_bits(0x0000_4428_0028_4080),
-    Bitboard::from_bits(0x0000_4428_0028_4000),
-    Bitboard::from_bits(0x0000_4428_0028_4000),
-    Bitboard::from_bits(0x0000_4428_0028_0402),
-    Bitboard::from_bits(0x0000_4428_0028_0400),
-    Bitboard::from_bits(0x0000_4428_0028_0402),
-    Bitboard::from_bits(0x0000_4428_0028_0400),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4428_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_4482),
-    Bitboard::from_bits(0x0000_4028_0028_4480),
-    Bitboard::from_bits(0x0000_4028_0028_4402),
-    Bitboard::from_bits(0x0000_4028_0028_4400),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4080),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_4000),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0402),
-    Bitboard::from_bits(0x0000_4028_0028_0400),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_4028_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_4482),
-    Bitboard::from_bits(0x0000_0428_0028_4480),
-    Bitboard::from_bits(0x0000_0428_0028_4402),
-    Bitboard::from_bits(0x0000_0428_0028_4400),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4080),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_4000),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0402),
-    Bitboard::from_bits(0x0000_0428_0028_0400),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0428_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_4482),
-    Bitboard::from_bits(0x0000_0028_0028_4480),
-    Bitboard::from_bits(0x0000_0028_0028_4402),
-    Bitboard::from_bits(0x0000_0028_0028_4400),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4080),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_4000),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0402),
-    Bitboard::from_bits(0x0000_0028_0028_0400),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0000_0028_0028_0000),
-    Bitboard::from_bits(0x0204_8850_0050_8804),
-    Bitboard::from_bits(0x0204_8850_0050_8800),
-    Bitboard::from_bits(0x0204_8850_0050_8000),
-    Bitboard::from_bits(0x0204_8850_0050_8000),
-    Bitboard::from_bits(0x0204_8850_0050_0804),
-    Bitboard::from_bits(0x0204_8850_0050_0800),
-    Bitboard::from_bits(0x0204_8850_0050_0000),
-    Bitboard::from_bits(0x0204_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_8804),
-    Bitboard::from_bits(0x0000_8050_0050_8800),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_0804),
-    Bitboard::from_bits(0x0000_8050_0050_0800),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0204_0850_0050_8804),
-    Bitboard::from_bits(0x0204_0850_0050_8800),
-    Bitboard::from_bits(0x0204_0850_0050_8000),
-    Bitboard::from_bits(0x0204_0850_0050_8000),
-    Bitboard::from_bits(0x0204_0850_0050_0804),
-    Bitboard::from_bits(0x0204_0850_0050_0800),
-    Bitboard::from_bits(0x0204_0850_0050_0000),
-    Bitboard::from_bits(0x0204_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_8804),
-    Bitboard::from_bits(0x0000_0050_0050_8800),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_0804),
-    Bitboard::from_bits(0x0000_0050_0050_0800),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_8850_0050_8804),
-    Bitboard::from_bits(0x0000_8850_0050_8800),
-    Bitboard::from_bits(0x0000_8850_0050_8000),
-    Bitboard::from_bits(0x0000_8850_0050_8000),
-    Bitboard::from_bits(0x0000_8850_0050_0804),
-    Bitboard::from_bits(0x0000_8850_0050_0800),
-    Bitboard::from_bits(0x0000_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_8804),
-    Bitboard::from_bits(0x0000_8050_0050_8800),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_0804),
-    Bitboard::from_bits(0x0000_8050_0050_0800),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_0850_0050_8804),
-    Bitboard::from_bits(0x0000_0850_0050_8800),
-    Bitboard::from_bits(0x0000_0850_0050_8000),
-    Bitboard::from_bits(0x0000_0850_0050_8000),
-    Bitboard::from_bits(0x0000_0850_0050_0804),
-    Bitboard::from_bits(0x0000_0850_0050_0800),
-    Bitboard::from_bits(0x0000_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_8804),
-    Bitboard::from_bits(0x0000_0050_0050_8800),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_0804),
-    Bitboard::from_bits(0x0000_0050_0050_0800),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0004_8850_0050_8804),
-    Bitboard::from_bits(0x0004_8850_0050_8800),
-    Bitboard::from_bits(0x0004_8850_0050_8000),
-    Bitboard::from_bits(0x0004_8850_0050_8000),
-    Bitboard::from_bits(0x0004_8850_0050_0804),
-    Bitboard::from_bits(0x0004_8850_0050_0800),
-    Bitboard::from_bits(0x0004_8850_0050_0000),
-    Bitboard::from_bits(0x0004_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_8804),
-    Bitboard::from_bits(0x0000_8050_0050_8800),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_0804),
-    Bitboard::from_bits(0x0000_8050_0050_0800),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0004_0850_0050_8804),
-    Bitboard::from_bits(0x0004_0850_0050_8800),
-    Bitboard::from_bits(0x0004_0850_0050_8000),
-    Bitboard::from_bits(0x0004_0850_0050_8000),
-    Bitboard::from_bits(0x0004_0850_0050_0804),
-    Bitboard::from_bits(0x0004_0850_0050_0800),
-    Bitboard::from_bits(0x0004_0850_0050_0000),
-    Bitboard::from_bits(0x0004_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_8804),
-    Bitboard::from_bits(0x0000_0050_0050_8800),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_0804),
-    Bitboard::from_bits(0x0000_0050_0050_0800),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_8850_0050_8804),
-    Bitboard::from_bits(0x0000_8850_0050_8800),
-    Bitboard::from_bits(0x0000_8850_0050_8000),
-    Bitboard::from_bits(0x0000_8850_0050_8000),
-    Bitboard::from_bits(0x0000_8850_0050_0804),
-    Bitboard::from_bits(0x0000_8850_0050_0800),
-    Bitboard::from_bits(0x0000_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8850_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_8804),
-    Bitboard::from_bits(0x0000_8050_0050_8800),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_8000),
-    Bitboard::from_bits(0x0000_8050_0050_0804),
-    Bitboard::from_bits(0x0000_8050_0050_0800),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_8050_0050_0000),
-    Bitboard::from_bits(0x0000_0850_0050_8804),
-    Bitboard::from_bits(0x0000_0850_0050_8800),
-    Bitboard::from_bits(0x0000_0850_0050_8000),
-    Bitboard::from_bits(0x0000_0850_0050_8000),
-    Bitboard::from_bits(0x0000_0850_0050_0804),
-    Bitboard::from_bits(0x0000_0850_0050_0800),
-    Bitboard::from_bits(0x0000_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0850_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_8804),
-    Bitboard::from_bits(0x0000_0050_0050_8800),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_8000),
-    Bitboard::from_bits(0x0000_0050_0050_0804),
-    Bitboard::from_bits(0x0000_0050_0050_0800),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0000_0050_0050_0000),
-    Bitboard::from_bits(0x0408_10a0_00a0_1008),
-    Bitboard::from_bits(0x0408_10a0_00a0_1000),
-    Bitboard::from_bits(0x0408_10a0_00a0_0000),
-    Bitboard::from_bits(0x0408_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_1008),
-    Bitboard::from_bits(0x0000_00a0_00a0_1000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_10a0_00a0_1008),
-    Bitboard::from_bits(0x0000_10a0_00a0_1000),
-    Bitboard::from_bits(0x0000_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_1008),
-    Bitboard::from_bits(0x0000_00a0_00a0_1000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0008_10a0_00a0_1008),
-    Bitboard::from_bits(0x0008_10a0_00a0_1000),
-    Bitboard::from_bits(0x0008_10a0_00a0_0000),
-    Bitboard::from_bits(0x0008_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_1008),
-    Bitboard::from_bits(0x0000_00a0_00a0_1000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_10a0_00a0_1008),
-    Bitboard::from_bits(0x0000_10a0_00a0_1000),
-    Bitboard::from_bits(0x0000_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_10a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_1008),
-    Bitboard::from_bits(0x0000_00a0_00a0_1000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0000_00a0_00a0_0000),
-    Bitboard::from_bits(0x0810_2040_0040_2010),
-    Bitboard::from_bits(0x0810_2040_0040_2000),
-    Bitboard::from_bits(0x0810_2040_0040_0000),
-    Bitboard::from_bits(0x0810_2040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_2010),
-    Bitboard::from_bits(0x0000_0040_0040_2000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_2040_0040_2010),
-    Bitboard::from_bits(0x0000_2040_0040_2000),
-    Bitboard::from_bits(0x0000_2040_0040_0000),
-    Bitboard::from_bits(0x0000_2040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_2010),
-    Bitboard::from_bits(0x0000_0040_0040_2000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0010_2040_0040_2010),
-    Bitboard::from_bits(0x0010_2040_0040_2000),
-    Bitboard::from_bits(0x0010_2040_0040_0000),
-    Bitboard::from_bits(0x0010_2040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_2010),
-    Bitboard::from_bits(0x0000_0040_0040_2000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_2040_0040_2010),
-    Bitboard::from_bits(0x0000_2040_0040_2000),
-    Bitboard::from_bits(0x0000_2040_0040_0000),
-    Bitboard::from_bits(0x0000_2040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_2010),
-    Bitboard::from_bits(0x0000_0040_0040_2000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0000_0040_0040_0000),
-    Bitboard::from_bits(0x0804_0200_0204_0810),
-    Bitboard::from_bits(0x0804_0200_0204_0800),
-    Bitboard::from_bits(0x0804_0200_0204_0000),
-    Bitboard::from_bits(0x0804_0200_0204_0000),
-    Bitboard::from_bits(0x0804_0200_0200_0000),
-    Bitboard::from_bits(0x0804_0200_0200_0000),
-    Bitboard::from_bits(0x0804_0200_0200_0000),
-    Bitboard::from_bits(0x0804_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0204_0810),
-    Bitboard::from_bits(0x0000_0200_0204_0800),
-    Bitboard::from_bits(0x0000_0200_0204_0000),
-    Bitboard::from_bits(0x0000_0200_0204_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0004_0200_0204_0810),
-    Bitboard::from_bits(0x0004_0200_0204_0800),
-    Bitboard::from_bits(0x0004_0200_0204_0000),
-    Bitboard::from_bits(0x0004_0200_0204_0000),
-    Bitboard::from_bits(0x0004_0200_0200_0000),
-    Bitboard::from_bits(0x0004_0200_0200_0000),
-    Bitboard::from_bits(0x0004_0200_0200_0000),
-    Bitboard::from_bits(0x0004_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0204_0810),
-    Bitboard::from_bits(0x0000_0200_0204_0800),
-    Bitboard::from_bits(0x0000_0200_0204_0000),
-    Bitboard::from_bits(0x0000_0200_0204_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x0000_0200_0200_0000),
-    Bitboard::from_bits(0x1008_0500_0508_1020),
-    Bitboard::from_bits(0x1008_0500_0508_1000),
-    Bitboard::from_bits(0x1008_0500_0508_0000),
-    Bitboard::from_bits(0x1008_0500_0508_0000),
-    Bitboard::from_bits(0x1008_0500_0500_0000),
-    Bitboard::from_bits(0x1008_0500_0500_0000),
-    Bitboard::from_bits(0x1008_0500_0500_0000),
-    Bitboard::from_bits(0x1008_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0508_1020),
-    Bitboard::from_bits(0x0000_0500_0508_1000),
-    Bitboard::from_bits(0x0000_0500_0508_0000),
-    Bitboard::from_bits(0x0000_0500_0508_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0008_0500_0508_1020),
-    Bitboard::from_bits(0x0008_0500_0508_1000),
-    Bitboard::from_bits(0x0008_0500_0508_0000),
-    Bitboard::from_bits(0x0008_0500_0508_0000),
-    Bitboard::from_bits(0x0008_0500_0500_0000),
-    Bitboard::from_bits(0x0008_0500_0500_0000),
-    Bitboard::from_bits(0x0008_0500_0500_0000),
-    Bitboard::from_bits(0x0008_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0508_1020),
-    Bitboard::from_bits(0x0000_0500_0508_1000),
-    Bitboard::from_bits(0x0000_0500_0508_0000),
-    Bitboard::from_bits(0x0000_0500_0508_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x0000_0500_0500_0000),
-    Bitboard::from_bits(0x2011_0a00_0a11_2040),
-    Bitboard::from_bits(0x2011_0a00_0a11_2000),
-    Bitboard::from_bits(0x2011_0a00_0a11_0000),
-    Bitboard::from_bits(0x2011_0a00_0a11_0000),
-    Bitboard::from_bits(0x2011_0a00_0a10_2040),
-    Bitboard::from_bits(0x2011_0a00_0a10_2000),
-    Bitboard::from_bits(0x2011_0a00_0a10_0000),
-    Bitboard::from_bits(0x2011_0a00_0a10_0000),
-    Bitboard::from_bits(0x2011_0a00_0a01_0000),
-    Bitboard::from_bits(0x2011_0a00_0a01_0000),
-    Bitboard::from_bits(0x2011_0a00_0a01_0000),
-    Bitboard::from_bits(0x2011_0a00_0a01_0000),
-    Bitboard::from_bits(0x2011_0a00_0a00_0000),
-    Bitboard::from_bits(0x2011_0a00_0a00_0000),
-    Bitboard::from_bits(0x2011_0a00_0a00_0000),
-    Bitboard::from_bits(0x2011_0a00_0a00_0000),
-    Bitboard::from_bits(0x2010_0a00_0a11_2040),
-    Bitboard::from_bits(0x2010_0a00_0a11_2000),
-    Bitboard::from_bits(0x2010_0a00_0a11_0000),
-    Bitboard::from_bits(0x2010_0a00_0a11_0000),
-    Bitboard::from_bits(0x2010_0a00_0a10_2040),
-    Bitboard::from_bits(0x2010_0a00_0a10_2000),
-    Bitboard::from_bits(0x2010_0a00_0a10_0000),
-    Bitboard::from_bits(0x2010_0a00_0a10_0000),
-    Bitboard::from_bits(0x2010_0a00_0a01_0000),
-    Bitboard::from_bits(0x2010_0a00_0a01_0000),
-    Bitboard::from_bits(0x2010_0a00_0a01_0000),
-    Bitboard::from_bits(0x2010_0a00_0a01_0000),
-    Bitboard::from_bits(0x2010_0a00_0a00_0000),
-    Bitboard::from_bits(0x2010_0a00_0a00_0000),
-    Bitboard::from_bits(0x2010_0a00_0a00_0000),
-    Bitboard::from_bits(0x2010_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a11_2040),
-    Bitboard::from_bits(0x0001_0a00_0a11_2000),
-    Bitboard::from_bits(0x0001_0a00_0a11_0000),
-    Bitboard::from_bits(0x0001_0a00_0a11_0000),
-    Bitboard::from_bits(0x0001_0a00_0a10_2040),
-    Bitboard::from_bits(0x0001_0a00_0a10_2000),
-    Bitboard::from_bits(0x0001_0a00_0a10_0000),
-    Bitboard::from_bits(0x0001_0a00_0a10_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a11_2040),
-    Bitboard::from_bits(0x0000_0a00_0a11_2000),
-    Bitboard::from_bits(0x0000_0a00_0a11_0000),
-    Bitboard::from_bits(0x0000_0a00_0a11_0000),
-    Bitboard::from_bits(0x0000_0a00_0a10_2040),
-    Bitboard::from_bits(0x0000_0a00_0a10_2000),
-    Bitboard::from_bits(0x0000_0a00_0a10_0000),
-    Bitboard::from_bits(0x0000_0a00_0a10_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0011_0a00_0a11_2040),
-    Bitboard::from_bits(0x0011_0a00_0a11_2000),
-    Bitboard::from_bits(0x0011_0a00_0a11_0000),
-    Bitboard::from_bits(0x0011_0a00_0a11_0000),
-    Bitboard::from_bits(0x0011_0a00_0a10_2040),
-    Bitboard::from_bits(0x0011_0a00_0a10_2000),
-    Bitboard::from_bits(0x0011_0a00_0a10_0000),
-    Bitboard::from_bits(0x0011_0a00_0a10_0000),
-    Bitboard::from_bits(0x0011_0a00_0a01_0000),
-    Bitboard::from_bits(0x0011_0a00_0a01_0000),
-    Bitboard::from_bits(0x0011_0a00_0a01_0000),
-    Bitboard::from_bits(0x0011_0a00_0a01_0000),
-    Bitboard::from_bits(0x0011_0a00_0a00_0000),
-    Bitboard::from_bits(0x0011_0a00_0a00_0000),
-    Bitboard::from_bits(0x0011_0a00_0a00_0000),
-    Bitboard::from_bits(0x0011_0a00_0a00_0000),
-    Bitboard::from_bits(0x0010_0a00_0a11_2040),
-    Bitboard::from_bits(0x0010_0a00_0a11_2000),
-    Bitboard::from_bits(0x0010_0a00_0a11_0000),
-    Bitboard::from_bits(0x0010_0a00_0a11_0000),
-    Bitboard::from_bits(0x0010_0a00_0a10_2040),
-    Bitboard::from_bits(0x0010_0a00_0a10_2000),
-    Bitboard::from_bits(0x0010_0a00_0a10_0000),
-    Bitboard::from_bits(0x0010_0a00_0a10_0000),
-    Bitboard::from_bits(0x0010_0a00_0a01_0000),
-    Bitboard::from_bits(0x0010_0a00_0a01_0000),
-    Bitboard::from_bits(0x0010_0a00_0a01_0000),
-    Bitboard::from_bits(0x0010_0a00_0a01_0000),
-    Bitboard::from_bits(0x0010_0a00_0a00_0000),
-    Bitboard::from_bits(0x0010_0a00_0a00_0000),
-    Bitboard::from_bits(0x0010_0a00_0a00_0000),
-    Bitboard::from_bits(0x0010_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a11_2040),
-    Bitboard::from_bits(0x0001_0a00_0a11_2000),
-    Bitboard::from_bits(0x0001_0a00_0a11_0000),
-    Bitboard::from_bits(0x0001_0a00_0a11_0000),
-    Bitboard::from_bits(0x0001_0a00_0a10_2040),
-    Bitboard::from_bits(0x0001_0a00_0a10_2000),
-    Bitboard::from_bits(0x0001_0a00_0a10_0000),
-    Bitboard::from_bits(0x0001_0a00_0a10_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a01_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0001_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a11_2040),
-    Bitboard::from_bits(0x0000_0a00_0a11_2000),
-    Bitboard::from_bits(0x0000_0a00_0a11_0000),
-    Bitboard::from_bits(0x0000_0a00_0a11_0000),
-    Bitboard::from_bits(0x0000_0a00_0a10_2040),
-    Bitboard::from_bits(0x0000_0a00_0a10_2000),
-    Bitboard::from_bits(0x0000_0a00_0a10_0000),
-    Bitboard::from_bits(0x0000_0a00_0a10_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a01_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x0000_0a00_0a00_0000),
-    Bitboard::from_bits(0x4122_1400_1422_4180),
-    Bitboard::from_bits(0x4122_1400_1422_4100),
-    Bitboard::from_bits(0x4122_1400_1422_4080),
-    Bitboard::from_bits(0x4122_1400_1422_4000),
-    Bitboard::from_bits(0x4122_1400_1422_0100),
-    Bitboard::from_bits(0x4122_1400_1422_0100),
-    Bitboard::from_bits(0x4122_1400_1422_0000),
-    Bitboard::from_bits(0x4122_1400_1422_0000),
-    Bitboard::from_bits(0x4122_1400_1420_4080),
-    Bitboard::from_bits(0x4122_1400_1420_4000),
-    Bitboard::from_bits(0x4122_1400_1420_4080),
-    Bitboard::from_bits(0x4122_1400_1420_4000),
-    Bitboard::from_bits(0x4122_1400_1420_0000),
-    Bitboard::from_bits(0x4122_1400_1420_0000),
-    Bitboard::from_bits(0x4122_1400_1420_0000),
-    Bitboard::from_bits(0x4122_1400_1420_0000),
-    Bitboard::from_bits(0x4122_1400_1402_0100),
-    Bitboard::from_bits(0x4122_1400_1402_0100),
-    Bitboard::from_bits(0x4122_1400_1402_0000),
-    Bitboard::from_bits(0x4122_1400_1402_0000),
-    Bitboard::from_bits(0x4122_1400_1402_0100),
-    Bitboard::from_bits(0x4122_1400_1402_0100),
-    Bitboard::from_bits(0x4122_1400_1402_0000),
-    Bitboard::from_bits(0x4122_1400_1402_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4122_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1422_4180),
-    Bitboard::from_bits(0x4020_1400_1422_4100),
-    Bitboard::from_bits(0x4020_1400_1422_4080),
-    Bitboard::from_bits(0x4020_1400_1422_4000),
-    Bitboard::from_bits(0x4020_1400_1422_0100),
-    Bitboard::from_bits(0x4020_1400_1422_0100),
-    Bitboard::from_bits(0x4020_1400_1422_0000),
-    Bitboard::from_bits(0x4020_1400_1422_0000),
-    Bitboard::from_bits(0x4020_1400_1420_4080),
-    Bitboard::from_bits(0x4020_1400_1420_4000),
-    Bitboard::from_bits(0x4020_1400_1420_4080),
-    Bitboard::from_bits(0x4020_1400_1420_4000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1422_4180),
-    Bitboard::from_bits(0x0102_1400_1422_4100),
-    Bitboard::from_bits(0x0102_1400_1422_4080),
-    Bitboard::from_bits(0x0102_1400_1422_4000),
-    Bitboard::from_bits(0x0102_1400_1422_0100),
-    Bitboard::from_bits(0x0102_1400_1422_0100),
-    Bitboard::from_bits(0x0102_1400_1422_0000),
-    Bitboard::from_bits(0x0102_1400_1422_0000),
-    Bitboard::from_bits(0x0102_1400_1420_4080),
-    Bitboard::from_bits(0x0102_1400_1420_4000),
-    Bitboard::from_bits(0x0102_1400_1420_4080),
-    Bitboard::from_bits(0x0102_1400_1420_4000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1422_4180),
-    Bitboard::from_bits(0x0000_1400_1422_4100),
-    Bitboard::from_bits(0x0000_1400_1422_4080),
-    Bitboard::from_bits(0x0000_1400_1422_4000),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1422_4180),
-    Bitboard::from_bits(0x4022_1400_1422_4100),
-    Bitboard::from_bits(0x4022_1400_1422_4080),
-    Bitboard::from_bits(0x4022_1400_1422_4000),
-    Bitboard::from_bits(0x4022_1400_1422_0100),
-    Bitboard::from_bits(0x4022_1400_1422_0100),
-    Bitboard::from_bits(0x4022_1400_1422_0000),
-    Bitboard::from_bits(0x4022_1400_1422_0000),
-    Bitboard::from_bits(0x4022_1400_1420_4080),
-    Bitboard::from_bits(0x4022_1400_1420_4000),
-    Bitboard::from_bits(0x4022_1400_1420_4080),
-    Bitboard::from_bits(0x4022_1400_1420_4000),
-    Bitboard::from_bits(0x4022_1400_1420_0000),
-    Bitboard::from_bits(0x4022_1400_1420_0000),
-    Bitboard::from_bits(0x4022_1400_1420_0000),
-    Bitboard::from_bits(0x4022_1400_1420_0000),
-    Bitboard::from_bits(0x4022_1400_1402_0100),
-    Bitboard::from_bits(0x4022_1400_1402_0100),
-    Bitboard::from_bits(0x4022_1400_1402_0000),
-    Bitboard::from_bits(0x4022_1400_1402_0000),
-    Bitboard::from_bits(0x4022_1400_1402_0100),
-    Bitboard::from_bits(0x4022_1400_1402_0100),
-    Bitboard::from_bits(0x4022_1400_1402_0000),
-    Bitboard::from_bits(0x4022_1400_1402_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4022_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1422_4180),
-    Bitboard::from_bits(0x4020_1400_1422_4100),
-    Bitboard::from_bits(0x4020_1400_1422_4080),
-    Bitboard::from_bits(0x4020_1400_1422_4000),
-    Bitboard::from_bits(0x4020_1400_1422_0100),
-    Bitboard::from_bits(0x4020_1400_1422_0100),
-    Bitboard::from_bits(0x4020_1400_1422_0000),
-    Bitboard::from_bits(0x4020_1400_1422_0000),
-    Bitboard::from_bits(0x4020_1400_1420_4080),
-    Bitboard::from_bits(0x4020_1400_1420_4000),
-    Bitboard::from_bits(0x4020_1400_1420_4080),
-    Bitboard::from_bits(0x4020_1400_1420_4000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1420_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0100),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1402_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x4020_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1422_4180),
-    Bitboard::from_bits(0x0002_1400_1422_4100),
-    Bitboard::from_bits(0x0002_1400_1422_4080),
-    Bitboard::from_bits(0x0002_1400_1422_4000),
-    Bitboard::from_bits(0x0002_1400_1422_0100),
-    Bitboard::from_bits(0x0002_1400_1422_0100),
-    Bitboard::from_bits(0x0002_1400_1422_0000),
-    Bitboard::from_bits(0x0002_1400_1422_0000),
-    Bitboard::from_bits(0x0002_1400_1420_4080),
-    Bitboard::from_bits(0x0002_1400_1420_4000),
-    Bitboard::from_bits(0x0002_1400_1420_4080),
-    Bitboard::from_bits(0x0002_1400_1420_4000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1422_4180),
-    Bitboard::from_bits(0x0000_1400_1422_4100),
-    Bitboard::from_bits(0x0000_1400_1422_4080),
-    Bitboard::from_bits(0x0000_1400_1422_4000),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1422_4180),
-    Bitboard::from_bits(0x0122_1400_1422_4100),
-    Bitboard::from_bits(0x0122_1400_1422_4080),
-    Bitboard::from_bits(0x0122_1400_1422_4000),
-    Bitboard::from_bits(0x0122_1400_1422_0100),
-    Bitboard::from_bits(0x0122_1400_1422_0100),
-    Bitboard::from_bits(0x0122_1400_1422_0000),
-    Bitboard::from_bits(0x0122_1400_1422_0000),
-    Bitboard::from_bits(0x0122_1400_1420_4080),
-    Bitboard::from_bits(0x0122_1400_1420_4000),
-    Bitboard::from_bits(0x0122_1400_1420_4080),
-    Bitboard::from_bits(0x0122_1400_1420_4000),
-    Bitboard::from_bits(0x0122_1400_1420_0000),
-    Bitboard::from_bits(0x0122_1400_1420_0000),
-    Bitboard::from_bits(0x0122_1400_1420_0000),
-    Bitboard::from_bits(0x0122_1400_1420_0000),
-    Bitboard::from_bits(0x0122_1400_1402_0100),
-    Bitboard::from_bits(0x0122_1400_1402_0100),
-    Bitboard::from_bits(0x0122_1400_1402_0000),
-    Bitboard::from_bits(0x0122_1400_1402_0000),
-    Bitboard::from_bits(0x0122_1400_1402_0100),
-    Bitboard::from_bits(0x0122_1400_1402_0100),
-    Bitboard::from_bits(0x0122_1400_1402_0000),
-    Bitboard::from_bits(0x0122_1400_1402_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0122_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1422_4180),
-    Bitboard::from_bits(0x0020_1400_1422_4100),
-    Bitboard::from_bits(0x0020_1400_1422_4080),
-    Bitboard::from_bits(0x0020_1400_1422_4000),
-    Bitboard::from_bits(0x0020_1400_1422_0100),
-    Bitboard::from_bits(0x0020_1400_1422_0100),
-    Bitboard::from_bits(0x0020_1400_1422_0000),
-    Bitboard::from_bits(0x0020_1400_1422_0000),
-    Bitboard::from_bits(0x0020_1400_1420_4080),
-    Bitboard::from_bits(0x0020_1400_1420_4000),
-    Bitboard::from_bits(0x0020_1400_1420_4080),
-    Bitboard::from_bits(0x0020_1400_1420_4000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1422_4180),
-    Bitboard::from_bits(0x0102_1400_1422_4100),
-    Bitboard::from_bits(0x0102_1400_1422_4080),
-    Bitboard::from_bits(0x0102_1400_1422_4000),
-    Bitboard::from_bits(0x0102_1400_1422_0100),
-    Bitboard::from_bits(0x0102_1400_1422_0100),
-    Bitboard::from_bits(0x0102_1400_1422_0000),
-    Bitboard::from_bits(0x0102_1400_1422_0000),
-    Bitboard::from_bits(0x0102_1400_1420_4080),
-    Bitboard::from_bits(0x0102_1400_1420_4000),
-    Bitboard::from_bits(0x0102_1400_1420_4080),
-    Bitboard::from_bits(0x0102_1400_1420_4000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1420_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0100),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1402_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0102_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1422_4180),
-    Bitboard::from_bits(0x0000_1400_1422_4100),
-    Bitboard::from_bits(0x0000_1400_1422_4080),
-    Bitboard::from_bits(0x0000_1400_1422_4000),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1422_4180),
-    Bitboard::from_bits(0x0022_1400_1422_4100),
-    Bitboard::from_bits(0x0022_1400_1422_4080),
-    Bitboard::from_bits(0x0022_1400_1422_4000),
-    Bitboard::from_bits(0x0022_1400_1422_0100),
-    Bitboard::from_bits(0x0022_1400_1422_0100),
-    Bitboard::from_bits(0x0022_1400_1422_0000),
-    Bitboard::from_bits(0x0022_1400_1422_0000),
-    Bitboard::from_bits(0x0022_1400_1420_4080),
-    Bitboard::from_bits(0x0022_1400_1420_4000),
-    Bitboard::from_bits(0x0022_1400_1420_4080),
-    Bitboard::from_bits(0x0022_1400_1420_4000),
-    Bitboard::from_bits(0x0022_1400_1420_0000),
-    Bitboard::from_bits(0x0022_1400_1420_0000),
-    Bitboard::from_bits(0x0022_1400_1420_0000),
-    Bitboard::from_bits(0x0022_1400_1420_0000),
-    Bitboard::from_bits(0x0022_1400_1402_0100),
-    Bitboard::from_bits(0x0022_1400_1402_0100),
-    Bitboard::from_bits(0x0022_1400_1402_0000),
-    Bitboard::from_bits(0x0022_1400_1402_0000),
-    Bitboard::from_bits(0x0022_1400_1402_0100),
-    Bitboard::from_bits(0x0022_1400_1402_0100),
-    Bitboard::from_bits(0x0022_1400_1402_0000),
-    Bitboard::from_bits(0x0022_1400_1402_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0022_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1422_4180),
-    Bitboard::from_bits(0x0020_1400_1422_4100),
-    Bitboard::from_bits(0x0020_1400_1422_4080),
-    Bitboard::from_bits(0x0020_1400_1422_4000),
-    Bitboard::from_bits(0x0020_1400_1422_0100),
-    Bitboard::from_bits(0x0020_1400_1422_0100),
-    Bitboard::from_bits(0x0020_1400_1422_0000),
-    Bitboard::from_bits(0x0020_1400_1422_0000),
-    Bitboard::from_bits(0x0020_1400_1420_4080),
-    Bitboard::from_bits(0x0020_1400_1420_4000),
-    Bitboard::from_bits(0x0020_1400_1420_4080),
-    Bitboard::from_bits(0x0020_1400_1420_4000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1420_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0100),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1402_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0020_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1422_4180),
-    Bitboard::from_bits(0x0002_1400_1422_4100),
-    Bitboard::from_bits(0x0002_1400_1422_4080),
-    Bitboard::from_bits(0x0002_1400_1422_4000),
-    Bitboard::from_bits(0x0002_1400_1422_0100),
-    Bitboard::from_bits(0x0002_1400_1422_0100),
-    Bitboard::from_bits(0x0002_1400_1422_0000),
-    Bitboard::from_bits(0x0002_1400_1422_0000),
-    Bitboard::from_bits(0x0002_1400_1420_4080),
-    Bitboard::from_bits(0x0002_1400_1420_4000),
-    Bitboard::from_bits(0x0002_1400_1420_4080),
-    Bitboard::from_bits(0x0002_1400_1420_4000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1420_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0100),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1402_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0002_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1422_4180),
-    Bitboard::from_bits(0x0000_1400_1422_4100),
-    Bitboard::from_bits(0x0000_1400_1422_4080),
-    Bitboard::from_bits(0x0000_1400_1422_4000),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0100),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1422_0000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_4080),
-    Bitboard::from_bits(0x0000_1400_1420_4000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1420_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0100),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1402_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x0000_1400_1400_0000),
-    Bitboard::from_bits(0x8244_2800_2844_8201),
-    Bitboard::from_bits(0x8244_2800_2844_8200),
-    Bitboard::from_bits(0x8244_2800_2844_8000),
-    Bitboard::from_bits(0x8244_2800_2844_8000),
-    Bitboard::from_bits(0x8244_2800_2844_0201),
-    Bitboard::from_bits(0x8244_2800_2844_0200),
-    Bitboard::from_bits(0x8244_2800_2844_0000),
-    Bitboard::from_bits(0x8244_2800_2844_0000),
-    Bitboard::from_bits(0x8244_2800_2840_8000),
-    Bitboard::from_bits(0x8244_2800_2840_8000),
-    Bitboard::from_bits(0x8244_2800_2840_8000),
-    Bitboard::from_bits(0x8244_2800_2840_8000),
-    Bitboard::from_bits(0x8244_2800_2840_0000),
-    Bitboard::from_bits(0x8244_2800_2840_0000),
-    Bitboard::from_bits(0x8244_2800_2840_0000),
-    Bitboard::from_bits(0x8244_2800_2840_0000),
-    Bitboard::from_bits(0x8244_2800_2804_0201),
-    Bitboard::from_bits(0x8244_2800_2804_0200),
-    Bitboard::from_bits(0x8244_2800_2804_0000),
-    Bitboard::from_bits(0x8244_2800_2804_0000),
-    Bitboard::from_bits(0x8244_2800_2804_0201),
-    Bitboard::from_bits(0x8244_2800_2804_0200),
-    Bitboard::from_bits(0x8244_2800_2804_0000),
-    Bitboard::from_bits(0x8244_2800_2804_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8244_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2844_8201),
-    Bitboard::from_bits(0x8040_2800_2844_8200),
-    Bitboard::from_bits(0x8040_2800_2844_8000),
-    Bitboard::from_bits(0x8040_2800_2844_8000),
-    Bitboard::from_bits(0x8040_2800_2844_0201),
-    Bitboard::from_bits(0x8040_2800_2844_0200),
-    Bitboard::from_bits(0x8040_2800_2844_0000),
-    Bitboard::from_bits(0x8040_2800_2844_0000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0201),
-    Bitboard::from_bits(0x8040_2800_2804_0200),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0201),
-    Bitboard::from_bits(0x8040_2800_2804_0200),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2844_8201),
-    Bitboard::from_bits(0x0204_2800_2844_8200),
-    Bitboard::from_bits(0x0204_2800_2844_8000),
-    Bitboard::from_bits(0x0204_2800_2844_8000),
-    Bitboard::from_bits(0x0204_2800_2844_0201),
-    Bitboard::from_bits(0x0204_2800_2844_0200),
-    Bitboard::from_bits(0x0204_2800_2844_0000),
-    Bitboard::from_bits(0x0204_2800_2844_0000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0201),
-    Bitboard::from_bits(0x0204_2800_2804_0200),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0201),
-    Bitboard::from_bits(0x0204_2800_2804_0200),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2844_8201),
-    Bitboard::from_bits(0x0000_2800_2844_8200),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_0201),
-    Bitboard::from_bits(0x0000_2800_2844_0200),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2844_8201),
-    Bitboard::from_bits(0x8044_2800_2844_8200),
-    Bitboard::from_bits(0x8044_2800_2844_8000),
-    Bitboard::from_bits(0x8044_2800_2844_8000),
-    Bitboard::from_bits(0x8044_2800_2844_0201),
-    Bitboard::from_bits(0x8044_2800_2844_0200),
-    Bitboard::from_bits(0x8044_2800_2844_0000),
-    Bitboard::from_bits(0x8044_2800_2844_0000),
-    Bitboard::from_bits(0x8044_2800_2840_8000),
-    Bitboard::from_bits(0x8044_2800_2840_8000),
-    Bitboard::from_bits(0x8044_2800_2840_8000),
-    Bitboard::from_bits(0x8044_2800_2840_8000),
-    Bitboard::from_bits(0x8044_2800_2840_0000),
-    Bitboard::from_bits(0x8044_2800_2840_0000),
-    Bitboard::from_bits(0x8044_2800_2840_0000),
-    Bitboard::from_bits(0x8044_2800_2840_0000),
-    Bitboard::from_bits(0x8044_2800_2804_0201),
-    Bitboard::from_bits(0x8044_2800_2804_0200),
-    Bitboard::from_bits(0x8044_2800_2804_0000),
-    Bitboard::from_bits(0x8044_2800_2804_0000),
-    Bitboard::from_bits(0x8044_2800_2804_0201),
-    Bitboard::from_bits(0x8044_2800_2804_0200),
-    Bitboard::from_bits(0x8044_2800_2804_0000),
-    Bitboard::from_bits(0x8044_2800_2804_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8044_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2844_8201),
-    Bitboard::from_bits(0x8040_2800_2844_8200),
-    Bitboard::from_bits(0x8040_2800_2844_8000),
-    Bitboard::from_bits(0x8040_2800_2844_8000),
-    Bitboard::from_bits(0x8040_2800_2844_0201),
-    Bitboard::from_bits(0x8040_2800_2844_0200),
-    Bitboard::from_bits(0x8040_2800_2844_0000),
-    Bitboard::from_bits(0x8040_2800_2844_0000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_8000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2840_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0201),
-    Bitboard::from_bits(0x8040_2800_2804_0200),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0201),
-    Bitboard::from_bits(0x8040_2800_2804_0200),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2804_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x8040_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2844_8201),
-    Bitboard::from_bits(0x0004_2800_2844_8200),
-    Bitboard::from_bits(0x0004_2800_2844_8000),
-    Bitboard::from_bits(0x0004_2800_2844_8000),
-    Bitboard::from_bits(0x0004_2800_2844_0201),
-    Bitboard::from_bits(0x0004_2800_2844_0200),
-    Bitboard::from_bits(0x0004_2800_2844_0000),
-    Bitboard::from_bits(0x0004_2800_2844_0000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0201),
-    Bitboard::from_bits(0x0004_2800_2804_0200),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0201),
-    Bitboard::from_bits(0x0004_2800_2804_0200),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2844_8201),
-    Bitboard::from_bits(0x0000_2800_2844_8200),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_0201),
-    Bitboard::from_bits(0x0000_2800_2844_0200),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2844_8201),
-    Bitboard::from_bits(0x0244_2800_2844_8200),
-    Bitboard::from_bits(0x0244_2800_2844_8000),
-    Bitboard::from_bits(0x0244_2800_2844_8000),
-    Bitboard::from_bits(0x0244_2800_2844_0201),
-    Bitboard::from_bits(0x0244_2800_2844_0200),
-    Bitboard::from_bits(0x0244_2800_2844_0000),
-    Bitboard::from_bits(0x0244_2800_2844_0000),
-    Bitboard::from_bits(0x0244_2800_2840_8000),
-    Bitboard::from_bits(0x0244_2800_2840_8000),
-    Bitboard::from_bits(0x0244_2800_2840_8000),
-    Bitboard::from_bits(0x0244_2800_2840_8000),
-    Bitboard::from_bits(0x0244_2800_2840_0000),
-    Bitboard::from_bits(0x0244_2800_2840_0000),
-    Bitboard::from_bits(0x0244_2800_2840_0000),
-    Bitboard::from_bits(0x0244_2800_2840_0000),
-    Bitboard::from_bits(0x0244_2800_2804_0201),
-    Bitboard::from_bits(0x0244_2800_2804_0200),
-    Bitboard::from_bits(0x0244_2800_2804_0000),
-    Bitboard::from_bits(0x0244_2800_2804_0000),
-    Bitboard::from_bits(0x0244_2800_2804_0201),
-    Bitboard::from_bits(0x0244_2800_2804_0200),
-    Bitboard::from_bits(0x0244_2800_2804_0000),
-    Bitboard::from_bits(0x0244_2800_2804_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0244_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2844_8201),
-    Bitboard::from_bits(0x0040_2800_2844_8200),
-    Bitboard::from_bits(0x0040_2800_2844_8000),
-    Bitboard::from_bits(0x0040_2800_2844_8000),
-    Bitboard::from_bits(0x0040_2800_2844_0201),
-    Bitboard::from_bits(0x0040_2800_2844_0200),
-    Bitboard::from_bits(0x0040_2800_2844_0000),
-    Bitboard::from_bits(0x0040_2800_2844_0000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0201),
-    Bitboard::from_bits(0x0040_2800_2804_0200),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0201),
-    Bitboard::from_bits(0x0040_2800_2804_0200),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2844_8201),
-    Bitboard::from_bits(0x0204_2800_2844_8200),
-    Bitboard::from_bits(0x0204_2800_2844_8000),
-    Bitboard::from_bits(0x0204_2800_2844_8000),
-    Bitboard::from_bits(0x0204_2800_2844_0201),
-    Bitboard::from_bits(0x0204_2800_2844_0200),
-    Bitboard::from_bits(0x0204_2800_2844_0000),
-    Bitboard::from_bits(0x0204_2800_2844_0000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_8000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2840_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0201),
-    Bitboard::from_bits(0x0204_2800_2804_0200),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0201),
-    Bitboard::from_bits(0x0204_2800_2804_0200),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2804_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0204_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2844_8201),
-    Bitboard::from_bits(0x0000_2800_2844_8200),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_0201),
-    Bitboard::from_bits(0x0000_2800_2844_0200),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2844_8201),
-    Bitboard::from_bits(0x0044_2800_2844_8200),
-    Bitboard::from_bits(0x0044_2800_2844_8000),
-    Bitboard::from_bits(0x0044_2800_2844_8000),
-    Bitboard::from_bits(0x0044_2800_2844_0201),
-    Bitboard::from_bits(0x0044_2800_2844_0200),
-    Bitboard::from_bits(0x0044_2800_2844_0000),
-    Bitboard::from_bits(0x0044_2800_2844_0000),
-    Bitboard::from_bits(0x0044_2800_2840_8000),
-    Bitboard::from_bits(0x0044_2800_2840_8000),
-    Bitboard::from_bits(0x0044_2800_2840_8000),
-    Bitboard::from_bits(0x0044_2800_2840_8000),
-    Bitboard::from_bits(0x0044_2800_2840_0000),
-    Bitboard::from_bits(0x0044_2800_2840_0000),
-    Bitboard::from_bits(0x0044_2800_2840_0000),
-    Bitboard::from_bits(0x0044_2800_2840_0000),
-    Bitboard::from_bits(0x0044_2800_2804_0201),
-    Bitboard::from_bits(0x0044_2800_2804_0200),
-    Bitboard::from_bits(0x0044_2800_2804_0000),
-    Bitboard::from_bits(0x0044_2800_2804_0000),
-    Bitboard::from_bits(0x0044_2800_2804_0201),
-    Bitboard::from_bits(0x0044_2800_2804_0200),
-    Bitboard::from_bits(0x0044_2800_2804_0000),
-    Bitboard::from_bits(0x0044_2800_2804_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0044_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2844_8201),
-    Bitboard::from_bits(0x0040_2800_2844_8200),
-    Bitboard::from_bits(0x0040_2800_2844_8000),
-    Bitboard::from_bits(0x0040_2800_2844_8000),
-    Bitboard::from_bits(0x0040_2800_2844_0201),
-    Bitboard::from_bits(0x0040_2800_2844_0200),
-    Bitboard::from_bits(0x0040_2800_2844_0000),
-    Bitboard::from_bits(0x0040_2800_2844_0000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_8000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2840_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0201),
-    Bitboard::from_bits(0x0040_2800_2804_0200),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0201),
-    Bitboard::from_bits(0x0040_2800_2804_0200),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2804_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0040_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2844_8201),
-    Bitboard::from_bits(0x0004_2800_2844_8200),
-    Bitboard::from_bits(0x0004_2800_2844_8000),
-    Bitboard::from_bits(0x0004_2800_2844_8000),
-    Bitboard::from_bits(0x0004_2800_2844_0201),
-    Bitboard::from_bits(0x0004_2800_2844_0200),
-    Bitboard::from_bits(0x0004_2800_2844_0000),
-    Bitboard::from_bits(0x0004_2800_2844_0000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_8000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2840_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0201),
-    Bitboard::from_bits(0x0004_2800_2804_0200),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0201),
-    Bitboard::from_bits(0x0004_2800_2804_0200),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2804_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0004_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2844_8201),
-    Bitboard::from_bits(0x0000_2800_2844_8200),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_8000),
-    Bitboard::from_bits(0x0000_2800_2844_0201),
-    Bitboard::from_bits(0x0000_2800_2844_0200),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2844_0000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_8000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2840_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0201),
-    Bitboard::from_bits(0x0000_2800_2804_0200),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2804_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0000_2800_2800_0000),
-    Bitboard::from_bits(0x0488_5000_5088_0402),
-    Bitboard::from_bits(0x0488_5000_5088_0400),
-    Bitboard::from_bits(0x0488_5000_5088_0000),
-    Bitboard::from_bits(0x0488_5000_5088_0000),
-    Bitboard::from_bits(0x0488_5000_5080_0000),
-    Bitboard::from_bits(0x0488_5000_5080_0000),
-    Bitboard::from_bits(0x0488_5000_5080_0000),
-    Bitboard::from_bits(0x0488_5000_5080_0000),
-    Bitboard::from_bits(0x0488_5000_5008_0402),
-    Bitboard::from_bits(0x0488_5000_5008_0400),
-    Bitboard::from_bits(0x0488_5000_5008_0000),
-    Bitboard::from_bits(0x0488_5000_5008_0000),
-    Bitboard::from_bits(0x0488_5000_5000_0000),
-    Bitboard::from_bits(0x0488_5000_5000_0000),
-    Bitboard::from_bits(0x0488_5000_5000_0000),
-    Bitboard::from_bits(0x0488_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5088_0402),
-    Bitboard::from_bits(0x0080_5000_5088_0400),
-    Bitboard::from_bits(0x0080_5000_5088_0000),
-    Bitboard::from_bits(0x0080_5000_5088_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5008_0402),
-    Bitboard::from_bits(0x0080_5000_5008_0400),
-    Bitboard::from_bits(0x0080_5000_5008_0000),
-    Bitboard::from_bits(0x0080_5000_5008_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0408_5000_5088_0402),
-    Bitboard::from_bits(0x0408_5000_5088_0400),
-    Bitboard::from_bits(0x0408_5000_5088_0000),
-    Bitboard::from_bits(0x0408_5000_5088_0000),
-    Bitboard::from_bits(0x0408_5000_5080_0000),
-    Bitboard::from_bits(0x0408_5000_5080_0000),
-    Bitboard::from_bits(0x0408_5000_5080_0000),
-    Bitboard::from_bits(0x0408_5000_5080_0000),
-    Bitboard::from_bits(0x0408_5000_5008_0402),
-    Bitboard::from_bits(0x0408_5000_5008_0400),
-    Bitboard::from_bits(0x0408_5000_5008_0000),
-    Bitboard::from_bits(0x0408_5000_5008_0000),
-    Bitboard::from_bits(0x0408_5000_5000_0000),
-    Bitboard::from_bits(0x0408_5000_5000_0000),
-    Bitboard::from_bits(0x0408_5000_5000_0000),
-    Bitboard::from_bits(0x0408_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5088_0402),
-    Bitboard::from_bits(0x0000_5000_5088_0400),
-    Bitboard::from_bits(0x0000_5000_5088_0000),
-    Bitboard::from_bits(0x0000_5000_5088_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5008_0402),
-    Bitboard::from_bits(0x0000_5000_5008_0400),
-    Bitboard::from_bits(0x0000_5000_5008_0000),
-    Bitboard::from_bits(0x0000_5000_5008_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0088_5000_5088_0402),
-    Bitboard::from_bits(0x0088_5000_5088_0400),
-    Bitboard::from_bits(0x0088_5000_5088_0000),
-    Bitboard::from_bits(0x0088_5000_5088_0000),
-    Bitboard::from_bits(0x0088_5000_5080_0000),
-    Bitboard::from_bits(0x0088_5000_5080_0000),
-    Bitboard::from_bits(0x0088_5000_5080_0000),
-    Bitboard::from_bits(0x0088_5000_5080_0000),
-    Bitboard::from_bits(0x0088_5000_5008_0402),
-    Bitboard::from_bits(0x0088_5000_5008_0400),
-    Bitboard::from_bits(0x0088_5000_5008_0000),
-    Bitboard::from_bits(0x0088_5000_5008_0000),
-    Bitboard::from_bits(0x0088_5000_5000_0000),
-    Bitboard::from_bits(0x0088_5000_5000_0000),
-    Bitboard::from_bits(0x0088_5000_5000_0000),
-    Bitboard::from_bits(0x0088_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5088_0402),
-    Bitboard::from_bits(0x0080_5000_5088_0400),
-    Bitboard::from_bits(0x0080_5000_5088_0000),
-    Bitboard::from_bits(0x0080_5000_5088_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5080_0000),
-    Bitboard::from_bits(0x0080_5000_5008_0402),
-    Bitboard::from_bits(0x0080_5000_5008_0400),
-    Bitboard::from_bits(0x0080_5000_5008_0000),
-    Bitboard::from_bits(0x0080_5000_5008_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0080_5000_5000_0000),
-    Bitboard::from_bits(0x0008_5000_5088_0402),
-    Bitboard::from_bits(0x0008_5000_5088_0400),
-    Bitboard::from_bits(0x0008_5000_5088_0000),
-    Bitboard::from_bits(0x0008_5000_5088_0000),
-    Bitboard::from_bits(0x0008_5000_5080_0000),
-    Bitboard::from_bits(0x0008_5000_5080_0000),
-    Bitboard::from_bits(0x0008_5000_5080_0000),
-    Bitboard::from_bits(0x0008_5000_5080_0000),
-    Bitboard::from_bits(0x0008_5000_5008_0402),
-    Bitboard::from_bits(0x0008_5000_5008_0400),
-    Bitboard::from_bits(0x0008_5000_5008_0000),
-    Bitboard::from_bits(0x0008_5000_5008_0000),
-    Bitboard::from_bits(0x0008_5000_5000_0000),
-    Bitboard::from_bits(0x0008_5000_5000_0000),
-    Bitboard::from_bits(0x0008_5000_5000_0000),
-    Bitboard::from_bits(0x0008_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5088_0402),
-    Bitboard::from_bits(0x0000_5000_5088_0400),
-    Bitboard::from_bits(0x0000_5000_5088_0000),
-    Bitboard::from_bits(0x0000_5000_5088_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5080_0000),
-    Bitboard::from_bits(0x0000_5000_5008_0402),
-    Bitboard::from_bits(0x0000_5000_5008_0400),
-    Bitboard::from_bits(0x0000_5000_5008_0000),
-    Bitboard::from_bits(0x0000_5000_5008_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0000_5000_5000_0000),
-    Bitboard::from_bits(0x0810_a000_a010_0804),
-    Bitboard::from_bits(0x0810_a000_a010_0800),
-    Bitboard::from_bits(0x0810_a000_a010_0000),
-    Bitboard::from_bits(0x0810_a000_a010_0000),
-    Bitboard::from_bits(0x0810_a000_a000_0000),
-    Bitboard::from_bits(0x0810_a000_a000_0000),
-    Bitboard::from_bits(0x0810_a000_a000_0000),
-    Bitboard::from_bits(0x0810_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a010_0804),
-    Bitboard::from_bits(0x0000_a000_a010_0800),
-    Bitboard::from_bits(0x0000_a000_a010_0000),
-    Bitboard::from_bits(0x0000_a000_a010_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0010_a000_a010_0804),
-    Bitboard::from_bits(0x0010_a000_a010_0800),
-    Bitboard::from_bits(0x0010_a000_a010_0000),
-    Bitboard::from_bits(0x0010_a000_a010_0000),
-    Bitboard::from_bits(0x0010_a000_a000_0000),
-    Bitboard::from_bits(0x0010_a000_a000_0000),
-    Bitboard::from_bits(0x0010_a000_a000_0000),
-    Bitboard::from_bits(0x0010_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a010_0804),
-    Bitboard::from_bits(0x0000_a000_a010_0800),
-    Bitboard::from_bits(0x0000_a000_a010_0000),
-    Bitboard::from_bits(0x0000_a000_a010_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x0000_a000_a000_0000),
-    Bitboard::from_bits(0x1020_4000_4020_1008),
-    Bitboard::from_bits(0x1020_4000_4020_1000),
-    Bitboard::from_bits(0x1020_4000_4020_0000),
-    Bitboard::from_bits(0x1020_4000_4020_0000),
-    Bitboard::from_bits(0x1020_4000_4000_0000),
-    Bitboard::from_bits(0x1020_4000_4000_0000),
-    Bitboard::from_bits(0x1020_4000_4000_0000),
-    Bitboard::from_bits(0x1020_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4020_1008),
-    Bitboard::from_bits(0x0000_4000_4020_1000),
-    Bitboard::from_bits(0x0000_4000_4020_0000),
-    Bitboard::from_bits(0x0000_4000_4020_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0020_4000_4020_1008),
-    Bitboard::from_bits(0x0020_4000_4020_1000),
-    Bitboard::from_bits(0x0020_4000_4020_0000),
-    Bitboard::from_bits(0x0020_4000_4020_0000),
-    Bitboard::from_bits(0x0020_4000_4000_0000),
-    Bitboard::from_bits(0x0020_4000_4000_0000),
-    Bitboard::from_bits(0x0020_4000_4000_0000),
-    Bitboard::from_bits(0x0020_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4020_1008),
-    Bitboard::from_bits(0x0000_4000_4020_1000),
-    Bitboard::from_bits(0x0000_4000_4020_0000),
-    Bitboard::from_bits(0x0000_4000_4020_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0000_4000_4000_0000),
-    Bitboard::from_bits(0x0402_0002_0408_1020),
-    Bitboard::from_bits(0x0402_0002_0408_1000),
-    Bitboard::from_bits(0x0402_0002_0408_0000),
-    Bitboard::from_bits(0x0402_0002_0408_0000),
-    Bitboard::from_bits(0x0402_0002_0400_0000),
-    Bitboard::from_bits(0x0402_0002_0400_0000),
-    Bitboard::from_bits(0x0402_0002_0400_0000),
-    Bitboard::from_bits(0x0402_0002_0400_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0402_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0408_1020),
-    Bitboard::from_bits(0x0002_0002_0408_1000),
-    Bitboard::from_bits(0x0002_0002_0408_0000),
-    Bitboard::from_bits(0x0002_0002_0408_0000),
-    Bitboard::from_bits(0x0002_0002_0400_0000),
-    Bitboard::from_bits(0x0002_0002_0400_0000),
-    Bitboard::from_bits(0x0002_0002_0400_0000),
-    Bitboard::from_bits(0x0002_0002_0400_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0002_0002_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0810_2040),
-    Bitboard::from_bits(0x0805_0005_0810_2000),
-    Bitboard::from_bits(0x0805_0005_0810_0000),
-    Bitboard::from_bits(0x0805_0005_0810_0000),
-    Bitboard::from_bits(0x0805_0005_0800_0000),
-    Bitboard::from_bits(0x0805_0005_0800_0000),
-    Bitboard::from_bits(0x0805_0005_0800_0000),
-    Bitboard::from_bits(0x0805_0005_0800_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0805_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0810_2040),
-    Bitboard::from_bits(0x0005_0005_0810_2000),
-    Bitboard::from_bits(0x0005_0005_0810_0000),
-    Bitboard::from_bits(0x0005_0005_0810_0000),
-    Bitboard::from_bits(0x0005_0005_0800_0000),
-    Bitboard::from_bits(0x0005_0005_0800_0000),
-    Bitboard::from_bits(0x0005_0005_0800_0000),
-    Bitboard::from_bits(0x0005_0005_0800_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x0005_0005_0000_0000),
-    Bitboard::from_bits(0x110a_000a_1120_4080),
-    Bitboard::from_bits(0x110a_000a_1120_4000),
-    Bitboard::from_bits(0x110a_000a_1120_0000),
-    Bitboard::from_bits(0x110a_000a_1120_0000),
-    Bitboard::from_bits(0x110a_000a_1100_0000),
-    Bitboard::from_bits(0x110a_000a_1100_0000),
-    Bitboard::from_bits(0x110a_000a_1100_0000),
-    Bitboard::from_bits(0x110a_000a_1100_0000),
-    Bitboard::from_bits(0x110a_000a_1020_4080),
-    Bitboard::from_bits(0x110a_000a_1020_4000),
-    Bitboard::from_bits(0x110a_000a_1020_0000),
-    Bitboard::from_bits(0x110a_000a_1020_0000),
-    Bitboard::from_bits(0x110a_000a_1000_0000),
-    Bitboard::from_bits(0x110a_000a_1000_0000),
-    Bitboard::from_bits(0x110a_000a_1000_0000),
-    Bitboard::from_bits(0x110a_000a_1000_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0100_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x110a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_1120_4080),
-    Bitboard::from_bits(0x100a_000a_1120_4000),
-    Bitboard::from_bits(0x100a_000a_1120_0000),
-    Bitboard::from_bits(0x100a_000a_1120_0000),
-    Bitboard::from_bits(0x100a_000a_1100_0000),
-    Bitboard::from_bits(0x100a_000a_1100_0000),
-    Bitboard::from_bits(0x100a_000a_1100_0000),
-    Bitboard::from_bits(0x100a_000a_1100_0000),
-    Bitboard::from_bits(0x100a_000a_1020_4080),
-    Bitboard::from_bits(0x100a_000a_1020_4000),
-    Bitboard::from_bits(0x100a_000a_1020_0000),
-    Bitboard::from_bits(0x100a_000a_1020_0000),
-    Bitboard::from_bits(0x100a_000a_1000_0000),
-    Bitboard::from_bits(0x100a_000a_1000_0000),
-    Bitboard::from_bits(0x100a_000a_1000_0000),
-    Bitboard::from_bits(0x100a_000a_1000_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0100_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x100a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_1120_4080),
-    Bitboard::from_bits(0x010a_000a_1120_4000),
-    Bitboard::from_bits(0x010a_000a_1120_0000),
-    Bitboard::from_bits(0x010a_000a_1120_0000),
-    Bitboard::from_bits(0x010a_000a_1100_0000),
-    Bitboard::from_bits(0x010a_000a_1100_0000),
-    Bitboard::from_bits(0x010a_000a_1100_0000),
-    Bitboard::from_bits(0x010a_000a_1100_0000),
-    Bitboard::from_bits(0x010a_000a_1020_4080),
-    Bitboard::from_bits(0x010a_000a_1020_4000),
-    Bitboard::from_bits(0x010a_000a_1020_0000),
-    Bitboard::from_bits(0x010a_000a_1020_0000),
-    Bitboard::from_bits(0x010a_000a_1000_0000),
-    Bitboard::from_bits(0x010a_000a_1000_0000),
-    Bitboard::from_bits(0x010a_000a_1000_0000),
-    Bitboard::from_bits(0x010a_000a_1000_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0100_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x010a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_1120_4080),
-    Bitboard::from_bits(0x000a_000a_1120_4000),
-    Bitboard::from_bits(0x000a_000a_1120_0000),
-    Bitboard::from_bits(0x000a_000a_1120_0000),
-    Bitboard::from_bits(0x000a_000a_1100_0000),
-    Bitboard::from_bits(0x000a_000a_1100_0000),
-    Bitboard::from_bits(0x000a_000a_1100_0000),
-    Bitboard::from_bits(0x000a_000a_1100_0000),
-    Bitboard::from_bits(0x000a_000a_1020_4080),
-    Bitboard::from_bits(0x000a_000a_1020_4000),
-    Bitboard::from_bits(0x000a_000a_1020_0000),
-    Bitboard::from_bits(0x000a_000a_1020_0000),
-    Bitboard::from_bits(0x000a_000a_1000_0000),
-    Bitboard::from_bits(0x000a_000a_1000_0000),
-    Bitboard::from_bits(0x000a_000a_1000_0000),
-    Bitboard::from_bits(0x000a_000a_1000_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0100_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x000a_000a_0000_0000),
-    Bitboard::from_bits(0x2214_0014_2241_8000),
-    Bitboard::from_bits(0x2214_0014_2241_0000),
-    Bitboard::from_bits(0x2214_0014_2240_8000),
-    Bitboard::from_bits(0x2214_0014_2240_0000),
-    Bitboard::from_bits(0x2214_0014_2201_0000),
-    Bitboard::from_bits(0x2214_0014_2201_0000),
-    Bitboard::from_bits(0x2214_0014_2200_0000),
-    Bitboard::from_bits(0x2214_0014_2200_0000),
-    Bitboard::from_bits(0x2214_0014_2040_8000),
-    Bitboard::from_bits(0x2214_0014_2040_0000),
-    Bitboard::from_bits(0x2214_0014_2040_8000),
-    Bitboard::from_bits(0x2214_0014_2040_0000),
-    Bitboard::from_bits(0x2214_0014_2000_0000),
-    Bitboard::from_bits(0x2214_0014_2000_0000),
-    Bitboard::from_bits(0x2214_0014_2000_0000),
-    Bitboard::from_bits(0x2214_0014_2000_0000),
-    Bitboard::from_bits(0x2214_0014_0201_0000),
-    Bitboard::from_bits(0x2214_0014_0201_0000),
-    Bitboard::from_bits(0x2214_0014_0200_0000),
-    Bitboard::from_bits(0x2214_0014_0200_0000),
-    Bitboard::from_bits(0x2214_0014_0201_0000),
-    Bitboard::from_bits(0x2214_0014_0201_0000),
-    Bitboard::from_bits(0x2214_0014_0200_0000),
-    Bitboard::from_bits(0x2214_0014_0200_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2214_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_2241_8000),
-    Bitboard::from_bits(0x2014_0014_2241_0000),
-    Bitboard::from_bits(0x2014_0014_2240_8000),
-    Bitboard::from_bits(0x2014_0014_2240_0000),
-    Bitboard::from_bits(0x2014_0014_2201_0000),
-    Bitboard::from_bits(0x2014_0014_2201_0000),
-    Bitboard::from_bits(0x2014_0014_2200_0000),
-    Bitboard::from_bits(0x2014_0014_2200_0000),
-    Bitboard::from_bits(0x2014_0014_2040_8000),
-    Bitboard::from_bits(0x2014_0014_2040_0000),
-    Bitboard::from_bits(0x2014_0014_2040_8000),
-    Bitboard::from_bits(0x2014_0014_2040_0000),
-    Bitboard::from_bits(0x2014_0014_2000_0000),
-    Bitboard::from_bits(0x2014_0014_2000_0000),
-    Bitboard::from_bits(0x2014_0014_2000_0000),
-    Bitboard::from_bits(0x2014_0014_2000_0000),
-    Bitboard::from_bits(0x2014_0014_0201_0000),
-    Bitboard::from_bits(0x2014_0014_0201_0000),
-    Bitboard::from_bits(0x2014_0014_0200_0000),
-    Bitboard::from_bits(0x2014_0014_0200_0000),
-    Bitboard::from_bits(0x2014_0014_0201_0000),
-    Bitboard::from_bits(0x2014_0014_0201_0000),
-    Bitboard::from_bits(0x2014_0014_0200_0000),
-    Bitboard::from_bits(0x2014_0014_0200_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x2014_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_2241_8000),
-    Bitboard::from_bits(0x0214_0014_2241_0000),
-    Bitboard::from_bits(0x0214_0014_2240_8000),
-    Bitboard::from_bits(0x0214_0014_2240_0000),
-    Bitboard::from_bits(0x0214_0014_2201_0000),
-    Bitboard::from_bits(0x0214_0014_2201_0000),
-    Bitboard::from_bits(0x0214_0014_2200_0000),
-    Bitboard::from_bits(0x0214_0014_2200_0000),
-    Bitboard::from_bits(0x0214_0014_2040_8000),
-    Bitboard::from_bits(0x0214_0014_2040_0000),
-    Bitboard::from_bits(0x0214_0014_2040_8000),
-    Bitboard::from_bits(0x0214_0014_2040_0000),
-    Bitboard::from_bits(0x0214_0014_2000_0000),
-    Bitboard::from_bits(0x0214_0014_2000_0000),
-    Bitboard::from_bits(0x0214_0014_2000_0000),
-    Bitboard::from_bits(0x0214_0014_2000_0000),
-    Bitboard::from_bits(0x0214_0014_0201_0000),
-    Bitboard::from_bits(0x0214_0014_0201_0000),
-    Bitboard::from_bits(0x0214_0014_0200_0000),
-    Bitboard::from_bits(0x0214_0014_0200_0000),
-    Bitboard::from_bits(0x0214_0014_0201_0000),
-    Bitboard::from_bits(0x0214_0014_0201_0000),
-    Bitboard::from_bits(0x0214_0014_0200_0000),
-    Bitboard::from_bits(0x0214_0014_0200_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0214_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_2241_8000),
-    Bitboard::from_bits(0x0014_0014_2241_0000),
-    Bitboard::from_bits(0x0014_0014_2240_8000),
-    Bitboard::from_bits(0x0014_0014_2240_0000),
-    Bitboard::from_bits(0x0014_0014_2201_0000),
-    Bitboard::from_bits(0x0014_0014_2201_0000),
-    Bitboard::from_bits(0x0014_0014_2200_0000),
-    Bitboard::from_bits(0x0014_0014_2200_0000),
-    Bitboard::from_bits(0x0014_0014_2040_8000),
-    Bitboard::from_bits(0x0014_0014_2040_0000),
-    Bitboard::from_bits(0x0014_0014_2040_8000),
-    Bitboard::from_bits(0x0014_0014_2040_0000),
-    Bitboard::from_bits(0x0014_0014_2000_0000),
-    Bitboard::from_bits(0x0014_0014_2000_0000),
-    Bitboard::from_bits(0x0014_0014_2000_0000),
-    Bitboard::from_bits(0x0014_0014_2000_0000),
-    Bitboard::from_bits(0x0014_0014_0201_0000),
-    Bitboard::from_bits(0x0014_0014_0201_0000),
-    Bitboard::from_bits(0x0014_0014_0200_0000),
-    Bitboard::from_bits(0x0014_0014_0200_0000),
-    Bitboard::from_bits(0x0014_0014_0201_0000),
-    Bitboard::from_bits(0x0014_0014_0201_0000),
-    Bitboard::from_bits(0x0014_0014_0200_0000),
-    Bitboard::from_bits(0x0014_0014_0200_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x0014_0014_0000_0000),
-    Bitboard::from_bits(0x4428_0028_4482_0100),
-    Bitboard::from_bits(0x4428_0028_4482_0000),
-    Bitboard::from_bits(0x4428_0028_4480_0000),
-    Bitboard::from_bits(0x4428_0028_4480_0000),
-    Bitboard::from_bits(0x4428_0028_4402_0100),
-    Bitboard::from_bits(0x4428_0028_4402_0000),
-    Bitboard::from_bits(0x4428_0028_4400_0000),
-    Bitboard::from_bits(0x4428_0028_4400_0000),
-    Bitboard::from_bits(0x4428_0028_4080_0000),
-    Bitboard::from_bits(0x4428_0028_4080_0000),
-    Bitboard::from_bits(0x4428_0028_4080_0000),
-    Bitboard::from_bits(0x4428_0028_4080_0000),
-    Bitboard::from_bits(0x4428_0028_4000_0000),
-    Bitboard::from_bits(0x4428_0028_4000_0000),
-    Bitboard::from_bits(0x4428_0028_4000_0000),
-    Bitboard::from_bits(0x4428_0028_4000_0000),
-    Bitboard::from_bits(0x4428_0028_0402_0100),
-    Bitboard::from_bits(0x4428_0028_0402_0000),
-    Bitboard::from_bits(0x4428_0028_0400_0000),
-    Bitboard::from_bits(0x4428_0028_0400_0000),
-    Bitboard::from_bits(0x4428_0028_0402_0100),
-    Bitboard::from_bits(0x4428_0028_0402_0000),
-    Bitboard::from_bits(0x4428_0028_0400_0000),
-    Bitboard::from_bits(0x4428_0028_0400_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4428_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_4482_0100),
-    Bitboard::from_bits(0x4028_0028_4482_0000),
-    Bitboard::from_bits(0x4028_0028_4480_0000),
-    Bitboard::from_bits(0x4028_0028_4480_0000),
-    Bitboard::from_bits(0x4028_0028_4402_0100),
-    Bitboard::from_bits(0x4028_0028_4402_0000),
-    Bitboard::from_bits(0x4028_0028_4400_0000),
-    Bitboard::from_bits(0x4028_0028_4400_0000),
-    Bitboard::from_bits(0x4028_0028_4080_0000),
-    Bitboard::from_bits(0x4028_0028_4080_0000),
-    Bitboard::from_bits(0x4028_0028_4080_0000),
-    Bitboard::from_bits(0x4028_0028_4080_0000),
-    Bitboard::from_bits(0x4028_0028_4000_0000),
-    Bitboard::from_bits(0x4028_0028_4000_0000),
-    Bitboard::from_bits(0x4028_0028_4000_0000),
-    Bitboard::from_bits(0x4028_0028_4000_0000),
-    Bitboard::from_bits(0x4028_0028_0402_0100),
-    Bitboard::from_bits(0x4028_0028_0402_0000),
-    Bitboard::from_bits(0x4028_0028_0400_0000),
-    Bitboard::from_bits(0x4028_0028_0400_0000),
-    Bitboard::from_bits(0x4028_0028_0402_0100),
-    Bitboard::from_bits(0x4028_0028_0402_0000),
-    Bitboard::from_bits(0x4028_0028_0400_0000),
-    Bitboard::from_bits(0x4028_0028_0400_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x4028_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_4482_0100),
-    Bitboard::from_bits(0x0428_0028_4482_0000),
-    Bitboard::from_bits(0x0428_0028_4480_0000),
-    Bitboard::from_bits(0x0428_0028_4480_0000),
-    Bitboard::from_bits(0x0428_0028_4402_0100),
-    Bitboard::from_bits(0x0428_0028_4402_0000),
-    Bitboard::from_bits(0x0428_0028_4400_0000),
-    Bitboard::from_bits(0x0428_0028_4400_0000),
-    Bitboard::from_bits(0x0428_0028_4080_0000),
-    Bitboard::from_bits(0x0428_0028_4080_0000),
-    Bitboard::from_bits(0x0428_0028_4080_0000),
-    Bitboard::from_bits(0x0428_0028_4080_0000),
-    Bitboard::from_bits(0x0428_0028_4000_0000),
-    Bitboard::from_bits(0x0428_0028_4000_0000),
-    Bitboard::from_bits(0x0428_0028_4000_0000),
-    Bitboard::from_bits(0x0428_0028_4000_0000),
-    Bitboard::from_bits(0x0428_0028_0402_0100),
-    Bitboard::from_bits(0x0428_0028_0402_0000),
-    Bitboard::from_bits(0x0428_0028_0400_0000),
-    Bitboard::from_bits(0x0428_0028_0400_0000),
-    Bitboard::from_bits(0x0428_0028_0402_0100),
-    Bitboard::from_bits(0x0428_0028_0402_0000),
-    Bitboard::from_bits(0x0428_0028_0400_0000),
-    Bitboard::from_bits(0x0428_0028_0400_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0428_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_4482_0100),
-    Bitboard::from_bits(0x0028_0028_4482_0000),
-    Bitboard::from_bits(0x0028_0028_4480_0000),
-    Bitboard::from_bits(0x0028_0028_4480_0000),
-    Bitboard::from_bits(0x0028_0028_4402_0100),
-    Bitboard::from_bits(0x0028_0028_4402_0000),
-    Bitboard::from_bits(0x0028_0028_4400_0000),
-    Bitboard::from_bits(0x0028_0028_4400_0000),
-    Bitboard::from_bits(0x0028_0028_4080_0000),
-    Bitboard::from_bits(0x0028_0028_4080_0000),
-    Bitboard::from_bits(0x0028_0028_4080_0000),
-    Bitboard::from_bits(0x0028_0028_4080_0000),
-    Bitboard::from_bits(0x0028_0028_4000_0000),
-    Bitboard::from_bits(0x0028_0028_4000_0000),
-    Bitboard::from_bits(0x0028_0028_4000_0000),
-    Bitboard::from_bits(0x0028_0028_4000_0000),
-    Bitboard::from_bits(0x0028_0028_0402_0100),
-    Bitboard::from_bits(0x0028_0028_0402_0000),
-    Bitboard::from_bits(0x0028_0028_0400_0000),
-    Bitboard::from_bits(0x0028_0028_0400_0000),
-    Bitboard::from_bits(0x0028_0028_0402_0100),
-    Bitboard::from_bits(0x0028_0028_0402_0000),
-    Bitboard::from_bits(0x0028_0028_0400_0000),
-    Bitboard::from_bits(0x0028_0028_0400_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x0028_0028_0000_0000),
-    Bitboard::from_bits(0x8850_0050_8804_0201),
-    Bitboard::from_bits(0x8850_0050_8804_0200),
-    Bitboard::from_bits(0x8850_0050_8804_0000),
-    Bitboard::from_bits(0x8850_0050_8804_0000),
-    Bitboard::from_bits(0x8850_0050_8800_0000),
-    Bitboard::from_bits(0x8850_0050_8800_0000),
-    Bitboard::from_bits(0x8850_0050_8800_0000),
-    Bitboard::from_bits(0x8850_0050_8800_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_8000_0000),
-    Bitboard::from_bits(0x8850_0050_0804_0201),
-    Bitboard::from_bits(0x8850_0050_0804_0200),
-    Bitboard::from_bits(0x8850_0050_0804_0000),
-    Bitboard::from_bits(0x8850_0050_0804_0000),
-    Bitboard::from_bits(0x8850_0050_0800_0000),
-    Bitboard::from_bits(0x8850_0050_0800_0000),
-    Bitboard::from_bits(0x8850_0050_0800_0000),
-    Bitboard::from_bits(0x8850_0050_0800_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8850_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_8804_0201),
-    Bitboard::from_bits(0x8050_0050_8804_0200),
-    Bitboard::from_bits(0x8050_0050_8804_0000),
-    Bitboard::from_bits(0x8050_0050_8804_0000),
-    Bitboard::from_bits(0x8050_0050_8800_0000),
-    Bitboard::from_bits(0x8050_0050_8800_0000),
-    Bitboard::from_bits(0x8050_0050_8800_0000),
-    Bitboard::from_bits(0x8050_0050_8800_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_8000_0000),
-    Bitboard::from_bits(0x8050_0050_0804_0201),
-    Bitboard::from_bits(0x8050_0050_0804_0200),
-    Bitboard::from_bits(0x8050_0050_0804_0000),
-    Bitboard::from_bits(0x8050_0050_0804_0000),
-    Bitboard::from_bits(0x8050_0050_0800_0000),
-    Bitboard::from_bits(0x8050_0050_0800_0000),
-    Bitboard::from_bits(0x8050_0050_0800_0000),
-    Bitboard::from_bits(0x8050_0050_0800_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x8050_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_8804_0201),
-    Bitboard::from_bits(0x0850_0050_8804_0200),
-    Bitboard::from_bits(0x0850_0050_8804_0000),
-    Bitboard::from_bits(0x0850_0050_8804_0000),
-    Bitboard::from_bits(0x0850_0050_8800_0000),
-    Bitboard::from_bits(0x0850_0050_8800_0000),
-    Bitboard::from_bits(0x0850_0050_8800_0000),
-    Bitboard::from_bits(0x0850_0050_8800_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_8000_0000),
-    Bitboard::from_bits(0x0850_0050_0804_0201),
-    Bitboard::from_bits(0x0850_0050_0804_0200),
-    Bitboard::from_bits(0x0850_0050_0804_0000),
-    Bitboard::from_bits(0x0850_0050_0804_0000),
-    Bitboard::from_bits(0x0850_0050_0800_0000),
-    Bitboard::from_bits(0x0850_0050_0800_0000),
-    Bitboard::from_bits(0x0850_0050_0800_0000),
-    Bitboard::from_bits(0x0850_0050_0800_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0850_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_8804_0201),
-    Bitboard::from_bits(0x0050_0050_8804_0200),
-    Bitboard::from_bits(0x0050_0050_8804_0000),
-    Bitboard::from_bits(0x0050_0050_8804_0000),
-    Bitboard::from_bits(0x0050_0050_8800_0000),
-    Bitboard::from_bits(0x0050_0050_8800_0000),
-    Bitboard::from_bits(0x0050_0050_8800_0000),
-    Bitboard::from_bits(0x0050_0050_8800_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_8000_0000),
-    Bitboard::from_bits(0x0050_0050_0804_0201),
-    Bitboard::from_bits(0x0050_0050_0804_0200),
-    Bitboard::from_bits(0x0050_0050_0804_0000),
-    Bitboard::from_bits(0x0050_0050_0804_0000),
-    Bitboard::from_bits(0x0050_0050_0800_0000),
-    Bitboard::from_bits(0x0050_0050_0800_0000),
-    Bitboard::from_bits(0x0050_0050_0800_0000),
-    Bitboard::from_bits(0x0050_0050_0800_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x0050_0050_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_1008_0402),
-    Bitboard::from_bits(0x10a0_00a0_1008_0400),
-    Bitboard::from_bits(0x10a0_00a0_1008_0000),
-    Bitboard::from_bits(0x10a0_00a0_1008_0000),
-    Bitboard::from_bits(0x10a0_00a0_1000_0000),
-    Bitboard::from_bits(0x10a0_00a0_1000_0000),
-    Bitboard::from_bits(0x10a0_00a0_1000_0000),
-    Bitboard::from_bits(0x10a0_00a0_1000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x10a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_1008_0402),
-    Bitboard::from_bits(0x00a0_00a0_1008_0400),
-    Bitboard::from_bits(0x00a0_00a0_1008_0000),
-    Bitboard::from_bits(0x00a0_00a0_1008_0000),
-    Bitboard::from_bits(0x00a0_00a0_1000_0000),
-    Bitboard::from_bits(0x00a0_00a0_1000_0000),
-    Bitboard::from_bits(0x00a0_00a0_1000_0000),
-    Bitboard::from_bits(0x00a0_00a0_1000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x00a0_00a0_0000_0000),
-    Bitboard::from_bits(0x2040_0040_2010_0804),
-    Bitboard::from_bits(0x2040_0040_2010_0800),
-    Bitboard::from_bits(0x2040_0040_2010_0000),
-    Bitboard::from_bits(0x2040_0040_2010_0000),
-    Bitboard::from_bits(0x2040_0040_2000_0000),
-    Bitboard::from_bits(0x2040_0040_2000_0000),
-    Bitboard::from_bits(0x2040_0040_2000_0000),
-    Bitboard::from_bits(0x2040_0040_2000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x2040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_2010_0804),
-    Bitboard::from_bits(0x0040_0040_2010_0800),
-    Bitboard::from_bits(0x0040_0040_2010_0000),
-    Bitboard::from_bits(0x0040_0040_2010_0000),
-    Bitboard::from_bits(0x0040_0040_2000_0000),
-    Bitboard::from_bits(0x0040_0040_2000_0000),
-    Bitboard::from_bits(0x0040_0040_2000_0000),
-    Bitboard::from_bits(0x0040_0040_2000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0040_0040_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0810_2040),
-    Bitboard::from_bits(0x0200_0204_0810_2000),
-    Bitboard::from_bits(0x0200_0204_0810_0000),
-    Bitboard::from_bits(0x0200_0204_0810_0000),
-    Bitboard::from_bits(0x0200_0204_0800_0000),
-    Bitboard::from_bits(0x0200_0204_0800_0000),
-    Bitboard::from_bits(0x0200_0204_0800_0000),
-    Bitboard::from_bits(0x0200_0204_0800_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0204_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0200_0200_0000_0000),
-    Bitboard::from_bits(0x0500_0508_1020_4080),
-    Bitboard::from_bits(0x0500_0508_1020_4000),
-    Bitboard::from_bits(0x0500_0508_1020_0000),
-    Bitboard::from_bits(0x0500_0508_1020_0000),
-    Bitboard::from_bits(0x0500_0508_1000_0000),
-    Bitboard::from_bits(0x0500_0508_1000_0000),
-    Bitboard::from_bits(0x0500_0508_1000_0000),
-    Bitboard::from_bits(0x0500_0508_1000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0508_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0500_0500_0000_0000),
-    Bitboard::from_bits(0x0a00_0a11_2040_8000),
-    Bitboard::from_bits(0x0a00_0a11_2040_0000),
-    Bitboard::from_bits(0x0a00_0a11_2000_0000),
-    Bitboard::from_bits(0x0a00_0a11_2000_0000),
-    Bitboard::from_bits(0x0a00_0a11_0000_0000),
-    Bitboard::from_bits(0x0a00_0a11_0000_0000),
-    Bitboard::from_bits(0x0a00_0a11_0000_0000),
-    Bitboard::from_bits(0x0a00_0a11_0000_0000),
-    Bitboard::from_bits(0x0a00_0a10_2040_8000),
-    Bitboard::from_bits(0x0a00_0a10_2040_0000),
-    Bitboard::from_bits(0x0a00_0a10_2000_0000),
-    Bitboard::from_bits(0x0a00_0a10_2000_0000),
-    Bitboard::from_bits(0x0a00_0a10_0000_0000),
-    Bitboard::from_bits(0x0a00_0a10_0000_0000),
-    Bitboard::from_bits(0x0a00_0a10_0000_0000),
-    Bitboard::from_bits(0x0a00_0a10_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a01_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x0a00_0a00_0000_0000),
-    Bitboard::from_bits(0x1400_1422_4180_0000),
-    Bitboard::from_bits(0x1400_1422_4100_0000),
-    Bitboard::from_bits(0x1400_1422_4080_0000),
-    Bitboard::from_bits(0x1400_1422_4000_0000),
-    Bitboard::from_bits(0x1400_1422_0100_0000),
-    Bitboard::from_bits(0x1400_1422_0100_0000),
-    Bitboard::from_bits(0x1400_1422_0000_0000),
-    Bitboard::from_bits(0x1400_1422_0000_0000),
-    Bitboard::from_bits(0x1400_1420_4080_0000),
-    Bitboard::from_bits(0x1400_1420_4000_0000),
-    Bitboard::from_bits(0x1400_1420_4080_0000),
-    Bitboard::from_bits(0x1400_1420_4000_0000),
-    Bitboard::from_bits(0x1400_1420_0000_0000),
-    Bitboard::from_bits(0x1400_1420_0000_0000),
-    Bitboard::from_bits(0x1400_1420_0000_0000),
-    Bitboard::from_bits(0x1400_1420_0000_0000),
-    Bitboard::from_bits(0x1400_1402_0100_0000),
-    Bitboard::from_bits(0x1400_1402_0100_0000),
-    Bitboard::from_bits(0x1400_1402_0000_0000),
-    Bitboard::from_bits(0x1400_1402_0000_0000),
-    Bitboard::from_bits(0x1400_1402_0100_0000),
-    Bitboard::from_bits(0x1400_1402_0100_0000),
-    Bitboard::from_bits(0x1400_1402_0000_0000),
-    Bitboard::from_bits(0x1400_1402_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x1400_1400_0000_0000),
-    Bitboard::from_bits(0x2800_2844_8201_0000),
-    Bitboard::from_bits(0x2800_2844_8200_0000),
-    Bitboard::from_bits(0x2800_2844_8000_0000),
-    Bitboard::from_bits(0x2800_2844_8000_0000),
-    Bitboard::from_bits(0x2800_2844_0201_0000),
-    Bitboard::from_bits(0x2800_2844_0200_0000),
-    Bitboard::from_bits(0x2800_2844_0000_0000),
-    Bitboard::from_bits(0x2800_2844_0000_0000),
-    Bitboard::from_bits(0x2800_2840_8000_0000),
-    Bitboard::from_bits(0x2800_2840_8000_0000),
-    Bitboard::from_bits(0x2800_2840_8000_0000),
-    Bitboard::from_bits(0x2800_2840_8000_0000),
-    Bitboard::from_bits(0x2800_2840_0000_0000),
-    Bitboard::from_bits(0x2800_2840_0000_0000),
-    Bitboard::from_bits(0x2800_2840_0000_0000),
-    Bitboard::from_bits(0x2800_2840_0000_0000),
-    Bitboard::from_bits(0x2800_2804_0201_0000),
-    Bitboard::from_bits(0x2800_2804_0200_0000),
-    Bitboard::from_bits(0x2800_2804_0000_0000),
-    Bitboard::from_bits(0x2800_2804_0000_0000),
-    Bitboard::from_bits(0x2800_2804_0201_0000),
-    Bitboard::from_bits(0x2800_2804_0200_0000),
-    Bitboard::from_bits(0x2800_2804_0000_0000),
-    Bitboard::from_bits(0x2800_2804_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x2800_2800_0000_0000),
-    Bitboard::from_bits(0x5000_5088_0402_0100),
-    Bitboard::from_bits(0x5000_5088_0402_0000),
-    Bitboard::from_bits(0x5000_5088_0400_0000),
-    Bitboard::from_bits(0x5000_5088_0400_0000),
-    Bitboard::from_bits(0x5000_5088_0000_0000),
-    Bitboard::from_bits(0x5000_5088_0000_0000),
-    Bitboard::from_bits(0x5000_5088_0000_0000),
-    Bitboard::from_bits(0x5000_5088_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5080_0000_0000),
-    Bitboard::from_bits(0x5000_5008_0402_0100),
-    Bitboard::from_bits(0x5000_5008_0402_0000),
-    Bitboard::from_bits(0x5000_5008_0400_0000),
-    Bitboard::from_bits(0x5000_5008_0400_0000),
-    Bitboard::from_bits(0x5000_5008_0000_0000),
-    Bitboard::from_bits(0x5000_5008_0000_0000),
-    Bitboard::from_bits(0x5000_5008_0000_0000),
-    Bitboard::from_bits(0x5000_5008_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0x5000_5000_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0804_0201),
-    Bitboard::from_bits(0xa000_a010_0804_0200),
-    Bitboard::from_bits(0xa000_a010_0804_0000),
-    Bitboard::from_bits(0xa000_a010_0804_0000),
-    Bitboard::from_bits(0xa000_a010_0800_0000),
-    Bitboard::from_bits(0xa000_a010_0800_0000),
-    Bitboard::from_bits(0xa000_a010_0800_0000),
-    Bitboard::from_bits(0xa000_a010_0800_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a010_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0xa000_a000_0000_0000),
-    Bitboard::from_bits(0x4000_4020_1008_0402),
-    Bitboard::from_bits(0x4000_4020_1008_0400),
-    Bitboard::from_bits(0x4000_4020_1008_0000),
-    Bitboard::from_bits(0x4000_4020_1008_0000),
-    Bitboard::from_bits(0x4000_4020_1000_0000),
-    Bitboard::from_bits(0x4000_4020_1000_0000),
-    Bitboard::from_bits(0x4000_4020_1000_0000),
-    Bitboard::from_bits(0x4000_4020_1000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4020_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x4000_4000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4080),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0810_2040_8000),
-    Bitboard::from_bits(0x0005_0810_2040_0000),
-    Bitboard::from_bits(0x0005_0810_2000_0000),
-    Bitboard::from_bits(0x0005_0810_2000_0000),
-    Bitboard::from_bits(0x0005_0810_0000_0000),
-    Bitboard::from_bits(0x0005_0810_0000_0000),
-    Bitboard::from_bits(0x0005_0810_0000_0000),
-    Bitboard::from_bits(0x0005_0810_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0800_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x000a_1120_4080_0000),
-    Bitboard::from_bits(0x000a_1120_4000_0000),
-    Bitboard::from_bits(0x000a_1120_0000_0000),
-    Bitboard::from_bits(0x000a_1120_0000_0000),
-    Bitboard::from_bits(0x000a_1100_0000_0000),
-    Bitboard::from_bits(0x000a_1100_0000_0000),
-    Bitboard::from_bits(0x000a_1100_0000_0000),
-    Bitboard::from_bits(0x000a_1100_0000_0000),
-    Bitboard::from_bits(0x000a_1020_4080_0000),
-    Bitboard::from_bits(0x000a_1020_4000_0000),
-    Bitboard::from_bits(0x000a_1020_0000_0000),
-    Bitboard::from_bits(0x000a_1020_0000_0000),
-    Bitboard::from_bits(0x000a_1000_0000_0000),
-    Bitboard::from_bits(0x000a_1000_0000_0000),
-    Bitboard::from_bits(0x000a_1000_0000_0000),
-    Bitboard::from_bits(0x000a_1000_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0100_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x0014_2241_8000_0000),
-    Bitboard::from_bits(0x0014_2241_0000_0000),
-    Bitboard::from_bits(0x0014_2240_8000_0000),
-    Bitboard::from_bits(0x0014_2240_0000_0000),
-    Bitboard::from_bits(0x0014_2201_0000_0000),
-    Bitboard::from_bits(0x0014_2201_0000_0000),
-    Bitboard::from_bits(0x0014_2200_0000_0000),
-    Bitboard::from_bits(0x0014_2200_0000_0000),
-    Bitboard::from_bits(0x0014_2040_8000_0000),
-    Bitboard::from_bits(0x0014_2040_0000_0000),
-    Bitboard::from_bits(0x0014_2040_8000_0000),
-    Bitboard::from_bits(0x0014_2040_0000_0000),
-    Bitboard::from_bits(0x0014_2000_0000_0000),
-    Bitboard::from_bits(0x0014_2000_0000_0000),
-    Bitboard::from_bits(0x0014_2000_0000_0000),
-    Bitboard::from_bits(0x0014_2000_0000_0000),
-    Bitboard::from_bits(0x0014_0201_0000_0000),
-    Bitboard::from_bits(0x0014_0201_0000_0000),
-    Bitboard::from_bits(0x0014_0200_0000_0000),
-    Bitboard::from_bits(0x0014_0200_0000_0000),
-    Bitboard::from_bits(0x0014_0201_0000_0000),
-    Bitboard::from_bits(0x0014_0201_0000_0000),
-    Bitboard::from_bits(0x0014_0200_0000_0000),
-    Bitboard::from_bits(0x0014_0200_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0028_4482_0100_0000),
-    Bitboard::from_bits(0x0028_4482_0000_0000),
-    Bitboard::from_bits(0x0028_4480_0000_0000),
-    Bitboard::from_bits(0x0028_4480_0000_0000),
-    Bitboard::from_bits(0x0028_4402_0100_0000),
-    Bitboard::from_bits(0x0028_4402_0000_0000),
-    Bitboard::from_bits(0x0028_4400_0000_0000),
-    Bitboard::from_bits(0x0028_4400_0000_0000),
-    Bitboard::from_bits(0x0028_4080_0000_0000),
-    Bitboard::from_bits(0x0028_4080_0000_0000),
-    Bitboard::from_bits(0x0028_4080_0000_0000),
-    Bitboard::from_bits(0x0028_4080_0000_0000),
-    Bitboard::from_bits(0x0028_4000_0000_0000),
-    Bitboard::from_bits(0x0028_4000_0000_0000),
-    Bitboard::from_bits(0x0028_4000_0000_0000),
-    Bitboard::from_bits(0x0028_4000_0000_0000),
-    Bitboard::from_bits(0x0028_0402_0100_0000),
-    Bitboard::from_bits(0x0028_0402_0000_0000),
-    Bitboard::from_bits(0x0028_0400_0000_0000),
-    Bitboard::from_bits(0x0028_0400_0000_0000),
-    Bitboard::from_bits(0x0028_0402_0100_0000),
-    Bitboard::from_bits(0x0028_0402_0000_0000),
-    Bitboard::from_bits(0x0028_0400_0000_0000),
-    Bitboard::from_bits(0x0028_0400_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0050_8804_0201_0000),
-    Bitboard::from_bits(0x0050_8804_0200_0000),
-    Bitboard::from_bits(0x0050_8804_0000_0000),
-    Bitboard::from_bits(0x0050_8804_0000_0000),
-    Bitboard::from_bits(0x0050_8800_0000_0000),
-    Bitboard::from_bits(0x0050_8800_0000_0000),
-    Bitboard::from_bits(0x0050_8800_0000_0000),
-    Bitboard::from_bits(0x0050_8800_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_8000_0000_0000),
-    Bitboard::from_bits(0x0050_0804_0201_0000),
-    Bitboard::from_bits(0x0050_0804_0200_0000),
-    Bitboard::from_bits(0x0050_0804_0000_0000),
-    Bitboard::from_bits(0x0050_0804_0000_0000),
-    Bitboard::from_bits(0x0050_0800_0000_0000),
-    Bitboard::from_bits(0x0050_0800_0000_0000),
-    Bitboard::from_bits(0x0050_0800_0000_0000),
-    Bitboard::from_bits(0x0050_0800_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_1008_0402_0100),
-    Bitboard::from_bits(0x00a0_1008_0402_0000),
-    Bitboard::from_bits(0x00a0_1008_0400_0000),
-    Bitboard::from_bits(0x00a0_1008_0400_0000),
-    Bitboard::from_bits(0x00a0_1008_0000_0000),
-    Bitboard::from_bits(0x00a0_1008_0000_0000),
-    Bitboard::from_bits(0x00a0_1008_0000_0000),
-    Bitboard::from_bits(0x00a0_1008_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_1000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0201),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-]
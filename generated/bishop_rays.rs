@@ -1,4098 +0,0 @@
-[
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0001),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0204),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0408),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0408),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2010),
-    Bitboard::from_bits(0x0000_0000_0204_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1000),
-    Bitboard::from_bits(0x0000_0002_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0000),
-    Bitboard::from_bits(0x0000_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0102_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0102_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0402_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0201_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0201_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4000_0000),
-    Bitboard::from_bits(0x0008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0100_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0402_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_2040_0000_0000),
-    Bitboard::from_bits(0x0804_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0804_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-]
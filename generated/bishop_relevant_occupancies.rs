@@ -1,66 +0,0 @@
-[
-    18_049_651_735_527_936,
-    70_506_452_091_904,
-    275_415_828_992,
-    1_075_975_168,
-    38_021_120,
-    8_657_588_224,
-    2_216_338_399_232,
-    567_382_630_219_776,
-    9_024_825_867_763_712,
-    18_049_651_735_527_424,
-    70_506_452_221_952,
-    275_449_643_008,
-    9_733_406_720,
-    2_216_342_585_344,
-    567_382_630_203_392,
-    1_134_765_260_406_784,
-    4_512_412_933_816_832,
-    9_024_825_867_633_664,
-    18_049_651_768_822_272,
-    70_515_108_615_168,
-    2_491_752_130_560,
-    567_383_701_868_544,
-    1_134_765_256_220_672,
-    2_269_530_512_441_344,
-    2_256_206_450_263_040,
-    4_512_412_900_526_080,
-    9_024_834_391_117_824,
-    18_051_867_805_491_712,
-    637_888_545_440_768,
-    1_135_039_602_493_440,
-    2_269_529_440_784_384,
-    4_539_058_881_568_768,
-    1_128_098_963_916_800,
-    2_256_197_927_833_600,
-    4_514_594_912_477_184,
-    9_592_139_778_506_752,
-    19_184_279_556_981_248,
-    2_339_762_086_609_920,
-    4_538_784_537_380_864,
-    9_077_569_074_761_728,
-    562_958_610_993_152,
-    1_125_917_221_986_304,
-    2_814_792_987_328_512,
-    5_629_586_008_178_688,
-    11_259_172_008_099_840,
-    22_518_341_868_716_544,
-    9_007_336_962_655_232,
-    18_014_673_925_310_464,
-    2_216_338_399_232,
-    4_432_676_798_464,
-    11_064_376_819_712,
-    22_137_335_185_408,
-    44_272_556_441_600,
-    87_995_357_200_384,
-    35_253_226_045_952,
-    70_506_452_091_904,
-    567_382_630_219_776,
-    1_134_765_260_406_784,
-    2_832_480_465_846_272,
-    5_667_157_807_464_448,
-    11_333_774_449_049_600,
-    22_526_811_443_298_304,
-    9_024_825_867_763_712,
-    18_049_651_735_527_936,
-]
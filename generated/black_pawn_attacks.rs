@@ -1,66 +0,0 @@
-[
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0005),
-    Bitboard::from_bits(0x0000_0000_0000_000a),
-    Bitboard::from_bits(0x0000_0000_0000_0014),
-    Bitboard::from_bits(0x0000_0000_0000_0028),
-    Bitboard::from_bits(0x0000_0000_0000_0050),
-    Bitboard::from_bits(0x0000_0000_0000_00a0),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0500),
-    Bitboard::from_bits(0x0000_0000_0000_0a00),
-    Bitboard::from_bits(0x0000_0000_0000_1400),
-    Bitboard::from_bits(0x0000_0000_0000_2800),
-    Bitboard::from_bits(0x0000_0000_0000_5000),
-    Bitboard::from_bits(0x0000_0000_0000_a000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0005_0000),
-    Bitboard::from_bits(0x0000_0000_000a_0000),
-    Bitboard::from_bits(0x0000_0000_0014_0000),
-    Bitboard::from_bits(0x0000_0000_0028_0000),
-    Bitboard::from_bits(0x0000_0000_0050_0000),
-    Bitboard::from_bits(0x0000_0000_00a0_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0500_0000),
-    Bitboard::from_bits(0x0000_0000_0a00_0000),
-    Bitboard::from_bits(0x0000_0000_1400_0000),
-    Bitboard::from_bits(0x0000_0000_2800_0000),
-    Bitboard::from_bits(0x0000_0000_5000_0000),
-    Bitboard::from_bits(0x0000_0000_a000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0005_0000_0000),
-    Bitboard::from_bits(0x0000_000a_0000_0000),
-    Bitboard::from_bits(0x0000_0014_0000_0000),
-    Bitboard::from_bits(0x0000_0028_0000_0000),
-    Bitboard::from_bits(0x0000_0050_0000_0000),
-    Bitboard::from_bits(0x0000_00a0_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0500_0000_0000),
-    Bitboard::from_bits(0x0000_0a00_0000_0000),
-    Bitboard::from_bits(0x0000_1400_0000_0000),
-    Bitboard::from_bits(0x0000_2800_0000_0000),
-    Bitboard::from_bits(0x0000_5000_0000_0000),
-    Bitboard::from_bits(0x0000_a000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-]
@@ -1,4098 +0,0 @@
-[
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0001),
-    Bitboard::from_bits(0x0000_0000_0000_0003),
-    Bitboard::from_bits(0x0000_0000_0000_0007),
-    Bitboard::from_bits(0x0000_0000_0000_000f),
-    Bitboard::from_bits(0x0000_0000_0000_001f),
-    Bitboard::from_bits(0x0000_0000_0000_003f),
-    Bitboard::from_bits(0x0000_0000_0000_007f),
-    Bitboard::from_bits(0x0000_0000_0000_0001),
-    Bitboard::from_bits(0x0000_0000_0000_0001),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0101_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0101_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0101_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0101_0101),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0201),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0006),
-    Bitboard::from_bits(0x0000_0000_0000_000e),
-    Bitboard::from_bits(0x0000_0000_0000_001e),
-    Bitboard::from_bits(0x0000_0000_0000_003e),
-    Bitboard::from_bits(0x0000_0000_0000_007e),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0002),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0202_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0202_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0202_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0402),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0202_0202),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0006),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_000c),
-    Bitboard::from_bits(0x0000_0000_0000_001c),
-    Bitboard::from_bits(0x0000_0000_0000_003c),
-    Bitboard::from_bits(0x0000_0000_0000_007c),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0004),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0204),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0404_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0404_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0804),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0404_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0404_0404),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_000e),
-    Bitboard::from_bits(0x0000_0000_0000_000c),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0018),
-    Bitboard::from_bits(0x0000_0000_0000_0038),
-    Bitboard::from_bits(0x0000_0000_0000_0078),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0408),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0408),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0808_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1008),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0808_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0808_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0808_0808),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_001e),
-    Bitboard::from_bits(0x0000_0000_0000_001c),
-    Bitboard::from_bits(0x0000_0000_0000_0018),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0030),
-    Bitboard::from_bits(0x0000_0000_0000_0070),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2010),
-    Bitboard::from_bits(0x0000_0000_0204_0810),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1010_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1010_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1010_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1010_1010),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_003e),
-    Bitboard::from_bits(0x0000_0000_0000_003c),
-    Bitboard::from_bits(0x0000_0000_0000_0038),
-    Bitboard::from_bits(0x0000_0000_0000_0030),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0060),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2020_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_1020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2020_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2020_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2020_2020),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_007e),
-    Bitboard::from_bits(0x0000_0000_0000_007c),
-    Bitboard::from_bits(0x0000_0000_0000_0078),
-    Bitboard::from_bits(0x0000_0000_0000_0070),
-    Bitboard::from_bits(0x0000_0000_0000_0060),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4040_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4040_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4040_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4040_4040),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_00fe),
-    Bitboard::from_bits(0x0000_0000_0000_00fc),
-    Bitboard::from_bits(0x0000_0000_0000_00f8),
-    Bitboard::from_bits(0x0000_0000_0000_00f0),
-    Bitboard::from_bits(0x0000_0000_0000_00e0),
-    Bitboard::from_bits(0x0000_0000_0000_00c0),
-    Bitboard::from_bits(0x0000_0000_0000_0080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0080),
-    Bitboard::from_bits(0x0000_0000_0000_0080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_8080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_8080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8080_8080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8080_8080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8080_8080),
-    Bitboard::from_bits(0x0002_0408_1020_4080),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8080_8080),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0300),
-    Bitboard::from_bits(0x0000_0000_0000_0700),
-    Bitboard::from_bits(0x0000_0000_0000_0f00),
-    Bitboard::from_bits(0x0000_0000_0000_1f00),
-    Bitboard::from_bits(0x0000_0000_0000_3f00),
-    Bitboard::from_bits(0x0000_0000_0000_7f00),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0600),
-    Bitboard::from_bits(0x0000_0000_0000_0e00),
-    Bitboard::from_bits(0x0000_0000_0000_1e00),
-    Bitboard::from_bits(0x0000_0000_0000_3e00),
-    Bitboard::from_bits(0x0000_0000_0000_7e00),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0600),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0c00),
-    Bitboard::from_bits(0x0000_0000_0000_1c00),
-    Bitboard::from_bits(0x0000_0000_0000_3c00),
-    Bitboard::from_bits(0x0000_0000_0000_7c00),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0e00),
-    Bitboard::from_bits(0x0000_0000_0000_0c00),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_1800),
-    Bitboard::from_bits(0x0000_0000_0000_3800),
-    Bitboard::from_bits(0x0000_0000_0000_7800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1e00),
-    Bitboard::from_bits(0x0000_0000_0000_1c00),
-    Bitboard::from_bits(0x0000_0000_0000_1800),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_3000),
-    Bitboard::from_bits(0x0000_0000_0000_7000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1000),
-    Bitboard::from_bits(0x0000_0002_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_3e00),
-    Bitboard::from_bits(0x0000_0000_0000_3c00),
-    Bitboard::from_bits(0x0000_0000_0000_3800),
-    Bitboard::from_bits(0x0000_0000_0000_3000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_6000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_7e00),
-    Bitboard::from_bits(0x0000_0000_0000_7c00),
-    Bitboard::from_bits(0x0000_0000_0000_7800),
-    Bitboard::from_bits(0x0000_0000_0000_7000),
-    Bitboard::from_bits(0x0000_0000_0000_6000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_fe00),
-    Bitboard::from_bits(0x0000_0000_0000_fc00),
-    Bitboard::from_bits(0x0000_0000_0000_f800),
-    Bitboard::from_bits(0x0000_0000_0000_f000),
-    Bitboard::from_bits(0x0000_0000_0000_e000),
-    Bitboard::from_bits(0x0000_0000_0000_c000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0001_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0003_0000),
-    Bitboard::from_bits(0x0000_0000_0007_0000),
-    Bitboard::from_bits(0x0000_0000_000f_0000),
-    Bitboard::from_bits(0x0000_0000_001f_0000),
-    Bitboard::from_bits(0x0000_0000_003f_0000),
-    Bitboard::from_bits(0x0000_0000_007f_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0001_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0201_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0006_0000),
-    Bitboard::from_bits(0x0000_0000_000e_0000),
-    Bitboard::from_bits(0x0000_0000_001e_0000),
-    Bitboard::from_bits(0x0000_0000_003e_0000),
-    Bitboard::from_bits(0x0000_0000_007e_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0006_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_000c_0000),
-    Bitboard::from_bits(0x0000_0000_001c_0000),
-    Bitboard::from_bits(0x0000_0000_003c_0000),
-    Bitboard::from_bits(0x0000_0000_007c_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0004_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_000e_0000),
-    Bitboard::from_bits(0x0000_0000_000c_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0018_0000),
-    Bitboard::from_bits(0x0000_0000_0038_0000),
-    Bitboard::from_bits(0x0000_0000_0078_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_001e_0000),
-    Bitboard::from_bits(0x0000_0000_001c_0000),
-    Bitboard::from_bits(0x0000_0000_0018_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0030_0000),
-    Bitboard::from_bits(0x0000_0000_0070_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0000),
-    Bitboard::from_bits(0x0000_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_003e_0000),
-    Bitboard::from_bits(0x0000_0000_003c_0000),
-    Bitboard::from_bits(0x0000_0000_0038_0000),
-    Bitboard::from_bits(0x0000_0000_0030_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0060_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_007e_0000),
-    Bitboard::from_bits(0x0000_0000_007c_0000),
-    Bitboard::from_bits(0x0000_0000_0078_0000),
-    Bitboard::from_bits(0x0000_0000_0070_0000),
-    Bitboard::from_bits(0x0000_0000_0060_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_00fe_0000),
-    Bitboard::from_bits(0x0000_0000_00fc_0000),
-    Bitboard::from_bits(0x0000_0000_00f8_0000),
-    Bitboard::from_bits(0x0000_0000_00f0_0000),
-    Bitboard::from_bits(0x0000_0000_00e0_0000),
-    Bitboard::from_bits(0x0000_0000_00c0_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0102_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0102_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0300_0000),
-    Bitboard::from_bits(0x0000_0000_0700_0000),
-    Bitboard::from_bits(0x0000_0000_0f00_0000),
-    Bitboard::from_bits(0x0000_0000_1f00_0000),
-    Bitboard::from_bits(0x0000_0000_3f00_0000),
-    Bitboard::from_bits(0x0000_0000_7f00_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0402_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0600_0000),
-    Bitboard::from_bits(0x0000_0000_0e00_0000),
-    Bitboard::from_bits(0x0000_0000_1e00_0000),
-    Bitboard::from_bits(0x0000_0000_3e00_0000),
-    Bitboard::from_bits(0x0000_0000_7e00_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0600_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0c00_0000),
-    Bitboard::from_bits(0x0000_0000_1c00_0000),
-    Bitboard::from_bits(0x0000_0000_3c00_0000),
-    Bitboard::from_bits(0x0000_0000_7c00_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0e00_0000),
-    Bitboard::from_bits(0x0000_0000_0c00_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_1800_0000),
-    Bitboard::from_bits(0x0000_0000_3800_0000),
-    Bitboard::from_bits(0x0000_0000_7800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1e00_0000),
-    Bitboard::from_bits(0x0000_0000_1c00_0000),
-    Bitboard::from_bits(0x0000_0000_1800_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_3000_0000),
-    Bitboard::from_bits(0x0000_0000_7000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_3e00_0000),
-    Bitboard::from_bits(0x0000_0000_3c00_0000),
-    Bitboard::from_bits(0x0000_0000_3800_0000),
-    Bitboard::from_bits(0x0000_0000_3000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_6000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_7e00_0000),
-    Bitboard::from_bits(0x0000_0000_7c00_0000),
-    Bitboard::from_bits(0x0000_0000_7800_0000),
-    Bitboard::from_bits(0x0000_0000_7000_0000),
-    Bitboard::from_bits(0x0000_0000_6000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8040_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_fe00_0000),
-    Bitboard::from_bits(0x0000_0000_fc00_0000),
-    Bitboard::from_bits(0x0000_0000_f800_0000),
-    Bitboard::from_bits(0x0000_0000_f000_0000),
-    Bitboard::from_bits(0x0000_0000_e000_0000),
-    Bitboard::from_bits(0x0000_0000_c000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8000_0000),
-    Bitboard::from_bits(0x0000_0001_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0204_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0204_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0003_0000_0000),
-    Bitboard::from_bits(0x0000_0007_0000_0000),
-    Bitboard::from_bits(0x0000_000f_0000_0000),
-    Bitboard::from_bits(0x0000_001f_0000_0000),
-    Bitboard::from_bits(0x0000_003f_0000_0000),
-    Bitboard::from_bits(0x0000_007f_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0001_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0201_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0201_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0006_0000_0000),
-    Bitboard::from_bits(0x0000_000e_0000_0000),
-    Bitboard::from_bits(0x0000_001e_0000_0000),
-    Bitboard::from_bits(0x0000_003e_0000_0000),
-    Bitboard::from_bits(0x0000_007e_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0006_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_000c_0000_0000),
-    Bitboard::from_bits(0x0000_001c_0000_0000),
-    Bitboard::from_bits(0x0000_003c_0000_0000),
-    Bitboard::from_bits(0x0000_007c_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0004_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_4000),
-    Bitboard::from_bits(0x0000_0008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_000e_0000_0000),
-    Bitboard::from_bits(0x0000_000c_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0018_0000_0000),
-    Bitboard::from_bits(0x0000_0038_0000_0000),
-    Bitboard::from_bits(0x0000_0078_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_001e_0000_0000),
-    Bitboard::from_bits(0x0000_001c_0000_0000),
-    Bitboard::from_bits(0x0000_0018_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0030_0000_0000),
-    Bitboard::from_bits(0x0000_0070_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_003e_0000_0000),
-    Bitboard::from_bits(0x0000_003c_0000_0000),
-    Bitboard::from_bits(0x0000_0038_0000_0000),
-    Bitboard::from_bits(0x0000_0030_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0060_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_007e_0000_0000),
-    Bitboard::from_bits(0x0000_007c_0000_0000),
-    Bitboard::from_bits(0x0000_0078_0000_0000),
-    Bitboard::from_bits(0x0000_0070_0000_0000),
-    Bitboard::from_bits(0x0000_0060_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4020_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_00fe_0000_0000),
-    Bitboard::from_bits(0x0000_00fc_0000_0000),
-    Bitboard::from_bits(0x0000_00f8_0000_0000),
-    Bitboard::from_bits(0x0000_00f0_0000_0000),
-    Bitboard::from_bits(0x0000_00e0_0000_0000),
-    Bitboard::from_bits(0x0000_00c0_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0408_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0408_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0101_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0102_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0300_0000_0000),
-    Bitboard::from_bits(0x0000_0700_0000_0000),
-    Bitboard::from_bits(0x0000_0f00_0000_0000),
-    Bitboard::from_bits(0x0000_1f00_0000_0000),
-    Bitboard::from_bits(0x0000_3f00_0000_0000),
-    Bitboard::from_bits(0x0000_7f00_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0202_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0600_0000_0000),
-    Bitboard::from_bits(0x0000_0e00_0000_0000),
-    Bitboard::from_bits(0x0000_1e00_0000_0000),
-    Bitboard::from_bits(0x0000_3e00_0000_0000),
-    Bitboard::from_bits(0x0000_7e00_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0404_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0600_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0c00_0000_0000),
-    Bitboard::from_bits(0x0000_1c00_0000_0000),
-    Bitboard::from_bits(0x0000_3c00_0000_0000),
-    Bitboard::from_bits(0x0000_7c00_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0808_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0e00_0000_0000),
-    Bitboard::from_bits(0x0000_0c00_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_1800_0000_0000),
-    Bitboard::from_bits(0x0000_3800_0000_0000),
-    Bitboard::from_bits(0x0000_7800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1e00_0000_0000),
-    Bitboard::from_bits(0x0000_1c00_0000_0000),
-    Bitboard::from_bits(0x0000_1800_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_3000_0000_0000),
-    Bitboard::from_bits(0x0000_7000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_3e00_0000_0000),
-    Bitboard::from_bits(0x0000_3c00_0000_0000),
-    Bitboard::from_bits(0x0000_3800_0000_0000),
-    Bitboard::from_bits(0x0000_3000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_6000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_7e00_0000_0000),
-    Bitboard::from_bits(0x0000_7c00_0000_0000),
-    Bitboard::from_bits(0x0000_7800_0000_0000),
-    Bitboard::from_bits(0x0000_7000_0000_0000),
-    Bitboard::from_bits(0x0000_6000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2010_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_fe00_0000_0000),
-    Bitboard::from_bits(0x0000_fc00_0000_0000),
-    Bitboard::from_bits(0x0000_f800_0000_0000),
-    Bitboard::from_bits(0x0000_f000_0000_0000),
-    Bitboard::from_bits(0x0000_e000_0000_0000),
-    Bitboard::from_bits(0x0000_c000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0810_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0810_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0101_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0204_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0003_0000_0000_0000),
-    Bitboard::from_bits(0x0007_0000_0000_0000),
-    Bitboard::from_bits(0x000f_0000_0000_0000),
-    Bitboard::from_bits(0x001f_0000_0000_0000),
-    Bitboard::from_bits(0x003f_0000_0000_0000),
-    Bitboard::from_bits(0x007f_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0001_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0202_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0006_0000_0000_0000),
-    Bitboard::from_bits(0x000e_0000_0000_0000),
-    Bitboard::from_bits(0x001e_0000_0000_0000),
-    Bitboard::from_bits(0x003e_0000_0000_0000),
-    Bitboard::from_bits(0x007e_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0404_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0006_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x000c_0000_0000_0000),
-    Bitboard::from_bits(0x001c_0000_0000_0000),
-    Bitboard::from_bits(0x003c_0000_0000_0000),
-    Bitboard::from_bits(0x007c_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0004_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_4000_0000),
-    Bitboard::from_bits(0x0008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0808_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x000e_0000_0000_0000),
-    Bitboard::from_bits(0x000c_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0018_0000_0000_0000),
-    Bitboard::from_bits(0x0038_0000_0000_0000),
-    Bitboard::from_bits(0x0078_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x001e_0000_0000_0000),
-    Bitboard::from_bits(0x001c_0000_0000_0000),
-    Bitboard::from_bits(0x0018_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0030_0000_0000_0000),
-    Bitboard::from_bits(0x0070_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x003e_0000_0000_0000),
-    Bitboard::from_bits(0x003c_0000_0000_0000),
-    Bitboard::from_bits(0x0038_0000_0000_0000),
-    Bitboard::from_bits(0x0030_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0060_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x007e_0000_0000_0000),
-    Bitboard::from_bits(0x007c_0000_0000_0000),
-    Bitboard::from_bits(0x0078_0000_0000_0000),
-    Bitboard::from_bits(0x0070_0000_0000_0000),
-    Bitboard::from_bits(0x0060_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1008_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1008_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x00fe_0000_0000_0000),
-    Bitboard::from_bits(0x00fc_0000_0000_0000),
-    Bitboard::from_bits(0x00f8_0000_0000_0000),
-    Bitboard::from_bits(0x00f0_0000_0000_0000),
-    Bitboard::from_bits(0x00e0_0000_0000_0000),
-    Bitboard::from_bits(0x00c0_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0080_0000_0000_0000),
-    Bitboard::from_bits(0x0101_0101_0101_0100),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1020_4000),
-    Bitboard::from_bits(0x0101_0101_0101_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0101_0101_0100_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0101_0101_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0408_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0101_0100_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0101_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0102_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0100_0000_0000_0000),
-    Bitboard::from_bits(0x0100_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0100_0000_0000_0000),
-    Bitboard::from_bits(0x0300_0000_0000_0000),
-    Bitboard::from_bits(0x0700_0000_0000_0000),
-    Bitboard::from_bits(0x0f00_0000_0000_0000),
-    Bitboard::from_bits(0x1f00_0000_0000_0000),
-    Bitboard::from_bits(0x3f00_0000_0000_0000),
-    Bitboard::from_bits(0x7f00_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0202_0202_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0202_0202_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_2040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0202_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0202_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0810_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0202_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0204_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0600_0000_0000_0000),
-    Bitboard::from_bits(0x0e00_0000_0000_0000),
-    Bitboard::from_bits(0x1e00_0000_0000_0000),
-    Bitboard::from_bits(0x3e00_0000_0000_0000),
-    Bitboard::from_bits(0x7e00_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0404_0404_0400),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0404_0404_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0404_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1020_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0404_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0402_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0404_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0408_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0600_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0400_0000_0000_0000),
-    Bitboard::from_bits(0x0c00_0000_0000_0000),
-    Bitboard::from_bits(0x1c00_0000_0000_0000),
-    Bitboard::from_bits(0x3c00_0000_0000_0000),
-    Bitboard::from_bits(0x7c00_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0808_0808_0800),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0808_0808_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0808_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0808_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_2040_0000_0000),
-    Bitboard::from_bits(0x0804_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0804_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0808_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0810_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0e00_0000_0000_0000),
-    Bitboard::from_bits(0x0c00_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0800_0000_0000_0000),
-    Bitboard::from_bits(0x1800_0000_0000_0000),
-    Bitboard::from_bits(0x3800_0000_0000_0000),
-    Bitboard::from_bits(0x7800_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_1010_1010_1000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_1010_1010_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_1010_1000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0402_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_1010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0400_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1020_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1008_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1e00_0000_0000_0000),
-    Bitboard::from_bits(0x1c00_0000_0000_0000),
-    Bitboard::from_bits(0x1800_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x1000_0000_0000_0000),
-    Bitboard::from_bits(0x3000_0000_0000_0000),
-    Bitboard::from_bits(0x7000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_2020_2020_2000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_2020_2020_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0804_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_2020_2000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0804_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_2020_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0800_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2010_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x3e00_0000_0000_0000),
-    Bitboard::from_bits(0x3c00_0000_0000_0000),
-    Bitboard::from_bits(0x3800_0000_0000_0000),
-    Bitboard::from_bits(0x3000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x2000_0000_0000_0000),
-    Bitboard::from_bits(0x6000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_4040_4040_4000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0402_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_4040_4040_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0400_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_4040_4000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1008_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_4040_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_1000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_4000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4020_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x7e00_0000_0000_0000),
-    Bitboard::from_bits(0x7c00_0000_0000_0000),
-    Bitboard::from_bits(0x7800_0000_0000_0000),
-    Bitboard::from_bits(0x7000_0000_0000_0000),
-    Bitboard::from_bits(0x6000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0804_0200),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_8080_8080_8000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0804_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_8080_8080_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0800_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_8080_8000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2010_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_8080_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_2000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_8000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8040_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8080_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x8000_0000_0000_0000),
-    Bitboard::from_bits(0x8000_0000_0000_0000),
-    Bitboard::from_bits(0xfe00_0000_0000_0000),
-    Bitboard::from_bits(0xfc00_0000_0000_0000),
-    Bitboard::from_bits(0xf800_0000_0000_0000),
-    Bitboard::from_bits(0xf000_0000_0000_0000),
-    Bitboard::from_bits(0xe000_0000_0000_0000),
-    Bitboard::from_bits(0xc000_0000_0000_0000),
-    Bitboard::from_bits(0x8000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-]
@@ -1,66 +0,0 @@
-[
-    282_578_800_148_862,
-    565_157_600_297_596,
-    1_130_315_200_595_066,
-    2_260_630_401_190_006,
-    4_521_260_802_379_886,
-    9_042_521_604_759_646,
-    18_085_043_209_519_166,
-    36_170_086_419_038_334,
-    282_578_800_180_736,
-    565_157_600_328_704,
-    1_130_315_200_625_152,
-    2_260_630_401_218_048,
-    4_521_260_802_403_840,
-    9_042_521_604_775_424,
-    18_085_043_209_518_592,
-    36_170_086_419_037_696,
-    282_578_808_340_736,
-    565_157_608_292_864,
-    1_130_315_208_328_192,
-    2_260_630_408_398_848,
-    4_521_260_808_540_160,
-    9_042_521_608_822_784,
-    18_085_043_209_388_032,
-    36_170_086_418_907_136,
-    282_580_897_300_736,
-    565_159_647_117_824,
-    1_130_317_180_306_432,
-    2_260_632_246_683_648,
-    4_521_262_379_438_080,
-    9_042_522_644_946_944,
-    18_085_043_175_964_672,
-    36_170_086_385_483_776,
-    283_115_671_060_736,
-    565_681_586_307_584,
-    1_130_822_006_735_872,
-    2_261_102_847_592_448,
-    4_521_664_529_305_600,
-    9_042_787_892_731_904,
-    18_085_034_619_584_512,
-    36_170_077_829_103_616,
-    420_017_753_620_736,
-    699_298_018_886_144,
-    1_260_057_572_672_512,
-    2_381_576_680_245_248,
-    4_624_614_895_390_720,
-    9_110_691_325_681_664,
-    18_082_844_186_263_552,
-    36_167_887_395_782_656,
-    35_466_950_888_980_736,
-    34_905_104_758_997_504,
-    34_344_362_452_452_352,
-    33_222_877_839_362_048,
-    30_979_908_613_181_440,
-    26_493_970_160_820_224,
-    17_522_093_256_097_792,
-    35_607_136_465_616_896,
-    9_079_539_427_579_068_672,
-    8_935_706_818_303_361_536,
-    8_792_156_787_827_803_136,
-    8_505_056_726_876_686_336,
-    7_930_856_604_974_452_736,
-    6_782_456_361_169_985_536,
-    4_485_655_873_561_051_136,
-    9_115_426_935_197_958_144,
-]
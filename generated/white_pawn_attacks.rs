@@ -1,66 +0,0 @@
-[
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0002_0000),
-    Bitboard::from_bits(0x0000_0000_0005_0000),
-    Bitboard::from_bits(0x0000_0000_000a_0000),
-    Bitboard::from_bits(0x0000_0000_0014_0000),
-    Bitboard::from_bits(0x0000_0000_0028_0000),
-    Bitboard::from_bits(0x0000_0000_0050_0000),
-    Bitboard::from_bits(0x0000_0000_00a0_0000),
-    Bitboard::from_bits(0x0000_0000_0040_0000),
-    Bitboard::from_bits(0x0000_0000_0200_0000),
-    Bitboard::from_bits(0x0000_0000_0500_0000),
-    Bitboard::from_bits(0x0000_0000_0a00_0000),
-    Bitboard::from_bits(0x0000_0000_1400_0000),
-    Bitboard::from_bits(0x0000_0000_2800_0000),
-    Bitboard::from_bits(0x0000_0000_5000_0000),
-    Bitboard::from_bits(0x0000_0000_a000_0000),
-    Bitboard::from_bits(0x0000_0000_4000_0000),
-    Bitboard::from_bits(0x0000_0002_0000_0000),
-    Bitboard::from_bits(0x0000_0005_0000_0000),
-    Bitboard::from_bits(0x0000_000a_0000_0000),
-    Bitboard::from_bits(0x0000_0014_0000_0000),
-    Bitboard::from_bits(0x0000_0028_0000_0000),
-    Bitboard::from_bits(0x0000_0050_0000_0000),
-    Bitboard::from_bits(0x0000_00a0_0000_0000),
-    Bitboard::from_bits(0x0000_0040_0000_0000),
-    Bitboard::from_bits(0x0000_0200_0000_0000),
-    Bitboard::from_bits(0x0000_0500_0000_0000),
-    Bitboard::from_bits(0x0000_0a00_0000_0000),
-    Bitboard::from_bits(0x0000_1400_0000_0000),
-    Bitboard::from_bits(0x0000_2800_0000_0000),
-    Bitboard::from_bits(0x0000_5000_0000_0000),
-    Bitboard::from_bits(0x0000_a000_0000_0000),
-    Bitboard::from_bits(0x0000_4000_0000_0000),
-    Bitboard::from_bits(0x0002_0000_0000_0000),
-    Bitboard::from_bits(0x0005_0000_0000_0000),
-    Bitboard::from_bits(0x000a_0000_0000_0000),
-    Bitboard::from_bits(0x0014_0000_0000_0000),
-    Bitboard::from_bits(0x0028_0000_0000_0000),
-    Bitboard::from_bits(0x0050_0000_0000_0000),
-    Bitboard::from_bits(0x00a0_0000_0000_0000),
-    Bitboard::from_bits(0x0040_0000_0000_0000),
-    Bitboard::from_bits(0x0200_0000_0000_0000),
-    Bitboard::from_bits(0x0500_0000_0000_0000),
-    Bitboard::from_bits(0x0a00_0000_0000_0000),
-    Bitboard::from_bits(0x1400_0000_0000_0000),
-    Bitboard::from_bits(0x2800_0000_0000_0000),
-    Bitboard::from_bits(0x5000_0000_0000_0000),
-    Bitboard::from_bits(0xa000_0000_0000_0000),
-    Bitboard::from_bits(0x4000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-    Bitboard::from_bits(0x0000_0000_0000_0000),
-]
@@ -0,0 +1,39 @@
+/* C interface of the Pabi chess engine, built with the "ffi" feature.
+ * See src/ffi.rs for the details. */
+
+#ifndef PABI_H
+#define PABI_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct PabiEngine PabiEngine;
+
+/* Creates an engine with the starting position. */
+PabiEngine *pabi_engine_new(void);
+
+/* Destroys the engine. Does nothing if engine is NULL. */
+void pabi_engine_free(PabiEngine *engine);
+
+/* Sets the position from FEN. Returns false and keeps the current position if
+ * the input is not a valid position. */
+bool pabi_engine_set_position(PabiEngine *engine, const char *fen);
+
+/* Searches the current position for movetime_ms milliseconds. Returns false
+ * if the search failed or the position has no legal moves. */
+bool pabi_engine_search(PabiEngine *engine, uint64_t movetime_ms);
+
+/* Best move of the last search in UCI notation (e.g. "e2e4"), empty if the
+ * search failed. Owned by the engine and valid until the next search or
+ * pabi_engine_free. */
+const char *pabi_engine_bestmove(const PabiEngine *engine);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* PABI_H */
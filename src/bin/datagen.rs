@@ -1,17 +1,325 @@
-use clap::Parser;
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use pabi::chess::game::Termination;
+use pabi::chess::position::Position;
+use pabi::chess::tablebase::Tablebase;
+use pabi::datagen::record::{GameRecord, RecordWriter};
+use pabi::datagen::{self, merge, Adjudication, PlayoutCap, TemperatureSchedule};
+use pabi::evaluation::Pesto;
+use pabi::search::Limits;
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
 
 /// Generates training data for the policy network through self-play.
 #[derive(Parser, Debug)]
 #[command(version, about)]
 struct Config {
+    #[command(subcommand)]
+    command: Option<Command>,
     // TODO: Book to seed the starting positions from.
-    // TODO: Number of games.
-    // TODO: Output file.
-    // TODO: Tablebase path.
     // TODO: Flatten Search config.
+    /// Number of games to play.
+    #[arg(long, default_value_t = 1)]
+    games: usize,
+    /// Number of threads playing the games in parallel. Worker i uses the seed
+    /// --seed + i and writes the games to its own --output shard (e.g.
+    /// games.bin becomes games.0.bin, games.1.bin, ...), which can be combined
+    /// with the merge command.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u16).range(1..))]
+    workers: u16,
+    /// Number of playouts per move.
+    #[arg(long, default_value_t = 800)]
+    nodes: u64,
+    /// Playout cap randomization: fraction of the moves searched with the full
+    /// number of playouts and recorded as policy targets. The other moves are
+    /// searched with --fast-nodes playouts. 1 searches all moves fully.
+    #[arg(long, default_value_t = 1.0)]
+    full_search_fraction: f64,
+    /// Number of playouts of the fast searches, see --full-search-fraction.
+    #[arg(long, default_value_t = 100)]
+    fast_nodes: u64,
+    /// Temperature schedule of choosing the moves from the root visits as
+    /// initial:plies:final, e.g. 1:20:0 samples the first 20 plies in
+    /// proportion to the visits and then plays the most visited moves. The
+    /// schedule is stored with each game written to --output.
+    #[arg(long, default_value = "1:20:0")]
+    temperature: TemperatureSchedule,
+    /// Seed for the random choices (e.g. sampling the moves, disabling
+    /// resignation).
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+    /// Resign when the value of the position (in [-1, 1]) stays at or below
+    /// this threshold. Values at or below -1 disable resignation.
+    #[arg(long, default_value_t = -0.95, allow_negative_numbers = true)]
+    resign_threshold: f32,
+    /// Number of consecutive own moves below the threshold before resigning.
+    #[arg(long, default_value_t = 4)]
+    resign_moves: usize,
+    /// Fraction of games played with resignation disabled to check how often
+    /// resigning would be a mistake.
+    #[arg(long, default_value_t = 0.1)]
+    resign_disabled_fraction: f64,
+    /// Agree to a draw when the absolute value of the position stays at or
+    /// below this threshold. Negative value disables draw adjudication.
+    #[arg(long, default_value_t = 0.05, allow_negative_numbers = true)]
+    draw_threshold: f32,
+    /// Number of consecutive plies within the threshold before the draw.
+    #[arg(long, default_value_t = 12)]
+    draw_moves: usize,
+    /// Do not adjudicate draws before this ply.
+    #[arg(long, default_value_t = 80)]
+    draw_min_ply: usize,
+    /// Directory with Syzygy tablebases: the games that reach a position with
+    /// few enough pieces end with the exact result instead of being played
+    /// out.
+    #[arg(long)]
+    tablebase: Option<PathBuf>,
+    /// Print all positions of each game along with their color-flipped and
+    /// mirrored versions in FEN format.
+    #[arg(long)]
+    augment: bool,
+    /// Skip the positions that were already printed, including color-flipped
+    /// and mirrored duplicates.
+    #[arg(long)]
+    dedup: bool,
+    /// Write the games along with the root visits to this file in the compact
+    /// binary format.
+    #[arg(long)]
+    output: Option<PathBuf>,
+    /// Append the statistics of each game (average depth, nps, evaluation
+    /// volatility and blunders) to this file in JSON lines format.
+    #[arg(long)]
+    stats: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Merges the game files written with --output into a single file,
+    /// dropping the duplicate games, and prints the dataset statistics.
+    Merge {
+        /// Game files to merge.
+        #[arg(required = true)]
+        shards: Vec<PathBuf>,
+        /// Merged game file.
+        #[arg(long)]
+        output: PathBuf,
+        /// Directory for the deduplication keys, which can take more space
+        /// than the available memory. Defaults to the system temporary
+        /// directory.
+        #[arg(long)]
+        temp_dir: Option<PathBuf>,
+    },
 }
 
-fn main() {
+fn main() -> anyhow::Result<()> {
     let config = Config::parse();
-    println!("{:?}", config);
+    if let Some(Command::Merge {
+        shards,
+        output,
+        temp_dir,
+    }) = &config.command
+    {
+        let temp_dir = temp_dir.clone().unwrap_or_else(std::env::temp_dir);
+        let stats = merge::merge(shards, BufWriter::new(File::create(output)?), &temp_dir)?;
+        eprintln!("{stats}");
+        return Ok(());
+    }
+    let self_play = datagen::Config {
+        limits: Limits {
+            nodes: Some(config.nodes),
+            ..Limits::default()
+        },
+        adjudication: Adjudication {
+            resign_threshold: (config.resign_threshold > -1.0).then_some(config.resign_threshold),
+            resign_moves: config.resign_moves,
+            resign_disabled_fraction: config.resign_disabled_fraction,
+            draw_threshold: (config.draw_threshold >= 0.0).then_some(config.draw_threshold),
+            draw_moves: config.draw_moves,
+            draw_min_ply: config.draw_min_ply,
+        },
+        playout_cap: (config.full_search_fraction < 1.0).then_some(PlayoutCap {
+            full_search_probability: config.full_search_fraction,
+            fast_nodes: config.fast_nodes,
+        }),
+        temperature: config.temperature,
+        tablebase: match &config.tablebase {
+            Some(path) => Some(Arc::new(
+                Tablebase::open(path).context("loading the tablebases")?,
+            )),
+            None => None,
+        },
+        ..datagen::Config::default()
+    };
+    let shared = Shared {
+        next_game: AtomicUsize::new(0),
+        finished: AtomicUsize::new(0),
+        positions: AtomicUsize::new(0),
+        start: Instant::now(),
+        stats: match &config.stats {
+            Some(path) => Some(Mutex::new(BufWriter::new(
+                OpenOptions::new().create(true).append(true).open(path)?,
+            ))),
+            None => None,
+        },
+        deduplicator: Mutex::default(),
+    };
+    let totals = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..config.workers)
+            .map(|worker| {
+                let (config, self_play, shared) = (&config, &self_play, &shared);
+                scope.spawn(move || play_games(worker, config, self_play, shared))
+            })
+            .collect();
+        workers
+            .into_iter()
+            .map(|worker| worker.join().expect("worker should not panic"))
+            .try_fold(Totals::default(), |totals, worker| {
+                Ok::<_, anyhow::Error>(totals + worker?)
+            })
+    })?;
+    if let Some(stats) = shared.stats {
+        stats.into_inner().expect("poisoned stats file").flush()?;
+    }
+    if config.tablebase.is_some() {
+        eprintln!(
+            "Tablebase adjudications: {}/{} games",
+            totals.tablebase_adjudications, config.games
+        );
+    }
+    eprintln!(
+        "False resignations: {}/{} games with resignation disabled",
+        totals.false_resignations, totals.resign_disabled
+    );
+    Ok(())
+}
+
+/// State shared by the workers.
+struct Shared {
+    /// Index of the next game to play.
+    next_game: AtomicUsize,
+    finished: AtomicUsize,
+    positions: AtomicUsize,
+    start: Instant,
+    stats: Option<Mutex<BufWriter<File>>>,
+    deduplicator: Mutex<datagen::Deduplicator>,
+}
+
+/// Counters of the games played by a worker.
+#[derive(Default)]
+struct Totals {
+    false_resignations: usize,
+    resign_disabled: usize,
+    tablebase_adjudications: usize,
+}
+
+impl std::ops::Add for Totals {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            false_resignations: self.false_resignations + other.false_resignations,
+            resign_disabled: self.resign_disabled + other.resign_disabled,
+            tablebase_adjudications: self.tablebase_adjudications + other.tablebase_adjudications,
+        }
+    }
+}
+
+/// Output file of the worker: the path itself if there is only one worker.
+fn shard_path(path: &Path, worker: u16, workers: u16) -> PathBuf {
+    if workers == 1 {
+        return path.to_path_buf();
+    }
+    match path.extension() {
+        Some(extension) => path.with_extension(format!("{worker}.{}", extension.to_string_lossy())),
+        None => path.with_extension(worker.to_string()),
+    }
+}
+
+/// Plays the games until all of them are taken by the workers.
+fn play_games(
+    worker: u16,
+    config: &Config,
+    self_play: &datagen::Config,
+    shared: &Shared,
+) -> anyhow::Result<Totals> {
+    let mut rng = SmallRng::seed_from_u64(config.seed + u64::from(worker));
+    let mut writer = match &config.output {
+        Some(path) => {
+            let path = shard_path(path, worker, config.workers);
+            Some(RecordWriter::new(BufWriter::new(File::create(path)?))?)
+        },
+        None => None,
+    };
+    let mut totals = Totals::default();
+    while shared.next_game.fetch_add(1, Ordering::Relaxed) < config.games {
+        let result = datagen::play_game(Position::starting(), self_play, &Pesto, &mut rng)?;
+        let moves: Vec<String> = result
+            .game
+            .history()
+            .iter()
+            .map(|record| record.played.to_string())
+            .collect();
+        // The output of each game is printed at once to keep the lines of
+        // different workers apart.
+        let mut output = format!(
+            "{} {:?} {}\n",
+            result.outcome,
+            result.outcome.termination,
+            moves.join(" ")
+        );
+        if let Some(writer) = &mut writer {
+            writer.write(&GameRecord::from(&result))?;
+        }
+        eprintln!("Game stats: {}", result.stats);
+        if let Some(stats) = &shared.stats {
+            let mut stats = stats.lock().expect("poisoned stats file");
+            writeln!(stats, "{}", result.stats.to_json())?;
+        }
+        if config.augment {
+            let mut position = result.game.root().clone();
+            for record in result.game.history() {
+                if config.dedup
+                    && !shared
+                        .deduplicator
+                        .lock()
+                        .expect("poisoned deduplicator")
+                        .insert(&position)
+                {
+                    position.make_move(&record.played);
+                    continue;
+                }
+                for augmented in datagen::augment(&position) {
+                    output += &format!("{augmented}\n");
+                }
+                position.make_move(&record.played);
+            }
+        }
+        std::io::stdout().lock().write_all(output.as_bytes())?;
+        if result.outcome.termination == Termination::Tablebase {
+            totals.tablebase_adjudications += 1;
+        }
+        if !result.resign_enabled {
+            totals.resign_disabled += 1;
+            totals.false_resignations += usize::from(result.false_resignation());
+        }
+        let finished = shared.finished.fetch_add(1, Ordering::Relaxed) + 1;
+        let positions = shared.positions.fetch_add(moves.len(), Ordering::Relaxed) + moves.len();
+        let elapsed = shared.start.elapsed().as_secs_f64();
+        eprintln!(
+            "Progress: {finished}/{} games, {positions} positions, {:.2} games/s",
+            config.games,
+            finished as f64 / elapsed
+        );
+    }
+    if let Some(writer) = writer {
+        let _ = writer.finish()?;
+    }
+    Ok(totals)
 }
@@ -1,21 +1,324 @@
 //! The main entry point for the UCI engine binary.
 
-use std::env;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::{Parser, Subcommand};
+use pabi::chess::position::Position;
+use pabi::engine::EngineConfig;
+use pabi::evaluation::{self, Backend};
+use pabi::search::{mcts, Limits};
+
+/// Pabi chess engine. Starts UCI session unless a command is given.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Evaluation backend: "pesto" (default), "candle" or "quantized".
+    #[arg(long, global = true)]
+    evaluator: Option<Backend>,
+    /// Network weights for the evaluation backends that need them.
+    #[arg(long, global = true)]
+    weights: Option<PathBuf>,
+    /// HTTPS URL to download the network weights from instead of --weights.
+    /// The weights are cached in $PABI_CACHE_DIR (~/.cache/pabi by default).
+    #[cfg(feature = "network-download")]
+    #[arg(
+        long,
+        global = true,
+        conflicts_with = "weights",
+        requires = "weights_sha256"
+    )]
+    weights_url: Option<String>,
+    /// Expected SHA-256 checksum of the weights downloaded from --weights-url.
+    #[cfg(feature = "network-download")]
+    #[arg(long, global = true)]
+    weights_sha256: Option<String>,
+    /// HTTP(S) URL to POST the JSON snapshots of the searches to during the UCI
+    /// session, e.g. for live analysis overlays.
+    #[cfg(feature = "broadcast")]
+    #[arg(long, global = true)]
+    broadcast_url: Option<String>,
+    /// Minimum time between the snapshots of a search in milliseconds.
+    #[cfg(feature = "broadcast")]
+    #[arg(long, global = true, default_value_t = 1000)]
+    broadcast_interval: u64,
+    /// Engine defaults in TOML format, see `pabi::engine::config`. Defaults
+    /// to pabi.toml next to the binary if it exists. The flags above take
+    /// precedence over the file.
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// OpenBench command for determining the relative speed of an engine.
+    Bench {
+        /// Print the time to reach the bench depth in each position and the
+        /// throughput scaling before the result.
+        #[arg(long)]
+        report: bool,
+        /// Measure the scaling from 1 up to this number of threads. Implies
+        /// --report.
+        #[arg(long, default_value_t = 1)]
+        threads: usize,
+        /// Pin the threads to the CPU cores. Implies --report.
+        #[arg(long)]
+        affinity: bool,
+    },
+    /// Measures the throughput of move generation, making moves, evaluation
+    /// and search on this machine.
+    Speedtest {
+        /// Duration of each measurement in milliseconds.
+        #[arg(long, default_value_t = 1000)]
+        millis: u64,
+    },
+    /// Prints static evaluation of the position (in centipawns, from the
+    /// perspective of the player to move).
+    Eval {
+        /// Position in FEN or EPD format.
+        fen: String,
+    },
+    /// Searches the position and prints the best move in UCI format and its
+    /// score.
+    Bestmove {
+        /// Position in FEN or EPD format.
+        fen: String,
+        /// Search time in milliseconds. Defaults to 1000 if neither time nor
+        /// nodes are limited.
+        #[arg(long)]
+        movetime: Option<u64>,
+        /// Maximum number of nodes to search.
+        #[arg(long)]
+        nodes: Option<u64>,
+    },
+    /// Searches the position after the given moves and prints the board, the
+    /// score and the principal variation in SAN.
+    Analyze {
+        /// Position in FEN or EPD format, the starting position by default.
+        #[arg(long)]
+        fen: Option<String>,
+        /// Space-separated moves to play before the search in SAN ("e4 e5
+        /// Nf3") or UCI ("e2e4 e7e5 g1f3") notation.
+        #[arg(long, default_value = "")]
+        moves: String,
+        /// Search time in milliseconds. Defaults to 1000 if neither time nor
+        /// nodes are limited.
+        #[arg(long)]
+        movetime: Option<u64>,
+        /// Maximum number of nodes to search.
+        #[arg(long)]
+        nodes: Option<u64>,
+    },
+    /// Searches every position of the games in the PGN file and annotates the
+    /// inaccuracies (?!), mistakes (?) and blunders (??). The annotated games
+    /// are printed in PGN.
+    Annotate {
+        /// PGN file with one or more games.
+        pgn: PathBuf,
+        /// Number of nodes to search in each position.
+        #[arg(long, default_value_t = 2000)]
+        nodes: u64,
+    },
+    /// Serves analysis to web frontends: JSON messages over WebSocket on the
+    /// local port, see `pabi::engine::server`.
+    #[cfg(feature = "server")]
+    Serve {
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Counts the leaf nodes of the move generation tree for each legal move
+    /// (perft divide).
+    Perft {
+        /// Position in FEN or EPD format.
+        fen: String,
+        depth: u8,
+        /// Print the results as a single line of JSON.
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+const DEFAULT_MOVETIME: Duration = Duration::from_millis(1000);
 
 fn main() -> anyhow::Result<()> {
-    let args: Vec<String> = env::args().collect();
+    #[allow(unused_mut, reason = "only updated with network-download feature")]
+    let mut cli = Cli::parse();
+    pabi::chess::verify_tables()?;
+    let config = match cli.config.clone().or_else(EngineConfig::default_path) {
+        Some(path) => EngineConfig::load(&path)?,
+        None => EngineConfig::default(),
+    };
+    let backend = cli.evaluator.or(config.evaluator).unwrap_or(Backend::Pesto);
+    if cli.weights.is_none() {
+        cli.weights.clone_from(&config.weights);
+    }
+    #[cfg(feature = "network-download")]
+    if let (Some(url), Some(sha256)) = (&cli.weights_url, &cli.weights_sha256) {
+        let cache_dir = evaluation::download::default_cache_dir();
+        cli.weights = Some(evaluation::download::fetch(url, sha256, &cache_dir)?);
+    }
+    let evaluator = backend.create(cli.weights.as_deref())?;
+    match cli.command {
+        Some(Command::Bench {
+            report,
+            threads,
+            affinity,
+        }) => {
+            if report || threads > 1 || affinity {
+                let report = pabi::engine::bench_report(
+                    pabi::engine::BENCH_POSITIONS,
+                    pabi::engine::BENCH_DEPTH,
+                    threads,
+                    affinity,
+                    &*evaluator,
+                )?;
+                println!("{report}");
+            }
+            // The last line is parsed by OpenBench.
+            pabi::engine::openbench()?;
+        },
+        Some(Command::Speedtest { millis }) => {
+            pabi::print_binary_info();
+            println!("Evaluator: {backend:?}");
+            let result = pabi::engine::speedtest(&*evaluator, Duration::from_millis(millis))?;
+            println!("{result}");
+        },
+        Some(Command::Eval { fen }) => {
+            let position = parse_position(&fen)?;
+            let prediction = evaluator.evaluate(&[position])?.remove(0);
+            println!("{}", evaluation::value_to_centipawns(prediction.value));
+        },
+        Some(Command::Bestmove {
+            fen,
+            movetime,
+            nodes,
+        }) => {
+            let position = parse_position(&fen)?;
+            let mut limits = Limits {
+                time: movetime.map(Duration::from_millis),
+                nodes,
+                ..Limits::default()
+            };
+            if limits.time.is_none() && limits.nodes.is_none() {
+                limits.time = Some(DEFAULT_MOVETIME);
+            }
+            let result = mcts::search(
+                &position,
+                &limits,
+                &mcts::Config::default(),
+                &*evaluator,
+                &AtomicBool::new(false),
+            )?;
+            match result.best_move {
+                Some(best_move) => println!("{best_move} {}", result.score),
+                None => println!("0000 {}", result.score),
+            }
+        },
+        Some(Command::Analyze {
+            fen,
+            moves,
+            movetime,
+            nodes,
+        }) => {
+            let mut position = match fen {
+                Some(fen) => parse_position(&fen)?,
+                None => Position::starting(),
+            };
+            for input in moves.split_whitespace() {
+                let next_move = position.parse_move(input)?;
+                position.make_move(&next_move);
+            }
+            let limits = Limits {
+                time: movetime.map(Duration::from_millis),
+                nodes,
+                ..Limits::default()
+            };
+            analyze(&position, limits, &*evaluator)?;
+        },
+        Some(Command::Annotate { pgn, nodes }) => {
+            let pgn = std::fs::read_to_string(&pgn)
+                .with_context(|| format!("reading {}", pgn.display()))?;
+            let config = pabi::engine::annotate::Config {
+                nodes,
+                ..pabi::engine::annotate::Config::default()
+            };
+            print!(
+                "{}",
+                pabi::engine::annotate::annotate_pgn(&pgn, &config, &*evaluator)?
+            );
+        },
+        #[cfg(feature = "server")]
+        Some(Command::Serve { port }) => {
+            eprintln!("Listening on ws://127.0.0.1:{port}");
+            pabi::engine::server::serve(port, Arc::from(evaluator))?;
+        },
+        Some(Command::Perft { fen, depth, json }) => {
+            let position = parse_position(&fen)?;
+            let result = pabi::chess::position::divide(&position, depth);
+            if json {
+                println!("{}", result.to_json());
+            } else {
+                println!("{result}");
+            }
+        },
+        None => {
+            pabi::print_engine_info();
+            pabi::print_binary_info();
 
-    // OpenBench command for determining the relative speed of an engine.
-    if args.len() == 2 && args[1] == "bench" {
-        pabi::engine::openbench();
-        return Ok(());
+            let mut input = std::io::stdin().lock();
+            let mut engine = pabi::engine::Engine::new(&mut input, std::io::stdout())
+                .with_evaluator(Arc::from(evaluator))
+                .with_backend(backend)
+                .with_config(&config)?;
+            #[cfg(feature = "broadcast")]
+            if let Some(url) = &cli.broadcast_url {
+                engine =
+                    engine.with_broadcast(url, Duration::from_millis(cli.broadcast_interval))?;
+            }
+            engine.uci_loop()?;
+        },
     }
+    Ok(())
+}
 
-    pabi::print_engine_info();
-    pabi::print_binary_info();
+/// Prints the board with coordinates and the search results.
+fn analyze(
+    position: &Position,
+    mut limits: Limits,
+    evaluator: &dyn evaluation::Evaluator,
+) -> anyhow::Result<()> {
+    if limits.time.is_none() && limits.nodes.is_none() {
+        limits.time = Some(DEFAULT_MOVETIME);
+    }
+    let board = format!("{position:?}");
+    for (rank, line) in board.lines().skip(1).take(8).enumerate() {
+        println!("{} {line}", 8 - rank);
+    }
+    println!("  a b c d e f g h");
+    println!();
+    println!("FEN: {position}");
+    let result = mcts::search(
+        position,
+        &limits,
+        &mcts::Config::default(),
+        evaluator,
+        &AtomicBool::new(false),
+    )?;
+    match result.best_move {
+        Some(best_move) => println!("Best move: {} ({best_move})", position.to_san(&best_move)),
+        None => println!("Best move: none"),
+    }
+    println!("Score: {}", result.score);
+    println!("PV: {}", position.san_line(&result.pv));
+    println!("Nodes: {}", result.nodes);
+    Ok(())
+}
 
-    let mut input = std::io::stdin().lock();
-    let mut output = std::io::stdout().lock();
-    let mut engine = pabi::engine::Engine::new(&mut input, &mut output);
-    engine.uci_loop()
+fn parse_position(fen: &str) -> anyhow::Result<Position> {
+    Position::try_from(fen).with_context(|| format!("parsing position {fen:?}"))
 }
@@ -2,18 +2,24 @@
 //! mappings are pre-calculated where possible to provide an efficient way of
 //! generating moves.
 //!
-//! The implementation uses BMI2 (if available) for performance ([reference]),
-//! specifically the PEXT instruction for [PEXT Bitboards].
+//! The sliding piece attacks use the PEXT instruction from BMI2 ([reference])
+//! for [PEXT Bitboards] if the CPU supports it. The support is detected at
+//! runtime unless the binary is compiled with `bmi2` target feature, so the
+//! same binary works on older CPUs, where it falls back to [Magic Bitboards],
+//! see [`SliderBackend`].
 //!
 //! [reference]: https://www.chessprogramming.org/BMI2
 //! [PEXT Bitboards]: https://www.chessprogramming.org/BMI2#PEXTBitboards
+//! [Magic Bitboards]: https://www.chessprogramming.org/Magic_Bitboards
 
 // TODO: This code is probably by far the least appealing in the project.
 // Refactor it and make it nicer.
 
+use std::fmt;
+
 use super::generated;
 use crate::chess::bitboard::{Bitboard, Pieces};
-use crate::chess::core::{Square, BOARD_SIZE};
+use crate::chess::core::{Square, BOARD_SIZE, BOARD_WIDTH};
 use crate::environment::Player;
 
 pub(super) fn king_attacks(from: Square) -> Bitboard {
@@ -25,6 +31,22 @@ pub(super) fn queen_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
 }
 
 pub(super) fn rook_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
+    if has_bmi2() {
+        rook_attacks_pext(from, occupancy)
+    } else {
+        rook_attacks_magic(from, occupancy)
+    }
+}
+
+pub(super) fn bishop_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
+    if has_bmi2() {
+        bishop_attacks_pext(from, occupancy)
+    } else {
+        bishop_attacks_magic(from, occupancy)
+    }
+}
+
+fn rook_attacks_pext(from: Square, occupancy: Bitboard) -> Bitboard {
     generated::ROOK_ATTACKS[generated::ROOK_ATTACK_OFFSETS[from as usize]
         + pext(
             occupancy.bits(),
@@ -32,7 +54,7 @@ pub(super) fn rook_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
         ) as usize]
 }
 
-pub(super) fn bishop_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
+fn bishop_attacks_pext(from: Square, occupancy: Bitboard) -> Bitboard {
     generated::BISHOP_ATTACKS[generated::BISHOP_ATTACK_OFFSETS[from as usize]
         + pext(
             occupancy.bits(),
@@ -40,6 +62,30 @@ pub(super) fn bishop_attacks(from: Square, occupancy: Bitboard) -> Bitboard {
         ) as usize]
 }
 
+fn rook_attacks_magic(from: Square, occupancy: Bitboard) -> Bitboard {
+    let square = from as usize;
+    generated::ROOK_MAGIC_ATTACKS[generated::ROOK_ATTACK_OFFSETS[square]
+        + magic_index(
+            occupancy.bits() & generated::ROOK_RELEVANT_OCCUPANCIES[square],
+            generated::ROOK_MAGICS[square],
+            generated::ROOK_MAGIC_SHIFTS[square],
+        )]
+}
+
+fn bishop_attacks_magic(from: Square, occupancy: Bitboard) -> Bitboard {
+    let square = from as usize;
+    generated::BISHOP_MAGIC_ATTACKS[generated::BISHOP_ATTACK_OFFSETS[square]
+        + magic_index(
+            occupancy.bits() & generated::BISHOP_RELEVANT_OCCUPANCIES[square],
+            generated::BISHOP_MAGICS[square],
+            generated::BISHOP_MAGIC_SHIFTS[square],
+        )]
+}
+
+const fn magic_index(relevant_occupancy: u64, magic: u64, shift: u32) -> usize {
+    (relevant_occupancy.wrapping_mul(magic) >> shift) as usize
+}
+
 pub(super) const fn knight_attacks(square: Square) -> Bitboard {
     generated::KNIGHT_ATTACKS[square as usize]
 }
@@ -63,27 +109,86 @@ const fn rook_ray(from: Square, to: Square) -> Bitboard {
     generated::ROOK_RAYS[(from as usize) * (BOARD_SIZE as usize) + to as usize]
 }
 
-// TODO: Document.
+/// Implementation of the sliding piece attack lookups.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliderBackend {
+    /// PEXT instruction, the binary was compiled for BMI2.
+    CompiledPext,
+    /// PEXT instruction, BMI2 support was detected at runtime.
+    DetectedPext,
+    /// Magic bitboards: the CPU does not support BMI2.
+    Magic,
+}
+
+impl SliderBackend {
+    /// Returns the implementation used on this CPU.
+    #[must_use]
+    pub fn current() -> Self {
+        if cfg!(target_feature = "bmi2") {
+            Self::CompiledPext
+        } else if has_bmi2() {
+            Self::DetectedPext
+        } else {
+            Self::Magic
+        }
+    }
+}
+
+impl fmt::Display for SliderBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CompiledPext => {
+                f.write_str("PEXT (compiled with BMI2)")?;
+                if !cpu_supports_bmi2() {
+                    // The compiler is free to use BMI2 anywhere, this binary
+                    // is likely to crash.
+                    f.write_str(", WARNING: the CPU does not support BMI2")?;
+                }
+                Ok(())
+            },
+            Self::DetectedPext => f.write_str("PEXT (BMI2 detected at runtime)"),
+            Self::Magic => f.write_str("magic bitboards (no BMI2)"),
+        }
+    }
+}
+
+/// Whether the PEXT lookups can be used. Only checked at runtime if the binary
+/// is not compiled for BMI2, the result of the detection is cached by the
+/// standard library.
+#[inline]
+fn has_bmi2() -> bool {
+    cfg!(target_feature = "bmi2") || cpu_supports_bmi2()
+}
+
+fn cpu_supports_bmi2() -> bool {
+    #[cfg(target_arch = "x86_64")]
+    {
+        std::is_x86_feature_detected!("bmi2")
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        false
+    }
+}
+
+/// Parallel bits extract: gathers the bits of `a` selected by `mask` into the
+/// low bits of the result. Only called if [`has_bmi2`] is true.
+#[inline]
 fn pext(a: u64, mask: u64) -> u64 {
     #[cfg(target_arch = "x86_64")]
     {
-        if cfg!(target_feature = "bmi2") {
-            return unsafe { core::arch::x86_64::_pext_u64(a, mask) };
+        #[target_feature(enable = "bmi2")]
+        unsafe fn pext_bmi2(a: u64, mask: u64) -> u64 {
+            core::arch::x86_64::_pext_u64(a, mask)
         }
+        debug_assert!(has_bmi2());
+        // SAFETY: The callers check that the CPU supports BMI2.
+        unsafe { pext_bmi2(a, mask) }
     }
-    // Fallback.
-    let mut result = 0u64;
-    let mut mask = mask;
-    let mut scanning_bit = 1u64;
-    while mask != 0 {
-        let ls1b = 1u64 << mask.trailing_zeros();
-        if (a & ls1b) != 0 {
-            result |= scanning_bit;
-        }
-        mask ^= ls1b;
-        scanning_bit <<= 1;
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        unreachable!("PEXT is only available on x86_64, got {a} {mask}")
     }
-    result
 }
 
 #[derive(Debug)]
@@ -200,18 +305,50 @@ impl AttackInfo {
     }
 }
 
-pub(super) const WHITE_SHORT_CASTLE_KING_WALK: Bitboard =
-    Bitboard::from_bits(0x0000_0000_0000_0060);
-pub(super) const WHITE_SHORT_CASTLE_ROOK_WALK: Bitboard =
-    Bitboard::from_bits(0x0000_0000_0000_0060);
-pub(super) const WHITE_LONG_CASTLE_KING_WALK: Bitboard = Bitboard::from_bits(0x0000_0000_0000_000C);
-pub(super) const WHITE_LONG_CASTLE_ROOK_WALK: Bitboard = Bitboard::from_bits(0x0000_0000_0000_000E);
-pub(super) const BLACK_SHORT_CASTLE_KING_WALK: Bitboard =
-    Bitboard::from_bits(0x6000_0000_0000_0000);
-pub(super) const BLACK_SHORT_CASTLE_ROOK_WALK: Bitboard =
-    Bitboard::from_bits(0x6000_0000_0000_0000);
-pub(super) const BLACK_LONG_CASTLE_KING_WALK: Bitboard = Bitboard::from_bits(0x0C00_0000_0000_0000);
-pub(super) const BLACK_LONG_CASTLE_ROOK_WALK: Bitboard = Bitboard::from_bits(0x0E00_0000_0000_0000);
+impl fmt::Display for AttackInfo {
+    /// Prints the bitboards side by side as labelled ASCII grids, from the
+    /// perspective of White (rank 8 on top), marking the set squares with `x`.
+    /// Each line is self-contained, so the output can be sent line by line in
+    /// `info string` UCI responses.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const GRID_WIDTH: usize = 1 + 2 * BOARD_WIDTH as usize;
+        let boards = [
+            ("attacks", self.attacks),
+            ("checkers", self.checkers),
+            ("pins", self.pins),
+            ("safe king", self.safe_king_squares),
+        ];
+        let header = boards
+            .iter()
+            .map(|(name, _)| format!("  {name:<width$}", width = GRID_WIDTH - 2))
+            .collect::<Vec<_>>()
+            .join("  ");
+        writeln!(f, "{}", header.trim_end())?;
+        for rank in (0..BOARD_WIDTH).rev() {
+            let row = boards
+                .iter()
+                .map(|(_, board)| {
+                    let squares: String = (0..BOARD_WIDTH)
+                        .map(|file| {
+                            let square = Square::try_from(rank * BOARD_WIDTH + file)
+                                .expect("rank and file are within the board");
+                            if board.contains(square) {
+                                " x"
+                            } else {
+                                " ."
+                            }
+                        })
+                        .collect();
+                    format!("{}{squares}", rank + 1)
+                })
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{row}")?;
+        }
+        let files = vec!["  a b c d e f g h"; boards.len()].join("  ");
+        write!(f, "{files}")
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -221,6 +358,242 @@ mod tests {
     use crate::chess::core::Rank;
     use crate::chess::position::Position;
 
+    /// Software PEXT to check the tables on CPUs without BMI2.
+    fn pext_software(a: u64, mut mask: u64) -> u64 {
+        let mut result = 0;
+        let mut bit = 1;
+        while mask != 0 {
+            if a & mask & mask.wrapping_neg() != 0 {
+                result |= bit;
+            }
+            mask &= mask - 1;
+            bit <<= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn backends_agree() {
+        use rand::{RngCore, SeedableRng};
+
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(42);
+        for square in Square::iter() {
+            let index = square as usize;
+            for _ in 0..1000 {
+                // Sparse occupancies are closer to the real positions.
+                let occupancy = Bitboard::from_bits(rng.next_u64() & rng.next_u64());
+                let rook = generated::ROOK_ATTACKS[generated::ROOK_ATTACK_OFFSETS[index]
+                    + pext_software(
+                        occupancy.bits(),
+                        generated::ROOK_RELEVANT_OCCUPANCIES[index],
+                    ) as usize];
+                let bishop = generated::BISHOP_ATTACKS[generated::BISHOP_ATTACK_OFFSETS[index]
+                    + pext_software(
+                        occupancy.bits(),
+                        generated::BISHOP_RELEVANT_OCCUPANCIES[index],
+                    ) as usize];
+                assert_eq!(rook_attacks_magic(square, occupancy), rook);
+                assert_eq!(bishop_attacks_magic(square, occupancy), bishop);
+                if has_bmi2() {
+                    assert_eq!(rook_attacks_pext(square, occupancy), rook);
+                    assert_eq!(bishop_attacks_pext(square, occupancy), bishop);
+                }
+                assert_eq!(rook_attacks(square, occupancy), rook);
+                assert_eq!(bishop_attacks(square, occupancy), bishop);
+            }
+        }
+    }
+
+    /// Squares reached from `square` by repeating each step (at most `limit`
+    /// times) until leaving the board or reaching an occupied square, which is
+    /// included.
+    fn cast(square: usize, occupancy: u64, steps: &[(i8, i8)], limit: usize) -> u64 {
+        let mut attacks = 0;
+        for &(file_step, rank_step) in steps {
+            let (mut file, mut rank) = ((square % 8) as i8, (square / 8) as i8);
+            for _ in 0..limit {
+                file += file_step;
+                rank += rank_step;
+                if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+                    break;
+                }
+                let target = 1u64 << (rank * 8 + file);
+                attacks |= target;
+                if occupancy & target != 0 {
+                    break;
+                }
+            }
+        }
+        attacks
+    }
+
+    /// Squares from `from` (inclusive) to `to` (exclusive) if they are on the
+    /// same line in one of the `steps` directions.
+    fn cast_ray(from: usize, to: usize, steps: &[(i8, i8)]) -> u64 {
+        for &(file_step, rank_step) in steps {
+            let mut ray = 1 << from;
+            let (mut file, mut rank) = ((from % 8) as i8, (from / 8) as i8);
+            loop {
+                file += file_step;
+                rank += rank_step;
+                if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+                    break;
+                }
+                let square = (rank * 8 + file) as usize;
+                if square == to {
+                    return ray;
+                }
+                ray |= 1 << square;
+            }
+        }
+        0
+    }
+
+    const ROOK_STEPS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+    const BISHOP_STEPS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+
+    /// Recomputes all generated tables by ray casting, so that a bug in the
+    /// generator does not silently corrupt the move generation.
+    #[test]
+    fn generated_tables() {
+        const KNIGHT_STEPS: [(i8, i8); 8] = [
+            (1, 2),
+            (2, 1),
+            (2, -1),
+            (1, -2),
+            (-1, -2),
+            (-2, -1),
+            (-2, 1),
+            (-1, 2),
+        ];
+        let queen_steps = [ROOK_STEPS, BISHOP_STEPS].concat();
+        let edges = |square: usize| {
+            let (file, rank) = (square % 8, square / 8);
+            let mut edges = 0u64;
+            if file != 0 {
+                edges |= 0x0101_0101_0101_0101;
+            }
+            if file != 7 {
+                edges |= 0x8080_8080_8080_8080;
+            }
+            if rank != 0 {
+                edges |= 0xFF;
+            }
+            if rank != 7 {
+                edges |= 0xFF << 56;
+            }
+            edges
+        };
+        let magic_backends: [fn(Square, Bitboard) -> Bitboard; 2] =
+            [rook_attacks_magic, bishop_attacks_magic];
+        let (mut rook_offset, mut bishop_offset) = (0, 0);
+        for square in 0..BOARD_SIZE as usize {
+            let expect = |table: Bitboard, expected: u64| {
+                assert_eq!(
+                    table.bits(),
+                    expected,
+                    "{:?}",
+                    Square::try_from(square as u8)
+                );
+            };
+            expect(
+                generated::KNIGHT_ATTACKS[square],
+                cast(square, 0, &KNIGHT_STEPS, 1),
+            );
+            expect(
+                generated::KING_ATTACKS[square],
+                cast(square, 0, &queen_steps, 1),
+            );
+            // Pawns never stand on the first and the last ranks.
+            let pawn_steps: &[(i8, i8)] = if (1..7).contains(&(square / 8)) {
+                &[(-1, 1), (1, 1)]
+            } else {
+                &[]
+            };
+            expect(
+                generated::WHITE_PAWN_ATTACKS[square],
+                cast(square, 0, pawn_steps, 1),
+            );
+            let pawn_steps: Vec<_> = pawn_steps
+                .iter()
+                .map(|&(file, rank)| (file, -rank))
+                .collect();
+            expect(
+                generated::BLACK_PAWN_ATTACKS[square],
+                cast(square, 0, &pawn_steps, 1),
+            );
+
+            for target in 0..BOARD_SIZE as usize {
+                let index = square * BOARD_SIZE as usize + target;
+                let rook = cast_ray(square, target, &ROOK_STEPS);
+                let bishop = cast_ray(square, target, &BISHOP_STEPS);
+                expect(generated::ROOK_RAYS[index], rook);
+                expect(generated::BISHOP_RAYS[index], bishop);
+                expect(generated::RAYS[index], rook | bishop);
+            }
+
+            for (steps, relevant, offsets, offset, table, magic) in [
+                (
+                    &ROOK_STEPS,
+                    generated::ROOK_RELEVANT_OCCUPANCIES,
+                    generated::ROOK_ATTACK_OFFSETS,
+                    &mut rook_offset,
+                    &generated::ROOK_ATTACKS[..],
+                    magic_backends[0],
+                ),
+                (
+                    &BISHOP_STEPS,
+                    generated::BISHOP_RELEVANT_OCCUPANCIES,
+                    generated::BISHOP_ATTACK_OFFSETS,
+                    &mut bishop_offset,
+                    &generated::BISHOP_ATTACKS[..],
+                    magic_backends[1],
+                ),
+            ] {
+                let mask = cast(square, 0, steps, 8) & !edges(square);
+                assert_eq!(relevant[square], mask);
+                assert_eq!(offsets[square], *offset);
+                *offset += 1 << mask.count_ones();
+                // Enumerate all subsets of the relevant occupancy.
+                let mut occupancy = 0u64;
+                loop {
+                    let expected = cast(square, occupancy, steps, 8);
+                    expect(
+                        table[offsets[square] + pext_software(occupancy, mask) as usize],
+                        expected,
+                    );
+                    expect(
+                        magic(
+                            Square::try_from(square as u8).unwrap(),
+                            Bitboard::from_bits(occupancy),
+                        ),
+                        expected,
+                    );
+                    occupancy = occupancy.wrapping_sub(mask) & mask;
+                    if occupancy == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+        assert_eq!(rook_offset, generated::ROOK_ATTACKS.len());
+        assert_eq!(bishop_offset, generated::BISHOP_ATTACKS.len());
+    }
+
+    #[test]
+    fn backend() {
+        let backend = SliderBackend::current();
+        assert_eq!(
+            backend == SliderBackend::Magic,
+            !cfg!(target_feature = "bmi2") && !cpu_supports_bmi2()
+        );
+        assert!(!backend.to_string().contains("WARNING"), "{backend}");
+        assert_eq!(
+            SliderBackend::Magic.to_string(),
+            "magic bitboards (no BMI2)"
+        );
+    }
+
     #[test]
     fn sliders() {
         let occupancy = Bitboard::from_squares(&[
@@ -693,4 +1066,26 @@ mod tests {
         );
         assert!(attacks.xrays.is_empty());
     }
+
+    #[test]
+    fn display() {
+        let position =
+            Position::from_fen("4k3/4r3/8/8/8/2b5/3N4/4K3 w - - 0 1").expect("valid position");
+        assert_eq!(
+            position.attack_info().to_string(),
+            [
+                "  attacks            checkers           pins               safe king",
+                "8 . . . x x x . x  8 . . . . . . . .  8 . . . . . . . .  8 . . . . . . . .",
+                "7 x x x x x x x x  7 . . . . x . . .  7 . . . . . . . .  7 . . . . . . . .",
+                "6 . . . . x x . .  6 . . . . . . . .  6 . . . . . . . .  6 . . . . . . . .",
+                "5 x . . . x . . .  5 . . . . . . . .  5 . . . . . . . .  5 . . . . . . . .",
+                "4 . x . x x . . .  4 . . . . . . . .  4 . . . . . . . .  4 . . . . . . . .",
+                "3 . . . . x . . .  3 . . . . . . . .  3 . . . . . . . .  3 . . . . . . . .",
+                "2 . x . x x . . .  2 . . . . . . . .  2 . . . x . . . .  2 . . . . . x . .",
+                "1 x . . . x . . .  1 . . . . . . . .  1 . . . . . . . .  1 . . . x . x . .",
+                "  a b c d e f g h    a b c d e f g h    a b c d e f g h    a b c d e f g h",
+            ]
+            .join("\n")
+        );
+    }
 }
@@ -111,7 +111,7 @@ impl Bitboard {
     }
 
     #[must_use]
-    pub(super) const fn is_empty(self) -> bool {
+    pub(crate) const fn is_empty(self) -> bool {
         self.bits == 0
     }
 
@@ -167,9 +167,35 @@ impl Bitboard {
         Self::from_bits(self.bits.swap_bytes())
     }
 
+    /// Mirrors the board horizontally: file A becomes file H, file B becomes
+    /// file G and so on.
+    ///
+    /// ```
+    /// use pabi::chess::bitboard::Bitboard;
+    /// use pabi::chess::core::Square;
+    ///
+    /// assert_eq!(
+    ///     Bitboard::from_squares(&[Square::A1, Square::C5]).mirror_horizontally(),
+    ///     Bitboard::from_squares(&[Square::H1, Square::F5])
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn mirror_horizontally(&self) -> Self {
+        // Reverses the bits within each byte (rank) by swapping adjacent
+        // bits, pairs and nibbles.
+        const K1: u64 = 0x5555_5555_5555_5555;
+        const K2: u64 = 0x3333_3333_3333_3333;
+        const K4: u64 = 0x0F0F_0F0F_0F0F_0F0F;
+        let mut bits = self.bits;
+        bits = ((bits >> 1) & K1) | ((bits & K1) << 1);
+        bits = ((bits >> 2) & K2) | ((bits & K2) << 2);
+        bits = ((bits >> 4) & K4) | ((bits & K4) << 4);
+        Self::from_bits(bits)
+    }
+
     /// An efficient way to iterate over the set squares.
     #[must_use]
-    pub(super) const fn iter(self) -> BitboardIterator {
+    pub(crate) const fn iter(self) -> BitboardIterator {
         BitboardIterator { bits: self.bits }
     }
 }
@@ -300,7 +326,7 @@ impl From<Square> for Bitboard {
 /// [bitscan] forward operation.
 ///
 /// [bitscan]: https://www.chessprogramming.org/BitScan
-pub(super) struct BitboardIterator {
+pub(crate) struct BitboardIterator {
     bits: u64,
 }
 
@@ -357,6 +383,19 @@ pub(crate) struct Pieces {
 }
 
 impl Pieces {
+    /// Applies the transformation to the bitboards of all pieces.
+    #[must_use]
+    pub(super) fn map(&self, transform: impl Fn(&Bitboard) -> Bitboard) -> Self {
+        Self {
+            king: transform(&self.king),
+            queens: transform(&self.queens),
+            rooks: transform(&self.rooks),
+            bishops: transform(&self.bishops),
+            knights: transform(&self.knights),
+            pawns: transform(&self.pawns),
+        }
+    }
+
     pub(super) const fn empty() -> Self {
         Self {
             king: Bitboard::empty(),
@@ -412,6 +451,18 @@ impl Pieces {
         self.king | self.queens | self.rooks | self.bishops | self.knights | self.pawns
     }
 
+    #[must_use]
+    pub(super) const fn bitboard_for(&self, piece: PieceKind) -> Bitboard {
+        match piece {
+            PieceKind::King => self.king,
+            PieceKind::Queen => self.queens,
+            PieceKind::Rook => self.rooks,
+            PieceKind::Bishop => self.bishops,
+            PieceKind::Knight => self.knights,
+            PieceKind::Pawn => self.pawns,
+        }
+    }
+
     #[must_use]
     pub(super) fn bitboard_for_mut(&mut self, piece: PieceKind) -> &mut Bitboard {
         match piece {
@@ -2,6 +2,7 @@
 
 use std::fmt::{self, Write};
 use std::mem;
+use std::str::FromStr;
 
 use anyhow::bail;
 use itertools::Itertools;
@@ -21,12 +22,22 @@ pub const BOARD_SIZE: u8 = BOARD_WIDTH * BOARD_WIDTH;
 /// representation. The moves can also be indexed and fed as an input to the
 /// Neural Network evaluators that would be able assess their potential without
 /// evaluating post-states.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+///
+/// Since the source and target squares of a real move are always different,
+/// the encodings where they are the same are reserved for the sentinels:
+/// [`Move::NONE`] and [`Move::NULL`].
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Move(u16);
 
 impl Move {
     // First 6 bits are reserved for the `from` square.
     const FROM_MASK: u16 = 0b0000_0000_0011_1111;
+    /// Absence of a move (e.g. empty transposition table entry or no best
+    /// move in a terminal position). Encoded as A1A1.
+    pub const NONE: Self = Self(0);
+    /// Passing the turn to the opponent, used in null move pruning. Encoded as
+    /// B1B1 and serialized as `0000` in UCI.
+    pub const NULL: Self = Self(Square::B1 as u16 | ((Square::B1 as u16) << Self::TO_OFFSET));
     // Next 3 bits are reserved for the promotion (if any).
     const PROMOTION_MASK: u16 = 0b0111_0000_0000_0000;
     const PROMOTION_OFFSET: u8 = 12;
@@ -47,20 +58,40 @@ impl Move {
         Self(packed)
     }
 
+    /// Returns true for [`Move::NONE`].
+    #[must_use]
+    pub const fn is_none(&self) -> bool {
+        self.0 == Self::NONE.0
+    }
+
+    /// Returns true for [`Move::NULL`].
+    #[must_use]
+    pub const fn is_null(&self) -> bool {
+        self.0 == Self::NULL.0
+    }
+
+    /// Returns true if the move is neither [`Move::NONE`] nor [`Move::NULL`].
+    #[must_use]
+    pub const fn is_real(&self) -> bool {
+        !self.is_none() && !self.is_null()
+    }
+
+    /// Source square of the move. For the sentinels, the reserved square of
+    /// the encoding is returned.
     #[must_use]
-    pub(super) fn from(&self) -> Square {
+    pub(crate) fn from(&self) -> Square {
         let square = self.0 & Self::FROM_MASK;
         Square::try_from(square as u8).unwrap()
     }
 
     #[must_use]
-    pub(super) fn to(&self) -> Square {
+    pub(crate) fn to(&self) -> Square {
         let square = (self.0 & Self::TO_MASK) >> Self::TO_OFFSET;
         Square::try_from(square as u8).unwrap()
     }
 
     #[must_use]
-    pub(super) fn promotion(&self) -> Option<Promotion> {
+    pub(crate) fn promotion(&self) -> Option<Promotion> {
         let promo = (self.0 & Self::PROMOTION_MASK) >> Self::PROMOTION_OFFSET;
         unsafe { std::mem::transmute(promo as u8) }
     }
@@ -72,6 +103,22 @@ impl Move {
         Self::try_from(uci)
     }
 
+    /// Returns the move in UCI format, the inverse of [`Move::from_uci`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pabi::chess::core::Move;
+    ///
+    /// let next_move: Move = "e7e8q".parse().unwrap();
+    /// assert_eq!(next_move.uci(), "e7e8q");
+    /// assert_eq!(Move::NULL.uci(), "0000");
+    /// ```
+    #[must_use]
+    pub fn uci(&self) -> String {
+        self.to_string()
+    }
+
     /// Converts the move from the perspective of one player to the other, as if
     /// the other player's backrank is rank 1.
     ///
@@ -97,15 +144,32 @@ impl Move {
     }
 
     #[must_use]
-    pub(super) fn as_packed_int(&self) -> u16 {
+    pub(crate) const fn as_packed_int(&self) -> u16 {
         self.0
     }
+
+    /// Restores the move from [`Move::as_packed_int`] or returns [`None`] if
+    /// the value is not a valid encoding.
+    #[must_use]
+    pub(crate) const fn from_packed_int(packed: u16) -> Option<Self> {
+        let promotion = (packed & Self::PROMOTION_MASK) >> Self::PROMOTION_OFFSET;
+        if packed >> 15 != 0 || promotion > Promotion::Queen as u16 {
+            return None;
+        }
+        Some(Self(packed))
+    }
 }
 
 impl TryFrom<&str> for Move {
     type Error = anyhow::Error;
 
     fn try_from(uci: &str) -> anyhow::Result<Self> {
+        if uci == "0000" {
+            return Ok(Self::NULL);
+        }
+        if !uci.is_ascii() {
+            bail!("UCI move should be ASCII, got {uci}");
+        }
         match uci.len() {
             4 => Ok(Self::new(
                 Square::try_from(&uci[..2])?,
@@ -115,16 +179,28 @@ impl TryFrom<&str> for Move {
             5 => Ok(Self::new(
                 Square::try_from(&uci[..2])?,
                 Square::try_from(&uci[2..4])?,
-                Some(Promotion::from(uci.chars().nth(4).unwrap())),
+                Some(Promotion::try_from(uci.as_bytes()[4] as char)?),
             )),
             _ => bail!("UCI move should be 4 or 5 characters long, got {uci}"),
         }
     }
 }
 
+impl FromStr for Move {
+    type Err = anyhow::Error;
+
+    fn from_str(uci: &str) -> anyhow::Result<Self> {
+        Self::try_from(uci)
+    }
+}
+
 impl fmt::Display for Move {
-    /// Serializes a move to UCI-compatible representation.
+    /// Serializes a move to UCI-compatible representation. Both sentinels are
+    /// serialized as the UCI null move.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.is_real() {
+            return write!(f, "0000");
+        }
         write!(f, "{}{}", self.from(), self.to())?;
         if let Some(promotion) = self.promotion() {
             write!(f, "{}", PieceKind::from(promotion))?;
@@ -133,6 +209,46 @@ impl fmt::Display for Move {
     }
 }
 
+/// Moves are serialized in UCI format for human-readable formats (e.g. JSON)
+/// and as the packed integer for binary formats.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Move {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_u16(self.0)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Move {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        if deserializer.is_human_readable() {
+            let uci = <std::borrow::Cow<'_, str>>::deserialize(deserializer)?;
+            return Self::from_uci(&uci).map_err(D::Error::custom);
+        }
+        let packed = u16::deserialize(deserializer)?;
+        Self::from_packed_int(packed)
+            .ok_or_else(|| D::Error::custom(format!("invalid packed move: {packed:#06x}")))
+    }
+}
+
+impl fmt::Debug for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_none() {
+            write!(f, "Move::NONE")
+        } else if self.is_null() {
+            write!(f, "Move::NULL")
+        } else {
+            write!(f, "Move({self})")
+        }
+    }
+}
+
 /// Size of [`MoveList`] and an upper bound of moves in a chess position (which
 /// [seems to be 218](https://www.chessprogramming.org/Chess_Position). 256 provides the best
 /// performance through optimal memory alignment.
@@ -232,6 +348,19 @@ impl Square {
         unsafe { mem::transmute(56 ^ self as u8) }
     }
 
+    /// Mirrors the square horizontally (file A becomes file H and so on).
+    ///
+    /// ```
+    /// use pabi::chess::core::Square;
+    ///
+    /// assert_eq!(Square::A1.mirror_horizontally(), Square::H1);
+    /// assert_eq!(Square::D4.mirror_horizontally(), Square::E4);
+    /// ```
+    #[must_use]
+    pub fn mirror_horizontally(self) -> Self {
+        unsafe { mem::transmute(7 ^ self as u8) }
+    }
+
     fn next(self) -> Option<Self> {
         let next = self as u8 + 1;
         if next == BOARD_SIZE {
@@ -298,6 +427,14 @@ impl TryFrom<&str> for Square {
     }
 }
 
+impl FromStr for Square {
+    type Err = anyhow::Error;
+
+    fn from_str(square: &str) -> anyhow::Result<Self> {
+        Self::try_from(square)
+    }
+}
+
 /// Iterates over squares in the order from A1 to H8, from left to right, from
 /// bottom to the top.
 pub struct SquareIterator {
@@ -353,6 +490,17 @@ impl TryFrom<char> for File {
     }
 }
 
+impl FromStr for File {
+    type Err = anyhow::Error;
+
+    fn from_str(file: &str) -> anyhow::Result<Self> {
+        match file.chars().collect_tuple() {
+            Some((file,)) => Self::try_from(file),
+            None => bail!("file should be a single character, got \"{{file}}\""),
+        }
+    }
+}
+
 impl TryFrom<u8> for File {
     type Error = anyhow::Error;
 
@@ -397,17 +545,18 @@ impl Rank {
         }
     }
 
-    pub(super) const fn backrank(player: Player) -> Self {
+    pub(super) const fn pawns_starting(player: Player) -> Self {
         match player {
-            Player::White => Self::Rank1,
-            Player::Black => Self::Rank8,
+            Player::White => Self::Rank2,
+            Player::Black => Self::Rank7,
         }
     }
 
-    pub(super) const fn pawns_starting(player: Player) -> Self {
+    /// The rank where the player's pawns promote.
+    pub(super) const fn promotion(player: Player) -> Self {
         match player {
-            Player::White => Self::Rank2,
-            Player::Black => Self::Rank7,
+            Player::White => Self::Rank8,
+            Player::Black => Self::Rank1,
         }
     }
 }
@@ -423,6 +572,17 @@ impl TryFrom<char> for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = anyhow::Error;
+
+    fn from_str(rank: &str) -> anyhow::Result<Self> {
+        match rank.chars().collect_tuple() {
+            Some((rank,)) => Self::try_from(rank),
+            None => bail!("rank should be a single character, got \"{{rank}}\""),
+        }
+    }
+}
+
 impl TryFrom<u8> for Rank {
     type Error = anyhow::Error;
 
@@ -479,6 +639,7 @@ impl fmt::Display for PieceKind {
 }
 
 /// Represents a specific piece owned by a player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Piece {
     #[allow(missing_docs)]
     pub player: Player,
@@ -673,6 +834,17 @@ impl TryFrom<&str> for CastleRights {
     }
 }
 
+impl CastleRights {
+    /// Swaps the castling rights of White and Black.
+    #[must_use]
+    pub const fn flip_colors(self) -> Self {
+        Self::from_bits_truncate(
+            ((self.bits() & Self::WHITE_BOTH.bits()) << 2)
+                | ((self.bits() & Self::BLACK_BOTH.bits()) >> 2),
+        )
+    }
+}
+
 impl fmt::Display for CastleRights {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if *self == Self::NONE {
@@ -704,14 +876,16 @@ pub enum Promotion {
     Queen = 4,
 }
 
-impl From<char> for Promotion {
-    fn from(c: char) -> Self {
+impl TryFrom<char> for Promotion {
+    type Error = anyhow::Error;
+
+    fn try_from(c: char) -> anyhow::Result<Self> {
         match c {
-            'n' => Self::Knight,
-            'b' => Self::Bishop,
-            'r' => Self::Rook,
-            'q' => Self::Queen,
-            _ => unreachable!("unknown promotion piece, has to be in 'kbrq': {c}"),
+            'n' => Ok(Self::Knight),
+            'b' => Ok(Self::Bishop),
+            'r' => Ok(Self::Rook),
+            'q' => Ok(Self::Queen),
+            _ => bail!("promotion piece should be one of 'nbrq', got '{c}'"),
         }
     }
 }
@@ -918,4 +1092,53 @@ mod tests {
             Move::new(Square::E7, Square::E8, Some(Promotion::Queen))
         );
     }
+
+    #[test]
+    fn parse() {
+        let next_move: Move = "g7g8n".parse().unwrap();
+        assert_eq!(
+            next_move,
+            Move::new(Square::G7, Square::G8, Some(Promotion::Knight))
+        );
+        assert_eq!(next_move.uci(), "g7g8n");
+        assert_eq!("0000".parse::<Move>().unwrap(), Move::NULL);
+        assert!("e7e8k".parse::<Move>().is_err());
+        assert!("e2e".parse::<Move>().is_err());
+        assert!("é2e4".parse::<Move>().is_err());
+
+        assert_eq!("c6".parse::<Square>().unwrap(), Square::C6);
+        assert!("c9".parse::<Square>().is_err());
+        assert_eq!("f".parse::<File>().unwrap(), File::F);
+        assert!("fg".parse::<File>().is_err());
+        assert!("".parse::<File>().is_err());
+        assert_eq!("3".parse::<Rank>().unwrap(), Rank::Rank3);
+        assert!("9".parse::<Rank>().is_err());
+        assert_eq!("b".parse::<Player>().unwrap(), Player::Black);
+        assert!("black".parse::<Player>().is_err());
+    }
+
+    #[test]
+    fn move_sentinels() {
+        assert_ne!(Move::NONE, Move::NULL);
+        assert!(Move::NONE.is_none() && !Move::NONE.is_real());
+        assert!(Move::NULL.is_null() && !Move::NULL.is_real());
+        // Accessors do not panic on sentinels.
+        assert_eq!(Move::NONE.from(), Square::A1);
+        assert_eq!(Move::NULL.to(), Square::B1);
+        assert_eq!(Move::NULL.promotion(), None);
+        assert_eq!(Move::NONE.to_string(), "0000");
+        assert_eq!(Move::NULL.to_string(), "0000");
+        assert_eq!(format!("{:?}", Move::NONE), "Move::NONE");
+        assert_eq!(format!("{:?}", Move::NULL), "Move::NULL");
+        assert_eq!(Move::from_uci("0000").unwrap(), Move::NULL);
+
+        // No real move collides with the sentinels.
+        for from in Square::iter() {
+            for to in Square::iter().filter(|&to| to != from) {
+                let candidate = Move::new(from, to, None);
+                assert!(candidate.is_real(), "{candidate:?}");
+                assert_eq!(format!("{candidate:?}"), format!("Move({from}{to})"));
+            }
+        }
+    }
 }
@@ -1,10 +1,17 @@
+//! Full game of chess: tracks the position, the history of moves, clocks of
+//! both players and determines the outcome. This is the integration point for
+//! frontends, self-play and matches between engines.
+
+use std::fmt;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
 
-use shakmaty::Chess;
-use shakmaty_syzygy::{AmbiguousWdl, Tablebase};
+use anyhow::{bail, Context};
 
 use super::core::{Move, MoveList};
 use crate::chess::position::Position;
+use crate::chess::tablebase::{Tablebase, Wdl};
 use crate::chess::zobrist::RepetitionTable;
 use crate::environment::{Action, Environment, GameResult, Observation, Player};
 
@@ -18,113 +25,377 @@ impl Action for Move {
 
 impl Observation for Position {}
 
+const THREEFOLD_REPETITION: u8 = 3;
+const FIVEFOLD_REPETITION: u8 = 5;
+/// Number of plies without captures and pawn moves that ends the game
+/// automatically.
+const SEVENTY_FIVE_MOVE_PLIES: u8 = 150;
+
+/// Reason for the game to end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    Checkmate,
+    Resignation,
+    /// The player ran out of time.
+    Timeout,
+    Stalemate,
+    /// The position occurred three times and the draw was claimed, see
+    /// [`Game::with_draw_claims`].
+    ThreefoldRepetition,
+    /// No captures or pawn moves in the last 50 moves and the draw was
+    /// claimed, see [`Game::with_draw_claims`].
+    FiftyMoveRule,
+    /// The position occurred five times: the game is drawn automatically.
+    FivefoldRepetition,
+    /// No captures or pawn moves in the last 75 moves: the game is drawn
+    /// automatically.
+    SeventyFiveMoveRule,
+    /// Players agreed to a draw.
+    Agreement,
+    /// The result was determined by probing the endgame tablebase.
+    Tablebase,
+}
+
+/// Final result of the game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Outcome {
+    /// [`None`] if the game is drawn.
+    pub winner: Option<Player>,
+    pub termination: Termination,
+}
+
+impl Outcome {
+    const fn win(winner: Player, termination: Termination) -> Self {
+        Self {
+            winner: Some(winner),
+            termination,
+        }
+    }
+
+    const fn draw(termination: Termination) -> Self {
+        Self {
+            winner: None,
+            termination,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    /// Formats the outcome as PGN game result.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.winner {
+            Some(Player::White) => "1-0",
+            Some(Player::Black) => "0-1",
+            None => "1/2-1/2",
+        })
+    }
+}
+
+/// Chess clock of a single player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Clock {
+    pub remaining: Duration,
+    /// Time added after each move.
+    pub increment: Duration,
+}
+
+/// Move that was played in the game.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Record {
+    pub played: Move,
+    /// The move in Standard Algebraic Notation.
+    pub san: String,
+    /// Time spent on the move, if the clocks are used.
+    pub elapsed: Option<Duration>,
+}
+
+/// Game of chess from the given root position. The outcome is determined by
+/// the rules of chess (checkmate, stalemate, repetitions, etc), tablebases (if
+/// provided) or the players (resignation, draw agreement, timeout).
 pub struct Game {
+    root: Position,
     position: Position,
     perspective: Player,
     repetitions: RepetitionTable,
     moves: MoveList,
-    tablebase: Tablebase<Chess>,
-    threefold_repetition: bool,
+    history: Vec<Record>,
+    clocks: Option<[Clock; 2]>,
+    draw_offer: Option<Player>,
+    /// Outcome that is not determined by the position (e.g. resignation).
+    adjudicated: Option<Outcome>,
+    tablebase: Option<Arc<Tablebase>>,
+    /// Number of times the current position has occurred.
+    occurrences: u8,
+    claim_draws: bool,
 }
 
 impl Game {
-    pub(super) fn new(root: Position, tablebase_dir: &Path) -> Self {
+    #[must_use]
+    pub fn new(root: Position) -> Self {
         let mut repetitions = RepetitionTable::new();
-        let _ = repetitions.record(root.hash());
+        let occurrences = repetitions.record(root.hash());
 
         let perspective = root.us();
         let moves = root.generate_moves();
 
         Self {
+            root: root.clone(),
             position: root,
             perspective,
             repetitions,
             moves,
-            tablebase: read_tablebase(tablebase_dir),
-            threefold_repetition: false,
+            history: Vec::new(),
+            clocks: None,
+            draw_offer: None,
+            adjudicated: None,
+            tablebase: None,
+            occurrences,
+            claim_draws: true,
         }
     }
-}
 
-impl Environment<Move, Position> for Game {
-    fn actions(&self) -> &[Move] {
-        &self.moves
+    /// Adjudicates the game using Syzygy tablebases from given directory once
+    /// the number of pieces is low enough.
+    pub fn with_tablebase(self, tablebase_dir: &Path) -> anyhow::Result<Self> {
+        Ok(self.with_shared_tablebase(Arc::new(Tablebase::open(tablebase_dir)?)))
     }
 
-    fn apply(&mut self, action: &Move) -> &Position {
-        self.position.make_move(action);
-        self.threefold_repetition = self.repetitions.record(self.position.hash());
-        self.moves = self.position.generate_moves();
+    /// Same as [`Game::with_tablebase`] for the tablebases that are already
+    /// loaded, e.g. shared between many self-play games.
+    #[must_use]
+    pub fn with_shared_tablebase(mut self, tablebase: Arc<Tablebase>) -> Self {
+        self.tablebase = Some(tablebase);
+        self
+    }
+
+    /// Sets whether the players always claim the draw by threefold repetition
+    /// and the fifty-move rule as soon as it is available (the default),
+    /// ending the game immediately.
+    ///
+    /// Otherwise, the draws have to be claimed with [`Game::claim_draw`]. The
+    /// game still ends automatically after fivefold repetition or 75 moves
+    /// without captures and pawn moves, as required by the FIDE rules, so
+    /// the games are always finite.
+    #[must_use]
+    pub fn with_draw_claims(mut self, claim_draws: bool) -> Self {
+        self.claim_draws = claim_draws;
+        self
+    }
+
+    /// Starts the clocks of both players with given initial time and
+    /// increment.
+    #[must_use]
+    pub fn with_clocks(mut self, initial: Duration, increment: Duration) -> Self {
+        let clock = Clock {
+            remaining: initial,
+            increment,
+        };
+        self.clocks = Some([clock; 2]);
+        self
+    }
+
+    /// Returns the initial position of the game.
+    #[must_use]
+    pub fn root(&self) -> &Position {
+        &self.root
+    }
+
+    /// Returns the current position.
+    #[must_use]
+    pub fn position(&self) -> &Position {
         &self.position
     }
 
-    fn result(&self) -> Option<GameResult> {
-        debug_assert!(self.position.num_pieces() >= self.tablebase.max_pieces());
+    /// Returns the moves played since the root position.
+    #[must_use]
+    pub fn history(&self) -> &[Record] {
+        &self.history
+    }
 
-        if self.threefold_repetition {
-            return Some(GameResult::Draw);
+    /// Returns the clock of the player or [`None`] if the game is not timed.
+    #[must_use]
+    pub fn clock(&self, player: Player) -> Option<&Clock> {
+        self.clocks.as_ref().map(|clocks| &clocks[player as usize])
+    }
+
+    /// Returns the player who offered a draw that has not been accepted or
+    /// declined yet.
+    #[must_use]
+    pub fn draw_offer(&self) -> Option<Player> {
+        self.draw_offer
+    }
+
+    /// Plays the move and updates the clock of the player to move with the
+    /// time spent on it. If the player runs out of time, the game is lost and
+    /// the move is not played.
+    ///
+    /// # Errors
+    ///
+    /// If the game is already over or the move is illegal.
+    pub fn make_move(&mut self, next_move: &Move, elapsed: Option<Duration>) -> anyhow::Result<()> {
+        if self.outcome().is_some() {
+            bail!("game is already over");
         }
-        if self.position.halfmove_clock_expired() {
-            return Some(GameResult::Draw);
-        }
-        if self.position.num_pieces() == self.tablebase.max_pieces() {
-            // TODO: This is a bit of a hack right now and not precise. Maybe
-            // it's not that inmportant, but worth revisiting.
-            let wdl = self
-                .tablebase
-                .probe_wdl(&to_shakmaty_position(&self.position))
-                .unwrap();
-            match wdl {
-                AmbiguousWdl::Win | AmbiguousWdl::MaybeWin => {
-                    return if self.perspective == self.position.us() {
-                        Some(GameResult::Win)
-                    } else {
-                        Some(GameResult::Loss)
-                    };
-                },
-                AmbiguousWdl::Draw | AmbiguousWdl::BlessedLoss | AmbiguousWdl::CursedWin => {
-                    return Some(GameResult::Draw)
-                },
-                AmbiguousWdl::Loss | AmbiguousWdl::MaybeLoss => {
-                    return if self.perspective == self.position.us() {
-                        Some(GameResult::Loss)
-                    } else {
-                        Some(GameResult::Win)
-                    };
-                },
+        if !self.moves.contains(next_move) {
+            bail!("illegal move {next_move} in {}", self.position);
+        }
+        let player = self.position.us();
+        if let Some(clocks) = &mut self.clocks {
+            let clock = &mut clocks[player as usize];
+            let elapsed = elapsed.context("timed games require elapsed time")?;
+            if elapsed > clock.remaining {
+                clock.remaining = Duration::ZERO;
+                self.adjudicated = Some(Outcome::win(!player, Termination::Timeout));
+                return Ok(());
             }
+            clock.remaining = clock.remaining - elapsed + clock.increment;
+        }
+        // Making a move declines the opponent's draw offer.
+        if self.draw_offer == Some(!player) {
+            self.draw_offer = None;
+        }
+        self.history.push(Record {
+            played: *next_move,
+            san: self.position.to_san(next_move),
+            elapsed,
+        });
+        let _ = self.apply(next_move);
+        Ok(())
+    }
+
+    /// Offers a draw to the opponent. The offer stays valid until the opponent
+    /// accepts it or makes a move.
+    ///
+    /// # Errors
+    ///
+    /// If the game is already over.
+    pub fn offer_draw(&mut self, player: Player) -> anyhow::Result<()> {
+        if self.outcome().is_some() {
+            bail!("game is already over");
+        }
+        self.draw_offer = Some(player);
+        Ok(())
+    }
+
+    /// Accepts the draw offered by the opponent and ends the game.
+    ///
+    /// # Errors
+    ///
+    /// If the game is already over or the opponent has not offered a draw.
+    pub fn accept_draw(&mut self, player: Player) -> anyhow::Result<()> {
+        if self.outcome().is_some() {
+            bail!("game is already over");
+        }
+        if self.draw_offer != Some(!player) {
+            bail!("{player:?} can not accept a draw that was not offered by the opponent");
+        }
+        self.draw_offer = None;
+        self.adjudicated = Some(Outcome::draw(Termination::Agreement));
+        Ok(())
+    }
+
+    /// Claims a draw by threefold repetition or the fifty-move rule.
+    ///
+    /// # Errors
+    ///
+    /// If the game is already over or the draw can not be claimed.
+    pub fn claim_draw(&mut self) -> anyhow::Result<()> {
+        if self.outcome().is_some() {
+            bail!("game is already over");
+        }
+        self.adjudicated = Some(Outcome::draw(
+            self.claimable_draw().context("there is no draw to claim")?,
+        ));
+        Ok(())
+    }
+
+    /// Returns the reason the draw can be claimed in the current position.
+    fn claimable_draw(&self) -> Option<Termination> {
+        if self.occurrences >= THREEFOLD_REPETITION {
+            return Some(Termination::ThreefoldRepetition);
+        }
+        if self.position.halfmove_clock_expired() {
+            return Some(Termination::FiftyMoveRule);
+        }
+        None
+    }
+
+    /// Ends the game with the opponent of the player winning.
+    ///
+    /// # Errors
+    ///
+    /// If the game is already over.
+    pub fn resign(&mut self, player: Player) -> anyhow::Result<()> {
+        if self.outcome().is_some() {
+            bail!("game is already over");
+        }
+        self.adjudicated = Some(Outcome::win(!player, Termination::Resignation));
+        Ok(())
+    }
+
+    /// Returns the outcome of the game or [`None`] if it is still in progress.
+    #[must_use]
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.adjudicated.is_some() {
+            return self.adjudicated;
         }
         if self.moves.is_empty() {
-            // Stalemate.
-            if !self.position.in_check() {
-                return Some(GameResult::Draw);
+            if self.position.in_check() {
+                return Some(Outcome::win(self.position.them(), Termination::Checkmate));
+            }
+            return Some(Outcome::draw(Termination::Stalemate));
+        }
+        if self.occurrences >= FIVEFOLD_REPETITION {
+            return Some(Outcome::draw(Termination::FivefoldRepetition));
+        }
+        if self.position.halfmove_clock() >= SEVENTY_FIVE_MOVE_PLIES {
+            return Some(Outcome::draw(Termination::SeventyFiveMoveRule));
+        }
+        if self.claim_draws {
+            if let Some(termination) = self.claimable_draw() {
+                return Some(Outcome::draw(termination));
             }
-            // Player to move is in checkmate.
-            return if self.perspective == self.position.us() {
-                Some(GameResult::Loss)
-            } else {
-                Some(GameResult::Win)
-            };
+        }
+        if let Some(wdl) = self
+            .tablebase
+            .as_ref()
+            .and_then(|tablebase| tablebase.probe_wdl(&self.position))
+        {
+            return Some(match wdl {
+                Wdl::Win => Outcome::win(self.position.us(), Termination::Tablebase),
+                Wdl::Draw => Outcome::draw(Termination::Tablebase),
+                Wdl::Loss => Outcome::win(self.position.them(), Termination::Tablebase),
+            });
         }
         None
     }
 }
 
-fn read_tablebase(path: &Path) -> Tablebase<Chess> {
-    let mut tablebase = Tablebase::new();
-    tablebase.add_directory(path).unwrap();
-    tablebase
-}
+impl Environment<Move, Position> for Game {
+    fn actions(&self) -> &[Move] {
+        &self.moves
+    }
 
-// TODO: Converting to FEN and back is ineffective. It's possible to manipulate
-// the bitboard values directly.
-fn to_shakmaty_position(position: &Position) -> Chess {
-    position
-        .to_string()
-        .parse::<shakmaty::fen::Fen>()
-        .unwrap()
-        .into_position(shakmaty::CastlingMode::Standard)
-        .unwrap()
+    fn apply(&mut self, action: &Move) -> &Position {
+        self.position.make_move(action);
+        // The positions before a capture or a pawn move can never repeat, so
+        // the table stays small even in very long games.
+        if self.position.halfmove_clock() == 0 {
+            self.repetitions.clear();
+        }
+        self.occurrences = self.repetitions.record(self.position.hash());
+        self.moves = self.position.generate_moves();
+        &self.position
+    }
+
+    fn result(&self) -> Option<GameResult> {
+        let outcome = self.outcome()?;
+        Some(match outcome.winner {
+            None => GameResult::Draw,
+            Some(winner) if winner == self.perspective => GameResult::Win,
+            Some(_) => GameResult::Loss,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -133,15 +404,9 @@ mod tests {
 
     const TABLEBASE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/syzygy");
 
-    #[test]
-    fn syzygy_tablebases() {
-        let tables = read_tablebase(TABLEBASE_PATH.as_ref());
-        assert_eq!(tables.max_pieces(), 3);
-    }
-
     #[test]
     fn detect_repetition() {
-        let mut game = Game::new(Position::starting(), TABLEBASE_PATH.as_ref());
+        let mut game = Game::new(Position::starting());
         assert!(game.result().is_none());
         // Move 1.
         game.apply(&Move::from_uci("g1f3").unwrap());
@@ -170,8 +435,9 @@ mod tests {
         // KQvKR position with a forced win for white.
         let mut game = Game::new(
             Position::from_fen("4k3/8/8/5r2/4KQ2/8/8/8 w - - 0 1").expect("valid_position"),
-            TABLEBASE_PATH.as_ref(),
-        );
+        )
+        .with_tablebase(TABLEBASE_PATH.as_ref())
+        .expect("valid tablebase");
         // Test tablebases only support 3 pieces, so it will not be adjudicated
         // until the rook is captured.
         assert!(game.result().is_none());
@@ -188,8 +454,9 @@ mod tests {
     fn stalemate() {
         let mut game = Game::new(
             Position::from_fen("3b2qk/p6p/1p3Q1P/8/8/n7/PP6/K7 b - - 3 2").expect("valid_position"),
-            TABLEBASE_PATH.as_ref(),
-        );
+        )
+        .with_tablebase(TABLEBASE_PATH.as_ref())
+        .expect("valid tablebase");
         assert!(game.result().is_none());
 
         // Black has no moves and is not in check.
@@ -202,8 +469,9 @@ mod tests {
     fn checkmate() {
         let mut game = Game::new(
             Position::from_fen("3b3k/p5qp/1p3Q1P/8/8/n7/PP6/K7 w - - 4 3").expect("valid_position"),
-            TABLEBASE_PATH.as_ref(),
-        );
+        )
+        .with_tablebase(TABLEBASE_PATH.as_ref())
+        .expect("valid tablebase");
         assert!(game.result().is_none());
 
         game.apply(&Move::from_uci("f6g7").unwrap());
@@ -218,11 +486,203 @@ mod tests {
         let mut game = Game::new(
             Position::from_fen("8/5k2/3p4/1p1Pp2p/pP2Pp1P/P4P1K/8/8 b - - 99 50")
                 .expect("valid_position"),
-            TABLEBASE_PATH.as_ref(),
-        );
+        )
+        .with_tablebase(TABLEBASE_PATH.as_ref())
+        .expect("valid tablebase");
         assert!(game.result().is_none());
 
         game.apply(&Move::from_uci("f7f6").unwrap());
         assert_eq!(game.result(), Some(GameResult::Draw));
     }
+
+    #[test]
+    fn fivefold_repetition() {
+        let mut game = Game::new(Position::starting()).with_draw_claims(false);
+        let shuffle = ["g1f3", "g8f6", "f3g1", "f6g8"];
+        for _ in 0..3 {
+            for uci in shuffle {
+                game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+            }
+        }
+        // Threefold repetition has to be claimed.
+        assert!(game.outcome().is_none());
+        // The starting position occurs for the fifth time after the last move.
+        for uci in shuffle {
+            assert!(game.outcome().is_none());
+            game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+        }
+        assert_eq!(
+            game.outcome(),
+            Some(Outcome {
+                winner: None,
+                termination: Termination::FivefoldRepetition
+            })
+        );
+    }
+
+    #[test]
+    fn seventy_five_move_rule() {
+        // The fifty-move rule draw is not claimed.
+        let position = "8/5k2/3p4/1p1Pp2p/pP2Pp1P/P4P1K/8/8 b - - 140 80";
+        let mut game = Game::new(Position::from_fen(position).unwrap()).with_draw_claims(false);
+        let shuffle = ["f7e7", "h3g2", "e7f7", "g2h3"];
+        for uci in shuffle.iter().cycle().take(9) {
+            assert!(game.outcome().is_none());
+            game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+        }
+        assert_eq!(game.position().halfmove_clock(), 149);
+        assert!(game.outcome().is_none());
+        game.make_move(&Move::from_uci(shuffle[1]).unwrap(), None)
+            .unwrap();
+        assert_eq!(
+            game.outcome(),
+            Some(Outcome {
+                winner: None,
+                termination: Termination::SeventyFiveMoveRule
+            })
+        );
+    }
+
+    #[test]
+    fn claim_draw() {
+        let position = "8/5k2/3p4/1p1Pp2p/pP2Pp1P/P4P1K/8/8 b - - 98 50";
+        let mut game = Game::new(Position::from_fen(position).unwrap()).with_draw_claims(false);
+        assert!(game.claim_draw().is_err());
+        game.make_move(&Move::from_uci("f7e7").unwrap(), None)
+            .unwrap();
+        assert!(game.claim_draw().is_err());
+        game.make_move(&Move::from_uci("h3g2").unwrap(), None)
+            .unwrap();
+        game.claim_draw().unwrap();
+        assert_eq!(
+            game.outcome(),
+            Some(Outcome {
+                winner: None,
+                termination: Termination::FiftyMoveRule
+            })
+        );
+
+        // Claimed automatically by default.
+        let mut game = Game::new(Position::from_fen(position).unwrap());
+        for uci in ["f7e7", "h3g2"] {
+            game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+        }
+        assert_eq!(
+            game.outcome().map(|outcome| outcome.termination),
+            Some(Termination::FiftyMoveRule)
+        );
+        assert!(game.claim_draw().is_err());
+    }
+
+    #[test]
+    fn history() {
+        let mut game = Game::new(Position::starting());
+        for uci in ["e2e4", "e7e5", "g1f3", "b8c6", "f1b5", "a7a6", "e1g1"] {
+            game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+        }
+        assert_eq!(
+            game.history()
+                .iter()
+                .map(|record| record.san.as_str())
+                .collect::<Vec<_>>(),
+            vec!["e4", "e5", "Nf3", "Nc6", "Bb5", "a6", "O-O"]
+        );
+        assert_eq!(game.root().to_string(), Position::starting().to_string());
+        assert!(game.outcome().is_none());
+        assert!(game
+            .make_move(&Move::from_uci("e1g1").unwrap(), None)
+            .is_err());
+    }
+
+    #[test]
+    fn fools_mate() {
+        let mut game = Game::new(Position::starting());
+        for uci in ["f2f3", "e7e5", "g2g4", "d8h4"] {
+            game.make_move(&Move::from_uci(uci).unwrap(), None).unwrap();
+        }
+        assert_eq!(game.history().last().unwrap().san, "Qh4#");
+        let outcome = game.outcome().unwrap();
+        assert_eq!(outcome.winner, Some(Player::Black));
+        assert_eq!(outcome.termination, Termination::Checkmate);
+        assert_eq!(outcome.to_string(), "0-1");
+        assert_eq!(game.result(), Some(GameResult::Loss));
+        assert!(game.resign(Player::White).is_err());
+    }
+
+    #[test]
+    fn clocks() {
+        let mut game = Game::new(Position::starting())
+            .with_clocks(Duration::from_secs(10), Duration::from_secs(1));
+        game.make_move(
+            &Move::from_uci("e2e4").unwrap(),
+            Some(Duration::from_secs(3)),
+        )
+        .unwrap();
+        assert_eq!(
+            game.clock(Player::White).unwrap().remaining,
+            Duration::from_secs(8)
+        );
+        assert_eq!(
+            game.clock(Player::Black).unwrap().remaining,
+            Duration::from_secs(10)
+        );
+        assert!(game
+            .make_move(&Move::from_uci("e7e5").unwrap(), None)
+            .is_err());
+
+        // Black flags and the move is not played.
+        game.make_move(
+            &Move::from_uci("e7e5").unwrap(),
+            Some(Duration::from_secs(11)),
+        )
+        .unwrap();
+        assert_eq!(game.history().len(), 1);
+        assert_eq!(
+            game.outcome(),
+            Some(Outcome {
+                winner: Some(Player::White),
+                termination: Termination::Timeout
+            })
+        );
+    }
+
+    #[test]
+    fn draw_offer() {
+        let mut game = Game::new(Position::starting());
+        game.offer_draw(Player::White).unwrap();
+        assert!(game.accept_draw(Player::White).is_err());
+        // Making a move declines the offer.
+        game.make_move(&Move::from_uci("e2e4").unwrap(), None)
+            .unwrap();
+        assert_eq!(game.draw_offer(), Some(Player::White));
+        game.make_move(&Move::from_uci("e7e5").unwrap(), None)
+            .unwrap();
+        assert_eq!(game.draw_offer(), None);
+        assert!(game.accept_draw(Player::White).is_err());
+
+        game.offer_draw(Player::White).unwrap();
+        game.accept_draw(Player::Black).unwrap();
+        assert_eq!(
+            game.outcome(),
+            Some(Outcome {
+                winner: None,
+                termination: Termination::Agreement
+            })
+        );
+        assert_eq!(game.outcome().unwrap().to_string(), "1/2-1/2");
+    }
+
+    #[test]
+    fn resignation() {
+        let mut game = Game::new(Position::starting());
+        game.resign(Player::White).unwrap();
+        assert_eq!(game.outcome().unwrap().to_string(), "0-1");
+        assert_eq!(
+            game.outcome().unwrap().termination,
+            Termination::Resignation
+        );
+        assert!(game
+            .make_move(&Move::from_uci("e2e4").unwrap(), None)
+            .is_err());
+    }
 }
@@ -12,13 +12,17 @@ pub(super) const WHITE_CAN_CASTLE_LONG: Key = 0x41D8_B55B_A5FE_B78B;
 pub(super) const BLACK_CAN_CASTLE_SHORT: Key = 0x6809_8878_7A43_D289;
 pub(super) const BLACK_CAN_CASTLE_LONG: Key = 0x2F94_1F8D_FD3E_3D1F;
 
-// NOTE: The following keys are randomly generated in build.rs and are not
-// stable even between different builds of the same version.
+// The tables below are generated by build.rs. The output is deterministic:
+// two builds from the same commit produce identical tables, which is verified
+// at startup by `verify_tables`.
 pub(super) const EN_PASSANT_FILES: [Key; 8] =
     include!(concat!(env!("OUT_DIR"), "/en_passant_zobrist_keys"));
 
 const PIECES_ZOBRIST_KEYS: [Key; 768] = include!(concat!(env!("OUT_DIR"), "/pieces_zobrist_keys"));
 
+/// Checksum of all tables below computed by build.rs.
+const TABLES_CHECKSUM: u64 = include!(concat!(env!("OUT_DIR"), "/tables_checksum"));
+
 pub(super) fn get_piece_key(piece: Piece, square: Square) -> Key {
     const NUM_PIECES: usize = 6;
     PIECES_ZOBRIST_KEYS[piece.player as usize * NUM_PIECES * BOARD_SIZE as usize
@@ -26,57 +30,119 @@ pub(super) fn get_piece_key(piece: Piece, square: Square) -> Key {
         + square as usize]
 }
 
-// Move generation-related precomputed bitboards.
+// Move generation-related precomputed bitboards, verified by ray casting in
+// `attacks::tests::generated_tables`. The large tables are statics: a const
+// would be copied into every function that indexes it.
 const BISHOP_ATTACKS_COUNT: usize = 5248;
-pub(super) const BISHOP_ATTACKS: [Bitboard; BISHOP_ATTACKS_COUNT] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/bishop_attacks.rs"
-));
-pub(super) const BISHOP_ATTACK_OFFSETS: [usize; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/bishop_attack_offsets.rs"
-));
-pub(super) const BISHOP_RELEVANT_OCCUPANCIES: [u64; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/bishop_relevant_occupancies.rs"
-));
+pub(super) static BISHOP_ATTACKS: [Bitboard; BISHOP_ATTACKS_COUNT] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_attacks"));
+pub(super) const BISHOP_ATTACK_OFFSETS: [usize; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_attack_offsets"));
+pub(super) const BISHOP_RELEVANT_OCCUPANCIES: [u64; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_relevant_occupancies"));
 
 const ROOK_ATTACKS_COUNT: usize = 102_400;
-pub(super) const ROOK_ATTACKS: [Bitboard; ROOK_ATTACKS_COUNT] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/rook_attacks.rs"
-));
-pub(super) const ROOK_RELEVANT_OCCUPANCIES: [u64; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/rook_relevant_occupancies.rs"
-));
-pub(super) const ROOK_ATTACK_OFFSETS: [usize; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/rook_attack_offsets.rs"
-));
+pub(super) static ROOK_ATTACKS: [Bitboard; ROOK_ATTACKS_COUNT] =
+    include!(concat!(env!("OUT_DIR"), "/rook_attacks"));
+pub(super) const ROOK_RELEVANT_OCCUPANCIES: [u64; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rook_relevant_occupancies"));
+pub(super) const ROOK_ATTACK_OFFSETS: [usize; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rook_attack_offsets"));
+
+// Magic bitboards for the CPUs without BMI2. The tables have the same layout
+// as the PEXT ones and share the offsets and relevant occupancies.
+pub(super) static BISHOP_MAGIC_ATTACKS: [Bitboard; BISHOP_ATTACKS_COUNT] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_magic_attacks"));
+pub(super) const BISHOP_MAGICS: [u64; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_magics"));
+pub(super) const BISHOP_MAGIC_SHIFTS: [u32; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_magic_shifts"));
+pub(super) static ROOK_MAGIC_ATTACKS: [Bitboard; ROOK_ATTACKS_COUNT] =
+    include!(concat!(env!("OUT_DIR"), "/rook_magic_attacks"));
+pub(super) const ROOK_MAGICS: [u64; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rook_magics"));
+pub(super) const ROOK_MAGIC_SHIFTS: [u32; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rook_magic_shifts"));
+
+pub(super) static RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rays"));
+pub(super) static BISHOP_RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/bishop_rays"));
+pub(super) static ROOK_RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/rook_rays"));
+
+pub(super) const KNIGHT_ATTACKS: [Bitboard; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/knight_attacks"));
+pub(super) const KING_ATTACKS: [Bitboard; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/king_attacks"));
+pub(super) const WHITE_PAWN_ATTACKS: [Bitboard; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/white_pawn_attacks"));
+pub(super) const BLACK_PAWN_ATTACKS: [Bitboard; BOARD_SIZE as usize] =
+    include!(concat!(env!("OUT_DIR"), "/black_pawn_attacks"));
 
-pub(super) const RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] =
-    include!(concat!(env!("CARGO_MANIFEST_DIR"), "/generated/rays.rs"));
-pub(super) const BISHOP_RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] = include!(
-    concat!(env!("CARGO_MANIFEST_DIR"), "/generated/bishop_rays.rs")
-);
-pub(super) const ROOK_RAYS: [Bitboard; BOARD_SIZE as usize * BOARD_SIZE as usize] = include!(
-    concat!(env!("CARGO_MANIFEST_DIR"), "/generated/rook_rays.rs")
-);
+/// Recomputes the checksum of the tables in the same order as build.rs (FNV-1a
+/// over 64-bit words).
+pub(super) fn checksum() -> u64 {
+    let mut checksum = 0xCBF2_9CE4_8422_2325u64;
+    let mut update = |values: &mut dyn Iterator<Item = u64>| {
+        for value in values {
+            checksum = (checksum ^ value).wrapping_mul(0x0000_0100_0000_01B3);
+        }
+    };
+    let bits = |table: &'static [Bitboard]| table.iter().map(|bitboard| bitboard.bits());
+    update(&mut PIECES_ZOBRIST_KEYS.into_iter());
+    update(&mut EN_PASSANT_FILES.into_iter());
+    update(&mut bits(&KNIGHT_ATTACKS));
+    update(&mut bits(&KING_ATTACKS));
+    update(&mut bits(&WHITE_PAWN_ATTACKS));
+    update(&mut bits(&BLACK_PAWN_ATTACKS));
+    update(&mut bits(&RAYS));
+    update(&mut bits(&BISHOP_RAYS));
+    update(&mut bits(&ROOK_RAYS));
+    for (relevant, offsets, attacks, magics, shifts, magic_attacks) in [
+        (
+            &BISHOP_RELEVANT_OCCUPANCIES,
+            &BISHOP_ATTACK_OFFSETS,
+            &BISHOP_ATTACKS[..],
+            &BISHOP_MAGICS,
+            &BISHOP_MAGIC_SHIFTS,
+            &BISHOP_MAGIC_ATTACKS[..],
+        ),
+        (
+            &ROOK_RELEVANT_OCCUPANCIES,
+            &ROOK_ATTACK_OFFSETS,
+            &ROOK_ATTACKS[..],
+            &ROOK_MAGICS,
+            &ROOK_MAGIC_SHIFTS,
+            &ROOK_MAGIC_ATTACKS[..],
+        ),
+    ] {
+        update(&mut relevant.iter().copied());
+        update(&mut offsets.iter().map(|&offset| offset as u64));
+        update(&mut bits(attacks));
+        update(&mut magics.iter().copied());
+        update(&mut shifts.iter().map(|&shift| u64::from(shift)));
+        update(&mut bits(magic_attacks));
+    }
+    checksum
+}
+
+/// Returns the checksum of the generated tables, or an error if they do not
+/// match the one computed by build.rs.
+pub(super) fn verify_tables() -> anyhow::Result<u64> {
+    let actual = checksum();
+    anyhow::ensure!(
+        actual == TABLES_CHECKSUM,
+        "generated chess tables are corrupted: checksum {actual:#018x}, expected \
+         {TABLES_CHECKSUM:#018x}"
+    );
+    Ok(actual)
+}
 
-pub(super) const KNIGHT_ATTACKS: [Bitboard; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/knight_attacks.rs"
-));
-pub(super) const KING_ATTACKS: [Bitboard; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/king_attacks.rs"
-));
-pub(super) const WHITE_PAWN_ATTACKS: [Bitboard; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/white_pawn_attacks.rs"
-));
-pub(super) const BLACK_PAWN_ATTACKS: [Bitboard; BOARD_SIZE as usize] = include!(concat!(
-    env!("CARGO_MANIFEST_DIR"),
-    "/generated/black_pawn_attacks.rs"
-));
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn checksum() {
+        assert_eq!(super::verify_tables().unwrap(), super::TABLES_CHECKSUM);
+    }
+}
@@ -0,0 +1,236 @@
+//! [Material signature]: the number of pieces of each kind for both players,
+//! regardless of their placement. It identifies the endgames that need special
+//! handling (e.g. [`crate::evaluation`] has dedicated evaluators for some of
+//! them) and is maintained incrementally by
+//! [`crate::chess::position::Position::make_move`].
+//!
+//! [Material signature]: https://www.chessprogramming.org/Material_Hash_Table
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::bail;
+
+use crate::chess::bitboard::Pieces;
+use crate::chess::core::PieceKind;
+use crate::environment::Player;
+
+/// Kings are not counted: there is always exactly one of each.
+const COUNTED_KINDS: [PieceKind; 5] = [
+    PieceKind::Queen,
+    PieceKind::Rook,
+    PieceKind::Bishop,
+    PieceKind::Knight,
+    PieceKind::Pawn,
+];
+const BITS_PER_COUNT: u32 = 4;
+const COUNT_MASK: u64 = (1 << BITS_PER_COUNT) - 1;
+
+/// Counts of each piece kind (except kings) for both players packed into a
+/// single integer, 4 bits per count. Counts above 15 (only possible with
+/// artificial positions) saturate.
+///
+/// The standard notation lists the pieces of the stronger (White) side first,
+/// starting with the king:
+///
+/// ```
+/// use pabi::chess::material::Material;
+/// use pabi::chess::position::Position;
+///
+/// let position = Position::from_fen("8/8/8/4k3/8/8/8/2BNK3 w - - 0 1").unwrap();
+/// assert_eq!(position.material().to_string(), "KBNK");
+/// assert_eq!(position.material(), "KBNK".parse().unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Material {
+    counts: u64,
+}
+
+impl Material {
+    /// Parses the signature in compile-time for the tables of known endgames.
+    /// Returns [`None`] if the signature is malformed.
+    #[must_use]
+    pub const fn parse(signature: &str) -> Option<Self> {
+        let bytes = signature.as_bytes();
+        if bytes.is_empty() || bytes[0] != b'K' {
+            return None;
+        }
+        let mut result = Self { counts: 0 };
+        let mut player = Player::White;
+        let mut index = 1;
+        while index < bytes.len() {
+            let kind = match bytes[index] {
+                b'K' if matches!(player, Player::White) => {
+                    player = Player::Black;
+                    index += 1;
+                    continue;
+                },
+                b'Q' => PieceKind::Queen,
+                b'R' => PieceKind::Rook,
+                b'B' => PieceKind::Bishop,
+                b'N' => PieceKind::Knight,
+                b'P' => PieceKind::Pawn,
+                _ => return None,
+            };
+            result = result.with(player, kind, result.count(player, kind) + 1);
+            index += 1;
+        }
+        if matches!(player, Player::White) {
+            return None;
+        }
+        Some(result)
+    }
+
+    pub(crate) fn from_pieces(white: &Pieces, black: &Pieces) -> Self {
+        let mut result = Self::default();
+        for (player, pieces) in [(Player::White, white), (Player::Black, black)] {
+            for kind in COUNTED_KINDS {
+                let count = pieces.bitboard_for(kind).count();
+                result = result.with(player, kind, count.min(COUNT_MASK as u32) as u8);
+            }
+        }
+        result
+    }
+
+    const fn shift(player: Player, kind: PieceKind) -> u32 {
+        (player as u32 * COUNTED_KINDS.len() as u32 + kind as u32) * BITS_PER_COUNT
+    }
+
+    const fn with(self, player: Player, kind: PieceKind, count: u8) -> Self {
+        let shift = Self::shift(player, kind);
+        let count = if count as u64 > COUNT_MASK {
+            COUNT_MASK
+        } else {
+            count as u64
+        };
+        Self {
+            counts: (self.counts & !(COUNT_MASK << shift)) | (count << shift),
+        }
+    }
+
+    /// Number of pieces of given kind the player has. Always 1 for the king.
+    #[must_use]
+    pub const fn count(self, player: Player, kind: PieceKind) -> u8 {
+        if matches!(kind, PieceKind::King) {
+            return 1;
+        }
+        ((self.counts >> Self::shift(player, kind)) & COUNT_MASK) as u8
+    }
+
+    pub(crate) fn add(&mut self, player: Player, kind: PieceKind) {
+        *self = self.with(player, kind, self.count(player, kind) + 1);
+    }
+
+    pub(crate) fn remove(&mut self, player: Player, kind: PieceKind) {
+        debug_assert!(self.count(player, kind) > 0);
+        *self = self.with(player, kind, self.count(player, kind).saturating_sub(1));
+    }
+
+    /// The same material with the players swapped.
+    #[must_use]
+    pub const fn flip_colors(self) -> Self {
+        let side_bits = COUNTED_KINDS.len() as u32 * BITS_PER_COUNT;
+        let side_mask = (1 << side_bits) - 1;
+        Self {
+            counts: ((self.counts & side_mask) << side_bits) | (self.counts >> side_bits),
+        }
+    }
+
+    /// Integer representation for the hash tables.
+    #[must_use]
+    pub const fn key(self) -> u64 {
+        self.counts
+    }
+
+    /// Total number of pieces on the board, including kings.
+    #[must_use]
+    pub const fn num_pieces(self) -> u8 {
+        let mut result = 2;
+        let mut counts = self.counts;
+        while counts != 0 {
+            result += (counts & COUNT_MASK) as u8;
+            counts >>= BITS_PER_COUNT;
+        }
+        result
+    }
+}
+
+impl FromStr for Material {
+    type Err = anyhow::Error;
+
+    fn from_str(signature: &str) -> anyhow::Result<Self> {
+        match Self::parse(signature) {
+            Some(material) => Ok(material),
+            None => bail!("material signature should look like \"KRPKR\", got {signature:?}"),
+        }
+    }
+}
+
+impl fmt::Display for Material {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for player in [Player::White, Player::Black] {
+            f.write_str("K")?;
+            for kind in COUNTED_KINDS {
+                for _ in 0..self.count(player, kind) {
+                    write!(
+                        f,
+                        "{}",
+                        match kind {
+                            PieceKind::Queen => 'Q',
+                            PieceKind::Rook => 'R',
+                            PieceKind::Bishop => 'B',
+                            PieceKind::Knight => 'N',
+                            _ => 'P',
+                        }
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::position::Position;
+
+    #[test]
+    fn signature() {
+        assert_eq!(
+            Position::starting().material().to_string(),
+            "KQRRBBNNPPPPPPPPKQRRBBNNPPPPPPPP"
+        );
+        assert_eq!(Position::starting().material().num_pieces(), 32);
+        let material: Material = "KRPKR".parse().unwrap();
+        assert_eq!(material.count(Player::White, PieceKind::Rook), 1);
+        assert_eq!(material.count(Player::White, PieceKind::Pawn), 1);
+        assert_eq!(material.count(Player::Black, PieceKind::Rook), 1);
+        assert_eq!(material.count(Player::Black, PieceKind::Pawn), 0);
+        assert_eq!(material.flip_colors().to_string(), "KRKRP");
+        assert_eq!(material.flip_colors().flip_colors(), material);
+        assert_eq!(material.num_pieces(), 5);
+        assert_eq!("KK".parse::<Material>().unwrap(), Material::default());
+        assert!("KRP".parse::<Material>().is_err());
+        assert!("RK".parse::<Material>().is_err());
+        assert!("KXK".parse::<Material>().is_err());
+        assert!("KKK".parse::<Material>().is_err());
+    }
+
+    #[test]
+    fn incremental() {
+        // En passant and a promotion with capture.
+        let mut position =
+            Position::from_fen("r7/1P6/8/3pP2k/8/8/8/4K3 w - d6 0 1").expect("valid position");
+        for uci in ["e5d6", "h5g6", "b7a8q"] {
+            let next_move = crate::chess::core::Move::from_uci(uci).unwrap();
+            position.make_move(&next_move);
+            let expected = Material::from_pieces(
+                position.pieces(Player::White),
+                position.pieces(Player::Black),
+            );
+            assert_eq!(position.material(), expected, "after {uci}");
+        }
+        assert_eq!(position.material().to_string(), "KQPK");
+    }
+}
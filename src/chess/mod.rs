@@ -4,7 +4,22 @@ pub mod attacks;
 pub mod bitboard;
 pub mod core;
 pub mod game;
+pub mod material;
 pub mod position;
+pub mod random;
+#[cfg(feature = "svg")]
+pub mod svg;
+pub mod tablebase;
 pub mod zobrist;
 
 mod generated;
+
+/// Verifies that the tables generated at build time (attacks, rays and Zobrist
+/// keys) match the checksum computed by `build.rs` and returns it.
+///
+/// # Errors
+///
+/// If the tables do not match, e.g. because the build was corrupted.
+pub fn verify_tables() -> anyhow::Result<u64> {
+    generated::verify_tables()
+}
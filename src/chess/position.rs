@@ -8,6 +8,7 @@
 //! [Chess Position]: https://www.chessprogramming.org/Chess_Position
 
 use std::fmt::{self, Write};
+use std::time::{Duration, Instant};
 
 use anyhow::{bail, Context};
 
@@ -22,8 +23,10 @@ use crate::chess::core::{
     Promotion,
     Rank,
     Square,
+    BOARD_SIZE,
     BOARD_WIDTH,
 };
+use crate::chess::material::Material;
 use crate::chess::{attacks, generated, zobrist};
 use crate::environment::Player;
 
@@ -55,6 +58,10 @@ pub struct Position {
     white_pieces: Pieces,
     black_pieces: Pieces,
     castling: CastleRights,
+    /// Home squares of the castling rooks in the order of
+    /// [`CASTLING_RIGHTS`], see [`CastlingRule`]. Only the ones of the current
+    /// rights matter.
+    castling_rooks: [Square; 4],
     side_to_move: Player,
     /// [Halfmove Clock][^ply] keeps track of the number of halfmoves since the
     /// last capture or pawn move and is used to enforce fifty[^fifty]-move draw
@@ -64,10 +71,58 @@ pub struct Position {
     /// [^ply]: Half-move or [ply](https://www.chessprogramming.org/Ply) means a move of only
     ///     one side.
     /// [^fifty]: 50 __full__ moves
+    ///
+    /// Only the values up to 150 (the seventy-five-move rule) matter, so the
+    /// clock saturates instead of overflowing in arbitrarily long games.
     halfmove_clock: u8,
+    /// Saturates at [`u16::MAX`] in games longer than any real one.
     fullmove_counter: u16,
+    /// Set after every double pawn push, as in FEN. It only affects the hash
+    /// if the pawn can be captured, see [`Position::en_passant_key`].
     en_passant_square: Option<Square>,
     hash: zobrist::Key,
+    material: Material,
+}
+
+/// Pieces that [`Position::make_move`] takes off and puts on the board, see
+/// [`Position::piece_changes`]. A move changes at most two pieces of each
+/// kind: castling moves the king and the rook, a capturing promotion removes
+/// the pawn and the captured piece.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PieceChanges {
+    pub removed: arrayvec::ArrayVec<(Piece, Square), 2>,
+    pub added: arrayvec::ArrayVec<(Piece, Square), 2>,
+}
+
+/// Legal target squares of each piece of the player to move, see
+/// [`Position::mobility`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mobility {
+    pieces: Bitboard,
+    targets: [Bitboard; BOARD_SIZE as usize],
+}
+
+impl Mobility {
+    /// Squares the piece on given square can move to. Empty if the square is
+    /// not occupied by a piece of the player to move.
+    #[must_use]
+    pub const fn targets(&self, square: Square) -> Bitboard {
+        self.targets[square as usize]
+    }
+
+    /// Total number of distinct (source, target) pairs. This is the number of
+    /// legal moves with each promotion counted once.
+    #[must_use]
+    pub fn count(&self) -> u32 {
+        self.targets.iter().map(|targets| targets.count()).sum()
+    }
+
+    /// Iterates over the pieces of the player to move and their targets.
+    pub fn iter(&self) -> impl Iterator<Item = (Square, Bitboard)> + '_ {
+        self.pieces
+            .iter()
+            .map(|square| (square, self.targets[square as usize]))
+    }
 }
 
 impl Position {
@@ -88,13 +143,16 @@ impl Position {
             white_pieces: Pieces::starting(Player::White),
             black_pieces: Pieces::starting(Player::Black),
             castling: CastleRights::ALL,
+            castling_rooks: STANDARD_CASTLING_ROOKS,
             side_to_move: Player::White,
             halfmove_clock: 0,
             fullmove_counter: 1,
             en_passant_square: None,
             hash: zobrist::Key::default(),
+            material: Material::default(),
         };
         result.hash = result.compute_hash();
+        result.material = Material::from_pieces(&result.white_pieces, &result.black_pieces);
         result
     }
 
@@ -123,12 +181,56 @@ impl Position {
         self.pieces(player).all()
     }
 
-    fn occupied_squares(&self) -> Bitboard {
+    /// Squares occupied by the pieces of both players.
+    #[must_use]
+    pub fn occupied(&self) -> Bitboard {
         self.occupancy(self.us()) | self.occupancy(self.them())
     }
 
+    /// Iterates over the pieces of both players from A1 to H8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pabi::chess::core::{Piece, PieceKind, Square};
+    /// use pabi::chess::position::Position;
+    /// use pabi::environment::Player;
+    ///
+    /// let position = Position::from_fen("8/8/8/8/8/2k5/8/K6r w - - 0 1").unwrap();
+    /// let pieces: Vec<_> = position.iter_pieces().collect();
+    /// assert_eq!(pieces.len(), 3);
+    /// assert_eq!(
+    ///     pieces[0],
+    ///     (
+    ///         Square::A1,
+    ///         Piece {
+    ///             player: Player::White,
+    ///             kind: PieceKind::King
+    ///         }
+    ///     )
+    /// );
+    /// ```
+    pub fn iter_pieces(&self) -> impl Iterator<Item = (Square, Piece)> + '_ {
+        self.occupied().iter().map(|square| {
+            let piece = self.at(square).expect("occupied square");
+            (square, piece)
+        })
+    }
+
+    /// Returns the square of the player's king.
+    #[must_use]
+    pub fn king_square(&self, player: Player) -> Square {
+        self.pieces(player).king.as_square()
+    }
+
+    /// Number of pieces of each kind for both players, see [`Material`].
+    #[must_use]
+    pub const fn material(&self) -> Material {
+        self.material
+    }
+
     pub fn num_pieces(&self) -> usize {
-        self.occupied_squares().count() as usize
+        self.occupied().count() as usize
     }
 
     /// Parses board from Forsyth-Edwards Notation and checks its correctness.
@@ -161,7 +263,13 @@ impl Position {
     /// additional whitespace. Use [`Position::try_from`] for cleaning up the
     /// input if it is coming from untrusted source and is likely to contain
     /// extra symbols.
-    // TODO: Add support for Shredder FEN and Chess960.
+    ///
+    /// The castling rights can also be given in [X-FEN] or [Shredder-FEN] for
+    /// [Chess960] positions, see [`parse_castling`].
+    ///
+    /// [X-FEN]: https://en.wikipedia.org/wiki/X-FEN
+    /// [Shredder-FEN]: https://www.chessprogramming.org/Forsyth-Edwards_Notation#Shredder-FEN
+    /// [Chess960]: https://www.chessprogramming.org/Chess960
     pub fn from_fen(input: &str) -> anyhow::Result<Self> {
         let mut white_pieces = Pieces::empty();
         let mut black_pieces = Pieces::empty();
@@ -220,8 +328,8 @@ impl Position {
             Some(value) => value.try_into()?,
             None => bail!("missing side to move"),
         };
-        let castling = match parts.next() {
-            Some(value) => value.try_into()?,
+        let (castling, castling_rooks) = match parts.next() {
+            Some(value) => parse_castling(value, &white_pieces, &black_pieces)?,
             None => bail!("missing castling rights"),
         };
         let en_passant_square = match parts.next() {
@@ -268,13 +376,16 @@ impl Position {
             white_pieces,
             black_pieces,
             castling,
+            castling_rooks,
             side_to_move,
             halfmove_clock,
             fullmove_counter,
             en_passant_square,
             hash: zobrist::Key::default(),
+            material: Material::default(),
         };
         result.hash = result.compute_hash();
+        result.material = Material::from_pieces(&result.white_pieces, &result.black_pieces);
 
         match validate(&result) {
             Ok(()) => Ok(result),
@@ -282,6 +393,212 @@ impl Position {
         }
     }
 
+    /// Returns the position with colors of all pieces swapped and the board
+    /// flipped vertically, i.e. the same position from the perspective of the
+    /// other player. The evaluation from the perspective of the player to move
+    /// does not change, which is useful for training data augmentation.
+    ///
+    /// ```
+    /// use pabi::chess::position::Position;
+    ///
+    /// let position =
+    ///     Position::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1").unwrap();
+    /// assert_eq!(
+    ///     position.flip_colors().to_string(),
+    ///     "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq e6 0 1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn flip_colors(&self) -> Self {
+        let mut result = Self {
+            white_pieces: self.black_pieces.map(Bitboard::flip_perspective),
+            black_pieces: self.white_pieces.map(Bitboard::flip_perspective),
+            castling: self.castling.flip_colors(),
+            castling_rooks: [2, 3, 0, 1].map(|index| self.castling_rooks[index].flip_perspective()),
+            side_to_move: !self.side_to_move,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_counter: self.fullmove_counter,
+            en_passant_square: self.en_passant_square.map(Square::flip_perspective),
+            hash: zobrist::Key::default(),
+            material: Material::default(),
+        };
+        result.hash = result.compute_hash();
+        result.material = Material::from_pieces(&result.white_pieces, &result.black_pieces);
+        result
+    }
+
+    /// Returns the position mirrored horizontally (file A becomes file H and
+    /// so on) or [`None`] if the position has castling rights (castling is
+    /// only possible on the original side of the board) or en passant square.
+    ///
+    /// ```
+    /// use pabi::chess::position::Position;
+    ///
+    /// let position = Position::from_fen("8/8/8/8/8/2k5/1P6/K7 w - - 0 1").unwrap();
+    /// assert_eq!(
+    ///     position.mirror_horizontally().unwrap().to_string(),
+    ///     "8/8/8/8/8/5k2/6P1/7K w - - 0 1"
+    /// );
+    /// assert!(Position::starting().mirror_horizontally().is_none());
+    /// ```
+    #[must_use]
+    pub fn mirror_horizontally(&self) -> Option<Self> {
+        if self.castling != CastleRights::NONE || self.en_passant_square.is_some() {
+            return None;
+        }
+        let mut result = Self {
+            white_pieces: self.white_pieces.map(Bitboard::mirror_horizontally),
+            black_pieces: self.black_pieces.map(Bitboard::mirror_horizontally),
+            hash: zobrist::Key::default(),
+            ..self.clone()
+        };
+        result.hash = result.compute_hash();
+        Some(result)
+    }
+
+    /// Returns the canonical form of the position: the same for all positions
+    /// that are equivalent up to color flip and horizontal mirroring. This is
+    /// used to deduplicate the training data.
+    ///
+    /// - The side to move is always White.
+    /// - En passant square is removed if there is no legal en passant capture.
+    /// - Move counters are reset.
+    /// - If the position can be mirrored horizontally, the version with the
+    ///   smaller hash is picked.
+    ///
+    /// ```
+    /// use pabi::chess::position::Position;
+    ///
+    /// let position = Position::from_fen("8/8/8/8/8/2k5/1P6/K7 w - - 17 42").unwrap();
+    /// let flipped = Position::from_fen("7k/6p1/5K2/8/8/8/8/8 b - - 0 1").unwrap();
+    /// assert_eq!(
+    ///     position.canonical().to_string(),
+    ///     flipped.canonical().to_string()
+    /// );
+    /// assert_eq!(position.canonical_hash(), flipped.canonical_hash());
+    /// ```
+    #[must_use]
+    pub fn canonical(&self) -> Self {
+        let mut result = match self.side_to_move {
+            Player::White => self.clone(),
+            Player::Black => self.flip_colors(),
+        };
+        result.halfmove_clock = 0;
+        result.fullmove_counter = 1;
+        if let Some(en_passant_square) = result.en_passant_square {
+            let can_capture = result.generate_moves().iter().any(|next_move| {
+                next_move.to() == en_passant_square
+                    && result.white_pieces.pawns.contains(next_move.from())
+            });
+            if !can_capture {
+                result.en_passant_square = None;
+            }
+        }
+        result.hash = result.compute_hash();
+        match result.mirror_horizontally() {
+            Some(mirrored) if mirrored.hash < result.hash => mirrored,
+            _ => result,
+        }
+    }
+
+    /// Returns the hash of [`Position::canonical`].
+    #[must_use]
+    pub fn canonical_hash(&self) -> zobrist::Key {
+        self.canonical().hash
+    }
+
+    /// Describes what changed between this position and the `other` one:
+    /// moved, added and removed pieces and the changes of the game state.
+    /// This is useful for debugging the positions sent by the GUI and in test
+    /// failure messages.
+    ///
+    /// A piece that disappeared from one square and appeared on another one is
+    /// reported as moved, so a capture is a move and a removal.
+    ///
+    /// ```
+    /// use pabi::chess::core::Move;
+    /// use pabi::chess::position::Position;
+    ///
+    /// let before = Position::starting();
+    /// let mut after = before.clone();
+    /// after.make_move(&Move::from_uci("e2e4").unwrap());
+    /// let changes: Vec<String> = before
+    ///     .diff(&after)
+    ///     .iter()
+    ///     .map(ToString::to_string)
+    ///     .collect();
+    /// assert_eq!(
+    ///     changes,
+    ///     ["P e2-e4", "side to move: w -> b", "en passant: - -> e3"]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<SquareChange> {
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        for square in Square::iter() {
+            let (before, after) = (self.at(square), other.at(square));
+            if before == after {
+                continue;
+            }
+            if let Some(piece) = before {
+                removed.push((square, piece));
+            }
+            if let Some(piece) = after {
+                added.push((square, piece));
+            }
+        }
+
+        let mut changes = Vec::new();
+        removed.retain(|&(from, piece)| {
+            let Some(index) = added.iter().position(|&(_, added)| added == piece) else {
+                return true;
+            };
+            let (to, _) = added.remove(index);
+            changes.push(SquareChange::Moved { piece, from, to });
+            false
+        });
+        changes.extend(
+            removed
+                .into_iter()
+                .map(|(square, piece)| SquareChange::Removed { square, piece }),
+        );
+        changes.extend(
+            added
+                .into_iter()
+                .map(|(square, piece)| SquareChange::Added { square, piece }),
+        );
+
+        if self.side_to_move != other.side_to_move {
+            changes.push(SquareChange::SideToMove(
+                self.side_to_move,
+                other.side_to_move,
+            ));
+        }
+        if self.castling != other.castling {
+            changes.push(SquareChange::Castling(self.castling, other.castling));
+        }
+        if self.en_passant_square != other.en_passant_square {
+            changes.push(SquareChange::EnPassant(
+                self.en_passant_square,
+                other.en_passant_square,
+            ));
+        }
+        if self.halfmove_clock != other.halfmove_clock {
+            changes.push(SquareChange::HalfmoveClock(
+                self.halfmove_clock,
+                other.halfmove_clock,
+            ));
+        }
+        if self.fullmove_counter != other.fullmove_counter {
+            changes.push(SquareChange::FullmoveCounter(
+                self.fullmove_counter,
+                other.fullmove_counter,
+            ));
+        }
+        changes
+    }
+
     /// Checks whether a position is pseudo-legal. This is a simple check to
     /// ensure that the state is not corrupted and is safe to work with. It
     /// doesn't handle all corner cases and is simply used to as a sanity check.
@@ -290,6 +607,11 @@ impl Position {
         validate(self).is_ok()
     }
 
+    /// Same as [`Position::is_legal`] but describes the problem.
+    pub(crate) fn validate(&self) -> anyhow::Result<()> {
+        validate(self).context("illegal position")
+    }
+
     pub(super) fn attack_info(&self) -> attacks::AttackInfo {
         let (us, them) = (self.us(), self.them());
         let (our_pieces, their_pieces) = (self.pieces(us), self.pieces(them));
@@ -299,6 +621,24 @@ impl Position {
         attacks::AttackInfo::new(them, their_pieces, king, our_occupancy, occupancy)
     }
 
+    /// Renders the squares attacked by the opponent, the pieces checking our
+    /// king, our pinned pieces and the squares our king can move to as ASCII
+    /// grids. This is the information legal move generation relies on, so it
+    /// is useful for diagnosing move generation issues.
+    ///
+    /// ```
+    /// use pabi::chess::position::Position;
+    ///
+    /// let position = Position::from_fen("4k3/8/8/8/8/8/4r3/4K3 w - - 0 1").unwrap();
+    /// let map = position.attack_map();
+    /// assert!(map.starts_with("  attacks"));
+    /// assert_eq!(map.lines().count(), 10);
+    /// ```
+    #[must_use]
+    pub fn attack_map(&self) -> String {
+        self.attack_info().to_string()
+    }
+
     /// Calculates a list of legal moves (i.e. the moves that do not leave our
     /// king in check).
     ///
@@ -323,6 +663,21 @@ impl Position {
     // TODO: Check movegen comparison (https://github.com/Gigantua/Chess_Movegen).
     #[must_use]
     pub fn generate_moves(&self) -> MoveList {
+        #[cfg(feature = "pseudo-legal")]
+        return self
+            .generate_pseudo_legal_moves()
+            .into_iter()
+            .filter(|next_move| self.clone().make_pseudo_legal_move(next_move))
+            .collect();
+
+        #[cfg(not(feature = "pseudo-legal"))]
+        self.generate_legal_moves()
+    }
+
+    /// Strictly legal move generator, the default implementation of
+    /// [`Position::generate_moves`].
+    #[cfg_attr(feature = "pseudo-legal", allow(dead_code))]
+    fn generate_legal_moves(&self) -> MoveList {
         let mut moves = MoveList::new();
         debug_assert!(self.is_legal());
         // TODO: Try caching more e.g. all()s? Benchmark to confirm that this is an
@@ -338,31 +693,10 @@ impl Position {
         // Moving the king to safety is always a valid move.
         generate_king_moves(king, attack_info.safe_king_squares, &mut moves);
         // If there are checks, the moves are restricted to resolving them.
-        let blocking_ray = match attack_info.checkers.count() {
-            0 => Bitboard::full(),
-            // There are two ways of getting out of check:
-            //
-            // - Moving king to safety (calculated above)
-            // - Blocking the checker or capturing it
-            //
-            // The former is calculated above, the latter is dealt with below.
-            1 => {
-                let checker: Square = attack_info.checkers.as_square();
-                let ray = attacks::ray(checker, king);
-                if ray.is_empty() {
-                    // This means the checker is a knight: capture is the only
-                    // way left to resolve this check.
-                    attack_info.checkers
-                } else {
-                    // Checker is a sliding piece: both capturing and blocking
-                    // resolves the check.
-                    ray
-                }
-            },
-            // Double checks can only be evaded by the king moves to safety: no
-            // need to consider other moves.
-            2 => return moves,
-            _ => unreachable!("checks can't be given by more than two pieces at once"),
+        // Double checks can only be evaded by the king moves to safety: no
+        // need to consider other moves.
+        let Some(blocking_ray) = check_mask(attack_info.checkers, king) else {
+            return moves;
         };
         generate_knight_moves(
             our_pieces.knights,
@@ -397,24 +731,254 @@ impl Position {
             their_occupancy,
             their_or_empty,
             blocking_ray,
-            attack_info.pins,
-            attack_info.checkers,
+            &attack_info,
             king,
             self.en_passant_square,
             occupied_squares,
             &mut moves,
         );
-        generate_castle_moves(
+        for rule in self.castling_rules(us) {
+            if rule.is_legal(&attack_info, occupied_squares, their_pieces) {
+                unsafe { moves.push_unchecked(Move::new(king, rule.to, None)) }
+            }
+        }
+        moves
+    }
+
+    /// Calculates the squares each piece of the player to move can legally go
+    /// to, taking pins and checks into account. This is cheaper than
+    /// [`Position::generate_moves`] when only the targets are needed (e.g. for
+    /// evaluation features): promotions are not expanded and no moves are
+    /// materialized.
+    ///
+    /// The rules are shared with the move generator, so the targets always
+    /// match the legal moves.
+    #[must_use]
+    pub fn mobility(&self) -> Mobility {
+        let (us, them) = (self.us(), self.them());
+        let (our_pieces, their_pieces) = (self.pieces(us), self.pieces(them));
+        let king: Square = our_pieces.king.as_square();
+        let (our_occupancy, their_occupancy) = (our_pieces.all(), their_pieces.all());
+        let occupied_squares = our_occupancy | their_occupancy;
+        let their_or_empty = !our_occupancy;
+        let attack_info =
+            attacks::AttackInfo::new(them, their_pieces, king, our_occupancy, occupied_squares);
+
+        let mut mobility = Mobility {
+            pieces: our_occupancy,
+            targets: [Bitboard::empty(); BOARD_SIZE as usize],
+        };
+        mobility.targets[king as usize] = attack_info.safe_king_squares;
+        for rule in self.castling_rules(us) {
+            if rule.is_legal(&attack_info, occupied_squares, their_pieces) {
+                mobility.targets[king as usize].extend(rule.to);
+            }
+        }
+        let Some(blocking_ray) = check_mask(attack_info.checkers, king) else {
+            return mobility;
+        };
+        let pinned_targets = |from: Square, targets: Bitboard| {
+            if !attack_info.pins.contains(from) {
+                return targets;
+            }
+            let mut result = Bitboard::empty();
+            for to in targets.iter() {
+                if !breaks_pin(attack_info.pins, from, to, king) {
+                    result.extend(to);
+                }
+            }
+            result
+        };
+
+        for from in (our_pieces.knights - attack_info.pins).iter() {
+            mobility.targets[from as usize] =
+                attacks::knight_attacks(from) & their_or_empty & blocking_ray;
+        }
+        for from in our_pieces.rooks.iter() {
+            let targets =
+                attacks::rook_attacks(from, occupied_squares) & their_or_empty & blocking_ray;
+            mobility.targets[from as usize] = pinned_targets(from, targets);
+        }
+        for from in our_pieces.bishops.iter() {
+            let targets =
+                attacks::bishop_attacks(from, occupied_squares) & their_or_empty & blocking_ray;
+            mobility.targets[from as usize] = pinned_targets(from, targets);
+        }
+        for from in our_pieces.queens.iter() {
+            let targets =
+                attacks::queen_attacks(from, occupied_squares) & their_or_empty & blocking_ray;
+            mobility.targets[from as usize] = pinned_targets(from, targets);
+        }
+
+        let push_direction = pawn_push_direction(us);
+        let third_rank = Rank::pawns_starting(us).mask().shift(push_direction);
+        for from in our_pieces.pawns.iter() {
+            let captures = attacks::pawn_attacks(from, us) & their_occupancy;
+            let push = Bitboard::from(from).shift(push_direction) - occupied_squares;
+            let double_push = (push & third_rank).shift(push_direction) - occupied_squares;
+            let targets = (captures | push | double_push) & blocking_ray;
+            mobility.targets[from as usize] = pinned_targets(from, targets);
+        }
+        if let Some(en_passant_square) = self.en_passant_square {
+            for from in en_passant_captures(
+                our_pieces.pawns,
+                them,
+                their_pieces,
+                &attack_info,
+                king,
+                en_passant_square,
+                occupied_squares,
+            )
+            .iter()
+            {
+                mobility.targets[from as usize].extend(en_passant_square);
+            }
+        }
+        mobility
+    }
+
+    /// Generates the moves that follow the piece movement rules but may leave
+    /// our king in check. These have to be applied with
+    /// [`Position::make_move_checked`], which rejects the illegal ones.
+    ///
+    /// This is the alternative to the strictly legal generator that shifts the
+    /// cost of the legality checks to making the moves. It is several times
+    /// slower in the perft benchmark (`perft_pseudo_legal` in
+    /// `benches/chess.rs`) because the strictly legal generator allows
+    /// counting the leaves without making the moves. `pseudo-legal` feature
+    /// makes [`Position::generate_moves`] use it for A/B testing.
+    #[must_use]
+    pub fn generate_pseudo_legal_moves(&self) -> MoveList {
+        let mut moves = MoveList::new();
+        let (us, them) = (self.us(), self.them());
+        let our_pieces = self.pieces(us);
+        let (our_occupancy, their_occupancy) = (self.occupancy(us), self.occupancy(them));
+        let occupied_squares = our_occupancy | their_occupancy;
+        let their_or_empty = !our_occupancy;
+        let king: Square = our_pieces.king.as_square();
+
+        generate_king_moves(
+            king,
+            attacks::king_attacks(king) & their_or_empty,
+            &mut moves,
+        );
+        for rule in self.castling_rules(us) {
+            if (occupied_squares & rule.path).is_empty() {
+                unsafe { moves.push_unchecked(Move::new(king, rule.to, None)) }
+            }
+        }
+        let no_pins = Bitboard::empty();
+        let no_checks = Bitboard::full();
+        generate_knight_moves(
+            our_pieces.knights,
+            their_or_empty,
+            no_pins,
+            no_checks,
+            &mut moves,
+        );
+        generate_rook_moves(
+            our_pieces.rooks | our_pieces.queens,
+            occupied_squares,
+            their_or_empty,
+            no_checks,
+            no_pins,
+            king,
+            &mut moves,
+        );
+        generate_bishop_moves(
+            our_pieces.bishops | our_pieces.queens,
+            occupied_squares,
+            their_or_empty,
+            no_checks,
+            no_pins,
+            king,
+            &mut moves,
+        );
+        generate_pawn_moves(
+            our_pieces.pawns,
             us,
-            attack_info.checkers,
-            self.castling,
-            attack_info.attacks,
+            them,
+            self.pieces(them),
+            their_occupancy,
+            their_or_empty,
+            no_checks,
+            &attacks::AttackInfo {
+                attacks: Bitboard::empty(),
+                checkers: Bitboard::empty(),
+                pins: no_pins,
+                xrays: Bitboard::empty(),
+                safe_king_squares: Bitboard::empty(),
+            },
+            king,
+            None,
             occupied_squares,
             &mut moves,
         );
+        if let Some(en_passant_square) = self.en_passant_square {
+            for from in (attacks::pawn_attacks(en_passant_square, them) & our_pieces.pawns).iter() {
+                unsafe { moves.push_unchecked(Move::new(from, en_passant_square, None)) }
+            }
+        }
         moves
     }
 
+    /// Applies a move from [`Position::generate_pseudo_legal_moves`] if it is
+    /// legal. Otherwise, returns false and leaves the position unchanged.
+    pub fn make_move_checked(&mut self, next_move: &Move) -> bool {
+        let previous = self.clone();
+        if self.make_pseudo_legal_move(next_move) {
+            return true;
+        }
+        *self = previous;
+        false
+    }
+
+    /// Makes the pseudo-legal move and returns true if it did not leave our
+    /// king in check (or castle through an attacked square). The position is
+    /// left in an invalid state otherwise.
+    fn make_pseudo_legal_move(&mut self, next_move: &Move) -> bool {
+        let (us, them) = (self.us(), self.them());
+        let occupied_squares = self.occupied();
+        if let Some(rule) = self.castling_rule(next_move) {
+            if self.attackers(rule.king, them, occupied_squares).has_any()
+                || rule
+                    .king_walk
+                    .iter()
+                    .any(|square| self.attackers(square, them, occupied_squares).has_any())
+            {
+                return false;
+            }
+        }
+        self.make_move(next_move);
+        self.attackers(self.king_square(us), them, self.occupied())
+            .is_empty()
+    }
+
+    /// Returns the pieces of the `attacker` that attack (or defend) the square
+    /// in this position. Pinned pieces are included.
+    #[must_use]
+    pub fn attackers_to(&self, square: Square, attacker: Player) -> Bitboard {
+        self.attackers(square, attacker, self.occupied())
+    }
+
+    /// Returns the pieces of the `attacker` that attack the square.
+    fn attackers(&self, square: Square, attacker: Player, occupancy: Bitboard) -> Bitboard {
+        let pieces = self.pieces(attacker);
+        // Pawn attack tables are empty for the backranks, so the lookup from
+        // our king's square does not work.
+        let mut pawns = Bitboard::empty();
+        for pawn in pieces.pawns.iter() {
+            if attacks::pawn_attacks(pawn, attacker).contains(square) {
+                pawns.extend(pawn);
+            }
+        }
+        pawns
+            | (attacks::knight_attacks(square) & pieces.knights)
+            | (attacks::king_attacks(square) & pieces.king)
+            | (attacks::bishop_attacks(square, occupancy) & (pieces.bishops | pieces.queens))
+            | (attacks::rook_attacks(square, occupancy) & (pieces.rooks | pieces.queens))
+    }
+
     /// Transitions to the next position by applying the move.
     ///
     /// This is the only way to mutate the position and it will ensure that the
@@ -424,54 +988,91 @@ impl Position {
 
         // Increment halfmove clock early: it will be reset on capture or pawn
         // push.
-        self.halfmove_clock += 1;
+        self.halfmove_clock = self.halfmove_clock.saturating_add(1);
 
+        self.hash ^= self.en_passant_key();
+        // The rights are updated before the pieces are moved.
+        let castling = self.castling_rule(next_move);
         self.update_castling_rights(next_move);
 
         self.handle_capture(next_move);
         self.make_pawn_move(next_move);
-        self.make_king_move(next_move);
-        self.make_regular_move(next_move);
+        // The rook can land on the king's home square in Chess960.
+        if !self.make_king_move(next_move, castling.as_ref()) {
+            self.make_regular_move(next_move);
+        }
 
         if self.side_to_move == Player::Black {
-            self.fullmove_counter += 1;
+            self.fullmove_counter = self.fullmove_counter.saturating_add(1);
         }
 
         self.side_to_move = !self.side_to_move;
+        self.hash ^= self.en_passant_key();
+    }
+
+    /// Returns the pieces that [`Position::make_move`] would take off and put
+    /// on the board for the legal move, e.g. to update the evaluation
+    /// incrementally instead of computing it for the new position from
+    /// scratch.
+    #[must_use]
+    pub fn piece_changes(&self, next_move: &Move) -> PieceChanges {
+        let mut changes = PieceChanges::default();
+        let piece = self
+            .at(next_move.from())
+            .expect("the move should start on the square of a piece");
+        changes.removed.push((piece, next_move.from()));
+        if let Some(rule) = self.castling_rule(next_move) {
+            let rook = Piece {
+                player: self.us(),
+                kind: PieceKind::Rook,
+            };
+            changes.removed.push((rook, rule.rook));
+            changes.added.push((piece, rule.king_target));
+            changes.added.push((rook, rule.rook_target));
+            return changes;
+        }
+        if let Some(captured) = self.at(next_move.to()) {
+            changes.removed.push((captured, next_move.to()));
+        } else if piece.kind == PieceKind::Pawn && self.en_passant_square == Some(next_move.to()) {
+            let captured = Piece {
+                player: self.them(),
+                kind: PieceKind::Pawn,
+            };
+            changes.removed.push((
+                captured,
+                Square::new(next_move.to().file(), next_move.from().rank()),
+            ));
+        }
+        let kind = next_move.promotion().map_or(piece.kind, PieceKind::from);
+        changes.added.push((
+            Piece {
+                player: self.us(),
+                kind,
+            },
+            next_move.to(),
+        ));
+        changes
     }
 
+    /// Removes the castling rights when the king or the rook leaves its home
+    /// square or the rook is captured there (including by a promoting pawn).
     fn update_castling_rights(&mut self, next_move: &Move) {
-        if self.castling.contains(CastleRights::WHITE_SHORT)
-            && (next_move.from() == Square::E1
-                || next_move.from() == Square::H1
-                || next_move.to() == Square::H1)
-        {
-            self.castling.remove(CastleRights::WHITE_SHORT);
-            self.hash ^= generated::WHITE_CAN_CASTLE_SHORT;
+        if self.castling == CastleRights::NONE {
+            return;
         }
-        if self.castling.contains(CastleRights::WHITE_LONG)
-            && (next_move.from() == Square::E1
-                || next_move.from() == Square::A1
-                || next_move.to() == Square::A1)
-        {
-            self.castling.remove(CastleRights::WHITE_LONG);
-            self.hash ^= generated::WHITE_CAN_CASTLE_LONG;
+        let mut lost = CastleRights::NONE;
+        for (right, rook) in CASTLING_RIGHTS.into_iter().zip(self.castling_rooks) {
+            if next_move.from() == rook || next_move.to() == rook {
+                lost |= right;
+            }
         }
-        if self.castling.contains(CastleRights::BLACK_SHORT)
-            && (next_move.from() == Square::E8
-                || next_move.from() == Square::H8
-                || next_move.to() == Square::H8)
-        {
-            self.castling.remove(CastleRights::BLACK_SHORT);
-            self.hash ^= generated::BLACK_CAN_CASTLE_SHORT;
+        if self.pieces(self.us()).king.contains(next_move.from()) {
+            lost |= player_castling_rights(self.us());
         }
-        if self.castling.contains(CastleRights::BLACK_LONG)
-            && (next_move.from() == Square::E8
-                || next_move.from() == Square::A8
-                || next_move.to() == Square::A8)
-        {
-            self.castling.remove(CastleRights::BLACK_LONG);
-            self.hash ^= generated::BLACK_CAN_CASTLE_LONG;
+        lost &= self.castling;
+        if lost != CastleRights::NONE {
+            self.castling.remove(lost);
+            self.hash ^= castling_key(lost);
         }
     }
 
@@ -496,6 +1097,7 @@ impl Position {
             ] {
                 if piece.contains(square) {
                     piece.clear(square);
+                    self.material.remove(!self.side_to_move, kind);
                     self.hash ^= generated::get_piece_key(
                         Piece {
                             player: !self.side_to_move,
@@ -530,6 +1132,7 @@ impl Position {
             if next_move.to() == en_passant_square {
                 let captured_pawn = Square::new(next_move.to().file(), next_move.from().rank());
                 their_pieces.pawns.clear(captured_pawn);
+                self.material.remove(!self.side_to_move, PieceKind::Pawn);
                 self.hash ^= generated::get_piece_key(
                     Piece {
                         player: !self.side_to_move,
@@ -549,9 +1152,21 @@ impl Position {
             next_move.from(),
         );
 
-        // Check promotions.
-        // TODO: Debug assertions to make sure the promotion is valid.
+        // Check promotions: the pawn has to promote exactly when it reaches the
+        // last rank, which is only possible from the one before it.
+        debug_assert_eq!(
+            next_move.promotion().is_some(),
+            next_move.to().rank() == Rank::promotion(self.side_to_move),
+            "pawn move {next_move} in {self} should promote iff it reaches the last rank"
+        );
         if let Some(promotion) = next_move.promotion() {
+            debug_assert_eq!(
+                next_move.from().rank(),
+                Rank::pawns_starting(!self.side_to_move),
+                "promotion {next_move} in {self} should start on the seventh rank"
+            );
+            self.material.remove(self.side_to_move, PieceKind::Pawn);
+            self.material.add(self.side_to_move, promotion.into());
             match promotion {
                 Promotion::Queen => {
                     our_pieces.queens.extend(next_move.to());
@@ -613,21 +1228,19 @@ impl Position {
 
         // Double push creates en passant square.
         if next_move.from().rank() == Rank::pawns_starting(self.side_to_move)
-                && next_move.from().file() == next_move.to().file()
-                && single_push_square != next_move.to()
-                // Technically, this is not correct: https://github.com/jhlywa/chess.js/issues/294
-                && (their_pieces.pawns & attacks::pawn_attacks(single_push_square, self.side_to_move)).has_any()
+            && next_move.from().file() == next_move.to().file()
+            && single_push_square != next_move.to()
         {
             self.en_passant_square = Some(single_push_square);
-            self.hash ^= generated::EN_PASSANT_FILES[single_push_square.file() as usize];
         }
 
         true
     }
 
-    /// Castle or regular king move.
-    // TODO: Merge with the other castling rights handler.
-    fn make_king_move(&mut self, next_move: &Move) -> bool {
+    /// Castle or regular king move. Castling moves the king to its target
+    /// square, which is not the target of the move if the king captures its
+    /// own rook.
+    fn make_king_move(&mut self, next_move: &Move, castling: Option<&CastlingRule>) -> bool {
         let our_pieces = match self.side_to_move {
             Player::White => &mut self.white_pieces,
             Player::Black => &mut self.black_pieces,
@@ -637,53 +1250,19 @@ impl Position {
             return false;
         }
 
-        let backrank = Rank::backrank(self.side_to_move);
-
-        // Check if the move is castling.
-        if next_move.from().rank() == backrank
-            && next_move.to().rank() == backrank
-            && next_move.from().file() == File::E
-        {
-            if next_move.to().file() == File::G {
-                let from = Square::new(File::H, backrank);
-                our_pieces.rooks.clear(from);
-                self.hash ^= generated::get_piece_key(
-                    Piece {
-                        player: self.side_to_move,
-                        kind: PieceKind::Rook,
-                    },
-                    from,
-                );
-                let to = Square::new(File::F, backrank);
-                our_pieces.rooks.extend(to);
-                self.hash ^= generated::get_piece_key(
-                    Piece {
-                        player: self.side_to_move,
-                        kind: PieceKind::Rook,
-                    },
-                    to,
-                );
-            } else if next_move.to().file() == File::C {
-                let from = Square::new(File::A, backrank);
-                our_pieces.rooks.clear(from);
-                self.hash ^= generated::get_piece_key(
-                    Piece {
-                        player: self.side_to_move,
-                        kind: PieceKind::Rook,
-                    },
-                    from,
-                );
-                let to = Square::new(File::D, backrank);
-                our_pieces.rooks.extend(to);
-                self.hash ^= generated::get_piece_key(
-                    Piece {
-                        player: self.side_to_move,
-                        kind: PieceKind::Rook,
-                    },
-                    to,
-                );
-            }
-        }
+        let mut to = next_move.to();
+        // The rook jumps over the king (or next to it in Chess960).
+        if let Some(rule) = castling {
+            to = rule.king_target;
+            let rook = Piece {
+                player: self.side_to_move,
+                kind: PieceKind::Rook,
+            };
+            our_pieces.rooks.clear(rule.rook);
+            self.hash ^= generated::get_piece_key(rook, rule.rook);
+            our_pieces.rooks.extend(rule.rook_target);
+            self.hash ^= generated::get_piece_key(rook, rule.rook_target);
+        }
 
         our_pieces.king.clear(next_move.from());
         self.hash ^= generated::get_piece_key(
@@ -693,13 +1272,13 @@ impl Position {
             },
             next_move.from(),
         );
-        our_pieces.king.extend(next_move.to());
+        our_pieces.king.extend(to);
         self.hash ^= generated::get_piece_key(
             Piece {
                 player: self.side_to_move,
                 kind: PieceKind::King,
             },
-            next_move.to(),
+            to,
         );
 
         true
@@ -746,13 +1325,27 @@ impl Position {
         let attack_info = attacks::AttackInfo::new(
             self.them(),
             self.pieces(self.them()),
-            self.pieces(self.us()).king.as_square(),
+            self.king_square(self.us()),
             self.occupancy(self.us()),
-            self.occupied_squares(),
+            self.occupied(),
         );
         attack_info.checkers.has_any()
     }
 
+    /// Number of plies since the last capture or pawn move, saturating at
+    /// [`u8::MAX`].
+    #[must_use]
+    pub fn halfmove_clock(&self) -> u8 {
+        self.halfmove_clock
+    }
+
+    /// Number of the full move, starting at 1 and incremented after Black's
+    /// move, saturating at [`u16::MAX`].
+    #[must_use]
+    pub fn fullmove_counter(&self) -> u16 {
+        self.fullmove_counter
+    }
+
     /// Returns true if 50-move rule draw is in effect.
     #[must_use]
     pub fn halfmove_clock_expired(&self) -> bool {
@@ -776,6 +1369,250 @@ impl Position {
         None
     }
 
+    /// Returns true if the legal move puts the opponent in check.
+    #[must_use]
+    pub fn gives_check(&self, next_move: &Move) -> bool {
+        let mut next_position = self.clone();
+        next_position.make_move(next_move);
+        next_position.in_check()
+    }
+
+    /// Converts a legal move to [Standard Algebraic Notation] (SAN), e.g.
+    /// `Nbd7`, `exd6`, `O-O` or `e8=Q#`.
+    ///
+    /// [Standard Algebraic Notation]: https://www.chessprogramming.org/Algebraic_Chess_Notation#Standard_Algebraic_Notation_.28SAN.29
+    #[must_use]
+    pub fn to_san(&self, next_move: &Move) -> String {
+        let (from, to) = (next_move.from(), next_move.to());
+        let piece = self.at(from).expect("moves should start at our piece");
+        let mut san = String::new();
+        if let Some(rule) = self.castling_rule(next_move) {
+            san.push_str(if rule.is_short() { "O-O" } else { "O-O-O" });
+        } else if piece.kind == PieceKind::Pawn {
+            // Pawns only change files when capturing (including en passant).
+            if from.file() != to.file() {
+                write!(san, "{}x", from.file()).unwrap();
+            }
+            write!(san, "{to}").unwrap();
+            if let Some(promotion) = next_move.promotion() {
+                let promoted = Piece {
+                    player: Player::White,
+                    kind: promotion.into(),
+                };
+                write!(san, "={promoted}").unwrap();
+            }
+        } else {
+            // SAN uses uppercase symbols regardless of the color.
+            let symbol = Piece {
+                player: Player::White,
+                kind: piece.kind,
+            };
+            write!(san, "{symbol}").unwrap();
+            let ambiguous: Vec<Square> = self
+                .generate_moves()
+                .iter()
+                .filter(|other| {
+                    other.to() == to
+                        && other.from() != from
+                        && self.at(other.from()).map(|other| other.kind) == Some(piece.kind)
+                })
+                .map(Move::from)
+                .collect();
+            if !ambiguous.is_empty() {
+                if ambiguous.iter().all(|square| square.file() != from.file()) {
+                    write!(san, "{}", from.file()).unwrap();
+                } else if ambiguous.iter().all(|square| square.rank() != from.rank()) {
+                    write!(san, "{}", from.rank()).unwrap();
+                } else {
+                    write!(san, "{from}").unwrap();
+                }
+            }
+            if self.at(to).is_some() {
+                san.push('x');
+            }
+            write!(san, "{to}").unwrap();
+        }
+
+        let mut next_position = self.clone();
+        next_position.make_move(next_move);
+        if next_position.in_check() {
+            san.push(if next_position.generate_moves().is_empty() {
+                '#'
+            } else {
+                '+'
+            });
+        }
+        san
+    }
+
+    /// Parses a legal move in [Standard Algebraic Notation] (SAN). The check
+    /// and annotation suffixes (`+`, `#`, `!`, `?`) are optional and castling
+    /// can be written with zeros (`0-0`).
+    ///
+    /// [Standard Algebraic Notation]: https://www.chessprogramming.org/Algebraic_Chess_Notation#Standard_Algebraic_Notation_.28SAN.29
+    ///
+    /// # Errors
+    ///
+    /// If the move is not legal in this position.
+    pub fn parse_san(&self, san: &str) -> anyhow::Result<Move> {
+        let strip = |san: &str| san.trim_end_matches(['+', '#', '!', '?']).replace('0', "O");
+        let expected = strip(san.trim());
+        self.generate_moves()
+            .into_iter()
+            .find(|next_move| strip(&self.to_san(next_move)) == expected)
+            .with_context(|| format!("illegal move {san} in {self}"))
+    }
+
+    /// Parses a legal move written either in UCI (`g1f3`) or in SAN (`Nf3`)
+    /// notation, which is convenient for the moves typed by hand.
+    ///
+    /// # Errors
+    ///
+    /// If the move is not legal in this position.
+    pub fn parse_move(&self, input: &str) -> anyhow::Result<Move> {
+        if let Ok(next_move) = Move::from_uci(input.trim()) {
+            if self.generate_moves().contains(&next_move) {
+                return Ok(next_move);
+            }
+        }
+        self.parse_san(input)
+    }
+
+    /// Parses the move in UCI notation sent by the GUI. In [Chess960] mode
+    /// (`UCI_Chess960`), castling is encoded as the king capturing its own
+    /// rook (`e1h1`), otherwise as the king move to its target square (`e1g1`).
+    /// The castling move is always returned in the internal encoding (see
+    /// [`CastlingRule`]) and the other encoding is rejected: accepting it
+    /// would hide the mismatch of the options between the GUI and the engine.
+    ///
+    /// Only the encoding is checked here, not the legality of the move.
+    ///
+    /// [Chess960]: https://www.chessprogramming.org/Chess960
+    ///
+    /// # Errors
+    ///
+    /// If the input is not a UCI move or castling uses the wrong encoding.
+    pub fn parse_uci(&self, uci: &str, chess960: bool) -> anyhow::Result<Move> {
+        let parsed = Move::from_uci(uci)?;
+        let Some(rule) = self.castling_rules(self.us()).find(|rule| {
+            parsed.from() == rule.king && (parsed.to() == rule.rook || parsed.to() == rule.to)
+        }) else {
+            return Ok(parsed);
+        };
+        let expected = if chess960 { rule.rook } else { rule.to };
+        if parsed.to() == expected {
+            return Ok(Move::new(rule.king, rule.to, None));
+        }
+        if chess960 {
+            bail!(
+                "castling {uci} should be encoded as {}{expected} with UCI_Chess960",
+                rule.king
+            )
+        }
+        bail!(
+            "castling {uci} should be encoded as {}{expected} unless UCI_Chess960 is set",
+            rule.king
+        )
+    }
+
+    /// Formats the move in UCI notation, the inverse of
+    /// [`Position::parse_uci`]: castling is encoded as the king capturing its
+    /// own rook in Chess960 mode.
+    #[must_use]
+    pub fn to_uci(&self, next_move: &Move, chess960: bool) -> String {
+        if chess960 {
+            if let Some(rule) = self.castling_rule(next_move) {
+                return Move::new(rule.king, rule.rook, None).to_string();
+            }
+        }
+        next_move.to_string()
+    }
+
+    /// Same as [`Position::to_uci`] for the legal moves played one after
+    /// another from this position, e.g. the principal variation.
+    #[must_use]
+    pub fn uci_line(&self, moves: &[Move], chess960: bool) -> Vec<String> {
+        let mut position = self.clone();
+        moves
+            .iter()
+            .map(|next_move| {
+                let uci = position.to_uci(next_move, chess960);
+                position.make_move(next_move);
+                uci
+            })
+            .collect()
+    }
+
+    /// Returns the castling rule if the move is castling.
+    fn castling_rule(&self, next_move: &Move) -> Option<CastlingRule> {
+        self.castling_rules(self.us())
+            .find(|rule| next_move.from() == rule.king && next_move.to() == rule.to)
+    }
+
+    /// Returns the rules of the castling rights the player still has. The
+    /// king is on its home square as long as it has any of them.
+    fn castling_rules(&self, player: Player) -> impl Iterator<Item = CastlingRule> + '_ {
+        let rights = self.castling & player_castling_rights(player);
+        CASTLING_RIGHTS
+            .into_iter()
+            .zip(self.castling_rooks)
+            .filter(move |&(right, _)| rights.contains(right))
+            .map(move |(right, rook)| CastlingRule::new(right, self.king_square(player), rook))
+    }
+
+    /// Writes the castling rights in [X-FEN]: the rook is given by its file
+    /// if it is not the outermost one on its side of the king, which only
+    /// happens in Chess960. Standard positions use the regular FEN.
+    ///
+    /// [X-FEN]: https://en.wikipedia.org/wiki/X-FEN
+    fn write_castling(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.castling == CastleRights::NONE {
+            return f.write_char('-');
+        }
+        for player in [Player::White, Player::Black] {
+            let rooks = self.pieces(player).rooks;
+            for rule in self.castling_rules(player) {
+                let outer = rooks.iter().any(|rook| {
+                    rook.rank() == rule.rook.rank()
+                        && if rule.is_short() {
+                            rook.file() > rule.rook.file()
+                        } else {
+                            rook.file() < rule.rook.file()
+                        }
+                });
+                let symbol = match (outer, rule.is_short()) {
+                    (true, _) => (b'a' + rule.rook.file() as u8) as char,
+                    (false, true) => 'k',
+                    (false, false) => 'q',
+                };
+                f.write_char(match player {
+                    Player::White => symbol.to_ascii_uppercase(),
+                    Player::Black => symbol,
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the legal moves played one after another from this position in
+    /// SAN with move numbers, e.g. `1. e4 e5 2. Nf3` or `3... Nc6 4. Bb5`.
+    #[must_use]
+    pub fn san_line(&self, moves: &[Move]) -> String {
+        let mut position = self.clone();
+        let mut line = Vec::with_capacity(moves.len());
+        for (index, next_move) in moves.iter().enumerate() {
+            let number = position.fullmove_counter();
+            match position.us() {
+                Player::White => line.push(format!("{number}.")),
+                Player::Black if index == 0 => line.push(format!("{number}...")),
+                Player::Black => {},
+            }
+            line.push(position.to_san(next_move));
+            position.make_move(next_move);
+        }
+        line.join(" ")
+    }
+
     /// Computes standard Zobrist hash of the position using pseudo-random
     /// numbers generated during the build stage.
     ///
@@ -788,30 +1625,36 @@ impl Position {
             key ^= generated::BLACK_TO_MOVE;
         }
 
-        if self.castling.contains(CastleRights::WHITE_SHORT) {
-            key ^= generated::WHITE_CAN_CASTLE_SHORT;
-        }
-        if self.castling.contains(CastleRights::WHITE_LONG) {
-            key ^= generated::WHITE_CAN_CASTLE_LONG;
-        }
-        if self.castling.contains(CastleRights::BLACK_SHORT) {
-            key ^= generated::BLACK_CAN_CASTLE_SHORT;
-        }
-        if self.castling.contains(CastleRights::BLACK_LONG) {
-            key ^= generated::BLACK_CAN_CASTLE_LONG;
-        }
+        key ^= castling_key(self.castling);
 
-        if let Some(ep_square) = self.en_passant_square {
-            key ^= generated::EN_PASSANT_FILES[ep_square.file() as usize];
-        }
+        key ^= self.en_passant_key();
 
-        for square in self.occupied_squares().iter() {
-            let piece = self.at(square).expect("occupied square");
+        for (square, piece) in self.iter_pieces() {
             key ^= generated::get_piece_key(piece, square);
         }
 
         key
     }
+
+    /// Returns the key of the en passant square if a pawn of the side to move
+    /// attacks it and 0 otherwise. The square is kept after every double push
+    /// for exact FEN round-trips, but the positions that only differ in an en
+    /// passant square that can not be captured are the same for repetition
+    /// detection and hence should have the same hash. Pins are not checked,
+    /// as in [Polyglot].
+    ///
+    /// [Polyglot]: http://hgm.nubati.net/book_format.html
+    fn en_passant_key(&self) -> zobrist::Key {
+        match self.en_passant_square {
+            Some(square)
+                if (self.pieces(self.us()).pawns & attacks::pawn_attacks(square, self.them()))
+                    .has_any() =>
+            {
+                generated::EN_PASSANT_FILES[square.file() as usize]
+            },
+            _ => 0,
+        }
+    }
 }
 
 impl TryFrom<&str> for Position {
@@ -828,6 +1671,74 @@ impl TryFrom<&str> for Position {
     }
 }
 
+/// Single difference between two positions, see [`Position::diff`]. State
+/// changes store the values before and after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SquareChange {
+    Moved {
+        piece: Piece,
+        from: Square,
+        to: Square,
+    },
+    Added {
+        square: Square,
+        piece: Piece,
+    },
+    Removed {
+        square: Square,
+        piece: Piece,
+    },
+    SideToMove(Player, Player),
+    Castling(CastleRights, CastleRights),
+    EnPassant(Option<Square>, Option<Square>),
+    HalfmoveClock(u8, u8),
+    FullmoveCounter(u16, u16),
+}
+
+impl fmt::Display for SquareChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn square_or_dash(square: Option<Square>) -> String {
+            square.map_or_else(|| "-".to_string(), |square| square.to_string())
+        }
+
+        match self {
+            Self::Moved { piece, from, to } => write!(f, "{piece} {from}-{to}"),
+            Self::Added { square, piece } => write!(f, "+{piece} {square}"),
+            Self::Removed { square, piece } => write!(f, "-{piece} {square}"),
+            Self::SideToMove(before, after) => write!(f, "side to move: {before} -> {after}"),
+            Self::Castling(before, after) => write!(f, "castling: {before} -> {after}"),
+            Self::EnPassant(before, after) => write!(
+                f,
+                "en passant: {} -> {}",
+                square_or_dash(*before),
+                square_or_dash(*after)
+            ),
+            Self::HalfmoveClock(before, after) => {
+                write!(f, "halfmove clock: {before} -> {after}")
+            },
+            Self::FullmoveCounter(before, after) => {
+                write!(f, "fullmove counter: {before} -> {after}")
+            },
+        }
+    }
+}
+
+/// Positions are serialized as FEN strings.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Position {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Position {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let fen = <std::borrow::Cow<'_, str>>::deserialize(deserializer)?;
+        Self::from_fen(&fen).map_err(|e| serde::de::Error::custom(format!("{e:#}")))
+    }
+}
+
 impl fmt::Display for Position {
     /// Returns position representation in Forsyth-Edwards Notation (FEN).
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -856,7 +1767,8 @@ impl fmt::Display for Position {
             }
         }
         write!(f, " {} ", &self.side_to_move)?;
-        write!(f, "{} ", &self.castling)?;
+        self.write_castling(f)?;
+        f.write_char(' ')?;
         match self.en_passant_square {
             Some(square) => write!(f, "{square} "),
             None => write!(f, "- "),
@@ -931,6 +1843,113 @@ pub fn perft(position: &Position, depth: u8) -> u64 {
     nodes
 }
 
+/// Leaf node counts of [`divide`]: comparing them move by move against another
+/// move generator narrows down the position where the two disagree.
+#[derive(Clone, Debug)]
+pub struct PerftResult {
+    pub depth: u8,
+    /// Number of leaf nodes after each legal move, in the order of move
+    /// generation.
+    pub moves: Vec<(Move, u64)>,
+    /// Total number of leaf nodes.
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+impl PerftResult {
+    /// Leaf nodes per second.
+    #[must_use]
+    pub fn nps(&self) -> u64 {
+        crate::search::nps(self.nodes, self.elapsed)
+    }
+
+    /// Formats the result as a single line of JSON for the scripts comparing
+    /// the move generator against other engines.
+    ///
+    /// ```json
+    /// {"depth":2,"nodes":400,"time_ms":0,"nps":4000000,
+    ///  "moves":[{"move":"a2a3","nodes":20},...]}
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let moves = self
+            .moves
+            .iter()
+            .map(|(next_move, nodes)| format!("{{\"move\":\"{next_move}\",\"nodes\":{nodes}}}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{{\"depth\":{},\"nodes\":{},\"time_ms\":{},\"nps\":{},\"moves\":[{moves}]}}",
+            self.depth,
+            self.nodes,
+            self.elapsed.as_millis(),
+            self.nps(),
+        )
+    }
+}
+
+impl fmt::Display for PerftResult {
+    /// Uses the same format as Stockfish's `go perft` so that the outputs can
+    /// be compared with standard tools.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (next_move, nodes) in &self.moves {
+            writeln!(f, "{next_move}: {nodes}")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Nodes searched: {}", self.nodes)?;
+        writeln!(f, "Time: {} ms", self.elapsed.as_millis())?;
+        write!(f, "Nodes/second: {}", self.nps())
+    }
+}
+
+/// Runs [`perft`] for each legal move separately (also known as "divide").
+#[must_use]
+pub fn divide(position: &Position, depth: u8) -> PerftResult {
+    let start = Instant::now();
+    let moves: Vec<(Move, u64)> = if depth == 0 {
+        Vec::new()
+    } else {
+        position
+            .generate_moves()
+            .iter()
+            .map(|next_move| {
+                let mut next_position = position.clone();
+                next_position.make_move(next_move);
+                (*next_move, perft(&next_position, depth - 1))
+            })
+            .collect()
+    };
+    let nodes = if depth == 0 {
+        1
+    } else {
+        moves.iter().map(|(_, nodes)| nodes).sum()
+    };
+    PerftResult {
+        depth,
+        moves,
+        nodes,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Same as [`perft`] but uses [`Position::generate_pseudo_legal_moves`] and
+/// filters the illegal moves when making them. Used to compare the performance
+/// of the two approaches.
+#[must_use]
+pub fn perft_pseudo_legal(position: &Position, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let mut nodes = 0;
+    for next_move in position.generate_pseudo_legal_moves() {
+        let mut next_position = position.clone();
+        if next_position.make_pseudo_legal_move(&next_move) {
+            nodes += perft_pseudo_legal(&next_position, depth - 1);
+        }
+    }
+    nodes
+}
+
 /// Checks if the position is "legal", i.e. if it can be reasoned about by the
 /// engine. Checking whether the position is truly reachable from the starting
 /// position (either in standard chess or Chess960) requires retrograde analysis
@@ -1003,7 +2022,7 @@ fn validate(position: &Position) -> anyhow::Result<()> {
         }
         // If en-passant was played and there's a check, doubly pushed pawn
         // should be the only checker or it should be a discovery.
-        let king = position.pieces(position.us()).king.as_square();
+        let king = position.king_square(position.us());
         if attack_info.checkers.has_any() {
             if attack_info.checkers.count() > 1 {
                 bail!("more than 1 check after double pawn push is impossible")
@@ -1028,7 +2047,7 @@ fn validate(position: &Position) -> anyhow::Result<()> {
             .iter()
         {
             let xray = attacks::bishop_ray(attacker, king);
-            if (xray & (position.occupied_squares())).count() == 2
+            if (xray & (position.occupied())).count() == 2
                 && xray.contains(attacker)
                 && xray.contains(pushed_pawn)
             {
@@ -1039,6 +2058,273 @@ fn validate(position: &Position) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Castling rights in the order of [`Position::castling_rooks`].
+const CASTLING_RIGHTS: [CastleRights; 4] = [
+    CastleRights::WHITE_SHORT,
+    CastleRights::WHITE_LONG,
+    CastleRights::BLACK_SHORT,
+    CastleRights::BLACK_LONG,
+];
+
+const STANDARD_CASTLING_ROOKS: [Square; 4] = [Square::H1, Square::A1, Square::H8, Square::A8];
+
+const fn player_castling_rights(player: Player) -> CastleRights {
+    match player {
+        Player::White => CastleRights::WHITE_BOTH,
+        Player::Black => CastleRights::BLACK_BOTH,
+    }
+}
+
+/// Squares involved in castling for one of the [`CastleRights`].
+///
+/// The king and the rook start on their home squares, which come from the FEN
+/// in [Chess960], and always end up on the same squares as in standard chess.
+/// All castling logic (move generation, making moves, updating the rights and
+/// the hash) is driven by [`Position::castling_rules`].
+///
+/// Castling is encoded as the king move to its target square, unless the
+/// king moves by a single square or not at all: then it is the king capturing
+/// its own rook, which can not be confused with a regular king move.
+///
+/// [Chess960]: https://www.chessprogramming.org/Chess960
+#[derive(Clone, Copy, Debug)]
+struct CastlingRule {
+    /// Home squares of the king and the rook: the right is lost once either of
+    /// them moves or the rook is captured.
+    king: Square,
+    rook: Square,
+    king_target: Square,
+    rook_target: Square,
+    /// Target of the castling move.
+    to: Square,
+    /// Squares that can not be attacked.
+    king_walk: Bitboard,
+    /// Squares that have to be empty, apart from the king and the rook.
+    path: Bitboard,
+}
+
+impl CastlingRule {
+    fn new(right: CastleRights, king: Square, rook: Square) -> Self {
+        let rank = king.rank();
+        let (king_file, rook_file) = if rook > king {
+            (File::G, File::F)
+        } else {
+            (File::C, File::D)
+        };
+        debug_assert_eq!(
+            rook > king,
+            right.intersects(CastleRights::WHITE_SHORT | CastleRights::BLACK_SHORT)
+        );
+        let (king_target, rook_target) =
+            (Square::new(king_file, rank), Square::new(rook_file, rank));
+        // Both ends included.
+        let span = |from: Square, to: Square| attacks::ray(from, to) | Bitboard::from(to);
+        let pieces = Bitboard::from(king) | Bitboard::from(rook);
+        let to = if (king.file() as i8 - king_file as i8).abs() < 2 {
+            rook
+        } else {
+            king_target
+        };
+        Self {
+            king,
+            rook,
+            king_target,
+            rook_target,
+            to,
+            king_walk: span(king, king_target) - Bitboard::from(king),
+            path: (span(king, king_target) | span(rook, rook_target)) - pieces,
+        }
+    }
+
+    fn is_short(&self) -> bool {
+        self.rook > self.king
+    }
+
+    /// Returns true if the king can castle: it is not in check, the path is
+    /// empty and the king does not pass through or land on an attacked square.
+    /// In Chess960 the rook might shield the king target from a rook or a
+    /// queen on the backrank before castling.
+    fn is_legal(
+        &self,
+        attack_info: &attacks::AttackInfo,
+        occupied_squares: Bitboard,
+        their_pieces: &Pieces,
+    ) -> bool {
+        if attack_info.checkers.has_any()
+            || (attack_info.attacks & self.king_walk).has_any()
+            || (occupied_squares & self.path).has_any()
+        {
+            return false;
+        }
+        let occupancy = (occupied_squares - Bitboard::from(self.king) - Bitboard::from(self.rook))
+            | Bitboard::from(self.rook_target);
+        (attacks::rook_attacks(self.king_target, occupancy)
+            & (their_pieces.rooks | their_pieces.queens))
+            .is_empty()
+    }
+}
+
+/// Parses the castling rights from FEN and returns them along with the home
+/// squares of the castling rooks. Besides the standard `KQkq`, the rights can
+/// be given by the files of the rooks ([Shredder-FEN], e.g. `HAha` or `GEge`
+/// in Chess960) or mix both ([X-FEN]): `K` and `Q` stand for the outermost
+/// rook on that side of the king.
+///
+/// The rights without the king on the backrank or the rook to castle with are
+/// dropped.
+///
+/// [Shredder-FEN]: https://www.chessprogramming.org/Forsyth-Edwards_Notation#Shredder-FEN
+/// [X-FEN]: https://en.wikipedia.org/wiki/X-FEN
+fn parse_castling(
+    input: &str,
+    white_pieces: &Pieces,
+    black_pieces: &Pieces,
+) -> anyhow::Result<(CastleRights, [Square; 4])> {
+    let mut castling = CastleRights::NONE;
+    let mut rooks = STANDARD_CASTLING_ROOKS;
+    if input == "-" {
+        return Ok((castling, rooks));
+    }
+    if input.is_empty() {
+        bail!("unknown castle rights: {input}");
+    }
+    for symbol in input.chars() {
+        let (player, pieces) = if symbol.is_ascii_uppercase() {
+            (Player::White, white_pieces)
+        } else {
+            (Player::Black, black_pieces)
+        };
+        let backrank = match player {
+            Player::White => Rank::Rank1,
+            Player::Black => Rank::Rank8,
+        };
+        let candidates = pieces.rooks & backrank.mask();
+        let king = (pieces.king & backrank.mask()).iter().next();
+        let rook = match symbol.to_ascii_lowercase() {
+            'k' => king.and_then(|king| candidates.iter().filter(|&rook| rook > king).last()),
+            'q' => king.and_then(|king| candidates.iter().find(|&rook| rook < king)),
+            file @ 'a'..='h' => {
+                let rook = Square::new(File::try_from(file)?, backrank);
+                king.filter(|_| candidates.contains(rook)).map(|_| rook)
+            },
+            _ => bail!("unknown castle rights: {input}"),
+        };
+        let (Some(king), Some(rook)) = (king, rook) else {
+            continue;
+        };
+        let index = match (player, rook > king) {
+            (Player::White, true) => 0,
+            (Player::White, false) => 1,
+            (Player::Black, true) => 2,
+            (Player::Black, false) => 3,
+        };
+        if castling.contains(CASTLING_RIGHTS[index]) {
+            bail!("duplicate castle rights: {input}");
+        }
+        castling |= CASTLING_RIGHTS[index];
+        rooks[index] = rook;
+    }
+    Ok((castling, rooks))
+}
+
+/// Zobrist key of the castling rights.
+fn castling_key(rights: CastleRights) -> zobrist::Key {
+    [
+        generated::WHITE_CAN_CASTLE_SHORT,
+        generated::WHITE_CAN_CASTLE_LONG,
+        generated::BLACK_CAN_CASTLE_SHORT,
+        generated::BLACK_CAN_CASTLE_LONG,
+    ]
+    .into_iter()
+    .zip(CASTLING_RIGHTS)
+    .filter(|&(_, right)| rights.contains(right))
+    .fold(0, |key, (right_key, _)| key ^ right_key)
+}
+
+/// Returns the squares where the pieces other than the king can move to
+/// resolve the check or [`None`] in case of a double check, which can only be
+/// evaded by moving the king.
+fn check_mask(checkers: Bitboard, king: Square) -> Option<Bitboard> {
+    match checkers.count() {
+        0 => Some(Bitboard::full()),
+        // There are two ways of getting out of check:
+        //
+        // - Moving king to safety (handled separately)
+        // - Blocking the checker or capturing it
+        1 => {
+            let checker: Square = checkers.as_square();
+            let ray = attacks::ray(checker, king);
+            if ray.is_empty() {
+                // This means the checker is a knight: capture is the only
+                // way left to resolve this check.
+                Some(checkers)
+            } else {
+                // Checker is a sliding piece: both capturing and blocking
+                // resolves the check.
+                Some(ray)
+            }
+        },
+        2 => None,
+        _ => unreachable!("checks can't be given by more than two pieces at once"),
+    }
+}
+
+/// Returns true if the pinned piece would leave the line between the pinner and
+/// our king.
+#[inline]
+fn breaks_pin(pins: Bitboard, from: Square, to: Square, king: Square) -> bool {
+    pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty()
+}
+
+/// Returns our pawns that can legally capture en passant. The pins and checks
+/// of our `king` come from the `attack_info` of the position.
+fn en_passant_captures(
+    pawns: Bitboard,
+    them: Player,
+    their_pieces: &Pieces,
+    attack_info: &attacks::AttackInfo,
+    king: Square,
+    en_passant_square: Square,
+    occupied_squares: Bitboard,
+) -> Bitboard {
+    let en_passant_pawn = en_passant_square.shift(pawn_push_direction(them)).unwrap();
+    // Check if capturing en passant resolves the check.
+    let candidate_pawns = attacks::pawn_attacks(en_passant_square, them) & pawns;
+    if attack_info.checkers.contains(en_passant_pawn) {
+        return candidate_pawns - attack_info.pins;
+    }
+    // Both pawns leave their squares and ours lands on the en passant square.
+    // The lines of sight from our king after removing the captured pawn are
+    // shared by both candidates, so they are only computed once.
+    let mut occupancy = occupied_squares;
+    occupancy.clear(en_passant_pawn);
+    occupancy.extend(en_passant_square);
+    let rook_sliders = their_pieces.rooks | their_pieces.queens;
+    let bishop_sliders = their_pieces.bishops | their_pieces.queens;
+    let rook_lines = attacks::rook_attacks(king, occupancy);
+    let bishop_lines = attacks::bishop_attacks(king, occupancy);
+    if !(rook_lines & rook_sliders).is_empty() || !(bishop_lines & bishop_sliders).is_empty() {
+        return Bitboard::empty();
+    }
+    // Moving a pawn the king can not see does not change its lines of sight.
+    let mut result = candidate_pawns - (rook_lines | bishop_lines);
+    // Otherwise the pawn can only expose the king along the line it was on
+    // (e.g. both pawns were shielding the king on the same rank).
+    for our_pawn in (candidate_pawns & (rook_lines | bishop_lines)).iter() {
+        let mut occupancy_after_capture = occupancy;
+        occupancy_after_capture.clear(our_pawn);
+        let exposed = if rook_lines.contains(our_pawn) {
+            attacks::rook_attacks(king, occupancy_after_capture) & rook_sliders
+        } else {
+            attacks::bishop_attacks(king, occupancy_after_capture) & bishop_sliders
+        };
+        if exposed.is_empty() {
+            result.extend(our_pawn);
+        }
+    }
+    result
+}
+
 fn generate_king_moves(king: Square, safe_squares: Bitboard, moves: &mut MoveList) {
     for safe_square in safe_squares.iter() {
         unsafe {
@@ -1078,9 +2364,7 @@ fn generate_rook_moves(
     for from in rooks.iter() {
         let targets = attacks::rook_attacks(from, occupied_squares) & their_or_empty & blocking_ray;
         for to in targets.iter() {
-            // TODO: This block is repeated several times; abstract it out.
-            if pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty()
-            {
+            if breaks_pin(pins, from, to, king) {
                 continue;
             }
             unsafe { moves.push_unchecked(Move::new(from, to, None)) }
@@ -1101,9 +2385,7 @@ fn generate_bishop_moves(
         let targets =
             attacks::bishop_attacks(from, occupied_squares) & their_or_empty & blocking_ray;
         for to in targets.iter() {
-            // TODO: This block is repeated several times; abstract it out.
-            if pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty()
-            {
+            if breaks_pin(pins, from, to, king) {
                 continue;
             }
             unsafe { moves.push_unchecked(Move::new(from, to, None)) }
@@ -1119,22 +2401,20 @@ fn generate_pawn_moves(
     their_occupancy: Bitboard,
     their_or_empty: Bitboard,
     blocking_ray: Bitboard,
-    pins: Bitboard,
-    checkers: Bitboard,
+    attack_info: &attacks::AttackInfo,
     king: Square,
     en_passant_square: Option<Square>,
     occupied_squares: Bitboard,
     moves: &mut MoveList,
 ) {
+    let pins = attack_info.pins;
     // TODO: Get rid of the branch: AND pawns getting to the promotion rank and the
     // rest.
     for from in pawns.iter() {
         let targets =
             (attacks::pawn_attacks(from, us) & their_occupancy) & their_or_empty & blocking_ray;
         for to in targets.iter() {
-            // TODO: This block is repeated several times; abstract it out.
-            if pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty()
-            {
+            if breaks_pin(pins, from, to, king) {
                 continue;
             }
             match to.rank() {
@@ -1150,37 +2430,19 @@ fn generate_pawn_moves(
     }
     // Generate en passant moves.
     if let Some(en_passant_square) = en_passant_square {
-        let en_passant_pawn = en_passant_square.shift(pawn_push_direction(them)).unwrap();
-        // Check if capturing en passant resolves the check.
-        let candidate_pawns = attacks::pawn_attacks(en_passant_square, them) & pawns;
-        if checkers.contains(en_passant_pawn) {
-            for our_pawn in candidate_pawns.iter() {
-                if pins.contains(our_pawn) {
-                    continue;
-                }
-                unsafe {
-                    moves.push_unchecked(Move::new(our_pawn, en_passant_square, None));
-                }
-            }
-        } else {
-            // Check if capturing en passant does not create a discovered check.
-            for our_pawn in candidate_pawns.iter() {
-                let mut occupancy_after_capture = occupied_squares;
-                occupancy_after_capture.clear(our_pawn);
-                occupancy_after_capture.clear(en_passant_pawn);
-                occupancy_after_capture.extend(en_passant_square);
-                if (attacks::queen_attacks(king, occupancy_after_capture) & their_pieces.queens)
-                    .is_empty()
-                    && (attacks::rook_attacks(king, occupancy_after_capture) & their_pieces.rooks)
-                        .is_empty()
-                    && (attacks::bishop_attacks(king, occupancy_after_capture)
-                        & their_pieces.bishops)
-                        .is_empty()
-                {
-                    unsafe {
-                        moves.push_unchecked(Move::new(our_pawn, en_passant_square, None));
-                    }
-                }
+        for our_pawn in en_passant_captures(
+            pawns,
+            them,
+            their_pieces,
+            attack_info,
+            king,
+            en_passant_square,
+            occupied_squares,
+        )
+        .iter()
+        {
+            unsafe {
+                moves.push_unchecked(Move::new(our_pawn, en_passant_square, None));
             }
         }
     }
@@ -1205,7 +2467,7 @@ fn generate_pawn_moves(
         if !blocking_ray.contains(to) {
             continue;
         }
-        if pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty() {
+        if breaks_pin(pins, from, to, king) {
             continue;
         }
         add_pawn_moves(moves, from, to);
@@ -1222,7 +2484,7 @@ fn generate_pawn_moves(
         if !blocking_ray.contains(to) {
             continue;
         }
-        if pins.contains(from) && (attacks::ray(from, king) & attacks::ray(to, king)).is_empty() {
+        if breaks_pin(pins, from, to, king) {
             continue;
         }
         unsafe {
@@ -1231,70 +2493,6 @@ fn generate_pawn_moves(
     }
 }
 
-fn generate_castle_moves(
-    us: Player,
-    checkers: Bitboard,
-    castling: CastleRights,
-    attacks: Bitboard,
-    occupied_squares: Bitboard,
-    moves: &mut MoveList,
-) {
-    // TODO: Generalize castling to FCR.
-    // TODO: In FCR we should check if the rook is pinned or not.
-    if checkers.is_empty() {
-        match us {
-            Player::White => {
-                if castling.contains(CastleRights::WHITE_SHORT)
-                    && (attacks & attacks::WHITE_SHORT_CASTLE_KING_WALK).is_empty()
-                    && (occupied_squares
-                        & (attacks::WHITE_SHORT_CASTLE_KING_WALK
-                            | attacks::WHITE_SHORT_CASTLE_ROOK_WALK))
-                        .is_empty()
-                {
-                    unsafe {
-                        moves.push_unchecked(Move::new(Square::E1, Square::G1, None));
-                    }
-                }
-                if castling.contains(CastleRights::WHITE_LONG)
-                    && (attacks & attacks::WHITE_LONG_CASTLE_KING_WALK).is_empty()
-                    && (occupied_squares
-                        & (attacks::WHITE_LONG_CASTLE_KING_WALK
-                            | attacks::WHITE_LONG_CASTLE_ROOK_WALK))
-                        .is_empty()
-                {
-                    unsafe {
-                        moves.push_unchecked(Move::new(Square::E1, Square::C1, None));
-                    }
-                }
-            },
-            Player::Black => {
-                if castling.contains(CastleRights::BLACK_SHORT)
-                    && (attacks & attacks::BLACK_SHORT_CASTLE_KING_WALK).is_empty()
-                    && (occupied_squares
-                        & (attacks::BLACK_SHORT_CASTLE_KING_WALK
-                            | attacks::BLACK_SHORT_CASTLE_ROOK_WALK))
-                        .is_empty()
-                {
-                    unsafe {
-                        moves.push_unchecked(Move::new(Square::E8, Square::G8, None));
-                    }
-                }
-                if castling.contains(CastleRights::BLACK_LONG)
-                    && (attacks & attacks::BLACK_LONG_CASTLE_KING_WALK).is_empty()
-                    && (occupied_squares
-                        & (attacks::BLACK_LONG_CASTLE_KING_WALK
-                            | attacks::BLACK_LONG_CASTLE_ROOK_WALK))
-                        .is_empty()
-                {
-                    unsafe {
-                        moves.push_unchecked(Move::new(Square::E8, Square::C8, None));
-                    }
-                }
-            },
-        }
-    }
-}
-
 const fn pawn_push_direction(player: Player) -> Direction {
     match player {
         Player::White => Direction::Up,
@@ -1308,6 +2506,22 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "should promote iff it reaches the last rank")]
+    fn missing_promotion() {
+        let mut position = Position::from_fen("7k/2P5/6K1/8/8/8/8/8 w - - 0 1").unwrap();
+        position.make_move(&Move::from_uci("c7c8").unwrap());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "should start on the seventh rank")]
+    fn promotion_from_wrong_rank() {
+        let mut position = Position::from_fen("7k/8/2P3K1/8/8/8/8/8 w - - 0 1").unwrap();
+        position.make_move(&Move::from_uci("c6c8q").unwrap());
+    }
+
     #[test]
     fn starting() {
         let position = Position::starting();
@@ -1338,4 +2552,512 @@ mod tests {
             Rank::Rank3.mask() | Rank::Rank4.mask() | Rank::Rank5.mask() | Rank::Rank6.mask()
         );
     }
+
+    #[test]
+    fn pieces() {
+        let position = Position::starting();
+        assert_eq!(
+            position.occupied(),
+            Rank::Rank1.mask() | Rank::Rank2.mask() | Rank::Rank7.mask() | Rank::Rank8.mask()
+        );
+        assert_eq!(position.iter_pieces().count(), 32);
+        assert!(position
+            .iter_pieces()
+            .all(|(square, piece)| position.at(square) == Some(piece)));
+        assert_eq!(position.king_square(Player::White), Square::E1);
+        assert_eq!(position.king_square(Player::Black), Square::E8);
+
+        let position = Position::from_fen("8/8/8/3k4/8/8/6K1/8 b - - 0 1").unwrap();
+        assert_eq!(position.occupied().count(), 2);
+        assert_eq!(
+            position
+                .iter_pieces()
+                .map(|(square, piece)| format!("{piece}{square}"))
+                .collect::<Vec<_>>(),
+            vec!["Kg2", "kd5"]
+        );
+        assert_eq!(position.king_square(Player::White), Square::G2);
+        assert_eq!(position.king_square(position.us()), Square::D5);
+    }
+
+    #[test]
+    fn en_passant_fen() {
+        // The square is set after every double push, as in FEN.
+        let mut position = Position::starting();
+        position.make_move(&Move::from_uci("e2e4").unwrap());
+        assert_eq!(
+            position.to_string(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+        position.make_move(&Move::from_uci("g8f6").unwrap());
+        assert_eq!(
+            position.to_string(),
+            "rnbqkb1r/pppppppp/5n2/8/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 1 2"
+        );
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+            "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        ] {
+            assert_eq!(Position::from_fen(fen).unwrap().to_string(), fen);
+        }
+    }
+
+    #[test]
+    fn en_passant_hash() {
+        let hash = |fen: &str| Position::from_fen(fen).unwrap().hash();
+        // The square only changes the hash if it can be captured.
+        assert_eq!(
+            hash("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"),
+            hash("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+        );
+        assert_ne!(
+            hash("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"),
+            hash("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+        );
+
+        let after = |fen: &str, moves: &[&str]| {
+            let mut position = Position::from_fen(fen).unwrap();
+            for uci in moves {
+                position.make_move(&Move::from_uci(uci).unwrap());
+            }
+            position
+        };
+        // The incremental updates match the hash computed from scratch, both
+        // when the square is set and when it is cleared.
+        let pawns = "4k3/8/8/8/3p4/8/4P3/4K3 w - - 0 1";
+        for moves in [&["e2e4", "e8d7"][..], &["e1d1", "e8d8", "e2e4", "d4e3"]] {
+            let position = after(pawns, moves);
+            assert_eq!(position.hash(), position.compute_hash(), "{moves:?}");
+        }
+        let position = after(
+            "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+            &["e1d1", "e8d8", "e2e4", "d8e8"],
+        );
+        assert_eq!(position.hash(), position.compute_hash());
+
+        // Repetitions are detected after a double push that can not be
+        // captured...
+        let pushed = after("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1", &["e2e4"]);
+        let repeated = after(
+            "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+            &["e2e4", "e8d8", "e1d1", "d8e8", "d1e1"],
+        );
+        assert_eq!(pushed.hash(), repeated.hash());
+        // ...but not after the one that can, since the capture is only
+        // possible once.
+        let pushed = after(pawns, &["e2e4"]);
+        let repeated = after(pawns, &["e2e4", "e8d8", "e1d1", "d8e8", "d1e1"]);
+        assert_ne!(pushed.hash(), repeated.hash());
+    }
+
+    #[test]
+    fn castling_rights() {
+        let after = |fen: &str, moves: &[&str]| {
+            let mut position = Position::from_fen(fen).unwrap();
+            for uci in moves {
+                position.make_move(&Move::from_uci(uci).unwrap());
+            }
+            position.to_string()
+        };
+        let rooks = "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1";
+        // King moves lose both rights, including castling itself.
+        assert_eq!(
+            after(rooks, &["e1e2"]),
+            "r3k2r/8/8/8/8/8/4K3/R6R b kq - 1 1"
+        );
+        assert_eq!(
+            after(rooks, &["e1g1"]),
+            "r3k2r/8/8/8/8/8/8/R4RK1 b kq - 1 1"
+        );
+        assert_eq!(
+            after(rooks, &["e1c1", "e8g8"]),
+            "r4rk1/8/8/8/8/8/8/2KR3R w - - 2 2"
+        );
+        // The castling keys are updated along with the rights.
+        let mut position = Position::from_fen(rooks).unwrap();
+        for uci in ["e1c1", "h8h7"] {
+            position.make_move(&Move::from_uci(uci).unwrap());
+        }
+        assert_eq!(position.hash(), position.compute_hash());
+        // Rook moves only lose the right on their side, even if the rook comes
+        // back.
+        assert_eq!(
+            after(rooks, &["h1h2", "a8a7", "h2h1"]),
+            "4k2r/r7/8/8/8/8/8/R3K2R b Qk - 3 2"
+        );
+        // Capturing a rook on its home square removes the opponent's right.
+        assert_eq!(after(rooks, &["a1a8"]), "R3k2r/8/8/8/8/8/8/4K2R b Kk - 0 1");
+        // Including by a promoting pawn.
+        assert_eq!(
+            after(
+                "r3k2r/1P6/8/8/8/8/6p1/R3K2R w KQkq - 0 1",
+                &["b7a8q", "g2h1n"]
+            ),
+            "Q3k2r/8/8/8/8/8/8/R3K2n w Qk - 0 2"
+        );
+        // Capturing a rook away from its home square does not change anything.
+        assert_eq!(
+            after("r3k2r/8/8/8/8/8/r7/R3K2R w KQkq - 0 1", &["a1a2"]),
+            "r3k2r/8/8/8/8/8/R7/4K2R b Kkq - 0 1"
+        );
+        // The moves to or from the home squares of the other pieces are not
+        // affected.
+        assert_eq!(
+            after("4k3/8/8/8/8/8/8/1N2K2R w K - 0 1", &["b1c3"]),
+            "4k3/8/8/8/8/2N5/8/4K2R b K - 1 1"
+        );
+    }
+
+    #[test]
+    fn mobility() {
+        fn check(position: &Position, depth: u8) {
+            let mobility = position.mobility();
+            let mut expected = [Bitboard::empty(); BOARD_SIZE as usize];
+            for next_move in position.generate_moves() {
+                expected[next_move.from() as usize].extend(next_move.to());
+            }
+            assert_eq!(mobility.targets, expected, "{position}");
+            assert!(mobility
+                .iter()
+                .all(|(square, targets)| mobility.targets(square) == targets));
+            if depth > 0 {
+                for next_move in position.generate_moves() {
+                    let mut child = position.clone();
+                    child.make_move(&next_move);
+                    check(&child, depth - 1);
+                }
+            }
+        }
+
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ] {
+            check(&Position::from_fen(fen).unwrap(), 2);
+        }
+
+        // Promotions are counted once.
+        let position = Position::from_fen("8/1P6/8/8/8/8/k7/4K3 w - - 0 1").unwrap();
+        assert_eq!(position.generate_moves().len(), 9);
+        assert_eq!(position.mobility().count(), 6);
+        // Double check: only the king can move.
+        let position = Position::from_fen("4k3/8/8/8/8/5n2/3N4/4K2r w - - 0 1").unwrap();
+        assert_eq!(
+            position
+                .mobility()
+                .iter()
+                .filter(|(_, t)| t.has_any())
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn san() {
+        let san = |fen: &str, uci: &str| {
+            Position::from_fen(fen)
+                .unwrap()
+                .to_san(&Move::from_uci(uci).unwrap())
+        };
+        let starting = Position::starting().to_string();
+        assert_eq!(san(&starting, "e2e4"), "e4");
+        assert_eq!(san(&starting, "g1f3"), "Nf3");
+        // Castling.
+        assert_eq!(san("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "e1g1"), "O-O");
+        assert_eq!(san("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "e8c8"), "O-O-O");
+        // Captures, including en passant.
+        assert_eq!(
+            san(
+                "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+                "e5f6"
+            ),
+            "exf6"
+        );
+        assert_eq!(
+            san(
+                "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2",
+                "e4d5"
+            ),
+            "exd5"
+        );
+        // Disambiguation by file, rank and both.
+        assert_eq!(san("4k3/8/8/8/8/8/8/R4RK1 w - - 0 1", "a1d1"), "Rad1");
+        assert_eq!(san("4k3/R7/8/8/8/8/8/R3K3 w - - 0 1", "a1a4"), "R1a4");
+        assert_eq!(san("4k3/8/8/8/8/8/8/Q1Q1K3 w - - 0 1", "a1b2"), "Qab2");
+        assert_eq!(san("4k3/8/8/8/Q1Q5/8/Q7/4K3 w - - 0 1", "a4b3"), "Qa4b3");
+        // Promotion with check and checkmate.
+        assert_eq!(san("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1", "b7b8q"), "b8=Q+");
+        assert_eq!(san("k7/8/1K6/8/8/8/8/7R w - - 0 1", "h1h8"), "Rh8#");
+
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        assert_eq!(
+            position.parse_san("0-0").unwrap(),
+            Move::from_uci("e1g1").unwrap()
+        );
+        assert_eq!(
+            position.parse_san("Rxa8+!").unwrap(),
+            Move::from_uci("a1a8").unwrap()
+        );
+        assert!(position.parse_san("Ra9").is_err());
+        assert!(Position::starting().parse_san("e5").is_err());
+
+        let starting = Position::starting();
+        for (input, uci) in [("e4", "e2e4"), ("e2e4", "e2e4"), (" Nf3 ", "g1f3")] {
+            assert_eq!(
+                starting.parse_move(input).unwrap(),
+                Move::from_uci(uci).unwrap()
+            );
+        }
+        assert!(starting.parse_move("e2e5").is_err());
+        assert!(starting.parse_move("Ke2").is_err());
+    }
+
+    #[test]
+    fn san_line() {
+        let moves = ["e2e4", "e7e5", "g1f3"].map(|uci| Move::from_uci(uci).unwrap());
+        let mut position = Position::starting();
+        assert_eq!(position.san_line(&moves), "1. e4 e5 2. Nf3");
+        assert_eq!(position.san_line(&[]), "");
+        position.make_move(&moves[0]);
+        assert_eq!(position.san_line(&moves[1..]), "1... e5 2. Nf3");
+    }
+
+    #[test]
+    fn chess960_castling() {
+        // The standard setup is a valid Chess960 position (#518).
+        for (fen, castling) in [
+            (
+                "r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1",
+                [("e1g1", "e1h1"), ("e1c1", "e1a1")],
+            ),
+            (
+                "r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1",
+                [("e8g8", "e8h8"), ("e8c8", "e8a8")],
+            ),
+        ] {
+            let position = Position::from_fen(fen).unwrap();
+            for (standard, chess960) in castling {
+                let castle = Move::from_uci(standard).unwrap();
+                assert!(position.generate_moves().contains(&castle), "{standard}");
+                assert_eq!(position.parse_uci(standard, false).unwrap(), castle);
+                assert_eq!(position.parse_uci(chess960, true).unwrap(), castle);
+                assert_eq!(position.to_uci(&castle, false), standard);
+                assert_eq!(position.to_uci(&castle, true), chess960);
+                // The encodings can not be mixed.
+                assert!(position.parse_uci(chess960, false).is_err());
+                assert!(position.parse_uci(standard, true).is_err());
+            }
+        }
+
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1").unwrap();
+        let moves = ["e1g1", "e8c8"].map(|uci| Move::from_uci(uci).unwrap());
+        assert_eq!(position.uci_line(&moves, false), ["e1g1", "e8c8"]);
+        assert_eq!(position.uci_line(&moves, true), ["e1h1", "e8a8"]);
+        // Without castling rights the king simply captures (or moves).
+        let position = Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R w - - 0 1").unwrap();
+        assert_eq!(
+            position.parse_uci("e1h1", true).unwrap(),
+            Move::from_uci("e1h1").unwrap()
+        );
+        let king_move = Move::from_uci("e1f1").unwrap();
+        assert_eq!(position.to_uci(&king_move, true), "e1f1");
+    }
+
+    #[test]
+    fn chess960_positions() {
+        let castle = |fen: &str, uci: &str, expected: &str| {
+            let mut position = Position::from_fen(fen).unwrap();
+            let castle = position.parse_uci(uci, true).unwrap();
+            assert!(position.generate_moves().contains(&castle), "{fen} {uci}");
+            assert_eq!(position.to_uci(&castle, true), uci);
+            assert_eq!(
+                position.parse_move(&position.to_san(&castle)).unwrap(),
+                castle
+            );
+            position.make_move(&castle);
+            assert_eq!(position.to_string(), expected);
+        };
+        // The king moves by one square when castling short, so the move is
+        // encoded as the king capturing its own rook either way.
+        let fen = "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w GEge - 0 1";
+        assert_eq!(
+            Position::from_fen(fen).unwrap().to_string(),
+            "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w KQkq - 0 1"
+        );
+        castle(
+            fen,
+            "f1g1",
+            "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RRK1 b kq - 1 1",
+        );
+        castle(
+            fen,
+            "f1e1",
+            "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1NKR2R1 b kq - 1 1",
+        );
+        let fen = "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 b GEge - 0 1";
+        castle(
+            fen,
+            "f8g8",
+            "1n2rrk1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w KQ - 1 2",
+        );
+        castle(
+            fen,
+            "f8e8",
+            "1nkr2r1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w KQ - 1 2",
+        );
+        // The castling keys are updated along with the rights.
+        let mut position = Position::from_fen(fen).unwrap();
+        for uci in ["f8e8", "f1g1"] {
+            position.make_move(&position.parse_uci(uci, true).unwrap());
+        }
+        assert_eq!(position.hash(), position.compute_hash());
+        let short = Move::from_uci("f1g1").unwrap();
+        let position =
+            Position::from_fen("1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w KQkq - 0 1").unwrap();
+        assert_eq!(position.to_uci(&short, false), "f1g1");
+        assert_eq!(position.to_san(&short), "O-O");
+        // The long castling is not confused with the regular king move.
+        assert_eq!(
+            position.parse_uci("f1c1", false).unwrap(),
+            Move::from_uci("f1c1").unwrap()
+        );
+        assert!(position.parse_uci("f1c1", true).is_err());
+
+        // The king stays on its square, or jumps over both rooks' targets.
+        let fen = "rk4r1/pppppppp/8/8/8/8/PPPPPPPP/RK4R1 w AGag - 0 1";
+        castle(
+            fen,
+            "b1g1",
+            "rk4r1/pppppppp/8/8/8/8/PPPPPPPP/R4RK1 b kq - 1 1",
+        );
+        castle(
+            fen,
+            "b1a1",
+            "rk4r1/pppppppp/8/8/8/8/PPPPPPPP/2KR2R1 b kq - 1 1",
+        );
+        let fen = "1r4kr/pppppppp/8/8/8/8/PPPPPPPP/1R4KR b HBhb - 0 1";
+        castle(
+            fen,
+            "g8h8",
+            "1r3rk1/pppppppp/8/8/8/8/PPPPPPPP/1R4KR w KQ - 1 2",
+        );
+        castle(
+            fen,
+            "g8b8",
+            "2kr3r/pppppppp/8/8/8/8/PPPPPPPP/1R4KR w KQ - 1 2",
+        );
+
+        // Only the rook next to the king castles: the outer one is named by
+        // its file.
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/RR2K3 w B - 0 1").unwrap();
+        assert_eq!(position.to_string(), "4k3/8/8/8/8/8/8/RR2K3 w B - 0 1");
+        assert_eq!(
+            Position::from_fen("4k3/8/8/8/8/8/8/RR2K3 w Q - 0 1")
+                .unwrap()
+                .to_string(),
+            "4k3/8/8/8/8/8/8/RR2K3 w Q - 0 1"
+        );
+        // The rights without the rook are dropped.
+        assert_eq!(
+            Position::from_fen("4k3/8/8/8/8/8/8/RR2K3 w KC - 0 1")
+                .unwrap()
+                .to_string(),
+            "4k3/8/8/8/8/8/8/RR2K3 w - - 0 1"
+        );
+        assert!(Position::from_fen("4k3/8/8/8/8/8/8/RR2K3 w QB - 0 1").is_err());
+
+        // The rook shields the king target from the rook on a1 until it
+        // castles.
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/rR1K4 w B - 0 1").unwrap();
+        assert!(!position.in_check());
+        assert!(position.parse_uci("d1b1", true).is_ok());
+        assert!(!position
+            .generate_moves()
+            .iter()
+            .any(|next_move| next_move.from() == Square::D1 && next_move.to() == Square::B1));
+    }
+
+    #[test]
+    fn diff() {
+        let position = Position::starting();
+        assert!(position.diff(&position).is_empty());
+
+        let before = Position::from_fen(
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 3 10",
+        )
+        .unwrap();
+        // Capture with a knight and castling.
+        let mut after = before.clone();
+        after.make_move(&Move::from_uci("e5f7").unwrap());
+        after.make_move(&Move::from_uci("e8g8").unwrap());
+        let changes: Vec<String> = before
+            .diff(&after)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                "N e5-f7",
+                "k e8-g8",
+                "r h8-f8",
+                "-p f7",
+                "castling: KQkq -> KQ",
+                "halfmove clock: 3 -> 1",
+                "fullmove counter: 10 -> 11",
+            ]
+        );
+
+        // The queen is moved even though the positions are unrelated.
+        let after = Position::from_fen("4k3/8/8/8/8/8/8/4K2Q w - - 0 1").unwrap();
+        let changes = Position::starting().diff(&after);
+        assert!(changes.contains(&SquareChange::Moved {
+            piece: Piece {
+                player: Player::White,
+                kind: PieceKind::Queen
+            },
+            from: Square::D1,
+            to: Square::H1,
+        }));
+        assert!(changes.contains(&SquareChange::Castling(
+            CastleRights::ALL,
+            CastleRights::NONE
+        )));
+    }
+
+    #[test]
+    fn piece_changes() {
+        let board = |position: &Position| {
+            Square::iter()
+                .map(|square| position.at(square))
+                .collect::<Vec<_>>()
+        };
+        // Castling (including Chess960), en passant and capturing promotions.
+        for fen in [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+            "1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w GEge - 0 1",
+        ] {
+            let position = Position::from_fen(fen).unwrap();
+            for next_move in position.generate_moves() {
+                let changes = position.piece_changes(&next_move);
+                let mut expected = board(&position);
+                for &(piece, square) in &changes.removed {
+                    assert_eq!(expected[square as usize], Some(piece), "{fen} {next_move}");
+                    expected[square as usize] = None;
+                }
+                for &(piece, square) in &changes.added {
+                    assert_eq!(expected[square as usize], None, "{fen} {next_move}");
+                    expected[square as usize] = Some(piece);
+                }
+                let mut next_position = position.clone();
+                next_position.make_move(&next_move);
+                assert_eq!(expected, board(&next_position), "{fen} {next_move}");
+            }
+        }
+    }
 }
@@ -0,0 +1,110 @@
+//! Deterministic random positions for benchmarks. The positions are reached by
+//! random legal playouts from the starting position, so the same seed produces
+//! the same positions on every machine and the benchmark inputs do not have to
+//! be shipped as FEN files.
+//!
+//! [`ChaCha8Rng`] is used instead of [`rand::rngs::SmallRng`], which is not
+//! portable across platforms.
+
+use std::ops::RangeInclusive;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::chess::position::Position;
+
+/// Stage of the game the generated positions come from, determined by the
+/// length of the playout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Opening,
+    Middlegame,
+    Endgame,
+}
+
+impl Phase {
+    pub const ALL: [Self; 3] = [Self::Opening, Self::Middlegame, Self::Endgame];
+
+    /// Number of random moves played from the starting position.
+    const fn plies(self) -> RangeInclusive<usize> {
+        match self {
+            Self::Opening => 4..=16,
+            Self::Middlegame => 30..=60,
+            // Random moves rarely capture, it takes a while to trade pieces.
+            Self::Endgame => 120..=200,
+        }
+    }
+}
+
+/// Generates `count` positions with legal moves, split evenly between the
+/// [`Phase`]s.
+#[must_use]
+pub fn generate(count: usize, seed: u64) -> Vec<Position> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    Phase::ALL
+        .iter()
+        .cycle()
+        .take(count)
+        .map(|&phase| generate_phase(phase, &mut rng))
+        .collect()
+}
+
+/// Generates a position in the given [`Phase`]. The playouts ending early
+/// (checkmate, stalemate or the fifty-move rule) are restarted.
+#[must_use]
+pub fn generate_phase(phase: Phase, rng: &mut impl Rng) -> Position {
+    'playout: loop {
+        let plies = rng.gen_range(phase.plies());
+        let mut position = Position::starting();
+        for _ in 0..plies {
+            let moves = position.generate_moves();
+            if moves.is_empty() || position.halfmove_clock_expired() {
+                continue 'playout;
+            }
+            position.make_move(&moves[rng.gen_range(0..moves.len())]);
+        }
+        if !position.generate_moves().is_empty() && !position.halfmove_clock_expired() {
+            return position;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let positions = generate(30, 42);
+        assert_eq!(positions.len(), 30);
+        let fens: Vec<String> = positions.iter().map(ToString::to_string).collect();
+        let again: Vec<String> = generate(30, 42).iter().map(ToString::to_string).collect();
+        assert_eq!(fens, again);
+        assert_ne!(
+            fens,
+            generate(30, 43)
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+        // Pinned to detect accidental changes of the generated inputs.
+        assert_eq!(
+            fens[0],
+            "r1bqkb1r/4pppp/1p1p3n/2P3Q1/pn6/N3P1P1/P1PP1PBP/R1B1K1NR w KQkq - 2 9"
+        );
+    }
+
+    #[test]
+    fn phases() {
+        for (index, position) in generate(60, 0).iter().enumerate() {
+            position.validate().unwrap();
+            assert!(!position.generate_moves().is_empty());
+            let plies = usize::from(position.fullmove_counter() - 1) * 2
+                + usize::from(position.us() == crate::environment::Player::Black);
+            assert!(
+                Phase::ALL[index % 3].plies().contains(&plies),
+                "{index}: {position}"
+            );
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Renders positions as SVG images for reports, blog posts about the engine
+//! games and debugging visualizations. The pieces are Unicode chess glyphs,
+//! so the images do not depend on external assets.
+
+use std::fmt::Write;
+
+use crate::chess::core::{Move, PieceKind, Square, BOARD_WIDTH};
+use crate::chess::position::Position;
+use crate::environment::Player;
+
+/// Size of a single square in pixels.
+const SQUARE_SIZE: u32 = 45;
+/// Space for the file and rank labels around the board.
+const MARGIN: u32 = 20;
+
+const LIGHT_SQUARE: &str = "#f0d9b5";
+const DARK_SQUARE: &str = "#b58863";
+const HIGHLIGHT: &str = "#cdd26a";
+const CHECK: &str = "#e55b5b";
+
+/// Rendering settings for [`render`].
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// Show the board from Black's side.
+    pub flipped: bool,
+    /// Highlight the squares of the move, e.g. the last move played.
+    pub highlight: Option<Move>,
+    /// Draw the file and rank labels around the board.
+    pub coordinates: bool,
+}
+
+/// Renders the position as a standalone SVG image. The king in check is
+/// marked red.
+///
+/// ```
+/// use pabi::chess::position::Position;
+/// use pabi::chess::svg::{self, Options};
+///
+/// let image = svg::render(&Position::starting(), &Options::default());
+/// assert!(image.starts_with("<svg "));
+/// ```
+#[must_use]
+pub fn render(position: &Position, options: &Options) -> String {
+    let margin = if options.coordinates { MARGIN } else { 0 };
+    let size = u32::from(BOARD_WIDTH) * SQUARE_SIZE + 2 * margin;
+    // Top left corner of the square on the image.
+    let corner = |square: Square| {
+        let (file, rank) = (square.file() as u32, square.rank() as u32);
+        let (column, row) = if options.flipped {
+            (7 - file, rank)
+        } else {
+            (file, 7 - rank)
+        };
+        (margin + column * SQUARE_SIZE, margin + row * SQUARE_SIZE)
+    };
+    let highlighted = |square: Square| {
+        options
+            .highlight
+            .is_some_and(|highlight| highlight.from() == square || highlight.to() == square)
+    };
+    let checked_king = position
+        .in_check()
+        .then(|| position.king_square(position.us()));
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">"#
+    )
+    .unwrap();
+    if options.coordinates {
+        writeln!(
+            svg,
+            r##"<rect width="{size}" height="{size}" fill="#312e2b"/>"##
+        )
+        .unwrap();
+    }
+    for square in Square::iter() {
+        let (x, y) = corner(square);
+        let fill = if checked_king == Some(square) {
+            CHECK
+        } else if highlighted(square) {
+            HIGHLIGHT
+        } else if (square.file() as u8 + square.rank() as u8) % 2 == 0 {
+            DARK_SQUARE
+        } else {
+            LIGHT_SQUARE
+        };
+        writeln!(
+            svg,
+            r#"<rect x="{x}" y="{y}" width="{SQUARE_SIZE}" height="{SQUARE_SIZE}" fill="{fill}"/>"#
+        )
+        .unwrap();
+    }
+    for (square, piece) in position.iter_pieces() {
+        let (x, y) = corner(square);
+        // The same glyphs are used for both sides, only the colors differ.
+        let glyph = match piece.kind {
+            PieceKind::King => '\u{265A}',
+            PieceKind::Queen => '\u{265B}',
+            PieceKind::Rook => '\u{265C}',
+            PieceKind::Bishop => '\u{265D}',
+            PieceKind::Knight => '\u{265E}',
+            PieceKind::Pawn => '\u{265F}',
+        };
+        let (fill, stroke) = match piece.player {
+            Player::White => ("#ffffff", "#000000"),
+            Player::Black => ("#000000", "#000000"),
+        };
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}" font-size="{}" text-anchor="middle" dominant-baseline="central" fill="{fill}" stroke="{stroke}" stroke-width="1">{glyph}</text>"#,
+            x + SQUARE_SIZE / 2,
+            y + SQUARE_SIZE / 2,
+            SQUARE_SIZE * 4 / 5,
+        )
+        .unwrap();
+    }
+    if options.coordinates {
+        for index in 0..u32::from(BOARD_WIDTH) {
+            let (file, rank) = if options.flipped {
+                (7 - index, index)
+            } else {
+                (index, 7 - index)
+            };
+            let center = margin + index * SQUARE_SIZE + SQUARE_SIZE / 2;
+            writeln!(
+                svg,
+                r##"<text x="{center}" y="{}" font-size="12" text-anchor="middle" fill="#ffffff">{}</text>"##,
+                size - margin / 3,
+                char::from(b'a' + file as u8),
+            )
+            .unwrap();
+            writeln!(
+                svg,
+                r##"<text x="{}" y="{center}" font-size="12" text-anchor="middle" dominant-baseline="central" fill="#ffffff">{}</text>"##,
+                margin / 2,
+                rank + 1,
+            )
+            .unwrap();
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn squares(svg: &str, fill: &str) -> usize {
+        svg.lines()
+            .filter(|line| line.starts_with("<rect x=") && line.contains(fill))
+            .count()
+    }
+
+    #[test]
+    fn starting_position() {
+        let svg = render(&Position::starting(), &Options::default());
+        assert!(svg.starts_with("<svg "), "{svg}");
+        assert!(svg.ends_with("</svg>\n"), "{svg}");
+        assert_eq!(squares(&svg, LIGHT_SQUARE), 32);
+        assert_eq!(squares(&svg, DARK_SQUARE), 32);
+        assert_eq!(svg.matches("<text ").count(), 32);
+        assert_eq!(svg.matches('\u{265F}').count(), 16);
+        // White rook on a1 is in the bottom left corner.
+        assert!(svg.contains(&format!(
+            r##"<text x="22" y="337" font-size="36" text-anchor="middle" dominant-baseline="central" fill="{}" stroke="#000000" stroke-width="1">{}</text>"##,
+            "#ffffff", '\u{265C}'
+        )));
+    }
+
+    #[test]
+    fn options() {
+        let position =
+            Position::from_fen("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3")
+                .unwrap();
+        let svg = render(
+            &position,
+            &Options {
+                flipped: true,
+                highlight: Some(Move::from_uci("d8h4").unwrap()),
+                coordinates: true,
+            },
+        );
+        assert_eq!(squares(&svg, HIGHLIGHT), 2);
+        assert_eq!(squares(&svg, CHECK), 1);
+        // Labels for each file and rank.
+        assert_eq!(svg.matches(r#"font-size="12""#).count(), 16);
+        // White king on e1 is at the top when the board is flipped.
+        assert!(svg.contains(&format!(
+            r#"<rect x="{}" y="{MARGIN}" width="45" height="45" fill="{CHECK}"/>"#,
+            MARGIN + 3 * SQUARE_SIZE
+        )));
+    }
+}
@@ -0,0 +1,301 @@
+//! Probing [Syzygy tablebases] for the exact results of the positions with few
+//! pieces.
+//!
+//! [Syzygy tablebases]: https://www.chessprogramming.org/Syzygy_Bases
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::Context;
+use shakmaty::Chess;
+use shakmaty_syzygy::AmbiguousWdl;
+
+use super::core::MoveList;
+use super::position::Position;
+use super::zobrist;
+use crate::environment::Player;
+
+/// Number of entries in [`Tablebase`] probe cache. The tablebases are probed
+/// for the root and its children, so the cache only needs to hold a few
+/// positions: it saves decompressing the same blocks again when the position
+/// is searched repeatedly (e.g. pondering or analysis).
+const CACHE_SIZE: usize = 1 << 12;
+
+/// Game-theoretic result of the position from the perspective of the player to
+/// move, assuming perfect play.
+///
+/// Wins and losses that can not be achieved because of the fifty-move rule
+/// ("cursed" wins and "blessed" losses) are draws.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Wdl {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl std::ops::Neg for Wdl {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match self {
+            Self::Loss => Self::Win,
+            Self::Draw => Self::Draw,
+            Self::Win => Self::Loss,
+        }
+    }
+}
+
+impl From<AmbiguousWdl> for Wdl {
+    fn from(wdl: AmbiguousWdl) -> Self {
+        match wdl {
+            AmbiguousWdl::Win | AmbiguousWdl::MaybeWin => Self::Win,
+            AmbiguousWdl::Draw | AmbiguousWdl::BlessedLoss | AmbiguousWdl::CursedWin => Self::Draw,
+            AmbiguousWdl::Loss | AmbiguousWdl::MaybeLoss => Self::Loss,
+        }
+    }
+}
+
+/// Cached probe result. The side to move is stored separately because it is
+/// not always reflected in the incrementally updated hash.
+#[derive(Clone, Copy)]
+struct CacheEntry {
+    key: zobrist::Key,
+    side_to_move: Player,
+    wdl: Wdl,
+}
+
+/// Collection of the tablebase files loaded from a directory. The files are
+/// memory-mapped, the recent probe results are cached.
+pub struct Tablebase {
+    tables: shakmaty_syzygy::Tablebase<Chess>,
+    cache: Mutex<Vec<Option<CacheEntry>>>,
+}
+
+impl std::fmt::Debug for Tablebase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tablebase")
+            .field("max_pieces", &self.max_pieces())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Tablebase {
+    /// Loads all tablebase files from the directory.
+    ///
+    /// # Errors
+    ///
+    /// If the directory can not be read.
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        // SAFETY: The tablebase files are not expected to be modified while
+        // the engine is running.
+        let mut tables = unsafe { shakmaty_syzygy::Tablebase::with_mmap_filesystem() };
+        let _ = tables
+            .add_directory(path)
+            .with_context(|| format!("reading tablebases from {}", path.display()))?;
+        Ok(Self {
+            tables,
+            cache: Mutex::new(vec![None; CACHE_SIZE]),
+        })
+    }
+
+    /// Loads the tablebases from the first of [`default_locations`] that
+    /// contains any. Returns the directory and the tablebases.
+    #[must_use]
+    pub fn detect() -> Option<(PathBuf, Self)> {
+        detect_in(default_locations())
+    }
+
+    /// Maximum number of pieces (including kings) in the loaded tables.
+    #[must_use]
+    pub fn max_pieces(&self) -> usize {
+        self.tables.max_pieces()
+    }
+
+    /// Returns true if the position has few enough pieces to be probed.
+    #[must_use]
+    pub fn contains(&self, position: &Position) -> bool {
+        position.num_pieces() <= self.max_pieces()
+    }
+
+    /// Returns the result of the position or [`None`] if it is not in the
+    /// tablebase (e.g. too many pieces, castling rights or missing tables).
+    #[must_use]
+    pub fn probe_wdl(&self, position: &Position) -> Option<Wdl> {
+        if !self.contains(position) {
+            return None;
+        }
+        let key = position.hash();
+        let slot = key as usize % CACHE_SIZE;
+        let cached = self.cache.lock().expect("cache is not poisoned")[slot];
+        if let Some(entry) = cached {
+            if entry.key == key && entry.side_to_move == position.us() {
+                return Some(entry.wdl);
+            }
+        }
+        let wdl = self
+            .tables
+            .probe_wdl(&to_shakmaty_position(position)?)
+            .ok()
+            .map(Wdl::from)?;
+        self.cache.lock().expect("cache is not poisoned")[slot] = Some(CacheEntry {
+            key,
+            side_to_move: position.us(),
+            wdl,
+        });
+        Some(wdl)
+    }
+
+    /// Returns the result of the position and the legal moves that preserve
+    /// it. [`None`] if the position is not in the tablebase or has no legal
+    /// moves.
+    #[must_use]
+    pub fn root_moves(&self, position: &Position) -> Option<(Wdl, MoveList)> {
+        if !self.contains(position) {
+            return None;
+        }
+        let mut results = Vec::new();
+        for next_move in position.generate_moves() {
+            let mut child = position.clone();
+            child.make_move(&next_move);
+            let wdl = if child.generate_moves().is_empty() {
+                if child.in_check() {
+                    Wdl::Loss
+                } else {
+                    Wdl::Draw
+                }
+            } else {
+                self.probe_wdl(&child)?
+            };
+            results.push((next_move, -wdl));
+        }
+        let best = results.iter().map(|(_, wdl)| *wdl).max()?;
+        let moves = results
+            .into_iter()
+            .filter(|(_, wdl)| *wdl == best)
+            .map(|(next_move, _)| next_move)
+            .collect();
+        Some((best, moves))
+    }
+}
+
+/// Directories where the tablebases are commonly installed, in the order of
+/// preference: `$SYZYGY_PATH` (which can list several directories like
+/// `$PATH`), then the user and system data directories.
+#[must_use]
+pub fn default_locations() -> Vec<PathBuf> {
+    let mut locations: Vec<PathBuf> = env::var_os("SYZYGY_PATH")
+        .map(|paths| env::split_paths(&paths).collect())
+        .unwrap_or_default();
+    if let Some(home) = env::var_os("HOME") {
+        let home = PathBuf::from(home);
+        locations.push(home.join("syzygy"));
+        locations.push(home.join(".local/share/syzygy"));
+        locations.push(home.join("chess/syzygy"));
+    }
+    locations.extend(
+        [
+            "/usr/local/share/syzygy",
+            "/usr/share/syzygy",
+            "/opt/syzygy",
+        ]
+        .iter()
+        .map(PathBuf::from),
+    );
+    locations
+}
+
+fn detect_in(candidates: impl IntoIterator<Item = PathBuf>) -> Option<(PathBuf, Tablebase)> {
+    candidates
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .find_map(|path| match Tablebase::open(&path) {
+            Ok(tablebase) if tablebase.max_pieces() > 0 => Some((path, tablebase)),
+            _ => None,
+        })
+}
+
+// TODO: Converting to FEN and back is ineffective. It's possible to manipulate
+// the bitboard values directly.
+fn to_shakmaty_position(position: &Position) -> Option<Chess> {
+    position
+        .to_string()
+        .parse::<shakmaty::fen::Fen>()
+        .ok()?
+        .into_position(shakmaty::CastlingMode::Standard)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::core::Move;
+
+    const TABLEBASE_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/syzygy");
+
+    fn tablebase() -> Tablebase {
+        Tablebase::open(TABLEBASE_PATH.as_ref()).expect("valid tablebase")
+    }
+
+    #[test]
+    fn syzygy_tablebases() {
+        assert_eq!(tablebase().max_pieces(), 3);
+    }
+
+    #[test]
+    fn probe() {
+        let tablebase = tablebase();
+        let position = Position::from_fen("4k3/8/8/5Q2/4K3/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&position), Some(Wdl::Loss));
+        let position = Position::from_fen("4k3/8/8/5N2/4K3/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(tablebase.probe_wdl(&position), Some(Wdl::Draw));
+        assert_eq!(tablebase.probe_wdl(&Position::starting()), None);
+    }
+
+    #[test]
+    fn cache() {
+        let tablebase = tablebase();
+        let mut position = Position::from_fen("4k3/8/8/5Q2/4K3/8/8/8 w - - 0 1").unwrap();
+        for _ in 0..2 {
+            assert_eq!(tablebase.probe_wdl(&position), Some(Wdl::Win));
+        }
+        // Triangulation: the same placement with the other player to move.
+        for uci in ["e4d4", "e8d8", "d4e3", "d8e8", "e3e4"] {
+            position.make_move(&Move::from_uci(uci).unwrap());
+        }
+        assert_eq!(position.to_string(), "4k3/8/8/5Q2/4K3/8/8/8 b - - 5 3");
+        assert_eq!(tablebase.probe_wdl(&position), Some(Wdl::Loss));
+    }
+
+    #[test]
+    fn detect() {
+        let (path, tablebase) = detect_in([
+            PathBuf::from("/nonexistent"),
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")),
+            PathBuf::from(TABLEBASE_PATH),
+        ])
+        .expect("tablebases are in the repository");
+        assert_eq!(path, PathBuf::from(TABLEBASE_PATH));
+        assert_eq!(tablebase.max_pieces(), 3);
+        assert!(detect_in([PathBuf::from("/nonexistent")]).is_none());
+    }
+
+    #[test]
+    fn root_moves() {
+        let tablebase = tablebase();
+        // KQvK: the stalemate is excluded.
+        let position = Position::from_fen("k7/8/2K5/8/8/8/8/1Q6 w - - 0 1").unwrap();
+        let (wdl, moves) = tablebase.root_moves(&position).unwrap();
+        assert_eq!(wdl, Wdl::Win);
+        assert!(moves.contains(&Move::from_uci("b1b7").unwrap()));
+        assert!(!moves.contains(&Move::from_uci("b1b6").unwrap()));
+
+        // KNvK can only be drawn: all moves are kept.
+        let position = Position::from_fen("4k3/8/8/5N2/4K3/8/8/8 w - - 0 1").unwrap();
+        let (wdl, moves) = tablebase.root_moves(&position).unwrap();
+        assert_eq!(wdl, Wdl::Draw);
+        assert_eq!(moves.len(), position.generate_moves().len());
+
+        assert!(tablebase.root_moves(&Position::starting()).is_none());
+    }
+}
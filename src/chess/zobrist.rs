@@ -24,14 +24,15 @@ impl RepetitionTable {
         self.table.clear();
     }
 
-    /// Returns true if the position has occurred 3 times.
+    /// Records the position and returns the number of times it has occurred,
+    /// including this one.
     ///
     /// In the tournament setting 3-fold repetition is a draw.
     #[must_use]
-    pub(crate) fn record(&mut self, key: Key) -> bool {
+    pub(crate) fn record(&mut self, key: Key) -> u8 {
         let count = self.table.entry(key).or_insert(0);
-        *count += 1;
-        *count == 3
+        *count = count.saturating_add(1);
+        *count
     }
 }
 
@@ -47,29 +48,29 @@ mod tests {
 
         let mut position = Position::starting();
         let initial_hash = position.hash();
-        assert!(!table.record(initial_hash));
+        assert_eq!(table.record(initial_hash), 1);
 
         position.make_move(&Move::from_uci("g1f3").expect("valid move"));
         assert_ne!(initial_hash, position.hash());
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 1);
         position.make_move(&Move::from_uci("g8f6").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 1);
 
         position.make_move(&Move::from_uci("f3g1").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 1);
         // Two-fold repetition.
         position.make_move(&Move::from_uci("f6g8").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 2);
 
         position.make_move(&Move::from_uci("g1f3").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 2);
         position.make_move(&Move::from_uci("g8f6").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 2);
 
         position.make_move(&Move::from_uci("f3g1").expect("valid move"));
-        assert!(!table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 2);
         // Three-fold repetition.
         position.make_move(&Move::from_uci("f6g8").expect("valid move"));
-        assert!(table.record(position.hash()));
+        assert_eq!(table.record(position.hash()), 3);
     }
 }
@@ -0,0 +1,405 @@
+//! Merges the self-play shards (files in the [`super::record`] format, e.g.
+//! written by separate `datagen` runs) into a single dataset.
+//!
+//! The same games and positions show up in many shards: the workers start from
+//! the same openings and the short games are often replayed move by move.
+//! Duplicates are detected by the Zobrist keys across all shards. The datasets
+//! can be larger than the available memory, so the keys are spilled to bucket
+//! files on disk by their top bits and each bucket is deduplicated separately
+//! (see [`DiskKeySet`]).
+
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+
+use super::record::{GameRecord, RecordReader, RecordResult, RecordWriter};
+use crate::chess::zobrist;
+
+/// Number of bucket files of a [`DiskKeySet`]. Each bucket is loaded into
+/// memory separately, so a set with `n` keys needs roughly `16 * n / BUCKETS`
+/// bytes.
+const BUCKETS: usize = 256;
+
+/// Set of keys stored on disk. The keys are only appended during the
+/// insertion, the duplicates are found at the end.
+///
+/// The files are removed when the set is dropped.
+pub struct DiskKeySet {
+    dir: PathBuf,
+    buckets: Vec<BufWriter<File>>,
+    len: u64,
+}
+
+impl DiskKeySet {
+    /// Creates the bucket files in `dir`.
+    ///
+    /// # Errors
+    ///
+    /// If the files can not be created.
+    pub fn new(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let buckets = (0..BUCKETS)
+            .map(|bucket| Ok(BufWriter::new(File::create(bucket_path(dir, bucket))?)))
+            .collect::<io::Result<_>>()?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            buckets,
+            len: 0,
+        })
+    }
+
+    /// Appends the key. Its index is the number of keys inserted before.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails.
+    pub fn insert(&mut self, key: zobrist::Key) -> io::Result<()> {
+        let bucket = &mut self.buckets[(key >> (64 - BUCKETS.ilog2())) as usize];
+        bucket.write_all(&key.to_le_bytes())?;
+        bucket.write_all(&self.len.to_le_bytes())?;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Number of inserted keys, including the duplicates.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the indices of the keys that were already inserted before
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// If reading the bucket files fails.
+    pub fn duplicates(mut self) -> io::Result<Duplicates> {
+        let mut duplicates = Duplicates {
+            bits: vec![0; self.len.div_ceil(64) as usize],
+            len: 0,
+        };
+        self.for_each_duplicate(|index| duplicates.insert(index))?;
+        Ok(duplicates)
+    }
+
+    /// Returns the number of distinct keys.
+    ///
+    /// # Errors
+    ///
+    /// If reading the bucket files fails.
+    pub fn count_unique(mut self) -> io::Result<u64> {
+        let mut duplicates = 0;
+        self.for_each_duplicate(|_| duplicates += 1)?;
+        Ok(self.len - duplicates)
+    }
+
+    fn for_each_duplicate(&mut self, mut f: impl FnMut(u64)) -> io::Result<()> {
+        for bucket in 0..BUCKETS {
+            self.buckets[bucket].flush()?;
+            let mut bytes = Vec::new();
+            let _ = File::open(bucket_path(&self.dir, bucket))?.read_to_end(&mut bytes)?;
+            let mut entries: Vec<(u64, u64)> = bytes
+                .chunks_exact(16)
+                .map(|entry| {
+                    let (key, index) = entry.split_at(8);
+                    (
+                        u64::from_le_bytes(key.try_into().expect("8 bytes")),
+                        u64::from_le_bytes(index.try_into().expect("8 bytes")),
+                    )
+                })
+                .collect();
+            // The first occurrence of each key is the one with the lowest index.
+            entries.sort_unstable();
+            for pair in entries.windows(2) {
+                if pair[0].0 == pair[1].0 {
+                    f(pair[1].1);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for DiskKeySet {
+    fn drop(&mut self) {
+        self.buckets.clear();
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn bucket_path(dir: &Path, bucket: usize) -> PathBuf {
+    dir.join(format!("{bucket:03}.keys"))
+}
+
+/// Indices of the duplicate keys found by [`DiskKeySet::duplicates`].
+#[derive(Debug)]
+pub struct Duplicates {
+    bits: Vec<u64>,
+    len: u64,
+}
+
+impl Duplicates {
+    fn insert(&mut self, index: u64) {
+        self.bits[(index / 64) as usize] |= 1 << (index % 64);
+        self.len += 1;
+    }
+
+    #[must_use]
+    pub fn contains(&self, index: u64) -> bool {
+        self.bits
+            .get((index / 64) as usize)
+            .is_some_and(|bits| bits & (1 << (index % 64)) != 0)
+    }
+
+    /// Number of duplicates.
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Statistics of the merged dataset.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MergeStats {
+    pub shards: usize,
+    /// Number of games in all shards.
+    pub games: u64,
+    /// Games dropped because the same game was already merged.
+    pub duplicate_games: u64,
+    /// Number of positions (plies) in the merged games.
+    pub positions: u64,
+    /// Number of distinct positions in the merged games, counting the
+    /// color-flipped and mirrored positions as the same (see
+    /// [`crate::chess::position::Position::canonical`]).
+    pub unique_positions: u64,
+    /// Plies with the root visits (the policy training targets).
+    pub policy_targets: u64,
+    /// Merged games by [`RecordResult`]: white wins, black wins, draws and
+    /// unfinished games.
+    pub results: [u64; 4],
+}
+
+impl fmt::Display for MergeStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let merged = self.games - self.duplicate_games;
+        writeln!(
+            f,
+            "Merged {merged} games from {} shards ({} duplicates dropped)",
+            self.shards, self.duplicate_games
+        )?;
+        writeln!(
+            f,
+            "Results: {} white wins, {} black wins, {} draws, {} unfinished",
+            self.results[RecordResult::WhiteWins as usize],
+            self.results[RecordResult::BlackWins as usize],
+            self.results[RecordResult::Draw as usize],
+            self.results[RecordResult::Unknown as usize],
+        )?;
+        write!(
+            f,
+            "Positions: {} ({} unique, {} with policy targets), {:.1} plies per game",
+            self.positions,
+            self.unique_positions,
+            self.policy_targets,
+            self.positions as f64 / merged.max(1) as f64
+        )
+    }
+}
+
+/// Keys of all positions of the game folded into one: games with the same
+/// root and moves have the same key.
+fn game_key(record: &GameRecord) -> zobrist::Key {
+    let mut position = record.root.clone();
+    let mut key = position.hash();
+    for next_move in &record.moves {
+        position.make_move(next_move);
+        key = key.rotate_left(1) ^ position.hash();
+    }
+    key
+}
+
+fn read_shard(path: &Path) -> anyhow::Result<RecordReader<BufReader<File>>> {
+    let file = File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    RecordReader::new(BufReader::new(file)).with_context(|| format!("reading {}", path.display()))
+}
+
+/// Writes the games from all `shards` to `output`, keeping the first
+/// occurrence of each game. The keys are spilled to `temp_dir`.
+///
+/// # Errors
+///
+/// If any of the shards is invalid or reading/writing fails.
+pub fn merge(
+    shards: &[PathBuf],
+    output: impl Write,
+    temp_dir: &Path,
+) -> anyhow::Result<MergeStats> {
+    let temp_dir = temp_dir.join(format!("pabi-merge-{}", std::process::id()));
+    let mut stats = MergeStats {
+        shards: shards.len(),
+        ..MergeStats::default()
+    };
+
+    let mut games = DiskKeySet::new(&temp_dir.join("games"))?;
+    for shard in shards {
+        for record in read_shard(shard)? {
+            let record = record.with_context(|| format!("reading {}", shard.display()))?;
+            games.insert(game_key(&record))?;
+        }
+    }
+    stats.games = games.len();
+    let duplicates = games.duplicates()?;
+    stats.duplicate_games = duplicates.len();
+
+    let mut positions = DiskKeySet::new(&temp_dir.join("positions"))?;
+    let mut writer = RecordWriter::new(output)?;
+    let mut index = 0;
+    for shard in shards {
+        for record in read_shard(shard)? {
+            let record = record?;
+            index += 1;
+            if duplicates.contains(index - 1) {
+                continue;
+            }
+            let mut position = record.root.clone();
+            for next_move in &record.moves {
+                positions.insert(position.canonical_hash())?;
+                position.make_move(next_move);
+            }
+            stats.results[record.result as usize] += 1;
+            stats.policy_targets += record.visits.as_ref().map_or(0, |visits| {
+                visits.iter().filter(|ply| !ply.is_empty()).count() as u64
+            });
+            writer.write(&record)?;
+        }
+    }
+    let _ = writer.finish()?;
+    stats.positions = positions.len();
+    stats.unique_positions = positions.count_unique()?;
+    let _ = fs::remove_dir_all(&temp_dir);
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::core::Move;
+    use crate::chess::position::Position;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("pabi-{name}-{}", std::process::id()))
+    }
+
+    fn game(moves: &[&str], result: RecordResult) -> GameRecord {
+        GameRecord {
+            root: Position::starting(),
+            moves: moves
+                .iter()
+                .map(|next_move| Move::from_uci(next_move).unwrap())
+                .collect(),
+            result,
+            visits: None,
+            temperature: None,
+        }
+    }
+
+    fn write_shard(path: &Path, games: &[GameRecord]) {
+        let mut writer = RecordWriter::new(File::create(path).unwrap()).unwrap();
+        for game in games {
+            writer.write(game).unwrap();
+        }
+        let _ = writer.finish().unwrap();
+    }
+
+    #[test]
+    fn disk_key_set() {
+        let dir = temp_dir("keys");
+        let mut set = DiskKeySet::new(&dir).unwrap();
+        assert!(set.is_empty());
+        for key in [1, u64::MAX, 1, 42, u64::MAX, 1] {
+            set.insert(key).unwrap();
+        }
+        assert_eq!(set.len(), 6);
+        let duplicates = set.duplicates().unwrap();
+        assert_eq!(duplicates.len(), 3);
+        let indices: Vec<u64> = (0..7).filter(|&index| duplicates.contains(index)).collect();
+        assert_eq!(indices, [2, 4, 5]);
+        assert!(!dir.exists());
+
+        let mut set = DiskKeySet::new(&dir).unwrap();
+        // Spread the keys across the buckets.
+        for key in 0..1000_u64 {
+            set.insert((key % 300).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+                .unwrap();
+        }
+        assert_eq!(set.count_unique().unwrap(), 300);
+    }
+
+    #[test]
+    fn merge_shards() {
+        let dir = temp_dir("shards");
+        fs::create_dir_all(&dir).unwrap();
+        let shards = [dir.join("0.bin"), dir.join("1.bin")];
+        let scholars_mate = game(
+            &["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"],
+            RecordResult::WhiteWins,
+        );
+        write_shard(
+            &shards[0],
+            &[
+                scholars_mate.clone(),
+                game(&["g1f3", "g8f6"], RecordResult::Unknown),
+            ],
+        );
+        let mut with_visits = game(&["g1f3", "g8f6", "f3g1"], RecordResult::Draw);
+        let knight = Move::from_uci("g1f3").unwrap();
+        with_visits.visits = Some(vec![vec![(knight, 10)], vec![], vec![(knight, 3)]]);
+        // Same moves as the first game with a different result.
+        let mut duplicate = scholars_mate.clone();
+        duplicate.result = RecordResult::Unknown;
+        write_shard(&shards[1], &[duplicate, with_visits]);
+
+        let mut output = Vec::new();
+        let stats = merge(&shards, &mut output, &dir).unwrap();
+        assert_eq!(
+            stats,
+            MergeStats {
+                shards: 2,
+                games: 4,
+                duplicate_games: 1,
+                positions: 12,
+                // The starting position and the one after Nf3 repeat.
+                unique_positions: 9,
+                policy_targets: 2,
+                results: [1, 0, 1, 1],
+            }
+        );
+        assert!(stats.to_string().contains("Merged 3 games from 2 shards"));
+
+        let merged: Vec<GameRecord> = RecordReader::new(output.as_slice())
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].moves, scholars_mate.moves);
+        assert_eq!(merged[0].result, RecordResult::WhiteWins);
+        assert_eq!(merged[2].visits.as_ref().unwrap().len(), 3);
+        assert!(!dir
+            .join(format!("pabi-merge-{}", std::process::id()))
+            .exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
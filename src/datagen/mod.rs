@@ -1 +1,821 @@
+//! Self-play games for generating the training data.
+//!
+//! Most of the self-play time would be spent on the games that are already
+//! decided or dead drawn, so the games are [adjudicated] early based on the
+//! search evaluation. Wrong resignations corrupt the training targets, so a
+//! fraction of games is played until the end with resignation disabled: they
+//! measure how often resigning would have been a mistake and help calibrate the
+//! threshold.
+//!
+//! With [playout cap randomization] only a fraction of the moves is searched
+//! with the full budget and recorded as the policy training targets. The other
+//! moves are played quickly with a small budget: they still contribute to the
+//! game outcome (the value target) at a fraction of the cost.
+//!
+//! The openings are varied by sampling the moves from the root visits with the
+//! [`TemperatureSchedule`]: the first plies are played with a high temperature
+//! and the rest of the game with the most visited moves.
+//!
+//! Once the game reaches a position from the Syzygy tablebases (if provided),
+//! it ends with the exact result instead of being played out: the value
+//! target is then precise rather than dependent on the (possibly imperfect)
+//! endgame play.
+//!
+//! [adjudicated]: https://www.chessprogramming.org/Adjudication
+//! [playout cap randomization]: https://arxiv.org/abs/1902.10565
 
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+
+use anyhow::Context;
+use rand::distributions::WeightedIndex;
+use rand::prelude::Distribution;
+use rand::Rng;
+
+use crate::chess::core::Move;
+use crate::chess::game::{Game, Outcome};
+use crate::chess::position::Position;
+use crate::chess::tablebase::Tablebase;
+use crate::chess::zobrist;
+use crate::environment::Player;
+use crate::evaluation::Evaluator;
+use crate::search::{mcts, Limits, RootMove};
+
+pub mod merge;
+pub mod record;
+pub mod stats;
+
+/// Rules for ending self-play games early. Values are in `[-1, 1]` range from
+/// the perspective of the player to move, see
+/// [`crate::evaluation::Prediction::value`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Adjudication {
+    /// The player resigns when the value of their position stays at or below
+    /// this threshold. [`None`] disables resignation.
+    pub resign_threshold: Option<f32>,
+    /// Number of consecutive own moves below the threshold before resigning.
+    pub resign_moves: usize,
+    /// Fraction of games played with resignation disabled, in `[0, 1]`.
+    pub resign_disabled_fraction: f64,
+    /// The position is considered drawn when the absolute value stays at or
+    /// below this threshold. [`None`] disables draw adjudication.
+    pub draw_threshold: Option<f32>,
+    /// Number of consecutive plies within the threshold before the draw is
+    /// agreed.
+    pub draw_moves: usize,
+    /// Draws are not adjudicated before this ply, the openings are often
+    /// balanced.
+    pub draw_min_ply: usize,
+}
+
+impl Default for Adjudication {
+    fn default() -> Self {
+        Self {
+            resign_threshold: Some(-0.95),
+            resign_moves: 4,
+            resign_disabled_fraction: 0.1,
+            draw_threshold: Some(0.05),
+            draw_moves: 12,
+            draw_min_ply: 80,
+        }
+    }
+}
+
+/// Decision of the [`Adjudicator`] for the player to move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Continue,
+    Resign,
+    /// Offer a draw or accept the opponent's offer.
+    Draw,
+}
+
+/// Tracks the evaluations throughout a single game and decides when it should
+/// be ended.
+#[derive(Clone, Debug)]
+pub struct Adjudicator {
+    config: Adjudication,
+    resign_enabled: bool,
+    /// Number of consecutive moves below the resign threshold for each
+    /// player.
+    losing_streak: [usize; 2],
+    drawish_streak: usize,
+    /// The first player that would have resigned in a game with resignation
+    /// disabled.
+    would_resign: Option<Player>,
+}
+
+impl Adjudicator {
+    /// Creates the adjudicator for a new game, resignation is disabled with
+    /// [`Adjudication::resign_disabled_fraction`] probability.
+    #[must_use]
+    pub fn new(config: Adjudication, rng: &mut impl Rng) -> Self {
+        let resign_enabled = config.resign_threshold.is_some()
+            && !rng.gen_bool(config.resign_disabled_fraction.clamp(0.0, 1.0));
+        Self {
+            config,
+            resign_enabled,
+            losing_streak: [0; 2],
+            drawish_streak: 0,
+            would_resign: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn resign_enabled(&self) -> bool {
+        self.resign_enabled
+    }
+
+    /// Returns the player that would have resigned if resignation was enabled.
+    #[must_use]
+    pub const fn would_resign(&self) -> Option<Player> {
+        self.would_resign
+    }
+
+    /// Records the value of the position searched by `player` at given ply and
+    /// returns what the player should do.
+    pub fn observe(&mut self, player: Player, ply: usize, value: f32) -> Verdict {
+        let streak = &mut self.losing_streak[player as usize];
+        match self.config.resign_threshold {
+            Some(threshold) if value <= threshold => *streak += 1,
+            _ => *streak = 0,
+        }
+        if *streak >= self.config.resign_moves.max(1) {
+            if self.resign_enabled {
+                return Verdict::Resign;
+            }
+            self.would_resign.get_or_insert(player);
+        }
+
+        match self.config.draw_threshold {
+            Some(threshold) if value.abs() <= threshold => self.drawish_streak += 1,
+            _ => self.drawish_streak = 0,
+        }
+        if self.config.draw_threshold.is_some()
+            && ply >= self.config.draw_min_ply
+            && self.drawish_streak >= self.config.draw_moves.max(1)
+        {
+            return Verdict::Draw;
+        }
+        Verdict::Continue
+    }
+}
+
+/// Searches most moves with a small node budget and only some of them with
+/// the full [`Config::limits`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayoutCap {
+    /// Probability of searching a move with the full budget, in `[0, 1]`.
+    pub full_search_probability: f64,
+    /// Node budget of the fast searches.
+    pub fast_nodes: u64,
+}
+
+impl Default for PlayoutCap {
+    fn default() -> Self {
+        Self {
+            full_search_probability: 0.25,
+            fast_nodes: 100,
+        }
+    }
+}
+
+/// Temperature of choosing the move to play in the self-play games: the move
+/// is sampled with the probability proportional to `visits^(1/temperature)`
+/// of the root moves, 0 always plays the most visited move. A high temperature
+/// during the first plies diversifies the openings, the rest of the game is
+/// played at (nearly) full strength so that the result is not spoiled by
+/// random blunders.
+///
+/// The schedule is written as `initial:plies:final`, e.g. `1:20:0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct TemperatureSchedule {
+    /// Temperature of the first [`TemperatureSchedule::plies`] plies.
+    pub initial: f32,
+    /// Number of plies played with the initial temperature.
+    pub plies: u16,
+    /// Temperature of the remaining plies.
+    pub final_temperature: f32,
+}
+
+impl TemperatureSchedule {
+    /// Temperature at the given ply since the start of the game.
+    #[must_use]
+    pub fn at(&self, ply: usize) -> f32 {
+        if ply < usize::from(self.plies) {
+            self.initial
+        } else {
+            self.final_temperature
+        }
+    }
+
+    /// Samples the move to play from the root visits, [`None`] if the
+    /// temperature is 0 or no moves were visited.
+    fn sample(&self, ply: usize, root_moves: &[RootMove], rng: &mut impl Rng) -> Option<Move> {
+        let temperature = self.at(ply);
+        if temperature <= 0.0 {
+            return None;
+        }
+        let max_visits = root_moves.iter().map(|root_move| root_move.visits).max()?;
+        if max_visits == 0 {
+            return None;
+        }
+        // Normalizing by the maximum keeps the weights in range for low
+        // temperatures.
+        let weights = root_moves.iter().map(|root_move| {
+            (f64::from(root_move.visits) / f64::from(max_visits)).powf(1.0 / f64::from(temperature))
+        });
+        let index = WeightedIndex::new(weights).ok()?.sample(rng);
+        Some(root_moves[index].next_move)
+    }
+}
+
+impl fmt::Display for TemperatureSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}",
+            self.initial, self.plies, self.final_temperature
+        )
+    }
+}
+
+impl FromStr for TemperatureSchedule {
+    type Err = anyhow::Error;
+
+    fn from_str(schedule: &str) -> anyhow::Result<Self> {
+        let context =
+            || format!("temperature schedule should be initial:plies:final, got {schedule:?}");
+        let mut parts = schedule.split(':');
+        let (Some(initial), Some(plies), Some(final_temperature), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            anyhow::bail!(context());
+        };
+        let schedule = Self {
+            initial: initial.parse().with_context(context)?,
+            plies: plies.parse().with_context(context)?,
+            final_temperature: final_temperature.parse().with_context(context)?,
+        };
+        anyhow::ensure!(
+            schedule.initial >= 0.0 && schedule.final_temperature >= 0.0,
+            "temperatures should not be negative, got {schedule}"
+        );
+        Ok(schedule)
+    }
+}
+
+/// Settings of the self-play games.
+#[derive(Clone, Debug, Default)]
+pub struct Config {
+    /// Search limits for each move.
+    pub limits: Limits,
+    pub search: mcts::Config,
+    pub adjudication: Adjudication,
+    /// [`None`] searches all moves with the full budget.
+    pub playout_cap: Option<PlayoutCap>,
+    pub temperature: TemperatureSchedule,
+    /// Tablebases to adjudicate the games with, see
+    /// [`Game::with_shared_tablebase`].
+    pub tablebase: Option<Arc<Tablebase>>,
+}
+
+impl Config {
+    /// Limits and settings of the fast searches: the node budget is capped
+    /// and the root noise is disabled, these moves should be played as
+    /// strongly as the budget allows rather than explore.
+    fn fast_search(&self, cap: &PlayoutCap) -> (Limits, mcts::Config) {
+        let nodes = self
+            .limits
+            .nodes
+            .map_or(cap.fast_nodes, |nodes| nodes.min(cap.fast_nodes));
+        let limits = Limits {
+            nodes: Some(nodes),
+            ..self.limits.clone()
+        };
+        let search = mcts::Config {
+            dirichlet_exploration_weight: 0.0,
+            ..self.search.clone()
+        };
+        (limits, search)
+    }
+}
+
+/// Finished self-play game.
+pub struct SelfPlayGame {
+    pub game: Game,
+    pub outcome: Outcome,
+    /// Schedule the moves were chosen with.
+    pub temperature: TemperatureSchedule,
+    /// Whether the players were allowed to resign.
+    pub resign_enabled: bool,
+    /// See [`Adjudicator::would_resign`].
+    pub would_resign: Option<Player>,
+    /// Root visits of each searched move for every ply of the game. The plies
+    /// searched with the reduced budget of [`Config::playout_cap`] are empty:
+    /// their visits are not good enough to be used as the policy targets.
+    pub visits: Vec<Vec<record::MoveVisits>>,
+    pub stats: stats::GameStats,
+}
+
+impl SelfPlayGame {
+    /// Returns true if the game was played with resignation disabled and the
+    /// player who would have resigned did not lose, i.e. the resignation would
+    /// have been a mistake.
+    #[must_use]
+    pub fn false_resignation(&self) -> bool {
+        self.would_resign
+            .is_some_and(|player| self.outcome.winner != Some(!player))
+    }
+}
+
+/// Returns the position along with its color-flipped and (if possible)
+/// horizontally mirrored versions, which have the same value for the player to
+/// move and can be used to multiply the training data.
+#[must_use]
+pub fn augment(position: &Position) -> Vec<Position> {
+    let mut positions = vec![position.clone(), position.flip_colors()];
+    if let Some(mirrored) = position.mirror_horizontally() {
+        positions.push(mirrored.flip_colors());
+        positions.push(mirrored);
+    }
+    positions
+}
+
+/// Filters out the positions that were already seen, including the ones that
+/// only differ by color flip or horizontal mirroring (see
+/// [`Position::canonical`]). Without it, transpositions and symmetric
+/// positions (especially in the openings) inflate the training set.
+#[derive(Debug, Default)]
+pub struct Deduplicator {
+    seen: HashSet<zobrist::Key>,
+}
+
+impl Deduplicator {
+    /// Returns true if neither the position nor its equivalent was seen
+    /// before.
+    pub fn insert(&mut self, position: &Position) -> bool {
+        self.seen.insert(position.canonical_hash())
+    }
+
+    /// Number of unique positions seen so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    /// Returns true if no positions were seen yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+/// Plays a game from the given position with the same evaluator for both
+/// sides.
+///
+/// # Errors
+///
+/// If the search fails.
+pub fn play_game(
+    root: Position,
+    config: &Config,
+    evaluator: &dyn Evaluator,
+    rng: &mut impl Rng,
+) -> anyhow::Result<SelfPlayGame> {
+    let mut game = Game::new(root);
+    if let Some(tablebase) = &config.tablebase {
+        game = game.with_shared_tablebase(Arc::clone(tablebase));
+    }
+    let mut adjudicator = Adjudicator::new(config.adjudication.clone(), rng);
+    let stop = AtomicBool::new(false);
+    let mut visits = Vec::new();
+    let mut plies = Vec::new();
+    let fast_search = config.playout_cap.as_ref().map(|cap| {
+        (
+            cap.full_search_probability.clamp(0.0, 1.0),
+            config.fast_search(cap),
+        )
+    });
+
+    let outcome = loop {
+        if let Some(outcome) = game.outcome() {
+            break outcome;
+        }
+        let player = game.position().us();
+        let (limits, search, full) = match &fast_search {
+            Some((probability, (limits, search))) if !rng.gen_bool(*probability) => {
+                (limits, search, false)
+            },
+            _ => (&config.limits, &config.search, true),
+        };
+        let result = mcts::search(game.position(), limits, search, evaluator, &stop)?;
+        plies.push(stats::PlyStats::from(&result));
+        match adjudicator.observe(player, game.history().len(), result.score.value()) {
+            Verdict::Resign => {
+                game.resign(player)?;
+                continue;
+            },
+            Verdict::Draw if game.draw_offer() == Some(!player) => {
+                game.accept_draw(player)?;
+                continue;
+            },
+            Verdict::Draw => game.offer_draw(player)?,
+            Verdict::Continue => (),
+        }
+        let best_move =
+            match config
+                .temperature
+                .sample(game.history().len(), &result.root_moves, rng)
+            {
+                Some(sampled) => sampled,
+                None => result
+                    .best_move
+                    .expect("the search returns a move in non-terminal positions"),
+            };
+        game.make_move(&best_move, None)?;
+        visits.push(if full {
+            result
+                .root_moves
+                .iter()
+                .map(|root_move| (root_move.next_move, root_move.visits))
+                .collect()
+        } else {
+            Vec::new()
+        });
+    };
+
+    Ok(SelfPlayGame {
+        game,
+        outcome,
+        temperature: config.temperature,
+        resign_enabled: adjudicator.resign_enabled(),
+        would_resign: adjudicator.would_resign(),
+        visits,
+        stats: stats::GameStats::new(&plies),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::chess::game::Termination;
+    use crate::evaluation::Pesto;
+
+    fn adjudicator(config: Adjudication) -> Adjudicator {
+        Adjudicator::new(config, &mut SmallRng::seed_from_u64(0))
+    }
+
+    #[test]
+    fn resignation() {
+        let mut adjudicator = adjudicator(Adjudication {
+            resign_moves: 2,
+            resign_disabled_fraction: 0.0,
+            ..Adjudication::default()
+        });
+        assert!(adjudicator.resign_enabled());
+        assert_eq!(
+            adjudicator.observe(Player::White, 0, -0.99),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::Black, 1, 0.99),
+            Verdict::Continue
+        );
+        // The streak is broken.
+        assert_eq!(
+            adjudicator.observe(Player::White, 2, -0.5),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::Black, 3, 0.99),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::White, 4, -0.99),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::Black, 5, 0.99),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::White, 6, -0.99),
+            Verdict::Resign
+        );
+    }
+
+    #[test]
+    fn resignation_disabled() {
+        let mut adjudicator = adjudicator(Adjudication {
+            resign_moves: 1,
+            resign_disabled_fraction: 1.0,
+            ..Adjudication::default()
+        });
+        assert!(!adjudicator.resign_enabled());
+        assert_eq!(
+            adjudicator.observe(Player::Black, 0, -1.0),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::White, 1, -1.0),
+            Verdict::Continue
+        );
+        assert_eq!(adjudicator.would_resign(), Some(Player::Black));
+
+        let disabled_games = (0..1000)
+            .filter(|seed| {
+                !Adjudicator::new(Adjudication::default(), &mut SmallRng::seed_from_u64(*seed))
+                    .resign_enabled()
+            })
+            .count();
+        assert!((50..150).contains(&disabled_games), "{disabled_games}");
+    }
+
+    #[test]
+    fn draw() {
+        let mut adjudicator = adjudicator(Adjudication {
+            draw_moves: 2,
+            draw_min_ply: 3,
+            ..Adjudication::default()
+        });
+        assert_eq!(
+            adjudicator.observe(Player::White, 0, 0.0),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::Black, 1, 0.01),
+            Verdict::Continue
+        );
+        // Too early.
+        assert_eq!(
+            adjudicator.observe(Player::White, 2, 0.0),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::Black, 3, -0.3),
+            Verdict::Continue
+        );
+        assert_eq!(
+            adjudicator.observe(Player::White, 4, 0.0),
+            Verdict::Continue
+        );
+        assert_eq!(adjudicator.observe(Player::Black, 5, -0.02), Verdict::Draw);
+    }
+
+    #[test]
+    fn augmentation() {
+        assert_eq!(augment(&Position::starting()).len(), 2);
+        let positions = augment(&Position::from_fen("8/8/8/8/8/2k5/1P6/K7 w - - 0 1").unwrap());
+        let fens: Vec<String> = positions.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            fens,
+            [
+                "8/8/8/8/8/2k5/1P6/K7 w - - 0 1",
+                "k7/1p6/2K5/8/8/8/8/8 b - - 0 1",
+                "7k/6p1/5K2/8/8/8/8/8 b - - 0 1",
+                "8/8/8/8/8/5k2/6P1/7K w - - 0 1",
+            ]
+        );
+    }
+
+    #[test]
+    fn deduplication() {
+        let mut deduplicator = Deduplicator::default();
+        assert!(deduplicator.is_empty());
+        let position = Position::from_fen("8/8/8/8/8/2k5/1P6/K7 w - - 0 1").unwrap();
+        for (index, equivalent) in augment(&position).iter().enumerate() {
+            assert_eq!(deduplicator.insert(equivalent), index == 0);
+        }
+        assert_eq!(deduplicator.len(), 1);
+        assert!(deduplicator.insert(&Position::starting()));
+        // Transposition with different move counters.
+        let mut position = Position::starting();
+        for next_move in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            position.make_move(&Move::from_uci(next_move).unwrap());
+        }
+        assert!(!deduplicator.insert(&position));
+        assert_eq!(deduplicator.len(), 2);
+    }
+
+    #[test]
+    fn self_play_resignation() {
+        let config = Config {
+            limits: Limits {
+                nodes: Some(50),
+                ..Limits::default()
+            },
+            adjudication: Adjudication {
+                resign_threshold: Some(-0.9),
+                resign_moves: 1,
+                resign_disabled_fraction: 0.0,
+                ..Adjudication::default()
+            },
+            ..Config::default()
+        };
+        // Black is down a queen and a rook.
+        let root = Position::from_fen("4k3/8/8/8/8/8/8/QR2K3 b - - 0 1").unwrap();
+        let result = play_game(root, &config, &Pesto, &mut SmallRng::seed_from_u64(0)).unwrap();
+        assert_eq!(result.outcome.winner, Some(Player::White));
+        assert_eq!(result.outcome.termination, Termination::Resignation);
+        assert!(result.game.history().is_empty());
+        assert!(!result.false_resignation());
+        assert_eq!(result.stats.searches, 1);
+        assert_eq!(result.stats.blunders, 0);
+    }
+
+    #[test]
+    fn self_play_draw() {
+        let config = Config {
+            limits: Limits {
+                nodes: Some(50),
+                ..Limits::default()
+            },
+            adjudication: Adjudication {
+                draw_moves: 2,
+                draw_min_ply: 0,
+                ..Adjudication::default()
+            },
+            ..Config::default()
+        };
+        // Symmetrical pawn endgame.
+        let root = Position::from_fen("4k3/pp6/8/8/8/8/PP6/4K3 w - - 0 1").unwrap();
+        let result = play_game(root, &config, &Pesto, &mut SmallRng::seed_from_u64(0)).unwrap();
+        assert_eq!(result.outcome.winner, None);
+        assert_eq!(result.outcome.termination, Termination::Agreement);
+        assert_eq!(result.stats.searches, result.game.history().len() + 1);
+        assert!(result.stats.average_depth > 0.0);
+    }
+
+    #[test]
+    fn self_play_tablebase() {
+        let config = Config {
+            limits: Limits {
+                nodes: Some(200),
+                ..Limits::default()
+            },
+            tablebase: Some(Arc::new(
+                Tablebase::open(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/syzygy").as_ref())
+                    .unwrap(),
+            )),
+            ..Config::default()
+        };
+        // KQvK is adjudicated without playing.
+        let root = Position::from_fen("4k3/8/8/8/8/8/8/Q3K3 b - - 0 1").unwrap();
+        let result = play_game(root, &config, &Pesto, &mut SmallRng::seed_from_u64(0)).unwrap();
+        assert_eq!(result.outcome.winner, Some(Player::White));
+        assert_eq!(result.outcome.termination, Termination::Tablebase);
+        assert!(result.game.history().is_empty());
+        assert_eq!(result.stats.searches, 0);
+
+        // The rook is hanging and the game ends as soon as it is captured.
+        let root = Position::from_fen("4k3/8/8/5r2/4KQ2/8/8/8 w - - 0 1").unwrap();
+        let result = play_game(root, &config, &Pesto, &mut SmallRng::seed_from_u64(0)).unwrap();
+        assert_eq!(result.outcome.winner, Some(Player::White));
+        assert_eq!(result.outcome.termination, Termination::Tablebase);
+        assert_eq!(result.game.history().len(), 1);
+    }
+
+    #[test]
+    fn temperature_schedule() {
+        let schedule: TemperatureSchedule = "1:20:0".parse().unwrap();
+        assert_eq!(
+            schedule,
+            TemperatureSchedule {
+                initial: 1.0,
+                plies: 20,
+                final_temperature: 0.0,
+            }
+        );
+        assert_eq!(schedule.to_string(), "1:20:0");
+        assert_eq!(schedule.at(0), 1.0);
+        assert_eq!(schedule.at(19), 1.0);
+        assert_eq!(schedule.at(20), 0.0);
+        assert!("1:20".parse::<TemperatureSchedule>().is_err());
+        assert!("1:20:0:0".parse::<TemperatureSchedule>().is_err());
+        assert!("-1:20:0".parse::<TemperatureSchedule>().is_err());
+        assert!("1:x:0".parse::<TemperatureSchedule>().is_err());
+
+        let limits = Limits {
+            nodes: Some(200),
+            ..Limits::default()
+        };
+        let result = mcts::search(
+            &Position::starting(),
+            &limits,
+            &mcts::Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let mut rng = SmallRng::seed_from_u64(0);
+        assert_eq!(schedule.sample(20, &result.root_moves, &mut rng), None);
+        let sampled: HashSet<String> = (0..100)
+            .filter_map(|_| schedule.sample(0, &result.root_moves, &mut rng))
+            .map(|next_move| next_move.to_string())
+            .collect();
+        assert!(sampled.len() > 1);
+        // Low temperature plays the most visited move.
+        let cold = TemperatureSchedule {
+            initial: 0.01,
+            plies: 1,
+            final_temperature: 0.0,
+        };
+        for _ in 0..10 {
+            assert_eq!(
+                cold.sample(0, &result.root_moves, &mut rng),
+                result.best_move
+            );
+        }
+    }
+
+    #[test]
+    fn temperature_recorded() {
+        let temperature = "2:4:0".parse().unwrap();
+        let config = Config {
+            limits: Limits {
+                nodes: Some(50),
+                ..Limits::default()
+            },
+            temperature,
+            ..Config::default()
+        };
+        let root = Position::from_fen("4k3/8/8/8/8/8/8/QR2K3 w - - 0 1").unwrap();
+        let result = play_game(root, &config, &Pesto, &mut SmallRng::seed_from_u64(0)).unwrap();
+        assert_eq!(result.temperature, temperature);
+        assert_eq!(
+            record::GameRecord::from(&result).temperature,
+            Some(temperature)
+        );
+    }
+
+    #[test]
+    fn playout_cap_randomization() {
+        let config = Config {
+            limits: Limits {
+                nodes: Some(200),
+                ..Limits::default()
+            },
+            playout_cap: Some(PlayoutCap {
+                full_search_probability: 0.25,
+                fast_nodes: 20,
+            }),
+            ..Config::default()
+        };
+        let result = play_game(
+            Position::starting(),
+            &config,
+            &Pesto,
+            &mut SmallRng::seed_from_u64(0),
+        )
+        .unwrap();
+        assert_eq!(result.visits.len(), result.game.history().len());
+        let full = result.visits.iter().filter(|ply| !ply.is_empty()).count();
+        assert!(full > 0, "{full}");
+        assert!(
+            full * 2 < result.visits.len(),
+            "{full}/{}",
+            result.visits.len()
+        );
+        for ply in result.visits.iter().filter(|ply| !ply.is_empty()) {
+            let visits: u32 = ply.iter().map(|(_, visits)| visits).sum();
+            assert!(visits > 20, "{visits}");
+        }
+        // Empty plies are preserved by the binary format.
+        let record = record::GameRecord::from(&result);
+        let mut buffer = Vec::new();
+        let mut writer = record::RecordWriter::new(&mut buffer).unwrap();
+        writer.write(&record).unwrap();
+        let _ = writer.finish().unwrap();
+        let decoded = record::RecordReader::new(buffer.as_slice())
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded.visits, Some(result.visits));
+    }
+
+    #[test]
+    fn fast_search() {
+        let cap = PlayoutCap::default();
+        let config = Config {
+            limits: Limits {
+                nodes: Some(50),
+                ..Limits::default()
+            },
+            ..Config::default()
+        };
+        let (limits, search) = config.fast_search(&cap);
+        // The fast search never exceeds the full budget.
+        assert_eq!(limits.nodes, Some(50));
+        assert_eq!(search.dirichlet_exploration_weight, 0.0);
+        let (limits, _) = Config::default().fast_search(&cap);
+        assert_eq!(limits.nodes, Some(cap.fast_nodes));
+    }
+}
@@ -0,0 +1,595 @@
+//! Compact binary format for storing millions of self-play games.
+//!
+//! A file starts with [`MAGIC`] followed by the games, each encoded as:
+//!
+//! - Flags (`u8`): [`CUSTOM_ROOT`], [`HAS_VISITS`] and [`HAS_TEMPERATURE`].
+//! - Result (`u8`): see [`RecordResult`].
+//! - If [`CUSTOM_ROOT`] is set: length of the starting position FEN (`u8`) and
+//!   the FEN itself. Games from the standard starting position omit it.
+//! - Number of plies (`u16`) and the moves packed into a `u16` each.
+//! - If [`HAS_VISITS`] is set: for each ply, the number of searched root moves
+//!   (`u8`) followed by the move (`u16`) and its visits (`u32`). Plies without
+//!   a policy target (see [`super::PlayoutCap`]) have no root moves.
+//! - If [`HAS_TEMPERATURE`] is set: the [`TemperatureSchedule`] the moves were
+//!   chosen with as the initial temperature (`f32`), the number of plies
+//!   (`u16`) and the final temperature (`f32`).
+//!
+//! All integers are little-endian. Without the visits, each ply takes 2 bytes
+//! compared to roughly 6 bytes in PGN movetext and no tags are stored. The
+//! games can be converted to and from PGN with [`GameRecord::to_pgn`] and
+//! [`GameRecord::from_pgn`], the temperature schedule is kept in the
+//! `Temperature` tag.
+
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+
+use anyhow::{bail, Context};
+
+use super::{SelfPlayGame, TemperatureSchedule};
+use crate::chess::core::Move;
+use crate::chess::position::Position;
+use crate::environment::Player;
+
+/// Identifies the format and its version at the beginning of the file.
+pub const MAGIC: &[u8; 4] = b"PGR\x01";
+/// The game starts from a position other than [`Position::starting`].
+const CUSTOM_ROOT: u8 = 1 << 0;
+/// The root visits are stored for each ply.
+const HAS_VISITS: u8 = 1 << 1;
+/// The temperature schedule of the self-play game is stored.
+const HAS_TEMPERATURE: u8 = 1 << 2;
+
+/// Result of the recorded game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RecordResult {
+    WhiteWins = 0,
+    BlackWins = 1,
+    Draw = 2,
+    /// The game was not finished.
+    Unknown = 3,
+}
+
+impl RecordResult {
+    /// Result given the winner of the game ([`None`] for a draw).
+    #[must_use]
+    pub const fn from_winner(winner: Option<Player>) -> Self {
+        match winner {
+            Some(Player::White) => Self::WhiteWins,
+            Some(Player::Black) => Self::BlackWins,
+            None => Self::Draw,
+        }
+    }
+
+    /// PGN result token.
+    #[must_use]
+    pub const fn as_pgn(self) -> &'static str {
+        match self {
+            Self::WhiteWins => "1-0",
+            Self::BlackWins => "0-1",
+            Self::Draw => "1/2-1/2",
+            Self::Unknown => "*",
+        }
+    }
+
+    fn from_pgn(token: &str) -> Option<Self> {
+        match token {
+            "1-0" => Some(Self::WhiteWins),
+            "0-1" => Some(Self::BlackWins),
+            "1/2-1/2" => Some(Self::Draw),
+            "*" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0 => Some(Self::WhiteWins),
+            1 => Some(Self::BlackWins),
+            2 => Some(Self::Draw),
+            3 => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+
+/// Visits of a single root move in the search that preceded the played move.
+pub type MoveVisits = (Move, u32);
+
+/// Game stored in the binary format.
+#[derive(Clone, Debug)]
+pub struct GameRecord {
+    pub root: Position,
+    pub moves: Vec<Move>,
+    pub result: RecordResult,
+    /// Root visit distribution for each ply, used as the policy training
+    /// target. Must have the same length as [`GameRecord::moves`].
+    pub visits: Option<Vec<Vec<MoveVisits>>>,
+    /// Temperature schedule of the self-play game, needed to reproduce it.
+    pub temperature: Option<TemperatureSchedule>,
+}
+
+impl GameRecord {
+    fn has_custom_root(&self) -> bool {
+        self.root.to_string() != Position::starting().to_string()
+    }
+
+    /// Converts the game to PGN with the `FEN` tag for non-standard starting
+    /// positions. The visits are not preserved.
+    #[must_use]
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+        if self.has_custom_root() {
+            writeln!(pgn, "[SetUp \"1\"]").unwrap();
+            writeln!(pgn, "[FEN \"{}\"]", self.root).unwrap();
+        }
+        if let Some(temperature) = &self.temperature {
+            writeln!(pgn, "[Temperature \"{temperature}\"]").unwrap();
+        }
+        writeln!(pgn, "[Result \"{}\"]", self.result.as_pgn()).unwrap();
+        pgn.push('\n');
+
+        let mut position = self.root.clone();
+        for (ply, next_move) in self.moves.iter().enumerate() {
+            let number = position.fullmove_counter();
+            match position.us() {
+                Player::White => write!(pgn, "{number}. ").unwrap(),
+                Player::Black if ply == 0 => write!(pgn, "{number}... ").unwrap(),
+                Player::Black => (),
+            }
+            write!(pgn, "{} ", position.to_san(next_move)).unwrap();
+            position.make_move(next_move);
+        }
+        pgn.push_str(self.result.as_pgn());
+        pgn
+    }
+
+    /// Parses a single game in PGN. Comments, variations and numeric
+    /// annotation glyphs are skipped.
+    ///
+    /// # Errors
+    ///
+    /// If the starting position or any of the moves is invalid.
+    pub fn from_pgn(pgn: &str) -> anyhow::Result<Self> {
+        let mut root = Position::starting();
+        let mut result = RecordResult::Unknown;
+        let mut temperature = None;
+        let mut movetext = String::new();
+        for line in pgn.lines().map(str::trim) {
+            if let Some(tag) = line.strip_prefix('[').and_then(|tag| tag.strip_suffix(']')) {
+                let Some((name, value)) = tag.split_once(' ') else {
+                    bail!("malformed tag: {line}");
+                };
+                let value = value.trim().trim_matches('"');
+                match name {
+                    "FEN" => root = Position::from_fen(value)?,
+                    "Result" => result = RecordResult::from_pgn(value).unwrap_or(result),
+                    "Temperature" => temperature = Some(value.parse()?),
+                    _ => (),
+                }
+            } else {
+                movetext.push_str(line);
+                movetext.push('\n');
+            }
+        }
+
+        let mut position = root.clone();
+        let mut moves = Vec::new();
+        for token in strip_comments(&movetext).split_whitespace() {
+            if let Some(token_result) = RecordResult::from_pgn(token) {
+                result = token_result;
+                break;
+            }
+            // Move numbers can be attached to the moves: "1.e4" or "1...e5".
+            let san = token.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+            if san.is_empty() || san.starts_with('$') {
+                continue;
+            }
+            let next_move = position.parse_san(san)?;
+            position.make_move(&next_move);
+            moves.push(next_move);
+        }
+        Ok(Self {
+            root,
+            moves,
+            result,
+            visits: None,
+            temperature,
+        })
+    }
+}
+
+impl From<&SelfPlayGame> for GameRecord {
+    fn from(game: &SelfPlayGame) -> Self {
+        Self {
+            root: game.game.root().clone(),
+            moves: game
+                .game
+                .history()
+                .iter()
+                .map(|record| record.played)
+                .collect(),
+            result: RecordResult::from_winner(game.outcome.winner),
+            visits: Some(game.visits.clone()),
+            temperature: Some(game.temperature),
+        }
+    }
+}
+
+/// Removes `{...}` and `;` comments and `(...)` variations from the movetext.
+fn strip_comments(movetext: &str) -> String {
+    let mut result = String::with_capacity(movetext.len());
+    let mut variation_depth = 0_usize;
+    let mut chars = movetext.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                }
+            },
+            ';' => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            },
+            '(' => variation_depth += 1,
+            ')' => variation_depth = variation_depth.saturating_sub(1),
+            _ if variation_depth == 0 => result.push(c),
+            _ => (),
+        }
+        if matches!(c, '{' | '}' | '(' | ')') {
+            result.push(' ');
+        }
+    }
+    result
+}
+
+/// Writes the games in the binary format.
+pub struct RecordWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> RecordWriter<W> {
+    /// Writes the [`MAGIC`] header.
+    ///
+    /// # Errors
+    ///
+    /// If writing fails.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(MAGIC)?;
+        Ok(Self { writer })
+    }
+
+    /// # Errors
+    ///
+    /// If writing fails or the record does not fit the format (e.g. too many
+    /// moves).
+    pub fn write(&mut self, record: &GameRecord) -> anyhow::Result<()> {
+        let custom_root = record.has_custom_root();
+        let mut flags = 0;
+        if custom_root {
+            flags |= CUSTOM_ROOT;
+        }
+        if record.visits.is_some() {
+            flags |= HAS_VISITS;
+        }
+        if record.temperature.is_some() {
+            flags |= HAS_TEMPERATURE;
+        }
+        let mut buffer = vec![flags, record.result as u8];
+        if custom_root {
+            let fen = record.root.to_string();
+            buffer.push(u8::try_from(fen.len()).context("FEN is too long")?);
+            buffer.extend_from_slice(fen.as_bytes());
+        }
+        let plies = u16::try_from(record.moves.len()).context("too many moves")?;
+        buffer.extend_from_slice(&plies.to_le_bytes());
+        for next_move in &record.moves {
+            buffer.extend_from_slice(&next_move.as_packed_int().to_le_bytes());
+        }
+        if let Some(visits) = &record.visits {
+            if visits.len() != record.moves.len() {
+                bail!(
+                    "expected visits for {} plies, got {}",
+                    record.moves.len(),
+                    visits.len()
+                );
+            }
+            for ply in visits {
+                buffer.push(u8::try_from(ply.len()).context("too many root moves")?);
+                for (next_move, count) in ply {
+                    buffer.extend_from_slice(&next_move.as_packed_int().to_le_bytes());
+                    buffer.extend_from_slice(&count.to_le_bytes());
+                }
+            }
+        }
+        if let Some(temperature) = &record.temperature {
+            buffer.extend_from_slice(&temperature.initial.to_le_bytes());
+            buffer.extend_from_slice(&temperature.plies.to_le_bytes());
+            buffer.extend_from_slice(&temperature.final_temperature.to_le_bytes());
+        }
+        self.writer.write_all(&buffer)?;
+        Ok(())
+    }
+
+    /// Flushes and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// If flushing fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads the games written by [`RecordWriter`]. Each game is replayed to check
+/// that the moves are legal.
+pub struct RecordReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> RecordReader<R> {
+    /// Checks the [`MAGIC`] header.
+    ///
+    /// # Errors
+    ///
+    /// If the header is missing or the version is not supported.
+    pub fn new(mut reader: R) -> anyhow::Result<Self> {
+        let mut magic = [0; MAGIC.len()];
+        reader
+            .read_exact(&mut magic)
+            .context("reading game record header")?;
+        if &magic != MAGIC {
+            bail!("not a game record file: header {magic:?}");
+        }
+        Ok(Self { reader })
+    }
+
+    /// Returns the next game or [`None`] at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// If the input is truncated or the game is invalid.
+    pub fn read(&mut self) -> anyhow::Result<Option<GameRecord>> {
+        let mut header = [0; 2];
+        match self.reader.read(&mut header[..1])? {
+            0 => return Ok(None),
+            _ => self.reader.read_exact(&mut header[1..])?,
+        }
+        let [flags, result] = header;
+        let result = RecordResult::from_byte(result)
+            .with_context(|| format!("invalid game result: {result}"))?;
+
+        let root = if flags & CUSTOM_ROOT == 0 {
+            Position::starting()
+        } else {
+            let mut fen = vec![0; usize::from(self.read_u8()?)];
+            self.reader.read_exact(&mut fen)?;
+            Position::from_fen(std::str::from_utf8(&fen)?)?
+        };
+
+        let plies = self.read_u16()?;
+        let mut position = root.clone();
+        let mut moves = Vec::with_capacity(usize::from(plies));
+        for _ in 0..plies {
+            let next_move = self.read_move()?;
+            if !position.generate_moves().contains(&next_move) {
+                bail!("illegal move {next_move} in {position}");
+            }
+            position.make_move(&next_move);
+            moves.push(next_move);
+        }
+
+        let visits = if flags & HAS_VISITS == 0 {
+            None
+        } else {
+            let mut visits = Vec::with_capacity(moves.len());
+            for _ in 0..plies {
+                let count = self.read_u8()?;
+                let mut ply = Vec::with_capacity(usize::from(count));
+                for _ in 0..count {
+                    let next_move = self.read_move()?;
+                    let mut count = [0; 4];
+                    self.reader.read_exact(&mut count)?;
+                    ply.push((next_move, u32::from_le_bytes(count)));
+                }
+                visits.push(ply);
+            }
+            Some(visits)
+        };
+
+        let temperature = if flags & HAS_TEMPERATURE == 0 {
+            None
+        } else {
+            Some(TemperatureSchedule {
+                initial: self.read_f32()?,
+                plies: self.read_u16()?,
+                final_temperature: self.read_f32()?,
+            })
+        };
+
+        Ok(Some(GameRecord {
+            root,
+            moves,
+            result,
+            visits,
+            temperature,
+        }))
+    }
+
+    fn read_u8(&mut self) -> io::Result<u8> {
+        let mut byte = [0; 1];
+        self.reader.read_exact(&mut byte)?;
+        Ok(byte[0])
+    }
+
+    fn read_u16(&mut self) -> io::Result<u16> {
+        let mut bytes = [0; 2];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    fn read_f32(&mut self) -> io::Result<f32> {
+        let mut bytes = [0; 4];
+        self.reader.read_exact(&mut bytes)?;
+        Ok(f32::from_le_bytes(bytes))
+    }
+
+    fn read_move(&mut self) -> anyhow::Result<Move> {
+        let packed = self.read_u16()?;
+        Move::from_packed_int(packed).with_context(|| format!("invalid packed move: {packed:#06x}"))
+    }
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = anyhow::Result<GameRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    impl PartialEq for GameRecord {
+        fn eq(&self, other: &Self) -> bool {
+            self.root.to_string() == other.root.to_string()
+                && self.moves == other.moves
+                && self.result == other.result
+                && self.visits == other.visits
+                && self.temperature == other.temperature
+        }
+    }
+
+    fn moves(uci: &[&str]) -> Vec<Move> {
+        uci.iter().map(|uci| Move::from_uci(uci).unwrap()).collect()
+    }
+
+    fn round_trip(records: &[GameRecord]) -> Vec<GameRecord> {
+        let mut writer = RecordWriter::new(Vec::new()).unwrap();
+        for record in records {
+            writer.write(record).unwrap();
+        }
+        let bytes = writer.finish().unwrap();
+        RecordReader::new(bytes.as_slice())
+            .unwrap()
+            .collect::<anyhow::Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let scholars_mate = GameRecord {
+            root: Position::starting(),
+            moves: moves(&["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"]),
+            result: RecordResult::WhiteWins,
+            visits: None,
+            temperature: None,
+        };
+        let promotion = GameRecord {
+            root: Position::from_fen("8/1P6/8/8/8/8/k7/4K3 w - - 0 1").unwrap(),
+            moves: moves(&["b7b8n", "a2b3"]),
+            result: RecordResult::Unknown,
+            visits: Some(vec![
+                vec![(moves(&["b7b8n"])[0], 10), (moves(&["b7b8q"])[0], 90)],
+                vec![(moves(&["a2b3"])[0], 1)],
+            ]),
+            temperature: Some(TemperatureSchedule {
+                initial: 1.0,
+                plies: 20,
+                final_temperature: 0.1,
+            }),
+        };
+        let records = vec![scholars_mate, promotion];
+        assert_eq!(round_trip(&records), records);
+
+        let mut writer = RecordWriter::new(Vec::new()).unwrap();
+        writer.write(&records[0]).unwrap();
+        // Magic, flags, result, number of plies and 7 moves.
+        assert_eq!(writer.finish().unwrap().len(), 4 + 2 + 2 + 7 * 2);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(RecordReader::new(&b"PGN"[..]).is_err());
+        assert!(RecordReader::new(&b"[Event \"?\"]"[..]).is_err());
+
+        // Truncated game.
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[0, 0, 1, 0]);
+        let mut reader = RecordReader::new(bytes.as_slice()).unwrap();
+        assert!(reader.read().is_err());
+
+        // Illegal move.
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[0, 0, 1, 0]);
+        bytes.extend_from_slice(&moves(&["e2e5"])[0].as_packed_int().to_le_bytes());
+        let mut reader = RecordReader::new(bytes.as_slice()).unwrap();
+        assert!(reader.read().is_err());
+
+        let record = GameRecord {
+            root: Position::starting(),
+            moves: moves(&["e2e4"]),
+            result: RecordResult::Draw,
+            visits: Some(Vec::new()),
+            temperature: None,
+        };
+        assert!(RecordWriter::new(Vec::new())
+            .unwrap()
+            .write(&record)
+            .is_err());
+    }
+
+    #[test]
+    fn pgn() {
+        let record = GameRecord {
+            root: Position::starting(),
+            moves: moves(&["e2e4", "e7e5", "f1c4", "b8c6", "d1h5", "g8f6", "h5f7"]),
+            result: RecordResult::WhiteWins,
+            visits: None,
+            temperature: None,
+        };
+        let pgn = record.to_pgn();
+        assert_eq!(
+            pgn,
+            "[Result \"1-0\"]\n\n1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0"
+        );
+        assert_eq!(GameRecord::from_pgn(&pgn).unwrap(), record);
+
+        let record = GameRecord {
+            root: Position::from_fen("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 10").unwrap(),
+            moves: moves(&["e8c8", "e1g1"]),
+            result: RecordResult::Unknown,
+            visits: None,
+            temperature: Some("1.5:8:0".parse().unwrap()),
+        };
+        let pgn = record.to_pgn();
+        assert!(pgn.contains("[Temperature \"1.5:8:0\"]"));
+        assert!(pgn.contains("[FEN \"r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 10\"]"));
+        assert!(pgn.ends_with("10... O-O-O 11. O-O *"));
+        assert_eq!(GameRecord::from_pgn(&pgn).unwrap(), record);
+    }
+
+    #[test]
+    fn pgn_import() {
+        let pgn = r#"[Event "Casual game"]
+[White "?"]
+[Black "?"]
+[Result "0-1"]
+
+1.f3 {Weak} e5 (1...e6 2.g4 Qh4#) 2.g4?? $4 Qh4# ; Fool's mate
+0-1
+"#;
+        let record = GameRecord::from_pgn(pgn).unwrap();
+        assert_eq!(record.moves, moves(&["f2f3", "e7e5", "g2g4", "d8h4"]));
+        assert_eq!(record.result, RecordResult::BlackWins);
+        assert!(GameRecord::from_pgn("1. e4 e4").is_err());
+    }
+}
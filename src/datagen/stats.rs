@@ -0,0 +1,141 @@
+//! Summary of the engine's play in a self-play game for monitoring the
+//! training progress over the generations: deeper and more stable searches
+//! with fewer blunders indicate a stronger network.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::search::{self, SearchResult};
+
+/// Drop in the value (in `[-1, 1]` range) of the player's position after their
+/// move that is considered a blunder: the search of the opponent found what
+/// the player missed.
+pub const BLUNDER_THRESHOLD: f32 = 0.3;
+
+/// Search information of a single ply.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlyStats {
+    pub depth: u32,
+    pub nodes: u64,
+    pub elapsed: Duration,
+    /// Value of the position for the player to move.
+    pub value: f32,
+}
+
+impl From<&SearchResult> for PlyStats {
+    fn from(result: &SearchResult) -> Self {
+        Self {
+            depth: result.depth,
+            nodes: result.nodes,
+            elapsed: result.elapsed,
+            value: result.score.value(),
+        }
+    }
+}
+
+/// Aggregated statistics of the searches in a game.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GameStats {
+    pub searches: usize,
+    pub average_depth: f64,
+    pub nps: u64,
+    /// Average absolute change of the value between consecutive plies from
+    /// the perspective of White. Large values mean the evaluation does not
+    /// anticipate the consequences of the moves.
+    pub volatility: f64,
+    /// Number of moves that dropped the value of the player's position by at
+    /// least [`BLUNDER_THRESHOLD`].
+    pub blunders: usize,
+}
+
+impl GameStats {
+    /// Aggregates the searches of consecutive plies.
+    #[must_use]
+    pub fn new(plies: &[PlyStats]) -> Self {
+        if plies.is_empty() {
+            return Self::default();
+        }
+        let nodes: u64 = plies.iter().map(|ply| ply.nodes).sum();
+        let elapsed: Duration = plies.iter().map(|ply| ply.elapsed).sum();
+        let depth: u64 = plies.iter().map(|ply| u64::from(ply.depth)).sum();
+        // The values alternate perspectives: the value for the player after
+        // their move is the negated value of the opponent.
+        let swings: Vec<f32> = plies
+            .windows(2)
+            .map(|pair| pair[0].value + pair[1].value)
+            .collect();
+        Self {
+            searches: plies.len(),
+            average_depth: depth as f64 / plies.len() as f64,
+            nps: search::nps(nodes, elapsed),
+            volatility: if swings.is_empty() {
+                0.0
+            } else {
+                swings
+                    .iter()
+                    .map(|swing| f64::from(swing.abs()))
+                    .sum::<f64>()
+                    / swings.len() as f64
+            },
+            blunders: swings
+                .iter()
+                .filter(|&&swing| swing >= BLUNDER_THRESHOLD)
+                .count(),
+        }
+    }
+
+    /// Serializes the statistics as a single JSON line.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"searches\":{},\"average_depth\":{:.2},\"nps\":{},\"volatility\":{:.4},\"blunders\":{}}}",
+            self.searches, self.average_depth, self.nps, self.volatility, self.blunders
+        )
+    }
+}
+
+impl fmt::Display for GameStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} searches, average depth {:.1}, {} nps, volatility {:.3}, {} blunders",
+            self.searches, self.average_depth, self.nps, self.volatility, self.blunders
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ply(value: f32) -> PlyStats {
+        PlyStats {
+            depth: 4,
+            nodes: 500,
+            elapsed: Duration::from_millis(10),
+            value,
+        }
+    }
+
+    #[test]
+    fn aggregation() {
+        assert_eq!(GameStats::new(&[]), GameStats::default());
+
+        // White is slightly better, then blunders and Black finds the
+        // refutation.
+        let stats = GameStats::new(&[ply(0.1), ply(-0.1), ply(0.1), ply(0.5), ply(-0.5)]);
+        assert_eq!(stats.searches, 5);
+        assert_eq!(stats.average_depth, 4.0);
+        assert_eq!(stats.nps, 50_000);
+        assert_eq!(stats.blunders, 1);
+        assert!((stats.volatility - 0.15).abs() < 1e-6, "{stats}");
+        assert_eq!(
+            stats.to_json(),
+            "{\"searches\":5,\"average_depth\":4.00,\"nps\":50000,\"volatility\":0.1500,\"blunders\":1}"
+        );
+        assert_eq!(
+            stats.to_string(),
+            "5 searches, average depth 4.0, 50000 nps, volatility 0.150, 1 blunders"
+        );
+    }
+}
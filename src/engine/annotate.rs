@@ -0,0 +1,263 @@
+//! Blunder check: searches every position of the games with a fixed node
+//! budget and marks the moves that lose a significant part of the expected
+//! outcome as inaccuracies (`?!`), mistakes (`?`) and blunders (`??`), like
+//! the analysis boards of the chess servers do.
+//!
+//! The loss is measured in the `[-1, 1]` value scale (see
+//! [`crate::evaluation::Prediction::value`]): losing 100 centipawns in a
+//! balanced position matters more than in a position that is already won.
+
+use std::fmt::Write as _;
+use std::sync::atomic::AtomicBool;
+
+use crate::datagen::record::GameRecord;
+use crate::environment::Player;
+use crate::evaluation::Evaluator;
+use crate::search::{mcts, Limits, Score};
+
+/// Settings of [`annotate_pgn`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Search budget for each position.
+    pub nodes: u64,
+    /// Minimum value loss of an inaccuracy (`?!`).
+    pub inaccuracy: f32,
+    /// Minimum value loss of a mistake (`?`).
+    pub mistake: f32,
+    /// Minimum value loss of a blunder (`??`).
+    pub blunder: f32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            nodes: 2000,
+            inaccuracy: 0.1,
+            mistake: 0.2,
+            blunder: 0.3,
+        }
+    }
+}
+
+impl Config {
+    /// Returns the annotation symbol for the move losing `loss` of the value.
+    fn symbol(&self, loss: f32) -> Option<&'static str> {
+        if loss >= self.blunder {
+            Some("??")
+        } else if loss >= self.mistake {
+            Some("?")
+        } else if loss >= self.inaccuracy {
+            Some("?!")
+        } else {
+            None
+        }
+    }
+}
+
+/// Annotates all games in `pgn` and returns them in PGN. The tags of the
+/// games are preserved, the comments and variations of the input are
+/// dropped. Each annotated move gets a comment with the evaluation after it
+/// and the best move according to the search.
+///
+/// # Errors
+///
+/// If any of the games can not be parsed or the search fails.
+pub fn annotate_pgn(
+    pgn: &str,
+    config: &Config,
+    evaluator: &dyn Evaluator,
+) -> anyhow::Result<String> {
+    let mut annotated = String::new();
+    for (index, game) in split_games(pgn).iter().enumerate() {
+        if index > 0 {
+            annotated.push('\n');
+        }
+        annotated.push_str(&annotate_game(game, config, evaluator)?);
+    }
+    Ok(annotated)
+}
+
+fn annotate_game(pgn: &str, config: &Config, evaluator: &dyn Evaluator) -> anyhow::Result<String> {
+    let record = GameRecord::from_pgn(pgn)?;
+    let search_config = mcts::Config {
+        // The annotations should not depend on the random noise.
+        dirichlet_exploration_weight: 0.0,
+        ..mcts::Config::default()
+    };
+    let mut position = record.root.clone();
+    let mut history = Vec::new();
+    let mut results = Vec::with_capacity(record.moves.len() + 1);
+    for ply in 0..=record.moves.len() {
+        let limits = Limits {
+            nodes: Some(config.nodes),
+            history: history.clone(),
+            ..Limits::default()
+        };
+        results.push(mcts::search(
+            &position,
+            &limits,
+            &search_config,
+            evaluator,
+            &AtomicBool::new(false),
+        )?);
+        if let Some(next_move) = record.moves.get(ply) {
+            history.push(position.hash());
+            position.make_move(next_move);
+            if position.halfmove_clock() == 0 {
+                history.clear();
+            }
+        }
+    }
+
+    let mut annotated = String::new();
+    let mut has_annotator = false;
+    for line in pgn
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with('['))
+    {
+        has_annotator |= line.starts_with("[Annotator ");
+        writeln!(annotated, "{line}").unwrap();
+    }
+    if !has_annotator {
+        writeln!(annotated, "[Annotator \"pabi\"]").unwrap();
+    }
+    annotated.push('\n');
+
+    let mut position = record.root.clone();
+    for (ply, next_move) in record.moves.iter().enumerate() {
+        let player = position.us();
+        let number = position.fullmove_counter();
+        match player {
+            Player::White => write!(annotated, "{number}. ").unwrap(),
+            Player::Black if ply == 0 => write!(annotated, "{number}... ").unwrap(),
+            Player::Black => (),
+        }
+        annotated.push_str(&position.to_san(next_move));
+        let (before, after) = (&results[ply], &results[ply + 1]);
+        // The value after the move is from the opponent's perspective.
+        let loss = before.score.value() + after.score.value();
+        match before.best_move {
+            Some(best_move) if best_move != *next_move => {
+                if let Some(symbol) = config.symbol(loss) {
+                    write!(
+                        annotated,
+                        "{symbol} {{ {}; {} was best ({}) }}",
+                        format_score(negate(after.score), player),
+                        position.to_san(&best_move),
+                        format_score(before.score, player),
+                    )
+                    .unwrap();
+                }
+            },
+            _ => (),
+        }
+        annotated.push(' ');
+        position.make_move(next_move);
+    }
+    annotated.push_str(record.result.as_pgn());
+    annotated.push('\n');
+    Ok(annotated)
+}
+
+/// Converts the score from the perspective of the opponent.
+const fn negate(score: Score) -> Score {
+    match score {
+        Score::Centipawns(cp) => Score::Centipawns(-cp),
+        Score::Mate(moves) => Score::Mate(-moves),
+    }
+}
+
+/// Formats the score of `player` from White's perspective, as the GUIs show
+/// the evaluation: "+1.25" or "#-3".
+fn format_score(score: Score, player: Player) -> String {
+    let sign = match player {
+        Player::White => 1,
+        Player::Black => -1,
+    };
+    match score {
+        Score::Centipawns(cp) => format!("{:+.2}", f64::from(sign * cp) / 100.0),
+        Score::Mate(moves) => format!("#{}", sign * moves),
+    }
+}
+
+/// Splits the PGN database into games: each game starts with its tags after
+/// the movetext of the previous one.
+fn split_games(pgn: &str) -> Vec<String> {
+    let mut games = Vec::new();
+    let mut current = String::new();
+    let mut movetext = false;
+    for line in pgn.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && movetext {
+            games.push(std::mem::take(&mut current));
+            movetext = false;
+        }
+        movetext |= !trimmed.is_empty() && !trimmed.starts_with('[');
+        current.push_str(line);
+        current.push('\n');
+    }
+    if !current.trim().is_empty() {
+        games.push(current);
+    }
+    games
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation::Pesto;
+
+    #[test]
+    fn symbols() {
+        let config = Config::default();
+        assert_eq!(config.symbol(0.05), None);
+        assert_eq!(config.symbol(0.1), Some("?!"));
+        assert_eq!(config.symbol(0.25), Some("?"));
+        assert_eq!(config.symbol(1.5), Some("??"));
+    }
+
+    #[test]
+    fn scores() {
+        assert_eq!(format_score(Score::Centipawns(125), Player::White), "+1.25");
+        assert_eq!(format_score(Score::Centipawns(125), Player::Black), "-1.25");
+        assert_eq!(format_score(Score::Centipawns(0), Player::Black), "+0.00");
+        assert_eq!(format_score(Score::Mate(3), Player::Black), "#-3");
+        assert_eq!(format_score(negate(Score::Mate(-2)), Player::White), "#2");
+    }
+
+    #[test]
+    fn split() {
+        let pgn = "[Event \"a\"]\n[Result \"1-0\"]\n\n1. e4 1-0\n\n[Event \"b\"]\n\n1. d4 \
+                   *\n";
+        let games = split_games(pgn);
+        assert_eq!(games.len(), 2);
+        assert!(games[0].contains("1. e4"), "{games:?}");
+        assert!(games[1].starts_with("[Event \"b\"]"), "{games:?}");
+        assert_eq!(split_games("1. e4 e5 *").len(), 1);
+        assert!(split_games("").is_empty());
+    }
+
+    #[test]
+    fn blunder() {
+        // 2... Qg5 hangs the queen to the knight and 3. a3 misses the capture.
+        let pgn = "[Event \"Test\"]\n[White \"A\"]\n[Black \"B\"]\n[Result \"*\"]\n\n1. e4 e5 \
+                   2. Nf3 Qg5 3. a3 *\n";
+        let annotated = annotate_pgn(pgn, &Config::default(), &Pesto).unwrap();
+        let (tags, movetext) = annotated.split_once("\n\n").unwrap();
+        assert_eq!(
+            tags,
+            "[Event \"Test\"]\n[White \"A\"]\n[Black \"B\"]\n[Result \"*\"]\n[Annotator \"pabi\"]"
+        );
+        assert!(
+            movetext.starts_with("1. e4 e5 2. Nf3 Qg5?? {"),
+            "{movetext}"
+        );
+        assert!(movetext.contains("3. a3?? {"), "{movetext}");
+        assert!(movetext.contains("Nxg5 was best"), "{movetext}");
+        assert!(movetext.ends_with(" *\n"), "{movetext}");
+        // The annotated game can be read back.
+        let record = GameRecord::from_pgn(&annotated).unwrap();
+        assert_eq!(record.moves.len(), 5);
+    }
+}
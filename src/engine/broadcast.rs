@@ -0,0 +1,196 @@
+//! Live broadcast of the analysis for streaming overlays: the search
+//! periodically POSTs a JSON snapshot of its current state to an HTTP
+//! endpoint.
+//!
+//! The snapshot is [`SearchResult::to_json`] with the position added:
+//!
+//! ```json
+//! {"fen":"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+//!  "best_move":"e2e4","score":{"cp":25},"q":0.0624,"wdl":[300,462,238],
+//!  "nodes":800,"depth":5,"seldepth":12,"time_ms":120,"pv":["e2e4","e7e5"],
+//!  ...}
+//! ```
+//!
+//! As with the network downloads, the requests are delegated to `curl`. They
+//! are sent from a separate thread so that a slow endpoint never stalls the
+//! search: while a request is in flight, only the latest snapshot is kept and
+//! sent next. The failed requests are ignored.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{ensure, Context};
+
+use crate::chess::position::Position;
+use crate::search::SearchResult;
+
+/// Upper bound on the time a single request can take.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Sends the snapshots of the searches to the endpoint.
+#[derive(Debug)]
+pub(super) struct Broadcast {
+    pending: Arc<(Mutex<Pending>, Condvar)>,
+    /// Minimum time between the snapshots of a search.
+    interval: Duration,
+}
+
+#[derive(Debug, Default)]
+struct Pending {
+    snapshot: Option<String>,
+    /// Set when the [`Broadcast`] is dropped to stop the thread.
+    closed: bool,
+}
+
+impl Broadcast {
+    /// Starts the thread that sends the snapshots to `url`.
+    ///
+    /// # Errors
+    ///
+    /// If the URL is not HTTP(S) or the thread can not be spawned.
+    pub(super) fn new(url: &str, interval: Duration) -> anyhow::Result<Self> {
+        ensure!(
+            url.starts_with("http://") || url.starts_with("https://"),
+            "broadcast URL should be HTTP or HTTPS, got {url:?}"
+        );
+        let pending = Arc::new((Mutex::new(Pending::default()), Condvar::new()));
+        let url = url.to_string();
+        let _ = thread::Builder::new()
+            .name("broadcast".to_string())
+            .spawn({
+                let pending = Arc::clone(&pending);
+                move || loop {
+                    let (lock, ready) = &*pending;
+                    let mut guard = ready
+                        .wait_while(
+                            lock.lock().expect("broadcast should not be poisoned"),
+                            |pending| pending.snapshot.is_none() && !pending.closed,
+                        )
+                        .expect("broadcast should not be poisoned");
+                    let Some(snapshot) = guard.snapshot.take() else {
+                        return;
+                    };
+                    drop(guard);
+                    let _ = post(&url, &snapshot);
+                }
+            })
+            .context("spawning the broadcast thread")?;
+        Ok(Self { pending, interval })
+    }
+
+    pub(super) const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Queues the snapshot, replacing the one that is not sent yet.
+    pub(super) fn send(&self, position: &Position, result: &SearchResult) {
+        let (lock, ready) = &*self.pending;
+        lock.lock()
+            .expect("broadcast should not be poisoned")
+            .snapshot = Some(snapshot(position, result));
+        ready.notify_one();
+    }
+}
+
+impl Drop for Broadcast {
+    /// Stops the thread after sending the last snapshot.
+    fn drop(&mut self) {
+        let (lock, ready) = &*self.pending;
+        if let Ok(mut pending) = lock.lock() {
+            pending.closed = true;
+        }
+        ready.notify_one();
+    }
+}
+
+fn snapshot(position: &Position, result: &SearchResult) -> String {
+    let json = result.to_json();
+    format!("{{\"fen\":\"{position}\",{}", &json[1..])
+}
+
+fn post(url: &str, body: &str) -> anyhow::Result<()> {
+    let mut curl = Command::new("curl")
+        .args(["--fail", "--silent", "--output", "/dev/null"])
+        .arg("--max-time")
+        .arg(REQUEST_TIMEOUT.as_secs().to_string())
+        .args(["--header", "Content-Type: application/json"])
+        .args(["--data-binary", "@-"])
+        .arg(url)
+        .stdin(Stdio::piped())
+        .spawn()
+        .context("running curl to broadcast the analysis")?;
+    curl.stdin
+        .take()
+        .context("curl should have stdin")?
+        .write_all(body.as_bytes())?;
+    let status = curl.wait()?;
+    ensure!(status.success(), "broadcasting to {url} failed: {status}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::TcpListener;
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+    use crate::evaluation::Pesto;
+    use crate::search::{mcts, Limits};
+
+    #[test]
+    fn broadcast() {
+        assert!(Broadcast::new("ftp://localhost", Duration::ZERO).is_err());
+
+        let position = Position::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1").unwrap();
+        let result = mcts::search(
+            &position,
+            &Limits {
+                shortcuts: true,
+                ..Limits::default()
+            },
+            &mcts::Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        let expected = snapshot(&position, &result);
+        assert!(
+            expected.starts_with(
+                "{\"fen\":\"k7/8/1K6/8/8/8/8/7R w - - 0 1\",\"best_move\":\"h1h8\",\"score\":{\"mate\":1}"
+            ),
+            "{expected}"
+        );
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/analysis", listener.local_addr().unwrap());
+        let broadcast = Broadcast::new(&url, Duration::ZERO).unwrap();
+        broadcast.send(&position, &result);
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut content_length = 0;
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        assert_eq!(request_line, "POST /analysis HTTP/1.1\r\n");
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            if header == "\r\n" {
+                break;
+            }
+            if let Some(length) = header.to_ascii_lowercase().strip_prefix("content-length: ") {
+                content_length = length.trim().parse().unwrap();
+            }
+        }
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+        assert_eq!(String::from_utf8(body).unwrap(), expected);
+        reader
+            .get_mut()
+            .write_all(b"HTTP/1.1 204 No Content\r\n\r\n")
+            .unwrap();
+    }
+}
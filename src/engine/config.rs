@@ -0,0 +1,175 @@
+//! Engine defaults loaded at startup from a [TOML] file, for deployments where
+//! sending `setoption` commands is awkward (e.g. OpenBench workers and bots).
+//! The binary looks for [`FILE_NAME`] next to the executable unless the path
+//! is given explicitly.
+//!
+//! ```toml
+//! # Evaluation, same as the --evaluator and --weights flags.
+//! evaluator = "quantized"
+//! weights = "network.safetensors"
+//! # Same as `debug on`.
+//! debug = false
+//!
+//! # UCI options by name (case-insensitive), e.g. Hash, Threads,
+//! # MoveOverhead or the logging options SearchStats and LogSan.
+//! [options]
+//! Hash = 512
+//! MoveOverhead = 100
+//! LogSan = true
+//! ```
+//!
+//! The relative weights path is resolved from the directory of the file. The
+//! options are applied before any UCI command and announced as the defaults
+//! in the `uci` handshake: `setoption` still overrides them.
+//!
+//! [TOML]: https://toml.io
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+
+use super::uci::EngineOption;
+use crate::evaluation::Backend;
+
+/// Name of the config file the binary looks for next to the executable.
+pub const FILE_NAME: &str = "pabi.toml";
+
+/// Contents of the config file. Everything is validated when the file is
+/// loaded, so that a typo is reported at startup rather than ignored.
+#[derive(Debug, Default)]
+pub struct EngineConfig {
+    pub evaluator: Option<Backend>,
+    pub weights: Option<PathBuf>,
+    pub debug: bool,
+    /// UCI options and their values.
+    pub(super) options: Vec<(EngineOption, String)>,
+}
+
+impl EngineConfig {
+    /// Returns the path of [`FILE_NAME`] next to the executable if it exists.
+    #[must_use]
+    pub fn default_path() -> Option<PathBuf> {
+        let path = std::env::current_exe().ok()?.parent()?.join(FILE_NAME);
+        path.is_file().then_some(path)
+    }
+
+    /// Reads and validates the config file.
+    ///
+    /// # Errors
+    ///
+    /// If the file can not be read, is not valid TOML or has unknown keys,
+    /// options or invalid values.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading config file {}", path.display()))?;
+        Self::parse(&text, path.parent().unwrap_or(Path::new(".")))
+            .with_context(|| format!("invalid config file {}", path.display()))
+    }
+
+    /// Parses the config, resolving the relative paths from `base_dir`.
+    ///
+    /// # Errors
+    ///
+    /// See [`EngineConfig::load`].
+    pub fn parse(text: &str, base_dir: &Path) -> anyhow::Result<Self> {
+        let table: toml::Table = text.parse()?;
+        let mut config = Self::default();
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("evaluator", toml::Value::String(backend)) => {
+                    config.evaluator = Some(backend.parse()?);
+                },
+                ("weights", toml::Value::String(weights)) => {
+                    config.weights = Some(base_dir.join(weights));
+                },
+                ("debug", toml::Value::Boolean(on)) => config.debug = on,
+                ("options", toml::Value::Table(options)) => {
+                    for (name, value) in options {
+                        let option = EngineOption::find(&name)
+                            .with_context(|| format!("unknown option {name:?}"))?;
+                        let value = match value {
+                            toml::Value::String(value) => value,
+                            toml::Value::Integer(value) => value.to_string(),
+                            toml::Value::Boolean(value) => value.to_string(),
+                            value => bail!("option {name:?} has unsupported value {value}"),
+                        };
+                        if let Err(message) = option.parse_value(&value) {
+                            bail!("option {name:?}: {message}");
+                        }
+                        config.options.push((option, value));
+                    }
+                },
+                ("evaluator" | "weights" | "debug" | "options", value) => {
+                    bail!("{key:?} has invalid value {value}");
+                },
+                _ => bail!("unknown key {key:?}"),
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let config = EngineConfig::parse(
+            r#"
+evaluator = "quantized"
+weights = "nets/network.safetensors"
+debug = true
+
+[options]
+Hash = 512
+moveoverhead = 100
+LogSan = true
+SyzygyTablebase = "/syzygy"
+"#,
+            Path::new("/opt/pabi"),
+        )
+        .unwrap();
+        assert_eq!(config.evaluator, Some(Backend::Quantized));
+        assert_eq!(
+            config.weights,
+            Some(PathBuf::from("/opt/pabi/nets/network.safetensors"))
+        );
+        assert!(config.debug);
+        assert_eq!(
+            config.options,
+            vec![
+                (EngineOption::Hash, "512".to_string()),
+                (EngineOption::LogSan, "true".to_string()),
+                (EngineOption::SyzygyTablebase, "/syzygy".to_string()),
+                (EngineOption::MoveOverhead, "100".to_string()),
+            ]
+        );
+        // Absolute paths are kept.
+        let config =
+            EngineConfig::parse("weights = \"/net.safetensors\"", Path::new("/opt")).unwrap();
+        assert_eq!(config.weights, Some(PathBuf::from("/net.safetensors")));
+        assert!(EngineConfig::parse("", Path::new("."))
+            .unwrap()
+            .options
+            .is_empty());
+    }
+
+    #[test]
+    fn invalid() {
+        let error = |text: &str| {
+            format!(
+                "{:#}",
+                EngineConfig::parse(text, Path::new(".")).unwrap_err()
+            )
+        };
+        assert!(error("Hash = 512").contains("unknown key \"Hash\""));
+        assert!(error("[options]\nHsh = 512").contains("unknown option \"Hsh\""));
+        assert!(error("[options]\nHash = 0").contains("expected an integer between 1"));
+        assert!(error("[options]\nLogSan = 1").contains("expected true or false"));
+        assert!(error("[options]\nHash = 1.5").contains("unsupported value"));
+        assert!(error("evaluator = \"onnx\"").contains("evaluation backend should be"));
+        assert!(error("debug = \"yes\"").contains("\"debug\" has invalid value"));
+        assert!(error("[options\nHash = 1").contains("TOML parse error"));
+    }
+}
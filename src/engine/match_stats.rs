@@ -0,0 +1,199 @@
+//! Statistics of the searches over a whole match for tournament post-mortems:
+//! time losses and scaling issues often only show up over many games. The
+//! report is written on `quit` if the `StatsFile` option is set.
+//!
+//! ```json
+//! {"games":[{"moves":42,"time_ms":{"total":21000,"mean":500,"median":480,
+//!  "p90":900,"max":1500,"moves":[500,...]},"depth":{"mean":12.5,"max":19},
+//!  "nodes":840000,"cache_hit_rate":0.1250,"ponder":{"hits":15,"misses":6}}],
+//!  "total":{...}}
+//! ```
+//!
+//! `total` has the same fields for all games combined, without the time of
+//! each move.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::search::SearchResult;
+
+/// Statistics of a single search.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(super) struct MoveStats {
+    pub(super) time: Duration,
+    pub(super) nodes: u64,
+    /// Average depth of the playouts.
+    pub(super) depth: u32,
+    /// Evaluation cache lookups during the search and how many of them hit.
+    pub(super) cache_hits: u64,
+    pub(super) cache_lookups: u64,
+}
+
+impl MoveStats {
+    pub(super) fn new(result: &SearchResult, cache_hits: u64, cache_lookups: u64) -> Self {
+        Self {
+            time: result.elapsed,
+            nodes: result.nodes,
+            depth: result.depth,
+            cache_hits,
+            cache_lookups,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct GameStats {
+    moves: Vec<MoveStats>,
+    ponder_hits: u32,
+    ponder_misses: u32,
+}
+
+/// Searches and ponder results of each game. The games are separated by
+/// `ucinewgame`; the searches before the first one form a game too.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(super) struct MatchStats {
+    games: Vec<GameStats>,
+}
+
+impl MatchStats {
+    /// Starts a new game unless the current one is empty.
+    pub(super) fn new_game(&mut self) {
+        if self
+            .games
+            .last()
+            .is_some_and(|game| *game != GameStats::default())
+        {
+            self.games.push(GameStats::default());
+        }
+    }
+
+    pub(super) fn record_move(&mut self, stats: MoveStats) {
+        self.current().moves.push(stats);
+    }
+
+    pub(super) fn record_ponder(&mut self, hit: bool) {
+        let game = self.current();
+        if hit {
+            game.ponder_hits += 1;
+        } else {
+            game.ponder_misses += 1;
+        }
+    }
+
+    fn current(&mut self) -> &mut GameStats {
+        if self.games.is_empty() {
+            self.games.push(GameStats::default());
+        }
+        self.games.last_mut().expect("there is at least one game")
+    }
+
+    /// Formats the report, see the [module documentation](self).
+    #[must_use]
+    pub(super) fn to_json(&self) -> String {
+        let games = self
+            .games
+            .iter()
+            .filter(|game| **game != GameStats::default())
+            .map(|game| summary(std::slice::from_ref(game), true))
+            .collect::<Vec<_>>()
+            .join(",");
+        format!(
+            "{{\"games\":[{games}],\"total\":{}}}",
+            summary(&self.games, false)
+        )
+    }
+}
+
+fn summary(games: &[GameStats], move_times: bool) -> String {
+    let moves: Vec<&MoveStats> = games.iter().flat_map(|game| &game.moves).collect();
+    let mut times: Vec<u128> = moves.iter().map(|stats| stats.time.as_millis()).collect();
+    let listed = times
+        .iter()
+        .map(u128::to_string)
+        .collect::<Vec<_>>()
+        .join(",");
+    times.sort_unstable();
+    let count = moves.len().max(1);
+    let total: u128 = times.iter().sum();
+    let percentile = |percent: usize| {
+        times
+            .get((times.len() * percent / 100).min(times.len().saturating_sub(1)))
+            .copied()
+            .unwrap_or(0)
+    };
+    let depth: u64 = moves.iter().map(|stats| u64::from(stats.depth)).sum();
+    let hits: u64 = moves.iter().map(|stats| stats.cache_hits).sum();
+    let lookups: u64 = moves.iter().map(|stats| stats.cache_lookups).sum();
+    let mut json = format!("{{\"moves\":{},\"time_ms\":", moves.len());
+    write!(
+        json,
+        "{{\"total\":{total},\"mean\":{},\"median\":{},\"p90\":{},\"max\":{}",
+        total / count as u128,
+        percentile(50),
+        percentile(90),
+        times.last().copied().unwrap_or(0),
+    )
+    .expect("writing to a string does not fail");
+    if move_times {
+        write!(json, ",\"moves\":[{listed}]").expect("writing to a string does not fail");
+    }
+    write!(
+        json,
+        "}},\"depth\":{{\"mean\":{:.1},\"max\":{}}},\"nodes\":{},\"cache_hit_rate\":{:.4},\"ponder\":{{\"hits\":{},\"misses\":{}}}}}",
+        depth as f64 / count as f64,
+        moves.iter().map(|stats| stats.depth).max().unwrap_or(0),
+        moves.iter().map(|stats| stats.nodes).sum::<u64>(),
+        hits as f64 / lookups.max(1) as f64,
+        games.iter().map(|game| game.ponder_hits).sum::<u32>(),
+        games.iter().map(|game| game.ponder_misses).sum::<u32>(),
+    )
+    .expect("writing to a string does not fail");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(millis: u64, depth: u32) -> MoveStats {
+        MoveStats {
+            time: Duration::from_millis(millis),
+            nodes: 100,
+            depth,
+            cache_hits: 1,
+            cache_lookups: 4,
+        }
+    }
+
+    #[test]
+    fn report() {
+        let mut stats = MatchStats::default();
+        assert_eq!(
+            stats.to_json(),
+            "{\"games\":[],\"total\":{\"moves\":0,\"time_ms\":{\"total\":0,\"mean\":0,\"median\":\
+             0,\"p90\":0,\"max\":0},\"depth\":{\"mean\":0.0,\"max\":0},\"nodes\":0,\"\
+             cache_hit_rate\":0.0000,\"ponder\":{\"hits\":0,\"misses\":0}}}"
+        );
+        stats.new_game();
+        stats.record_move(search(100, 4));
+        stats.record_move(search(300, 6));
+        stats.record_ponder(true);
+        stats.new_game();
+        // Empty games are not counted.
+        stats.new_game();
+        stats.record_move(search(200, 5));
+        stats.record_ponder(false);
+        assert_eq!(
+            stats.to_json(),
+            "{\"games\":[{\"moves\":2,\"time_ms\":{\"total\":400,\"mean\":200,\"median\":300,\"\
+             p90\":300,\"max\":300,\"moves\":[100,300]},\"depth\":{\"mean\":5.0,\"max\":6},\"\
+             nodes\":200,\"cache_hit_rate\":0.2500,\"ponder\":{\"hits\":1,\"misses\":0}},{\"\
+             moves\":1,\"time_ms\":{\"total\":200,\"mean\":200,\"median\":200,\"p90\":200,\"\
+             max\":200,\"moves\":[200]},\"depth\":{\"mean\":5.0,\"max\":5},\"nodes\":100,\"\
+             cache_hit_rate\":0.2500,\"ponder\":{\"hits\":0,\"misses\":1}}],\"total\":{\"\
+             moves\":3,\"time_ms\":{\"total\":600,\"mean\":200,\"median\":200,\"p90\":300,\"\
+             max\":300},\"depth\":{\"mean\":5.0,\"max\":6},\"nodes\":300,\"cache_hit_rate\":0.\
+             2500,\"ponder\":{\"hits\":1,\"misses\":1}}}"
+        );
+    }
+}
@@ -7,44 +7,245 @@
 /// [Universal Chess Interface]: https://www.chessprogramming.org/UCI
 use core::panic;
 use std::io::{BufRead, Write};
-use std::time::Duration;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::{mpsc, Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
 
-use crate::chess::core::Move;
+use itertools::Itertools;
+
+use crate::chess::core::{Move, PieceKind};
 use crate::chess::position::Position;
-use crate::engine::uci::Command;
-use crate::environment::Player;
+use crate::chess::tablebase::Tablebase;
+use crate::chess::zobrist;
+use crate::engine::uci::{Command, GoParameters};
+use crate::evaluation::{self, Backend, Blend, BlendWeights, Evaluator, Pesto};
+use crate::search::{self, mcts, Limits};
 
+pub mod annotate;
+#[cfg(feature = "broadcast")]
+mod broadcast;
+pub mod config;
+mod match_stats;
+pub mod platform;
+mod searcher;
+#[cfg(feature = "server")]
+pub mod server;
+mod speedtest;
+mod telemetry;
 mod time_manager;
 mod uci;
 
+pub use config::EngineConfig;
+pub use platform::{Platform, UciOutput};
+pub use searcher::{Reporting, Searcher};
+pub use speedtest::{speedtest, SpeedTest};
+pub use telemetry::{bench_report, BenchReport, PositionReport, Scaling, SpawnOverhead};
+
+/// Upper bound of the `CheckBoost` option (in hundredths).
+const MAX_CHECK_BOOST: usize = 1000;
+
+/// Size of the search run by the `Warmup` option: enough to touch the attack
+/// tables and run the evaluator a few times while being negligible compared to
+/// the time of any move.
+const WARMUP_NODES: u64 = 256;
+
+/// Search started with `go ponder` that is waiting for `ponderhit` or `stop`.
+struct Ponder {
+    started: Instant,
+    /// Time budget for the move if the opponent plays the expected move.
+    budget: Option<Duration>,
+}
+
+/// Network set with the `EvalFile` option, loaded in a background thread so
+/// that the engine keeps responding to the commands (and the analysis keeps
+/// running) in the meantime.
+struct PendingNetwork {
+    path: String,
+    loaded: mpsc::Receiver<anyhow::Result<Box<dyn Evaluator>>>,
+}
+
 /// The Engine connects everything together and handles commands sent by UCI
 /// server. It is created when the program is started and implement the "main
 /// loop" via [`Engine::uci_loop`].
-pub struct Engine<'a, R: BufRead, W: Write> {
+pub struct Engine<'a, R: BufRead, W: Write + Send + 'static> {
     /// Next search will start from this position.
     position: Position,
+    /// Hashes of the positions before [`Engine::position`] since the last
+    /// irreversible move, used to detect repetitions in the search.
+    history: Vec<zobrist::Key>,
+    /// Number of moves applied on top of the initial position in the last
+    /// `position` command.
+    moves_played: usize,
+    search_config: mcts::Config,
+    evaluator: Arc<dyn Evaluator>,
+    /// Classical evaluation mixed into the [`Engine::evaluator`] predictions.
+    blend: BlendWeights,
+    /// [`Engine::evaluator`] with the [`Engine::blend`] applied, kept between
+    /// the searches so that the cached evaluations stay valid. Reset when
+    /// either of them changes.
+    blended: Option<Arc<dyn Evaluator>>,
+    /// Loads the `EvalFile` networks, see [`Engine::with_backend`].
+    backend: Backend,
+    /// Replaces [`Engine::evaluator`] once loaded, see
+    /// [`Engine::install_network`].
+    pending_network: Option<PendingNetwork>,
     debug: bool,
+    /// Extra information sent with the search results.
+    reporting: Reporting,
+    /// Show the moves in SAN next to UCI in the `info string` logs. SAN always
+    /// uses the English piece letters, independent of the system locale. The
+    /// protocol lines (`info ... pv`, `bestmove`) always use UCI.
+    log_san: bool,
+    /// Used to restrict the root moves in the endgames.
+    tablebase: Option<Tablebase>,
+    /// Look for the tablebases in the common locations during the handshake
+    /// unless the path was set explicitly.
+    detect_tablebase: bool,
+    searcher: Searcher<UciOutput<W>>,
+    ponder: Option<Ponder>,
+    ponder_hits: u32,
+    ponder_misses: u32,
+    /// Subtracted from the time budget of each move, see
+    /// [`time_manager::budget`].
+    move_overhead: Duration,
+    /// See [`time_manager::max_time`].
+    time_extension: u32,
+    /// See [`time_manager::TimeControl::Delay`].
+    time_delay: Duration,
+    /// Node limit of each search unless `go` sets one, e.g. for self-play
+    /// matches at a fixed number of nodes.
+    nodes_per_move: Option<u64>,
+    /// Option values from the [`EngineConfig`], announced as the defaults in
+    /// the handshake.
+    configured: Vec<(uci::EngineOption, String)>,
+    /// Write the [`match_stats::MatchStats`] report to this file on `quit`.
+    stats_file: Option<PathBuf>,
+    /// Pin the search threads to the CPU cores, see [`platform::ThreadPool`].
+    thread_affinity: bool,
+    /// Run [`WARMUP_NODES`] search on the next `isready` after `ucinewgame`.
+    warmup: bool,
+    warmup_pending: bool,
     // TODO: time_manager,
-    // TODO: transposition_table
+    /// Clock for the pondering time, shared with [`Engine::searcher`].
+    platform: Platform,
     /// UCI commands will be read from this stream.
     input: &'a mut R,
-    /// Responses to UCI commands will be written to this stream.
-    out: &'a mut W,
+    /// Responses to UCI commands will be written to this stream. It is shared
+    /// with the search thread which sends the best move.
+    out: Arc<Mutex<UciOutput<W>>>,
 }
 
-impl<'a, R: BufRead, W: Write> Engine<'a, R, W> {
+impl<'a, R: BufRead, W: Write + Send + 'static> Engine<'a, R, W> {
     /// Creates a new instance of the engine with the starting position as the
     /// search root.
     #[must_use]
-    pub fn new(input: &'a mut R, out: &'a mut W) -> Self {
+    pub fn new(input: &'a mut R, out: W) -> Self {
+        let out = Arc::new(Mutex::new(UciOutput::new(out)));
+        // The searcher shares the thread pool with the engine.
+        let platform = Platform::default();
         Self {
             position: Position::starting(),
+            history: Vec::new(),
+            moves_played: 0,
+            search_config: mcts::Config::default(),
+            evaluator: Arc::new(Pesto),
+            blend: BlendWeights::default(),
+            blended: None,
+            backend: Backend::Quantized,
+            pending_network: None,
             debug: false,
+            reporting: Reporting::default(),
+            log_san: false,
+            tablebase: None,
+            detect_tablebase: true,
+            searcher: Searcher::with_platform(Arc::clone(&out), platform.clone()),
+            ponder: None,
+            ponder_hits: 0,
+            ponder_misses: 0,
+            move_overhead: time_manager::DEFAULT_MOVE_OVERHEAD,
+            time_extension: time_manager::DEFAULT_TIME_EXTENSION,
+            time_delay: Duration::ZERO,
+            nodes_per_move: None,
+            configured: Vec::new(),
+            stats_file: None,
+            thread_affinity: false,
+            warmup: false,
+            warmup_pending: false,
+            platform,
             input,
             out,
         }
     }
 
+    /// Replaces the clock and the thread spawner, e.g. with a
+    /// [`platform::ManualClock`] to control the timers in tests.
+    #[must_use]
+    pub fn with_platform(mut self, platform: Platform) -> Self {
+        self.searcher = Searcher::with_platform(Arc::clone(&self.out), platform.clone());
+        self.platform = platform;
+        self
+    }
+
+    /// Replaces the default evaluation used by the search.
+    #[must_use]
+    pub fn with_evaluator(mut self, evaluator: Arc<dyn Evaluator>) -> Self {
+        self.evaluator = evaluator;
+        self.blended = None;
+        self
+    }
+
+    /// Sets the backend loading the networks from the `EvalFile` option.
+    /// [`Backend::Pesto`] does not use the weights, so the networks are
+    /// loaded with [`Backend::Quantized`] (the default) in that case.
+    #[must_use]
+    pub fn with_backend(mut self, backend: Backend) -> Self {
+        self.backend = match backend {
+            Backend::Pesto => Backend::Quantized,
+            backend => backend,
+        };
+        self
+    }
+
+    /// Applies the defaults from the config file before any UCI command. The
+    /// evaluator is not created here, see [`Engine::with_evaluator`].
+    ///
+    /// # Errors
+    ///
+    /// If applying an option fails.
+    pub fn with_config(mut self, config: &EngineConfig) -> anyhow::Result<Self> {
+        self.debug = config.debug;
+        for (option, value) in &config.options {
+            let parsed = option
+                .parse_value(value)
+                .map_err(|message| anyhow::anyhow!("{}: {message}", option.name()))?;
+            self.set_option(*option, parsed)?;
+            // Spin options are announced with numeric defaults.
+            let value = if *option == uci::EngineOption::Hash && value == "auto" {
+                (self.search_config.tree_memory >> 20).to_string()
+            } else {
+                value.clone()
+            };
+            self.configured
+                .retain(|(configured, _)| configured != option);
+            self.configured.push((*option, value));
+        }
+        Ok(self)
+    }
+
+    /// POSTs the snapshots of the searches to `url` at most once per
+    /// `interval` for live analysis overlays, see [`broadcast`].
+    ///
+    /// # Errors
+    ///
+    /// If the URL is not HTTP(S) or the broadcast thread can not be spawned.
+    #[cfg(feature = "broadcast")]
+    pub fn with_broadcast(mut self, url: &str, interval: Duration) -> anyhow::Result<Self> {
+        self.searcher
+            .set_broadcast(broadcast::Broadcast::new(url, interval)?);
+        Ok(self)
+    }
+
     /// Continuously reads the input stream and executes sent UCI commands until
     /// "quit" is sent.
     ///
@@ -60,13 +261,22 @@ impl<'a, R: BufRead, W: Write> Engine<'a, R, W> {
     /// much time and effort on error recovery. If a command is not valid or
     /// unsupported yet, it will just be skipped.
     ///
-    /// For example, if the UCI server sends a corrupted position or illegal
-    /// moves to the engine, the behavior is undefined.
+    /// The exception is the `position` command: a corrupted position or an
+    /// illegal move is rejected with an `info string` and the previous
+    /// position is kept, so that the engine never searches an invalid
+    /// position.
+    ///
+    /// The search runs in a separate thread. Both "quit" and the end of the
+    /// input stream stop it and wait for the thread to finish (within
+    /// a bounded timeout) before returning.
     pub fn uci_loop(&mut self) -> anyhow::Result<()> {
         loop {
             let mut line = String::new();
             match self.input.read_line(&mut line) {
-                Ok(0) => break,
+                Ok(0) => {
+                    self.shutdown()?;
+                    break;
+                },
                 Ok(_) => {},
                 Err(e) => {
                     panic!("Error reading from input: {}", e);
@@ -76,105 +286,791 @@ impl<'a, R: BufRead, W: Write> Engine<'a, R, W> {
                 Command::Uci => self.handshake()?,
                 Command::Debug { on } => self.debug = on,
                 Command::IsReady => self.sync()?,
-                Command::SetOption { option, value } => match option {
-                    uci::EngineOption::Hash => match value {
-                        uci::OptionValue::Integer(_) => todo!(),
-                        uci::OptionValue::String(value) => writeln!(
-                            self.out,
-                            "info string Invalid value for Hash option: {value}"
-                        )?,
-                    },
-                    uci::EngineOption::Threads => todo!(),
-                    uci::EngineOption::SyzygyTablebase => todo!(),
-                },
+                Command::SetOption { option, value } => self.set_option(option, value)?,
+                Command::InvalidOption(message) => writeln!(self.out(), "info string {message}")?,
                 Command::SetPosition { fen, moves } => self.set_position(fen, moves)?,
                 Command::NewGame => self.new_game()?,
-                Command::Go {
-                    wtime,
-                    btime,
-                    winc,
-                    binc,
-                } => self.go(wtime, btime, winc, binc)?,
+                Command::Go(parameters) => self.go(&parameters)?,
+                Command::PonderHit => self.ponderhit()?,
                 Command::Stop => self.stop_search()?,
                 Command::Quit => {
-                    self.stop_search()?;
+                    self.shutdown()?;
                     break;
                 },
                 Command::State => todo!(),
+                Command::Attacks => self.print_attacks()?,
+                Command::RootStats => self.print_root_stats()?,
+                Command::Hash => writeln!(
+                    self.out(),
+                    "info string hash {:016x} moves {} fen {}",
+                    self.position.hash(),
+                    self.moves_played,
+                    self.position
+                )?,
+                Command::LegalMoves => self.print_legal_moves()?,
                 Command::Unknown(command) => {
-                    writeln!(self.out, "info string Unsupported command: {command}")?;
+                    writeln!(self.out(), "info string Unsupported command: {command}")?;
                 },
             }
         }
         Ok(())
     }
 
+    /// Applies the `setoption` command. The value is already checked to be
+    /// within the range of the option, see [`uci::EngineOption::kind`].
+    fn set_option(
+        &mut self,
+        option: uci::EngineOption,
+        value: uci::OptionValue,
+    ) -> anyhow::Result<()> {
+        use uci::{EngineOption, OptionValue};
+
+        match (option, value) {
+            (EngineOption::Hash, OptionValue::Integer(megabytes)) => {
+                self.search_config.tree_memory = megabytes << 20;
+            },
+            (EngineOption::Hash, OptionValue::String(_)) => {
+                let (megabytes, available) = auto_hash_megabytes();
+                self.search_config.tree_memory = megabytes << 20;
+                writeln!(
+                    self.out(),
+                    "info string Hash set to {megabytes} MB ({available} MB of memory available)"
+                )?;
+            },
+            // The search is single-threaded for now: the option only sizes the
+            // pool of the search threads.
+            (EngineOption::Threads, OptionValue::Integer(value)) => {
+                self.search_config.threads = value as u16;
+                self.platform.spawner.configure(value, self.thread_affinity);
+            },
+            (EngineOption::ThreadAffinity, OptionValue::Boolean(on)) => {
+                self.thread_affinity = on;
+                self.platform
+                    .spawner
+                    .configure(usize::from(self.search_config.threads), on);
+            },
+            (EngineOption::SyzygyTablebase, OptionValue::String(path)) => {
+                self.set_tablebase(&path)?;
+            },
+            (EngineOption::StatsFile, OptionValue::String(path)) => {
+                self.stats_file = (!path.is_empty() && path != "<empty>").then(|| path.into());
+            },
+            (EngineOption::EvalFile, OptionValue::String(path)) => self.load_network(path)?,
+            (EngineOption::SearchStats, OptionValue::Boolean(on)) => self.reporting.stats = on,
+            (EngineOption::LogSan, OptionValue::Boolean(on)) => self.log_san = on,
+            (EngineOption::AnalyseMode, OptionValue::Boolean(on)) => {
+                self.search_config.analysis = on;
+            },
+            // The server decides whether to send `go ponder`.
+            (EngineOption::Ponder, _) => {},
+            (EngineOption::ShowWdl, OptionValue::Boolean(on)) => self.reporting.wdl = on,
+            (EngineOption::Chess960, OptionValue::Boolean(on)) => self.reporting.chess960 = on,
+            (EngineOption::PolicyMoves, OptionValue::Integer(value)) => {
+                self.reporting.policy_moves = value as u16;
+            },
+            (EngineOption::Cpuct, OptionValue::Integer(value)) => {
+                self.search_config.cpuct = from_hundredths(value);
+            },
+            (EngineOption::FpuReduction, OptionValue::Integer(value)) => {
+                self.search_config.fpu_reduction = from_hundredths(value);
+            },
+            (EngineOption::PolicyTemperature, OptionValue::Integer(value)) => {
+                self.search_config.policy_temperature = from_hundredths(value);
+            },
+            (EngineOption::CheckBoost, OptionValue::Integer(value)) => {
+                self.search_config.check_prior_boost = from_hundredths(value);
+            },
+            (EngineOption::MoveOverhead, OptionValue::Integer(value)) => {
+                self.move_overhead = Duration::from_millis(value as u64);
+            },
+            (EngineOption::TimeExtension, OptionValue::Integer(value)) => {
+                self.time_extension = value as u32;
+            },
+            (EngineOption::TimeDelay, OptionValue::Integer(value)) => {
+                self.time_delay = Duration::from_millis(value as u64);
+            },
+            (EngineOption::Warmup, OptionValue::Boolean(on)) => self.warmup = on,
+            (EngineOption::BlendMiddlegame, OptionValue::Integer(value)) => {
+                self.blend.middlegame = value as u8;
+                self.blended = None;
+            },
+            (EngineOption::BlendEndgame, OptionValue::Integer(value)) => {
+                self.blend.endgame = value as u8;
+                self.blended = None;
+            },
+            (EngineOption::BlendKnownEndgame, OptionValue::Integer(value)) => {
+                self.blend.known_endgame = value as u8;
+                self.blended = None;
+            },
+            (EngineOption::NodesPerMove, OptionValue::Integer(value)) => {
+                self.nodes_per_move = (value > 0).then_some(value as u64);
+            },
+            (EngineOption::RootJitter, OptionValue::Integer(value)) => {
+                self.search_config.root_jitter = value as u16;
+            },
+            (EngineOption::RootJitterPlies, OptionValue::Integer(value)) => {
+                self.search_config.root_jitter_plies = value as u16;
+            },
+            (EngineOption::RootJitterSeed, OptionValue::Integer(value)) => {
+                self.search_config.root_jitter_seed = value as u64;
+            },
+            (EngineOption::MaxPvLength, OptionValue::Integer(value)) => {
+                self.search_config.max_pv_length = value as u16;
+            },
+            (EngineOption::Contempt, OptionValue::Integer(value)) => {
+                self.search_config.contempt = value as u16;
+            },
+            (EngineOption::WideningBase, OptionValue::Integer(value)) => {
+                self.search_config.widening_base = from_hundredths(value);
+            },
+            (EngineOption::WideningExponent, OptionValue::Integer(value)) => {
+                self.search_config.widening_exponent = from_hundredths(value);
+            },
+            (EngineOption::RootPruning, OptionValue::Integer(value)) => {
+                self.search_config.root_pruning = value as u16;
+            },
+            (EngineOption::RootPruningVisits, OptionValue::Integer(value)) => {
+                self.search_config.root_pruning_visits = value as u32;
+            },
+            (option, value) => unreachable!("{value:?} is not a valid value of {option:?}"),
+        }
+        Ok(())
+    }
+
+    /// Locks the output stream for writing a response.
+    fn out(&self) -> MutexGuard<'_, UciOutput<W>> {
+        self.out.lock().expect("output should not be poisoned")
+    }
+
     /// Responds to the `uci` handshake command by identifying the engine.
     fn handshake(&mut self) -> anyhow::Result<()> {
+        let mut out = self.out();
         writeln!(
-            self.out,
+            out,
             "id name {} {}",
             env!("CARGO_PKG_NAME"),
             crate::engine_version()
         )?;
-        writeln!(self.out, "id author {}", env!("CARGO_PKG_AUTHORS"))?;
-        writeln!(self.out, "uciok")?;
+        writeln!(out, "id author {}", env!("CARGO_PKG_AUTHORS"))?;
+        for option in uci::EngineOption::ALL {
+            let Some(mut default) = option_default(option) else {
+                continue;
+            };
+            if let Some((_, value)) = self
+                .configured
+                .iter()
+                .find(|(configured, _)| *configured == option)
+            {
+                default.clone_from(value);
+            }
+            let name = option.name();
+            match option.kind() {
+                uci::OptionKind::Check => {
+                    writeln!(out, "option name {name} type check default {default}")?;
+                },
+                uci::OptionKind::Spin { min, max } => writeln!(
+                    out,
+                    "option name {name} type spin default {default} min {min} max {max}"
+                )?,
+                uci::OptionKind::String => {
+                    writeln!(out, "option name {name} type string default {default}")?;
+                },
+            }
+        }
+        writeln!(out, "uciok")?;
+        writeln!(out, "info string Build: {}", crate::build_info())?;
+        drop(out);
+        if std::mem::take(&mut self.detect_tablebase) {
+            if let Some((path, tablebase)) = Tablebase::detect() {
+                writeln!(
+                    self.out(),
+                    "info string Found tablebases with up to {} pieces in {}",
+                    tablebase.max_pieces(),
+                    path.display()
+                )?;
+                self.tablebase = Some(tablebase);
+            }
+        }
         Ok(())
     }
 
     /// Syncs with the UCI server by responding with `readyok`.
+    ///
+    /// The first `isready` after `ucinewgame` runs the warmup search if it is
+    /// enabled: the GUI waits for `readyok` before starting the clock, so the
+    /// page faults on the attack tables and the evaluator weights are not
+    /// charged to the first move.
+    ///
+    /// The network set with `EvalFile` is installed before responding, so
+    /// that the GUI can rely on it being used by the next search.
     fn sync(&mut self) -> anyhow::Result<()> {
-        writeln!(self.out, "readyok")?;
+        self.install_network()?;
+        if self.warmup_pending && !self.searcher.is_searching() {
+            self.warmup_pending = false;
+            self.run_warmup()?;
+        }
+        writeln!(self.out(), "readyok")?;
+        Ok(())
+    }
+
+    /// Searches the starting position for [`WARMUP_NODES`] and reports how
+    /// long it took, which is roughly the time saved on the first move.
+    fn run_warmup(&mut self) -> anyhow::Result<()> {
+        let started = self.platform.clock.now();
+        let limits = Limits {
+            nodes: Some(WARMUP_NODES),
+            ..Limits::default()
+        };
+        let evaluator = self.search_evaluator();
+        let result = mcts::search(
+            &Position::starting(),
+            &limits,
+            &self.search_config,
+            &*evaluator,
+            &AtomicBool::new(false),
+        )?;
+        writeln!(
+            self.out(),
+            "info string Warmup searched {} nodes in {} ms",
+            result.nodes,
+            (self.platform.clock.now() - started).as_millis()
+        )?;
         Ok(())
     }
 
     fn new_game(&mut self) -> anyhow::Result<()> {
-        // TODO: Reset search state.
-        // TODO: Clear transposition table.
+        self.warmup_pending = self.warmup;
         // TODO: Reset time manager.
+        self.searcher.clear()?;
+        self.searcher.match_stats().new_game();
+        Ok(())
+    }
+
+    /// Loads Syzygy tablebases from the directory. Empty path unloads them.
+    fn set_tablebase(&mut self, path: &str) -> anyhow::Result<()> {
+        self.detect_tablebase = false;
+        if path.is_empty() || path == "<empty>" {
+            self.tablebase = None;
+            return Ok(());
+        }
+        match Tablebase::open(path.as_ref()) {
+            Ok(tablebase) => {
+                writeln!(
+                    self.out(),
+                    "info string Loaded tablebases with up to {} pieces",
+                    tablebase.max_pieces()
+                )?;
+                self.tablebase = Some(tablebase);
+            },
+            Err(e) => writeln!(self.out(), "info string Failed to load tablebases: {e:#}")?,
+        }
+        Ok(())
+    }
+
+    /// Starts loading the network in the background. The current evaluator
+    /// keeps being used until the next search, including the one that might be
+    /// running right now. Empty path keeps the current network.
+    fn load_network(&mut self, path: String) -> anyhow::Result<()> {
+        // Only the last requested network is installed.
+        self.install_network()?;
+        if path.is_empty() || path == "<empty>" {
+            return Ok(());
+        }
+        let (sender, loaded) = mpsc::channel();
+        let backend = self.backend;
+        let weights = PathBuf::from(&path);
+        let _ = self.platform.spawner.spawn(
+            "network loader",
+            Box::new(move || {
+                // The engine might be gone by the time the network is loaded.
+                let _ = sender.send(backend.create(Some(&weights)));
+                Ok(())
+            }),
+        )?;
+        self.pending_network = Some(PendingNetwork { path, loaded });
+        Ok(())
+    }
+
+    /// Waits for the network from [`Engine::load_network`] and replaces the
+    /// evaluator with it. The evaluations cached by the search are dropped with
+    /// the old evaluator (see [`crate::search::session::Session::analyze`]).
+    /// The search that is running keeps its evaluator, so the swap only
+    /// happens between the searches.
+    fn install_network(&mut self) -> anyhow::Result<()> {
+        let Some(PendingNetwork { path, loaded }) = self.pending_network.take() else {
+            return Ok(());
+        };
+        let result = loaded
+            .recv()
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("network loader thread failed")));
+        match result {
+            Ok(evaluator) => {
+                self.evaluator = Arc::from(evaluator);
+                self.blended = None;
+                writeln!(self.out(), "info string Loaded network from {path}")?;
+            },
+            Err(e) => writeln!(
+                self.out(),
+                "info string Failed to load network from {path}: {e:#}"
+            )?,
+        }
         Ok(())
     }
 
     /// Changes the position of the board to the one specified in the command.
+    /// Keeps the previous position if the new one is invalid. In debug mode,
+    /// the differences from the previous position are reported.
     fn set_position(&mut self, fen: Option<String>, moves: Vec<String>) -> anyhow::Result<()> {
-        match fen {
-            Some(fen) => self.position = Position::from_fen(&fen)?,
-            None => self.position = Position::starting(),
-        };
-        for next_move in moves {
-            match Move::from_uci(&next_move) {
-                Ok(next_move) => self.position.make_move(&next_move),
-                Err(_) => unreachable!(),
+        match setup_position(fen.as_deref(), &moves, self.reporting.chess960) {
+            Ok((position, history)) => {
+                if self.debug {
+                    let changes = self.position.diff(&position).iter().join(", ");
+                    writeln!(self.out(), "info string Position changes: {changes}")?;
+                }
+                self.position = position;
+                self.history = history;
+                self.moves_played = moves.len();
+                if self.debug {
+                    self.print_attacks()?;
+                }
+            },
+            Err(e) => writeln!(self.out(), "info string Rejected position: {e:#}")?,
+        }
+        Ok(())
+    }
+
+    /// Sends the attack map of the current position as a series of
+    /// `info string` lines.
+    fn print_attacks(&self) -> anyhow::Result<()> {
+        let mut out = self.out();
+        for line in self.position.attack_map().lines() {
+            writeln!(out, "info string {line}")?;
+        }
+        Ok(())
+    }
+
+    /// Sends the legal moves of the current position grouped by the kind of
+    /// the moving piece, each move in UCI and SAN notation.
+    fn print_legal_moves(&self) -> anyhow::Result<()> {
+        let moves = self.position.generate_moves();
+        let mut out = self.out();
+        writeln!(out, "info string legal moves {}", moves.len())?;
+        for kind in [
+            PieceKind::Pawn,
+            PieceKind::Knight,
+            PieceKind::Bishop,
+            PieceKind::Rook,
+            PieceKind::Queen,
+            PieceKind::King,
+        ] {
+            let group: Vec<String> = moves
+                .iter()
+                .filter(|next_move| {
+                    self.position
+                        .at(next_move.from())
+                        .is_some_and(|piece| piece.kind == kind)
+                })
+                .map(|next_move| format!("{next_move} ({})", self.position.to_san(next_move)))
+                .collect();
+            if !group.is_empty() {
+                writeln!(
+                    out,
+                    "info string {kind:?} {}: {}",
+                    group.len(),
+                    group.join(" ")
+                )?;
             }
         }
         Ok(())
     }
 
-    fn go(
-        &mut self,
-        wtime: Option<Duration>,
-        btime: Option<Duration>,
-        winc: Option<Duration>,
-        binc: Option<Duration>,
-    ) -> anyhow::Result<()> {
-        let (time, increment) = match self.position.us() {
-            Player::White => (wtime, winc),
-            Player::Black => (btime, binc),
-        };
-        todo!();
+    /// Sends the statistics of the root moves in the last finished search.
+    fn print_root_stats(&self) -> anyhow::Result<()> {
+        match self.searcher.last_search() {
+            Some((root, result)) => {
+                searcher::root_stats(&mut *self.out(), &result, self.log_san.then_some(&root))
+            },
+            None => {
+                writeln!(self.out(), "info string No finished search")?;
+                Ok(())
+            },
+        }
     }
 
-    /// Stops the search immediately.
+    /// Starts searching the current position in the background. The best move
+    /// is sent once the search is finished or stopped.
     ///
-    /// NOTE: This is a no-op for now.
+    /// `go mate <x>` stops the search once a mate in at most `x` moves is
+    /// proven or the tree shows that there is none, see [`Limits::mate`].
+    ///
+    /// In analysis mode the clocks are ignored and the search only stops when
+    /// explicit limits are reached or it is stopped by the server. All searched
+    /// root moves are reported as separate `multipv` lines.
+    ///
+    /// With `go ponder` the time budget is only applied after `ponderhit`.
+    /// Starting a new search while pondering counts as a ponder miss.
+    ///
+    /// In debug mode, the evaluation hints for the root position (see
+    /// [`evaluation::hints`]) are sent before each search.
+    ///
+    /// The network set with `EvalFile` is used from this search on, waiting
+    /// for it to load if needed.
+    fn go(&mut self, parameters: &GoParameters) -> anyhow::Result<()> {
+        self.ponder_miss()?;
+        self.install_network()?;
+        if self.debug {
+            let mut out = self.out();
+            for hint in evaluation::hints(&self.position) {
+                writeln!(out, "info string {hint}")?;
+            }
+        }
+        let control = parameters.time_control(self.position.us(), self.time_delay);
+        let budget = time_manager::budget(control, self.move_overhead).map(|budget| {
+            // Forced moves (e.g. recaptures) are played immediately and the
+            // time is saved for the rest of the game.
+            if self.position.generate_moves().len() == 1 {
+                return Duration::ZERO;
+            }
+            time_manager::adjust(
+                budget,
+                control,
+                parameters.opponent_time(self.position.us()),
+                evaluation::phase(&self.position).unsigned_abs(),
+                evaluation::MAX_PHASE.unsigned_abs(),
+                self.move_overhead,
+            )
+        });
+        let nodes = parameters.nodes.or(self.nodes_per_move);
+        // With a node limit the result only depends on the position, the
+        // options and the tree kept from the previous searches (cleared by
+        // `ucinewgame`), which makes the searches reproducible: the clock is
+        // ignored unless the move time is given explicitly.
+        // The shortcuts are only taken when playing on the clock: a ponder
+        // search has to keep going until the opponent moves.
+        let (time, max_time, shortcuts) = if parameters.infinite {
+            (None, None, false)
+        } else if self.search_config.analysis || parameters.movetime.is_some() || nodes.is_some() {
+            (parameters.movetime, None, false)
+        } else {
+            let max_time = budget.map(|budget| {
+                time_manager::max_time(budget, control, self.move_overhead, self.time_extension)
+            });
+            (budget, max_time, budget.is_some() && !parameters.ponder)
+        };
+        let mut limits = Limits {
+            time,
+            max_time,
+            nodes,
+            depth: parameters.depth,
+            mate: parameters.mate,
+            searchmoves: self.tablebase_moves()?,
+            history: self.history.clone(),
+            shortcuts,
+        };
+        let evaluator = self.search_evaluator();
+        if parameters.ponder {
+            self.ponder = Some(Ponder {
+                started: self.platform.clock.now(),
+                budget: limits.time.take(),
+            });
+            return self.searcher.ponder(
+                &self.position,
+                limits,
+                &self.search_config,
+                evaluator,
+                self.reporting,
+            );
+        }
+        self.searcher.go(
+            &self.position,
+            limits,
+            &self.search_config,
+            evaluator,
+            self.reporting,
+        )
+    }
+
+    /// Returns the evaluator for the next search: [`Engine::evaluator`] with
+    /// the classical evaluation blended in if it is enabled.
+    fn search_evaluator(&mut self) -> Arc<dyn Evaluator> {
+        let blended = self.blended.get_or_insert_with(|| {
+            if self.blend.is_disabled() {
+                Arc::clone(&self.evaluator)
+            } else {
+                Arc::new(Blend::new(Arc::clone(&self.evaluator), self.blend))
+            }
+        });
+        Arc::clone(blended)
+    }
+
+    /// Returns the root moves preserving the tablebase result if the position
+    /// is in the tablebase and all legal moves otherwise (empty list).
+    fn tablebase_moves(&self) -> anyhow::Result<Vec<Move>> {
+        let Some((wdl, moves)) = self
+            .tablebase
+            .as_ref()
+            .and_then(|tablebase| tablebase.root_moves(&self.position))
+        else {
+            return Ok(Vec::new());
+        };
+        writeln!(
+            self.out(),
+            "info string tablebase hit: {wdl:?} with {} of {} moves",
+            moves.len(),
+            self.position.generate_moves().len()
+        )?;
+        Ok(moves.to_vec())
+    }
+
+    /// The opponent played the expected move: continue the ponder search with
+    /// the accumulated tree and the time budget reduced by the pondering
+    /// time.
+    fn ponderhit(&mut self) -> anyhow::Result<()> {
+        let Some(ponder) = self.ponder.take() else {
+            return Ok(());
+        };
+        let pondered = self.platform.clock.now() - ponder.started;
+        let time = ponder
+            .budget
+            .map(|budget| time_manager::after_ponder_hit(budget, pondered));
+        if self.searcher.ponderhit(time) {
+            self.ponder_hits += 1;
+            self.searcher.match_stats().record_ponder(true);
+            self.report_ponder_stats()?;
+        }
+        Ok(())
+    }
+
+    /// The opponent played an unexpected move: the ponder search is discarded
+    /// and the server restarts the search from the actual position.
+    fn ponder_miss(&mut self) -> anyhow::Result<()> {
+        if self.ponder.take().is_some() {
+            self.ponder_misses += 1;
+            self.searcher.match_stats().record_ponder(false);
+            self.report_ponder_stats()?;
+        }
+        Ok(())
+    }
+
+    fn report_ponder_stats(&self) -> anyhow::Result<()> {
+        let total = self.ponder_hits + self.ponder_misses;
+        writeln!(
+            self.out(),
+            "info string Ponder hit rate: {}/{total} ({}%)",
+            self.ponder_hits,
+            self.ponder_hits * 100 / total
+        )?;
+        Ok(())
+    }
+
+    /// Stops the search (if there is one running) and waits for the best move
+    /// to be sent.
     fn stop_search(&mut self) -> anyhow::Result<()> {
-        // TODO: Implement this method.
+        self.ponder_miss()?;
+        self.searcher.stop()
+    }
+
+    /// Stops the search, writes the match statistics if requested and flushes
+    /// the output before exiting.
+    fn shutdown(&mut self) -> anyhow::Result<()> {
+        self.searcher.stop()?;
+        if let Some(path) = &self.stats_file {
+            let report = self.searcher.match_stats().to_json();
+            if let Err(e) = std::fs::write(path, report + "\n") {
+                writeln!(
+                    self.out(),
+                    "info string Failed to write match statistics to {}: {e}",
+                    path.display()
+                )?;
+            }
+        }
+        self.out().flush()?;
         Ok(())
     }
 }
 
+/// Upper bound of the `RootJitter` option in centipawns: the jitter is meant to
+/// choose between moves of similar strength.
+const MAX_ROOT_JITTER: usize = 100;
+
+/// Upper bound of the `Contempt` option in centipawns.
+const MAX_CONTEMPT: usize = 200;
+
+/// Upper bound of the `Hash` option in megabytes.
+const MAX_HASH_MEGABYTES: usize = 1 << 20;
+
+/// Upper bound of the `Threads` option.
+const MAX_THREADS: usize = 512;
+
+/// Upper bound of the `PolicyMoves` option: the maximum number of legal moves.
+const MAX_POLICY_MOVES: usize = 218;
+
+/// Default value of the option announced in the `uci` handshake, [`None`] for
+/// the options that are accepted but not announced.
+fn option_default(option: uci::EngineOption) -> Option<String> {
+    use uci::EngineOption;
+
+    // Search parameters are real numbers, but UCI only supports integers: the
+    // values are in hundredths.
+    let defaults = mcts::Config::default();
+    let default = match option {
+        EngineOption::Hash => (mcts::DEFAULT_TREE_MEMORY >> 20).to_string(),
+        // The search is single-threaded for now.
+        EngineOption::Threads => return None,
+        EngineOption::ThreadAffinity
+        | EngineOption::SearchStats
+        | EngineOption::LogSan
+        | EngineOption::AnalyseMode
+        | EngineOption::Ponder
+        | EngineOption::ShowWdl
+        | EngineOption::Chess960
+        | EngineOption::Warmup => false.to_string(),
+        EngineOption::SyzygyTablebase | EngineOption::StatsFile | EngineOption::EvalFile => {
+            "<empty>".to_string()
+        },
+        EngineOption::Cpuct => to_hundredths(defaults.cpuct).to_string(),
+        EngineOption::FpuReduction => to_hundredths(defaults.fpu_reduction).to_string(),
+        EngineOption::PolicyTemperature => to_hundredths(defaults.policy_temperature).to_string(),
+        EngineOption::CheckBoost => to_hundredths(defaults.check_prior_boost).to_string(),
+        EngineOption::MoveOverhead => time_manager::DEFAULT_MOVE_OVERHEAD.as_millis().to_string(),
+        EngineOption::TimeExtension => time_manager::DEFAULT_TIME_EXTENSION.to_string(),
+        EngineOption::NodesPerMove
+        | EngineOption::TimeDelay
+        | EngineOption::PolicyMoves
+        | EngineOption::BlendMiddlegame
+        | EngineOption::BlendEndgame
+        | EngineOption::BlendKnownEndgame => 0.to_string(),
+        EngineOption::RootJitter => defaults.root_jitter.to_string(),
+        EngineOption::RootJitterPlies => defaults.root_jitter_plies.to_string(),
+        EngineOption::RootJitterSeed => defaults.root_jitter_seed.to_string(),
+        EngineOption::MaxPvLength => defaults.max_pv_length.to_string(),
+        EngineOption::Contempt => defaults.contempt.to_string(),
+        EngineOption::WideningBase => to_hundredths(defaults.widening_base).to_string(),
+        EngineOption::WideningExponent => to_hundredths(defaults.widening_exponent).to_string(),
+        EngineOption::RootPruning => defaults.root_pruning.to_string(),
+        EngineOption::RootPruningVisits => defaults.root_pruning_visits.to_string(),
+    };
+    Some(default)
+}
+
+/// `Hash=auto` leaves the rest of the available memory to the evaluator, the
+/// operating system and other processes on the machine.
+const AUTO_HASH_FRACTION: usize = 4;
+
+/// Returns the `Hash` size for `Hash=auto` and the available memory, both in
+/// megabytes.
+fn auto_hash_megabytes() -> (usize, usize) {
+    let mut system = sysinfo::System::new();
+    system.refresh_memory();
+    let available = (system.available_memory() >> 20) as usize;
+    (
+        (available / AUTO_HASH_FRACTION).clamp(1, MAX_HASH_MEGABYTES),
+        available,
+    )
+}
+
+/// Upper bound of the `MaxPvLength` option.
+const MAX_PV_LENGTH: usize = 256;
+
+/// Converts the value of a real-valued UCI option, sent in hundredths.
+fn from_hundredths(value: usize) -> f32 {
+    value as f32 / 100.0
+}
+
+fn to_hundredths(value: f32) -> usize {
+    (value * 100.0).round() as usize
+}
+
+/// Creates the position from FEN (or the starting position) and plays the
+/// moves, checking that each of them is legal. Castling is expected in the
+/// Chess960 encoding (king captures rook) if `chess960` is set.
+/// Returns the position after the moves and the hashes of the positions that
+/// preceded it since the last irreversible move (capture or pawn move), which
+/// are the only ones that can repeat.
+fn setup_position(
+    fen: Option<&str>,
+    moves: &[String],
+    chess960: bool,
+) -> anyhow::Result<(Position, Vec<zobrist::Key>)> {
+    let mut position = match fen {
+        Some(fen) => Position::try_from(fen)?,
+        None => Position::starting(),
+    };
+    let mut history = Vec::new();
+    for next_move in moves {
+        let parsed = position.parse_uci(next_move, chess960)?;
+        if !position.generate_moves().contains(&parsed) {
+            anyhow::bail!("illegal move {next_move} in {position}");
+        }
+        history.push(position.hash());
+        position.make_move(&parsed);
+        if position.halfmove_clock() == 0 {
+            history.clear();
+        }
+    }
+    position.validate()?;
+    Ok((position, history))
+}
+
+/// Positions for [`bench`]: a mix of openings, middlegames and endgames,
+/// including the standard [perft positions].
+///
+/// [perft positions]: https://www.chessprogramming.org/Perft_Results
+pub const BENCH_POSITIONS: &[&str] = &[
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+    "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+    "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4",
+    "rnbqkb1r/pp3ppp/4pn2/2pp4/2PP4/2N2N2/PP2PPPP/R1BQKB1R w KQkq - 0 5",
+    "2rq1rk1/pp1bppbp/2np1np1/8/3NP3/1BN1BP2/PPPQ2PP/2KR3R b - - 0 11",
+    "r1b2rk1/2q1b1pp/p2ppn2/1p6/3QP3/1BN1B3/PPP3PP/R4RK1 w - - 0 14",
+    "6k1/6p1/6Pp/ppp5/3pn2P/1P3K2/1PP2P2/8 b - - 3 54",
+    "8/8/1p1k2p1/p1prp2p/P2n3P/6P1/1P1R1PK1/4R3 b - - 5 49",
+    "8/3k4/8/8/8/8/3K4/3R4 w - - 0 1",
+    "4k3/8/8/8/8/8/4P3/4K3 w - - 0 1",
+];
+
+/// Playouts are searched until their average depth reaches this value: unlike
+/// a node limit, the node count changes whenever the search behavior does.
+pub const BENCH_DEPTH: u32 = 6;
+
+/// Searches each position until [`BENCH_DEPTH`] and returns the total number
+/// of nodes and the time spent. The number of nodes is the signature of the
+/// search: it only changes when the search behavior changes. The root
+/// shortcuts ([`Limits::shortcuts`]) are disabled, so the forced moves and
+/// mates in one are searched like any other position.
+///
+/// # Errors
+///
+/// If a position is invalid or the search fails.
+pub fn bench(
+    positions: &[&str],
+    depth: u32,
+    evaluator: &dyn Evaluator,
+) -> anyhow::Result<(u64, Duration)> {
+    let limits = Limits {
+        depth: Some(depth),
+        ..Limits::default()
+    };
+    let config = mcts::Config::default();
+    let stop = AtomicBool::new(false);
+    let mut nodes = 0;
+    let mut elapsed = Duration::ZERO;
+    for fen in positions {
+        let position = Position::from_fen(fen)?;
+        let result = mcts::search(&position, &limits, &config, evaluator, &stop)?;
+        nodes += result.nodes;
+        elapsed += result.elapsed;
+    }
+    Ok((nodes, elapsed))
+}
+
 /// Runs search on a small set of positions to provide an estimate of engine's
-/// performance.
+/// performance and prints the number of nodes (the signature used to detect
+/// functional changes) and the speed in `<nodes> nodes <nps> nps` format.
 ///
 /// Implementing `bench` CLI command is a [requirement for OpenBench].
 ///
@@ -185,8 +1081,1061 @@ impl<'a, R: BufRead, W: Write> Engine<'a, R, W> {
 /// more details.
 ///
 /// [requirement for OpenBench]: https://github.com/AndyGrant/OpenBench/wiki/Requirements-For-Public-Engines#basic-requirements
-pub fn openbench() {
-    todo!()
+///
+/// # Errors
+///
+/// If the search fails.
+pub fn openbench() -> anyhow::Result<()> {
+    let (nodes, elapsed) = bench(BENCH_POSITIONS, BENCH_DEPTH, &Pesto)?;
+    println!("{nodes} nodes {} nps", search::nps(nodes, elapsed));
+    Ok(())
 }
 
 // TODO: Add extensive test suite for the UCI protocol implementation.
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufReader, BufWriter};
+    use std::sync::mpsc;
+    use std::thread::{self, JoinHandle};
+    use std::time::Instant;
+
+    use super::platform::{ChannelInput, SharedOutput};
+    use super::searcher::SHUTDOWN_TIMEOUT;
+    use super::*;
+
+    /// Engine running in a separate thread and communicating with the test
+    /// the same way it would with a UCI server.
+    struct Session {
+        input: mpsc::Sender<String>,
+        output: SharedOutput,
+        engine: JoinHandle<anyhow::Result<()>>,
+    }
+
+    impl Session {
+        fn start() -> Self {
+            Self::with_platform(Platform::default())
+        }
+
+        fn with_platform(platform: Platform) -> Self {
+            let (input, commands) = ChannelInput::new();
+            let output = SharedOutput::default();
+            let engine = {
+                let output = output.clone();
+                thread::spawn(move || {
+                    let mut commands = BufReader::new(commands);
+                    let mut engine = Engine::new(&mut commands, output).with_platform(platform);
+                    engine.uci_loop()
+                })
+            };
+            Self {
+                input,
+                output,
+                engine,
+            }
+        }
+
+        fn send(&self, command: &str) {
+            self.input.send(format!("{command}\n")).unwrap();
+        }
+
+        /// Waits until the output contains given pattern and returns it.
+        fn wait_for(&self, pattern: &str) -> String {
+            let deadline = Instant::now() + Duration::from_secs(30);
+            loop {
+                let output = self.output.contents();
+                if output.contains(pattern) {
+                    return output;
+                }
+                assert!(Instant::now() < deadline, "{pattern} not found in {output}");
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        /// Waits until the pattern occurs `count` times in the output and
+        /// returns it.
+        fn wait_for_count(&self, pattern: &str, count: usize) -> String {
+            let deadline = Instant::now() + Duration::from_secs(30);
+            loop {
+                let output = self.output.contents();
+                if output.matches(pattern).count() >= count {
+                    return output;
+                }
+                assert!(Instant::now() < deadline, "{pattern} not found in {output}");
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+
+        /// Closes the input stream and returns the output after the engine
+        /// exits.
+        fn finish(self) -> String {
+            drop(self.input);
+            self.engine.join().unwrap().expect("engine should not fail");
+            self.output.contents()
+        }
+    }
+
+    fn run(commands: &str) -> String {
+        let session = Session::start();
+        for command in commands.lines() {
+            session.send(command);
+        }
+        let _ = session.wait_for("bestmove");
+        session.finish()
+    }
+
+    #[test]
+    fn go_mate() {
+        let output = run("position fen k7/8/2K5/8/8/8/8/7R w - - 0 1\ngo mate 2");
+        assert!(output.contains("score mate 2"), "{output}");
+        assert!(output.contains("bestmove"), "{output}");
+
+        // The side to move has no mate: the search stops right away.
+        let output = run("position fen k7/8/1K6/8/8/8/8/7R b - - 0 1\ngo mate 1");
+        assert!(output.contains("nodes 1 "), "{output}");
+        assert!(output.ends_with("bestmove a8b8\n"), "{output}");
+
+        // The search does not run forever when there is no mate.
+        let output = run("position fen k7/8/8/8/8/8/8/K6R w - - 0 1\ngo mate 2");
+        assert!(!output.contains("score mate"), "{output}");
+        assert!(output.contains("bestmove"), "{output}");
+    }
+
+    #[test]
+    fn chess960_castling() {
+        // Only the rooks on their new squares can make the last two moves.
+        let output = run(
+            "setoption name UCI_Chess960 value true\nposition fen r3k2r/8/8/8/8/8/8/R3K2R w \
+             KQkq - 0 1 moves e1h1 e8a8 f1f2 d8d2\nposition startpos moves e2e4 e7e5 e1g1\ngo \
+             nodes 1",
+        );
+        let rejected: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("info string Rejected position: "))
+            .collect();
+        assert_eq!(
+            rejected,
+            ["castling e1g1 should be encoded as e1h1 with UCI_Chess960"],
+            "{output}"
+        );
+        // Chess960 starting arrays come in Shredder-FEN: only the king and
+        // the rook on their new squares can make the last two moves.
+        let output = run("setoption name UCI_Chess960 value true\nposition fen \
+             1n2rkr1/pppppppp/8/8/8/8/PPPPPPPP/1N2RKR1 w GEge - 0 1 moves f1g1 f8e8 g1h1 \
+             d8e8\ngo nodes 1");
+        assert!(!output.contains("Rejected position"), "{output}");
+        assert!(output.contains("bestmove "), "{output}");
+
+        // The moves sent by the engine use the same encoding.
+        let policy =
+            "setoption name PolicyMoves value 20\nposition fen 4k3/8/8/8/8/8/8/4K2R w K - 0 1\ngo \
+             nodes 1";
+        let output = run(policy);
+        assert!(output.contains(" e1g1 "), "{output}");
+        assert!(!output.contains(" e1h1 "), "{output}");
+        let output = run(&format!("setoption name UCI_Chess960 value true\n{policy}"));
+        assert!(output.contains(" e1h1 "), "{output}");
+        assert!(!output.contains(" e1g1 "), "{output}");
+    }
+
+    #[test]
+    fn go_nodes_reproducible() {
+        // Only the moves and the node counts are compared: the search speed
+        // varies between the runs.
+        let summary = |output: String| {
+            output
+                .lines()
+                .filter_map(|line| {
+                    let pv = line.split_once(" pv ")?.1;
+                    let nodes = line
+                        .split_whitespace()
+                        .skip_while(|&token| token != "nodes");
+                    Some(format!("{} {pv}", nodes.take(2).join(" ")))
+                })
+                .chain(
+                    output
+                        .lines()
+                        .filter(|line| line.starts_with("bestmove"))
+                        .map(String::from),
+                )
+                .collect::<Vec<_>>()
+        };
+        // The clock is ignored with a node limit.
+        let commands =
+            "setoption name Threads value 1\nposition startpos moves e2e4\ngo nodes 300 wtime 1 btime 1";
+        let first = summary(run(commands));
+        assert_eq!(first.len(), 2, "{first:?}");
+        assert!(first[0].starts_with("nodes 300 "), "{first:?}");
+        assert_eq!(summary(run(commands)), first);
+
+        let output = run(
+            "setoption name NodesPerMove value 300\nposition startpos moves e2e4\ngo wtime 1 btime 1",
+        );
+        assert_eq!(summary(output), first);
+    }
+
+    #[test]
+    fn go_terminal() {
+        let output = run("position fen k6R/8/1K6/8/8/8/8/8 b - - 1 1\ngo nodes 10");
+        assert!(output.contains("score mate 0"), "{output}");
+        assert!(output.ends_with("bestmove 0000\n"), "{output}");
+    }
+
+    #[test]
+    fn invalid_position() {
+        let output = run("position startpos moves e2e4\nposition startpos moves e2e5\ngo nodes 50");
+        assert!(
+            output.contains("info string Rejected position: illegal move e2e5"),
+            "{output}"
+        );
+        // The previous position is kept: Black is to move.
+        let best_move = output
+            .lines()
+            .find_map(|line| line.strip_prefix("bestmove "))
+            .expect("search should finish");
+        assert!(matches!(&best_move[1..2], "7" | "8"), "{output}");
+
+        for command in [
+            "position fen 8/8/8/8/8/8/8/8 w - - 0 1",
+            "position fen k7/8/1K6/8/8/8/8/7R x - - 0 1",
+            "position startpos moves e2e4 e7",
+            "position fen k7/8/1K6/8/8/8/8/7R w - - 0 1 moves h1h8 a8b8",
+        ] {
+            let output = run(&format!("{command}\ngo nodes 10"));
+            assert!(output.contains("info string Rejected position"), "{output}");
+            assert!(output.contains("bestmove "), "{output}");
+        }
+    }
+
+    #[test]
+    fn position_fen_fields() {
+        // GUIs may send the 4-field FEN: the clocks default to "0 1".
+        for (command, fen) in [
+            (
+                "position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -",
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            ),
+            (
+                "position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - moves g1f3 g8f6",
+                "rnbqkb1r/pppppppp/5n2/8/8/5N2/PPPPPPPP/RNBQKB1R w KQkq - 2 2",
+            ),
+            (
+                "position fen r3k2r/8/8/8/8/8/8/R3K2R b Kq - 7 40 moves e8c8",
+                "2kr3r/8/8/8/8/8/8/R3K2R w K - 8 41",
+            ),
+            (
+                "position fen 8/8/8/8/8/2k5/1P6/K7 w - - moves b2b4",
+                "8/8/8/8/1P6/2k5/8/K7 b - b3 0 1",
+            ),
+        ] {
+            let output = run(&format!("{command}\nhash\ngo nodes 10"));
+            assert!(!output.contains("Rejected position"), "{output}");
+            assert!(output.contains(&format!(" fen {fen}\n")), "{output}");
+        }
+        // The halfmove clock without the fullmove counter is ambiguous.
+        let output = run("position fen 8/8/8/8/8/2k5/1P6/K7 w - - 0\ngo nodes 10");
+        assert!(output.contains("info string Rejected position"), "{output}");
+    }
+
+    #[test]
+    fn repetition_history() {
+        let moves = ["e2e4", "e7e5", "g1f3", "g8f6", "f3g1"].map(String::from);
+        let (position, history) = setup_position(None, &moves, false).unwrap();
+        // The history starts after the last pawn move.
+        assert_eq!(history.len(), 3);
+        let (after_pawn_move, _) = setup_position(None, &moves[..2], false).unwrap();
+        assert_eq!(history[0], after_pawn_move.hash());
+        assert_eq!(position.halfmove_clock(), 3);
+        let (_, history) = setup_position(None, &moves[..2], false).unwrap();
+        assert!(history.is_empty());
+    }
+
+    fn bestmove(output: &str) -> &str {
+        output
+            .lines()
+            .find_map(|line| line.strip_prefix("bestmove "))
+            .and_then(|line| line.split_whitespace().next())
+            .expect("search should finish")
+    }
+
+    #[test]
+    fn threefold_repetition() {
+        // The kings shuffle between g1-h1 and g8-h8: Black is lost, but h8g8
+        // repeats the starting position for the third time.
+        let output = run(
+            "position fen 6k1/8/8/8/8/8/5PPP/3Q2K1 w - - 0 1 moves g1h1 g8h8 \
+             h1g1 h8g8 g1h1 g8h8 h1g1\ngo nodes 2000",
+        );
+        assert_eq!(bestmove(&output), "h8g8", "{output}");
+        assert!(output.contains("score cp 0 "), "{output}");
+        // Without the history the same position is simply lost.
+        let output = run("position fen 7k/8/8/8/8/8/5PPP/3Q2K1 b - - 7 4\ngo nodes 2000");
+        assert!(!output.contains("score cp 0 "), "{output}");
+
+        // The same shuffle with Black starting: White is winning and has to
+        // avoid h1g1 which repeats the position for the third time.
+        let output = run(
+            "position fen 6k1/8/8/8/8/8/5PPP/3Q2K1 b - - 0 1 moves g8h8 g1h1 \
+             h8g8 h1g1 g8h8 g1h1 h8g8\ngo nodes 2000",
+        );
+        assert_ne!(bestmove(&output), "h1g1", "{output}");
+        let output = run("position fen 6k1/8/8/8/8/8/5PPP/3Q3K w - - 7 4\ngo nodes 2000\n");
+        assert!(!output.contains("score cp 0 "), "{output}");
+    }
+
+    /// Reduced version of [`openbench`]. The expected number of nodes should
+    /// only be updated when the search behavior is changed intentionally.
+    #[test]
+    #[cfg_attr(
+        feature = "pseudo-legal",
+        ignore = "the pseudo-legal generator changes the move order"
+    )]
+    fn bench_signature() {
+        let (nodes, _) = bench(&BENCH_POSITIONS[..6], 3, &Pesto).unwrap();
+        assert_eq!(nodes, 5338);
+    }
+
+    #[test]
+    fn go_nodes() {
+        let output = run("position startpos moves e2e4\ngo nodes 50");
+        assert!(output.contains("score cp"), "{output}");
+        assert!(output.contains("nodes 50 "), "{output}");
+        assert!(output.contains("bestmove"), "{output}");
+    }
+
+    #[test]
+    fn warmup() {
+        let output = run("ucinewgame\nisready\ngo nodes 1");
+        assert!(!output.contains("Warmup"), "{output}");
+
+        let output = run(concat!(
+            "setoption name Warmup value true\nisready\nucinewgame\nisready\nisready\n",
+            "go nodes 1"
+        ));
+        assert_eq!(
+            output.matches("info string Warmup searched").count(),
+            1,
+            "{output}"
+        );
+        assert_eq!(output.matches("readyok").count(), 3, "{output}");
+        let warmup = output.find("Warmup searched").unwrap();
+        // The warmup finishes before the second readyok.
+        assert_eq!(output[..warmup].matches("readyok").count(), 1, "{output}");
+    }
+
+    #[test]
+    fn search_stats() {
+        let output = run("go nodes 10");
+        assert!(!output.contains("info string {"), "{output}");
+
+        let output = run("setoption name SearchStats value true\ngo nodes 10");
+        let json = output
+            .lines()
+            .find_map(|line| line.strip_prefix("info string {"))
+            .expect("search stats should be reported");
+        assert!(json.contains("\"nodes\":10,"), "{json}");
+        assert!(json.contains("\"root\":[{\"move\":"), "{json}");
+    }
+
+    #[test]
+    fn analyse_mode() {
+        let output = run("position startpos\ngo nodes 100");
+        assert!(!output.contains("multipv"), "{output}");
+
+        let output =
+            run("setoption name UCI_AnalyseMode value true\nposition startpos\ngo nodes 100");
+        assert!(output.contains(" multipv 1 "), "{output}");
+        assert!(output.contains(" multipv 2 "), "{output}");
+        assert_eq!(output.matches("bestmove").count(), 1);
+        // The search continues after the mate is found.
+        let output = run(
+            "setoption name UCI_AnalyseMode value true\nposition fen k7/8/1K6/8/8/8/8/7R w - - 0 \
+             1\ngo nodes 300",
+        );
+        assert!(
+            output.contains("multipv 1 score mate 1 nodes 300 "),
+            "{output}"
+        );
+        assert!(output.contains("bestmove h1h8"), "{output}");
+    }
+
+    #[test]
+    fn show_wdl() {
+        let output = run("go nodes 10");
+        assert!(!output.contains(" wdl "), "{output}");
+
+        let output = run("setoption name UCI_ShowWDL value true\ngo nodes 10");
+        let line = output
+            .lines()
+            .find(|line| line.starts_with("info depth"))
+            .expect("search results should be reported");
+        let wdl: Vec<i32> = line
+            .split_once(" wdl ")
+            .expect("wdl should be reported")
+            .1
+            .split_whitespace()
+            .take(3)
+            .map(|value| value.parse().unwrap())
+            .collect();
+        assert_eq!(wdl.iter().sum::<i32>(), 1000, "{line}");
+    }
+
+    #[test]
+    fn policy_moves() {
+        let output = run("go nodes 10");
+        assert!(!output.contains("Policy moves"), "{output}");
+
+        // Pesto does not have a policy: the first moves are equally likely.
+        let output = run("setoption name PolicyMoves value 3
+go nodes 10");
+        let line = output
+            .lines()
+            .find(|line| line.starts_with("info string Policy moves: "))
+            .expect("policy moves should be reported");
+        assert_eq!(line.matches(" 5.0%").count(), 3, "{line}");
+        assert!(output.find(line) < output.find("bestmove"), "{output}");
+
+        // Checkmate: no moves to report.
+        let output = run("setoption name PolicyMoves value 3
+position fen R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 \
+             1\ngo nodes 10");
+        assert!(!output.contains("Policy moves"), "{output}");
+        assert!(output.contains("bestmove 0000"), "{output}");
+    }
+
+    #[test]
+    fn responsive_during_search() {
+        let session = Session::start();
+        session.send("go infinite");
+        session.send("isready");
+        let _ = session.wait_for("readyok");
+        session.send("stop");
+        let output = session.wait_for("bestmove");
+        assert_eq!(output.matches("bestmove").count(), 1);
+        assert_eq!(session.finish().matches("bestmove").count(), 1);
+    }
+
+    #[test]
+    fn quit_during_search() {
+        let start = Instant::now();
+        let session = Session::start();
+        session.send("go infinite");
+        thread::sleep(Duration::from_millis(50));
+        session.send("quit");
+        let output = session.finish();
+        assert!(output.contains("bestmove"), "{output}");
+        assert!(start.elapsed() < SHUTDOWN_TIMEOUT * 5);
+    }
+
+    #[test]
+    fn eof_during_search() {
+        let start = Instant::now();
+        let session = Session::start();
+        session.send("position startpos");
+        session.send("go wtime 3600000 btime 3600000");
+        let output = session.finish();
+        assert!(output.contains("bestmove"), "{output}");
+        assert!(start.elapsed() < SHUTDOWN_TIMEOUT * 5);
+    }
+
+    #[test]
+    fn new_search_stops_previous() {
+        let session = Session::start();
+        session.send("go infinite");
+        session.send("position fen k7/8/1K6/8/8/8/8/7R w - - 0 1");
+        session.send("go nodes 1000");
+        let output = session.wait_for("bestmove h1h8");
+        assert_eq!(output.matches("bestmove").count(), 2, "{output}");
+        let _ = session.finish();
+    }
+
+    #[test]
+    fn rapid_go_stop() {
+        let session = Session::start();
+        for _ in 0..50 {
+            session.send("go infinite");
+            session.send("stop");
+        }
+        session.send("isready");
+        let output = session.wait_for("readyok");
+        assert_eq!(output.matches("bestmove").count(), 50, "{output}");
+        let _ = session.finish();
+    }
+
+    #[test]
+    fn ponder_hit() {
+        let session = Session::start();
+        session.send("position fen k7/8/1K6/8/8/8/8/7R w - - 0 1");
+        session.send("go ponder wtime 1000 btime 1000");
+        // The mate is found quickly, but the best move is held back.
+        session.send("isready");
+        let _ = session.wait_for("readyok");
+        thread::sleep(Duration::from_millis(50));
+        assert!(!session.output.contents().contains("bestmove"));
+        session.send("ponderhit");
+        let _ = session.wait_for("bestmove h1h8");
+        // The statistics are reported after the best move is released.
+        let _ = session.wait_for("info string Ponder hit rate: 1/1 (100%)");
+        let _ = session.finish();
+
+        // After a ponder hit the search stops on its own.
+        let session = Session::start();
+        session.send("go ponder wtime 200 btime 200");
+        session.send("ponderhit");
+        let output = session.wait_for("bestmove");
+        assert_eq!(output.matches("bestmove").count(), 1, "{output}");
+        let _ = session.finish();
+    }
+
+    #[test]
+    fn responses_are_flushed() {
+        let (input, commands) = ChannelInput::new();
+        let sink = SharedOutput::default();
+        let engine = {
+            // Nothing reaches the sink unless the engine flushes the output.
+            let output = BufWriter::with_capacity(1 << 20, sink.clone());
+            thread::spawn(move || {
+                let mut commands = BufReader::new(commands);
+                Engine::new(&mut commands, output).uci_loop()
+            })
+        };
+        let wait_for = |pattern: &str| {
+            let deadline = Instant::now() + Duration::from_secs(30);
+            while !sink.contents().contains(pattern) {
+                assert!(Instant::now() < deadline, "{pattern} not flushed");
+                thread::sleep(Duration::from_millis(1));
+            }
+        };
+        // The input stays open, so the engine is waiting for the next
+        // command while the GUI is waiting for the response.
+        input.send("uci\n".to_string()).unwrap();
+        wait_for("uciok");
+        input.send("isready\n".to_string()).unwrap();
+        wait_for("readyok");
+        input.send("go nodes 100\n".to_string()).unwrap();
+        wait_for("bestmove");
+        drop(input);
+        engine.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn ponder_hit_deadline() {
+        let (platform, clock) = Platform::manual();
+        let session = Session::with_platform(platform);
+        session.send("go ponder wtime 60000 btime 60000");
+        // Pondering time is subtracted from the budget after the hit.
+        clock.advance(Duration::from_millis(10));
+        session.send("ponderhit");
+        let _ = session.wait_for("info string Ponder hit rate: 1/1 (100%)");
+        // The search only stops when the clock reaches the deadline.
+        thread::sleep(Duration::from_millis(50));
+        assert!(!session.output.contents().contains("bestmove"));
+        clock.advance(Duration::from_secs(60));
+        let output = session.wait_for("bestmove");
+        assert_eq!(output.matches("bestmove").count(), 1, "{output}");
+        let _ = session.finish();
+    }
+
+    #[test]
+    fn ponder_miss() {
+        let session = Session::start();
+        session.send("position startpos moves e2e4 e7e5");
+        session.send("go ponder wtime 1000 btime 1000");
+        session.send("stop");
+        let _ = session.wait_for("bestmove");
+        session.send("position startpos moves e2e4 c7c5");
+        session.send("go nodes 50");
+        session.send("isready");
+        let output = session.wait_for("readyok");
+        assert!(
+            output.contains("info string Ponder hit rate: 0/1 (0%)"),
+            "{output}"
+        );
+        let output = session.finish();
+        assert_eq!(output.matches("bestmove").count(), 2, "{output}");
+    }
+
+    #[test]
+    fn match_stats() {
+        let path = std::env::temp_dir().join(format!("pabi-stats-{}.json", std::process::id()));
+        let session = Session::start();
+        session.send(&format!(
+            "setoption name StatsFile value {}",
+            path.display()
+        ));
+        for game in ["e2e4", "d2d4"] {
+            session.send("ucinewgame");
+            session.send(&format!("position startpos moves {game}"));
+            session.send("go nodes 50");
+            let _ = session.wait_for("bestmove");
+        }
+        session.send("quit");
+        let _ = session.finish();
+        let report = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(report.starts_with("{\"games\":[{\"moves\":1,"), "{report}");
+        assert_eq!(report.matches("\"moves\":1,").count(), 2, "{report}");
+        assert!(report.contains("\"total\":{\"moves\":2,"), "{report}");
+    }
+
+    #[test]
+    fn config_file() {
+        let config = EngineConfig::parse(
+            "debug = true\n[options]\nMoveOverhead = 100\nHash = 16\nContempt = 20\nLogSan = true",
+            std::path::Path::new("."),
+        )
+        .unwrap();
+        let mut input = "uci\nsetoption name Contempt value 0\nquit\n".as_bytes();
+        let mut engine = Engine::new(&mut input, Vec::new())
+            .with_config(&config)
+            .unwrap();
+        assert!(engine.debug);
+        assert!(engine.log_san);
+        assert_eq!(engine.move_overhead, Duration::from_millis(100));
+        assert_eq!(engine.search_config.tree_memory, 16 << 20);
+        engine.uci_loop().unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+        // The configured values are announced as the defaults and can be
+        // changed with `setoption`.
+        assert!(
+            output.contains("option name Hash type spin default 16 min 1 "),
+            "{output}"
+        );
+        assert!(output.contains("option name MoveOverhead type spin default 100 "));
+        assert!(output.contains("option name LogSan type check default true"));
+        assert!(output.contains("option name Contempt type spin default 20 "));
+        assert_eq!(engine.search_config.contempt, 0);
+
+        let config =
+            EngineConfig::parse("[options]\nHash = \"auto\"", std::path::Path::new(".")).unwrap();
+        let mut input = "uci\nquit\n".as_bytes();
+        let mut engine = Engine::new(&mut input, Vec::new())
+            .with_config(&config)
+            .unwrap();
+        engine.uci_loop().unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+        let megabytes = engine.search_config.tree_memory >> 20;
+        assert!(
+            output.contains(&format!("option name Hash type spin default {megabytes} ")),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn eval_file() {
+        let path =
+            std::env::temp_dir().join(format!("pabi-eval-file-{}.safetensors", std::process::id()));
+        crate::evaluation::network::Network::random(0).save(&path);
+        let commands = format!(
+            "setoption name EvalFile value /nonexistent/network.safetensors\nisready\nsetoption \
+             name EvalFile value {}\ngo nodes 50\nquit\n",
+            path.display()
+        );
+        let mut input = commands.as_bytes();
+        let initial: Arc<dyn Evaluator> = Arc::new(Pesto);
+        let mut engine = Engine::new(&mut input, Vec::new()).with_evaluator(Arc::clone(&initial));
+        engine.uci_loop().unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+
+        // The failed load keeps the previous network.
+        let failed = output
+            .find("info string Failed to load network from /nonexistent/network.safetensors: ")
+            .expect(&output);
+        assert!(failed < output.find("readyok").unwrap(), "{output}");
+        // The new network is installed before the search starts.
+        let loaded = output
+            .find(&format!(
+                "info string Loaded network from {}\n",
+                path.display()
+            ))
+            .expect(&output);
+        assert!(loaded < output.find("bestmove").unwrap(), "{output}");
+        assert!(!Arc::ptr_eq(&engine.evaluator, &initial));
+        assert!(engine.pending_network.is_none());
+    }
+
+    #[test]
+    fn search_options() {
+        let mut input = "uci\nsetoption name CPuct value 250\nsetoption name FpuReduction value \
+                         30\nsetoption name PolicyTemperature value 1\nsetoption name MoveOverhead \
+                         value 5000\nsetoption name TimeExtension value 100\nsetoption name \
+                         BlendEndgame value 80\nsetoption name BlendKnownEndgame value \
+                         100\nsetoption name RootJitter value 100\nsetoption name CheckBoost value \
+                         250\nsetoption name RootJitterPlies value 8\nsetoption name \
+                         RootJitterSeed value 7\nsetoption name MaxPvLength value 1\nsetoption \
+                         name PolicyTemperature value 0\nsetoption name CheckBoost value \
+                         50\nsetoption name MaxPvLength value 1000\nsetoption name Contempt \
+                         value 30\nsetoption name WideningBase value 250\nsetoption name \
+                         WideningExponent value 50\nsetoption name RootPruning value \
+                         20\nsetoption name RootPruningVisits value 50\nquit\n"
+            .as_bytes();
+        let mut engine = Engine::new(&mut input, Vec::new());
+        engine.uci_loop().unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+        assert!(
+            output.contains("option name CPuct type spin default 150 min 0 max 10000"),
+            "{output}"
+        );
+        assert!(output.contains("option name FpuReduction type spin default 0 "));
+        assert!(output.contains("option name PolicyTemperature type spin default 100 "));
+        assert_eq!(engine.search_config.cpuct, 2.5);
+        assert_eq!(engine.search_config.fpu_reduction, 0.3);
+        // Out of range values are rejected and the previous ones are kept, e.g.
+        // zero temperature would divide by zero and checks are never
+        // penalized.
+        assert_eq!(engine.search_config.policy_temperature, 0.01);
+        assert!(output.contains(
+            "info string Invalid value for option PolicyTemperature: expected an integer between \
+             1 and 1000, got \"0\"\n"
+        ));
+        assert!(output.contains("option name CheckBoost type spin default 100 min 100 max 1000"));
+        assert_eq!(engine.search_config.check_prior_boost, 2.5);
+        assert!(output.contains("info string Invalid value for option CheckBoost: "));
+        assert!(output.contains("info string Invalid value for option MaxPvLength: "));
+        assert!(output.contains("option name MoveOverhead type spin default 50 min 0 max 5000"));
+        assert_eq!(engine.move_overhead, Duration::from_secs(5));
+        assert!(output.contains("option name TimeExtension type spin default 150 min 100 max 400"));
+        assert_eq!(engine.time_extension, 100);
+        assert!(output.contains("option name BlendMiddlegame type spin default 0 min 0 max 100"));
+        assert_eq!(
+            engine.blend,
+            BlendWeights {
+                middlegame: 0,
+                endgame: 80,
+                known_endgame: 100,
+            }
+        );
+        assert!(output.contains("option name RootJitter type spin default 0 min 0 max 100"));
+        assert_eq!(engine.search_config.root_jitter, 100);
+        assert_eq!(engine.search_config.root_jitter_plies, 8);
+        assert_eq!(engine.search_config.root_jitter_seed, 7);
+        assert!(output.contains("option name MaxPvLength type spin default 64 min 1 max 256"));
+        assert_eq!(engine.search_config.max_pv_length, 1);
+        assert!(output.contains("option name UCI_ShowWDL type check default false"));
+        assert!(output.contains("option name Contempt type spin default 0 min 0 max 200"));
+        assert_eq!(engine.search_config.contempt, 30);
+        assert!(output.contains("option name WideningBase type spin default 0 min 0 max 10000"));
+        assert!(output.contains("option name WideningExponent type spin default 40 min 0 max 100"));
+        assert_eq!(engine.search_config.widening_base, 2.5);
+        assert_eq!(engine.search_config.widening_exponent, 0.5);
+        assert!(output.contains("option name RootPruning type spin default 0 min 0 max 1000"));
+        assert!(output
+            .contains("option name RootPruningVisits type spin default 100 min 1 max 1000000"));
+        assert_eq!(engine.search_config.root_pruning, 20);
+        assert_eq!(engine.search_config.root_pruning_visits, 50);
+    }
+
+    #[test]
+    fn hash() {
+        let output = run("uci\nsetoption name Hash value 1\nposition startpos\ngo nodes 1");
+        assert!(
+            output.contains("option name Hash type spin default 256 min 1 max 1048576"),
+            "{output}"
+        );
+
+        // The full tree does not end the infinite search. The 1 MB tree holds
+        // about 20000 nodes and a playout in the opening adds a few dozen, so
+        // it is full long before this many playouts.
+        let playouts = 5000;
+        let session = Session::start();
+        session.send("setoption name Hash value 1");
+        session.send("position startpos");
+        session.send("go infinite");
+        let deadline = Instant::now() + Duration::from_secs(60);
+        loop {
+            let output = session.output.contents();
+            assert!(!output.contains("bestmove"), "{output}");
+            let nodes = output
+                .split_whitespace()
+                .tuple_windows()
+                .filter(|&(token, _)| token == "nodes")
+                .map(|(_, nodes)| nodes.parse::<u64>().unwrap())
+                .last()
+                .unwrap_or(0);
+            if nodes > playouts {
+                break;
+            }
+            assert!(Instant::now() < deadline, "{output}");
+            thread::sleep(Duration::from_millis(10));
+        }
+        session.send("stop");
+        let _ = session.wait_for("bestmove");
+        session.finish();
+
+        let mut input = "setoption name Hash value auto\nquit\n".as_bytes();
+        let mut engine = Engine::new(&mut input, Vec::new());
+        engine.uci_loop().unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+        assert!(output.starts_with("info string Hash set to "), "{output}");
+        assert!(engine.search_config.tree_memory >= 1 << 20);
+    }
+
+    #[test]
+    fn thread_affinity() {
+        /// Runs the tasks in a [`platform::ThreadPool`] and records how it is
+        /// configured.
+        #[derive(Default)]
+        struct Recording {
+            pool: platform::ThreadPool,
+            configured: Mutex<Vec<(usize, bool)>>,
+        }
+
+        impl platform::Spawner for Recording {
+            fn spawn(
+                &self,
+                name: &str,
+                task: platform::Task,
+            ) -> std::io::Result<platform::TaskHandle> {
+                self.pool.spawn(name, task)
+            }
+
+            fn configure(&self, threads: usize, affinity: bool) {
+                self.configured.lock().unwrap().push((threads, affinity));
+                self.pool.configure(threads, affinity);
+            }
+        }
+
+        let spawner = Arc::new(Recording::default());
+        let platform = Platform {
+            spawner: spawner.clone(),
+            ..Platform::default()
+        };
+        let mut input = "uci\nsetoption name ThreadAffinity value true\nsetoption name Threads \
+                         value 1\nposition startpos\ngo nodes 10\nisready\nquit\n"
+            .as_bytes();
+        let mut engine = Engine::new(&mut input, Vec::new()).with_platform(platform);
+        engine.uci_loop().unwrap();
+        let output = String::from_utf8(engine.out().get_ref().clone()).unwrap();
+        assert!(
+            output.contains("option name ThreadAffinity type check default false"),
+            "{output}"
+        );
+        assert!(output.contains("bestmove "), "{output}");
+        // The search threads are pinned with the single search thread, too.
+        assert_eq!(*spawner.configured.lock().unwrap(), [(1, true), (1, true)]);
+    }
+
+    #[test]
+    fn tablebase_root_moves() {
+        let output = run(concat!(
+            "setoption name SyzygyTablebase value ",
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/syzygy\nposition fen k7/8/2K5/8/8/8/8/1Q6 w - - 0 1\ngo nodes 1"
+        ));
+        assert!(
+            output.contains("info string Loaded tablebases with up to 3 pieces"),
+            "{output}"
+        );
+        assert!(
+            output.contains("info string tablebase hit: Win with "),
+            "{output}"
+        );
+        // The stalemate is never played, even with a tiny search.
+        assert!(!output.contains("bestmove b1b6"), "{output}");
+
+        let output = run("setoption name SyzygyTablebase value /nonexistent\ngo nodes 1");
+        assert!(
+            output.contains("info string Failed to load tablebases"),
+            "{output}"
+        );
+        assert!(!output.contains("tablebase hit"), "{output}");
+    }
+
+    #[test]
+    fn debug_position_changes() {
+        let output = run("position startpos\ndebug on\nposition startpos moves e2e4\ngo nodes 1");
+        assert!(
+            output.contains("info string Position changes: P e2-e4, side to move: w -> b, en passant: - -> e3\n"),
+            "{output}"
+        );
+        assert!(output.contains("info string   attacks "), "{output}");
+        assert!(
+            output.contains("info string Mobility: 20 moves (pawns 16, knights 4, "),
+            "{output}"
+        );
+        assert!(
+            output.contains("info string Hanging pieces: none\n"),
+            "{output}"
+        );
+        let output = run("position startpos moves e2e4\ngo nodes 1");
+        assert!(!output.contains("Position changes"), "{output}");
+        assert!(!output.contains("Mobility"), "{output}");
+        assert!(!output.contains("attacks"), "{output}");
+    }
+
+    #[test]
+    fn reuse_tree() {
+        let session = Session::start();
+        // Sum of the root move visits in the `rootstats` output after the
+        // `count`-th best move.
+        let root_visits = |count: usize| -> u32 {
+            session.send("rootstats");
+            let output = session.wait_for_count("info string Root moves after", count);
+            let stats = &output[output.rfind("Root moves after").unwrap()..];
+            stats
+                .lines()
+                .skip(1)
+                .filter_map(|line| line.strip_prefix("info string "))
+                .filter_map(|line| line.split_whitespace().nth(2)?.parse::<u32>().ok())
+                .sum()
+        };
+        session.send("position startpos");
+        session.send("go nodes 300");
+        let _ = session.wait_for_count("bestmove", 1);
+        // The search continues from the tree of the previous one.
+        session.send("go nodes 1");
+        let _ = session.wait_for_count("bestmove", 2);
+        assert_eq!(root_visits(1), 300);
+        // A new game starts from scratch.
+        session.send("ucinewgame");
+        session.send("go nodes 1");
+        let _ = session.wait_for_count("bestmove", 3);
+        assert_eq!(root_visits(2), 0);
+        drop(session.finish());
+    }
+
+    #[test]
+    fn root_stats() {
+        let session = Session::start();
+        session.send("rootstats");
+        let _ = session.wait_for("info string No finished search");
+        session.send("position fen k7/8/2K5/8/8/8/8/7R w - - 0 1");
+        session.send("go nodes 300");
+        let _ = session.wait_for("bestmove");
+        session.send("rootstats");
+        let output = session.finish();
+        let stats: Vec<&str> = output
+            .lines()
+            .skip_while(|line| !line.starts_with("bestmove"))
+            .filter_map(|line| line.strip_prefix("info string "))
+            .collect();
+        assert!(stats[0].starts_with("Root moves after "), "{output}");
+        // One line for each legal move, the most visited first.
+        assert_eq!(stats.len(), 1 + 21, "{output}");
+        let visits: Vec<u32> = stats[1..]
+            .iter()
+            .map(|line| {
+                line.split_whitespace()
+                    .nth(2)
+                    .and_then(|visits| visits.parse().ok())
+                    .expect("visits are reported")
+            })
+            .collect();
+        assert!(visits.windows(2).all(|pair| pair[0] >= pair[1]), "{output}");
+        assert!(stats[1].contains(" pv "), "{output}");
+    }
+
+    #[test]
+    fn root_stats_san() {
+        let session = Session::start();
+        session.send("setoption name LogSan value true");
+        session.send("position fen k7/8/1K6/8/8/8/8/7R w - - 0 1");
+        session.send("go nodes 300");
+        let _ = session.wait_for("bestmove");
+        session.send("rootstats");
+        let output = session.finish();
+        // The protocol lines are not affected.
+        assert!(output.contains("bestmove h1h8\n"), "{output}");
+        assert!(!output.contains("pv h1h8 ("), "{output}");
+        assert!(output.contains(", best move h1h8 (Rh8#):"), "{output}");
+        let mate = output
+            .lines()
+            .find(|line| line.starts_with("info string  h1h8 "))
+            .expect("all root moves are reported");
+        assert!(mate.ends_with(" pv h1h8 san 1. Rh8#"), "{output}");
+    }
+
+    #[test]
+    fn attacks() {
+        let output = run("position fen 4k3/8/8/8/8/8/4r3/4K3 w - - 0 1\nattacks\ngo nodes 1");
+        let grid: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("info string "))
+            .skip_while(|line| !line.starts_with("  attacks"))
+            .take(10)
+            .collect();
+        assert_eq!(grid.len(), 10, "{output}");
+        // The rook gives check from e2 and the king can capture it.
+        assert_eq!(
+            grid[7], "2 x x x x . x x x  2 . . . . x . . .  2 . . . . . . . .  2 . . . . x . . .",
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn single_legal_move() {
+        // The king is in check and can only capture the queen.
+        let start = Instant::now();
+        let output = run(
+            "position fen k7/1Q6/3K4/8/8/8/8/8 b - - 0 1\ngo wtime 600000 btime \
+                          600000",
+        );
+        assert!(output.ends_with("bestmove a8b7\n"), "{output}");
+        assert!(start.elapsed() < Duration::from_secs(1), "{output}");
+    }
+
+    #[test]
+    fn mate_in_one() {
+        let start = Instant::now();
+        let output = run(
+            "position fen 6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1\ngo wtime 600000 btime \
+                          600000",
+        );
+        assert!(output.contains("score mate 1"), "{output}");
+        assert!(output.ends_with("bestmove a1a8\n"), "{output}");
+        assert!(start.elapsed() < Duration::from_secs(1), "{output}");
+    }
+
+    #[test]
+    fn legal_moves() {
+        // The en passant capture would expose the king to the rook on h5.
+        let output = run("position fen 8/8/8/KPp4r/8/8/8/4k3 w - c6 0 2\nlegalmoves\ngo nodes 1");
+        let lines: Vec<&str> = output
+            .lines()
+            .filter_map(|line| line.strip_prefix("info string "))
+            .take(3)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "legal moves 4",
+                "Pawn 1: b5b6 (b6)",
+                "King 3: a5a4 (Ka4) a5a6 (Ka6) a5b6 (Kb6)",
+            ],
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn hash_command() {
+        let output = run(
+            "position startpos moves e2e4 e7e5 g1f3\nhash\nposition fen \
+                          8/8/8/8/8/8/8/k6K w - - 0 1\nhash\ngo nodes 1",
+        );
+        let mut expected = Position::starting();
+        for next_move in ["e2e4", "e7e5", "g1f3"] {
+            expected.make_move(&Move::from_uci(next_move).unwrap());
+        }
+        let hashes: Vec<&str> = output
+            .lines()
+            .filter(|line| line.starts_with("info string hash "))
+            .collect();
+        assert_eq!(
+            hashes,
+            [
+                format!(
+                    "info string hash {:016x} moves 3 fen {expected}",
+                    expected.hash()
+                ),
+                format!(
+                    "info string hash {:016x} moves 0 fen 8/8/8/8/8/8/8/k6K w - - 0 1",
+                    Position::from_fen("8/8/8/8/8/8/8/k6K w - - 0 1")
+                        .unwrap()
+                        .hash()
+                ),
+            ],
+            "{output}"
+        );
+    }
+}
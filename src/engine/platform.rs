@@ -0,0 +1,625 @@
+//! Services the engine takes from the environment it runs in: the clock, the
+//! threads and the input/output streams.
+//!
+//! [`Platform::default`] uses the real clock and OS threads. Embedders and
+//! tests can replace them, e.g. with [`ManualClock`] the time only moves when
+//! the test advances it, so the timers of the engine (pondering deadline,
+//! progress reports) fire exactly when the test expects them to.
+//!
+//! The default threads come from a [`ThreadPool`], which keeps them between
+//! the searches and can pin them to the CPU cores.
+
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Source of time for the engine timers.
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Instant;
+
+    /// Waits for roughly `duration` when polling for an event.
+    fn sleep(&self, duration: Duration);
+}
+
+/// Wall clock time.
+#[derive(Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Clock that only moves forward when [`ManualClock::advance`] is called.
+///
+/// Sleeping does not advance the time, it only yields to the other threads.
+/// Hence any wait for a deadline (including [`super::Searcher::stop`] waiting
+/// for an unresponsive search) blocks until the clock is advanced.
+#[derive(Debug)]
+pub struct ManualClock {
+    origin: Instant,
+    elapsed: Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// Creates the clock stopped at the current time.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Moves the time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.elapsed.lock().expect("clock should not be poisoned") += duration;
+    }
+}
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + *self.elapsed.lock().expect("clock should not be poisoned")
+    }
+
+    fn sleep(&self, _duration: Duration) {
+        thread::yield_now();
+    }
+}
+
+/// Work run in a background thread, e.g. the search.
+pub type Task = Box<dyn FnOnce() -> anyhow::Result<()> + Send>;
+
+/// Result of a [`Task`] or the panic payload if it panicked, same as
+/// [`thread::JoinHandle::join`].
+pub type Outcome = thread::Result<anyhow::Result<()>>;
+
+/// Waits for a task started by [`Spawner::spawn`]. Unlike
+/// [`thread::JoinHandle`], it is not tied to the thread, which can be reused
+/// for other tasks.
+pub struct TaskHandle {
+    outcome: Arc<(Mutex<Option<Outcome>>, Condvar)>,
+}
+
+impl TaskHandle {
+    /// Creates the handle and the [`Completion`] that runs the task.
+    fn new() -> (Self, Completion) {
+        let outcome = Arc::new((Mutex::new(None), Condvar::new()));
+        (
+            Self {
+                outcome: Arc::clone(&outcome),
+            },
+            Completion { outcome },
+        )
+    }
+
+    /// Returns true if the task is finished.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.outcome
+            .0
+            .lock()
+            .expect("task outcome should not be poisoned")
+            .is_some()
+    }
+
+    /// Waits for the task to finish and returns its result.
+    ///
+    /// # Errors
+    ///
+    /// If the task panicked.
+    pub fn join(self) -> Outcome {
+        let (outcome, finished) = &*self.outcome;
+        let mut outcome = finished
+            .wait_while(
+                outcome.lock().expect("task outcome should not be poisoned"),
+                |outcome| outcome.is_none(),
+            )
+            .expect("task outcome should not be poisoned");
+        outcome.take().expect("task should be finished")
+    }
+}
+
+/// Runs the task and reports the outcome to its [`TaskHandle`].
+struct Completion {
+    outcome: Arc<(Mutex<Option<Outcome>>, Condvar)>,
+}
+
+impl Completion {
+    fn run(self, task: Task) {
+        // The panic is reported to the handle instead of killing the pooled
+        // thread.
+        let result = panic::catch_unwind(AssertUnwindSafe(task));
+        let (outcome, finished) = &*self.outcome;
+        *outcome.lock().expect("task outcome should not be poisoned") = Some(result);
+        finished.notify_all();
+    }
+}
+
+/// Starts the background threads of the engine.
+pub trait Spawner: Send + Sync {
+    /// Runs `task` in a background thread called `name` (or a reused one).
+    ///
+    /// # Errors
+    ///
+    /// If the thread can not be created.
+    fn spawn(&self, name: &str, task: Task) -> io::Result<TaskHandle>;
+
+    /// Keeps up to `threads` idle threads for the following tasks and pins
+    /// them to the CPU cores if `affinity` is set (`Threads` and
+    /// `ThreadAffinity` options). Spawners that create a new thread for each
+    /// task ignore it.
+    fn configure(&self, _threads: usize, _affinity: bool) {}
+}
+
+/// Spawns a new OS thread for each task.
+#[derive(Debug, Default)]
+pub struct ThreadSpawner;
+
+impl Spawner for ThreadSpawner {
+    fn spawn(&self, name: &str, task: Task) -> io::Result<TaskHandle> {
+        let (handle, completion) = TaskHandle::new();
+        let _ = thread::Builder::new()
+            .name(name.to_string())
+            .spawn(move || completion.run(task))?;
+        Ok(handle)
+    }
+}
+
+/// Pins the current thread to the CPU core `index` (modulo the number of
+/// cores), so that the search keeps its caches and stays on the same NUMA
+/// node. Returns false if the platform does not support it.
+#[must_use]
+pub fn pin_to_core(index: usize) -> bool {
+    match core_affinity::get_core_ids() {
+        Some(cores) if !cores.is_empty() => {
+            core_affinity::set_for_current(cores[index % cores.len()])
+        },
+        _ => false,
+    }
+}
+
+enum Job {
+    Run(Task, Completion),
+    /// Sent to the idle threads when the pool shrinks.
+    Exit,
+}
+
+struct PoolState {
+    /// Maximum number of idle threads.
+    size: usize,
+    /// Number of the threads waiting for a job.
+    idle: usize,
+    /// Number of the threads created so far, the next one is pinned to this
+    /// core.
+    created: usize,
+    affinity: bool,
+}
+
+/// Reuses the threads between the tasks, so that starting a search does not
+/// pay for creating a thread. Up to `size` threads wait for the next task.
+/// When they are all busy (e.g. the previous search was abandoned and still
+/// runs), a new thread is created and it only joins the pool if there is room
+/// after finishing the task.
+pub struct ThreadPool {
+    jobs: mpsc::Sender<Job>,
+    receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+    state: Arc<Mutex<PoolState>>,
+}
+
+impl ThreadPool {
+    /// Creates the pool without starting any threads: they are created by the
+    /// first tasks.
+    #[must_use]
+    pub fn new(size: usize, affinity: bool) -> Self {
+        let (jobs, receiver) = mpsc::channel();
+        Self {
+            jobs,
+            receiver: Arc::new(Mutex::new(receiver)),
+            state: Arc::new(Mutex::new(PoolState {
+                size,
+                idle: 0,
+                created: 0,
+                affinity,
+            })),
+        }
+    }
+
+    /// Returns the number of threads waiting for a task.
+    #[must_use]
+    pub fn idle(&self) -> usize {
+        self.lock().idle
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, PoolState> {
+        self.state
+            .lock()
+            .expect("pool state should not be poisoned")
+    }
+}
+
+impl Default for ThreadPool {
+    fn default() -> Self {
+        Self::new(1, false)
+    }
+}
+
+impl Spawner for ThreadPool {
+    fn spawn(&self, name: &str, task: Task) -> io::Result<TaskHandle> {
+        let (handle, completion) = TaskHandle::new();
+        let mut state = self.lock();
+        if state.idle > 0 {
+            state.idle -= 1;
+            // The pool owns the receiver, so sending can not fail.
+            let _ = self.jobs.send(Job::Run(task, completion));
+            return Ok(handle);
+        }
+        let index = state.created;
+        state.created += 1;
+        let pinned = state.affinity;
+        drop(state);
+        let receiver = Arc::clone(&self.receiver);
+        let state = Arc::clone(&self.state);
+        let _ = thread::Builder::new()
+            .name(format!("{name} {index}"))
+            .spawn(move || {
+                if pinned {
+                    let _ = pin_to_core(index);
+                }
+                completion.run(task);
+                loop {
+                    {
+                        let mut state = state.lock().expect("pool state should not be poisoned");
+                        if state.idle >= state.size || state.affinity != pinned {
+                            return;
+                        }
+                        state.idle += 1;
+                    }
+                    let job = receiver
+                        .lock()
+                        .expect("pool jobs should not be poisoned")
+                        .recv();
+                    match job {
+                        Ok(Job::Run(task, completion)) => completion.run(task),
+                        // The pool is dropped or shrinked.
+                        Ok(Job::Exit) | Err(_) => return,
+                    }
+                }
+            })?;
+        Ok(handle)
+    }
+
+    /// Resizes the pool. Changing the affinity replaces the idle threads, so
+    /// that the new setting applies to the next task.
+    fn configure(&self, threads: usize, affinity: bool) {
+        let mut state = self.lock();
+        let keep = if state.affinity == affinity {
+            threads
+        } else {
+            0
+        };
+        state.size = threads;
+        state.affinity = affinity;
+        while state.idle > keep {
+            state.idle -= 1;
+            let _ = self.jobs.send(Job::Exit);
+        }
+    }
+}
+
+/// Clock and threads used by [`super::Engine`] and [`super::Searcher`].
+#[derive(Clone)]
+pub struct Platform {
+    pub clock: Arc<dyn Clock>,
+    pub spawner: Arc<dyn Spawner>,
+}
+
+impl Platform {
+    /// Real threads with a [`ManualClock`] controlled by the caller.
+    #[must_use]
+    pub fn manual() -> (Self, Arc<ManualClock>) {
+        let clock = Arc::new(ManualClock::new());
+        let shared: Arc<dyn Clock> = clock.clone();
+        let platform = Self {
+            clock: shared,
+            spawner: Arc::new(ThreadSpawner),
+        };
+        (platform, clock)
+    }
+}
+
+impl Default for Platform {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            spawner: Arc::new(ThreadPool::default()),
+        }
+    }
+}
+
+/// Input stream that blocks until the next command is sent, like stdin.
+/// Dropping the sender closes the stream. Wrap it in [`std::io::BufReader`]
+/// to pass it to [`super::Engine::new`].
+#[derive(Debug)]
+pub struct ChannelInput {
+    receiver: mpsc::Receiver<String>,
+    pending: Vec<u8>,
+}
+
+impl ChannelInput {
+    /// Creates the stream and the sender of its commands. Each command should
+    /// end with a newline.
+    #[must_use]
+    pub fn new() -> (mpsc::Sender<String>, Self) {
+        let (sender, receiver) = mpsc::channel();
+        let input = Self {
+            receiver,
+            pending: Vec::new(),
+        };
+        (sender, input)
+    }
+}
+
+impl Read for ChannelInput {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() {
+            match self.receiver.recv() {
+                Ok(line) => self.pending = line.into_bytes(),
+                Err(_) => return Ok(0),
+            }
+        }
+        let size = buf.len().min(self.pending.len());
+        buf[..size].copy_from_slice(&self.pending[..size]);
+        let _ = self.pending.drain(..size);
+        Ok(size)
+    }
+}
+
+/// Output stream that sends each complete line (without the newline) to the
+/// receiver, e.g. to forward the responses to a network client.
+#[derive(Debug)]
+pub struct ChannelOutput {
+    sender: mpsc::Sender<String>,
+    buffer: Vec<u8>,
+}
+
+impl ChannelOutput {
+    /// Creates the stream and the receiver of its lines.
+    #[must_use]
+    pub fn new() -> (Self, mpsc::Receiver<String>) {
+        let (sender, receiver) = mpsc::channel();
+        let output = Self {
+            sender,
+            buffer: Vec::new(),
+        };
+        (output, receiver)
+    }
+}
+
+impl Write for ChannelOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        while let Some(end) = self.buffer.iter().position(|&byte| byte == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            // The receiver might have stopped listening already.
+            let _ = self
+                .sender
+                .send(String::from_utf8_lossy(&line[..end]).into_owned());
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Output stream that can be inspected while the engine is writing to it.
+#[derive(Clone, Debug, Default)]
+pub struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+impl SharedOutput {
+    /// Returns everything written so far.
+    #[must_use]
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().expect("output should not be poisoned")).into_owned()
+    }
+}
+
+impl Write for SharedOutput {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .lock()
+            .expect("output should not be poisoned")
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Flushes the underlying stream after each complete line. The engine writes
+/// all UCI responses through it: a GUI talking to the engine over a pipe
+/// blocks waiting for `readyok` or `bestmove`, so the responses can not stay
+/// in a buffer until the next one arrives.
+#[derive(Debug)]
+pub struct UciOutput<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> UciOutput<W> {
+    /// Wraps the stream the responses are sent to.
+    #[must_use]
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying stream.
+    #[must_use]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+}
+
+impl<W: Write> Write for UciOutput<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        if buf[..written].contains(&b'\n') {
+            self.inner.flush()?;
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, BufWriter};
+
+    use super::*;
+
+    #[test]
+    fn manual_clock() {
+        let clock = ManualClock::new();
+        let start = clock.now();
+        clock.sleep(Duration::from_secs(1));
+        assert_eq!(clock.now(), start);
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(clock.now() - start, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn channel_input() {
+        let (sender, input) = ChannelInput::new();
+        sender.send("uci\n".to_string()).unwrap();
+        sender.send("isready\n".to_string()).unwrap();
+        drop(sender);
+        let lines: Vec<String> = BufReader::new(input).lines().map(Result::unwrap).collect();
+        assert_eq!(lines, ["uci", "isready"]);
+    }
+
+    #[test]
+    fn channel_output() {
+        let (mut output, lines) = ChannelOutput::new();
+        write!(output, "bestmove").unwrap();
+        writeln!(output, " e2e4\nreadyok").unwrap();
+        drop(output);
+        assert_eq!(
+            lines.iter().collect::<Vec<_>>(),
+            ["bestmove e2e4", "readyok"]
+        );
+    }
+
+    #[test]
+    fn spawner() {
+        let handle = ThreadSpawner
+            .spawn(
+                "worker",
+                Box::new(|| {
+                    assert_eq!(thread::current().name(), Some("worker"));
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        handle.join().unwrap().unwrap();
+    }
+
+    #[test]
+    fn thread_pool() {
+        let pool = ThreadPool::new(2, false);
+        let run = |pool: &ThreadPool| {
+            pool.spawn("worker", Box::new(|| Ok(())))
+                .unwrap()
+                .join()
+                .unwrap()
+                .unwrap();
+        };
+        let wait_for_idle = |pool: &ThreadPool, idle: usize| {
+            while pool.idle() != idle {
+                thread::yield_now();
+            }
+        };
+
+        // The thread is reused by the following tasks.
+        run(&pool);
+        wait_for_idle(&pool, 1);
+        let name = pool
+            .spawn(
+                "other",
+                Box::new(|| {
+                    assert_eq!(thread::current().name(), Some("worker 0"));
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        assert!(name.join().unwrap().is_ok());
+        wait_for_idle(&pool, 1);
+
+        // Busy threads do not block new tasks.
+        let (release, blocked) = mpsc::channel::<()>();
+        let busy = pool
+            .spawn(
+                "worker",
+                Box::new(move || {
+                    let _ = blocked.recv();
+                    Ok(())
+                }),
+            )
+            .unwrap();
+        assert_eq!(pool.idle(), 0);
+        run(&pool);
+        assert!(!busy.is_finished());
+        drop(release);
+        busy.join().unwrap().unwrap();
+        wait_for_idle(&pool, 2);
+
+        pool.configure(1, false);
+        assert_eq!(pool.idle(), 1);
+        pool.configure(1, true);
+        assert_eq!(pool.idle(), 0);
+
+        // Errors and panics are reported to the handle.
+        let failed = pool
+            .spawn("worker", Box::new(|| anyhow::bail!("failed")))
+            .unwrap();
+        assert!(failed.join().unwrap().is_err());
+        let panicked = pool
+            .spawn("worker", Box::new(|| panic!("search failed")))
+            .unwrap();
+        assert!(panicked.join().is_err());
+        wait_for_idle(&pool, 1);
+    }
+
+    #[test]
+    fn uci_output() {
+        let sink = SharedOutput::default();
+        let mut out = UciOutput::new(BufWriter::with_capacity(1 << 16, sink.clone()));
+        write!(out, "bestmove").unwrap();
+        assert_eq!(sink.contents(), "");
+        writeln!(out, " e2e4").unwrap();
+        assert_eq!(sink.contents(), "bestmove e2e4\n");
+        writeln!(out, "info string a\ninfo string b").unwrap();
+        assert_eq!(
+            sink.contents(),
+            "bestmove e2e4\ninfo string a\ninfo string b\n"
+        );
+    }
+}
@@ -0,0 +1,931 @@
+//! Runs the search in the background while the engine keeps processing
+//! commands.
+
+use std::io::Write;
+use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::chess::core::Move;
+use crate::chess::position::Position;
+#[cfg(feature = "broadcast")]
+use crate::engine::broadcast::Broadcast;
+use crate::engine::match_stats::{MatchStats, MoveStats};
+use crate::engine::platform::{Clock, Platform, TaskHandle};
+use crate::evaluation::{Evaluator, Pesto};
+use crate::search::session::Session;
+use crate::search::{self, mcts, Limits, Listener, NodeCounter, SearchResult};
+
+/// Upper bound on the time it takes to stop the search and join the search
+/// thread. If the search does not stop in time, the thread is detached so that
+/// the engine stays responsive.
+pub(super) const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Extra information sent with the search results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Reporting {
+    /// Send [`SearchResult::to_json`] before the best move.
+    pub stats: bool,
+    /// Send the win, draw and loss probabilities with the score
+    /// (`UCI_ShowWDL`).
+    pub wdl: bool,
+    /// Send this many moves with the highest priors when the search starts,
+    /// see [`policy_moves`].
+    pub policy_moves: u16,
+    /// Encode castling as the king capturing its own rook (`UCI_Chess960`),
+    /// see [`Position::to_uci`].
+    pub chess960: bool,
+}
+
+/// Search running in a background thread.
+struct SearchThread {
+    stop: Arc<AtomicBool>,
+    /// While set, the best move is not sent even if the search is finished.
+    pondering: Arc<AtomicBool>,
+    /// Set by [`Searcher::ponderhit`]: the ponder search became a move.
+    ponder_hit: Arc<AtomicBool>,
+    /// Set once the best move is sent. Either the search thread or
+    /// [`Searcher::join`] (if the thread does not stop in time) sends it, but
+    /// never both.
+    reported: Arc<AtomicBool>,
+    /// Sent if the search thread is abandoned: the first legal move in UCI.
+    fallback: String,
+    handle: TaskHandle,
+}
+
+/// Owns the background search and sends its results to the shared output.
+///
+/// All methods take `&self`, so the searcher can be shared between threads
+/// (e.g. by a frontend that serves several games or handles `stop` in a
+/// separate thread). The calls are serialized: at most one search is running
+/// at any time and starting a new search stops the previous one, which still
+/// reports its best move. Every [`Searcher::go`] results in exactly one
+/// `bestmove`.
+pub struct Searcher<W: Write + Send + 'static> {
+    out: Arc<Mutex<W>>,
+    current: Mutex<Option<SearchThread>>,
+    /// Root position and result of the last finished search, see
+    /// [`Searcher::last_search`].
+    last_search: Arc<Mutex<Option<(Position, SearchResult)>>>,
+    /// The search tree and the evaluations kept between the searches.
+    session: Arc<Mutex<Session>>,
+    /// Statistics of the searches that resulted in a move.
+    match_stats: Arc<Mutex<MatchStats>>,
+    #[cfg(feature = "broadcast")]
+    broadcast: Option<Arc<Broadcast>>,
+    platform: Platform,
+}
+
+impl<W: Write + Send + 'static> Searcher<W> {
+    /// Creates the searcher that writes the search results to `out`.
+    #[must_use]
+    pub fn new(out: Arc<Mutex<W>>) -> Self {
+        Self::with_platform(out, Platform::default())
+    }
+
+    /// Same as [`Searcher::new`], but the timers and the search threads use
+    /// the given `platform`.
+    #[must_use]
+    pub fn with_platform(out: Arc<Mutex<W>>, platform: Platform) -> Self {
+        Self {
+            out,
+            current: Mutex::new(None),
+            last_search: Arc::new(Mutex::new(None)),
+            session: Arc::new(Mutex::new(Session::new(Arc::new(Pesto)))),
+            match_stats: Arc::new(Mutex::new(MatchStats::default())),
+            #[cfg(feature = "broadcast")]
+            broadcast: None,
+            platform,
+        }
+    }
+
+    /// Sends the snapshots of the following searches to the `broadcast`.
+    #[cfg(feature = "broadcast")]
+    pub(super) fn set_broadcast(&mut self, broadcast: Broadcast) {
+        self.broadcast = Some(Arc::new(broadcast));
+    }
+
+    /// Starts searching the position in the background, stopping the previous
+    /// search if it is still running. The `reporting` options add extra
+    /// information to the results.
+    ///
+    /// # Errors
+    ///
+    /// If the previous search failed or the thread can not be spawned.
+    pub fn go(
+        &self,
+        position: &Position,
+        limits: Limits,
+        config: &mcts::Config,
+        evaluator: Arc<dyn Evaluator>,
+        reporting: Reporting,
+    ) -> anyhow::Result<()> {
+        self.start(position, limits, config, evaluator, reporting, false)
+    }
+
+    /// Starts pondering: searching the position after the expected opponent
+    /// move until [`Searcher::ponderhit`] or [`Searcher::stop`]. The best move
+    /// is held back while pondering, even if the search finishes early.
+    ///
+    /// # Errors
+    ///
+    /// If the previous search failed or the thread can not be spawned.
+    pub fn ponder(
+        &self,
+        position: &Position,
+        limits: Limits,
+        config: &mcts::Config,
+        evaluator: Arc<dyn Evaluator>,
+        reporting: Reporting,
+    ) -> anyhow::Result<()> {
+        self.start(position, limits, config, evaluator, reporting, true)
+    }
+
+    /// Turns pondering into a regular search that keeps the tree built so far
+    /// and stops after `time` (or when stopped explicitly if the time is not
+    /// limited). Returns false if the searcher was not pondering.
+    pub fn ponderhit(&self, time: Option<Duration>) -> bool {
+        let current = self.lock();
+        let Some(search) = current.as_ref() else {
+            return false;
+        };
+        if !search.pondering.swap(false, Ordering::Relaxed) {
+            return false;
+        }
+        search.ponder_hit.store(true, Ordering::Relaxed);
+        if let Some(time) = time {
+            let stop = Arc::clone(&search.stop);
+            let clock = Arc::clone(&self.platform.clock);
+            let deadline = clock.now() + time;
+            // The timer exits early if the search is stopped before the
+            // deadline.
+            let _ = self.platform.spawner.spawn(
+                "ponder timer",
+                Box::new(move || {
+                    while !stop.load(Ordering::Relaxed) && clock.now() < deadline {
+                        clock.sleep(Duration::from_millis(1));
+                    }
+                    stop.store(true, Ordering::Relaxed);
+                    Ok(())
+                }),
+            );
+        }
+        true
+    }
+
+    fn start(
+        &self,
+        position: &Position,
+        limits: Limits,
+        config: &mcts::Config,
+        evaluator: Arc<dyn Evaluator>,
+        reporting: Reporting,
+        pondering: bool,
+    ) -> anyhow::Result<()> {
+        let mut current = self.lock();
+        // The previous search should have been stopped already, but don't
+        // leave it running if the caller did not do that.
+        self.join(current.take())?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let started_pondering = pondering;
+        let pondering = Arc::new(AtomicBool::new(pondering));
+        let ponder_hit = Arc::new(AtomicBool::new(false));
+        let reported = Arc::new(AtomicBool::new(false));
+        let fallback = position.generate_moves().first().map_or_else(
+            || Move::NULL.to_string(),
+            |next_move| position.to_uci(next_move, reporting.chess960),
+        );
+        let handle = {
+            let position = position.clone();
+            let config = config.clone();
+            let stop = Arc::clone(&stop);
+            let pondering = Arc::clone(&pondering);
+            let ponder_hit = Arc::clone(&ponder_hit);
+            let reported = Arc::clone(&reported);
+            let match_stats = Arc::clone(&self.match_stats);
+            let out = Arc::clone(&self.out);
+            let last_search = Arc::clone(&self.last_search);
+            let session = Arc::clone(&self.session);
+            let clock = Arc::clone(&self.platform.clock);
+            #[cfg(feature = "broadcast")]
+            let broadcast = self.broadcast.clone();
+            self.platform.spawner.spawn(
+                "search",
+                Box::new(move || {
+                    if reporting.policy_moves > 0 {
+                        policy_moves(
+                            &mut *out.lock().expect("output should not be poisoned"),
+                            &position,
+                            &*evaluator,
+                            reporting,
+                        )?;
+                    }
+                    let mut listener = Progress::new(
+                        Arc::clone(&out),
+                        Arc::clone(&clock),
+                        Arc::new(NodeCounter::default()),
+                    );
+                    if reporting.chess960 {
+                        listener.chess960_root = Some(position.clone());
+                    }
+                    #[cfg(feature = "broadcast")]
+                    listener.set_broadcast(broadcast, &position);
+                    let (result, cache_hits, cache_lookups) = match try_lock_session(&session) {
+                        Some(mut session) => {
+                            let (hits, lookups) = session.cache_stats();
+                            let result = session.analyze(
+                                &position,
+                                &limits,
+                                &config,
+                                &evaluator,
+                                &stop,
+                                &mut listener,
+                            )?;
+                            let (total_hits, total_lookups) = session.cache_stats();
+                            (result, total_hits - hits, total_lookups - lookups)
+                        },
+                        // The previous search was abandoned and might still be
+                        // running: start from scratch.
+                        None => (
+                            mcts::search_with_listener(
+                                &position,
+                                &limits,
+                                &config,
+                                &*evaluator,
+                                &stop,
+                                &mut listener,
+                            )?,
+                            0,
+                            0,
+                        ),
+                    };
+                    #[cfg(feature = "broadcast")]
+                    listener.snapshot(&result);
+                    while pondering.load(Ordering::Relaxed) {
+                        clock.sleep(Duration::from_millis(1));
+                    }
+                    // The ponder misses are not moves of the game.
+                    if !started_pondering || ponder_hit.load(Ordering::Relaxed) {
+                        match_stats
+                            .lock()
+                            .expect("match statistics should not be poisoned")
+                            .record_move(MoveStats::new(&result, cache_hits, cache_lookups));
+                    }
+                    *last_search
+                        .lock()
+                        .expect("search result should not be poisoned") =
+                        Some((position.clone(), result.clone()));
+                    let mut out = out.lock().expect("output should not be poisoned");
+                    if reported.swap(true, Ordering::Relaxed) {
+                        return Ok(());
+                    }
+                    if reporting.stats {
+                        writeln!(out, "info string {}", result.to_json())?;
+                    }
+                    report(&mut *out, &position, &result, config.analysis, reporting)
+                }),
+            )?
+        };
+        *current = Some(SearchThread {
+            stop,
+            pondering,
+            ponder_hit,
+            reported,
+            fallback,
+            handle,
+        });
+        Ok(())
+    }
+
+    /// Stops the search (if there is one running) and waits for the best move
+    /// to be sent.
+    ///
+    /// # Errors
+    ///
+    /// If the search failed.
+    pub fn stop(&self) -> anyhow::Result<()> {
+        let mut current = self.lock();
+        self.join(current.take())
+    }
+
+    /// Stops the search and drops the state kept between the searches (e.g.
+    /// on `ucinewgame`).
+    ///
+    /// # Errors
+    ///
+    /// If the search failed.
+    pub fn clear(&self) -> anyhow::Result<()> {
+        self.stop()?;
+        if let Some(mut session) = try_lock_session(&self.session) {
+            session.clear();
+        }
+        Ok(())
+    }
+
+    /// Statistics of the searches since the searcher was created, see
+    /// [`MatchStats`].
+    pub(super) fn match_stats(&self) -> MutexGuard<'_, MatchStats> {
+        self.match_stats
+            .lock()
+            .expect("match statistics should not be poisoned")
+    }
+
+    /// Returns the result of the last search that sent its best move, if any.
+    #[must_use]
+    pub fn last_result(&self) -> Option<SearchResult> {
+        self.last_search().map(|(_, result)| result)
+    }
+
+    /// Same as [`Searcher::last_result`], but also returns the searched
+    /// position.
+    #[must_use]
+    pub fn last_search(&self) -> Option<(Position, SearchResult)> {
+        self.last_search
+            .lock()
+            .expect("search result should not be poisoned")
+            .clone()
+    }
+
+    /// Returns true if the search is still running.
+    #[must_use]
+    pub fn is_searching(&self) -> bool {
+        self.lock()
+            .as_ref()
+            .is_some_and(|search| !search.handle.is_finished())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<SearchThread>> {
+        // The lock is never held while running user code that can panic.
+        self.current
+            .lock()
+            .expect("search state should not be poisoned")
+    }
+
+    fn join(&self, search: Option<SearchThread>) -> anyhow::Result<()> {
+        let Some(search) = search else {
+            return Ok(());
+        };
+        search.stop.store(true, Ordering::Relaxed);
+        search.pondering.store(false, Ordering::Relaxed);
+        let clock = &self.platform.clock;
+        let deadline = clock.now() + SHUTDOWN_TIMEOUT;
+        while !search.handle.is_finished() {
+            if clock.now() >= deadline {
+                // Detach the thread: it will be terminated with the process.
+                // The server still gets exactly one best move for the search.
+                let mut out = self.out.lock().expect("output should not be poisoned");
+                writeln!(
+                    out,
+                    "info string Search did not stop within {SHUTDOWN_TIMEOUT:?}"
+                )?;
+                if !search.reported.swap(true, Ordering::Relaxed) {
+                    writeln!(out, "bestmove {}", search.fallback)?;
+                    out.flush()?;
+                }
+                return Ok(());
+            }
+            clock.sleep(Duration::from_millis(1));
+        }
+        match search.handle.join() {
+            Ok(result) => result,
+            Err(_) => anyhow::bail!("search thread panicked"),
+        }
+    }
+}
+
+impl<W: Write + Send + 'static> Drop for Searcher<W> {
+    /// Signals the search to stop if the searcher is dropped without stopping
+    /// it first.
+    fn drop(&mut self) {
+        if let Ok(Some(search)) = self.current.get_mut() {
+            search.stop.store(true, Ordering::Relaxed);
+            search.pondering.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Returns the session unless it is used by an abandoned search. The state
+/// left by a failed search is dropped.
+fn try_lock_session(session: &Mutex<Session>) -> Option<MutexGuard<'_, Session>> {
+    match session.try_lock() {
+        Ok(session) => Some(session),
+        Err(TryLockError::Poisoned(poisoned)) => {
+            let mut guard = poisoned.into_inner();
+            guard.clear();
+            session.clear_poison();
+            Some(guard)
+        },
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
+
+/// GUIs display the root move being searched as progress, but it is only
+/// useful in long searches.
+const CURRMOVE_DELAY: Duration = Duration::from_secs(1);
+
+/// MCTS goes through different root moves on every playout, so the updates are
+/// rate limited.
+const CURRMOVE_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Interval between the `info depth <depth> seldepth <seldepth> nodes <nodes>
+/// ...` updates while the search is running.
+const INFO_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Reports the progress of the search thread: sends `info currmove <move>
+/// currmovenumber <n>` and periodic `info` lines with the total number of
+/// nodes searched by all threads and the speed.
+struct Progress<W: Write> {
+    out: Arc<Mutex<W>>,
+    clock: Arc<dyn Clock>,
+    /// Total number of nodes of the search, shared by the threads.
+    nodes: Arc<NodeCounter>,
+    /// Nodes of this thread already added to [`Progress::nodes`].
+    counted: u64,
+    start: Instant,
+    next_currmove: Instant,
+    next_info: Instant,
+    /// Root position for encoding the castling moves in Chess960 mode.
+    chess960_root: Option<Position>,
+    /// Broadcast of the snapshots and the root position.
+    #[cfg(feature = "broadcast")]
+    broadcast: Option<(Arc<Broadcast>, Position)>,
+    #[cfg(feature = "broadcast")]
+    next_snapshot: Instant,
+}
+
+impl<W: Write> Progress<W> {
+    fn new(out: Arc<Mutex<W>>, clock: Arc<dyn Clock>, nodes: Arc<NodeCounter>) -> Self {
+        let start = clock.now();
+        Self {
+            out,
+            clock,
+            nodes,
+            counted: 0,
+            start,
+            next_currmove: start + CURRMOVE_DELAY,
+            next_info: start + INFO_INTERVAL,
+            chess960_root: None,
+            #[cfg(feature = "broadcast")]
+            broadcast: None,
+            #[cfg(feature = "broadcast")]
+            next_snapshot: start,
+        }
+    }
+
+    #[cfg(feature = "broadcast")]
+    fn set_broadcast(&mut self, broadcast: Option<Arc<Broadcast>>, position: &Position) {
+        self.broadcast = broadcast.map(|broadcast| (broadcast, position.clone()));
+    }
+}
+
+impl<W: Write> Listener for Progress<W> {
+    fn root_move(&mut self, next_move: Move, number: usize) {
+        let now = self.clock.now();
+        if now < self.next_currmove {
+            return;
+        }
+        self.next_currmove = now + CURRMOVE_INTERVAL;
+        let mut out = self.out.lock().expect("output should not be poisoned");
+        // The search can not handle output errors, the final report will
+        // surface them.
+        let next_move = match &self.chess960_root {
+            Some(root) => root.to_uci(&next_move, true),
+            None => next_move.to_string(),
+        };
+        let _ = writeln!(out, "info currmove {next_move} currmovenumber {number}");
+        let _ = out.flush();
+    }
+
+    fn playout(&mut self, nodes: u64, depth: u32, seldepth: u32) {
+        self.nodes.add(nodes.saturating_sub(self.counted));
+        self.counted = nodes;
+        let now = self.clock.now();
+        if now < self.next_info {
+            return;
+        }
+        self.next_info = now + INFO_INTERVAL;
+        let elapsed = now - self.start;
+        let total = self.nodes.get();
+        let mut out = self.out.lock().expect("output should not be poisoned");
+        let _ = writeln!(
+            out,
+            "info depth {depth} seldepth {seldepth} nodes {total} nps {} time {}",
+            search::nps(total, elapsed),
+            elapsed.as_millis()
+        );
+        let _ = out.flush();
+    }
+
+    #[cfg(feature = "broadcast")]
+    fn snapshot_due(&mut self) -> bool {
+        let Some((broadcast, _)) = &self.broadcast else {
+            return false;
+        };
+        let now = self.clock.now();
+        if now < self.next_snapshot {
+            return false;
+        }
+        self.next_snapshot = now + broadcast.interval();
+        true
+    }
+
+    #[cfg(feature = "broadcast")]
+    fn snapshot(&mut self, result: &SearchResult) {
+        if let Some((broadcast, position)) = &self.broadcast {
+            broadcast.send(position, result);
+        }
+    }
+}
+
+/// Number of moves of the principal variation shown by [`root_stats`].
+const ROOT_STATS_PV_LENGTH: usize = 6;
+
+/// Sends the statistics of each root move of the search as `info string`
+/// lines, the most visited moves first, to explain the choice of the best
+/// move. With the `root` position, the moves are also shown in SAN.
+pub(super) fn root_stats(
+    out: &mut impl Write,
+    result: &SearchResult,
+    root: Option<&Position>,
+) -> anyhow::Result<()> {
+    let total_visits = result
+        .root_moves
+        .iter()
+        .map(|root_move| u64::from(root_move.visits))
+        .sum::<u64>()
+        .max(1);
+    let best_move = match (result.best_move, root) {
+        (Some(best_move), Some(root)) => format!("{best_move} ({})", root.to_san(&best_move)),
+        (Some(best_move), None) => best_move.to_string(),
+        (None, _) => "(none)".to_string(),
+    };
+    writeln!(
+        out,
+        "info string Root moves after {} nodes, best move {best_move}:",
+        result.nodes,
+    )?;
+    for root_move in result
+        .root_moves
+        .iter()
+        .sorted_by_key(|root_move| std::cmp::Reverse(root_move.visits))
+    {
+        let pv = &root_move.pv[..root_move.pv.len().min(ROOT_STATS_PV_LENGTH)];
+        write!(
+            out,
+            "info string {:>5} visits {:>7} ({:>5.1}%) q {:>+.4} score {} prior {:.4} pv {}",
+            root_move.next_move.to_string(),
+            root_move.visits,
+            100.0 * f64::from(root_move.visits) / total_visits as f64,
+            root_move.q,
+            root_move.score,
+            root_move.prior,
+            pv.iter().join(" ")
+        )?;
+        if let Some(root) = root {
+            write!(out, " san {}", root.san_line(pv))?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Sends the `count` moves with the highest prior probabilities predicted by
+/// the evaluator as an `info string` line, to judge the policy on its own
+/// before the search refines it. The priors are the raw policy, without the
+/// temperature and the check boost applied by the search.
+fn policy_moves(
+    out: &mut impl Write,
+    position: &Position,
+    evaluator: &dyn Evaluator,
+    reporting: Reporting,
+) -> anyhow::Result<()> {
+    let prediction = evaluator
+        .evaluate(slice::from_ref(position))?
+        .pop()
+        .context("evaluator should return a prediction for each position")?;
+    let moves = position.generate_moves();
+    if moves.is_empty() {
+        return Ok(());
+    }
+    let top = moves
+        .iter()
+        .zip(prediction.policy)
+        .sorted_by(|(_, left), (_, right)| right.total_cmp(left))
+        .take(usize::from(reporting.policy_moves))
+        .map(|(next_move, prior)| {
+            let next_move = position.to_uci(next_move, reporting.chess960);
+            format!("{next_move} {:.1}%", 100.0 * prior)
+        })
+        .join(" ");
+    writeln!(out, "info string Policy moves: {top}")?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Upper bound on the length of the principal variation in the `info` lines:
+/// some GUIs truncate or reject longer input lines.
+const MAX_PV_CHARS: usize = 1024;
+
+/// Formats the moves of the principal variation (already in UCI), dropping
+/// the ones that do not fit into [`MAX_PV_CHARS`].
+fn pv_string(pv: &[String]) -> String {
+    let mut line = String::new();
+    for next_move in pv {
+        let separator = usize::from(!line.is_empty());
+        if line.len() + separator + next_move.len() > MAX_PV_CHARS {
+            break;
+        }
+        if separator > 0 {
+            line.push(' ');
+        }
+        line.push_str(next_move);
+    }
+    line
+}
+
+/// Sends the final search information and the best move to the UCI server.
+/// With `multipv`, each searched root move is reported on a separate line,
+/// starting with the best one. The second move of the principal variation is
+/// suggested for pondering. The moves are encoded for the `root` position,
+/// see [`Reporting::chess960`].
+fn report(
+    out: &mut impl Write,
+    root: &Position,
+    result: &SearchResult,
+    multipv: bool,
+    reporting: Reporting,
+) -> anyhow::Result<()> {
+    let uci_line = |moves: &[Move]| root.uci_line(moves, reporting.chess960);
+    let wdl_string = |value: search::Wdl| {
+        if reporting.wdl {
+            format!(" {value}")
+        } else {
+            String::new()
+        }
+    };
+    let stats = format!(
+        "nodes {} nps {} time {}",
+        result.nodes,
+        search::nps(result.nodes, result.elapsed),
+        result.elapsed.as_millis(),
+    );
+    if multipv && result.best_move.is_some() {
+        let root_moves = result
+            .root_moves
+            .iter()
+            .filter(|root_move| root_move.visits > 0)
+            .sorted_by_key(|root_move| {
+                (
+                    Some(root_move.next_move) != result.best_move,
+                    std::cmp::Reverse(root_move.visits),
+                )
+            });
+        for (index, root_move) in root_moves.enumerate() {
+            writeln!(
+                out,
+                "info depth {} seldepth {} multipv {} score {}{} {stats} pv {}",
+                result.depth,
+                result.seldepth,
+                index + 1,
+                root_move.score,
+                wdl_string(root_move.wdl),
+                pv_string(&uci_line(&root_move.pv))
+            )?;
+        }
+    } else {
+        writeln!(
+            out,
+            "info depth {} seldepth {} score {}{} {stats} pv {}",
+            result.depth,
+            result.seldepth,
+            result.score,
+            wdl_string(result.wdl),
+            pv_string(&uci_line(&result.pv))
+        )?;
+    }
+    match result.best_move {
+        Some(best_move) => {
+            let best_move = root.to_uci(&best_move, reporting.chess960);
+            match uci_line(&result.pv).get(1) {
+                Some(ponder) => writeln!(out, "bestmove {best_move} ponder {ponder}")?,
+                None => writeln!(out, "bestmove {best_move}")?,
+            }
+        },
+        // UCI null move is sent when the position is terminal.
+        None => writeln!(out, "bestmove {}", Move::NULL)?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::evaluation::Pesto;
+
+    type Output = Arc<Mutex<Vec<u8>>>;
+
+    fn searcher() -> (Arc<Searcher<Vec<u8>>>, Output) {
+        let out = Arc::new(Mutex::new(Vec::new()));
+        (Arc::new(Searcher::new(Arc::clone(&out))), out)
+    }
+
+    fn count_best_moves(out: &Mutex<Vec<u8>>) -> usize {
+        String::from_utf8(out.lock().unwrap().clone())
+            .unwrap()
+            .matches("bestmove")
+            .count()
+    }
+
+    fn go_infinite(searcher: &Searcher<Vec<u8>>) {
+        searcher
+            .go(
+                &Position::starting(),
+                Limits::default(),
+                &mcts::Config::default(),
+                Arc::new(Pesto),
+                Reporting::default(),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn pv_length() {
+        let pv = vec!["g1f3".to_string(); 500];
+        let line = pv_string(&pv);
+        assert!(line.len() <= MAX_PV_CHARS);
+        // Each move takes 4 characters and a separator.
+        assert_eq!(line.split(' ').count(), (MAX_PV_CHARS + 1) / 5);
+        assert_eq!(pv_string(&pv[..2]), "g1f3 g1f3");
+        assert_eq!(pv_string(&[]), "");
+    }
+
+    #[test]
+    fn rapid_go_stop() {
+        let (searcher, out) = searcher();
+        let start = Instant::now();
+        for _ in 0..100 {
+            go_infinite(&searcher);
+            searcher.stop().unwrap();
+            assert!(!searcher.is_searching());
+        }
+        // Go without stop and stop without go.
+        for _ in 0..100 {
+            go_infinite(&searcher);
+        }
+        searcher.stop().unwrap();
+        searcher.stop().unwrap();
+        assert_eq!(count_best_moves(&out), 200);
+        assert!(start.elapsed() < SHUTDOWN_TIMEOUT * 10);
+    }
+
+    #[test]
+    fn concurrent_go_stop() {
+        let (searcher, out) = searcher();
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let searcher = Arc::clone(&searcher);
+                thread::spawn(move || {
+                    for _ in 0..25 {
+                        go_infinite(&searcher);
+                        searcher.stop().unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert!(!searcher.is_searching());
+        assert_eq!(count_best_moves(&out), 100);
+    }
+
+    /// Evaluator that does not check the stop flag for a long time.
+    struct Stuck;
+
+    impl Evaluator for Stuck {
+        fn evaluate(
+            &self,
+            positions: &[Position],
+        ) -> anyhow::Result<Vec<crate::evaluation::Prediction>> {
+            thread::sleep(SHUTDOWN_TIMEOUT * 2);
+            Pesto.evaluate(positions)
+        }
+    }
+
+    #[test]
+    fn abandoned_search() {
+        let (searcher, out) = searcher();
+        searcher
+            .go(
+                &Position::starting(),
+                Limits::default(),
+                &mcts::Config::default(),
+                Arc::new(Stuck),
+                Reporting::default(),
+            )
+            .unwrap();
+        searcher.stop().unwrap();
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(
+            output.contains("info string Search did not stop"),
+            "{output}"
+        );
+        assert_eq!(count_best_moves(&out), 1);
+        // The search finishes later, but it does not send another best move.
+        thread::sleep(SHUTDOWN_TIMEOUT * 3);
+        assert_eq!(count_best_moves(&out), 1);
+    }
+
+    #[test]
+    fn drop_stops_search() {
+        let (searcher, out) = searcher();
+        go_infinite(&searcher);
+        drop(searcher);
+        let deadline = Instant::now() + SHUTDOWN_TIMEOUT * 5;
+        while count_best_moves(&out) == 0 {
+            assert!(Instant::now() < deadline, "search did not stop");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn periodic_info() {
+        let out = Arc::new(Mutex::new(Vec::new()));
+        let (platform, clock) = Platform::manual();
+        let searcher = Searcher::with_platform(Arc::clone(&out), platform);
+        let updates = || -> Vec<String> {
+            String::from_utf8(out.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("info depth ") && !line.contains(" pv "))
+                .map(ToString::to_string)
+                .collect()
+        };
+        go_infinite(&searcher);
+        thread::sleep(Duration::from_millis(50));
+        assert!(updates().is_empty());
+        clock.advance(INFO_INTERVAL);
+        let deadline = Instant::now() + SHUTDOWN_TIMEOUT * 5;
+        while updates().is_empty() {
+            assert!(Instant::now() < deadline, "no info update");
+            thread::sleep(Duration::from_millis(1));
+        }
+        searcher.stop().unwrap();
+        let update = &updates()[0];
+        let tokens: Vec<&str> = update.split_whitespace().collect();
+        assert_eq!(tokens[3], "seldepth", "{update}");
+        let depth: u32 = tokens[2].parse().unwrap();
+        let seldepth: u32 = tokens[4].parse().unwrap();
+        assert!(seldepth >= depth, "{update}");
+        assert_eq!(tokens[5], "nodes", "{update}");
+        let nodes: u64 = tokens[6].parse().unwrap();
+        assert!(nodes > 0, "{update}");
+        // Exactly one second has passed.
+        assert_eq!(tokens[7..], ["nps", tokens[6], "time", "1000"], "{update}");
+        assert_eq!(count_best_moves(&out), 1);
+    }
+
+    #[test]
+    fn current_move() {
+        let out = Arc::new(Mutex::new(Vec::new()));
+        let (platform, clock) = Platform::manual();
+        let searcher = Searcher::with_platform(Arc::clone(&out), platform);
+        let updates = || {
+            String::from_utf8(out.lock().unwrap().clone())
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("info currmove "))
+                .count()
+        };
+        let wait_for_updates = |count| {
+            let deadline = Instant::now() + SHUTDOWN_TIMEOUT * 5;
+            while updates() < count {
+                assert!(Instant::now() < deadline, "no currmove update");
+                thread::sleep(Duration::from_millis(1));
+            }
+        };
+        go_infinite(&searcher);
+        // The search keeps running, but the time does not move.
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(updates(), 0);
+        clock.advance(CURRMOVE_DELAY);
+        wait_for_updates(1);
+        thread::sleep(Duration::from_millis(50));
+        assert_eq!(updates(), 1);
+        clock.advance(CURRMOVE_INTERVAL);
+        wait_for_updates(2);
+        searcher.stop().unwrap();
+        assert_eq!(updates(), 2);
+        let output = String::from_utf8(out.lock().unwrap().clone()).unwrap();
+        assert!(output.contains(" currmovenumber "), "{output}");
+        assert_eq!(count_best_moves(&out), 1);
+    }
+}
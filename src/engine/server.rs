@@ -0,0 +1,371 @@
+//! Analysis server for web frontends: a small JSON protocol over WebSocket.
+//!
+//! Each connection gets its own [`Engine`] in analysis mode, so the server
+//! supports the same options and search behavior as a UCI session. The client
+//! sends one JSON object per message:
+//!
+//! - `{"type": "position", "fen": "...", "moves": ["e2e4"]}` (both fields are
+//!   optional, the default is the starting position)
+//! - `{"type": "go", "nodes": 10000, "movetime": 1000, "depth": 10}` (without
+//!   limits the analysis runs until `stop`)
+//! - `{"type": "stop"}`
+//! - `{"type": "setoption", "name": "CPuct", "value": 250}`
+//! - `{"type": "newgame"}` and `{"type": "isready"}`
+//!
+//! The server streams the engine output back:
+//!
+//! - `{"type": "info", "depth": 5, "seldepth": 12, "score": {"cp": 20},
+//!   "nodes": 1000, "pv": ["e2e4", "e7e5"], ...}`
+//! - `{"type": "bestmove", "move": "e2e4", "ponder": "e7e5"}`
+//! - `{"type": "ready"}`, `{"type": "log", "message": "..."}` for the `info
+//!   string` lines and `{"type": "error", "message": "..."}` for invalid
+//!   requests.
+
+use std::io::{self, BufReader};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{bail, Context};
+use serde_json::{json, Map, Value};
+use tungstenite::{Message, WebSocket};
+
+use crate::engine::platform::{ChannelInput, ChannelOutput};
+use crate::engine::Engine;
+use crate::evaluation::Evaluator;
+
+/// How often the connection checks for the engine output while waiting for
+/// the client messages.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Accepts WebSocket connections on the local `port` until the process is
+/// terminated. The server only listens on the loopback interface: the
+/// frontend is expected to run on the same machine or behind a proxy.
+///
+/// # Errors
+///
+/// If the port can not be bound.
+pub fn serve(port: u16, evaluator: Arc<dyn Evaluator>) -> anyhow::Result<()> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .with_context(|| format!("failed to listen on port {port}"))?;
+    serve_on(&listener, evaluator)
+}
+
+/// Same as [`serve`], but accepts the connections from the given listener,
+/// e.g. one bound to port 0 in tests.
+///
+/// # Errors
+///
+/// If accepting a connection fails.
+pub fn serve_on(listener: &TcpListener, evaluator: Arc<dyn Evaluator>) -> anyhow::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let evaluator = Arc::clone(&evaluator);
+        let _ = thread::Builder::new()
+            .name("connection".to_string())
+            .spawn(move || {
+                // A failed connection does not affect the others.
+                if let Err(e) = handle_connection(stream, evaluator) {
+                    eprintln!("Connection failed: {e:#}");
+                }
+            })?;
+    }
+    Ok(())
+}
+
+/// Runs an engine for the connection and translates the messages in both
+/// directions until the client disconnects.
+fn handle_connection(stream: TcpStream, evaluator: Arc<dyn Evaluator>) -> anyhow::Result<()> {
+    let mut socket = tungstenite::accept(stream).context("WebSocket handshake failed")?;
+    socket.get_ref().set_read_timeout(Some(POLL_INTERVAL))?;
+
+    let (commands, input) = ChannelInput::new();
+    let (output, lines) = ChannelOutput::new();
+    let engine = thread::Builder::new()
+        .name("engine".to_string())
+        .spawn(move || {
+            let mut input = BufReader::new(input);
+            Engine::new(&mut input, output)
+                .with_evaluator(evaluator)
+                .uci_loop()
+        })?;
+    commands.send("setoption name UCI_AnalyseMode value true\n".to_string())?;
+
+    let result = relay(&mut socket, &commands, &lines);
+    // Closing the input stops the search and the engine.
+    drop(commands);
+    match engine.join() {
+        Ok(engine_result) => result.and(engine_result),
+        Err(_) => bail!("engine thread panicked"),
+    }
+}
+
+fn relay(
+    socket: &mut WebSocket<TcpStream>,
+    commands: &mpsc::Sender<String>,
+    lines: &mpsc::Receiver<String>,
+) -> anyhow::Result<()> {
+    loop {
+        match socket.read() {
+            Ok(Message::Text(text)) => match to_uci(&text) {
+                Ok(command) => commands.send(format!("{command}\n"))?,
+                Err(e) => {
+                    let error = json!({"type": "error", "message": format!("{e:#}")});
+                    socket.send(Message::text(error.to_string()))?;
+                },
+            },
+            Ok(Message::Close(_)) => return Ok(()),
+            // Pings are answered by the library.
+            Ok(_) => {},
+            Err(tungstenite::Error::Io(e))
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                ) => {},
+            Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                return Ok(())
+            },
+            Err(e) => return Err(e.into()),
+        }
+        for line in lines.try_iter() {
+            if let Some(message) = from_uci(&line) {
+                socket.send(Message::text(message.to_string()))?;
+            }
+        }
+    }
+}
+
+/// Converts a client message to the UCI command.
+fn to_uci(message: &str) -> anyhow::Result<String> {
+    let message: Value = serde_json::from_str(message).context("invalid JSON")?;
+    let field = |name: &str| -> anyhow::Result<Option<String>> {
+        let value = match message.get(name) {
+            None | Some(Value::Null) => return Ok(None),
+            Some(Value::String(value)) => value.clone(),
+            Some(value @ (Value::Number(_) | Value::Bool(_))) => value.to_string(),
+            Some(value) => bail!("invalid {name}: {value}"),
+        };
+        // Each command takes a single line of the UCI input.
+        if value.contains(['\n', '\r']) {
+            bail!("invalid {name}: {value:?}");
+        }
+        Ok(Some(value))
+    };
+    let command = match field("type")?.as_deref() {
+        Some("position") => {
+            let mut command = match field("fen")? {
+                Some(fen) => format!("position fen {fen}"),
+                None => "position startpos".to_string(),
+            };
+            if let Some(moves) = message.get("moves") {
+                let moves = moves
+                    .as_array()
+                    .and_then(|moves| moves.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+                    .context("moves should be a list of strings")?;
+                if moves
+                    .iter()
+                    .any(|next_move| next_move.contains(char::is_whitespace))
+                {
+                    bail!("invalid moves: {moves:?}");
+                }
+                if !moves.is_empty() {
+                    command.push_str(" moves ");
+                    command.push_str(&moves.join(" "));
+                }
+            }
+            command
+        },
+        Some("go") => {
+            let mut command = "go".to_string();
+            for limit in ["nodes", "movetime", "depth"] {
+                if let Some(value) = message.get(limit) {
+                    let value = value
+                        .as_u64()
+                        .with_context(|| format!("{limit} should be a non-negative integer"))?;
+                    command.push_str(&format!(" {limit} {value}"));
+                }
+            }
+            if command == "go" {
+                command.push_str(" infinite");
+            }
+            command
+        },
+        Some("stop") => "stop".to_string(),
+        Some("isready") => "isready".to_string(),
+        Some("newgame") => "ucinewgame".to_string(),
+        Some("setoption") => {
+            let name = field("name")?.context("missing option name")?;
+            match field("value")? {
+                Some(value) => format!("setoption name {name} value {value}"),
+                None => format!("setoption name {name}"),
+            }
+        },
+        Some(other) => bail!("unknown message type: {other}"),
+        None => bail!("missing message type"),
+    };
+    Ok(command)
+}
+
+/// Converts a line of the engine output to the message for the client.
+/// Returns [`None`] for the lines the client does not need.
+fn from_uci(line: &str) -> Option<Value> {
+    let mut tokens = line.split_whitespace();
+    match tokens.next()? {
+        "bestmove" => Some(json!({
+            "type": "bestmove",
+            "move": tokens.next(),
+            "ponder": tokens.nth(1),
+        })),
+        "readyok" => Some(json!({"type": "ready"})),
+        "info" if line.starts_with("info string ") => Some(json!({
+            "type": "log",
+            "message": &line["info string ".len()..],
+        })),
+        "info" => {
+            let mut info = Map::new();
+            let _ = info.insert("type".to_string(), json!("info"));
+            while let Some(key) = tokens.next() {
+                let value = match key {
+                    "score" => {
+                        let kind = tokens.next()?;
+                        let value: i64 = tokens.next()?.parse().ok()?;
+                        json!({ kind: value })
+                    },
+                    // The principal variation is always the last field.
+                    "pv" => json!(tokens.by_ref().collect::<Vec<_>>()),
+                    "currmove" => json!(tokens.next()?),
+                    _ => json!(tokens.next()?.parse::<u64>().ok()?),
+                };
+                let _ = info.insert(key.to_string(), value);
+            }
+            Some(Value::Object(info))
+        },
+        // The handshake is not forwarded.
+        "id" | "option" | "uciok" => None,
+        _ => Some(json!({"type": "log", "message": line})),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+    use crate::evaluation::Pesto;
+
+    #[test]
+    fn client_messages() {
+        assert_eq!(
+            to_uci(r#"{"type": "position"}"#).unwrap(),
+            "position startpos"
+        );
+        assert_eq!(
+            to_uci(
+                r#"{"type": "position", "fen": "k7/8/1K6/8/8/8/8/7R w - - 0 1", "moves": ["h1h2"]}"#
+            )
+            .unwrap(),
+            "position fen k7/8/1K6/8/8/8/8/7R w - - 0 1 moves h1h2"
+        );
+        assert_eq!(to_uci(r#"{"type": "go"}"#).unwrap(), "go infinite");
+        assert_eq!(
+            to_uci(r#"{"type": "go", "nodes": 100, "movetime": 50}"#).unwrap(),
+            "go nodes 100 movetime 50"
+        );
+        assert_eq!(
+            to_uci(r#"{"type": "setoption", "name": "CPuct", "value": 250}"#).unwrap(),
+            "setoption name CPuct value 250"
+        );
+        assert_eq!(
+            to_uci(r#"{"type": "setoption", "name": "LogSan", "value": true}"#).unwrap(),
+            "setoption name LogSan value true"
+        );
+        assert_eq!(to_uci(r#"{"type": "stop"}"#).unwrap(), "stop");
+        assert_eq!(to_uci(r#"{"type": "newgame"}"#).unwrap(), "ucinewgame");
+
+        assert!(to_uci("stop").is_err());
+        assert!(to_uci(r#"{"type": "quit"}"#).is_err());
+        assert!(to_uci(r#"{"type": "go", "nodes": -1}"#).is_err());
+        // Clients can not inject additional UCI commands.
+        assert!(
+            to_uci(r#"{"type": "position", "fen": "8/8/8/8/8/8/8/8 w - - 0 1\nquit"}"#).is_err()
+        );
+        assert!(to_uci(r#"{"type": "position", "moves": ["e2e4\nquit"]}"#).is_err());
+        assert!(to_uci(r#"{"type": "setoption", "name": ["Hash"]}"#).is_err());
+    }
+
+    #[test]
+    fn engine_messages() {
+        assert_eq!(
+            from_uci(
+                "info depth 3 seldepth 5 score mate 1 nodes 100 nps 1000 time 100 pv h1h8 a8a7"
+            ),
+            Some(json!({
+                "type": "info",
+                "depth": 3,
+                "seldepth": 5,
+                "score": {"mate": 1},
+                "nodes": 100,
+                "nps": 1000,
+                "time": 100,
+                "pv": ["h1h8", "a8a7"],
+            }))
+        );
+        assert_eq!(
+            from_uci("info currmove e2e4 currmovenumber 1"),
+            Some(json!({"type": "info", "currmove": "e2e4", "currmovenumber": 1}))
+        );
+        assert_eq!(
+            from_uci("bestmove e2e4 ponder e7e5"),
+            Some(json!({"type": "bestmove", "move": "e2e4", "ponder": "e7e5"}))
+        );
+        assert_eq!(
+            from_uci("bestmove e2e4"),
+            Some(json!({"type": "bestmove", "move": "e2e4", "ponder": null}))
+        );
+        assert_eq!(
+            from_uci("info string Hash set to 1 MB"),
+            Some(json!({"type": "log", "message": "Hash set to 1 MB"}))
+        );
+        assert_eq!(from_uci("readyok"), Some(json!({"type": "ready"})));
+        assert_eq!(from_uci("uciok"), None);
+    }
+
+    #[test]
+    fn analysis_session() {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let _ = thread::spawn(move || serve_on(&listener, Arc::new(Pesto)));
+
+        let (mut socket, _) = tungstenite::connect(format!("ws://127.0.0.1:{port}")).unwrap();
+        for request in [
+            r#"{"type": "position", "fen": "k7/8/1K6/8/8/8/8/7R w - - 0 1"}"#,
+            r#"{"type": "go", "nodes": 1000}"#,
+            r#"{"type": "go", "depth": "deep"}"#,
+        ] {
+            socket.send(Message::text(request)).unwrap();
+        }
+        let deadline = Instant::now() + Duration::from_secs(30);
+        let mut messages = Vec::new();
+        let received =
+            |messages: &[Value], kind: &str| messages.iter().any(|message| message["type"] == kind);
+        while !received(&messages, "bestmove") || !received(&messages, "error") {
+            assert!(Instant::now() < deadline, "{messages:?}");
+            if let Message::Text(text) = socket.read().unwrap() {
+                messages.push(serde_json::from_str(&text).unwrap());
+            }
+        }
+        let info = messages
+            .iter()
+            .find(|message| message["type"] == "info" && message["multipv"] == 1)
+            .expect("analysis mode reports each root move");
+        assert_eq!(info["score"], json!({"mate": 1}), "{messages:?}");
+        assert_eq!(info["pv"][0], "h1h8", "{messages:?}");
+        let bestmove = messages
+            .iter()
+            .find(|message| message["type"] == "bestmove")
+            .unwrap();
+        assert_eq!(bestmove["move"], "h1h8", "{messages:?}");
+        socket.close(None).unwrap();
+    }
+}
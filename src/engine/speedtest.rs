@@ -0,0 +1,131 @@
+//! Micro-benchmarks of the engine building blocks on the current machine. The
+//! numbers help to compare builds (e.g. with different target features) and
+//! hardware, unlike [`super::bench`] that is used to detect functional changes.
+
+use std::fmt;
+use std::sync::atomic::AtomicBool;
+use std::time::{Duration, Instant};
+
+use crate::chess::position::Position;
+use crate::chess::random;
+use crate::evaluation::Evaluator;
+use crate::search::{mcts, Limits};
+
+/// Throughput of each component in operations per second.
+#[derive(Clone, Copy, Debug)]
+pub struct SpeedTest {
+    /// Legal move generation calls.
+    pub movegen: f64,
+    /// Copying the position and making a move (the search uses copy-make).
+    pub make_move: f64,
+    /// Single-position evaluator calls.
+    pub evaluation: f64,
+    /// Search playouts.
+    pub search: f64,
+}
+
+/// Number of positions generated by [`random::generate`] for the
+/// micro-benchmarks: the same inputs on every machine.
+const POSITIONS: usize = 120;
+
+/// Runs each micro-benchmark for approximately `duration` on the generated
+/// positions.
+///
+/// # Errors
+///
+/// If the evaluation or the search fails.
+pub fn speedtest(evaluator: &dyn Evaluator, duration: Duration) -> anyhow::Result<SpeedTest> {
+    let positions = random::generate(POSITIONS, 0);
+
+    let movegen = measure(duration, || {
+        for position in &positions {
+            let _ = std::hint::black_box(position.generate_moves());
+        }
+        Ok(positions.len() as u64)
+    })?;
+
+    let moves: Vec<_> = positions
+        .iter()
+        .map(|position| (position, position.generate_moves()))
+        .collect();
+    let make_move = measure(duration, || {
+        let mut count = 0;
+        for (position, moves) in &moves {
+            for next_move in moves {
+                let mut child = (*position).clone();
+                child.make_move(next_move);
+                let _ = std::hint::black_box(child);
+                count += 1;
+            }
+        }
+        Ok(count)
+    })?;
+
+    let evaluation = measure(duration, || {
+        for position in &positions {
+            let _ = std::hint::black_box(evaluator.evaluate(std::slice::from_ref(position))?);
+        }
+        Ok(positions.len() as u64)
+    })?;
+
+    let result = mcts::search(
+        &Position::starting(),
+        &Limits {
+            time: Some(duration),
+            ..Limits::default()
+        },
+        &mcts::Config::default(),
+        evaluator,
+        &AtomicBool::new(false),
+    )?;
+    let search = result.nodes as f64 / result.elapsed.as_secs_f64().max(f64::EPSILON);
+
+    Ok(SpeedTest {
+        movegen,
+        make_move,
+        evaluation,
+        search,
+    })
+}
+
+/// Repeats the batch until the time runs out and returns the number of
+/// operations per second.
+fn measure(
+    duration: Duration,
+    mut batch: impl FnMut() -> anyhow::Result<u64>,
+) -> anyhow::Result<f64> {
+    let start = Instant::now();
+    let mut operations = 0;
+    loop {
+        operations += batch()?;
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            return Ok(operations as f64 / elapsed.as_secs_f64());
+        }
+    }
+}
+
+impl fmt::Display for SpeedTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "movegen:    {:>12.0} positions/s", self.movegen)?;
+        writeln!(f, "make_move:  {:>12.0} moves/s", self.make_move)?;
+        writeln!(f, "evaluation: {:>12.0} positions/s", self.evaluation)?;
+        write!(f, "search:     {:>12.0} nodes/s", self.search)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation::Pesto;
+
+    #[test]
+    fn all_components_measured() {
+        let result = speedtest(&Pesto, Duration::from_millis(10)).unwrap();
+        assert!(result.movegen > 0.0);
+        assert!(result.make_move > 0.0);
+        assert!(result.evaluation > 0.0);
+        assert!(result.search > 0.0);
+        assert_eq!(result.to_string().lines().count(), 4);
+    }
+}
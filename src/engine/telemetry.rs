@@ -0,0 +1,248 @@
+//! Detailed report of the [`super::bench`] run for diagnosing performance
+//! regressions: the time it takes to reach the bench depth in each position
+//! and how the throughput scales with the number of threads.
+
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+use super::bench;
+use super::platform::{self, Spawner, ThreadPool, ThreadSpawner};
+use crate::evaluation::Evaluator;
+use crate::search;
+
+/// Time to reach the bench depth in a single position.
+#[derive(Clone, Debug)]
+pub struct PositionReport {
+    pub fen: String,
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+/// Combined throughput of the searches running in parallel.
+#[derive(Clone, Copy, Debug)]
+pub struct Scaling {
+    pub threads: usize,
+    pub nodes: u64,
+    pub elapsed: Duration,
+}
+
+impl Scaling {
+    #[must_use]
+    pub fn nps(&self) -> u64 {
+        search::nps(self.nodes, self.elapsed)
+    }
+}
+
+/// Average time it takes to start an empty task and wait for it.
+#[derive(Clone, Copy, Debug)]
+pub struct SpawnOverhead {
+    /// On a new thread, like [`ThreadSpawner`].
+    pub new_thread: Duration,
+    /// On a thread reused from the [`ThreadPool`].
+    pub pooled: Duration,
+}
+
+/// Number of the tasks [`SpawnOverhead`] is averaged over.
+const SPAWN_SAMPLES: u32 = 100;
+
+impl SpawnOverhead {
+    /// Measures the overhead of both spawners, pinning the pooled thread if
+    /// `affinity` is set.
+    ///
+    /// # Errors
+    ///
+    /// If a thread can not be created.
+    pub fn measure(affinity: bool) -> anyhow::Result<Self> {
+        fn average(spawner: &dyn Spawner) -> anyhow::Result<Duration> {
+            let started = Instant::now();
+            for _ in 0..SPAWN_SAMPLES {
+                let task = spawner.spawn("bench", Box::new(|| Ok(())))?;
+                if task.join().is_err() {
+                    anyhow::bail!("bench task panicked");
+                }
+            }
+            Ok(started.elapsed() / SPAWN_SAMPLES)
+        }
+        let pool = ThreadPool::new(1, affinity);
+        // Creates the thread that is reused by the measured tasks.
+        let _ = pool.spawn("bench", Box::new(|| Ok(())))?.join();
+        while pool.idle() == 0 {
+            thread::yield_now();
+        }
+        Ok(Self {
+            new_thread: average(&ThreadSpawner)?,
+            pooled: average(&pool)?,
+        })
+    }
+}
+
+/// Result of [`bench_report`], formatted as a table for humans.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub depth: u32,
+    pub positions: Vec<PositionReport>,
+    /// The scaling threads were pinned to the CPU cores.
+    pub affinity: bool,
+    /// One entry for each number of threads from 1 up to the requested one.
+    pub scaling: Vec<Scaling>,
+    pub spawn_overhead: SpawnOverhead,
+}
+
+/// Searches each position until `depth` and then runs the whole bench on 1 to
+/// `threads` threads at the same time, pinned to the CPU cores if `affinity`
+/// is set. Finally, measures the [`SpawnOverhead`] saved by reusing the search
+/// threads.
+///
+/// The search itself is single-threaded, so each thread runs an independent
+/// bench: the scaling shows the limits of the hardware (memory bandwidth,
+/// frequency scaling, shared caches) and any contention in the engine code,
+/// which bound the speedup of the parallel search.
+///
+/// # Errors
+///
+/// If a position is invalid or the search fails.
+pub fn bench_report(
+    positions: &[&str],
+    depth: u32,
+    threads: usize,
+    affinity: bool,
+    evaluator: &dyn Evaluator,
+) -> anyhow::Result<BenchReport> {
+    let positions = positions
+        .iter()
+        .map(|fen| {
+            let (nodes, elapsed) = bench(&[fen], depth, evaluator)?;
+            Ok(PositionReport {
+                fen: (*fen).to_string(),
+                nodes,
+                elapsed,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let fens: Vec<&str> = positions.iter().map(|report| report.fen.as_str()).collect();
+    let scaling = (1..=threads.max(1))
+        .map(|threads| {
+            let started = Instant::now();
+            let nodes = thread::scope(|scope| {
+                let handles: Vec<_> = (0..threads)
+                    .map(|index| {
+                        let fens = &fens;
+                        scope.spawn(move || {
+                            if affinity {
+                                let _ = platform::pin_to_core(index);
+                            }
+                            bench(fens, depth, evaluator)
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| match handle.join() {
+                        Ok(result) => result.map(|(nodes, _)| nodes),
+                        Err(_) => anyhow::bail!("bench thread panicked"),
+                    })
+                    .sum::<anyhow::Result<u64>>()
+            })?;
+            Ok(Scaling {
+                threads,
+                nodes,
+                elapsed: started.elapsed(),
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(BenchReport {
+        depth,
+        positions,
+        affinity,
+        scaling,
+        spawn_overhead: SpawnOverhead::measure(affinity)?,
+    })
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Time to depth {}:", self.depth)?;
+        writeln!(f, "{:>3} {:>9} {:>10}  position", "#", "nodes", "time (ms)")?;
+        for (index, report) in self.positions.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>3} {:>9} {:>10.1}  {}",
+                index + 1,
+                report.nodes,
+                report.elapsed.as_secs_f64() * 1000.0,
+                report.fen
+            )?;
+        }
+        writeln!(f)?;
+        if self.affinity {
+            writeln!(f, "Scaling (pinned to cores):")?;
+        } else {
+            writeln!(f, "Scaling:")?;
+        }
+        writeln!(
+            f,
+            "{:>7} {:>12} {:>8} {:>10}",
+            "threads", "nps", "speedup", "efficiency"
+        )?;
+        let base = self.scaling.first().map_or(1, Scaling::nps).max(1) as f64;
+        for scaling in &self.scaling {
+            let speedup = scaling.nps() as f64 / base;
+            writeln!(
+                f,
+                "{:>7} {:>12} {:>8.2} {:>9.0}%",
+                scaling.threads,
+                scaling.nps(),
+                speedup,
+                100.0 * speedup / scaling.threads as f64
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "Thread start: {:.1} us new, {:.1} us pooled",
+            self.spawn_overhead.new_thread.as_secs_f64() * 1e6,
+            self.spawn_overhead.pooled.as_secs_f64() * 1e6
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::BENCH_POSITIONS;
+    use crate::evaluation::Pesto;
+
+    #[test]
+    fn report() {
+        let report = bench_report(&BENCH_POSITIONS[..3], 2, 2, false, &Pesto).unwrap();
+        assert_eq!(report.positions.len(), 3);
+        let (nodes, _) = bench(&BENCH_POSITIONS[..3], 2, &Pesto).unwrap();
+        assert_eq!(
+            report
+                .positions
+                .iter()
+                .map(|position| position.nodes)
+                .sum::<u64>(),
+            nodes
+        );
+        // Every thread runs the whole bench.
+        assert_eq!(report.scaling.len(), 2);
+        assert_eq!(report.scaling[0].nodes, nodes);
+        assert_eq!(report.scaling[1].nodes, 2 * nodes);
+
+        let table = report.to_string();
+        assert!(table.starts_with("Time to depth 2:\n"), "{table}");
+        assert!(table.contains(BENCH_POSITIONS[2]), "{table}");
+        assert!(
+            table.contains("threads          nps  speedup efficiency\n"),
+            "{table}"
+        );
+        assert!(table.contains("\nThread start: "), "{table}");
+        assert_eq!(table.lines().count(), 2 + 3 + 1 + 2 + 2 + 2, "{table}");
+
+        let report = bench_report(&BENCH_POSITIONS[..1], 1, 2, true, &Pesto).unwrap();
+        assert_eq!(report.scaling[1].nodes, 2 * report.positions[0].nodes);
+        assert!(report.to_string().contains("Scaling (pinned to cores):\n"));
+    }
+}
@@ -1 +1,500 @@
+//! Decides how much time the engine should spend on the next move given the
+//! remaining time on the clock, the opponent's clock and the stage of the game.
 
+use std::time::Duration;
+
+/// If the number of moves until the next time control is not known, assume the
+/// game will last this many more moves.
+const DEFAULT_MOVES_TO_GO: u32 = 30;
+
+/// Never use more than this fraction of the remaining time for a single move.
+const MAX_TIME_FRACTION: u32 = 2;
+
+/// Time reserved for the communication with the server (e.g. network latency
+/// in online play) by default, see [`budget`].
+pub(super) const DEFAULT_MOVE_OVERHEAD: Duration = Duration::from_millis(50);
+
+/// Upper bound of the `MoveOverhead` option.
+pub(super) const MAX_MOVE_OVERHEAD: Duration = Duration::from_secs(5);
+
+/// Upper bound of the `TimeDelay` option, see [`TimeControl::Delay`].
+pub(super) const MAX_TIME_DELAY: Duration = Duration::from_secs(60);
+
+/// Default of the `TimeExtension` option: an unstable search can take up to 1.5
+/// times the regular budget, see [`max_time`].
+pub(super) const DEFAULT_TIME_EXTENSION: u32 = 150;
+
+/// Upper bound of the `TimeExtension` option, in percent.
+pub(super) const MAX_TIME_EXTENSION: u32 = 400;
+
+/// Fraction of the pondering time that is subtracted from the budget after a
+/// ponder hit: at most `1 / PONDER_CREDIT` of the budget is saved.
+const PONDER_CREDIT: u32 = 2;
+
+/// Time control of the game from the engine's point of view, see
+/// [`super::uci::GoParameters::time_control`].
+///
+/// The time that is not taken from the clock (delay and byo-yomi period) can
+/// be spent in full each move, while the increment is only partially used
+/// because the unspent time is saved for later moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum TimeControl {
+    /// The clock does not limit the search.
+    Unlimited,
+    /// The rest of the game has to be played in `time_left`, with the
+    /// `increment` added after each move (Fischer clock).
+    SuddenDeath {
+        time_left: Duration,
+        increment: Duration,
+    },
+    /// The next `moves_to_go` moves have to be played in `time_left`, after
+    /// which the clock receives more time (classical time control).
+    MovesToGo {
+        time_left: Duration,
+        moves_to_go: u32,
+        increment: Duration,
+    },
+    /// The clock only starts running after the `delay` (simple or Bronstein
+    /// delay: the clock never gains time).
+    Delay {
+        time_left: Duration,
+        delay: Duration,
+    },
+    /// Each move has to be made within the `period` once the main
+    /// `time_left` runs out (byo-yomi with a single period).
+    ByoYomi {
+        time_left: Duration,
+        period: Duration,
+    },
+}
+
+impl TimeControl {
+    /// Time on the clock excluding the delay or the byo-yomi period, [`None`]
+    /// if the time is not limited.
+    #[must_use]
+    pub(super) const fn time_left(self) -> Option<Duration> {
+        match self {
+            Self::Unlimited => None,
+            Self::SuddenDeath { time_left, .. }
+            | Self::MovesToGo { time_left, .. }
+            | Self::Delay { time_left, .. }
+            | Self::ByoYomi { time_left, .. } => Some(time_left),
+        }
+    }
+
+    /// Time that is available on each move without touching the clock.
+    const fn free_time(self) -> Duration {
+        match self {
+            Self::Delay { delay: free, .. } | Self::ByoYomi { period: free, .. } => free,
+            Self::Unlimited | Self::SuddenDeath { .. } | Self::MovesToGo { .. } => Duration::ZERO,
+        }
+    }
+
+    /// Upper bound on the time for a single move: a fraction of the clock and
+    /// the free time, without the `overhead`.
+    fn move_limit(self, overhead: Duration) -> Duration {
+        self.time_left().map_or(Duration::MAX, |time_left| {
+            (time_left / MAX_TIME_FRACTION)
+                .saturating_add(self.free_time())
+                .saturating_sub(overhead)
+        })
+    }
+}
+
+/// Returns the time budget for the next move or [`None`] if the time is not
+/// limited.
+///
+/// The `overhead` is subtracted from the budget to account for the time it
+/// takes the move to reach the server, so that the engine does not lose on
+/// time in online play. The budget is zero if the overhead exceeds it: the
+/// search still returns a move after the first playout.
+#[must_use]
+pub(super) fn budget(control: TimeControl, overhead: Duration) -> Option<Duration> {
+    let (time_left, moves_to_go, increment) = match control {
+        TimeControl::Unlimited => return None,
+        TimeControl::SuddenDeath {
+            time_left,
+            increment,
+        } => (time_left, DEFAULT_MOVES_TO_GO, increment),
+        TimeControl::MovesToGo {
+            time_left,
+            moves_to_go,
+            increment,
+        } => (time_left, moves_to_go.max(1), increment),
+        TimeControl::Delay { time_left, .. } | TimeControl::ByoYomi { time_left, .. } => {
+            (time_left, DEFAULT_MOVES_TO_GO, Duration::ZERO)
+        },
+    };
+    let free_time = control.free_time();
+    let budget = (time_left / moves_to_go)
+        .saturating_add(increment.saturating_mul(3) / 4)
+        .saturating_add(free_time);
+    Some(
+        budget
+            .min((time_left / MAX_TIME_FRACTION).saturating_add(free_time))
+            .saturating_sub(overhead),
+    )
+}
+
+/// The budget changes by at most this many percent depending on the stage of
+/// the game, see [`adjust`].
+const MAX_PHASE_ADJUSTMENT: u32 = 25;
+
+/// The budget changes by at most this many percent depending on the
+/// difference between the clocks, see [`adjust`].
+const MAX_CLOCK_ADJUSTMENT: u32 = 25;
+
+/// Adjusts the `budget` computed by [`budget`] for the game situation:
+///
+/// - The middlegame positions are the most complicated ones, the engine spends
+///   more time there and saves it in the opening and in simple endgames.
+///   `phase` is the game phase from 0 (only kings and pawns) to `max_phase`
+///   (all pieces are on the board).
+/// - If the engine has more time than the opponent, it can afford to think
+///   longer. If it is behind on the clock, it plays faster to avoid getting
+///   into time trouble.
+///
+/// The adjusted budget still uses at most the same fraction of the remaining
+/// time as [`budget`].
+#[must_use]
+pub(super) fn adjust(
+    budget: Duration,
+    control: TimeControl,
+    opponent_time: Option<Duration>,
+    phase: u32,
+    max_phase: u32,
+    overhead: Duration,
+) -> Duration {
+    let max_phase = max_phase.max(1);
+    let phase = phase.min(max_phase);
+    // Peaks in the middle of the game: the distance to the closest end of the
+    // phase range scaled to [0, 2 * MAX_PHASE_ADJUSTMENT].
+    let middlegame = 2 * phase.min(max_phase - phase) * 2 * MAX_PHASE_ADJUSTMENT / max_phase;
+    let phase_percent = 100 - MAX_PHASE_ADJUSTMENT + middlegame;
+    let clock_percent =
+        control
+            .time_left()
+            .zip(opponent_time)
+            .map_or(100, |(time_left, opponent_time)| {
+                clock_percent(time_left.as_millis(), opponent_time.as_millis())
+            });
+    let adjusted = budget.saturating_mul(phase_percent) / 100;
+    let adjusted = adjusted.saturating_mul(clock_percent) / 100;
+    adjusted.min(control.move_limit(overhead))
+}
+
+/// Returns the budget scale in percent for the clocks: half of the relative
+/// advantage, within [`MAX_CLOCK_ADJUSTMENT`].
+fn clock_percent(ours: u128, theirs: u128) -> u32 {
+    let difference = ours.abs_diff(theirs).saturating_mul(50) / theirs.max(ours).max(1);
+    let difference = u32::try_from(difference)
+        .unwrap_or(u32::MAX)
+        .min(MAX_CLOCK_ADJUSTMENT);
+    if ours >= theirs {
+        100 + difference
+    } else {
+        100 - difference
+    }
+}
+
+/// Returns the deadline for the search that has not settled on the best move
+/// after spending the `budget` (see [`crate::search::Stability`]):
+/// `extension` percent of the budget, but still within the fraction of the
+/// remaining time a single move can use.
+#[must_use]
+pub(super) fn max_time(
+    budget: Duration,
+    control: TimeControl,
+    overhead: Duration,
+    extension: u32,
+) -> Duration {
+    let extended = budget.saturating_mul(extension) / 100;
+    extended.min(control.move_limit(overhead)).max(budget)
+}
+
+/// Returns the time budget for the rest of the search after a ponder hit.
+///
+/// The clock only starts running once the opponent actually played the
+/// expected move, but the tree built while pondering is kept, so part of the
+/// pondering time is credited towards the regular `budget`. After a ponder miss
+/// the search restarts from the actual position and gets the full budget.
+#[must_use]
+pub(super) fn after_ponder_hit(budget: Duration, pondered: Duration) -> Duration {
+    budget - pondered.min(budget) / PONDER_CREDIT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sudden_death(time_left: Duration, increment: Duration) -> TimeControl {
+        TimeControl::SuddenDeath {
+            time_left,
+            increment,
+        }
+    }
+
+    fn moves_to_go(time_left: Duration, moves_to_go: u32) -> TimeControl {
+        TimeControl::MovesToGo {
+            time_left,
+            moves_to_go,
+            increment: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn unlimited() {
+        assert_eq!(budget(TimeControl::Unlimited, Duration::ZERO), None);
+        assert_eq!(TimeControl::Unlimited.time_left(), None);
+    }
+
+    #[test]
+    fn sudden_death_budget() {
+        assert_eq!(
+            budget(
+                sudden_death(Duration::from_secs(60), Duration::ZERO),
+                Duration::ZERO
+            ),
+            Some(Duration::from_secs(2))
+        );
+    }
+
+    #[test]
+    fn moves_to_go_budget() {
+        assert_eq!(
+            budget(moves_to_go(Duration::from_secs(60), 10), Duration::ZERO),
+            Some(Duration::from_secs(6))
+        );
+        // The last move before time control should not use all the time.
+        assert_eq!(
+            budget(moves_to_go(Duration::from_secs(60), 1), Duration::ZERO),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            budget(moves_to_go(Duration::from_secs(60), 0), Duration::ZERO),
+            Some(Duration::from_secs(30))
+        );
+        // The increment is added on top of the share of the remaining time.
+        assert_eq!(
+            budget(
+                TimeControl::MovesToGo {
+                    time_left: Duration::from_secs(60),
+                    moves_to_go: 10,
+                    increment: Duration::from_secs(4),
+                },
+                Duration::ZERO
+            ),
+            Some(Duration::from_secs(9))
+        );
+    }
+
+    #[test]
+    fn increment() {
+        assert_eq!(
+            budget(
+                sudden_death(Duration::from_secs(30), Duration::from_secs(4)),
+                Duration::ZERO
+            ),
+            Some(Duration::from_secs(4))
+        );
+        // Increment can not make the budget exceed the remaining time.
+        assert_eq!(
+            budget(
+                sudden_death(Duration::from_millis(100), Duration::from_secs(10)),
+                Duration::ZERO
+            ),
+            Some(Duration::from_millis(50))
+        );
+    }
+
+    #[test]
+    fn delay_and_byo_yomi() {
+        let time_left = Duration::from_secs(30);
+        let free = Duration::from_secs(4);
+        let delay = TimeControl::Delay {
+            time_left,
+            delay: free,
+        };
+        let byo_yomi = TimeControl::ByoYomi {
+            time_left,
+            period: free,
+        };
+        // Unlike the increment, the time not spent is lost: use all of it.
+        assert_eq!(
+            budget(sudden_death(time_left, free), Duration::ZERO),
+            Some(Duration::from_secs(4))
+        );
+        assert_eq!(budget(delay, Duration::ZERO), Some(Duration::from_secs(5)));
+        assert_eq!(
+            budget(byo_yomi, DEFAULT_MOVE_OVERHEAD),
+            Some(Duration::from_millis(4950))
+        );
+        // Only the period is left: the move still has to be made in time.
+        let period_only = TimeControl::ByoYomi {
+            time_left: Duration::ZERO,
+            period: free,
+        };
+        assert_eq!(
+            budget(period_only, DEFAULT_MOVE_OVERHEAD),
+            Some(Duration::from_millis(3950))
+        );
+        assert_eq!(
+            max_time(
+                Duration::from_millis(3950),
+                period_only,
+                DEFAULT_MOVE_OVERHEAD,
+                MAX_TIME_EXTENSION
+            ),
+            Duration::from_millis(3950)
+        );
+        assert_eq!(
+            adjust(
+                Duration::from_secs(10),
+                period_only,
+                None,
+                12,
+                24,
+                DEFAULT_MOVE_OVERHEAD
+            ),
+            Duration::from_millis(3950)
+        );
+        // Time left on the clock is still split between the moves.
+        let long_delay = TimeControl::Delay {
+            time_left: Duration::from_secs(1),
+            delay: Duration::from_secs(10),
+        };
+        assert_eq!(
+            max_time(
+                Duration::from_secs(10),
+                long_delay,
+                Duration::ZERO,
+                MAX_TIME_EXTENSION
+            ),
+            Duration::from_millis(10500)
+        );
+    }
+
+    #[test]
+    fn move_overhead() {
+        let time_left = sudden_death(Duration::from_secs(60), Duration::ZERO);
+        assert_eq!(
+            budget(time_left, DEFAULT_MOVE_OVERHEAD),
+            Some(Duration::from_millis(1950))
+        );
+        // The budget never goes negative.
+        assert_eq!(budget(time_left, MAX_MOVE_OVERHEAD), Some(Duration::ZERO));
+        let flagging = sudden_death(Duration::ZERO, Duration::ZERO);
+        assert_eq!(
+            budget(flagging, DEFAULT_MOVE_OVERHEAD),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(budget(flagging, Duration::MAX), Some(Duration::ZERO));
+        // Extreme clocks do not overflow.
+        let extreme = Duration::from_millis(u64::MAX);
+        assert!(budget(sudden_death(extreme, extreme), DEFAULT_MOVE_OVERHEAD).is_some());
+        let extreme_moves = TimeControl::MovesToGo {
+            time_left: extreme,
+            moves_to_go: u32::MAX,
+            increment: extreme,
+        };
+        assert!(budget(extreme_moves, MAX_MOVE_OVERHEAD).is_some());
+        let extreme_delay = TimeControl::Delay {
+            time_left: extreme,
+            delay: Duration::MAX,
+        };
+        assert!(budget(extreme_delay, MAX_MOVE_OVERHEAD).is_some());
+    }
+
+    #[test]
+    fn extension() {
+        let control = sudden_death(Duration::from_secs(60), Duration::ZERO);
+        let budget = budget(control, Duration::ZERO).unwrap();
+        assert_eq!(
+            max_time(budget, control, Duration::ZERO, DEFAULT_TIME_EXTENSION),
+            Duration::from_secs(3)
+        );
+        assert_eq!(max_time(budget, control, Duration::ZERO, 100), budget);
+        // The extension is limited by the remaining time.
+        assert_eq!(
+            max_time(budget, control, DEFAULT_MOVE_OVERHEAD, MAX_TIME_EXTENSION),
+            Duration::from_secs(8)
+        );
+        let control = moves_to_go(Duration::from_secs(5), 1);
+        assert_eq!(
+            max_time(Duration::from_secs(2), control, Duration::ZERO, 300),
+            Duration::from_millis(2500)
+        );
+        // Never less than the budget.
+        assert_eq!(
+            max_time(Duration::from_secs(2), control, Duration::from_secs(1), 50),
+            Duration::from_secs(2)
+        );
+    }
+
+    #[test]
+    fn game_phase() {
+        let budget = Duration::from_secs(4);
+        let control = sudden_death(Duration::from_secs(60), Duration::ZERO);
+        let adjust = |phase| adjust(budget, control, None, phase, 24, Duration::ZERO);
+        assert_eq!(adjust(24), Duration::from_secs(3));
+        assert_eq!(adjust(12), Duration::from_secs(5));
+        assert_eq!(adjust(0), Duration::from_secs(3));
+        assert!(adjust(6) > adjust(24));
+        assert!(adjust(18) < adjust(12));
+        // Early promotions might push the phase above the maximum.
+        assert_eq!(adjust(30), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn opponent_clock() {
+        let budget = Duration::from_secs(4);
+        let time_left = Duration::from_secs(60);
+        let control = sudden_death(time_left, Duration::ZERO);
+        let adjust = |opponent| adjust(budget, control, opponent, 12, 24, Duration::ZERO) * 4 / 5;
+        assert_eq!(adjust(None), budget);
+        assert_eq!(adjust(Some(time_left)), budget);
+        // 20% behind on the clock: 10% less time.
+        assert_eq!(
+            adjust(Some(Duration::from_secs(75))),
+            Duration::from_millis(3600)
+        );
+        assert_eq!(
+            adjust(Some(Duration::from_secs(48))),
+            Duration::from_millis(4400)
+        );
+        // The adjustment is limited.
+        assert_eq!(adjust(Some(Duration::ZERO)), Duration::from_secs(5));
+        assert_eq!(
+            adjust(Some(Duration::from_secs(3600))),
+            Duration::from_secs(3)
+        );
+        // The extended budget still does not exceed the fraction of the
+        // remaining time.
+        assert_eq!(
+            super::adjust(
+                Duration::from_secs(30),
+                control,
+                Some(Duration::from_secs(1)),
+                12,
+                24,
+                DEFAULT_MOVE_OVERHEAD
+            ),
+            Duration::from_millis(29950)
+        );
+    }
+
+    #[test]
+    fn ponder_hit() {
+        let budget = Duration::from_secs(4);
+        assert_eq!(after_ponder_hit(budget, Duration::ZERO), budget);
+        assert_eq!(
+            after_ponder_hit(budget, Duration::from_secs(2)),
+            Duration::from_secs(3)
+        );
+        // Long pondering can not reduce the budget below a half.
+        assert_eq!(
+            after_ponder_hit(budget, Duration::from_secs(60)),
+            Duration::from_secs(2)
+        );
+    }
+}
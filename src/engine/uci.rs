@@ -1,5 +1,9 @@
 use std::time::Duration;
 
+use super::time_manager::{TimeControl, MAX_MOVE_OVERHEAD, MAX_TIME_DELAY, MAX_TIME_EXTENSION};
+use crate::environment::Player;
+use crate::evaluation::BlendWeights;
+
 #[derive(Debug, PartialEq)]
 pub(super) enum Command {
     Uci,
@@ -16,12 +20,10 @@ pub(super) enum Command {
         moves: Vec<String>,
     },
     NewGame,
-    Go {
-        wtime: Option<Duration>,
-        btime: Option<Duration>,
-        winc: Option<Duration>,
-        binc: Option<Duration>,
-    },
+    Go(GoParameters),
+    /// The opponent played the expected move: the ponder search continues as
+    /// a regular search.
+    PonderHit,
     Stop,
     Quit,
     /// This is an extension to the UCI protocol useful for debugging. The
@@ -29,100 +31,404 @@ pub(super) enum Command {
     /// the engine internal state (current settings, search options,
     /// transposition table information and so on).
     State,
+    /// This is an extension to the UCI protocol useful for debugging move
+    /// generation. The response will contain the attack information of the
+    /// current position (attacked squares, checkers, pins and safe king
+    /// squares) as ASCII grids.
+    Attacks,
+    /// This is an extension to the UCI protocol useful for debugging the
+    /// search. The response will contain visits, average value, prior and the
+    /// principal variation of each root move in the last finished search.
+    RootStats,
+    /// The `setoption` command names an unknown option or has an invalid
+    /// value. The message explains what is wrong.
+    InvalidOption(String),
+    /// This is an extension to the UCI protocol useful for detecting position
+    /// desyncs between the GUI and the engine. The response will contain the
+    /// Zobrist key of the current position and the number of moves applied
+    /// on top of the initial position, which can be compared with another
+    /// engine or a reference implementation.
+    Hash,
+    /// This is an extension to the UCI protocol useful for debugging move
+    /// generation. The response will contain all legal moves of the current
+    /// position in UCI and SAN notation grouped by the moving piece, which
+    /// makes it easy to compare the engine's move generation with the GUI.
+    LegalMoves,
     Unknown(String),
 }
 
-#[derive(Debug, PartialEq)]
+/// Arguments of the `go` command: time control and search limits. All time
+/// values are sent in milliseconds.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct GoParameters {
+    pub(super) wtime: Option<Duration>,
+    pub(super) btime: Option<Duration>,
+    pub(super) winc: Option<Duration>,
+    pub(super) binc: Option<Duration>,
+    pub(super) movestogo: Option<u32>,
+    /// Byo-yomi period: an extension to the UCI protocol borrowed from USI
+    /// and sent by some GUIs.
+    pub(super) byoyomi: Option<Duration>,
+    pub(super) movetime: Option<Duration>,
+    pub(super) depth: Option<u32>,
+    pub(super) nodes: Option<u64>,
+    /// Search for a mate in given number of moves.
+    pub(super) mate: Option<u32>,
+    pub(super) infinite: bool,
+    /// Search the position after the expected opponent move until `ponderhit`
+    /// or `stop` is received.
+    pub(super) ponder: bool,
+}
+
+impl GoParameters {
+    /// Interprets the clock of the `player` to move as one of the supported
+    /// [`TimeControl`] schemes. UCI does not send the clock `delay`, it comes
+    /// from the `TimeDelay` option.
+    pub(super) fn time_control(&self, player: Player, delay: Duration) -> TimeControl {
+        let (time_left, increment) = match player {
+            Player::White => (self.wtime, self.winc),
+            Player::Black => (self.btime, self.binc),
+        };
+        let Some(time_left) = time_left else {
+            return TimeControl::Unlimited;
+        };
+        let increment = increment.unwrap_or(Duration::ZERO);
+        match (self.byoyomi, self.movestogo) {
+            (Some(period), _) if !period.is_zero() => TimeControl::ByoYomi { time_left, period },
+            _ if !delay.is_zero() => TimeControl::Delay { time_left, delay },
+            (_, Some(moves_to_go)) => TimeControl::MovesToGo {
+                time_left,
+                moves_to_go,
+                increment,
+            },
+            _ => TimeControl::SuddenDeath {
+                time_left,
+                increment,
+            },
+        }
+    }
+
+    /// Returns the time left on the clock of the opponent of the `player`.
+    pub(super) const fn opponent_time(&self, player: Player) -> Option<Duration> {
+        match player {
+            Player::White => self.btime,
+            Player::Black => self.wtime,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(super) enum EngineOption {
+    /// Memory for the search tree in megabytes or `auto`, see
+    /// [`crate::search::mcts::Config::tree_memory`].
     Hash,
     SyzygyTablebase,
+    /// Number of the search threads kept between the searches, see
+    /// [`super::platform::ThreadPool`].
     Threads,
+    /// Pin the search threads to the CPU cores, e.g. on NUMA machines.
+    ThreadAffinity,
+    /// Report the search statistics in JSON format after each search.
+    SearchStats,
+    /// Show the moves in the logs (`info string`) in SAN next to UCI.
+    LogSan,
+    /// The engine is used for analysis rather than playing a game.
+    AnalyseMode,
+    /// The server is allowed to send `go ponder`.
+    Ponder,
+    /// Send the win, draw and loss probabilities with the score.
+    ShowWdl,
+    /// Castling moves are encoded as the king capturing its own rook.
+    Chess960,
+    /// Number of the moves with the highest priors sent when the search
+    /// starts, 0 to disable.
+    PolicyMoves,
+    /// Exploration constant of the search in hundredths, see
+    /// [`crate::search::mcts::Config::cpuct`].
+    Cpuct,
+    /// First Play Urgency reduction in hundredths, see
+    /// [`crate::search::mcts::Config::fpu_reduction`].
+    FpuReduction,
+    /// Softmax temperature of the policy in hundredths, see
+    /// [`crate::search::mcts::Config::policy_temperature`].
+    PolicyTemperature,
+    /// Multiplier of the checking moves' priors in hundredths, see
+    /// [`crate::search::mcts::Config::check_prior_boost`].
+    CheckBoost,
+    /// Milliseconds subtracted from each move's time budget to compensate for
+    /// the communication delays.
+    MoveOverhead,
+    /// Maximum time the search can spend on an unstable move, in percent of
+    /// the regular time budget.
+    TimeExtension,
+    /// Milliseconds the clock waits on each move before it starts running,
+    /// which UCI does not send.
+    TimeDelay,
+    /// Run a tiny search on `isready` after `ucinewgame` so that the first
+    /// move of the game does not pay for the page faults.
+    Warmup,
+    /// Node limit of each search when `go` does not set one, 0 for no limit.
+    NodesPerMove,
+    /// Weights of the classical evaluation blended into the evaluator's
+    /// predictions in percent, see [`crate::evaluation::BlendWeights`].
+    BlendMiddlegame,
+    BlendEndgame,
+    BlendKnownEndgame,
+    /// Opening variety, see [`crate::search::mcts::Config::root_jitter`].
+    RootJitter,
+    RootJitterPlies,
+    RootJitterSeed,
+    /// Maximum number of moves in the reported principal variations.
+    MaxPvLength,
+    /// Draw avoidance in centipawns, see
+    /// [`crate::search::mcts::Config::contempt`].
+    Contempt,
+    /// Progressive widening parameters in hundredths, see
+    /// [`crate::search::mcts::Config::widening_base`].
+    WideningBase,
+    WideningExponent,
+    /// Root move pruning margin in centipawns and the visits before a move can
+    /// be pruned, see [`crate::search::mcts::Config::root_pruning`].
+    RootPruning,
+    RootPruningVisits,
+    /// File for the statistics of the match written on `quit`, see
+    /// [`super::match_stats`].
+    StatsFile,
+    /// Network weights loaded in the background and used from the next
+    /// search on.
+    EvalFile,
 }
 
-#[derive(Debug, PartialEq)]
-pub(super) enum OptionValue {
-    Integer(usize),
-    String(String),
+/// Type of the option value and its allowed range, announced in the `uci`
+/// handshake.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum OptionKind {
+    Check,
+    Spin { min: usize, max: usize },
+    String,
 }
 
-fn parse_go(parts: &[&str]) -> Command {
-    let mut wtime = None;
-    let mut btime = None;
-    let mut winc = None;
-    let mut binc = None;
+impl EngineOption {
+    /// All options in the order of the handshake.
+    pub(super) const ALL: [Self; 34] = [
+        Self::Hash,
+        Self::Threads,
+        Self::ThreadAffinity,
+        Self::SearchStats,
+        Self::LogSan,
+        Self::AnalyseMode,
+        Self::Ponder,
+        Self::ShowWdl,
+        Self::Chess960,
+        Self::PolicyMoves,
+        Self::SyzygyTablebase,
+        Self::Cpuct,
+        Self::FpuReduction,
+        Self::PolicyTemperature,
+        Self::CheckBoost,
+        Self::MoveOverhead,
+        Self::TimeExtension,
+        Self::TimeDelay,
+        Self::Warmup,
+        Self::NodesPerMove,
+        Self::BlendMiddlegame,
+        Self::BlendEndgame,
+        Self::BlendKnownEndgame,
+        Self::RootJitter,
+        Self::RootJitterPlies,
+        Self::RootJitterSeed,
+        Self::MaxPvLength,
+        Self::Contempt,
+        Self::WideningBase,
+        Self::WideningExponent,
+        Self::RootPruning,
+        Self::RootPruningVisits,
+        Self::StatsFile,
+        Self::EvalFile,
+    ];
 
-    let mut i = 1;
+    pub(super) const fn name(self) -> &'static str {
+        match self {
+            Self::Hash => "Hash",
+            Self::SyzygyTablebase => "SyzygyTablebase",
+            Self::Threads => "Threads",
+            Self::ThreadAffinity => "ThreadAffinity",
+            Self::SearchStats => "SearchStats",
+            Self::LogSan => "LogSan",
+            Self::AnalyseMode => "UCI_AnalyseMode",
+            Self::Ponder => "Ponder",
+            Self::ShowWdl => "UCI_ShowWDL",
+            Self::Chess960 => "UCI_Chess960",
+            Self::PolicyMoves => "PolicyMoves",
+            Self::Cpuct => "CPuct",
+            Self::FpuReduction => "FpuReduction",
+            Self::PolicyTemperature => "PolicyTemperature",
+            Self::CheckBoost => "CheckBoost",
+            Self::MoveOverhead => "MoveOverhead",
+            Self::TimeExtension => "TimeExtension",
+            Self::TimeDelay => "TimeDelay",
+            Self::Warmup => "Warmup",
+            Self::NodesPerMove => "NodesPerMove",
+            Self::BlendMiddlegame => "BlendMiddlegame",
+            Self::BlendEndgame => "BlendEndgame",
+            Self::BlendKnownEndgame => "BlendKnownEndgame",
+            Self::RootJitter => "RootJitter",
+            Self::RootJitterPlies => "RootJitterPlies",
+            Self::RootJitterSeed => "RootJitterSeed",
+            Self::MaxPvLength => "MaxPvLength",
+            Self::Contempt => "Contempt",
+            Self::WideningBase => "WideningBase",
+            Self::WideningExponent => "WideningExponent",
+            Self::RootPruning => "RootPruning",
+            Self::RootPruningVisits => "RootPruningVisits",
+            Self::StatsFile => "StatsFile",
+            Self::EvalFile => "EvalFile",
+        }
+    }
 
-    while i < parts.len() {
-        match parts[i] {
-            "wtime" if i + 1 < parts.len() => {
-                wtime = parts[i + 1].parse().map(Duration::from_micros).ok();
+    pub(super) const fn kind(self) -> OptionKind {
+        const fn spin(min: usize, max: usize) -> OptionKind {
+            OptionKind::Spin { min, max }
+        }
+        match self {
+            Self::Hash => spin(1, super::MAX_HASH_MEGABYTES),
+            Self::Threads => spin(1, super::MAX_THREADS),
+            Self::ThreadAffinity
+            | Self::SearchStats
+            | Self::LogSan
+            | Self::AnalyseMode
+            | Self::Ponder
+            | Self::ShowWdl
+            | Self::Chess960
+            | Self::Warmup => OptionKind::Check,
+            Self::SyzygyTablebase | Self::StatsFile | Self::EvalFile => OptionKind::String,
+            Self::Cpuct => spin(0, 10000),
+            Self::FpuReduction => spin(0, 200),
+            Self::PolicyTemperature => spin(1, 1000),
+            Self::CheckBoost => spin(100, super::MAX_CHECK_BOOST),
+            Self::MoveOverhead => spin(0, MAX_MOVE_OVERHEAD.as_millis() as usize),
+            Self::TimeExtension => spin(100, MAX_TIME_EXTENSION as usize),
+            Self::TimeDelay => spin(0, MAX_TIME_DELAY.as_millis() as usize),
+            Self::NodesPerMove | Self::RootJitterSeed => spin(0, i32::MAX as usize),
+            Self::BlendMiddlegame | Self::BlendEndgame | Self::BlendKnownEndgame => {
+                spin(0, BlendWeights::MAX as usize)
             },
-            "btime" if i + 1 < parts.len() => {
-                btime = parts[i + 1].parse().map(Duration::from_micros).ok();
+            Self::RootJitter => spin(0, super::MAX_ROOT_JITTER),
+            Self::RootJitterPlies => spin(0, 1000),
+            Self::MaxPvLength => spin(1, super::MAX_PV_LENGTH),
+            Self::PolicyMoves => spin(0, super::MAX_POLICY_MOVES),
+            Self::Contempt => spin(0, super::MAX_CONTEMPT),
+            Self::WideningBase => spin(0, 10000),
+            Self::WideningExponent => spin(0, 100),
+            Self::RootPruning => spin(0, 1000),
+            Self::RootPruningVisits => spin(1, 1_000_000),
+        }
+    }
+
+    /// Finds the option by name. The names are case-insensitive.
+    pub(super) fn find(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|option| option.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Checks that the value has the right type and is within the allowed
+    /// range. The error describes the expected value.
+    pub(super) fn parse_value(self, value: &str) -> Result<OptionValue, String> {
+        match self.kind() {
+            // Sized by the available memory.
+            OptionKind::Spin { .. } if self == Self::Hash && value == "auto" => {
+                Ok(OptionValue::String(value.to_string()))
             },
-            "winc" if i + 1 < parts.len() => {
-                winc = parts[i + 1].parse().map(Duration::from_micros).ok();
+            OptionKind::Spin { min, max } => match value.parse::<usize>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(OptionValue::Integer(number)),
+                _ => Err(format!(
+                    "expected an integer between {min} and {max}, got {value:?}"
+                )),
             },
-            "binc" if i + 1 < parts.len() => {
-                binc = parts[i + 1].parse().map(Duration::from_micros).ok();
+            OptionKind::Check => match value.to_ascii_lowercase().as_str() {
+                "true" => Ok(OptionValue::Boolean(true)),
+                "false" => Ok(OptionValue::Boolean(false)),
+                _ => Err(format!("expected true or false, got {value:?}")),
             },
-            _ => {},
-        }
-        if parts[i] == "infinite" {
-            i += 1;
-        } else {
-            i += 2;
+            OptionKind::String if value.is_empty() => Err("missing value".to_string()),
+            OptionKind::String => Ok(OptionValue::String(value.to_string())),
         }
     }
+}
+
+#[derive(Debug, PartialEq)]
+pub(super) enum OptionValue {
+    Integer(usize),
+    String(String),
+    Boolean(bool),
+}
 
-    Command::Go {
-        wtime,
-        btime,
-        winc,
-        binc,
+fn parse_go(parts: &[&str]) -> Command {
+    fn parse_millis(value: Option<&&str>) -> Option<Duration> {
+        value?.parse().map(Duration::from_millis).ok()
+    }
+    fn parse_number<T: std::str::FromStr>(value: Option<&&str>) -> Option<T> {
+        value?.parse().ok()
+    }
+
+    let mut parameters = GoParameters::default();
+    let mut tokens = parts.iter().skip(1);
+    while let Some(&token) = tokens.next() {
+        match token {
+            "wtime" => parameters.wtime = parse_millis(tokens.next()),
+            "btime" => parameters.btime = parse_millis(tokens.next()),
+            "winc" => parameters.winc = parse_millis(tokens.next()),
+            "binc" => parameters.binc = parse_millis(tokens.next()),
+            "movestogo" => parameters.movestogo = parse_number(tokens.next()),
+            "byoyomi" => parameters.byoyomi = parse_millis(tokens.next()),
+            "movetime" => parameters.movetime = parse_millis(tokens.next()),
+            "depth" => parameters.depth = parse_number(tokens.next()),
+            "nodes" => parameters.nodes = parse_number(tokens.next()),
+            "mate" => parameters.mate = parse_number(tokens.next()),
+            "infinite" => parameters.infinite = true,
+            "ponder" => parameters.ponder = true,
+            _ => {},
+        }
     }
+    Command::Go(parameters)
 }
 
 fn parse_setoption(parts: &[&str]) -> Command {
-    if parts.len() > 3 && parts[1] == "name" {
-        let name_end = parts
-            .iter()
-            .position(|&x| x == "value")
-            .unwrap_or(parts.len());
-        let option = parts[2..name_end].join(" ");
-        let option = match option.as_str() {
-            "Hash" => EngineOption::Hash,
-            "SyzygyTablebase" => EngineOption::SyzygyTablebase,
-            "Threads" => EngineOption::Threads,
-            _ => return Command::Unknown(parts.join(" ")),
-        };
-        let value = if name_end < parts.len() {
-            match option {
-                EngineOption::Hash | EngineOption::Threads => parts[name_end + 1]
-                    .parse::<usize>()
-                    .ok()
-                    .map(OptionValue::Integer),
-                EngineOption::SyzygyTablebase => {
-                    Some(OptionValue::String(parts[name_end + 1..].join(" ")))
-                },
-            }
-        } else {
-            None
-        };
-        if let Some(value) = value {
-            Command::SetOption { option, value }
-        } else {
-            Command::Unknown(parts.join(" "))
-        }
-    } else {
-        Command::Unknown(parts.join(" "))
+    if parts.len() < 3 || parts[1] != "name" {
+        return Command::Unknown(parts.join(" "));
+    }
+    let name_end = parts
+        .iter()
+        .position(|&x| x == "value")
+        .unwrap_or(parts.len());
+    let name = parts[2..name_end].join(" ");
+    let Some(option) = EngineOption::find(&name) else {
+        return Command::InvalidOption(format!("Unknown option: {name}"));
+    };
+    let value = parts.get(name_end + 1..).unwrap_or_default().join(" ");
+    match option.parse_value(&value) {
+        Ok(value) => Command::SetOption { option, value },
+        Err(message) => Command::InvalidOption(format!(
+            "Invalid value for option {}: {message}",
+            option.name()
+        )),
     }
 }
 
+/// Parses `position [startpos | fen <FEN>] [moves <move>...]`. The FEN is
+/// everything between `fen` and `moves`, so both the full 6-field FEN and the
+/// 4-field EPD-style FEN some GUIs send are passed to the [`Position`] parser
+/// as is.
+///
+/// [`Position`]: crate::chess::position::Position
 fn parse_setposition(parts: &[&str]) -> Command {
     let fen_index = parts.iter().position(|&x| x == "fen");
-    let moves_index = parts.iter().position(|&x| x == "moves");
+    let moves_index = parts
+        .iter()
+        .skip(fen_index.unwrap_or(0))
+        .position(|&x| x == "moves")
+        .map(|index| index + fen_index.unwrap_or(0));
     let fen = fen_index.map(|index| parts[index + 1..moves_index.unwrap_or(parts.len())].join(" "));
     let moves = if let Some(moves_index) = moves_index {
         parts[moves_index + 1..]
@@ -153,9 +459,14 @@ impl Command {
             "position" => parse_setposition(&parts),
             "ucinewgame" => Self::NewGame,
             "go" => parse_go(&parts),
+            "ponderhit" => Self::PonderHit,
             "stop" => Self::Stop,
             "quit" => Self::Quit,
             "state" => Self::State,
+            "attacks" => Self::Attacks,
+            "rootstats" => Self::RootStats,
+            "hash" => Self::Hash,
+            "legalmoves" => Self::LegalMoves,
             _ => Self::Unknown(input.to_string()),
         }
     }
@@ -190,6 +501,28 @@ mod tests {
                 value: OptionValue::Integer(128)
             }
         );
+        assert_eq!(
+            Command::parse("setoption name Hash value auto"),
+            Command::SetOption {
+                option: EngineOption::Hash,
+                value: OptionValue::String("auto".to_string())
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name Hash value large"),
+            Command::InvalidOption(
+                "Invalid value for option Hash: expected an integer between 1 and 1048576, got \
+                 \"large\""
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            Command::parse("setoption name UCI_Chess960 value true"),
+            Command::SetOption {
+                option: EngineOption::Chess960,
+                value: OptionValue::Boolean(true)
+            }
+        );
         assert_eq!(
             Command::parse("setoption name SyzygyTablebase value /path/to/tablebase"),
             Command::SetOption {
@@ -197,6 +530,13 @@ mod tests {
                 value: OptionValue::String("/path/to/tablebase".to_string())
             }
         );
+        assert_eq!(
+            Command::parse("setoption name EvalFile value /path/to/my net.safetensors"),
+            Command::SetOption {
+                option: EngineOption::EvalFile,
+                value: OptionValue::String("/path/to/my net.safetensors".to_string())
+            }
+        );
         assert_eq!(
             Command::parse("setoption name Threads value 4"),
             Command::SetOption {
@@ -205,8 +545,128 @@ mod tests {
             }
         );
         assert_eq!(
-            Command::parse("setoption name InvalidOption value 123"),
-            Command::Unknown("setoption name InvalidOption value 123".to_string())
+            Command::parse("setoption name SearchStats value true"),
+            Command::SetOption {
+                option: EngineOption::SearchStats,
+                value: OptionValue::Boolean(true)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name LogSan value true"),
+            Command::SetOption {
+                option: EngineOption::LogSan,
+                value: OptionValue::Boolean(true)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name Warmup value true"),
+            Command::SetOption {
+                option: EngineOption::Warmup,
+                value: OptionValue::Boolean(true)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name UCI_AnalyseMode value false"),
+            Command::SetOption {
+                option: EngineOption::AnalyseMode,
+                value: OptionValue::Boolean(false)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name CPuct value 250"),
+            Command::SetOption {
+                option: EngineOption::Cpuct,
+                value: OptionValue::Integer(250)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name FpuReduction value 30"),
+            Command::SetOption {
+                option: EngineOption::FpuReduction,
+                value: OptionValue::Integer(30)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name PolicyTemperature value 120"),
+            Command::SetOption {
+                option: EngineOption::PolicyTemperature,
+                value: OptionValue::Integer(120)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name MoveOverhead value 100"),
+            Command::SetOption {
+                option: EngineOption::MoveOverhead,
+                value: OptionValue::Integer(100)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name TimeExtension value 200"),
+            Command::SetOption {
+                option: EngineOption::TimeExtension,
+                value: OptionValue::Integer(200)
+            }
+        );
+        assert_eq!(
+            Command::parse("setoption name TimeDelay value 5000"),
+            Command::SetOption {
+                option: EngineOption::TimeDelay,
+                value: OptionValue::Integer(5000)
+            }
+        );
+        // Option names and check values are case-insensitive.
+        assert_eq!(
+            Command::parse("setoption name logsan value True"),
+            Command::SetOption {
+                option: EngineOption::LogSan,
+                value: OptionValue::Boolean(true)
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_setoption() {
+        let message = |input: &str| match Command::parse(input) {
+            Command::InvalidOption(message) => message,
+            command => panic!("{input}: {command:?}"),
+        };
+        assert_eq!(
+            message("setoption name MoveOverhead value -1"),
+            "Invalid value for option MoveOverhead: expected an integer between 0 and 5000, got \
+             \"-1\""
+        );
+        assert_eq!(
+            message("setoption name Hash value 0"),
+            "Invalid value for option Hash: expected an integer between 1 and 1048576, got \"0\""
+        );
+        assert_eq!(
+            message("setoption name Threads value 513"),
+            "Invalid value for option Threads: expected an integer between 1 and 512, got \"513\""
+        );
+        assert_eq!(
+            message("setoption name CheckBoost value 1.5"),
+            "Invalid value for option CheckBoost: expected an integer between 100 and 1000, got \
+             \"1.5\""
+        );
+        assert_eq!(
+            message("setoption name SearchStats value yes"),
+            "Invalid value for option SearchStats: expected true or false, got \"yes\""
+        );
+        assert_eq!(
+            message("setoption name Warmup"),
+            "Invalid value for option Warmup: expected true or false, got \"\""
+        );
+        assert_eq!(
+            message("setoption name SyzygyTablebase value"),
+            "Invalid value for option SyzygyTablebase: missing value"
+        );
+        assert_eq!(
+            message("setoption name InvalidOption value 123"),
+            "Unknown option: InvalidOption"
+        );
+        assert_eq!(
+            Command::parse("setoption value 123"),
+            Command::Unknown("setoption value 123".to_string())
         );
     }
 
@@ -226,6 +686,30 @@ mod tests {
                 moves: vec!["e2e4".to_string(), "e7e5".to_string()]
             }
         );
+        assert_eq!(
+            Command::parse(
+                "position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - moves e2e4"
+            ),
+            Command::SetPosition {
+                fen: Some("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -".to_string()),
+                moves: vec!["e2e4".to_string()]
+            }
+        );
+        assert_eq!(
+            Command::parse("position  fen 8/8/8/8/8/2k5/1P6/K7 w -  -"),
+            Command::SetPosition {
+                fen: Some("8/8/8/8/8/2k5/1P6/K7 w - -".to_string()),
+                moves: vec![]
+            }
+        );
+        // Misplaced "moves" is a part of the (invalid) FEN instead of a panic.
+        assert_eq!(
+            Command::parse("position moves e2e4 fen"),
+            Command::SetPosition {
+                fen: Some(String::new()),
+                moves: vec![]
+            }
+        );
     }
 
     #[test]
@@ -237,23 +721,148 @@ mod tests {
     fn parse_go() {
         assert_eq!(
             Command::parse("go wtime 300000 btime 300000 winc 10000 binc 10000"),
-            Command::Go {
-                wtime: Some(Duration::from_micros(300_000)),
-                btime: Some(Duration::from_micros(300_000)),
-                winc: Some(Duration::from_micros(10000)),
-                binc: Some(Duration::from_micros(10000)),
-            }
+            Command::Go(GoParameters {
+                wtime: Some(Duration::from_secs(300)),
+                btime: Some(Duration::from_secs(300)),
+                winc: Some(Duration::from_secs(10)),
+                binc: Some(Duration::from_secs(10)),
+                ..GoParameters::default()
+            })
         );
 
         assert_eq!(
             Command::parse("go wtime 1000"),
-            Command::Go {
-                wtime: Some(Duration::from_micros(1000)),
-                btime: None,
-                winc: None,
-                binc: None,
+            Command::Go(GoParameters {
+                wtime: Some(Duration::from_secs(1)),
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go wtime 1000 btime 2000 movestogo 5"),
+            Command::Go(GoParameters {
+                wtime: Some(Duration::from_secs(1)),
+                btime: Some(Duration::from_secs(2)),
+                movestogo: Some(5),
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go movetime 100"),
+            Command::Go(GoParameters {
+                movetime: Some(Duration::from_millis(100)),
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go depth 10 nodes 1000"),
+            Command::Go(GoParameters {
+                depth: Some(10),
+                nodes: Some(1000),
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go mate 3"),
+            Command::Go(GoParameters {
+                mate: Some(3),
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go infinite"),
+            Command::Go(GoParameters {
+                infinite: true,
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go ponder wtime 1000 btime 2000"),
+            Command::Go(GoParameters {
+                wtime: Some(Duration::from_secs(1)),
+                btime: Some(Duration::from_secs(2)),
+                ponder: true,
+                ..GoParameters::default()
+            })
+        );
+
+        assert_eq!(
+            Command::parse("go wtime 1000 btime 2000 byoyomi 5000"),
+            Command::Go(GoParameters {
+                wtime: Some(Duration::from_secs(1)),
+                btime: Some(Duration::from_secs(2)),
+                byoyomi: Some(Duration::from_secs(5)),
+                ..GoParameters::default()
+            })
+        );
+    }
+
+    #[test]
+    fn time_control() {
+        let time_control = |command: &str, player| match Command::parse(command) {
+            Command::Go(parameters) => parameters.time_control(player, Duration::ZERO),
+            command => panic!("unexpected command: {command:?}"),
+        };
+        assert_eq!(
+            time_control("go infinite", Player::White),
+            TimeControl::Unlimited
+        );
+        assert_eq!(
+            time_control("go btime 1000", Player::White),
+            TimeControl::Unlimited
+        );
+        assert_eq!(
+            time_control("go wtime 1000 btime 2000 winc 10 binc 20", Player::Black),
+            TimeControl::SuddenDeath {
+                time_left: Duration::from_secs(2),
+                increment: Duration::from_millis(20),
+            }
+        );
+        assert_eq!(
+            time_control(
+                "go wtime 1000 btime 2000 winc 10 movestogo 5",
+                Player::White
+            ),
+            TimeControl::MovesToGo {
+                time_left: Duration::from_secs(1),
+                moves_to_go: 5,
+                increment: Duration::from_millis(10),
             }
         );
+        assert_eq!(
+            time_control("go wtime 0 btime 0 byoyomi 5000", Player::White),
+            TimeControl::ByoYomi {
+                time_left: Duration::ZERO,
+                period: Duration::from_secs(5),
+            }
+        );
+        assert_eq!(
+            time_control("go wtime 1000 btime 1000 byoyomi 0", Player::White),
+            TimeControl::SuddenDeath {
+                time_left: Duration::from_secs(1),
+                increment: Duration::ZERO,
+            }
+        );
+        let Command::Go(parameters) = Command::parse("go wtime 1000 btime 2000") else {
+            unreachable!()
+        };
+        assert_eq!(
+            parameters.time_control(Player::Black, Duration::from_secs(5)),
+            TimeControl::Delay {
+                time_left: Duration::from_secs(2),
+                delay: Duration::from_secs(5),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_ponderhit() {
+        assert_eq!(Command::parse("ponderhit"), Command::PonderHit);
     }
 
     #[test]
@@ -271,6 +880,26 @@ mod tests {
         assert_eq!(Command::parse("state"), Command::State);
     }
 
+    #[test]
+    fn parse_attacks() {
+        assert_eq!(Command::parse("attacks"), Command::Attacks);
+    }
+
+    #[test]
+    fn parse_rootstats() {
+        assert_eq!(Command::parse("rootstats"), Command::RootStats);
+    }
+
+    #[test]
+    fn parse_hash() {
+        assert_eq!(Command::parse("hash"), Command::Hash);
+    }
+
+    #[test]
+    fn parse_legalmoves() {
+        assert_eq!(Command::parse("legalmoves"), Command::LegalMoves);
+    }
+
     #[test]
     fn unknown() {
         assert_eq!(
@@ -3,13 +3,14 @@
 
 use std::fmt;
 use std::ops::Not;
+use std::str::FromStr;
 
 use anyhow::bail;
 
 /// A standard game of chess is played between two players: White (having the
 /// advantage of the first turn) and Black.
 #[allow(missing_docs)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Player {
     White,
     Black,
@@ -38,6 +39,14 @@ impl TryFrom<&str> for Player {
     }
 }
 
+impl FromStr for Player {
+    type Err = anyhow::Error;
+
+    fn from_str(color: &str) -> anyhow::Result<Self> {
+        Self::try_from(color)
+    }
+}
+
 impl fmt::Display for Player {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -53,6 +62,7 @@ impl fmt::Display for Player {
 
 /// Result of the game from the perspective of the player to move at root.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GameResult {
     Win,
     Draw,
@@ -0,0 +1,170 @@
+//! Blending of the [`Evaluator`] predictions with the classical evaluation
+//! ([`super::evaluate`]). The network might be weak in some classes of
+//! positions (e.g. simple endgames that are rare in the training data), while
+//! the hand-crafted evaluation knows how to win them. The weight of the
+//! classical evaluation depends on the game phase, so it is possible to trust
+//! the network in the middlegame and the classical knowledge in the endgame.
+
+use std::sync::Arc;
+
+use super::{
+    centipawns_to_value, draw_probability, endgame, pesto, Accumulators, Evaluator, Prediction,
+};
+use crate::chess::position::Position;
+
+/// Weights of the classical evaluation in percent: 0 only uses the
+/// predictions of the evaluator and 100 replaces them. The policy is never
+/// changed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlendWeights {
+    /// Weight with all pieces on the board.
+    pub middlegame: u8,
+    /// Weight with only kings and pawns on the board. The weights between the
+    /// middlegame and the endgame are interpolated by the game phase.
+    pub endgame: u8,
+    /// Weight in the endgames that have specialized evaluation (e.g. KPK or
+    /// KBNK) regardless of the game phase.
+    pub known_endgame: u8,
+}
+
+impl BlendWeights {
+    /// Maximum value of each weight.
+    pub const MAX: u8 = 100;
+
+    /// The evaluator is used as is.
+    #[must_use]
+    pub fn is_disabled(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the weight of the classical evaluation in `[0, 1]` and the
+    /// classical evaluation itself in centipawns.
+    fn classical(&self, position: &Position) -> (f32, i32) {
+        let percent = |weight: u8| f32::from(weight.min(Self::MAX)) / f32::from(Self::MAX);
+        if let Some(score) = endgame::probe(position) {
+            return (percent(self.known_endgame), score);
+        }
+        let phase = pesto::phase(position) as f32 / pesto::MAX_PHASE as f32;
+        let weight = percent(self.middlegame) * phase + percent(self.endgame) * (1.0 - phase);
+        (weight, pesto::evaluate(position))
+    }
+}
+
+/// Evaluator that mixes the values predicted by the inner evaluator with the
+/// classical evaluation according to [`BlendWeights`].
+pub struct Blend {
+    inner: Arc<dyn Evaluator>,
+    weights: BlendWeights,
+}
+
+impl Blend {
+    #[must_use]
+    pub fn new(inner: Arc<dyn Evaluator>, weights: BlendWeights) -> Self {
+        Self { inner, weights }
+    }
+
+    /// Mixes the classical evaluation into the predicted value and draw
+    /// probability.
+    fn blend(&self, prediction: &mut Prediction, position: &Position) {
+        let (weight, score) = self.weights.classical(position);
+        if weight > 0.0 {
+            let value = centipawns_to_value(score);
+            prediction.value = (1.0 - weight) * prediction.value + weight * value;
+            prediction.draw = (1.0 - weight) * prediction.draw + weight * draw_probability(value);
+        }
+    }
+}
+
+impl Evaluator for Blend {
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+        let mut predictions = self.inner.evaluate(positions)?;
+        for (prediction, position) in predictions.iter_mut().zip(positions) {
+            self.blend(prediction, position);
+        }
+        Ok(predictions)
+    }
+
+    fn accumulators(&self, root: &Position) -> Accumulators {
+        self.inner.accumulators(root)
+    }
+
+    fn evaluate_incremental(
+        &self,
+        position: &Position,
+        accumulators: &Accumulators,
+    ) -> anyhow::Result<Prediction> {
+        let mut prediction = self.inner.evaluate_incremental(position, accumulators)?;
+        self.blend(&mut prediction, position);
+        Ok(prediction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Predicts a draw in every position.
+    struct Drawish;
+
+    impl Evaluator for Drawish {
+        fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+            Ok(positions
+                .iter()
+                .map(|_| Prediction {
+                    value: 0.0,
+                    draw: 1.0,
+                    policy: vec![1.0],
+                })
+                .collect())
+        }
+    }
+
+    fn blended(weights: BlendWeights, fen: &str) -> f32 {
+        let position = Position::from_fen(fen).unwrap();
+        Blend::new(Arc::new(Drawish), weights)
+            .evaluate(&[position])
+            .unwrap()
+            .remove(0)
+            .value
+    }
+
+    #[test]
+    fn weights() {
+        // Queen up in the middlegame and KQK.
+        let middlegame = "rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let known_endgame = "8/8/8/4k3/8/8/8/Q3K3 w - - 0 1";
+        assert!(BlendWeights::default().is_disabled());
+        assert_eq!(blended(BlendWeights::default(), middlegame), 0.0);
+        assert_eq!(blended(BlendWeights::default(), known_endgame), 0.0);
+
+        let middlegame_only = BlendWeights {
+            middlegame: 100,
+            ..BlendWeights::default()
+        };
+        assert!(!middlegame_only.is_disabled());
+        let classical =
+            centipawns_to_value(pesto::evaluate(&Position::from_fen(middlegame).unwrap()));
+        // The queen is missing: the phase is not at the maximum.
+        let value = blended(middlegame_only, middlegame);
+        assert!(value > 0.5 * classical && value < classical, "{value}");
+        assert_eq!(blended(middlegame_only, known_endgame), 0.0);
+        // Pawn endgames are evaluated classically.
+        let pawns = "4k3/pppp4/8/8/8/8/PPPPP3/4K3 w - - 0 1";
+        let endgame_only = BlendWeights {
+            endgame: 100,
+            ..BlendWeights::default()
+        };
+        assert_eq!(
+            blended(endgame_only, pawns),
+            centipawns_to_value(pesto::evaluate(&Position::from_fen(pawns).unwrap()))
+        );
+        assert_eq!(blended(middlegame_only, pawns), 0.0);
+
+        let known_only = BlendWeights {
+            known_endgame: 50,
+            ..BlendWeights::default()
+        };
+        let value = blended(known_only, known_endgame);
+        assert!(value > 0.45 && value < 0.5, "{value}");
+    }
+}
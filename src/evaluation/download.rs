@@ -0,0 +1,113 @@
+//! Fetching network weights from HTTPS URLs for bot deployments, so that new
+//! networks can be distributed without repackaging the binary.
+//!
+//! The download is delegated to `curl` to avoid pulling a TLS stack into the
+//! engine. The weights are verified against the expected SHA-256 checksum and
+//! cached by it: the network is downloaded only once and a cached file that
+//! does not match the checksum is replaced.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::{env, fs};
+
+use anyhow::{bail, ensure, Context};
+
+use super::checksum;
+
+/// Returns the path to the network weights from `url`, downloading them into
+/// `cache_dir` unless they are already there.
+///
+/// # Errors
+///
+/// If the URL is not HTTPS, the checksum is malformed, the download fails or
+/// the downloaded file does not match the checksum.
+pub fn fetch(url: &str, sha256: &str, cache_dir: &Path) -> anyhow::Result<PathBuf> {
+    ensure!(
+        url.starts_with("https://"),
+        "network weights should be downloaded over HTTPS, got {url:?}"
+    );
+    let expected = sha256.to_ascii_lowercase();
+    ensure!(
+        expected.len() == 64 && expected.bytes().all(|byte| byte.is_ascii_hexdigit()),
+        "SHA-256 checksum should be 64 hexadecimal digits, got {sha256:?}"
+    );
+    let path = cache_dir.join(format!("{expected}.safetensors"));
+    if path.exists() && file_digest(&path)? == expected {
+        return Ok(path);
+    }
+
+    fs::create_dir_all(cache_dir)
+        .with_context(|| format!("creating cache directory {}", cache_dir.display()))?;
+    // Download into a temporary file first so that an interrupted download
+    // never ends up in the cache.
+    let partial = cache_dir.join(format!("{expected}.part{}", std::process::id()));
+    let status = Command::new("curl")
+        .args(["--fail", "--silent", "--show-error", "--location"])
+        .args(["--proto", "=https", "--output"])
+        .arg(&partial)
+        .arg(url)
+        .status()
+        .context("running curl to download network weights")?;
+    if !status.success() {
+        let _ = fs::remove_file(&partial);
+        bail!("downloading network weights from {url} failed: {status}");
+    }
+    let actual = file_digest(&partial)?;
+    if actual != expected {
+        let _ = fs::remove_file(&partial);
+        bail!("checksum mismatch for {url}: expected {expected}, got {actual}");
+    }
+    fs::rename(&partial, &path)
+        .with_context(|| format!("moving network weights to {}", path.display()))?;
+    Ok(path)
+}
+
+/// `$PABI_CACHE_DIR` if set, otherwise the platform cache directory
+/// (`$XDG_CACHE_HOME/pabi` or `~/.cache/pabi`).
+#[must_use]
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("PABI_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+    if let Some(dir) = env::var_os("XDG_CACHE_HOME") {
+        return PathBuf::from(dir).join("pabi");
+    }
+    env::var_os("HOME")
+        .map_or_else(env::temp_dir, PathBuf::from)
+        .join(".cache")
+        .join("pabi")
+}
+
+fn file_digest(path: &Path) -> anyhow::Result<String> {
+    let data = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    Ok(checksum::hex_digest(&data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache() {
+        let cache_dir = std::env::temp_dir().join(format!("pabi-download-{}", std::process::id()));
+        fs::create_dir_all(&cache_dir).unwrap();
+        let checksum = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        let cached = cache_dir.join(format!("{checksum}.safetensors"));
+        fs::write(&cached, "abc").unwrap();
+
+        // The cached weights are used without downloading them again.
+        let url = "https://localhost:0/network.safetensors";
+        assert_eq!(fetch(url, checksum, &cache_dir).unwrap(), cached);
+        assert_eq!(
+            fetch(url, &checksum.to_ascii_uppercase(), &cache_dir).unwrap(),
+            cached
+        );
+        // A corrupted file is downloaded again, which fails here.
+        fs::write(&cached, "abd").unwrap();
+        assert!(fetch(url, checksum, &cache_dir).is_err());
+
+        assert!(fetch("http://localhost/network.safetensors", checksum, &cache_dir).is_err());
+        assert!(fetch(url, "abc", &cache_dir).is_err());
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,256 @@
+//! Specialized evaluation of the endgames that the general evaluation is known
+//! to handle poorly: it either does not know how to make progress (e.g. it has
+//! no incentive to drive the king to the right corner in KBNK) or
+//! overestimates the material advantage (e.g. KRKB is usually a draw).
+//!
+//! The endgames are identified by their [`Material`] signature and are
+//! consulted before the general evaluation. They only matter without
+//! tablebases: the tablebase results take priority in the search.
+
+use crate::chess::core::{Piece, PieceKind, Rank, Square, BOARD_WIDTH};
+use crate::chess::material::Material;
+use crate::chess::position::Position;
+use crate::environment::Player;
+use crate::evaluation::kpk::{self, KpkResult};
+
+/// Base score of the positions that are won with correct play, in centipawns.
+/// The evaluators add a bonus for making progress on top of it.
+const KNOWN_WIN: i32 = 1000;
+
+/// Evaluates the position from the perspective of the `strong` player, i.e.
+/// the one who has the pieces listed first in the signature.
+type Evaluate = fn(&Position, Player) -> i32;
+
+const fn signature(signature: &str) -> Material {
+    match Material::parse(signature) {
+        Some(material) => material,
+        None => panic!("invalid material signature"),
+    }
+}
+
+const ENDGAMES: [(Material, Evaluate); 9] = [
+    (signature("KQK"), mate_bare_king),
+    (signature("KPK"), king_and_pawn),
+    (signature("KRK"), mate_bare_king),
+    (signature("KBNK"), bishop_and_knight_mate),
+    (signature("KNNK"), draw),
+    (signature("KRKR"), draw),
+    (signature("KRKB"), rook_against_minor),
+    (signature("KRKN"), rook_against_minor),
+    (signature("KRKP"), rook_against_pawn),
+];
+
+/// Returns the evaluation of the position in centipawns from the perspective
+/// of the player to move if it is one of the known endgames.
+#[must_use]
+pub(crate) fn probe(position: &Position) -> Option<i32> {
+    let material = position.material();
+    ENDGAMES.iter().find_map(|&(signature, evaluate)| {
+        let strong = if material == signature {
+            Player::White
+        } else if material == signature.flip_colors() {
+            Player::Black
+        } else {
+            return None;
+        };
+        let score = evaluate(position, strong);
+        Some(if position.us() == strong {
+            score
+        } else {
+            -score
+        })
+    })
+}
+
+fn squares(
+    position: &Position,
+    player: Player,
+    kind: PieceKind,
+) -> impl Iterator<Item = Square> + '_ {
+    position
+        .iter_pieces()
+        .filter(move |&(_, piece)| piece == Piece { player, kind })
+        .map(|(square, _)| square)
+}
+
+fn find(position: &Position, player: Player, kind: PieceKind) -> Square {
+    squares(position, player, kind)
+        .next()
+        .expect("the piece is present according to the material signature")
+}
+
+/// Number of king moves between the squares.
+fn distance(from: Square, to: Square) -> i32 {
+    let files = (from.file() as i32 - to.file() as i32).abs();
+    let ranks = (from.rank() as i32 - to.rank() as i32).abs();
+    files.max(ranks)
+}
+
+/// 0 in the center of the board and 6 in the corners.
+fn distance_to_center(square: Square) -> i32 {
+    let center = |coordinate: i32| (3 - coordinate).max(coordinate - 4);
+    center(square.file() as i32) + center(square.rank() as i32)
+}
+
+/// Rank from the perspective of the player: 0 is the first rank.
+fn relative_rank(square: Square, player: Player) -> i32 {
+    match player {
+        Player::White => square.rank() as i32,
+        Player::Black => BOARD_WIDTH as i32 - 1 - square.rank() as i32,
+    }
+}
+
+fn draw(_position: &Position, _strong: Player) -> i32 {
+    0
+}
+
+/// KQK and KRK: the mate is forced by driving the lone king to the edge with
+/// the help of our king.
+fn mate_bare_king(position: &Position, strong: Player) -> i32 {
+    let strong_king = position.king_square(strong);
+    let weak_king = position.king_square(!strong);
+    KNOWN_WIN + 20 * distance_to_center(weak_king) + 10 * (7 - distance(strong_king, weak_king))
+}
+
+/// KPK: the exact result is known from the bitbase, the won positions are
+/// ranked by how close the pawn is to promotion.
+fn king_and_pawn(position: &Position, strong: Player) -> i32 {
+    match kpk::probe(position) {
+        Some(KpkResult::Win) => {
+            KNOWN_WIN + 10 * relative_rank(find(position, strong, PieceKind::Pawn), strong)
+        },
+        Some(KpkResult::Draw) | None => 0,
+    }
+}
+
+/// KBNK: the mate is only possible in the corners of the bishop's color, the
+/// general evaluation would push the king to any edge.
+fn bishop_and_knight_mate(position: &Position, strong: Player) -> i32 {
+    let strong_king = position.king_square(strong);
+    let weak_king = position.king_square(!strong);
+    let bishop = find(position, strong, PieceKind::Bishop);
+    let dark_bishop = (bishop.file() as u8 + bishop.rank() as u8) % 2 == 0;
+    let corners = if dark_bishop {
+        [Square::A1, Square::H8]
+    } else {
+        [Square::A8, Square::H1]
+    };
+    let corner_distance = corners
+        .iter()
+        .map(|&corner| distance(weak_king, corner))
+        .min()
+        .expect("there are two corners");
+    KNOWN_WIN
+        + 30 * (7 - corner_distance)
+        + 5 * distance_to_center(weak_king)
+        + 10 * (7 - distance(strong_king, weak_king))
+}
+
+/// KRKB and KRKN are usually drawn, but the weak side has to defend
+/// accurately: keep a small advantage and push the lone king to the edge and
+/// away from its piece.
+fn rook_against_minor(position: &Position, strong: Player) -> i32 {
+    let weak_king = position.king_square(!strong);
+    let mut score = 50 + 10 * distance_to_center(weak_king);
+    if let Some(knight) = squares(position, !strong, PieceKind::Knight).next() {
+        score += 5 * distance(weak_king, knight);
+    }
+    score
+}
+
+/// KRKP: the rook wins unless the pawn is far advanced and supported by its
+/// king while our king is too far away to help.
+fn rook_against_pawn(position: &Position, strong: Player) -> i32 {
+    let weak = !strong;
+    let strong_king = position.king_square(strong);
+    let weak_king = position.king_square(weak);
+    let rook = find(position, strong, PieceKind::Rook);
+    let pawn = find(position, weak, PieceKind::Pawn);
+    let promotion_rank = match weak {
+        Player::White => Rank::Rank8,
+        Player::Black => Rank::Rank1,
+    };
+    let queening_square = Square::new(pawn.file(), promotion_rank);
+    let tempo = i32::from(position.us() == strong);
+
+    let in_front_of_pawn = strong_king.file() == pawn.file()
+        && relative_rank(strong_king, weak) > relative_rank(pawn, weak);
+    let pawn_abandoned = distance(weak_king, pawn) >= 3 + tempo && distance(weak_king, rook) >= 3;
+    if in_front_of_pawn || pawn_abandoned {
+        return KNOWN_WIN / 2 - distance(strong_king, pawn);
+    }
+    // The race: our king has to stop the pawn supported by the other king.
+    200 - 8
+        * (distance(strong_king, queening_square)
+            - distance(weak_king, queening_square)
+            - distance(pawn, queening_square))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn probe_fen(fen: &str) -> Option<i32> {
+        probe(&Position::from_fen(fen).expect("valid position"))
+    }
+
+    #[test]
+    fn registry() {
+        assert_eq!(probe_fen("8/8/8/8/8/8/8/K1k5 w - - 0 1"), None);
+        assert!(Position::starting().material() != signature("KQK"));
+        assert_eq!(probe(&Position::starting()), None);
+        // The score is from the perspective of the player to move regardless of
+        // which color has the extra material.
+        let white = probe_fen("8/8/8/4k3/8/8/8/Q3K3 w - - 0 1").unwrap();
+        assert!(white > KNOWN_WIN, "{white}");
+        assert_eq!(probe_fen("8/8/8/4k3/8/8/8/Q3K3 b - - 0 1"), Some(-white));
+        assert_eq!(probe_fen("q3k3/8/8/8/4K3/8/8/8 b - - 0 1"), Some(white));
+        assert_eq!(probe_fen("8/8/3rk3/8/8/8/8/3RK3 w - - 0 1"), Some(0));
+        assert_eq!(probe_fen("8/8/4k3/8/8/8/1NN5/4K3 w - - 0 1"), Some(0));
+    }
+
+    #[test]
+    fn king_and_pawn() {
+        // Opposition: only the side to move matters.
+        assert_eq!(probe_fen("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1"), Some(0));
+        let win = probe_fen("8/4k3/8/4K3/4P3/8/8/8 b - - 0 1").unwrap();
+        assert!(win < -KNOWN_WIN, "{win}");
+        // The same for Black, the pawn closer to promotion is better.
+        let advanced = probe_fen("8/8/8/8/8/4p3/3k4/7K b - - 0 1").unwrap();
+        let behind = probe_fen("8/8/8/8/4p3/8/3k4/7K b - - 0 1").unwrap();
+        assert!(
+            advanced > behind && behind > KNOWN_WIN,
+            "{advanced} {behind}"
+        );
+    }
+
+    #[test]
+    fn mate_progress() {
+        // The lone king on the edge is closer to the mate.
+        assert!(
+            probe_fen("4k3/8/4K3/8/8/8/8/7R w - - 0 1").unwrap()
+                > probe_fen("8/8/8/3k4/8/3K4/8/7R w - - 0 1").unwrap()
+        );
+        // Dark-squared bishop: mate in a1 or h8, the king in a8 is safe.
+        let right_corner = probe_fen("7k/8/5K2/8/8/8/8/2B1N3 w - - 0 1").unwrap();
+        let wrong_corner = probe_fen("k7/8/2K5/8/8/8/8/2B1N3 w - - 0 1").unwrap();
+        assert!(right_corner > wrong_corner, "{right_corner} {wrong_corner}");
+        assert!(wrong_corner > KNOWN_WIN);
+    }
+
+    #[test]
+    fn rook_endings() {
+        let rook_vs_bishop = probe_fen("8/8/8/3bk3/8/8/8/3RK3 w - - 0 1").unwrap();
+        assert!(
+            (0..KNOWN_WIN / 4).contains(&rook_vs_bishop),
+            "{rook_vs_bishop}"
+        );
+        // Our king blocks the pawn.
+        let blocked = probe_fen("8/8/8/8/8/2k5/2p5/2K1R3 w - - 0 1").unwrap();
+        assert!(blocked > KNOWN_WIN / 4, "{blocked}");
+        // The pawn is about to promote with the support of its king while
+        // our king is far away.
+        let race = probe_fen("K7/8/8/8/R7/8/5pk1/8 w - - 0 1").unwrap();
+        assert!(race < blocked, "{race} {blocked}");
+    }
+}
@@ -1 +1,93 @@
 //! Extracts features from the position.
+
+use crate::chess::core::{Move, Piece, Square, BOARD_SIZE};
+use crate::chess::position::Position;
+use crate::environment::Player;
+
+/// One plane of [`BOARD_SIZE`] squares for each piece kind of each player.
+pub(crate) const NUM_FEATURES: usize = 12 * BOARD_SIZE as usize;
+
+/// Moves are indexed by their source and target squares. Promotions to
+/// different pieces share the same index.
+// TODO: Use the action space compression from lc0.
+pub(crate) const NUM_MOVES: usize = BOARD_SIZE as usize * BOARD_SIZE as usize;
+
+/// Appends one-hot encoding of the pieces to `features`. The encoding is
+/// relative to the player to move: their pieces come first and the board is
+/// flipped for Black, so that the network does not need to learn both colors.
+pub(crate) fn encode(position: &Position, features: &mut Vec<f32>) {
+    let offset = features.len();
+    features.resize(offset + NUM_FEATURES, 0.0);
+    for feature in active(position) {
+        features[offset + feature] = 1.0;
+    }
+}
+
+/// Returns the indices of non-zero features in [`encode`]d position.
+pub(crate) fn active(position: &Position) -> impl Iterator<Item = usize> + '_ {
+    active_for(position, position.us())
+}
+
+/// Returns the indices of non-zero features from the perspective of the given
+/// player, which is not necessarily the one to move.
+pub(crate) fn active_for(
+    position: &Position,
+    perspective: Player,
+) -> impl Iterator<Item = usize> + '_ {
+    position
+        .iter_pieces()
+        .map(move |(square, piece)| index(piece, square, perspective))
+}
+
+/// Returns the index of the feature for the piece standing on the square.
+pub(crate) fn index(piece: Piece, square: Square, perspective: Player) -> usize {
+    let plane = if piece.player == perspective { 0 } else { 6 } + piece.kind as usize;
+    plane * BOARD_SIZE as usize + relative_square(square, perspective) as usize
+}
+
+/// Returns the index of the move in the policy output.
+pub(crate) fn move_index(position: &Position, next_move: &Move) -> usize {
+    let us = position.us();
+    relative_square(next_move.from(), us) as usize * BOARD_SIZE as usize
+        + relative_square(next_move.to(), us) as usize
+}
+
+fn relative_square(square: Square, player: Player) -> Square {
+    match player {
+        Player::White => square,
+        Player::Black => square.flip_perspective(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symmetric_encoding() {
+        let mut white = Vec::new();
+        encode(
+            &Position::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap(),
+            &mut white,
+        );
+        let mut black = Vec::new();
+        encode(
+            &Position::from_fen("4k3/4p3/8/8/8/8/8/4K3 b - - 0 1").unwrap(),
+            &mut black,
+        );
+        assert_eq!(white.len(), NUM_FEATURES);
+        assert_eq!(white.iter().sum::<f32>(), 3.0);
+        assert_eq!(white, black);
+
+        let white_move = Move::from_uci("e2e4").unwrap();
+        let black_move = Move::from_uci("e7e5").unwrap();
+        assert_eq!(
+            move_index(&Position::starting(), &white_move),
+            move_index(
+                &Position::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1")
+                    .unwrap(),
+                &black_move
+            )
+        );
+    }
+}
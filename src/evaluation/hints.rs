@@ -0,0 +1,117 @@
+//! Human-readable explanation of the position for the users analysing it in
+//! debug mode: the static evaluation, mobility of the player to move and the
+//! pieces that can be won.
+
+use crate::chess::core::{Piece, PieceKind, Square};
+use crate::chess::position::Position;
+use crate::environment::Player;
+
+/// Rough piece values for comparing the attackers with their targets.
+const fn value(kind: PieceKind) -> u32 {
+    match kind {
+        PieceKind::Pawn => 1,
+        PieceKind::Knight | PieceKind::Bishop => 3,
+        PieceKind::Rook => 5,
+        PieceKind::Queen => 9,
+        PieceKind::King => 100,
+    }
+}
+
+/// Returns the lines describing the position. A piece is considered hanging if
+/// it is attacked and either not defended or attacked by a less valuable
+/// piece.
+#[must_use]
+pub fn hints(position: &Position) -> Vec<String> {
+    let mut hints = vec![format!(
+        "Static evaluation: {} cp for the player to move",
+        super::evaluate(position)
+    )];
+
+    let mobility = position.mobility();
+    let mut moves = [0; 6];
+    for (square, targets) in mobility.iter() {
+        if let Some(piece) = position.at(square) {
+            moves[piece.kind as usize] += targets.count();
+        }
+    }
+    hints.push(format!(
+        "Mobility: {} moves (pawns {}, knights {}, bishops {}, rooks {}, queens {}, king {})",
+        mobility.count(),
+        moves[0],
+        moves[1],
+        moves[2],
+        moves[3],
+        moves[4],
+        moves[5]
+    ));
+
+    let hanging: Vec<String> = position
+        .iter_pieces()
+        .filter(|&(square, piece)| is_hanging(position, square, piece))
+        .map(|(square, piece)| {
+            let player = match piece.player {
+                Player::White => "White",
+                Player::Black => "Black",
+            };
+            format!(
+                "{player} {}{square} ({} attackers, {} defenders)",
+                piece.kind.to_string().to_uppercase(),
+                position.attackers_to(square, !piece.player).count(),
+                position.attackers_to(square, piece.player).count()
+            )
+        })
+        .collect();
+    hints.push(if hanging.is_empty() {
+        "Hanging pieces: none".to_string()
+    } else {
+        format!("Hanging pieces: {}", hanging.join(", "))
+    });
+    hints
+}
+
+fn is_hanging(position: &Position, square: Square, piece: Piece) -> bool {
+    if piece.kind == PieceKind::King {
+        return false;
+    }
+    let attackers = position.attackers_to(square, !piece.player);
+    let Some(weakest) = attackers
+        .iter()
+        .filter_map(|attacker| position.at(attacker))
+        .map(|attacker| value(attacker.kind))
+        .min()
+    else {
+        return false;
+    };
+    position.attackers_to(square, piece.player).is_empty() || weakest < value(piece.kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starting_position() {
+        let hints = hints(&Position::starting());
+        assert_eq!(
+            hints,
+            [
+                "Static evaluation: 0 cp for the player to move",
+                "Mobility: 20 moves (pawns 16, knights 4, bishops 0, rooks 0, queens 0, king 0)",
+                "Hanging pieces: none",
+            ]
+        );
+    }
+
+    #[test]
+    fn hanging_pieces() {
+        // The knight on e5 is undefended and the rook on d8 is attacked by the
+        // bishop. The pawn on d4 is defended by the queen.
+        let position =
+            Position::from_fen("3rk3/8/8/4n1B1/3P4/8/8/3QK3 w - - 0 1").expect("valid position");
+        assert_eq!(
+            hints(&position)[2],
+            "Hanging pieces: Black Ne5 (1 attackers, 0 defenders), Black Rd8 (1 attackers, 1 \
+             defenders)"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! King and Pawn vs King [bitbase]: exact win/draw result for all positions
+//! with a single pawn. The bitbase is generated by `build.rs` and takes 24 KB.
+//!
+//! [bitbase]: https://www.chessprogramming.org/KPK
+
+use crate::chess::core::{File, PieceKind};
+use crate::chess::position::Position;
+use crate::environment::Player;
+
+/// One bit for each position with White pawn on files A to D: set if White
+/// wins. See `generate_kpk_bitbase` in `build.rs` for the indexing scheme.
+static BITBASE: [u64; 3072] = include!(concat!(env!("OUT_DIR"), "/kpk_bitbase"));
+
+/// Result of the game with perfect play for the side that has the pawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KpkResult {
+    Win,
+    Draw,
+}
+
+/// Returns the result for the player with the pawn or [`None`] if the
+/// position is not KPK.
+#[must_use]
+pub fn probe(position: &Position) -> Option<KpkResult> {
+    let material = position.material();
+    let strong = if material == "KPK".parse().ok()? {
+        Player::White
+    } else if material == "KKP".parse().ok()? {
+        Player::Black
+    } else {
+        return None;
+    };
+    let find = |player, kind| {
+        position
+            .iter_pieces()
+            .find(|(_, piece)| piece.player == player && piece.kind == kind)
+            .map(|(square, _)| square)
+            .expect("the piece is present according to the material signature")
+    };
+    let (mut strong_king, mut weak_king, mut pawn) = (
+        find(strong, PieceKind::King),
+        find(!strong, PieceKind::King),
+        find(strong, PieceKind::Pawn),
+    );
+    // The bitbase only stores White pawns on the queen side.
+    if strong == Player::Black {
+        (strong_king, weak_king, pawn) = (
+            strong_king.flip_perspective(),
+            weak_king.flip_perspective(),
+            pawn.flip_perspective(),
+        );
+    }
+    if pawn.file() > File::D {
+        (strong_king, weak_king, pawn) = (
+            strong_king.mirror_horizontally(),
+            weak_king.mirror_horizontally(),
+            pawn.mirror_horizontally(),
+        );
+    }
+    let index = strong_king as usize
+        | (weak_king as usize) << 6
+        | usize::from(position.us() != strong) << 12
+        | (pawn.file() as usize) << 13
+        | (6 - pawn.rank() as usize) << 15;
+    if BITBASE[index / 64] & (1 << (index % 64)) != 0 {
+        Some(KpkResult::Win)
+    } else {
+        Some(KpkResult::Draw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::core::Square;
+    use crate::chess::tablebase::Wdl;
+
+    fn probe_fen(fen: &str) -> Option<KpkResult> {
+        probe(&Position::from_fen(fen).expect("valid position"))
+    }
+
+    #[test]
+    fn theory() {
+        assert_eq!(probe(&Position::starting()), None);
+        assert_eq!(probe_fen("8/8/8/8/8/4k3/4R3/4K3 b - - 0 1"), None);
+        // The defending king in front of the pawn.
+        assert_eq!(
+            probe_fen("8/8/8/8/8/4k3/4P3/4K3 w - - 0 1"),
+            Some(KpkResult::Draw)
+        );
+        // Opposition: the side to move loses it.
+        assert_eq!(
+            probe_fen("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1"),
+            Some(KpkResult::Draw)
+        );
+        assert_eq!(
+            probe_fen("8/4k3/8/4K3/4P3/8/8/8 b - - 0 1"),
+            Some(KpkResult::Win)
+        );
+        // The king on a key square wins regardless of the side to move.
+        for fen in [
+            "4k3/8/3K4/3P4/8/8/8/8 w - - 0 1",
+            "4k3/8/3K4/3P4/8/8/8/8 b - - 0 1",
+        ] {
+            assert_eq!(probe_fen(fen), Some(KpkResult::Win), "{fen}");
+        }
+        // Rook pawns are drawn when the defending king reaches the corner.
+        for fen in [
+            "k7/8/K7/P7/8/8/8/8 w - - 0 1",
+            "k7/8/K7/P7/8/8/8/8 b - - 0 1",
+        ] {
+            assert_eq!(probe_fen(fen), Some(KpkResult::Draw), "{fen}");
+        }
+        // The rule of the square when the other king is too far to help,
+        // including the double push.
+        for (fen, expected) in [
+            ("7K/8/8/6k1/P7/8/8/8 b - - 0 1", KpkResult::Win),
+            ("7K/8/8/5k2/P7/8/8/8 b - - 0 1", KpkResult::Draw),
+            ("7K/8/8/8/8/8/4P3/k7 b - - 0 1", KpkResult::Win),
+            ("7K/8/8/8/8/1k6/4P3/8 b - - 0 1", KpkResult::Draw),
+            // The same for Black.
+            ("8/8/8/p7/6K1/8/8/7k w - - 0 1", KpkResult::Win),
+            ("8/8/8/p7/5K2/8/8/7k w - - 0 1", KpkResult::Draw),
+        ] {
+            assert_eq!(probe_fen(fen), Some(expected), "{fen}");
+        }
+        // Opposition with Black pawn.
+        assert_eq!(
+            probe_fen("8/8/8/4p3/4k3/8/4K3/8 w - - 0 1"),
+            Some(KpkResult::Win)
+        );
+        assert_eq!(
+            probe_fen("8/8/8/4p3/4k3/8/4K3/8 b - - 0 1"),
+            Some(KpkResult::Draw)
+        );
+    }
+
+    #[test]
+    fn matches_tablebase() {
+        let tablebase = crate::chess::tablebase::Tablebase::open(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/syzygy").as_ref(),
+        )
+        .expect("tablebases are in the repository");
+        let mut checked = 0;
+        // Every position is too slow for a debug build: visit a sample of the
+        // king placements for each pawn square and side to move.
+        for (index, (strong_king, weak_king, pawn, side_to_move)) in Square::iter()
+            .flat_map(|strong_king| Square::iter().map(move |weak_king| (strong_king, weak_king)))
+            .flat_map(|(strong_king, weak_king)| {
+                Square::iter()
+                    .filter(|pawn| {
+                        !matches!(
+                            pawn.rank(),
+                            crate::chess::core::Rank::Rank1 | crate::chess::core::Rank::Rank8
+                        )
+                    })
+                    .flat_map(move |pawn| {
+                        ["w", "b"].map(move |side| (strong_king, weak_king, pawn, side))
+                    })
+            })
+            .enumerate()
+        {
+            if index % 97 != 0
+                || strong_king == weak_king
+                || pawn == strong_king
+                || pawn == weak_king
+            {
+                continue;
+            }
+            let mut board = [['1'; 8]; 8];
+            for (square, piece) in [(strong_king, 'K'), (weak_king, 'k'), (pawn, 'P')] {
+                board[7 - square.rank() as usize][square.file() as usize] = piece;
+            }
+            let placement = board.map(|rank| rank.iter().collect::<String>()).join("/");
+            let Ok(position) = Position::from_fen(&format!("{placement} {side_to_move} - - 0 1"))
+            else {
+                continue;
+            };
+            // The tablebase rejects positions where the side that just moved
+            // is in check.
+            let Some(wdl) = tablebase.probe_wdl(&position) else {
+                continue;
+            };
+            let expected = match wdl {
+                Wdl::Draw => KpkResult::Draw,
+                Wdl::Win | Wdl::Loss => KpkResult::Win,
+            };
+            assert_eq!(probe(&position), Some(expected), "{position}");
+            checked += 1;
+        }
+        assert!(checked > 1000, "{checked}");
+    }
+}
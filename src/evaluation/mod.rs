@@ -3,7 +3,245 @@
 //!
 //! For convenience, the score is returned in centipawn units.
 //!
+//! The search does not depend on a specific evaluation method: anything that
+//! implements [`Evaluator`] can be plugged in and the implementation can be
+//! selected at runtime via [`Backend`].
+//!
 //! [evaluation]: https://www.chessprogramming.org/Evaluation
 
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context};
+
+use crate::chess::position::Position;
+
+mod blend;
+mod checksum;
+#[cfg(feature = "network-download")]
+pub mod download;
+pub(crate) mod endgame;
 pub(crate) mod features;
+mod hints;
+pub mod kpk;
 pub(crate) mod network;
+mod pesto;
+mod quantized;
+
+pub use blend::{Blend, BlendWeights};
+pub use hints::hints;
+pub(crate) use pesto::{phase, MAX_PHASE};
+pub use quantized::Accumulators;
+
+/// Returns the static evaluation of the position in centipawns from the
+/// perspective of the player to move. The known endgames are evaluated by the
+/// specialized evaluators.
+#[must_use]
+pub fn evaluate(position: &Position) -> i32 {
+    endgame::probe(position).unwrap_or_else(|| pesto::evaluate(position))
+}
+
+/// Prediction of the evaluator for a single position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Prediction {
+    /// Expected outcome of the game in `[-1, 1]` range from the perspective of
+    /// the player to move.
+    pub value: f32,
+    /// Probability of a draw in `[0, 1 - |value|]` range: the value is the
+    /// difference between the probabilities of winning and losing, the draw
+    /// probability tells the balanced positions from the sharp ones. The
+    /// evaluators without a draw prediction use [`draw_probability`].
+    pub draw: f32,
+    /// Probabilities of each legal move being the best one, in the order of
+    /// [`Position::generate_moves`].
+    pub policy: Vec<f32>,
+}
+
+/// Predicts the value of the positions and the probabilities of the moves
+/// (policy) for the search.
+pub trait Evaluator: Send + Sync {
+    /// Evaluates a batch of positions at once, which is much more efficient
+    /// for neural networks than evaluating them one by one.
+    ///
+    /// # Errors
+    ///
+    /// If the backend fails to run the inference.
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>>;
+
+    /// Creates the state the evaluator keeps incrementally (e.g. the first
+    /// layer of the network) along the search path starting at `root`. The
+    /// evaluators without such state return the empty
+    /// [`Accumulators::default`].
+    fn accumulators(&self, _root: &Position) -> Accumulators {
+        Accumulators::default()
+    }
+
+    /// Same as [`Evaluator::evaluate`] for a single position reached by the
+    /// moves pushed to the `accumulators`, which can be much cheaper than
+    /// evaluating it from scratch.
+    ///
+    /// # Errors
+    ///
+    /// If the backend fails to run the inference.
+    fn evaluate_incremental(
+        &self,
+        position: &Position,
+        _accumulators: &Accumulators,
+    ) -> anyhow::Result<Prediction> {
+        self.evaluate(std::slice::from_ref(position))?
+            .pop()
+            .context("evaluator should return a prediction for each position")
+    }
+}
+
+/// Hand-crafted evaluation that does not have a policy: all moves are equally
+/// likely.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pesto;
+
+impl Evaluator for Pesto {
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+        Ok(positions
+            .iter()
+            .map(|position| {
+                let moves = position.generate_moves().len();
+                let value = centipawns_to_value(evaluate(position));
+                Prediction {
+                    value,
+                    draw: draw_probability(value),
+                    policy: vec![1.0 / moves.max(1) as f32; moves],
+                }
+            })
+            .collect())
+    }
+}
+
+/// Implementations of [`Evaluator`] that can be selected at runtime.
+// TODO: Support ONNX Runtime for networks trained with other frameworks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Backend {
+    /// [`Pesto`] evaluation, does not need network weights.
+    Pesto,
+    /// Policy and value network in [safetensors] format running on [Candle].
+    ///
+    /// [safetensors]: https://huggingface.co/docs/safetensors
+    /// [Candle]: https://github.com/huggingface/candle
+    Candle,
+    /// The same network as [`Backend::Candle`] with quantized weights and
+    /// integer arithmetic, which is faster on CPU.
+    Quantized,
+}
+
+impl Backend {
+    /// Creates the evaluator, loading the network weights if the backend
+    /// needs them.
+    ///
+    /// # Errors
+    ///
+    /// If the network weights are required but not provided or can not be
+    /// loaded.
+    pub fn create(self, weights: Option<&Path>) -> anyhow::Result<Box<dyn Evaluator>> {
+        match (self, weights) {
+            (Self::Pesto, _) => Ok(Box::new(Pesto)),
+            (Self::Candle, Some(weights)) => Ok(Box::new(network::Network::load(weights)?)),
+            (Self::Quantized, Some(weights)) => Ok(Box::new(quantized::QuantizedNetwork::new(
+                &network::Network::load(weights)?,
+            )?)),
+            (Self::Candle | Self::Quantized, None) => {
+                bail!("{self} backend requires network weights")
+            },
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = anyhow::Error;
+
+    fn from_str(backend: &str) -> anyhow::Result<Self> {
+        match backend {
+            "pesto" => Ok(Self::Pesto),
+            "candle" => Ok(Self::Candle),
+            "quantized" => Ok(Self::Quantized),
+            _ => bail!(
+                "evaluation backend should be 'pesto', 'candle' or 'quantized', got '{backend}'"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pesto => "pesto",
+            Self::Candle => "candle",
+            Self::Quantized => "quantized",
+        })
+    }
+}
+
+/// Scale for converting centipawns to the values in `(-1, 1)` range and back.
+const CENTIPAWN_SCALE: f32 = 400.0;
+
+/// Converts the score in centipawns to the expected outcome in `(-1, 1)`.
+#[must_use]
+pub fn centipawns_to_value(cp: i32) -> f32 {
+    (cp as f32 / CENTIPAWN_SCALE).tanh()
+}
+
+/// Share of the non-decisive outcomes assumed to be draws by
+/// [`draw_probability`].
+const DRAW_RATE: f32 = 0.5;
+
+/// Estimates the probability of a draw from the expected outcome for the
+/// evaluators that only predict the value: the more balanced the position is,
+/// the more likely the draw.
+#[must_use]
+pub fn draw_probability(value: f32) -> f32 {
+    DRAW_RATE * (1.0 - value.clamp(-1.0, 1.0).abs())
+}
+
+/// Converts the expected outcome to centipawns, inverse of
+/// [`centipawns_to_value`].
+#[must_use]
+pub fn value_to_centipawns(value: f32) -> i32 {
+    (value.clamp(-0.999, 0.999).atanh() * CENTIPAWN_SCALE).round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn centipawn_conversion() {
+        for cp in [-500, -100, 0, 1, 42, 100, 800] {
+            assert_eq!(value_to_centipawns(centipawns_to_value(cp)), cp);
+        }
+    }
+
+    #[test]
+    fn pesto_prediction() {
+        let predictions = Pesto
+            .evaluate(&[
+                Position::starting(),
+                Position::from_fen("k6R/8/1K6/8/8/8/8/8 b - - 1 1").unwrap(),
+            ])
+            .unwrap();
+        assert_eq!(predictions.len(), 2);
+        assert_eq!(predictions[0].policy.len(), 20);
+        assert!((predictions[0].policy.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        // Checkmate: no moves.
+        assert!(predictions[1].policy.is_empty());
+    }
+
+    #[test]
+    fn backend() {
+        assert_eq!("pesto".parse::<Backend>().unwrap(), Backend::Pesto);
+        assert_eq!("candle".parse::<Backend>().unwrap(), Backend::Candle);
+        assert!("onnx".parse::<Backend>().is_err());
+        assert!(Backend::Pesto.create(None).is_ok());
+        assert!(Backend::Candle.create(None).is_err());
+        assert_eq!("quantized".parse::<Backend>().unwrap(), Backend::Quantized);
+        assert!(Backend::Quantized.create(None).is_err());
+    }
+}
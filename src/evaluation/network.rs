@@ -1 +1,384 @@
 //! Policy + Value Neural Network model.
+
+use std::path::Path;
+
+use anyhow::{bail, ensure, Context};
+use candle_core::{DType, Device, Tensor};
+use candle_nn::{linear, Linear, Module, VarBuilder};
+use safetensors::{SafeTensorError, SafeTensors};
+
+use super::features::{self, NUM_FEATURES, NUM_MOVES};
+use super::{centipawns_to_value, draw_probability, endgame, Evaluator, Prediction};
+use crate::chess::position::Position;
+
+pub(super) const HIDDEN_SIZE: usize = 256;
+
+/// Value of the `format` metadata entry of the network files.
+pub(crate) const FORMAT: &str = "pabi";
+/// Version of the network architecture, stored in the `version` metadata
+/// entry. Bumped whenever the inputs or the layers change.
+pub(crate) const FORMAT_VERSION: &str = "1";
+
+/// Simple network with a shared hidden layer and separate value and policy
+/// heads. The hidden layer uses clipped ReLU activation, which allows
+/// [`super::quantized::QuantizedNetwork`] to use integer arithmetic.
+pub(crate) struct Network {
+    pub(super) hidden: Linear,
+    pub(super) value: Linear,
+    pub(super) policy: Linear,
+    device: Device,
+}
+
+impl Network {
+    pub(super) fn new(weights: VarBuilder<'_>) -> candle_core::Result<Self> {
+        Ok(Self {
+            hidden: linear(NUM_FEATURES, HIDDEN_SIZE, weights.pp("hidden"))?,
+            value: linear(HIDDEN_SIZE, 1, weights.pp("value"))?,
+            policy: linear(HIDDEN_SIZE, NUM_MOVES, weights.pp("policy"))?,
+            device: weights.device().clone(),
+        })
+    }
+
+    /// Loads the network weights in [safetensors] format.
+    ///
+    /// The file is checked before loading (see [`validate`]) and the weights
+    /// have to be finite, so that a corrupted file is reported at startup
+    /// instead of producing garbage or failing in the middle of the search.
+    ///
+    /// [safetensors]: https://huggingface.co/docs/safetensors
+    pub(crate) fn load(path: &Path) -> anyhow::Result<Self> {
+        let data = std::fs::read(path)
+            .with_context(|| format!("reading network weights from {}", path.display()))?;
+        let context = || format!("loading network from {}", path.display());
+        validate(&data).with_context(context)?;
+        let weights = VarBuilder::from_buffered_safetensors(data, DType::F32, &Device::Cpu)
+            .with_context(context)?;
+        let network = Self::new(weights).with_context(context)?;
+        network.check_finite().with_context(context)?;
+        Ok(network)
+    }
+
+    fn check_finite(&self) -> anyhow::Result<()> {
+        for (name, layer) in [
+            ("hidden", &self.hidden),
+            ("value", &self.value),
+            ("policy", &self.policy),
+        ] {
+            let weights = layer.weight().flatten_all()?.to_vec1::<f32>()?;
+            let bias = match layer.bias() {
+                Some(bias) => bias.to_vec1::<f32>()?,
+                None => Vec::new(),
+            };
+            ensure!(
+                weights.iter().chain(&bias).all(|weight| weight.is_finite()),
+                "{name} layer contains NaN or infinite weights"
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Checks the framing of the safetensors file (header size, header and
+/// tensor offsets covering exactly the whole file), which catches truncated
+/// and non-safetensors files. If the file has the metadata written by the
+/// training pipeline, it also has to be the expected [`FORMAT`] and
+/// [`FORMAT_VERSION`] and the tensor data has to match the `sha256` checksum.
+/// Files without the metadata (e.g. saved directly from Candle) are accepted.
+pub(crate) fn validate(data: &[u8]) -> anyhow::Result<()> {
+    let (header_size, metadata) = SafeTensors::read_metadata(data).map_err(|error| {
+        anyhow::anyhow!(
+            "not a valid safetensors file ({} bytes): {}",
+            data.len(),
+            describe(&error)
+        )
+    })?;
+    let Some(metadata) = metadata.metadata() else {
+        return Ok(());
+    };
+    if let Some(format) = metadata.get("format") {
+        ensure!(
+            format == FORMAT,
+            "unexpected network format: expected {FORMAT:?}, found {format:?}"
+        );
+    }
+    if let Some(version) = metadata.get("version") {
+        ensure!(
+            version == FORMAT_VERSION,
+            "unsupported network version: expected {FORMAT_VERSION}, found {version}"
+        );
+    }
+    if let Some(expected) = metadata.get("sha256") {
+        let actual = super::checksum::hex_digest(&data[8 + header_size..]);
+        if !actual.eq_ignore_ascii_case(expected) {
+            bail!("checksum mismatch: expected {expected}, found {actual}, the file is corrupted");
+        }
+    }
+    Ok(())
+}
+
+fn describe(error: &SafeTensorError) -> String {
+    match error {
+        SafeTensorError::HeaderTooSmall => "file is too short to contain the header size".into(),
+        SafeTensorError::HeaderTooLarge | SafeTensorError::InvalidHeaderLength => {
+            "header size is larger than the file, the file is truncated or not in safetensors \
+             format"
+                .into()
+        },
+        SafeTensorError::InvalidHeader
+        | SafeTensorError::InvalidHeaderStart
+        | SafeTensorError::InvalidHeaderDeserialization => {
+            "header is not valid JSON, the file is not in safetensors format".into()
+        },
+        SafeTensorError::MetadataIncompleteBuffer => {
+            "tensor data does not match the header, the file is truncated or has trailing bytes"
+                .into()
+        },
+        error => format!("{error:?}"),
+    }
+}
+
+impl Evaluator for Network {
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+        let mut input = Vec::with_capacity(positions.len() * NUM_FEATURES);
+        for position in positions {
+            features::encode(position, &mut input);
+        }
+        let input = Tensor::from_vec(input, (positions.len(), NUM_FEATURES), &self.device)?;
+        let hidden = self.hidden.forward(&input)?.clamp(0f32, 1f32)?;
+        let values = self
+            .value
+            .forward(&hidden)?
+            .tanh()?
+            .flatten_all()?
+            .to_vec1()?;
+        let logits: Vec<Vec<f32>> = self.policy.forward(&hidden)?.to_vec2()?;
+
+        Ok(positions
+            .iter()
+            .zip(values)
+            .zip(logits)
+            .map(|((position, value), logits)| {
+                let logits: Vec<f32> = position
+                    .generate_moves()
+                    .iter()
+                    .map(|next_move| logits[features::move_index(position, next_move)])
+                    .collect();
+                let value = endgame::probe(position).map_or(value, centipawns_to_value);
+                Prediction {
+                    value,
+                    draw: draw_probability(value),
+                    policy: softmax(&logits),
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+impl Network {
+    /// Creates the network with reproducible random weights. Unlike the
+    /// default initialization, the weights have realistic magnitude for the
+    /// quantization.
+    pub(crate) fn random(seed: u64) -> Self {
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = rand::rngs::SmallRng::seed_from_u64(seed);
+        let tensors = [
+            ("hidden.weight", vec![HIDDEN_SIZE, NUM_FEATURES]),
+            ("hidden.bias", vec![HIDDEN_SIZE]),
+            ("value.weight", vec![1, HIDDEN_SIZE]),
+            ("value.bias", vec![1]),
+            ("policy.weight", vec![NUM_MOVES, HIDDEN_SIZE]),
+            ("policy.bias", vec![NUM_MOVES]),
+        ]
+        .into_iter()
+        .map(|(name, shape)| {
+            let data = (0..shape.iter().product())
+                .map(|_| rng.gen_range(-0.1f32..0.1))
+                .collect();
+            let tensor = Tensor::from_vec(data, shape, &Device::Cpu).unwrap();
+            (name.to_string(), tensor)
+        })
+        .collect();
+        Self::new(VarBuilder::from_tensors(tensors, DType::F32, &Device::Cpu)).unwrap()
+    }
+
+    /// Writes the weights in the format read by [`Network::load`].
+    pub(crate) fn save(&self, path: &Path) {
+        let tensors: std::collections::HashMap<String, Tensor> = [
+            ("hidden", &self.hidden),
+            ("value", &self.value),
+            ("policy", &self.policy),
+        ]
+        .into_iter()
+        .flat_map(|(name, layer)| {
+            [
+                (format!("{name}.weight"), layer.weight().clone()),
+                (format!("{name}.bias"), layer.bias().unwrap().clone()),
+            ]
+        })
+        .collect();
+        candle_core::safetensors::save(&tensors, path).unwrap();
+    }
+}
+
+pub(super) fn softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exps: Vec<f32> = logits.iter().map(|logit| (logit - max).exp()).collect();
+    let sum: f32 = exps.iter().sum();
+    exps.iter().map(|exp| exp / sum).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use candle_nn::VarMap;
+
+    use super::*;
+
+    fn random_weights() -> VarMap {
+        let weights = VarMap::new();
+        let _ = Network::new(VarBuilder::from_varmap(&weights, DType::F32, &Device::Cpu)).unwrap();
+        weights
+    }
+
+    /// Serializes the weights with the metadata, adding the checksum of the
+    /// tensor data.
+    fn serialize(weights: &VarMap, mut metadata: HashMap<String, String>) -> Vec<u8> {
+        let tensors: Vec<(String, Tensor)> = weights
+            .data()
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, var)| (name.clone(), var.as_tensor().clone()))
+            .collect();
+        let data = safetensors::serialize(tensors.clone(), &Some(metadata.clone())).unwrap();
+        let header_size = u64::from_le_bytes(data[..8].try_into().unwrap()) as usize;
+        let _ = metadata.insert(
+            "sha256".to_string(),
+            super::super::checksum::hex_digest(&data[8 + header_size..]),
+        );
+        safetensors::serialize(tensors, &Some(metadata)).unwrap()
+    }
+
+    fn metadata(version: &str) -> HashMap<String, String> {
+        HashMap::from([
+            ("format".to_string(), FORMAT.to_string()),
+            ("version".to_string(), version.to_string()),
+        ])
+    }
+
+    /// Returns the error message of loading the network from the data.
+    fn load_error(name: &str, data: &[u8]) -> String {
+        let path: PathBuf =
+            std::env::temp_dir().join(format!("pabi-{name}-{}.safetensors", std::process::id()));
+        std::fs::write(&path, data).unwrap();
+        let result = Network::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let message = format!("{:#}", result.err().expect("loading should fail"));
+        assert!(message.contains(&path.display().to_string()), "{message}");
+        message
+    }
+
+    #[test]
+    fn save_and_load() {
+        let weights = VarMap::new();
+        let network =
+            Network::new(VarBuilder::from_varmap(&weights, DType::F32, &Device::Cpu)).unwrap();
+        let path =
+            std::env::temp_dir().join(format!("pabi-network-{}.safetensors", std::process::id()));
+        weights.save(&path).unwrap();
+        let loaded = Network::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let positions = [
+            Position::starting(),
+            Position::from_fen("k7/8/1K6/8/8/8/8/7R b - - 0 1").unwrap(),
+        ];
+        let predictions = network.evaluate(&positions).unwrap();
+        assert_eq!(predictions, loaded.evaluate(&positions).unwrap());
+        assert_eq!(predictions[0].policy.len(), 20);
+        assert_eq!(predictions[1].policy.len(), 1);
+        for prediction in predictions {
+            assert!((-1.0..=1.0).contains(&prediction.value));
+            assert!((prediction.policy.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+        }
+
+        assert!(Network::load(&path).is_err());
+    }
+
+    #[test]
+    fn metadata_validation() {
+        let weights = random_weights();
+        let data = serialize(&weights, metadata(FORMAT_VERSION));
+        validate(&data).unwrap();
+
+        let message = load_error("version", &serialize(&weights, metadata("0")));
+        assert!(
+            message.contains("unsupported network version: expected 1, found 0"),
+            "{message}"
+        );
+
+        let mut other = metadata(FORMAT_VERSION);
+        let _ = other.insert("format".to_string(), "lc0".to_string());
+        let message = load_error("format", &serialize(&weights, other));
+        assert!(
+            message.contains("expected \"pabi\", found \"lc0\""),
+            "{message}"
+        );
+
+        let mut corrupted = data.clone();
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        let message = load_error("checksum", &corrupted);
+        assert!(message.contains("checksum mismatch"), "{message}");
+    }
+
+    #[test]
+    fn corrupted_files() {
+        let data = serialize(&random_weights(), metadata(FORMAT_VERSION));
+
+        let message = load_error("truncated", &data[..data.len() / 2]);
+        assert!(message.contains("the file is truncated"), "{message}");
+
+        let message = load_error("trailing", &[data.as_slice(), &[0; 4]].concat());
+        assert!(message.contains("has trailing bytes"), "{message}");
+
+        let message = load_error("empty", &[]);
+        assert!(message.contains("too short"), "{message}");
+
+        let message = load_error("text", b"This is not a network, just some text.");
+        assert!(
+            message.contains("not a valid safetensors file"),
+            "{message}"
+        );
+
+        // Valid file with the wrong tensors.
+        let tensors = [(
+            "hidden.weight",
+            Tensor::zeros((2, 2), DType::F32, &Device::Cpu).unwrap(),
+        )];
+        let message = load_error("shape", &safetensors::serialize(tensors, &None).unwrap());
+        assert!(message.contains("hidden.weight"), "{message}");
+    }
+
+    #[test]
+    fn non_finite_weights() {
+        let weights = random_weights();
+        weights
+            .data()
+            .lock()
+            .unwrap()
+            .get("value.bias")
+            .unwrap()
+            .set(&Tensor::new(&[f32::NAN], &Device::Cpu).unwrap())
+            .unwrap();
+        let message = load_error("nan", &serialize(&weights, HashMap::new()));
+        assert!(
+            message.contains("value layer contains NaN or infinite weights"),
+            "{message}"
+        );
+    }
+}
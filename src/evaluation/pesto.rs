@@ -0,0 +1,109 @@
+//! Tapered [PeSTO] evaluation: a hand-crafted evaluation function that only
+//! uses piece-square tables tuned with Texel's tuning method. This is a strong
+//! baseline for the search until the neural network evaluation is ready.
+//!
+//! [PeSTO]: https://www.chessprogramming.org/PeSTO%27s_Evaluation_Function
+
+use crate::chess::position::Position;
+
+/// Piece-square tables for each [`crate::chess::core::Piece::plane`] in the
+/// middlegame, generated by `build.rs`.
+const MIDDLEGAME_TABLE: [[i32; 64]; 12] =
+    include!(concat!(env!("OUT_DIR"), "/pesto_middlegame_table"));
+/// Piece-square tables for each [`crate::chess::core::Piece::plane`] in the
+/// endgame, generated by `build.rs`.
+const ENDGAME_TABLE: [[i32; 64]; 12] = include!(concat!(env!("OUT_DIR"), "/pesto_endgame_table"));
+
+/// Contribution of each [`PieceKind`] to the game phase: the starting position
+/// has the maximum phase value ([`MAX_PHASE`]) and it decreases as the pieces
+/// are traded.
+const PHASE_INCREMENT: [i32; 6] = [0, 1, 1, 2, 4, 0];
+pub(crate) const MAX_PHASE: i32 = 24;
+
+/// Returns the static evaluation of the position in centipawns from the
+/// perspective of the player to move.
+#[must_use]
+pub(super) fn evaluate(position: &Position) -> i32 {
+    let mut middlegame = [0; 2];
+    let mut endgame = [0; 2];
+    let mut phase = 0;
+
+    for (square, piece) in position.iter_pieces() {
+        let plane = piece.plane();
+        middlegame[piece.player as usize] += MIDDLEGAME_TABLE[plane][square as usize];
+        endgame[piece.player as usize] += ENDGAME_TABLE[plane][square as usize];
+        phase += PHASE_INCREMENT[piece.kind as usize];
+    }
+
+    let (us, them) = (position.us() as usize, position.them() as usize);
+    let middlegame_score = middlegame[us] - middlegame[them];
+    let endgame_score = endgame[us] - endgame[them];
+    // Early promotions might push the phase above the maximum.
+    let middlegame_phase = phase.min(MAX_PHASE);
+    let endgame_phase = MAX_PHASE - middlegame_phase;
+
+    (middlegame_score * middlegame_phase + endgame_score * endgame_phase) / MAX_PHASE
+}
+
+/// Returns the game phase from 0 (only kings and pawns) to [`MAX_PHASE`] (all
+/// pieces are on the board), same as in [`evaluate`].
+#[must_use]
+pub(crate) fn phase(position: &Position) -> i32 {
+    let phase: i32 = position
+        .iter_pieces()
+        .map(|(_, piece)| PHASE_INCREMENT[piece.kind as usize])
+        .sum();
+    phase.min(MAX_PHASE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symmetric() {
+        assert_eq!(evaluate(&Position::starting()), 0);
+        // The evaluation is from the perspective of the player to move, so
+        // mirrored positions should have the same score.
+        assert_eq!(
+            evaluate(
+                &Position::from_fen(
+                    "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3"
+                )
+                .unwrap()
+            ),
+            evaluate(
+                &Position::from_fen(
+                    "rnbqkb1r/pppp1ppp/5n2/4p3/4P3/2N5/PPPP1PPP/R1BQKBNR b KQkq - 2 3"
+                )
+                .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn material_advantage() {
+        // White is up a queen.
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        assert!(evaluate(&position) > 800);
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/3QK3 b - - 0 1").unwrap();
+        assert!(evaluate(&position) < -800);
+    }
+
+    #[test]
+    fn game_phase() {
+        assert_eq!(phase(&Position::starting()), MAX_PHASE);
+        assert_eq!(
+            phase(&Position::from_fen("4k3/pppp4/8/8/8/8/PPPP4/3QK3 w - - 0 1").unwrap()),
+            4
+        );
+        // Extra queens do not push the phase above the maximum.
+        assert_eq!(
+            phase(
+                &Position::from_fen("QQQQkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQk - 0 1")
+                    .unwrap()
+            ),
+            MAX_PHASE
+        );
+    }
+}
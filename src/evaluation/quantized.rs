@@ -0,0 +1,411 @@
+//! Integer version of [`Network`] for fast inference on CPU.
+//!
+//! The input features are sparse and binary, so the hidden layer is computed
+//! by adding up the weight columns of the active features in `i16`
+//! accumulators, the same way [NNUE] does it. The output layers use `i32` dot
+//! products and only the logits of legal moves are computed for the policy.
+//!
+//! The loops work on fixed-size arrays, which the compiler vectorizes with the
+//! available SIMD instructions.
+//!
+//! The hidden layer can also be updated incrementally: the search keeps
+//! [`Accumulators`] along its path and each move only adds and removes the
+//! features of the pieces that moved ([`Position::piece_changes`]).
+//!
+//! [NNUE]: https://www.chessprogramming.org/NNUE
+
+use std::sync::Arc;
+
+use super::features::{self, NUM_FEATURES, NUM_MOVES};
+use super::network::{self, Network, HIDDEN_SIZE};
+use super::{centipawns_to_value, draw_probability, endgame, Evaluator, Prediction};
+use crate::chess::core::{Move, Piece, Square};
+use crate::chess::position::{PieceChanges, Position};
+use crate::environment::Player;
+
+/// Scale of the hidden layer weights and activations: clipped ReLU output of
+/// 1.0 corresponds to `QA`.
+const QA: i32 = 255;
+/// Scale of the output layer weights. The weights are small, so the scale has
+/// to be large to keep the precision. The dot product still fits into `i32`:
+/// `QA * i16::MAX * HIDDEN_SIZE < i32::MAX`.
+const QB: i32 = 1024;
+/// Upper bound on the number of pieces on the board.
+const MAX_ACTIVE_FEATURES: i32 = 32;
+/// Hidden weights are clipped so that the sum of all active features and the
+/// bias never overflows the `i16` accumulator.
+const MAX_HIDDEN_WEIGHT: i32 = i16::MAX as i32 / (MAX_ACTIVE_FEATURES + 1);
+
+type Hidden = [i16; HIDDEN_SIZE];
+
+/// Quantized weights of the first (hidden) layer.
+struct FeatureTransformer {
+    /// Column of weights for each input feature.
+    weights: Vec<Hidden>,
+    bias: Hidden,
+}
+
+impl FeatureTransformer {
+    fn transform(&self, position: &Position, perspective: Player) -> Hidden {
+        let mut hidden = self.bias;
+        for feature in features::active_for(position, perspective) {
+            add(&mut hidden, &self.weights[feature]);
+        }
+        hidden
+    }
+}
+
+/// Hidden layer values (before activation) for both perspectives.
+struct Accumulator {
+    transformer: Arc<FeatureTransformer>,
+    /// Indexed by [`Player`].
+    values: [Hidden; 2],
+}
+
+impl Accumulator {
+    fn new(transformer: Arc<FeatureTransformer>, position: &Position) -> Self {
+        let values = [
+            transformer.transform(position, Player::White),
+            transformer.transform(position, Player::Black),
+        ];
+        Self {
+            transformer,
+            values,
+        }
+    }
+
+    /// Removes the pieces before adding the new ones, so that the values
+    /// never have more than [`MAX_ACTIVE_FEATURES`] features.
+    fn apply(&mut self, changes: &PieceChanges) {
+        for &(piece, square) in &changes.removed {
+            self.remove(piece, square);
+        }
+        for &(piece, square) in &changes.added {
+            self.add(piece, square);
+        }
+    }
+
+    fn revert(&mut self, changes: &PieceChanges) {
+        for &(piece, square) in &changes.added {
+            self.remove(piece, square);
+        }
+        for &(piece, square) in &changes.removed {
+            self.add(piece, square);
+        }
+    }
+
+    fn add(&mut self, piece: Piece, square: Square) {
+        for perspective in [Player::White, Player::Black] {
+            let feature = features::index(piece, square, perspective);
+            add(
+                &mut self.values[perspective as usize],
+                &self.transformer.weights[feature],
+            );
+        }
+    }
+
+    fn remove(&mut self, piece: Piece, square: Square) {
+        for perspective in [Player::White, Player::Black] {
+            let feature = features::index(piece, square, perspective);
+            sub(
+                &mut self.values[perspective as usize],
+                &self.transformer.weights[feature],
+            );
+        }
+    }
+}
+
+/// Hidden layer of the [`QuantizedNetwork`] kept up to date along the search
+/// path: the search pushes each move it makes from the root and pops it on the
+/// way back, so that the leaves are evaluated without computing the hidden
+/// layer from scratch. The default is empty and ignores the moves, it is used
+/// by the evaluators that do not have incremental state.
+#[derive(Default)]
+pub struct Accumulators {
+    accumulator: Option<Accumulator>,
+    /// Changes of the moves from the root, applied to the accumulator.
+    path: Vec<PieceChanges>,
+}
+
+impl Accumulators {
+    /// Applies the changes of the legal move made in `position`.
+    pub fn push(&mut self, position: &Position, next_move: &Move) {
+        let Some(accumulator) = &mut self.accumulator else {
+            return;
+        };
+        let changes = position.piece_changes(next_move);
+        accumulator.apply(&changes);
+        self.path.push(changes);
+    }
+
+    /// Reverts the last [`Accumulators::push`].
+    pub fn pop(&mut self) {
+        if let (Some(accumulator), Some(changes)) = (&mut self.accumulator, self.path.pop()) {
+            accumulator.revert(&changes);
+        }
+    }
+}
+
+pub(crate) struct QuantizedNetwork {
+    transformer: Arc<FeatureTransformer>,
+    value_weights: Hidden,
+    value_bias: i32,
+    /// Row of weights for each move.
+    policy_weights: Vec<Hidden>,
+    policy_bias: Vec<i32>,
+}
+
+impl QuantizedNetwork {
+    /// Quantizes the weights of the float network.
+    pub(crate) fn new(network: &Network) -> anyhow::Result<Self> {
+        let hidden_weights: Vec<Vec<f32>> = network.hidden.weight().t()?.to_vec2()?;
+        let value_weights: Vec<Vec<f32>> = network.value.weight().to_vec2()?;
+        let policy_weights: Vec<Vec<f32>> = network.policy.weight().to_vec2()?;
+        debug_assert_eq!(hidden_weights.len(), NUM_FEATURES);
+        debug_assert_eq!(policy_weights.len(), NUM_MOVES);
+
+        Ok(Self {
+            transformer: Arc::new(FeatureTransformer {
+                weights: hidden_weights
+                    .iter()
+                    .map(|column| quantize(column, QA, MAX_HIDDEN_WEIGHT))
+                    .collect(),
+                bias: quantize(&bias(&network.hidden)?, QA, MAX_HIDDEN_WEIGHT),
+            }),
+            value_weights: quantize(&value_weights[0], QB, i16::MAX.into()),
+            value_bias: quantize_bias(bias(&network.value)?[0]),
+            policy_weights: policy_weights
+                .iter()
+                .map(|row| quantize(row, QB, i16::MAX.into()))
+                .collect(),
+            policy_bias: bias(&network.policy)?
+                .into_iter()
+                .map(quantize_bias)
+                .collect(),
+        })
+    }
+
+    /// Computes the output layers from the hidden layer values of the player
+    /// to move.
+    fn predict(&self, position: &Position, mut hidden: Hidden) -> Prediction {
+        // Clipped ReLU.
+        for value in &mut hidden {
+            *value = (*value).clamp(0, QA as i16);
+        }
+        let value = dequantize(dot(&hidden, &self.value_weights) + self.value_bias);
+        let logits: Vec<f32> = position
+            .generate_moves()
+            .iter()
+            .map(|next_move| {
+                let index = features::move_index(position, next_move);
+                dequantize(dot(&hidden, &self.policy_weights[index]) + self.policy_bias[index])
+            })
+            .collect();
+        let value = endgame::probe(position).map_or(value.tanh(), centipawns_to_value);
+        Prediction {
+            value,
+            draw: draw_probability(value),
+            policy: network::softmax(&logits),
+        }
+    }
+}
+
+impl Evaluator for QuantizedNetwork {
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+        Ok(positions
+            .iter()
+            .map(|position| {
+                self.predict(
+                    position,
+                    self.transformer.transform(position, position.us()),
+                )
+            })
+            .collect())
+    }
+
+    fn accumulators(&self, root: &Position) -> Accumulators {
+        Accumulators {
+            accumulator: Some(Accumulator::new(Arc::clone(&self.transformer), root)),
+            path: Vec::new(),
+        }
+    }
+
+    /// Uses the accumulators if they were produced by this network.
+    fn evaluate_incremental(
+        &self,
+        position: &Position,
+        accumulators: &Accumulators,
+    ) -> anyhow::Result<Prediction> {
+        let us = position.us();
+        let hidden = match &accumulators.accumulator {
+            Some(accumulator) if Arc::ptr_eq(&accumulator.transformer, &self.transformer) => {
+                accumulator.values[us as usize]
+            },
+            _ => self.transformer.transform(position, us),
+        };
+        Ok(self.predict(position, hidden))
+    }
+}
+
+fn bias(layer: &candle_nn::Linear) -> anyhow::Result<Vec<f32>> {
+    Ok(layer
+        .bias()
+        .map(candle_core::Tensor::to_vec1)
+        .transpose()?
+        .unwrap_or_else(|| vec![0.0; layer.weight().dims()[0]]))
+}
+
+fn quantize(weights: &[f32], scale: i32, limit: i32) -> Hidden {
+    debug_assert_eq!(weights.len(), HIDDEN_SIZE);
+    let mut result = [0; HIDDEN_SIZE];
+    for (quantized, weight) in result.iter_mut().zip(weights) {
+        *quantized = ((weight * scale as f32).round() as i32).clamp(-limit, limit) as i16;
+    }
+    result
+}
+
+/// Output biases are added to the dot product which has `QA * QB` scale.
+fn quantize_bias(bias: f32) -> i32 {
+    (bias * (QA * QB) as f32).round() as i32
+}
+
+fn dequantize(value: i32) -> f32 {
+    value as f32 / (QA * QB) as f32
+}
+
+fn add(hidden: &mut Hidden, weights: &Hidden) {
+    for (value, weight) in hidden.iter_mut().zip(weights) {
+        *value += weight;
+    }
+}
+
+fn sub(hidden: &mut Hidden, weights: &Hidden) {
+    for (value, weight) in hidden.iter_mut().zip(weights) {
+        *value -= weight;
+    }
+}
+
+fn dot(hidden: &Hidden, weights: &Hidden) -> i32 {
+    hidden
+        .iter()
+        .zip(weights)
+        .map(|(value, weight)| i32::from(*value) * i32::from(*weight))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn random_network(seed: u64) -> (Network, QuantizedNetwork) {
+        let network = Network::random(seed);
+        let quantized = QuantizedNetwork::new(&network).unwrap();
+        (network, quantized)
+    }
+
+    #[test]
+    fn accuracy() {
+        let (network, quantized) = random_network(0);
+
+        let positions: Vec<Position> = [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        ]
+        .iter()
+        .map(|fen| Position::from_fen(fen).unwrap())
+        .collect();
+        let expected = network.evaluate(&positions).unwrap();
+        let actual = quantized.evaluate(&positions).unwrap();
+
+        for (expected, actual) in expected.iter().zip(&actual) {
+            assert!(
+                (expected.value - actual.value).abs() < 0.01,
+                "{} vs {}",
+                expected.value,
+                actual.value
+            );
+            assert_eq!(expected.policy.len(), actual.policy.len());
+            for (expected, actual) in expected.policy.iter().zip(&actual.policy) {
+                assert!((expected - actual).abs() < 0.01, "{expected} vs {actual}");
+            }
+        }
+    }
+
+    #[test]
+    fn incremental_accumulator() {
+        let (_, quantized) = random_network(0);
+        let mut rng = SmallRng::seed_from_u64(42);
+        // Castling, en passant and promotions all change more than two
+        // features.
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+        ] {
+            let root = Position::from_fen(fen).unwrap();
+            let mut accumulators = quantized.accumulators(&root);
+            let values =
+                |accumulators: &Accumulators| accumulators.accumulator.as_ref().unwrap().values;
+            for _ in 0..20 {
+                let mut position = root.clone();
+                let mut plies = 0;
+                for _ in 0..100 {
+                    let moves = position.generate_moves();
+                    let Some(next_move) = moves.choose(&mut rng) else {
+                        break;
+                    };
+                    accumulators.push(&position, next_move);
+                    position.make_move(next_move);
+                    plies += 1;
+
+                    let expected = Accumulator::new(Arc::clone(&quantized.transformer), &position);
+                    assert_eq!(values(&accumulators), expected.values, "{position}");
+                }
+                // Popping the moves goes back to the root.
+                for _ in 0..plies {
+                    accumulators.pop();
+                }
+                assert_eq!(
+                    values(&accumulators),
+                    Accumulator::new(Arc::clone(&quantized.transformer), &root).values
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn foreign_accumulator() {
+        let (_, quantized) = random_network(0);
+        let (_, other) = random_network(1);
+        let mut position = Position::starting();
+        let mut accumulators = other.accumulators(&position);
+        let next_move = Move::from_uci("e2e4").unwrap();
+        accumulators.push(&position, &next_move);
+        position.make_move(&next_move);
+        assert_eq!(
+            quantized
+                .evaluate_incremental(&position, &accumulators)
+                .unwrap(),
+            quantized
+                .evaluate(std::slice::from_ref(&position))
+                .unwrap()
+                .remove(0)
+        );
+        // The default accumulators are empty.
+        assert_eq!(
+            quantized
+                .evaluate_incremental(&position, &Accumulators::default())
+                .unwrap(),
+            quantized
+                .evaluate_incremental(&position, &accumulators)
+                .unwrap()
+        );
+    }
+}
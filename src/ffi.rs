@@ -0,0 +1,177 @@
+//! C interface for embedding the engine into GUIs and tools written in other
+//! languages without running it as a separate UCI process. The declarations
+//! are in `include/pabi.h`.
+//!
+//! The library is built with `cargo rustc --release --features ffi --lib
+//! --crate-type cdylib` (or `staticlib`).
+//!
+//! All functions take the engine handle created by [`pabi_engine_new`] which
+//! must be released with [`pabi_engine_free`]. The handle is not thread-safe:
+//! the calls for the same engine should not overlap.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use crate::chess::position::Position;
+use crate::evaluation::{Backend, Evaluator};
+use crate::search::{mcts, Limits};
+
+/// Engine state behind the opaque C handle.
+pub struct PabiEngine {
+    position: Position,
+    evaluator: Box<dyn Evaluator>,
+    /// Best move of the last search in UCI notation, empty if there was no
+    /// search or no legal moves.
+    best_move: CString,
+}
+
+/// Creates an engine with the [`Backend::Pesto`] evaluation and the starting
+/// position.
+#[no_mangle]
+pub extern "C" fn pabi_engine_new() -> *mut PabiEngine {
+    let evaluator = Backend::Pesto
+        .create(None)
+        .expect("pesto evaluation does not need weights");
+    Box::into_raw(Box::new(PabiEngine {
+        position: Position::starting(),
+        evaluator,
+        best_move: CString::default(),
+    }))
+}
+
+/// Destroys the engine. Does nothing if `engine` is null.
+///
+/// # Safety
+///
+/// `engine` should be null or a pointer returned by [`pabi_engine_new`] that
+/// was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn pabi_engine_free(engine: *mut PabiEngine) {
+    if !engine.is_null() {
+        drop(Box::from_raw(engine));
+    }
+}
+
+/// Sets the position from FEN (or EPD). Returns false and keeps the current
+/// position if the input is not a valid position.
+///
+/// # Safety
+///
+/// `engine` should be a valid engine handle and `fen` a null-terminated
+/// string.
+#[no_mangle]
+pub unsafe extern "C" fn pabi_engine_set_position(
+    engine: *mut PabiEngine,
+    fen: *const c_char,
+) -> bool {
+    let (Some(engine), false) = (engine.as_mut(), fen.is_null()) else {
+        return false;
+    };
+    let Ok(fen) = CStr::from_ptr(fen).to_str() else {
+        return false;
+    };
+    match Position::try_from(fen) {
+        Ok(position) => {
+            engine.position = position;
+            true
+        },
+        Err(_) => false,
+    }
+}
+
+/// Searches the current position for `movetime_ms` milliseconds. Returns false
+/// if the search failed or the position has no legal moves.
+///
+/// # Safety
+///
+/// `engine` should be a valid engine handle.
+#[no_mangle]
+pub unsafe extern "C" fn pabi_engine_search(engine: *mut PabiEngine, movetime_ms: u64) -> bool {
+    let Some(engine) = engine.as_mut() else {
+        return false;
+    };
+    engine.best_move = CString::default();
+    let limits = Limits {
+        time: Some(Duration::from_millis(movetime_ms)),
+        ..Limits::default()
+    };
+    let Ok(result) = mcts::search(
+        &engine.position,
+        &limits,
+        &mcts::Config::default(),
+        &*engine.evaluator,
+        &AtomicBool::new(false),
+    ) else {
+        return false;
+    };
+    let Some(best_move) = result.best_move else {
+        return false;
+    };
+    engine.best_move = CString::new(best_move.to_string()).expect("UCI moves are ASCII");
+    true
+}
+
+/// Returns the best move found by the last search in UCI notation (e.g.
+/// "e2e4"), or an empty string if the search failed. The string is owned by
+/// the engine and is valid until the next search or [`pabi_engine_free`].
+///
+/// # Safety
+///
+/// `engine` should be a valid engine handle.
+#[no_mangle]
+pub unsafe extern "C" fn pabi_engine_bestmove(engine: *const PabiEngine) -> *const c_char {
+    engine
+        .as_ref()
+        .map_or(ptr::null(), |engine| engine.best_move.as_ptr())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bestmove(engine: *const PabiEngine) -> String {
+        unsafe { CStr::from_ptr(pabi_engine_bestmove(engine)) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn session() {
+        let engine = pabi_engine_new();
+        unsafe {
+            assert_eq!(bestmove(engine), "");
+            assert!(pabi_engine_search(engine, 10));
+            let best_move = bestmove(engine);
+            assert!(Position::starting()
+                .generate_moves()
+                .iter()
+                .any(|next_move| next_move.to_string() == best_move));
+
+            // The only legal move.
+            let fen = c"8/8/8/8/8/2k5/8/K6r w - - 0 1";
+            assert!(pabi_engine_set_position(engine, fen.as_ptr()));
+            assert!(pabi_engine_search(engine, 10));
+            assert_eq!(bestmove(engine), "a1a2");
+
+            // Invalid positions are rejected and the position is preserved.
+            assert!(!pabi_engine_set_position(engine, c"8/8/8 w - -".as_ptr()));
+            assert!(!pabi_engine_set_position(engine, ptr::null()));
+            assert!(pabi_engine_search(engine, 10));
+            assert_eq!(bestmove(engine), "a1a2");
+
+            // Checkmate.
+            let fen = c"8/8/8/8/8/1k6/8/K6r w - - 0 1";
+            assert!(pabi_engine_set_position(engine, fen.as_ptr()));
+            assert!(!pabi_engine_search(engine, 10));
+            assert_eq!(bestmove(engine), "");
+
+            pabi_engine_free(engine);
+            pabi_engine_free(ptr::null_mut());
+            assert!(!pabi_engine_search(ptr::null_mut(), 10));
+            assert!(pabi_engine_bestmove(ptr::null()).is_null());
+        }
+    }
+}
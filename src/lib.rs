@@ -8,6 +8,10 @@ pub mod datagen;
 pub mod engine;
 pub mod environment;
 pub mod evaluation;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "python")]
+mod python;
 pub mod search;
 
 pub use engine::Engine;
@@ -29,6 +33,26 @@ fn engine_version() -> String {
     )
 }
 
+/// Summary of the build for identifying the binary in logs: target features,
+/// build type, commit and move generation backend.
+fn build_info() -> String {
+    format!(
+        "{BUILD_FEATURES}, {} build, commit {}{}, move generation: {}",
+        if shadow_rs::is_debug() {
+            "debug"
+        } else {
+            "release"
+        },
+        build::SHORT_COMMIT,
+        if shadow_rs::git_clean() {
+            ""
+        } else {
+            " with uncommitted changes"
+        },
+        chess::attacks::SliderBackend::current()
+    )
+}
+
 /// Prints information about the engine version, author and GitHub repository
 /// on engine startup.
 pub fn print_engine_info() {
@@ -36,11 +60,19 @@ pub fn print_engine_info() {
     println!("<https://github.com/kirillbobyrev/pabi>");
 }
 
-/// Prints information the build type, features and whether the build is clean
-/// on engine startup.
+/// Prints information the build type, features, move generation backend and
+/// whether the build is clean on engine startup.
 pub fn print_binary_info() {
     println!("Release build: {}", !shadow_rs::is_debug());
     println!("Features: {BUILD_FEATURES}");
+    println!(
+        "info string Move generation: {}",
+        chess::attacks::SliderBackend::current()
+    );
+    match chess::verify_tables() {
+        Ok(checksum) => println!("Generated tables checksum: {checksum:016x}"),
+        Err(error) => println!("Warning: {error}"),
+    }
     if !shadow_rs::git_clean() {
         println!("Warning: built with uncommitted changes");
     }
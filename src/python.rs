@@ -0,0 +1,184 @@
+//! Python bindings for scripting analyses and building training datasets with
+//! the same move generation and search as the engine.
+//!
+//! The extension module is built with [maturin] or directly with `cargo rustc
+//! --release --features python,pyo3/extension-module --lib --crate-type
+//! cdylib` and renaming `libpabi.so` to `pabi.so`:
+//!
+//! ```python
+//! import pabi
+//!
+//! position = pabi.Position()
+//! position.make_move("e2e4")
+//! print(position.fen(), len(position.legal_moves()), pabi.perft(position, 3))
+//! best_move, score = pabi.search(position, nodes=1000)
+//! ```
+//!
+//! [maturin]: https://www.maturin.rs
+
+#![allow(
+    clippy::useless_conversion,
+    reason = "false positive in the code generated by pyo3 macros"
+)]
+
+use std::sync::atomic::AtomicBool;
+use std::time::Duration;
+
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::chess::position;
+use crate::evaluation::Backend;
+use crate::search::{mcts, Limits, Score};
+
+/// Chess position with the engine's move generator.
+#[pyclass(name = "Position")]
+#[derive(Clone)]
+struct PyPosition {
+    position: position::Position,
+}
+
+#[pymethods]
+impl PyPosition {
+    /// Parses the position from FEN or EPD, the starting position by default.
+    #[new]
+    #[pyo3(signature = (fen = None))]
+    fn new(fen: Option<&str>) -> PyResult<Self> {
+        let position = match fen {
+            Some(fen) => position::Position::try_from(fen)
+                .map_err(|e| PyValueError::new_err(format!("{e:#}")))?,
+            None => position::Position::starting(),
+        };
+        Ok(Self { position })
+    }
+
+    fn fen(&self) -> String {
+        self.position.to_string()
+    }
+
+    /// Legal moves in UCI notation.
+    fn legal_moves(&self) -> Vec<String> {
+        self.position
+            .generate_moves()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    /// Plays the move given in UCI notation. Raises `ValueError` if the move is
+    /// not legal.
+    fn make_move(&mut self, uci: &str) -> PyResult<()> {
+        let next_move = self
+            .position
+            .generate_moves()
+            .iter()
+            .find(|next_move| next_move.to_string() == uci)
+            .copied()
+            .ok_or_else(|| PyValueError::new_err(format!("illegal move: {uci}")))?;
+        self.position.make_move(&next_move);
+        Ok(())
+    }
+
+    fn in_check(&self) -> bool {
+        self.position.in_check()
+    }
+
+    fn __str__(&self) -> String {
+        self.fen()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Position('{}')", self.fen())
+    }
+}
+
+/// Number of leaf nodes in the move generation tree of given depth.
+#[pyfunction]
+fn perft(position: &PyPosition, depth: u8) -> u64 {
+    position::perft(&position.position, depth)
+}
+
+/// Searches the position with the Pesto evaluation and returns the best move
+/// in UCI notation (`None` if the game is over) and the score in centipawns
+/// (±100000 for mate) from the perspective of the player to move. The search
+/// takes 1 second unless limited by time (in milliseconds) or nodes.
+#[pyfunction]
+#[pyo3(signature = (position, movetime = None, nodes = None))]
+fn search(
+    py: Python<'_>,
+    position: &PyPosition,
+    movetime: Option<u64>,
+    nodes: Option<u64>,
+) -> PyResult<(Option<String>, i32)> {
+    let mut limits = Limits {
+        time: movetime.map(Duration::from_millis),
+        nodes,
+        ..Limits::default()
+    };
+    if limits.time.is_none() && limits.nodes.is_none() {
+        limits.time = Some(Duration::from_secs(1));
+    }
+    let evaluator = Backend::Pesto
+        .create(None)
+        .map_err(|e| PyRuntimeError::new_err(e.to_string()))?;
+    let position = position.position.clone();
+    // Other Python threads can run while the engine is searching.
+    let result = py
+        .allow_threads(|| {
+            mcts::search(
+                &position,
+                &limits,
+                &mcts::Config::default(),
+                &*evaluator,
+                &AtomicBool::new(false),
+            )
+        })
+        .map_err(|e| PyRuntimeError::new_err(format!("{e:#}")))?;
+    let score = match result.score {
+        Score::Centipawns(cp) => cp,
+        Score::Mate(moves) => 100_000 * moves.signum(),
+    };
+    Ok((
+        result.best_move.map(|best_move| best_move.to_string()),
+        score,
+    ))
+}
+
+#[pymodule]
+fn pabi(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyPosition>()?;
+    module.add_function(wrap_pyfunction!(perft, module)?)?;
+    module.add_function(wrap_pyfunction!(search, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position() {
+        let mut position = PyPosition::new(None).unwrap();
+        assert_eq!(position.legal_moves().len(), 20);
+        assert_eq!(perft(&position, 3), 8902);
+        position.make_move("e2e4").unwrap();
+        assert_eq!(
+            position.fen(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+        assert!(position.make_move("e2e4").is_err());
+        assert!(position.make_move("invalid").is_err());
+        assert_eq!(
+            position.fen(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+
+        let position = PyPosition::new(Some("7k/8/8/8/8/8/8/K5r1 w - - 0 1")).unwrap();
+        assert!(position.in_check());
+        assert_eq!(
+            position.__repr__(),
+            "Position('7k/8/8/8/8/8/8/K5r1 w - - 0 1')"
+        );
+        assert!(PyPosition::new(Some("8/8/8 w - -")).is_err());
+    }
+}
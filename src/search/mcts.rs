@@ -1,18 +1,174 @@
+use std::collections::{HashSet, VecDeque};
+use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+use super::tree::{Node, Outcome, Proof};
+use super::{policy, Limits, Listener, RootMove, Score, SearchResult, Stability, Wdl};
+use crate::chess::core::{Move, MoveList};
+use crate::chess::position::Position;
+use crate::chess::zobrist;
+use crate::environment::Player;
+use crate::evaluation::{self, Accumulators, Evaluator};
+
 /// Parameters for MCTS search algorithm.
-#[derive(Debug)]
-struct Config {
-    /// Number of search iterations to perform.
-    iterations: u16,
+#[derive(Clone, Debug)]
+pub struct Config {
     /// Number of threads to use.
-    threads: u16,
+    pub threads: u16,
     /// Exploration constant ($c_puct$ in the original paper).
-    cpuct: f32,
-    temperature: f32,
+    pub cpuct: f32,
+    /// First Play Urgency reduction: unvisited children are assumed to be
+    /// this much worse than the parent. Higher values make the search narrower.
+    pub fpu_reduction: f32,
+    /// Softmax temperature applied to the policy priors: values above 1 flatten
+    /// the distribution (wider search) and values below 1 sharpen it.
+    pub policy_temperature: f32,
+    /// Multiplier of the priors of the checking moves (renormalized after
+    /// boosting). Values above 1 search the forcing lines earlier, which helps
+    /// to find tactics in check-dense positions.
+    pub check_prior_boost: f32,
+    pub temperature: f32,
     /// Dirichlet distribution parameter for action selection at the root node.
-    dirichlet_alpha: f32,
+    pub dirichlet_alpha: f32,
     /// Fraction of the dirichlet noise to add to the prior probabilities
     /// ($\epsilon$ in the original paper).
-    dirichlet_exploration_weight: f32,
+    pub dirichlet_exploration_weight: f32,
+    /// Analysis mode (`UCI_AnalyseMode`): keep searching all root moves after
+    /// the result is proven instead of stopping early.
+    pub analysis: bool,
+    /// Amplitude of the random jitter (in centipawns) added to the values of
+    /// the root moves when choosing the move to play. Varies the openings in
+    /// self-play and casual games; 0 disables it.
+    pub root_jitter: u16,
+    /// The jitter is only applied during the first plies of the game.
+    pub root_jitter_plies: u16,
+    /// The same seed and position always produce the same jitter.
+    pub root_jitter_seed: u64,
+    /// Penalty (in centipawns) for the draw probability of the root moves when
+    /// choosing the move to play: among the moves of similar value, the ones
+    /// keeping more winning chances are preferred. 0 disables it.
+    pub contempt: u16,
+    /// Progressive widening: a node with $N$ visits only opens up to
+    /// $\lceil b \cdot (N + 1)^e \rceil$ children in the order of their priors,
+    /// where $b$ is the base and $e$ the exponent. In positions with many
+    /// legal moves the playouts first go to the most promising ones instead of
+    /// evaluating every move once. 0 disables widening.
+    pub widening_base: f32,
+    pub widening_exponent: f32,
+    /// Root move pruning: a root move with at least
+    /// [`Config::root_pruning_visits`] visits and the value this many
+    /// centipawns below the best such move gets no more playouts, which go to
+    /// the candidate moves instead. The margin grows linearly with the traded
+    /// material up to twice this value in pawn endgames, where the evaluation
+    /// swings more. Disabled in analysis mode, which reports all root moves,
+    /// and with 0.
+    pub root_pruning: u16,
+    pub root_pruning_visits: u32,
+    /// Maximum number of moves in the reported principal variations.
+    pub max_pv_length: u16,
+    /// Upper bound on the memory used by the search tree in bytes (UCI
+    /// `Hash` option). Once the tree reaches it, the search goes on without
+    /// adding new nodes: the leaves are evaluated again instead of expanded.
+    pub tree_memory: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            threads: 1,
+            cpuct: 1.5,
+            fpu_reduction: 0.0,
+            policy_temperature: 1.0,
+            check_prior_boost: 1.0,
+            temperature: 0.0,
+            dirichlet_alpha: 0.3,
+            dirichlet_exploration_weight: 0.25,
+            analysis: false,
+            root_jitter: 0,
+            root_jitter_plies: 16,
+            root_jitter_seed: 0,
+            contempt: 0,
+            widening_base: 0.0,
+            widening_exponent: 0.4,
+            root_pruning: 0,
+            root_pruning_visits: 100,
+            max_pv_length: 64,
+            tree_memory: DEFAULT_TREE_MEMORY,
+        }
+    }
+}
+
+impl Config {
+    /// Maximum number of children opened by the progressive widening at the
+    /// node with the given number of visits.
+    #[must_use]
+    pub(super) fn widening_limit(&self, visits: u32) -> usize {
+        if self.widening_base <= 0.0 {
+            return usize::MAX;
+        }
+        (self.widening_base * ((visits + 1) as f32).powf(self.widening_exponent)).ceil() as usize
+    }
+
+    /// Margin of the root move pruning in the `position` in the units of
+    /// [`Node::q`] or [`None`] if the pruning is disabled.
+    #[must_use]
+    pub(super) fn root_pruning_margin(&self, position: &Position) -> Option<f32> {
+        if self.root_pruning == 0 || self.analysis {
+            return None;
+        }
+        let traded = evaluation::MAX_PHASE - evaluation::phase(position);
+        let centipawns =
+            i32::from(self.root_pruning) * (evaluation::MAX_PHASE + traded) / evaluation::MAX_PHASE;
+        Some(evaluation::centipawns_to_value(centipawns))
+    }
+}
+
+/// Default [`Config::tree_memory`]: 256 MB.
+pub const DEFAULT_TREE_MEMORY: usize = 256 << 20;
+
+/// Number of playouts between the samples of [`Stability`].
+const STABILITY_INTERVAL: u64 = 64;
+/// Number of playouts between the checks of [`Limits::mate`], which walk the
+/// tree up to the mate distance.
+const MATE_CHECK_INTERVAL: u64 = 16;
+/// Number of recent samples for [`Stability::q_variance`].
+const STABILITY_WINDOW: usize = 8;
+
+/// Samples the root periodically to compute [`Stability`].
+#[derive(Default)]
+struct StabilityTracker {
+    stability: Stability,
+    best_move: Option<Move>,
+    recent_q: VecDeque<f32>,
+}
+
+impl StabilityTracker {
+    fn sample(&mut self, root: &Node, nodes: u64) {
+        let Some(best_child) = root.best_child() else {
+            return;
+        };
+        if self.best_move.is_some() && self.best_move != best_child.last_move {
+            self.stability.best_move_changes += 1;
+            self.stability.last_change = nodes;
+        }
+        self.best_move = best_child.last_move;
+        if self.recent_q.len() == STABILITY_WINDOW {
+            let _ = self.recent_q.pop_front();
+        }
+        self.recent_q.push_back(best_child.q());
+        let mean = self.recent_q.iter().sum::<f32>() / self.recent_q.len() as f32;
+        self.stability.q_variance = self
+            .recent_q
+            .iter()
+            .map(|q| (q - mean) * (q - mean))
+            .sum::<f32>()
+            / self.recent_q.len() as f32;
+        self.stability.visit_concentration = best_child.visits as f32 / root.visits.max(1) as f32;
+    }
 }
 
 /// Implements AlphaZero's Monte Carlo Tree Search algorithm.
@@ -20,20 +176,1528 @@ struct Config {
 /// 1. Selection: Start from root node and select the most promising child node.
 /// 2. Expansion: If the selected node is not a leaf node, expand it by adding a
 ///    new child node.
-/// 3. Simulation: Run a simulation from the child node until a result is
-///    reached.
+/// 3. Simulation: Evaluate the position in the new node (or determine the
+///    result if the game is over).
 /// 4. Backpropagation: Update the nodes on the path from the root to the
 ///    selected node with the result.
-fn search(iterations: usize) {
-    for _ in 0..iterations {
-        todo!()
+///
+/// Terminal positions are valued exactly instead of being evaluated: checkmate
+/// is a loss for the side to move, while stalemate, the fifty-move rule and
+/// repetitions (a position occurring twice on the path from the root or three
+/// times including [`Limits::history`]) are draws. These results are propagated
+/// through the tree (MCTS-Solver), so forced mates are reported as
+/// [`Score::Mate`] and the search stops as soon as the result at the root is
+/// proven.
+///
+/// The search can be interrupted at any time by setting `stop` flag, in which
+/// case the best result found so far is returned. Once [`Limits::time`] is
+/// reached, the search keeps going until [`Limits::max_time`] while the best
+/// move is unstable.
+///
+/// # Errors
+///
+/// If the evaluator fails.
+pub fn search(
+    position: &Position,
+    limits: &Limits,
+    config: &Config,
+    evaluator: &dyn Evaluator,
+    stop: &AtomicBool,
+) -> anyhow::Result<SearchResult> {
+    search_with_listener(position, limits, config, evaluator, stop, &mut ())
+}
+
+/// Same as [`search`], but reports the progress to the `listener`.
+///
+/// # Errors
+///
+/// If the evaluator fails.
+pub fn search_with_listener(
+    position: &Position,
+    limits: &Limits,
+    config: &Config,
+    evaluator: &dyn Evaluator,
+    stop: &AtomicBool,
+    listener: &mut dyn Listener,
+) -> anyhow::Result<SearchResult> {
+    search_tree(
+        Instant::now(),
+        position,
+        &mut Node::new(None, 1.0),
+        SearchContext {
+            limits,
+            config,
+            evaluator,
+            stop,
+            listener,
+        },
+    )
+}
+
+/// Everything the search runs with, apart from the position and the tree.
+pub(super) struct SearchContext<'a> {
+    pub(super) limits: &'a Limits,
+    pub(super) config: &'a Config,
+    pub(super) evaluator: &'a dyn Evaluator,
+    pub(super) stop: &'a AtomicBool,
+    pub(super) listener: &'a mut dyn Listener,
+}
+
+/// The state of a single playout: what it evaluates the leaves with, the
+/// positions it repeats and the size of the tree it grows.
+struct PlayoutContext<'a> {
+    config: &'a Config,
+    evaluator: &'a dyn Evaluator,
+    /// Incremental state of the evaluator, kept in sync with the moves of the
+    /// playout: each move is pushed on the way down and popped on the way back
+    /// to the root.
+    accumulators: &'a mut Accumulators,
+    /// Hashes of the positions played before the root.
+    history: &'a [zobrist::Key],
+    /// Hashes of the positions from the root to the parent of the current
+    /// node.
+    path: Vec<zobrist::Key>,
+    /// Number of nodes in the tree, updated when the leaves are expanded.
+    tree_size: &'a mut usize,
+}
+
+/// Same as [`search_with_listener`], but continues growing the `root` tree
+/// (e.g. kept from the previous search, see [`super::session::Session`])
+/// instead of starting from scratch. The tree is left in `root` for the next
+/// search. The time limits are counted from `start`, so that the time spent on
+/// preparing the tree is included.
+pub(super) fn search_tree(
+    start: Instant,
+    position: &Position,
+    root: &mut Node,
+    context: SearchContext<'_>,
+) -> anyhow::Result<SearchResult> {
+    let SearchContext {
+        limits,
+        config,
+        evaluator,
+        stop,
+        listener,
+    } = context;
+    // A single playout through the move is enough to report its value or to
+    // prove the mate.
+    let instant_limits;
+    let limits = match limits
+        .shortcuts
+        .then(|| instant_move(position, &limits.searchmoves))
+        .flatten()
+    {
+        Some(next_move) => {
+            instant_limits = Limits {
+                nodes: Some(2),
+                searchmoves: vec![next_move],
+                history: limits.history.clone(),
+                ..Limits::default()
+            };
+            &instant_limits
+        },
+        None => limits,
+    };
+    let mut accumulators = evaluator.accumulators(position);
+    let mut nodes: u64 = 0;
+    let mut depth = Depth::default();
+    let mut tree_size = root.size();
+    let mut stability = StabilityTracker::default();
+    if !root.is_leaf() {
+        restrict_root_moves(root, &limits.searchmoves);
+        order_root_moves(root);
+    }
+
+    // Run at least one playout so that there is a move to play even if the
+    // search is stopped immediately.
+    loop {
+        let mut playout_position = position.clone();
+        let mut playout = PlayoutContext {
+            config,
+            evaluator,
+            accumulators: &mut accumulators,
+            history: &limits.history,
+            path: Vec::new(),
+            tree_size: &mut tree_size,
+        };
+        root_playout(root, &mut playout_position, &mut playout, listener)?;
+        if nodes == 0 {
+            restrict_root_moves(root, &limits.searchmoves);
+            order_root_moves(root);
+        }
+        nodes += 1;
+        depth.add(playout.path.len());
+        listener.playout(nodes, depth.average(nodes), depth.max);
+        if nodes % STABILITY_INTERVAL == 0 {
+            stability.sample(root, nodes);
+        }
+        if listener.snapshot_due() {
+            listener.snapshot(&search_result(
+                root,
+                position,
+                config,
+                nodes,
+                depth,
+                start,
+                stability.stability,
+            ));
+        }
+        if stop.load(Ordering::Relaxed)
+            || (nodes % MATE_CHECK_INTERVAL == 1
+                && limits
+                    .mate
+                    .is_some_and(|moves| mate_settled(root, position, moves)))
+            || should_stop(
+                root,
+                limits,
+                config,
+                nodes,
+                depth,
+                start.elapsed(),
+                &stability.stability,
+            )
+        {
+            break;
+        }
     }
+    stability.sample(root, nodes);
+    Ok(search_result(
+        root,
+        position,
+        config,
+        nodes,
+        depth,
+        start,
+        stability.stability,
+    ))
 }
 
-fn backup() {
-    todo!()
+/// Collects the result of the search from the tree.
+fn search_result(
+    root: &Node,
+    position: &Position,
+    config: &Config,
+    nodes: u64,
+    depth: Depth,
+    start: Instant,
+    stability: Stability,
+) -> SearchResult {
+    let root_moves = root
+        .children
+        .iter()
+        .map(|child| RootMove {
+            next_move: child.last_move.expect("children always have moves"),
+            score: score(child),
+            pv: principal_variation(child, position, config),
+            visits: child.visits,
+            q: child.q(),
+            prior: child.prior,
+            wdl: child.wdl(),
+        })
+        .collect();
+
+    let best_child = select_root_move(root, position, config);
+    let score = match best_child {
+        Some(child) => score(child),
+        // Terminal position at the root.
+        None if position.in_check() => Score::Mate(0),
+        None => Score::Centipawns(0),
+    };
+    let pv = match best_child {
+        Some(child) if child.visited() => principal_variation(child, position, config),
+        _ => Vec::new(),
+    };
+
+    SearchResult {
+        best_move: best_child.and_then(|child| child.last_move),
+        score,
+        q: best_child.map_or_else(|| score.value(), Node::q),
+        wdl: best_child.map_or_else(|| Wdl::new(score.value(), 1.0), Node::wdl),
+        pv,
+        nodes,
+        depth: depth.average(nodes),
+        seldepth: depth.max,
+        elapsed: start.elapsed(),
+        root_moves,
+        stability,
+    }
 }
 
-fn simulate() {
-    todo!()
+/// Returns the move that does not need a search: the only legal move or a
+/// mate in one. Only the `searchmoves` are considered unless the list is empty.
+#[must_use]
+pub fn instant_move(position: &Position, searchmoves: &[Move]) -> Option<Move> {
+    let moves: Vec<Move> = position
+        .generate_moves()
+        .into_iter()
+        .filter(|next_move| searchmoves.is_empty() || searchmoves.contains(next_move))
+        .collect();
+    if let [only_move] = moves[..] {
+        return Some(only_move);
+    }
+    moves.into_iter().find(|next_move| {
+        let mut position = position.clone();
+        position.make_move(next_move);
+        position.in_check() && position.generate_moves().is_empty()
+    })
+}
+
+/// Chooses the move to play: the best child of the root, unless
+/// [`Config::root_jitter`] is enabled and the game is still in the opening or
+/// [`Config::contempt`] is set. Then the moves that got at least half of the
+/// best move's visits compete by their value with the jitter added and the
+/// draw probability penalized, while proven results are never perturbed.
+fn select_root_move<'a>(root: &'a Node, position: &Position, config: &Config) -> Option<&'a Node> {
+    let best_child = root.best_child()?;
+    let ply = 2 * (u32::from(position.fullmove_counter()) - 1)
+        + u32::from(position.us() == Player::Black);
+    let jitter = config.root_jitter > 0 && ply < u32::from(config.root_jitter_plies);
+    if (!jitter && config.contempt == 0) || best_child.proof.is_some() {
+        return Some(best_child);
+    }
+    let amplitude = evaluation::centipawns_to_value(i32::from(config.root_jitter));
+    let contempt = evaluation::centipawns_to_value(i32::from(config.contempt));
+    let mut rng = SmallRng::seed_from_u64(config.root_jitter_seed ^ position.hash());
+    root.children
+        .iter()
+        .map(|child| {
+            let jitter = if jitter {
+                rng.gen_range(-amplitude..=amplitude)
+            } else {
+                0.0
+            };
+            (child, child.q() + jitter - contempt * child.draw())
+        })
+        .filter(|(child, _)| {
+            child.proof.is_none() && child.visited() && 2 * child.visits >= best_child.visits
+        })
+        .max_by(|(_, lhs), (_, rhs)| lhs.total_cmp(rhs))
+        .map_or(Some(best_child), |(child, _)| Some(child))
+}
+
+/// Returns the sequence of best moves starting with the move of the root
+/// child, as far as the tree is explored. The line is cut at
+/// [`Config::max_pv_length`] and after the first repeated position: the rest
+/// of the cycle adds nothing but length.
+fn principal_variation(mut node: &Node, root: &Position, config: &Config) -> Vec<Move> {
+    let max_length = usize::from(config.max_pv_length.max(1));
+    let mut position = root.clone();
+    let mut seen = HashSet::from([(position.hash(), position.us())]);
+    let mut pv = Vec::new();
+    loop {
+        let next_move = node.last_move.expect("children always have moves");
+        pv.push(next_move);
+        position.make_move(&next_move);
+        if pv.len() >= max_length || !seen.insert((position.hash(), position.us())) {
+            break;
+        }
+        match node.best_child() {
+            Some(child) if child.visited() => node = child,
+            _ => break,
+        }
+    }
+    pv
+}
+
+/// Removes the root children that are not in `searchmoves`, unless none of them
+/// are legal.
+fn restrict_root_moves(root: &mut Node, searchmoves: &[Move]) {
+    let allowed = |child: &Node| {
+        child
+            .last_move
+            .is_some_and(|next_move| searchmoves.contains(&next_move))
+    };
+    if root.children.iter().any(allowed) {
+        root.children.retain(allowed);
+    }
+}
+
+/// Sorts the root children by their priors, the most likely moves first. The
+/// root moves are reported in this order (e.g. `currmovenumber` is the rank of
+/// the move by the policy) and the unvisited children with equal PUCT scores
+/// are tried in it. The sort is stable, so without a policy the moves keep the
+/// order of the move generation.
+fn order_root_moves(root: &mut Node) {
+    root.children
+        .sort_by(|left, right| right.prior.total_cmp(&left.prior));
+}
+
+fn should_stop(
+    root: &Node,
+    limits: &Limits,
+    config: &Config,
+    nodes: u64,
+    depth: Depth,
+    elapsed: Duration,
+    stability: &Stability,
+) -> bool {
+    if root.proof.is_some() && !config.analysis {
+        return true;
+    }
+    if limits.nodes.is_some_and(|limit| nodes >= limit) {
+        return true;
+    }
+    if past_deadline(limits, nodes, elapsed) {
+        return true;
+    }
+    if limits.time.is_some_and(|limit| elapsed >= limit) {
+        let extend =
+            limits.max_time.is_some_and(|limit| elapsed < limit) && stability.is_unstable(nodes);
+        if !extend {
+            return true;
+        }
+    }
+    limits
+        .depth
+        .is_some_and(|limit| nodes > 0 && depth.average(nodes) >= limit)
+}
+
+/// Returns true if the search for `go mate <moves>` is over: the mate is
+/// proven at the root (so that it is reported) or the tree shows that there
+/// is none.
+fn mate_settled(root: &Node, position: &Position, moves: u32) -> bool {
+    match mate_within(root, position, 2 * moves) {
+        Some(found) => !found || root.proof.is_some(),
+        None => false,
+    }
+}
+
+/// Answers whether the side to move in the `position` of the `node` can force
+/// a checkmate in less than `plies` plies, as far as the tree shows: [`None`]
+/// if the explored part of the tree is not enough to tell.
+///
+/// The proven nodes give the exact answer. Otherwise the mate has to be forced
+/// against every reply, so all moves of the defending side need to be explored
+/// to prove it, and all moves of the attacking side to refute it. The last
+/// attacking moves are checked directly without expanding the tree.
+fn mate_within(node: &Node, position: &Position, plies: u32) -> Option<bool> {
+    if plies <= 1 {
+        return Some(false);
+    }
+    // The player who moved into the node is mated in at most `distance - 1`
+    // plies: a faster mate might still be found through the unproven moves.
+    match node.proof {
+        Some(Proof::Loss(distance)) if u32::from(distance) <= plies => return Some(true),
+        Some(Proof::Win(_) | Proof::Draw) => return Some(false),
+        _ => {},
+    }
+    if node.is_leaf() {
+        // Only the mate in one can be found without the search.
+        return (plies == 2).then(|| {
+            position.generate_moves().iter().any(|next_move| {
+                let mut position = position.clone();
+                position.make_move(next_move);
+                position.in_check() && position.generate_moves().is_empty()
+            })
+        });
+    }
+    let mut settled = true;
+    for child in &node.children {
+        let mut position = position.clone();
+        position.make_move(&child.last_move.expect("children always have moves"));
+        match mate_against(child, &position, plies - 1) {
+            Some(true) => return Some(true),
+            Some(false) => {},
+            None => settled = false,
+        }
+    }
+    settled.then_some(false)
+}
+
+/// Same as [`mate_within`] for the defending side to move in the `position`:
+/// returns true if it is checkmated in less than `plies` plies whatever it
+/// plays.
+fn mate_against(node: &Node, position: &Position, plies: u32) -> Option<bool> {
+    // The distance counts the mating move leading to the node.
+    match node.proof {
+        Some(Proof::Win(distance)) if u32::from(distance) <= plies => return Some(true),
+        Some(Proof::Loss(_) | Proof::Draw) => return Some(false),
+        _ => {},
+    }
+    if node.is_leaf() {
+        let moves = position.generate_moves();
+        if moves.is_empty() {
+            return Some(position.in_check());
+        }
+        return (plies <= 1).then_some(false);
+    }
+    let mut settled = true;
+    for child in &node.children {
+        let mut position = position.clone();
+        position.make_move(&child.last_move.expect("children always have moves"));
+        match mate_within(child, &position, plies - 1) {
+            Some(false) => return Some(false),
+            Some(true) => {},
+            None => settled = false,
+        }
+    }
+    settled.then_some(true)
+}
+
+/// Returns true if the next playout is expected to finish after the hard time
+/// limit: [`Limits::max_time`] or [`Limits::time`] if the search can not be
+/// extended. The duration of the playout is estimated from the search speed so
+/// far, so the search stops in time even if each evaluation is slow (e.g. a
+/// network on CPU) instead of overshooting by a playout.
+fn past_deadline(limits: &Limits, nodes: u64, elapsed: Duration) -> bool {
+    let Some(deadline) = limits.max_time.max(limits.time) else {
+        return false;
+    };
+    let playout = elapsed.div_f64(nodes.max(1) as f64);
+    elapsed + playout >= deadline
+}
+
+/// Lengths of the playouts: MCTS does not have a uniform depth, so both the
+/// average and the maximum are reported.
+#[derive(Clone, Copy, Debug, Default)]
+struct Depth {
+    total: u64,
+    /// The deepest path explored so far, reported as `seldepth`.
+    max: u32,
+}
+
+impl Depth {
+    fn add(&mut self, length: usize) {
+        self.total = self.total.saturating_add(length as u64);
+        self.max = self.max.max(length as u32);
+    }
+
+    fn average(self, nodes: u64) -> u32 {
+        if nodes == 0 {
+            return 0;
+        }
+        (self.total / nodes) as u32
+    }
+}
+
+/// Runs a single iteration of the search from the root and notifies the
+/// listener about the root move it goes through.
+///
+/// Once the result at the root is proven, regular playouts stop exploring the
+/// tree. In analysis mode the search goes on to find the scores of the other
+/// moves, until they are all proven or the search is stopped.
+fn root_playout(
+    root: &mut Node,
+    position: &mut Position,
+    context: &mut PlayoutContext<'_>,
+    listener: &mut dyn Listener,
+) -> anyhow::Result<()> {
+    let config = context.config;
+    if root.is_leaf() || (root.proof.is_some() && !config.analysis) {
+        let _ = playout(root, position, context)?;
+        return Ok(());
+    }
+    let index = if root.proof.is_none() {
+        policy::select_root(root, config, config.root_pruning_margin(position))
+    } else {
+        match policy::select_unproven(root, config) {
+            Some(index) => index,
+            None => return Ok(()),
+        }
+    };
+    context.path.push(position.hash());
+    let child = &mut root.children[index];
+    let next_move = child.last_move.expect("children always have moves");
+    listener.root_move(next_move, index + 1);
+    context.accumulators.push(position, &next_move);
+    position.make_move(&next_move);
+    let mut outcome = -playout(child, position, context)?;
+    context.accumulators.pop();
+    if root.proof.is_none() {
+        root.update_proof();
+        outcome = root.proof.map_or(outcome, Proof::outcome);
+    }
+    root.update(outcome);
+    Ok(())
+}
+
+/// Runs a single iteration of the search from the given node and returns the
+/// outcome from the perspective of the player who made the move leading to
+/// it.
+///
+/// The leaves are not expanded once the tree reaches [`Config::tree_memory`],
+/// except for the root (with the empty path): there has to be a move to play.
+fn playout(
+    node: &mut Node,
+    position: &mut Position,
+    context: &mut PlayoutContext<'_>,
+) -> anyhow::Result<Outcome> {
+    let outcome = if let Some(proof) = node.proof {
+        proof.outcome()
+    } else if node.is_leaf() {
+        let grow = context.path.is_empty()
+            || *context.tree_size * mem::size_of::<Node>() < context.config.tree_memory;
+        let outcome = expand(node, position, context, grow)?;
+        *context.tree_size += node.children.len();
+        outcome
+    } else {
+        context.path.push(position.hash());
+        let index = policy::select(node, context.config);
+        let child = &mut node.children[index];
+        let next_move = child.last_move.expect("children always have moves");
+        context.accumulators.push(position, &next_move);
+        position.make_move(&next_move);
+        let outcome = -playout(child, position, context)?;
+        context.accumulators.pop();
+        node.update_proof();
+        node.proof.map_or(outcome, Proof::outcome)
+    };
+    node.update(outcome);
+    Ok(outcome)
+}
+
+/// Expands the leaf and returns its outcome from the perspective of the player
+/// who made the move leading to it. Terminal positions are proven instead.
+/// Without `grow` the leaf is only evaluated and stays a leaf.
+fn expand(
+    node: &mut Node,
+    position: &Position,
+    context: &PlayoutContext<'_>,
+    grow: bool,
+) -> anyhow::Result<Outcome> {
+    let PlayoutContext {
+        config, evaluator, ..
+    } = *context;
+    let moves = position.generate_moves();
+    if let Some(proof) = terminal_proof(position, &moves, context.history, &context.path) {
+        node.proof = Some(proof);
+        return Ok(proof.outcome());
+    }
+    // TODO: Collect leaves from multiple playouts and evaluate them in a
+    // single batch.
+    let prediction = evaluator.evaluate_incremental(position, context.accumulators)?;
+    if grow {
+        let priors = policy::apply_temperature(prediction.policy, config.policy_temperature);
+        node.expand(
+            &moves,
+            &policy::boost_checks(priors, position, &moves, config.check_prior_boost),
+        );
+    }
+    Ok(-Outcome {
+        value: prediction.value,
+        draw: prediction.draw,
+    })
+}
+
+/// Returns the result of the game if the position is terminal, from the
+/// perspective of the player who made the last move.
+///
+/// Repeating a position from the search `path` is a draw: either player could
+/// repeat it again. The positions from the game `history` have to occur twice
+/// before for the threefold repetition.
+fn terminal_proof(
+    position: &Position,
+    moves: &MoveList,
+    history: &[zobrist::Key],
+    path: &[zobrist::Key],
+) -> Option<Proof> {
+    if moves.is_empty() {
+        if position.in_check() {
+            return Some(Proof::Win(1));
+        }
+        return Some(Proof::Draw);
+    }
+    if position.halfmove_clock_expired() {
+        return Some(Proof::Draw);
+    }
+    // Positions with the same side to move are two plies apart and the
+    // repetition can not span an irreversible move.
+    let reversible = usize::from(position.halfmove_clock());
+    let mut occurrences = 0;
+    for (plies, &key) in path
+        .iter()
+        .rev()
+        .chain(history.iter().rev())
+        .enumerate()
+        .take(reversible)
+        .skip(1)
+        .step_by(2)
+    {
+        if key == position.hash() {
+            occurrences += 1;
+            if plies < path.len() || occurrences == 2 {
+                return Some(Proof::Draw);
+            }
+        }
+    }
+    None
+}
+
+fn score(child: &Node) -> Score {
+    match child.proof {
+        Some(Proof::Win(plies)) => Score::Mate((i32::from(plies) + 1) / 2),
+        Some(Proof::Loss(plies)) => Score::Mate(-i32::from(plies) / 2),
+        Some(Proof::Draw) => Score::Centipawns(0),
+        None => Score::Centipawns(evaluation::value_to_centipawns(child.q())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluation::Pesto;
+
+    fn search_position(fen: &str) -> SearchResult {
+        search(
+            &Position::from_fen(fen).expect("valid position"),
+            &Limits {
+                nodes: Some(100_000),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .expect("search should not fail")
+    }
+
+    #[test]
+    fn mate_in_one() {
+        let result = search_position("k7/8/1K6/8/8/8/8/7R w - - 0 1");
+        assert_eq!(result.score, Score::Mate(1));
+        assert_eq!(result.best_move, Some(Move::from_uci("h1h8").unwrap()));
+        assert_eq!(result.pv, vec![Move::from_uci("h1h8").unwrap()]);
+    }
+
+    #[test]
+    fn mated_in_one() {
+        let result = search_position("k7/8/1K6/8/8/8/8/7R b - - 0 1");
+        assert_eq!(result.score, Score::Mate(-1));
+        assert_eq!(result.best_move, Some(Move::from_uci("a8b8").unwrap()));
+    }
+
+    #[test]
+    fn mate_in_two() {
+        let result = search_position("k7/8/2K5/8/8/8/8/7R w - - 0 1");
+        assert_eq!(result.score, Score::Mate(2));
+        assert_eq!(result.pv.len(), 3);
+    }
+
+    #[test]
+    fn terminal_root() {
+        let result = search_position("k6R/8/1K6/8/8/8/8/8 b - - 1 1");
+        assert_eq!(result.score, Score::Mate(0));
+        assert!(result.best_move.is_none());
+        assert!(result.root_moves.is_empty());
+        assert_eq!(result.q, -1.0);
+
+        let result = search_position("k7/2Q5/1K6/8/8/8/8/8 b - - 0 1");
+        assert_eq!(result.score, Score::Centipawns(0));
+        assert!(result.best_move.is_none());
+    }
+
+    #[test]
+    fn fifty_move_rule() {
+        // Checkmate takes precedence over the fifty-move rule.
+        let result = search_position("k7/8/1K6/8/8/8/8/7R w - - 99 80");
+        assert_eq!(result.score, Score::Mate(1));
+
+        // Every move completes 50 moves without captures and pawn moves.
+        let result = search_position("k7/8/8/8/8/8/8/K6Q w - - 99 80");
+        assert_eq!(result.score, Score::Centipawns(0));
+        assert_eq!(result.q, 0.0);
+        assert!(result.nodes < 100);
+    }
+
+    #[test]
+    fn repetition() {
+        let mut position = Position::starting();
+        let mut path = Vec::new();
+        for next_move in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            path.push(position.hash());
+            position.make_move(&Move::from_uci(next_move).unwrap());
+        }
+        let moves = position.generate_moves();
+        assert_eq!(
+            terminal_proof(&position, &moves, &[], &path),
+            Some(Proof::Draw)
+        );
+        // The position only repeats from the perspective of the same player.
+        assert_eq!(terminal_proof(&position, &moves, &[], &path[1..]), None);
+        assert_eq!(terminal_proof(&position, &moves, &[], &[]), None);
+        // The game history needs two occurrences.
+        assert_eq!(terminal_proof(&position, &moves, &path, &[]), None);
+        for next_move in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            path.push(position.hash());
+            position.make_move(&Move::from_uci(next_move).unwrap());
+        }
+        assert_eq!(
+            terminal_proof(&position, &moves, &path, &[]),
+            Some(Proof::Draw)
+        );
+        assert_eq!(terminal_proof(&position, &moves, &path[4..], &[]), None);
+        // Once in the history and once in the search.
+        assert_eq!(
+            terminal_proof(&position, &moves, &path[..4], &path[4..]),
+            Some(Proof::Draw)
+        );
+    }
+
+    #[test]
+    fn root_jitter() {
+        let search_with = |position: &Position, config: &Config| {
+            search(
+                position,
+                &Limits {
+                    nodes: Some(1000),
+                    ..Limits::default()
+                },
+                config,
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .expect("search should not fail")
+        };
+        let position = Position::starting();
+        let baseline = search_with(&position, &Config::default());
+        // Disabled jitter has no effect regardless of the other parameters.
+        let disabled = search_with(
+            &position,
+            &Config {
+                root_jitter_plies: 100,
+                root_jitter_seed: 42,
+                ..Config::default()
+            },
+        );
+        assert_eq!(disabled.best_move, baseline.best_move);
+        assert_eq!(disabled.pv, baseline.pv);
+        assert_eq!(disabled.q, baseline.q);
+
+        let jittered = |position: &Position, seed| {
+            search_with(
+                position,
+                &Config {
+                    root_jitter: 50,
+                    root_jitter_seed: seed,
+                    ..Config::default()
+                },
+            )
+        };
+        let result = jittered(&position, 0);
+        assert_eq!(result.pv.first().copied(), result.best_move);
+        assert_eq!(jittered(&position, 0).best_move, result.best_move);
+        let moves: std::collections::HashSet<String> = (0..8)
+            .map(|seed| jittered(&position, seed).best_move.unwrap().to_string())
+            .collect();
+        assert!(moves.len() > 1, "{moves:?}");
+        // No jitter after the opening.
+        let position =
+            Position::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 9").unwrap();
+        assert_eq!(
+            jittered(&position, 1).best_move,
+            search_with(&position, &Config::default()).best_move
+        );
+    }
+
+    #[test]
+    fn principal_variation_cycles() {
+        let position = Position::from_fen("6k1/8/8/8/8/8/8/6K1 w - - 0 1").unwrap();
+        // A line that shuffles the kings back and forth.
+        let shuffle = ["g1h1", "g8h8", "h1g1", "h8g8"];
+        let mut line = None;
+        for uci in [shuffle; 3].concat().iter().rev() {
+            let mut node = Node::new(Some(Move::from_uci(uci).unwrap()), 1.0);
+            node.visits = 1;
+            node.children.extend(line.take());
+            line = Some(node);
+        }
+        let line = line.unwrap();
+        let pv = |max_pv_length| {
+            principal_variation(
+                &line,
+                &position,
+                &Config {
+                    max_pv_length,
+                    ..Config::default()
+                },
+            )
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+        };
+        // The line stops once the starting position is repeated.
+        assert_eq!(pv(64), shuffle);
+        assert_eq!(pv(3), shuffle[..3]);
+        assert_eq!(pv(0), shuffle[..1]);
+    }
+
+    #[test]
+    fn reproducible() {
+        let position = Position::from_fen(
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4",
+        )
+        .unwrap();
+        let run = || {
+            search(
+                &position,
+                &Limits {
+                    nodes: Some(1000),
+                    ..Limits::default()
+                },
+                &Config::default(),
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .expect("search should not fail")
+        };
+        let (first, second) = (run(), run());
+        assert_eq!(first.best_move, second.best_move);
+        assert_eq!(first.pv, second.pv);
+        assert_eq!(first.q.to_bits(), second.q.to_bits());
+        assert_eq!(first.root_moves.len(), second.root_moves.len());
+        for (lhs, rhs) in first.root_moves.iter().zip(&second.root_moves) {
+            assert_eq!(lhs.next_move, rhs.next_move);
+            assert_eq!(lhs.visits, rhs.visits);
+            assert_eq!(lhs.q.to_bits(), rhs.q.to_bits());
+            assert_eq!(lhs.pv, rhs.pv);
+        }
+    }
+
+    #[test]
+    fn node_limit() {
+        let result = search(
+            &Position::starting(),
+            &Limits {
+                nodes: Some(100),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .expect("search should not fail");
+        assert_eq!(result.nodes, 100);
+        assert!(matches!(result.score, Score::Centipawns(_)));
+        assert!(result.best_move.is_some());
+
+        assert_eq!(result.root_moves.len(), 20);
+        assert!((result.root_moves.iter().map(|m| m.prior).sum::<f32>() - 1.0).abs() < 1e-5);
+        let visits: u32 = result.root_moves.iter().map(|m| m.visits).sum();
+        assert!(visits < 100);
+        let best = result
+            .root_moves
+            .iter()
+            .find(|m| Some(m.next_move) == result.best_move)
+            .unwrap();
+        assert_eq!(
+            best.visits,
+            result.root_moves.iter().map(|m| m.visits).max().unwrap()
+        );
+        assert_eq!(best.q, result.q);
+    }
+
+    #[test]
+    fn time_extension() {
+        let run = |fen: &str, max_time: Option<Duration>| {
+            search(
+                &Position::from_fen(fen).unwrap(),
+                &Limits {
+                    time: Some(Duration::ZERO),
+                    max_time,
+                    nodes: Some(1000),
+                    ..Limits::default()
+                },
+                &Config::default(),
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .expect("search should not fail")
+        };
+        let starting = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert_eq!(run(starting, None).nodes, 1);
+        assert_eq!(run(starting, Some(Duration::ZERO)).nodes, 1);
+        // Many moves of similar value: the visits are spread between them.
+        let unstable = run(starting, Some(Duration::from_secs(60)));
+        assert!(unstable.nodes > 1, "{:?}", unstable.stability);
+        // The only legal move is settled after the first sample.
+        let forced = run(
+            "8/8/8/8/8/2k5/8/K6r w - - 0 1",
+            Some(Duration::from_secs(60)),
+        );
+        assert_eq!(forced.nodes, STABILITY_INTERVAL);
+        assert!(forced.stability.visit_concentration > 0.9);
+        assert_eq!(forced.stability.best_move_changes, 0);
+    }
+
+    #[test]
+    fn root_pruning() {
+        let position =
+            Position::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+                .unwrap();
+        let run = |config: &Config| {
+            search(
+                &position,
+                &Limits {
+                    nodes: Some(2000),
+                    ..Limits::default()
+                },
+                config,
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .unwrap()
+        };
+        let pruning = Config {
+            root_pruning: 10,
+            root_pruning_visits: 20,
+            ..Config::default()
+        };
+        let regular = run(&Config::default());
+        let pruned = run(&pruning);
+        // The moves that are skipped by the pruning at the end of the search.
+        let margin = pruning.root_pruning_margin(&position).unwrap();
+        let eligible: Vec<&RootMove> = pruned
+            .root_moves
+            .iter()
+            .filter(|root_move| root_move.visits >= pruning.root_pruning_visits)
+            .collect();
+        let best = eligible
+            .iter()
+            .map(|root_move| root_move.q)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let hopeless: Vec<&RootMove> = eligible
+            .into_iter()
+            .filter(|root_move| root_move.q < best - margin)
+            .collect();
+        assert!(!hopeless.is_empty(), "{:?}", pruned.root_moves);
+        // Their playouts went to the candidate moves instead.
+        let visits = |result: &SearchResult| -> u32 {
+            result
+                .root_moves
+                .iter()
+                .filter(|root_move| {
+                    hopeless
+                        .iter()
+                        .any(|other| other.next_move == root_move.next_move)
+                })
+                .map(|root_move| root_move.visits)
+                .sum()
+        };
+        assert!(
+            visits(&pruned) < visits(&regular),
+            "{} vs {}",
+            visits(&pruned),
+            visits(&regular)
+        );
+        // The analysis mode reports all moves and does not prune them.
+        let analysis = Config {
+            analysis: true,
+            ..Config::default()
+        };
+        assert_eq!(
+            run(&Config {
+                analysis: true,
+                ..pruning
+            })
+            .root_moves,
+            run(&analysis).root_moves
+        );
+    }
+
+    #[test]
+    fn hard_deadline() {
+        /// Takes a while to evaluate each position, like a network on CPU.
+        struct Slow;
+
+        impl Evaluator for Slow {
+            fn evaluate(
+                &self,
+                positions: &[Position],
+            ) -> anyhow::Result<Vec<evaluation::Prediction>> {
+                std::thread::sleep(Duration::from_millis(20));
+                Pesto.evaluate(positions)
+            }
+        }
+
+        let time = Duration::from_millis(100);
+        for max_time in [None, Some(time)] {
+            let result = search(
+                &Position::starting(),
+                &Limits {
+                    time: Some(time),
+                    max_time,
+                    ..Limits::default()
+                },
+                &Config::default(),
+                &Slow,
+                &AtomicBool::new(false),
+            )
+            .unwrap();
+            // The last playout is skipped instead of finishing 20 ms late, up
+            // to the jitter of the sleeps.
+            assert!(
+                result.elapsed < time + Duration::from_millis(10),
+                "{:?}",
+                result.elapsed
+            );
+            assert!(result.nodes >= 3, "{}", result.nodes);
+        }
+    }
+
+    #[test]
+    fn mate_limit() {
+        let search_mate = |fen: &str, moves: u32, config: &Config| {
+            let limits = Limits {
+                mate: Some(moves),
+                ..Limits::default()
+            };
+            let position = Position::from_fen(fen).unwrap();
+            search(&position, &limits, config, &Pesto, &AtomicBool::new(false)).unwrap()
+        };
+        let analysis = Config {
+            analysis: true,
+            ..Config::default()
+        };
+        // The mate is found: even the analysis stops.
+        let result = search_mate("k7/8/2K5/8/8/8/8/7R w - - 0 1", 2, &analysis);
+        assert_eq!(result.score, Score::Mate(2));
+        // The mate takes longer than asked for.
+        let result = search_mate("k7/8/2K5/8/8/8/8/7R w - - 0 1", 1, &Config::default());
+        assert_ne!(result.score, Score::Mate(1));
+        assert_eq!(result.nodes, 1);
+        // No mate at all: each move has to be refuted.
+        let result = search_mate("k7/8/8/8/8/8/8/K6R w - - 0 1", 2, &Config::default());
+        assert!(!matches!(result.score, Score::Mate(_)));
+        assert!(result.nodes < 1000, "{}", result.nodes);
+        // Only the mates for the side to move count.
+        let result = search_mate("k7/8/1K6/8/8/8/8/7R b - - 0 1", 1, &analysis);
+        assert_eq!(result.best_move, Some(Move::from_uci("a8b8").unwrap()));
+        assert_eq!(result.nodes, 1);
+    }
+
+    #[test]
+    fn analysis_mode() {
+        let position = Position::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1").unwrap();
+        let limits = Limits {
+            nodes: Some(500),
+            ..Limits::default()
+        };
+        let stop = AtomicBool::new(false);
+
+        let result = search(&position, &limits, &Config::default(), &Pesto, &stop).unwrap();
+        assert_eq!(result.score, Score::Mate(1));
+        assert!(result.nodes < 500);
+
+        let config = Config {
+            analysis: true,
+            ..Config::default()
+        };
+        let result = search(&position, &limits, &config, &Pesto, &stop).unwrap();
+        assert_eq!(result.best_move, Some(Move::from_uci("h1h8").unwrap()));
+        assert_eq!(result.score, Score::Mate(1));
+        assert_eq!(result.nodes, 500);
+        // The other moves are searched after the mate is found.
+        assert!(
+            result
+                .root_moves
+                .iter()
+                .filter(|root_move| root_move.visits > 1)
+                .count()
+                > 10
+        );
+    }
+
+    #[test]
+    fn searchmoves() {
+        let searchmoves = vec![
+            Move::from_uci("a2a3").unwrap(),
+            Move::from_uci("h2h4").unwrap(),
+        ];
+        let result = search(
+            &Position::starting(),
+            &Limits {
+                nodes: Some(50),
+                searchmoves: searchmoves.clone(),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .expect("search should not fail");
+        assert_eq!(result.root_moves.len(), 2);
+        assert!(searchmoves.contains(&result.best_move.unwrap()));
+
+        // Illegal moves are ignored.
+        let result = search(
+            &Position::starting(),
+            &Limits {
+                nodes: Some(10),
+                searchmoves: vec![Move::from_uci("e2e5").unwrap()],
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .expect("search should not fail");
+        assert_eq!(result.root_moves.len(), 20);
+    }
+
+    #[test]
+    fn instant_moves() {
+        let mate = Position::from_fen("k7/8/1K6/8/8/8/8/7R w - - 0 1").unwrap();
+        assert_eq!(
+            instant_move(&mate, &[]),
+            Some(Move::from_uci("h1h8").unwrap())
+        );
+        assert_eq!(
+            instant_move(&mate, &[Move::from_uci("h1h7").unwrap()]),
+            Some(Move::from_uci("h1h7").unwrap())
+        );
+        let forced = Position::from_fen("k7/1Q6/3K4/8/8/8/8/8 b - - 0 1").unwrap();
+        assert_eq!(
+            instant_move(&forced, &[]),
+            Some(Move::from_uci("a8b7").unwrap())
+        );
+        assert_eq!(instant_move(&Position::starting(), &[]), None);
+
+        let search = |shortcuts| {
+            search(
+                &mate,
+                &Limits {
+                    nodes: Some(1000),
+                    shortcuts,
+                    ..Limits::default()
+                },
+                &Config {
+                    analysis: true,
+                    ..Config::default()
+                },
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .expect("search should not fail")
+        };
+        let result = search(true);
+        assert_eq!(result.nodes, 2);
+        assert_eq!(result.score, Score::Mate(1));
+        assert_eq!(result.best_move, Some(Move::from_uci("h1h8").unwrap()));
+        // The shortcuts are disabled by default.
+        assert_eq!(search(false).nodes, 1000);
+    }
+
+    #[test]
+    fn listener() {
+        #[derive(Default)]
+        struct RootMoves(Vec<(Move, usize)>);
+
+        impl Listener for RootMoves {
+            fn root_move(&mut self, next_move: Move, number: usize) {
+                self.0.push((next_move, number));
+            }
+        }
+
+        let position = Position::starting();
+        let mut listener = RootMoves::default();
+        let result = search_with_listener(
+            &position,
+            &Limits {
+                nodes: Some(100),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+            &mut listener,
+        )
+        .unwrap();
+        // The first playout expands the root.
+        assert_eq!(listener.0.len(), 99);
+        let moves = position.generate_moves();
+        for (next_move, number) in &listener.0 {
+            assert_eq!(moves[number - 1], *next_move);
+        }
+        let visits: u32 = result.root_moves.iter().map(|m| m.visits).sum();
+        assert_eq!(visits, 99);
+    }
+
+    #[test]
+    fn snapshots() {
+        /// Takes a snapshot every 25 playouts.
+        #[derive(Default)]
+        struct Snapshots {
+            playouts: u64,
+            results: Vec<SearchResult>,
+        }
+
+        impl Listener for Snapshots {
+            fn snapshot_due(&mut self) -> bool {
+                self.playouts += 1;
+                self.playouts % 25 == 0
+            }
+
+            fn snapshot(&mut self, result: &SearchResult) {
+                self.results.push(result.clone());
+            }
+        }
+
+        let mut listener = Snapshots::default();
+        let result = search_with_listener(
+            &Position::starting(),
+            &Limits {
+                nodes: Some(100),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+            &mut listener,
+        )
+        .unwrap();
+        assert_eq!(
+            listener
+                .results
+                .iter()
+                .map(|snapshot| snapshot.nodes)
+                .collect::<Vec<_>>(),
+            [25, 50, 75, 100]
+        );
+        // The snapshot after the last playout is the final result.
+        let last = listener.results.last().unwrap();
+        assert_eq!(last.best_move, result.best_move);
+        assert_eq!(last.pv, result.pv);
+        assert_eq!(last.root_moves.len(), result.root_moves.len());
+        assert!(listener.results[0].best_move.is_some());
+    }
+
+    #[test]
+    fn seldepth() {
+        /// Records the average and maximum depth after each playout.
+        #[derive(Default)]
+        struct Depths(Vec<(u32, u32)>);
+
+        impl Listener for Depths {
+            fn playout(&mut self, _nodes: u64, depth: u32, seldepth: u32) {
+                self.0.push((depth, seldepth));
+            }
+        }
+
+        let mut listener = Depths::default();
+        let result = search_with_listener(
+            &Position::starting(),
+            &Limits {
+                nodes: Some(500),
+                ..Limits::default()
+            },
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(false),
+            &mut listener,
+        )
+        .unwrap();
+        assert_eq!(listener.0.len(), 500);
+        assert!(listener.0.iter().all(|(depth, seldepth)| seldepth >= depth));
+        assert!(listener.0.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+        assert_eq!(listener.0.last(), Some(&(result.depth, result.seldepth)));
+        assert!(result.seldepth > result.depth, "{result:?}");
+    }
+
+    #[test]
+    fn stop_flag() {
+        let result = search(
+            &Position::starting(),
+            &Limits::default(),
+            &Config::default(),
+            &Pesto,
+            &AtomicBool::new(true),
+        )
+        .expect("search should not fail");
+        assert_eq!(result.nodes, 1);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn tree_memory() {
+        let config = Config {
+            tree_memory: 100 * mem::size_of::<Node>(),
+            ..Config::default()
+        };
+        let limits = Limits {
+            nodes: Some(1000),
+            ..Limits::default()
+        };
+        let mut root = Node::new(None, 1.0);
+        let result = search_tree(
+            Instant::now(),
+            &Position::starting(),
+            &mut root,
+            SearchContext {
+                limits: &limits,
+                config: &config,
+                evaluator: &Pesto,
+                stop: &AtomicBool::new(false),
+                listener: &mut (),
+            },
+        )
+        .unwrap();
+        // The full tree stops growing, but the search goes on. The last
+        // expansion might go over the limit.
+        assert_eq!(result.nodes, 1000);
+        assert!(root.size() <= 100 + MoveList::new().capacity());
+        assert_eq!(root.visits, 1000);
+
+        // The root is always expanded.
+        let config = Config {
+            tree_memory: 0,
+            ..Config::default()
+        };
+        let mut root = Node::new(None, 1.0);
+        let result = search_tree(
+            Instant::now(),
+            &Position::starting(),
+            &mut root,
+            SearchContext {
+                limits: &limits,
+                config: &config,
+                evaluator: &Pesto,
+                stop: &AtomicBool::new(false),
+                listener: &mut (),
+            },
+        )
+        .unwrap();
+        assert_eq!(result.nodes, 1000);
+        assert_eq!(root.size(), 21);
+        assert!(result.best_move.is_some());
+    }
+
+    #[test]
+    fn progressive_widening() {
+        let widening = Config {
+            widening_base: 2.0,
+            widening_exponent: 0.3,
+            ..Config::default()
+        };
+        let limits = Limits {
+            nodes: Some(200),
+            ..Limits::default()
+        };
+        let opened = |config: &Config| {
+            search(
+                &Position::starting(),
+                &limits,
+                config,
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .unwrap()
+            .root_moves
+            .iter()
+            .filter(|root_move| root_move.visits > 0)
+            .count()
+        };
+        assert_eq!(opened(&Config::default()), 20);
+        let widened = opened(&widening);
+        assert!(widened <= widening.widening_limit(200), "{widened}");
+        assert!(widened < 20, "{widened}");
+
+        // Scholar's mate is still found among 42 legal moves once the search
+        // widens enough: the priors of Pesto are uniform, so the moves are
+        // opened in the order of generation.
+        let result = search(
+            &Position::from_fen(
+                "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5Q2/PPPP1PPP/RNB1K1NR w KQkq - 2 3",
+            )
+            .unwrap(),
+            &Limits {
+                nodes: Some(2000),
+                ..Limits::default()
+            },
+            &widening,
+            &Pesto,
+            &AtomicBool::new(false),
+        )
+        .unwrap();
+        assert_eq!(result.best_move, Move::from_uci("f3f7").ok());
+        assert_eq!(result.score, Score::Mate(1));
+    }
+
+    #[test]
+    fn check_prior_boost() {
+        // Mates starting with a check: Scholar's mate, smothered mate, Legal's
+        // mate and a queen sacrifice.
+        let suite = [
+            (
+                "r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+                1,
+            ),
+            ("6rk/6pp/8/6N1/8/8/8/6QK w - - 0 1", 1),
+            (
+                "r2qkb1r/pp2nppp/3p4/2pNN1B1/2BnP3/3P4/PPP2PPP/R2bK2R w KQkq - 1 1",
+                2,
+            ),
+            ("2r3k1/p4p2/3Rp2p/1p2P1pK/8/1P4P1/P3Q2P/1q6 b - - 0 1", 3),
+        ];
+        let nodes_to_mate = |check_prior_boost| {
+            let config = Config {
+                check_prior_boost,
+                ..Config::default()
+            };
+            suite
+                .iter()
+                .map(|&(fen, mate)| {
+                    let result = search(
+                        &Position::from_fen(fen).unwrap(),
+                        &Limits {
+                            nodes: Some(20_000),
+                            ..Limits::default()
+                        },
+                        &config,
+                        &Pesto,
+                        &AtomicBool::new(false),
+                    )
+                    .unwrap();
+                    assert_eq!(result.score, Score::Mate(mate), "{fen}");
+                    result.nodes
+                })
+                .sum::<u64>()
+        };
+        let baseline = nodes_to_mate(1.0);
+        let boosted = nodes_to_mate(4.0);
+        assert!(boosted * 4 < baseline, "{boosted} vs {baseline}");
+    }
+
+    #[test]
+    fn root_moves_ordered_by_prior() {
+        let position = Position::from_fen("6rk/6pp/8/6N1/8/8/8/6QK w - - 0 1").unwrap();
+        let limits = Limits {
+            nodes: Some(50),
+            ..Limits::default()
+        };
+        let run = |check_prior_boost| {
+            search(
+                &position,
+                &limits,
+                &Config {
+                    check_prior_boost,
+                    ..Config::default()
+                },
+                &Pesto,
+                &AtomicBool::new(false),
+            )
+            .unwrap()
+            .root_moves
+        };
+        // Without a policy, the moves are in the order of move generation.
+        assert_eq!(
+            run(1.0)
+                .iter()
+                .map(|root_move| root_move.next_move)
+                .collect::<Vec<_>>(),
+            position.generate_moves().to_vec()
+        );
+        // The boosted checks go first.
+        let root_moves = run(4.0);
+        assert!(root_moves
+            .windows(2)
+            .all(|pair| pair[0].prior >= pair[1].prior));
+        let checks = root_moves
+            .iter()
+            .take_while(|root_move| position.gives_check(&root_move.next_move))
+            .count();
+        assert!(checks > 0);
+        assert!(root_moves[checks..]
+            .iter()
+            .all(|root_move| !position.gives_check(&root_move.next_move)));
+    }
 }
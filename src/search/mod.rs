@@ -2,6 +2,491 @@
 //!
 //! [Monte Carlo Tree Search]: https://en.wikipedia.org/wiki/Monte_Carlo_tree_search
 
+use std::fmt::{self, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::chess::core::Move;
+use crate::chess::zobrist;
+use crate::evaluation;
+
 pub mod mcts;
 mod policy;
+pub mod session;
 mod tree;
+
+/// Conditions for stopping the search. The search is stopped as soon as any of
+/// the limits is reached or the result at the root is proven (e.g. a forced
+/// mate is found). If no limits are set, the search runs until it is stopped
+/// externally.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// Maximum amount of time to spend on the search.
+    pub time: Option<Duration>,
+    /// The search can go on after [`Limits::time`] until this deadline while
+    /// the best move is not settled, see [`Stability::is_unstable`].
+    ///
+    /// The later of the two is a hard deadline: the search does not start a
+    /// playout that is expected to finish after it.
+    pub max_time: Option<Duration>,
+    /// Maximum number of nodes (playouts) to search.
+    pub nodes: Option<u64>,
+    /// Stop when the average depth of the playouts reaches this value.
+    pub depth: Option<u32>,
+    /// Stop once a mate in at most this many moves is proven for the side to
+    /// move or the tree shows that there is none (`go mate`).
+    pub mate: Option<u32>,
+    /// Only search these moves at the root (e.g. the ones preserving the
+    /// tablebase result). Empty means all legal moves.
+    pub searchmoves: Vec<Move>,
+    /// Hashes of the positions played before the root since the last
+    /// irreversible move, oldest first. Reaching a position that occurred
+    /// twice in the game is a draw by threefold repetition.
+    pub history: Vec<zobrist::Key>,
+    /// Play the only legal move or a mate in one without searching the other
+    /// moves (see [`mcts::instant_move`]). This saves the clock time in games,
+    /// but the node counts and the reported scores of such searches are not
+    /// comparable to the regular ones, so benchmarks leave it disabled.
+    pub shortcuts: bool,
+}
+
+/// Receives notifications about the search progress, e.g. to report it to the
+/// GUI. All methods are called from the search thread and should be cheap.
+pub trait Listener {
+    /// Called before each playout that goes through the root move. `number`
+    /// is the 1-based index of the move among the root moves.
+    fn root_move(&mut self, _next_move: Move, _number: usize) {}
+
+    /// Called after each playout with the number of playouts so far, their
+    /// average depth and the depth of the longest one.
+    fn playout(&mut self, _nodes: u64, _depth: u32, _seldepth: u32) {}
+
+    /// Returns true if the listener wants the [`Listener::snapshot`] of the
+    /// search after the current playout. Building the intermediate result
+    /// walks the whole principal variations, so the snapshots should be rate
+    /// limited.
+    fn snapshot_due(&mut self) -> bool {
+        false
+    }
+
+    /// Receives the intermediate result of the search, as if it was stopped
+    /// after the current playout.
+    fn snapshot(&mut self, _result: &SearchResult) {}
+}
+
+/// Listener that ignores all notifications.
+impl Listener for () {}
+
+/// Number of playouts shared by all threads searching the same position, used
+/// for reporting the progress. The counter saturates instead of overflowing in
+/// (very) long analysis sessions.
+#[derive(Debug, Default)]
+pub struct NodeCounter(AtomicU64);
+
+impl NodeCounter {
+    pub fn add(&self, nodes: u64) {
+        let _ = self
+            .0
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |total| {
+                Some(total.saturating_add(nodes))
+            });
+    }
+
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Nodes per second for `nodes` searched in `elapsed` time, saturating at
+/// [`u64::MAX`].
+#[must_use]
+pub fn nps(nodes: u64, elapsed: Duration) -> u64 {
+    let nanos = elapsed.as_nanos().max(1);
+    u64::try_from(u128::from(nodes) * 1_000_000_000 / nanos).unwrap_or(u64::MAX)
+}
+
+/// Evaluation of the root position from the perspective of the player to move
+/// in the format UCI expects.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Score {
+    /// Approximate advantage in centipawns.
+    Centipawns(i32),
+    /// Forced mate in given number of **moves** (not plies): positive if the
+    /// player to move is delivering the mate and negative if the player to
+    /// move is getting mated.
+    Mate(i32),
+}
+
+impl Score {
+    /// Converts the score to the expected outcome in `[-1, 1]` range, see
+    /// [`evaluation::Prediction::value`].
+    #[must_use]
+    pub fn value(self) -> f32 {
+        match self {
+            Self::Centipawns(cp) => evaluation::centipawns_to_value(cp),
+            Self::Mate(moves) if moves > 0 => 1.0,
+            Self::Mate(_) => -1.0,
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    /// Formats the score as UCI `info score` argument.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Centipawns(cp) => write!(f, "cp {cp}"),
+            Self::Mate(moves) => write!(f, "mate {moves}"),
+        }
+    }
+}
+
+/// Probabilities of winning, drawing and losing the game from the perspective
+/// of the player to move.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Wdl {
+    pub win: f32,
+    pub draw: f32,
+    pub loss: f32,
+}
+
+impl Wdl {
+    /// Splits the expected outcome `value` (see
+    /// [`evaluation::Prediction::value`]) into the probabilities given the
+    /// probability of a draw. The draw probability is clamped to the range
+    /// allowed by the value.
+    #[must_use]
+    pub fn new(value: f32, draw: f32) -> Self {
+        let value = value.clamp(-1.0, 1.0);
+        let draw = draw.clamp(0.0, 1.0 - value.abs());
+        Self {
+            win: (1.0 + value - draw) / 2.0,
+            draw,
+            loss: (1.0 - value - draw) / 2.0,
+        }
+    }
+}
+
+impl Wdl {
+    /// Returns the wins, draws and losses per mille, adding up to 1000.
+    #[must_use]
+    pub fn per_mille(self) -> [i32; 3] {
+        let per_mille = |probability: f32| (probability.clamp(0.0, 1.0) * 1000.0).round() as i32;
+        let (win, loss) = (per_mille(self.win), per_mille(self.loss));
+        [win, (1000 - win - loss).max(0), loss]
+    }
+}
+
+impl fmt::Display for Wdl {
+    /// Formats the probabilities as UCI `info wdl` argument.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [win, draw, loss] = self.per_mille();
+        write!(f, "wdl {win} {draw} {loss}")
+    }
+}
+
+/// Search statistics of a single legal move at the root.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RootMove {
+    pub next_move: Move,
+    pub score: Score,
+    /// Principal variation starting with [`RootMove::next_move`].
+    pub pv: Vec<Move>,
+    /// Number of playouts that went through this move.
+    pub visits: u32,
+    /// Average value of the move in `[-1, 1]` range from the perspective of
+    /// the player to move.
+    pub q: f32,
+    /// Prior probability of the move predicted by the policy.
+    pub prior: f32,
+    /// Expected outcome of the move from the perspective of the player to
+    /// move.
+    pub wdl: Wdl,
+}
+
+/// How settled the search is on the best move, sampled periodically during the
+/// search. The alpha-beta engines spend more time on the "hard" moves where
+/// the best move changes between iterations and less on the "easy" ones; this
+/// is the MCTS equivalent.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stability {
+    /// Number of times the best move changed between the samples.
+    pub best_move_changes: u32,
+    /// Number of playouts when the best move changed for the last time.
+    pub last_change: u64,
+    /// Variance of the best move value over the recent samples.
+    pub q_variance: f32,
+    /// Fraction of the root visits that went to the best move.
+    pub visit_concentration: f32,
+}
+
+impl Stability {
+    /// The best move value fluctuates by more than ~20 centipawns.
+    const MAX_Q_VARIANCE: f32 = 0.05 * 0.05;
+    /// The best move gets less than a third of the visits.
+    const MIN_VISIT_CONCENTRATION: f32 = 1.0 / 3.0;
+    /// The best move changed during the last tenth of the search.
+    const RECENT_CHANGE: u64 = 10;
+
+    /// Returns true if spending more time on the move is likely to change the
+    /// decision.
+    #[must_use]
+    pub fn is_unstable(&self, nodes: u64) -> bool {
+        (self.best_move_changes > 0
+            && self.last_change * Self::RECENT_CHANGE > nodes * (Self::RECENT_CHANGE - 1))
+            || self.visit_concentration < Self::MIN_VISIT_CONCENTRATION
+            || self.q_variance > Self::MAX_Q_VARIANCE
+    }
+}
+
+/// Summary of the finished search.
+#[derive(Clone, Debug)]
+pub struct SearchResult {
+    /// The move to play. [`None`] if the root position is terminal.
+    pub best_move: Option<Move>,
+    pub score: Score,
+    /// Average value of the best move, see [`RootMove::q`].
+    pub q: f32,
+    /// Expected outcome of the best move, see [`RootMove::wdl`].
+    pub wdl: Wdl,
+    /// Principal variation, starting with [`SearchResult::best_move`].
+    pub pv: Vec<Move>,
+    /// Number of playouts performed.
+    pub nodes: u64,
+    /// Average depth of the playouts.
+    pub depth: u32,
+    /// Depth of the longest playout, i.e. the deepest path explored.
+    pub seldepth: u32,
+    pub elapsed: Duration,
+    /// Statistics of all legal moves, the ones with the highest priors first.
+    /// Empty if the root position is terminal.
+    pub root_moves: Vec<RootMove>,
+    pub stability: Stability,
+}
+
+impl SearchResult {
+    /// Formats the result as a single line of JSON for the tools that tune the
+    /// search parameters and would otherwise have to parse `info` lines.
+    ///
+    /// ```json
+    /// {"best_move":"e2e4","score":{"cp":25},"q":0.0624,"wdl":[300,462,238],
+    ///  "nodes":800,"depth":5,"seldepth":12,"time_ms":120,"pv":["e2e4","e7e5"],
+    ///  "root":[{"move":"e2e4","visits":400,"q":0.0624,"prior":0.05,
+    ///  "wdl":[300,462,238]},...],
+    ///  "stability":{"best_move_changes":2,"q_variance":0.0001,
+    ///  "visit_concentration":0.5}}
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("{");
+        match self.best_move {
+            Some(best_move) => write!(json, "\"best_move\":\"{best_move}\""),
+            None => write!(json, "\"best_move\":null"),
+        }
+        .expect("writing to a string does not fail");
+        let score = match self.score {
+            Score::Centipawns(cp) => format!("{{\"cp\":{cp}}}"),
+            Score::Mate(moves) => format!("{{\"mate\":{moves}}}"),
+        };
+        let pv = self
+            .pv
+            .iter()
+            .map(|next_move| format!("\"{next_move}\""))
+            .collect::<Vec<_>>()
+            .join(",");
+        let root = self
+            .root_moves
+            .iter()
+            .map(|root_move| {
+                format!(
+                    "{{\"move\":\"{}\",\"visits\":{},\"q\":{:.4},\"prior\":{:.4},\"wdl\":{}}}",
+                    root_move.next_move,
+                    root_move.visits,
+                    root_move.q,
+                    root_move.prior,
+                    wdl_json(root_move.wdl)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        write!(
+            json,
+            ",\"score\":{score},\"q\":{:.4},\"wdl\":{},\"nodes\":{},\"depth\":{},\"seldepth\":{},\"time_ms\":{},\"pv\":[{pv}],\"root\":[{root}],\"stability\":{{\"best_move_changes\":{},\"q_variance\":{:.4},\"visit_concentration\":{:.4}}}}}",
+            self.q,
+            wdl_json(self.wdl),
+            self.nodes,
+            self.depth,
+            self.seldepth,
+            self.elapsed.as_millis(),
+            self.stability.best_move_changes,
+            self.stability.q_variance,
+            self.stability.visit_concentration,
+        )
+        .expect("writing to a string does not fail");
+        json
+    }
+}
+
+fn wdl_json(wdl: Wdl) -> String {
+    let [win, draw, loss] = wdl.per_mille();
+    format!("[{win},{draw},{loss}]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn node_counter() {
+        let counter = NodeCounter::default();
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                let _ = scope.spawn(|| {
+                    for _ in 0..1000 {
+                        counter.add(1);
+                    }
+                });
+            }
+        });
+        assert_eq!(counter.get(), 4000);
+        counter.add(u64::MAX);
+        assert_eq!(counter.get(), u64::MAX);
+    }
+
+    #[test]
+    fn nodes_per_second() {
+        assert_eq!(nps(1500, Duration::from_millis(500)), 3000);
+        assert_eq!(nps(10, Duration::ZERO), 10_000_000_000);
+        assert_eq!(nps(u64::MAX, Duration::from_nanos(1)), u64::MAX);
+        assert_eq!(nps(u64::MAX, Duration::from_secs(u64::MAX)), 1);
+    }
+
+    #[test]
+    fn wdl() {
+        let wdl = Wdl::new(0.2, 0.4);
+        assert!((wdl.win - 0.4).abs() < 1e-6);
+        assert!((wdl.loss - 0.2).abs() < 1e-6);
+        assert_eq!(wdl.to_string(), "wdl 400 400 200");
+        // The draw probability is limited by the value.
+        assert_eq!(Wdl::new(0.9, 0.5).per_mille(), [900, 100, 0]);
+        assert_eq!(Wdl::new(-1.0, 1.0).to_string(), "wdl 0 0 1000");
+        assert_eq!(Wdl::new(0.0, 1.0).to_string(), "wdl 0 1000 0");
+        // Rounding never breaks the sum.
+        let [win, draw, loss] = Wdl::new(1.0 / 3.0, 1.0 / 3.0).per_mille();
+        assert_eq!(win + draw + loss, 1000);
+    }
+
+    #[test]
+    fn score_format() {
+        assert_eq!(Score::Centipawns(42).to_string(), "cp 42");
+        assert_eq!(Score::Centipawns(-7).to_string(), "cp -7");
+        assert_eq!(Score::Mate(3).to_string(), "mate 3");
+        assert_eq!(Score::Mate(-1).to_string(), "mate -1");
+    }
+
+    #[test]
+    fn json() {
+        let e2e4 = Move::from_uci("e2e4").unwrap();
+        let d2d4 = Move::from_uci("d2d4").unwrap();
+        let result = SearchResult {
+            best_move: Some(e2e4),
+            score: Score::Centipawns(25),
+            q: 0.0625,
+            wdl: Wdl::new(0.0625, 0.5),
+            pv: vec![e2e4, Move::from_uci("e7e5").unwrap()],
+            nodes: 3,
+            depth: 2,
+            seldepth: 3,
+            elapsed: Duration::from_millis(12),
+            root_moves: vec![
+                RootMove {
+                    next_move: e2e4,
+                    score: Score::Centipawns(25),
+                    pv: vec![e2e4],
+                    visits: 2,
+                    q: 0.0625,
+                    prior: 0.5,
+                    wdl: Wdl::new(0.0625, 0.5),
+                },
+                RootMove {
+                    next_move: d2d4,
+                    score: Score::Centipawns(-100),
+                    pv: vec![d2d4],
+                    visits: 1,
+                    q: -0.25,
+                    prior: 0.5,
+                    wdl: Wdl::new(-0.25, 0.75),
+                },
+            ],
+            stability: Stability {
+                best_move_changes: 1,
+                last_change: 2,
+                q_variance: 0.0,
+                visit_concentration: 2.0 / 3.0,
+            },
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"best_move":"e2e4","score":{"cp":25},"q":0.0625,"wdl":[281,500,219],"nodes":3,"depth":2,"seldepth":3,"time_ms":12,"pv":["e2e4","e7e5"],"root":[{"move":"e2e4","visits":2,"q":0.0625,"prior":0.5000,"wdl":[281,500,219]},{"move":"d2d4","visits":1,"q":-0.2500,"prior":0.5000,"wdl":[0,750,250]}],"stability":{"best_move_changes":1,"q_variance":0.0000,"visit_concentration":0.6667}}"#
+        );
+
+        let terminal = SearchResult {
+            best_move: None,
+            score: Score::Mate(0),
+            q: -1.0,
+            wdl: Wdl::new(-1.0, 0.0),
+            pv: Vec::new(),
+            nodes: 1,
+            depth: 0,
+            seldepth: 0,
+            elapsed: Duration::ZERO,
+            root_moves: Vec::new(),
+            stability: Stability::default(),
+        };
+        assert_eq!(
+            terminal.to_json(),
+            r#"{"best_move":null,"score":{"mate":0},"q":-1.0000,"wdl":[0,0,1000],"nodes":1,"depth":0,"seldepth":0,"time_ms":0,"pv":[],"root":[],"stability":{"best_move_changes":0,"q_variance":0.0000,"visit_concentration":0.0000}}"#
+        );
+    }
+
+    #[test]
+    fn stability() {
+        let settled = Stability {
+            best_move_changes: 3,
+            last_change: 100,
+            q_variance: 0.0001,
+            visit_concentration: 0.8,
+        };
+        assert!(!settled.is_unstable(1000));
+        assert!(!Stability {
+            best_move_changes: 0,
+            last_change: 0,
+            ..settled
+        }
+        .is_unstable(1000));
+        assert!(Stability {
+            last_change: 950,
+            ..settled
+        }
+        .is_unstable(1000));
+        assert!(Stability {
+            q_variance: 0.01,
+            ..settled
+        }
+        .is_unstable(1000));
+        assert!(Stability {
+            visit_concentration: 0.2,
+            ..settled
+        }
+        .is_unstable(1000));
+    }
+
+    #[test]
+    fn score_value() {
+        assert_eq!(Score::Centipawns(0).value(), 0.0);
+        assert!(Score::Centipawns(300).value() > 0.5);
+        assert_eq!(Score::Mate(2).value(), 1.0);
+        assert_eq!(Score::Mate(0).value(), -1.0);
+        assert_eq!(Score::Mate(-3).value(), -1.0);
+    }
+}
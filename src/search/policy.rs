@@ -1,6 +1,268 @@
-use super::tree;
-use crate::environment::Action;
+use super::mcts::Config;
+use super::tree::{Node, Proof};
+use crate::chess::core::Move;
+use crate::chess::position::Position;
 
-fn select<A: Action>(node: &tree::Node<A>) {
-    todo!()
+/// Selects the child to descend into using the PUCT formula from AlphaZero.
+/// Proven wins are always selected and proven losses are avoided unless there
+/// are no other options.
+#[must_use]
+pub(super) fn select(node: &Node, config: &Config) -> usize {
+    debug_assert!(!node.is_leaf());
+    if let Some(index) = node
+        .children
+        .iter()
+        .position(|child| matches!(child.proof, Some(Proof::Win(_))))
+    {
+        return index;
+    }
+    select_unproven(node, config).unwrap_or(0)
+}
+
+/// Same as [`select`] for the root, but with the root move pruning (see
+/// [`Config::root_pruning`]): the moves with enough visits and the value more
+/// than `margin` below the best of them are skipped.
+#[must_use]
+pub(super) fn select_root(root: &Node, config: &Config, margin: Option<f32>) -> usize {
+    let Some(margin) = margin else {
+        return select(root, config);
+    };
+    if root
+        .children
+        .iter()
+        .any(|child| matches!(child.proof, Some(Proof::Win(_))))
+    {
+        return select(root, config);
+    }
+    let best = root
+        .children
+        .iter()
+        .filter(|child| child.visits >= config.root_pruning_visits)
+        .map(Node::q)
+        .fold(f32::NEG_INFINITY, f32::max);
+    select_above(root, config, best - margin).unwrap_or_else(|| select(root, config))
+}
+
+/// Selects the best child by the PUCT formula among the ones that are not
+/// proven wins or losses. Proven draws are selected as regular children: their
+/// value is exact, but the other moves still need to be compared with it.
+/// Returns [`None`] if all children are proven wins or losses.
+#[must_use]
+pub(super) fn select_unproven(node: &Node, config: &Config) -> Option<usize> {
+    select_above(node, config, f32::NEG_INFINITY)
+}
+
+/// Same as [`select_unproven`], but skips the children with at least
+/// [`Config::root_pruning_visits`] visits and the value below `threshold`.
+fn select_above(node: &Node, config: &Config, threshold: f32) -> Option<usize> {
+    // Progressive widening: the unvisited children are not considered once
+    // enough children are opened. The unvisited child with the highest prior
+    // always has the best score among them, so the children are opened in the
+    // order of the priors.
+    let limit = config.widening_limit(node.visits);
+    if limit >= node.children.len()
+        || node.children.iter().filter(|child| child.visited()).count() < limit
+    {
+        return select_among(node, config, true, threshold);
+    }
+    // All opened children can be proven wins or losses.
+    select_among(node, config, false, threshold)
+        .or_else(|| select_among(node, config, true, threshold))
+}
+
+fn select_among(node: &Node, config: &Config, unvisited: bool, threshold: f32) -> Option<usize> {
+    let exploration = config.cpuct * (node.visits as f32).sqrt();
+    // First Play Urgency: unvisited children are assumed to be as good as the
+    // parent from the perspective of the player to move, minus the reduction.
+    let first_play_urgency = -node.q() - config.fpu_reduction;
+
+    let mut best = None;
+    let mut best_score = f32::NEG_INFINITY;
+    for (index, child) in node.children.iter().enumerate() {
+        if matches!(child.proof, Some(Proof::Win(_) | Proof::Loss(_)))
+            || (!unvisited && !child.visited())
+            || (child.visits >= config.root_pruning_visits && child.q() < threshold)
+        {
+            continue;
+        }
+        let q = if child.visited() {
+            child.q()
+        } else {
+            first_play_urgency
+        };
+        let score = q + exploration * child.prior / (1 + child.visits) as f32;
+        if best.is_none() || score > best_score {
+            best_score = score;
+            best = Some(index);
+        }
+    }
+    best
+}
+
+/// Rescales the prior probabilities with the softmax temperature:
+/// $p_i^{1/T} / \sum_j p_j^{1/T}$.
+#[must_use]
+pub(super) fn apply_temperature(mut priors: Vec<f32>, temperature: f32) -> Vec<f32> {
+    if temperature == 1.0 {
+        return priors;
+    }
+    for prior in &mut priors {
+        *prior = prior.powf(temperature.recip());
+    }
+    let sum: f32 = priors.iter().sum();
+    if sum > 0.0 {
+        for prior in &mut priors {
+            *prior /= sum;
+        }
+    }
+    priors
+}
+
+/// Multiplies the priors of the checking moves by `boost` and renormalizes
+/// them, so that the forcing moves are searched earlier. Without a trained
+/// policy, MCTS spreads the visits evenly and needs many playouts to find
+/// the tactics built on checks.
+#[must_use]
+pub(super) fn boost_checks(
+    mut priors: Vec<f32>,
+    position: &Position,
+    moves: &[Move],
+    boost: f32,
+) -> Vec<f32> {
+    debug_assert_eq!(priors.len(), moves.len());
+    if boost == 1.0 {
+        return priors;
+    }
+    for (prior, next_move) in priors.iter_mut().zip(moves) {
+        if position.gives_check(next_move) {
+            *prior *= boost;
+        }
+    }
+    let sum: f32 = priors.iter().sum();
+    if sum > 0.0 {
+        for prior in &mut priors {
+            *prior /= sum;
+        }
+    }
+    priors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::core::Square;
+
+    #[test]
+    fn temperature() {
+        let priors = vec![0.5, 0.3, 0.2];
+        assert_eq!(apply_temperature(priors.clone(), 1.0), priors);
+
+        let sharp = apply_temperature(priors.clone(), 0.5);
+        let flat = apply_temperature(priors, 2.0);
+        assert!(sharp[0] > 0.5 && flat[0] < 0.5);
+        assert!((sharp.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!((flat.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!(flat.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+
+    #[test]
+    fn check_boost() {
+        // Ra8+ is the only check.
+        let position = Position::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        let moves = position.generate_moves();
+        let uniform = vec![1.0 / moves.len() as f32; moves.len()];
+        assert_eq!(
+            boost_checks(uniform.clone(), &position, &moves, 1.0),
+            uniform
+        );
+
+        let boosted = boost_checks(uniform.clone(), &position, &moves, 4.0);
+        assert!((boosted.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        for (next_move, (prior, original)) in moves.iter().zip(boosted.iter().zip(&uniform)) {
+            let is_check = next_move.to_string() == "a1a8";
+            assert_eq!(position.gives_check(next_move), is_check, "{next_move}");
+            assert_eq!(prior > original, is_check, "{next_move}");
+        }
+    }
+
+    #[test]
+    fn progressive_widening() {
+        let config = Config {
+            widening_base: 1.0,
+            widening_exponent: 0.5,
+            ..Config::default()
+        };
+        assert_eq!(config.widening_limit(0), 1);
+        assert_eq!(config.widening_limit(3), 2);
+        assert_eq!(config.widening_limit(99), 10);
+        assert_eq!(Config::default().widening_limit(1000), usize::MAX);
+
+        let mut node = Node::new(None, 1.0);
+        node.children = [0.1, 0.4, 0.2, 0.3]
+            .into_iter()
+            .map(|prior| Node::new(Some(Move::new(Square::E2, Square::E4, None)), prior))
+            .collect();
+        node.visits = 1;
+        assert_eq!(select_unproven(&node, &config), Some(1));
+        node.children[1].visits = 1;
+        node.children[1].total_value = -1.0;
+        // The second child is opened even though the first one is bad.
+        node.visits = 2;
+        assert_eq!(select_unproven(&node, &config), Some(3));
+        node.children[3].visits = 1;
+        node.children[3].total_value = -1.0;
+        // Two children are enough for 3 visits.
+        node.visits = 3;
+        assert!([1, 3].contains(&select_unproven(&node, &config).unwrap()));
+        // Unless they are proven.
+        node.children[1].proof = Some(Proof::Loss(2));
+        node.children[3].proof = Some(Proof::Loss(2));
+        assert_eq!(select_unproven(&node, &config), Some(2));
+    }
+
+    #[test]
+    fn root_pruning() {
+        let config = Config {
+            root_pruning: 50,
+            root_pruning_visits: 10,
+            ..Config::default()
+        };
+        let starting = Position::starting();
+        assert_eq!(Config::default().root_pruning_margin(&starting), None);
+        let analysis = Config {
+            analysis: true,
+            ..config.clone()
+        };
+        assert_eq!(analysis.root_pruning_margin(&starting), None);
+        assert_eq!(
+            config.root_pruning_margin(&starting),
+            Some(crate::evaluation::centipawns_to_value(50))
+        );
+        // The margin doubles without pieces.
+        let pawns = Position::from_fen("4k3/p7/8/8/8/8/P7/4K3 w - - 0 1").unwrap();
+        assert_eq!(
+            config.root_pruning_margin(&pawns),
+            Some(crate::evaluation::centipawns_to_value(100))
+        );
+
+        let mut root = Node::new(None, 1.0);
+        root.children = [0.05, 0.9, 0.05]
+            .into_iter()
+            .map(|prior| Node::new(Some(Move::new(Square::E2, Square::E4, None)), prior))
+            .collect();
+        root.visits = 22;
+        root.children[0].visits = 10;
+        root.children[0].total_value = 2.0;
+        root.children[1].visits = 10;
+        root.children[1].total_value = -3.0;
+        root.children[2].visits = 2;
+        root.children[2].total_value = -1.0;
+        // The high prior keeps the bad move selected without the pruning.
+        assert_eq!(select_root(&root, &config, None), 1);
+        assert_eq!(select_root(&root, &config, Some(0.6)), 1);
+        assert_eq!(select_root(&root, &config, Some(0.4)), 0);
+        // Not enough visits to judge the move.
+        root.children[1].visits = 9;
+        assert_eq!(select_root(&root, &config, Some(0.4)), 1);
+    }
 }
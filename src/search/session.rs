@@ -0,0 +1,532 @@
+//! Keeps the search state between the searches, so that analyzing the
+//! positions of the same game (e.g. when the GUI steps through the moves or the
+//! engine plays the next move) does not start from scratch.
+//!
+//! Two caches are kept:
+//!
+//! - The search tree of the last search. If the next position is the same or
+//!   occurs in the tree within [`MAX_REUSE_PLIES`] plies of the old root, the
+//!   corresponding subtree becomes the new root and the search continues from
+//!   it.
+//! - The evaluator predictions of the recently evaluated positions, which helps
+//!   when the GUI jumps back and forth in the game.
+//!
+//! The caches are invalidated by the following rules:
+//!
+//! - Both are dropped when the evaluator changes ([`Session::analyze`] compares
+//!   the [`Arc`]s) and on [`Session::clear`] (e.g. `ucinewgame`).
+//! - The tree is dropped when the settings that shape the priors
+//!   ([`mcts::Config::policy_temperature`] and
+//!   [`mcts::Config::check_prior_boost`]) change, when the new position is not
+//!   in the tree and when the reused subtree does not fit into
+//!   [`mcts::Config::tree_memory`].
+//! - The root with restricted moves ([`Limits::searchmoves`]) is only reused by
+//!   the subtrees: the next search of the same position needs all moves.
+//! - The draws proven in the reused subtree are proven again: the repetitions
+//!   depend on the path to the root.
+//!
+//! The evaluation cache holds at most [`EVAL_CACHE_ENTRIES`] predictions.
+
+use std::mem;
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Instant;
+
+use super::tree::Node;
+use super::{mcts, Limits, Listener, SearchResult};
+use crate::chess::position::Position;
+use crate::chess::zobrist;
+use crate::environment::Player;
+use crate::evaluation::{Accumulators, Evaluator, Prediction};
+
+/// The tree is reused if the new root is at most this many plies below the old
+/// one: the engine's own move and the opponent's reply.
+pub const MAX_REUSE_PLIES: usize = 2;
+
+/// Capacity of the evaluation cache. The predictions take a few hundred bytes
+/// each (most of it is the policy), so the cache takes a few MB at most.
+pub const EVAL_CACHE_ENTRIES: usize = 1 << 14;
+
+/// Search state kept between the calls to [`Session::analyze`].
+pub struct Session {
+    evaluator: Arc<dyn Evaluator>,
+    /// Root position and the tree of the last search.
+    tree: Option<(Position, Node)>,
+    /// Settings the priors of the [`Session::tree`] were computed with.
+    priors: (f32, f32),
+    cache: Mutex<EvalCache>,
+}
+
+impl Session {
+    /// Creates an empty session that will search with the given evaluator.
+    #[must_use]
+    pub fn new(evaluator: Arc<dyn Evaluator>) -> Self {
+        Self {
+            evaluator,
+            tree: None,
+            priors: (0.0, 0.0),
+            cache: Mutex::new(EvalCache::new(EVAL_CACHE_ENTRIES)),
+        }
+    }
+
+    /// Drops the kept tree and the cached evaluations.
+    pub fn clear(&mut self) {
+        self.tree = None;
+        self.cache_mut().clear();
+    }
+
+    /// Searches the position, reusing the tree and the evaluations from the
+    /// previous searches when they are still valid (see the [module
+    /// documentation](self)). The result is the same as the one of
+    /// [`mcts::search_with_listener`], except that the root statistics include
+    /// the visits from the previous searches.
+    ///
+    /// # Errors
+    ///
+    /// If the evaluator fails.
+    pub fn analyze(
+        &mut self,
+        position: &Position,
+        limits: &Limits,
+        config: &mcts::Config,
+        evaluator: &Arc<dyn Evaluator>,
+        stop: &AtomicBool,
+        listener: &mut dyn Listener,
+    ) -> anyhow::Result<SearchResult> {
+        // Reusing the tree drops the rest of it, which takes a while for the
+        // large ones: count it towards the time limit.
+        let start = Instant::now();
+        if !Arc::ptr_eq(&self.evaluator, evaluator) {
+            self.evaluator = Arc::clone(evaluator);
+            self.clear();
+        }
+        let priors = (config.policy_temperature, config.check_prior_boost);
+        let mut root = match self.tree.take() {
+            Some((old_position, tree)) if self.priors == priors => {
+                reuse(tree, &old_position, position, config)
+            },
+            _ => None,
+        }
+        .unwrap_or_else(|| Node::new(None, 1.0));
+        self.priors = priors;
+        let cached = Cached {
+            evaluator: &**evaluator,
+            cache: &self.cache,
+        };
+        let result = mcts::search_tree(
+            start,
+            position,
+            &mut root,
+            mcts::SearchContext {
+                limits,
+                config,
+                evaluator: &cached,
+                stop,
+                listener,
+            },
+        )?;
+        self.tree = Some((position.clone(), root));
+        Ok(result)
+    }
+
+    /// Returns the number of evaluation cache hits and lookups since the
+    /// session was created.
+    #[must_use]
+    pub fn cache_stats(&self) -> (u64, u64) {
+        let cache = self
+            .cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        (cache.hits, cache.lookups)
+    }
+
+    fn cache_mut(&mut self) -> &mut EvalCache {
+        self.cache
+            .get_mut()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Returns the subtree of the `tree` searched from `old_position` that starts
+/// at `position` or [`None`] if it can not be reused.
+fn reuse(
+    tree: Node,
+    old_position: &Position,
+    position: &Position,
+    config: &mcts::Config,
+) -> Option<Node> {
+    let path = find(&tree, old_position, position, MAX_REUSE_PLIES)?;
+    let mut root = tree;
+    for index in path {
+        root = root.children.swap_remove(index);
+    }
+    // The moves excluded from the previous search are missing.
+    if !root.is_leaf() && root.children.len() != position.generate_moves().len() {
+        return None;
+    }
+    if root.size() * mem::size_of::<Node>() > config.tree_memory {
+        return None;
+    }
+    root.last_move = None;
+    root.forget_draws();
+    Some(root)
+}
+
+/// Returns the indices of the children leading from the `node` to the
+/// `target` position, looking at most `plies` deep.
+fn find(node: &Node, position: &Position, target: &Position, plies: usize) -> Option<Vec<usize>> {
+    if key(position) == key(target) {
+        return Some(Vec::new());
+    }
+    if plies == 0 {
+        return None;
+    }
+    node.children
+        .iter()
+        .enumerate()
+        .filter(|(_, child)| !child.is_leaf())
+        .find_map(|(index, child)| {
+            let mut position = position.clone();
+            position.make_move(&child.last_move.expect("children always have moves"));
+            let mut path = find(child, &position, target, plies - 1)?;
+            path.insert(0, index);
+            Some(path)
+        })
+}
+
+/// Identifies the position: the hash does not include the side to move.
+fn key(position: &Position) -> (zobrist::Key, Player) {
+    (position.hash(), position.us())
+}
+
+/// Fixed-size table of the predictions indexed by the position hash. The
+/// collisions replace the older entries.
+struct EvalCache {
+    entries: Vec<Option<((zobrist::Key, Player), Prediction)>>,
+    /// Lookups by [`Cached`] and how many of them were found, not reset by
+    /// [`EvalCache::clear`].
+    hits: u64,
+    lookups: u64,
+}
+
+impl EvalCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: vec![None; capacity],
+            hits: 0,
+            lookups: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.fill(None);
+    }
+
+    fn index(&self, (hash, player): (zobrist::Key, Player)) -> usize {
+        let hash = hash ^ u64::from(player == Player::Black);
+        (hash % self.entries.len() as u64) as usize
+    }
+
+    fn get(&self, key: (zobrist::Key, Player)) -> Option<&Prediction> {
+        match &self.entries[self.index(key)] {
+            Some((entry_key, prediction)) if *entry_key == key => Some(prediction),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, key: (zobrist::Key, Player), prediction: Prediction) {
+        let index = self.index(key);
+        self.entries[index] = Some((key, prediction));
+    }
+}
+
+/// Evaluator that looks up the predictions in the [`EvalCache`] first.
+struct Cached<'a> {
+    evaluator: &'a dyn Evaluator,
+    cache: &'a Mutex<EvalCache>,
+}
+
+impl Cached<'_> {
+    fn cache(&self) -> MutexGuard<'_, EvalCache> {
+        // The cache is only a hint: the entries are valid even if another
+        // thread panicked while holding the lock.
+        self.cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Evaluator for Cached<'_> {
+    fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+        let mut predictions: Vec<Option<Prediction>> = {
+            let mut cache = self.cache();
+            let predictions: Vec<_> = positions
+                .iter()
+                .map(|position| cache.get(key(position)).cloned())
+                .collect();
+            cache.lookups += predictions.len() as u64;
+            cache.hits += predictions.iter().flatten().count() as u64;
+            predictions
+        };
+        let missing: Vec<Position> = positions
+            .iter()
+            .zip(&predictions)
+            .filter(|(_, prediction)| prediction.is_none())
+            .map(|(position, _)| position.clone())
+            .collect();
+        if !missing.is_empty() {
+            let mut evaluated = self.evaluator.evaluate(&missing)?.into_iter();
+            let mut cache = self.cache();
+            for (position, prediction) in positions.iter().zip(&mut predictions) {
+                if prediction.is_none() {
+                    let fresh = evaluated.next().ok_or_else(|| {
+                        anyhow::anyhow!("evaluator should return a prediction for each position")
+                    })?;
+                    cache.insert(key(position), fresh.clone());
+                    *prediction = Some(fresh);
+                }
+            }
+        }
+        Ok(predictions.into_iter().flatten().collect())
+    }
+
+    fn accumulators(&self, root: &Position) -> Accumulators {
+        self.evaluator.accumulators(root)
+    }
+
+    fn evaluate_incremental(
+        &self,
+        position: &Position,
+        accumulators: &Accumulators,
+    ) -> anyhow::Result<Prediction> {
+        let cached = {
+            let mut cache = self.cache();
+            let cached = cache.get(key(position)).cloned();
+            cache.lookups += 1;
+            cache.hits += u64::from(cached.is_some());
+            cached
+        };
+        if let Some(prediction) = cached {
+            return Ok(prediction);
+        }
+        let prediction = self
+            .evaluator
+            .evaluate_incremental(position, accumulators)?;
+        self.cache().insert(key(position), prediction.clone());
+        Ok(prediction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::chess::core::Move;
+    use crate::evaluation::Pesto;
+
+    /// Counts the positions evaluated by [`Pesto`].
+    #[derive(Default)]
+    struct Counting(AtomicUsize);
+
+    impl Evaluator for Counting {
+        fn evaluate(&self, positions: &[Position]) -> anyhow::Result<Vec<Prediction>> {
+            self.0.fetch_add(positions.len(), Ordering::Relaxed);
+            Pesto.evaluate(positions)
+        }
+    }
+
+    fn analyze(
+        session: &mut Session,
+        evaluator: &Arc<dyn Evaluator>,
+        position: &Position,
+        nodes: u64,
+    ) -> SearchResult {
+        analyze_with(
+            session,
+            evaluator,
+            position,
+            &Limits {
+                nodes: Some(nodes),
+                ..Limits::default()
+            },
+            &mcts::Config::default(),
+        )
+    }
+
+    fn analyze_with(
+        session: &mut Session,
+        evaluator: &Arc<dyn Evaluator>,
+        position: &Position,
+        limits: &Limits,
+        config: &mcts::Config,
+    ) -> SearchResult {
+        session
+            .analyze(
+                position,
+                limits,
+                config,
+                evaluator,
+                &AtomicBool::new(false),
+                &mut (),
+            )
+            .unwrap()
+    }
+
+    fn root_visits(result: &SearchResult) -> u32 {
+        result
+            .root_moves
+            .iter()
+            .map(|root_move| root_move.visits)
+            .sum()
+    }
+
+    #[test]
+    fn reuse_tree() {
+        let counting = Arc::new(Counting::default());
+        let evaluator: Arc<dyn Evaluator> = counting.clone();
+        let mut session = Session::new(Arc::clone(&evaluator));
+        let mut position = Position::starting();
+        let result = analyze(&mut session, &evaluator, &position, 500);
+        assert_eq!(result.nodes, 500);
+
+        // The same position continues the search.
+        let result = analyze(&mut session, &evaluator, &position, 100);
+        assert_eq!(result.nodes, 100);
+        assert_eq!(root_visits(&result), 599);
+
+        // The position after the best reply is in the tree.
+        let best_move = result.best_move.unwrap();
+        let reply = result.pv.get(1).copied();
+        position.make_move(&best_move);
+        let result = analyze(&mut session, &evaluator, &position, 1);
+        assert!(root_visits(&result) > 1, "{result:?}");
+        if let Some(reply) = reply {
+            position.make_move(&reply);
+            let result = analyze(&mut session, &evaluator, &position, 1);
+            assert!(root_visits(&result) > 1, "{result:?}");
+        }
+
+        // An unrelated position starts from scratch.
+        let result = analyze(
+            &mut session,
+            &evaluator,
+            &Position::from_fen("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").unwrap(),
+            10,
+        );
+        assert_eq!(root_visits(&result), 9);
+    }
+
+    #[test]
+    fn eval_cache() {
+        let counting = Arc::new(Counting::default());
+        let evaluator: Arc<dyn Evaluator> = counting.clone();
+        let mut session = Session::new(Arc::clone(&evaluator));
+        let start = Position::starting();
+        let _ = analyze(&mut session, &evaluator, &start, 200);
+        let evaluated = counting.0.load(Ordering::Relaxed);
+        assert!(evaluated > 0);
+
+        // Jumping to another position and back evaluates nothing new for the
+        // first position.
+        let mut other = start.clone();
+        other.make_move(&Move::from_uci("a2a3").unwrap());
+        other.make_move(&Move::from_uci("h7h6").unwrap());
+        other.make_move(&Move::from_uci("a3a4").unwrap());
+        let _ = analyze(&mut session, &evaluator, &other, 1);
+        let evaluated = counting.0.load(Ordering::Relaxed);
+        let (hits, lookups) = session.cache_stats();
+        assert_eq!(lookups - hits, evaluated as u64);
+        let result = analyze(&mut session, &evaluator, &start, 1);
+        assert_eq!(result.nodes, 1);
+        assert_eq!(counting.0.load(Ordering::Relaxed), evaluated);
+        assert_eq!(session.cache_stats(), (hits + 1, lookups + 1));
+
+        // Changing the evaluator drops the caches.
+        let other_evaluator: Arc<dyn Evaluator> = Arc::new(Counting::default());
+        let result = analyze(&mut session, &other_evaluator, &start, 1);
+        assert_eq!(root_visits(&result), 0);
+    }
+
+    #[test]
+    fn invalidation() {
+        let evaluator: Arc<dyn Evaluator> = Arc::new(Pesto);
+        let mut session = Session::new(Arc::clone(&evaluator));
+        let position = Position::starting();
+        let _ = analyze(&mut session, &evaluator, &position, 100);
+
+        // The priors depend on the settings.
+        let config = mcts::Config {
+            policy_temperature: 2.0,
+            ..mcts::Config::default()
+        };
+        let limits = |searchmoves| Limits {
+            nodes: Some(10),
+            searchmoves,
+            ..Limits::default()
+        };
+        let result = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(vec![]),
+            &config,
+        );
+        assert_eq!(root_visits(&result), 9);
+
+        // The restricted root is not reused for the same position.
+        let searchmoves = vec![Move::from_uci("e2e4").unwrap()];
+        let result = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(searchmoves),
+            &config,
+        );
+        assert_eq!(result.root_moves.len(), 1);
+        let result = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(vec![]),
+            &config,
+        );
+        assert_eq!(result.root_moves.len(), 20);
+        assert_eq!(root_visits(&result), 9);
+
+        // The tree does not fit into the memory limit: the search starts from
+        // scratch and only expands the root.
+        let result = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(vec![]),
+            &mcts::Config {
+                tree_memory: 1,
+                ..config.clone()
+            },
+        );
+        assert_eq!(root_visits(&result), 9);
+        assert!(result
+            .root_moves
+            .iter()
+            .all(|root_move| root_move.pv.len() == 1));
+
+        let _ = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(vec![]),
+            &config,
+        );
+        session.clear();
+        let result = analyze_with(
+            &mut session,
+            &evaluator,
+            &position,
+            &limits(vec![]),
+            &config,
+        );
+        assert_eq!(root_visits(&result), 9);
+    }
+}
@@ -1,59 +1,327 @@
-use crate::environment::Action;
+use std::ops::Neg;
 
-/// Node stores (wins, draws, losses) statistics instead of expanded "value"
-/// (or total score), which is usually wins + 0.5 * draws.
+use super::Wdl;
+use crate::chess::core::Move;
+
+/// Game-theoretic value of the node that was proven by the search (MCTS-Solver
+/// approach), from the perspective of the player who made the move leading to
+/// the node. The number of plies is counted from that move to the end of the
+/// game, so the checkmating move itself is [`Proof::Win`] in 1 ply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Proof {
+    Win(u16),
+    Loss(u16),
+    /// Stalemate, draw by the fifty-move rule or repetition, or a position
+    /// where the best both players can achieve is one of these.
+    Draw,
+}
+
+/// Each ply until the checkmate reduces the value of the proven win (and
+/// increases the value of the proven loss) so that the search prefers faster
+/// mates and longer resistance even before the exact distances are compared.
+const MATE_PLY_PENALTY: f32 = 0.001;
+
+/// Proven results are never valued less decisively than this, no matter how
+/// far the checkmate is.
+const MIN_MATE_VALUE: f32 = 0.9;
+
+impl Proof {
+    /// Returns the exact value of the proven node that is backed up instead of
+    /// the evaluation.
+    #[must_use]
+    pub(super) fn value(self) -> f32 {
+        match self {
+            Self::Win(plies) => mate_value(plies),
+            Self::Loss(plies) => -mate_value(plies),
+            Self::Draw => 0.0,
+        }
+    }
+
+    /// Returns the exact outcome of the proven node, see [`Proof::value`].
+    #[must_use]
+    pub(super) fn outcome(self) -> Outcome {
+        Outcome {
+            value: self.value(),
+            draw: if self == Self::Draw { 1.0 } else { 0.0 },
+        }
+    }
+}
+
+/// Result of a playout backed up through the tree: the value from the
+/// perspective of the player who made the move leading to the node and the
+/// probability of a draw, which is the same for both players.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Outcome {
+    pub(super) value: f32,
+    pub(super) draw: f32,
+}
+
+impl Neg for Outcome {
+    type Output = Self;
+
+    /// Converts the outcome to the perspective of the other player.
+    fn neg(self) -> Self {
+        Self {
+            value: -self.value,
+            draw: self.draw,
+        }
+    }
+}
+
+fn mate_value(plies: u16) -> f32 {
+    (1.0 - MATE_PLY_PENALTY * f32::from(plies.saturating_sub(1))).max(MIN_MATE_VALUE)
+}
+
+/// Node stores the sums of backed up values in `[-1, 1]` range and draw
+/// probabilities, which is equivalent to the (wins, draws, losses) statistics:
+/// the value is the difference between the win and loss probabilities.
 ///
-/// For more details, ses https://lczero.org/blog/2020/04/wdl-head/
+/// For more details, see <https://lczero.org/blog/2020/04/wdl-head/>
 // TODO: Measure the performance and see if switching to ArrayVec will make it
 // faster.
-pub(super) struct Node<A: Action> {
-    children: Vec<Node<A>>,
-    actions: Vec<A>,
-    prior: f32,
+pub(super) struct Node {
+    /// The move leading to this node from its parent. [`None`] for the root.
+    pub(super) last_move: Option<Move>,
+    pub(super) children: Vec<Node>,
+    pub(super) prior: f32,
     /// Total number of search iterations that went through this node.
-    visits: u32,
-    /// Number of wins from the perspective of player to move.
-    wins: u32,
-    /// Number of losses from the perspective of player to move.
-    losses: u32,
+    pub(super) visits: u32,
+    /// Sum of values from the perspective of the player who made the
+    /// [`Node::last_move`].
+    pub(super) total_value: f32,
+    /// Sum of the draw probabilities.
+    pub(super) total_draw: f32,
+    pub(super) proof: Option<Proof>,
 }
 
-impl<A: Action> Default for Node<A> {
-    fn default() -> Self {
+impl Node {
+    #[must_use]
+    pub(super) const fn new(last_move: Option<Move>, prior: f32) -> Self {
         Self {
+            last_move,
             children: Vec::new(),
-            actions: Vec::new(),
-            prior: 0.0,
+            prior,
             visits: 0,
-            wins: 0,
-            losses: 0,
+            total_value: 0.0,
+            total_draw: 0.0,
+            proof: None,
         }
     }
-}
 
-impl<A: Action> Node<A> {
-    fn expand(&mut self) {
-        todo!()
+    /// Adds a child for each legal move with the prior probability predicted
+    /// by the policy.
+    pub(super) fn expand(&mut self, moves: &[Move], priors: &[f32]) {
+        debug_assert!(self.is_leaf());
+        debug_assert_eq!(moves.len(), priors.len());
+        self.children = moves
+            .iter()
+            .zip(priors)
+            .map(|(next_move, prior)| Self::new(Some(*next_move), *prior))
+            .collect();
+    }
+
+    pub(super) fn update(&mut self, outcome: Outcome) {
+        self.visits += 1;
+        self.total_value += outcome.value;
+        self.total_draw += outcome.draw;
     }
 
     /// Returns true if the node has been visited at least once.
     #[must_use]
-    const fn visited(&self) -> bool {
+    pub(super) const fn visited(&self) -> bool {
         self.visits > 0
     }
 
     #[must_use]
-    const fn is_leaf(&self) -> bool {
-        todo!()
+    pub(super) fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Returns the number of nodes in the subtree, including this one.
+    #[must_use]
+    pub(super) fn size(&self) -> usize {
+        1 + self.children.iter().map(Self::size).sum::<usize>()
+    }
+
+    /// Drops the [`Proof::Draw`]s in the subtree, so that they are proven again
+    /// once the nodes are visited. The repetitions depend on the path from the
+    /// root and the game history, which change when the tree is reused for
+    /// another root. The wins and losses are never derived from the draws and
+    /// stay valid.
+    pub(super) fn forget_draws(&mut self) {
+        if self.proof == Some(Proof::Draw) {
+            self.proof = None;
+        }
+        self.children.iter_mut().for_each(Self::forget_draws);
+    }
+
+    /// Average value of the node from the perspective of the player who made
+    /// the [`Node::last_move`].
+    #[must_use]
+    pub(super) fn q(&self) -> f32 {
+        if self.visited() {
+            self.total_value / self.visits as f32
+        } else {
+            0.0
+        }
     }
 
+    /// Average draw probability of the node.
     #[must_use]
-    const fn is_terminal(&self) -> bool {
-        todo!()
+    pub(super) fn draw(&self) -> f32 {
+        if self.visited() {
+            self.total_draw / self.visits as f32
+        } else {
+            0.0
+        }
     }
 
+    /// Expected outcome of the node from the perspective of the player who
+    /// made the [`Node::last_move`], exact for the proven nodes.
     #[must_use]
-    const fn draws(&self) -> u32 {
-        self.visits - self.wins - self.losses
+    pub(super) fn wdl(&self) -> Wdl {
+        match self.proof {
+            Some(Proof::Win(_)) => Wdl::new(1.0, 0.0),
+            Some(Proof::Loss(_)) => Wdl::new(-1.0, 0.0),
+            Some(Proof::Draw) => Wdl::new(0.0, 1.0),
+            None => Wdl::new(self.q(), self.draw()),
+        }
+    }
+
+    /// Propagates the proofs from the children: the node is lost for the
+    /// player who moved into it if the opponent has a winning reply, drawn if
+    /// all replies are proven and the best of them is a draw and won if all
+    /// replies are lost.
+    pub(super) fn update_proof(&mut self) {
+        let mut fastest_win = None;
+        let mut longest_loss = 0;
+        let mut all_proven = true;
+        let mut can_draw = false;
+        for child in &self.children {
+            match child.proof {
+                Some(Proof::Win(plies)) => {
+                    fastest_win =
+                        Some(fastest_win.map_or(plies, |fastest: u16| fastest.min(plies)));
+                },
+                Some(Proof::Loss(plies)) => longest_loss = longest_loss.max(plies),
+                Some(Proof::Draw) => can_draw = true,
+                None => all_proven = false,
+            }
+        }
+        if let Some(plies) = fastest_win {
+            self.proof = Some(Proof::Loss(plies + 1));
+        } else if all_proven && !self.is_leaf() {
+            self.proof = Some(if can_draw {
+                Proof::Draw
+            } else {
+                Proof::Win(longest_loss + 1)
+            });
+        }
+    }
+
+    /// Returns the child the player to move should choose: the fastest proven
+    /// win if available, the most visited unproven (or drawn) child otherwise
+    /// and the longest resistance if all moves are losing.
+    #[must_use]
+    pub(super) fn best_child(&self) -> Option<&Self> {
+        self.children.iter().max_by(|lhs, rhs| {
+            lhs.preference()
+                .cmp(&rhs.preference())
+                .then(lhs.q().total_cmp(&rhs.q()))
+        })
+    }
+
+    /// Key for choosing the best child: proven wins (faster is better), then
+    /// unproven and drawn nodes by visits and then proven losses (slower is
+    /// better).
+    fn preference(&self) -> (u8, i64) {
+        match self.proof {
+            Some(Proof::Win(plies)) => (2, -i64::from(plies)),
+            None | Some(Proof::Draw) => (1, i64::from(self.visits)),
+            Some(Proof::Loss(plies)) => (0, i64::from(plies)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::core::Square;
+
+    fn child(proof: Option<Proof>, visits: u32) -> Node {
+        let mut node = Node::new(Some(Move::new(Square::E2, Square::E4, None)), 0.5);
+        node.proof = proof;
+        node.visits = visits;
+        node
+    }
+
+    #[test]
+    fn proof_propagation() {
+        let mut node = Node::new(None, 1.0);
+        node.children = vec![child(None, 10), child(Some(Proof::Win(3)), 1)];
+        node.update_proof();
+        assert_eq!(node.proof, Some(Proof::Loss(4)));
+
+        let mut node = Node::new(None, 1.0);
+        node.children = vec![child(Some(Proof::Loss(2)), 1), child(None, 1)];
+        node.update_proof();
+        assert_eq!(node.proof, None);
+
+        node.children[1].proof = Some(Proof::Loss(4));
+        node.update_proof();
+        assert_eq!(node.proof, Some(Proof::Win(5)));
+
+        let mut node = Node::new(None, 1.0);
+        node.children = vec![child(Some(Proof::Loss(2)), 1), child(Some(Proof::Draw), 1)];
+        node.update_proof();
+        assert_eq!(node.proof, Some(Proof::Draw));
+    }
+
+    #[test]
+    fn proof_values() {
+        assert_eq!(Proof::Win(1).value(), 1.0);
+        assert_eq!(Proof::Loss(1).value(), -1.0);
+        assert_eq!(Proof::Draw.value(), 0.0);
+        // Faster mates are preferred.
+        assert!(Proof::Win(1).value() > Proof::Win(3).value());
+        assert!(Proof::Loss(2).value() < Proof::Loss(4).value());
+        assert!(Proof::Win(999).value() >= MIN_MATE_VALUE);
+    }
+
+    #[test]
+    fn wdl() {
+        let mut node = Node::new(None, 1.0);
+        node.update(Outcome {
+            value: 0.5,
+            draw: 0.2,
+        });
+        node.update(-Outcome {
+            value: 0.5,
+            draw: 0.4,
+        });
+        assert_eq!(node.q(), 0.0);
+        assert!((node.draw() - 0.3).abs() < 1e-6);
+        assert_eq!(node.wdl().per_mille(), [350, 300, 350]);
+
+        node.proof = Some(Proof::Draw);
+        assert_eq!(node.wdl().per_mille(), [0, 1000, 0]);
+        assert_eq!(Proof::Draw.outcome().draw, 1.0);
+        assert_eq!(Proof::Win(1).outcome().draw, 0.0);
+    }
+
+    #[test]
+    fn best_child() {
+        let mut node = Node::new(None, 1.0);
+        node.children = vec![
+            child(Some(Proof::Loss(2)), 100),
+            child(None, 10),
+            child(None, 20),
+        ];
+        assert_eq!(node.best_child().unwrap().visits, 20);
+        node.children.push(child(Some(Proof::Draw), 30));
+        assert_eq!(node.best_child().unwrap().proof, Some(Proof::Draw));
+        node.children.push(child(Some(Proof::Win(5)), 1));
+        node.children.push(child(Some(Proof::Win(3)), 1));
+        assert_eq!(node.best_child().unwrap().proof, Some(Proof::Win(3)));
     }
 }
@@ -5,8 +5,10 @@ use std::fs;
 
 use itertools::Itertools;
 use pabi::chess::core::Move;
-use pabi::chess::position::{perft, Position};
+use pabi::chess::position::{divide, perft, perft_pseudo_legal, Position};
 use pretty_assertions::assert_eq;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use shakmaty::Position as ShakmatyPosition;
 
 #[must_use]
@@ -282,124 +284,50 @@ fn pins() {
     );
 }
 
-// Artifacts from the fuzzer or perft.
+// Fuzzer artifacts and other tricky positions with their golden move lists.
 #[test]
-fn fuzzing_artifact_moves() {
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/3p3k/1p3pp1/1B5P/5P2/2P1pqP1/PP4KP/3R4 w - - 0 34"
-        )),
-        sorted_moves(&["g2g1", "g2f3", "g2h3"])
-    );
-    assert_eq!(
-        get_moves(&setup("K7/8/8/8/1R2Pp1k/8/8/8 b - e3 0 1")),
-        sorted_moves(&["h4h5", "h4h3", "h4g4", "h4g5", "h4g3", "f4f3"])
-    );
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/3p3k/1p3pp1/1B5P/5p2/2P1p1P1/PP4KP/3R4 w - - 0 34"
-        )),
-        sorted_moves(&[
-            "a2a3", "a2a4", "b2b3", "b2b4", "c3c4", "b5a4", "b5a6", "b5c6", "b5d7", "b5c4", "b5d3",
-            "b5e2", "b5f1", "g3g4", "h2h3", "h2h4", "h5h6", "h5g6", "g2f3", "g2f1", "g2g1", "g2h3",
-            "g2h1", "d1a1", "d1b1", "d1c1", "d1e1", "d1f1", "d1g1", "d1h1", "d1d2", "d1d3", "d1d4",
-            "d1d5", "d1d6", "d1d7", "g3f4",
-        ])
-    );
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/3p3k/1p3pp1/1B5p/5P2/2P2pP1/PP4KP/3R4 w - - 0 34"
-        )),
-        sorted_moves(&["g2f1", "g2f2", "g2f3", "g2g1", "g2h1", "g2h3"])
-    );
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/P3k3/pp3p2/1B5p/5P2/2P3pP/PP4KP/3R4 w - - 0 1"
-        )),
-        sorted_moves(&[
-            "a2a3", "a2a4", "a7a8b", "a7a8n", "a7a8q", "a7a8r", "b2b3", "b2b4", "b5a4", "b5a6",
-            "b5c4", "b5c6", "b5d3", "b5d7", "b5e2", "b5e8", "b5f1", "c3c4", "d1a1", "d1b1", "d1c1",
-            "d1d2", "d1d3", "d1d4", "d1d5", "d1d6", "d1d7", "d1d8", "d1e1", "d1f1", "d1g1", "d1h1",
-            "f4f5", "g2f1", "g2f3", "g2g1", "g2h1", "h2g3", "h3h4",
-        ])
-    );
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/p3k3/pp3p2/1B5p/5P2/2pqp1P1/PPK4P/3R4 w - - 0 34"
-        )),
-        sorted_moves(&["b5d3", "c2b3", "c2c1", "c2d3", "d1d3"])
-    );
-    assert_eq!(
-        get_moves(&setup(
-            "2r3r1/p3k3/pp3p2/1B5p/5P2/2P1p1P1/PP4Kr/3R4 w - - 0 1"
-        )),
-        sorted_moves(&["g2f1", "g2f3", "g2g1", "g2h2"])
-    );
-    assert_eq!(
-        get_moves(&setup("r3k3/r7/8/5pP1/5QKN/8/8/6RR w - f6 0 1")),
-        sorted_moves(&["f4f5", "h4f5", "g4f5", "g4f3", "g4g3", "g4h3", "g5f6", "g4h5"])
-    );
-    assert_eq!(
-        get_moves(&setup("4k1r1/8/8/4PpP1/6K1/8/8/8 w - f6 0 1")),
-        sorted_moves(&["g4f4", "g4f3", "g4f5", "g4g3", "g4h3", "g4h4", "g4h5", "e5f6"])
-    );
-    assert_eq!(
-        get_moves(&setup("8/2p5/3p4/1P5r/KR3p1k/8/4P1P1/8 b - - 1 1")),
-        sorted_moves(&[
-            "c7c6", "c7c5", "d6d5", "h5b5", "h5c5", "h5d5", "h5e5", "h5g5", "h5f5", "h5h6", "h5h7",
-            "h5h8", "h4g4", "h4g5", "h4g3"
-        ])
-    );
-}
-
-#[test]
-fn castle() {
-    // Can castle both sides.
-    assert_eq!(
-        get_moves(&setup("r3k2r/8/8/8/8/8/6N1/4K3 b kq - 0 1")),
-        sorted_moves(&[
-            "a8a7", "a8a6", "a8a5", "a8a4", "a8a3", "a8a2", "a8a1", "a8b8", "a8c8", "a8d8", "h8f8",
-            "h8g8", "h8h7", "h8h6", "h8h5", "h8h4", "h8h3", "h8h2", "h8h1", "e8e7", "e8d8", "e8d7",
-            "e8f8", "e8f7", "e8c8", "e8g8"
-        ])
-    );
-    // Castling short blocked by a check.
-    assert_eq!(
-        get_moves(&setup("r3k2r/8/8/8/8/8/6R1/4K3 b kq - 0 1")),
-        sorted_moves(&[
-            "a8a7", "a8a6", "a8a5", "a8a4", "a8a3", "a8a2", "a8a1", "a8b8", "a8c8", "a8d8", "h8f8",
-            "h8g8", "h8h7", "h8h6", "h8h5", "h8h4", "h8h3", "h8h2", "h8h1", "e8e7", "e8d8", "e8d7",
-            "e8f8", "e8f7", "e8c8"
-        ])
-    );
-    // Castling short blocked by our piece, castling long is not available.
-    assert_eq!(
-        get_moves(&setup("r3k2r/8/8/8/8/8/6R1/4K3 b k - 0 1")),
-        sorted_moves(&[
-            "a8a7", "a8a6", "a8a5", "a8a4", "a8a3", "a8a2", "a8a1", "a8b8", "a8c8", "a8d8", "h8f8",
-            "h8g8", "h8h7", "h8h6", "h8h5", "h8h4", "h8h3", "h8h2", "h8h1", "e8e7", "e8d8", "e8d7",
-            "e8f8", "e8f7"
-        ])
-    );
-    // Castling long is not blocked: the attacked square is not the one king will
-    // walk through.
-    assert_eq!(
-        get_moves(&setup("r3k2r/8/8/8/8/8/1R6/4K3 b q - 0 1")),
-        sorted_moves(&[
-            "a8a7", "a8a6", "a8a5", "a8a4", "a8a3", "a8a2", "a8a1", "a8b8", "a8c8", "a8d8", "h8f8",
-            "h8g8", "h8h7", "h8h6", "h8h5", "h8h4", "h8h3", "h8h2", "h8h1", "e8e7", "e8d8", "e8d7",
-            "e8f8", "e8f7", "e8c8"
-        ])
-    );
-    // Castling long is blocked by an attack and the king is cut off.
-    assert_eq!(
-        get_moves(&setup("r3k2r/8/8/8/8/8/3R4/4K3 b kq - 0 1")),
-        sorted_moves(&[
-            "a8a7", "a8a6", "a8a5", "a8a4", "a8a3", "a8a2", "a8a1", "a8b8", "a8c8", "a8d8", "h8f8",
-            "h8g8", "h8h7", "h8h6", "h8h5", "h8h4", "h8h3", "h8h2", "h8h1", "e8e7", "e8f8", "e8f7",
-            "e8g8"
-        ])
-    );
+fn legality_corpus() {
+    let corpus = fs::read_to_string(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/data/legality.txt"
+    ))
+    .unwrap();
+    let mut description = "";
+    let mut cases = 0;
+    for line in corpus.lines() {
+        if let Some(comment) = line.strip_prefix("##") {
+            description = comment.trim();
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (fen, moves) = line
+            .split_once(';')
+            .unwrap_or_else(|| panic!("case should have a move list: {line}"));
+        let expected = sorted_moves(&moves.split_whitespace().collect::<Vec<_>>());
+        assert_eq!(
+            get_moves(&setup(fen)),
+            expected,
+            "{description}\nposition: {fen}"
+        );
+        let shakmaty_position: shakmaty::Chess = fen
+            .parse::<shakmaty::fen::Fen>()
+            .unwrap()
+            .into_position(shakmaty::CastlingMode::Standard)
+            .unwrap();
+        assert_eq!(
+            shakmaty::Position::legal_moves(&shakmaty_position)
+                .iter()
+                .map(|m| m.to_uci(shakmaty::CastlingMode::Standard).to_string())
+                .sorted()
+                .collect::<Vec<_>>(),
+            expected,
+            "golden move list should be correct: {description}\nposition: {fen}"
+        );
+        cases += 1;
+    }
+    assert!(cases > 0);
 }
 
 #[test]
@@ -476,7 +404,7 @@ fn make_moves() {
 
     assert_eq!(
         position.to_string(),
-        "rnbqkbnr/pp4pp/2p1p3/3p1p2/PP5P/2P5/1B1PPPP1/RN1QKBNR b KQkq - 0 5"
+        "rnbqkbnr/pp4pp/2p1p3/3p1p2/PP5P/2P5/1B1PPPP1/RN1QKBNR b KQkq h3 0 5"
     );
 
     position.make_move(&Move::from_uci("g7g6").unwrap());
@@ -573,12 +501,12 @@ fn basic_moves() {
     position.make_move(&Move::from_uci("e2e4").expect("valid move"));
     assert_eq!(
         position.to_string(),
-        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
     );
     position.make_move(&Move::from_uci("e7e5").expect("valid move"));
     assert_eq!(
         position.to_string(),
-        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq - 0 2"
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"
     );
     position.make_move(&Move::from_uci("g1f3").expect("valid move"));
     assert_eq!(
@@ -602,6 +530,62 @@ fn promotion_moves() {
     );
 }
 
+#[test]
+fn under_promotions() {
+    for (promotion, expected) in [
+        ("q", "2Q4k/1P6/6K1/8/8/8/8/8 b - - 0 1"),
+        ("r", "2R4k/1P6/6K1/8/8/8/8/8 b - - 0 1"),
+        ("b", "2B4k/1P6/6K1/8/8/8/8/8 b - - 0 1"),
+        ("n", "2N4k/1P6/6K1/8/8/8/8/8 b - - 0 1"),
+    ] {
+        let mut position = setup("7k/1PP5/6K1/8/8/8/8/8 w - - 3 1");
+        let next_move = format!("c7c8{promotion}");
+        assert!(get_moves(&position).contains(&next_move));
+        position.make_move(&Move::from_uci(&next_move).expect("valid move"));
+        assert_eq!(position.to_string(), expected);
+    }
+    let mut position = setup("8/8/8/8/8/6k1/3p4/K7 b - - 0 1");
+    position.make_move(&Move::from_uci("d2d1n").expect("valid move"));
+    assert_eq!(position.to_string(), "8/8/8/8/8/6k1/8/K2n4 w - - 0 2");
+}
+
+#[test]
+fn capture_promotions() {
+    let mut position = setup("1rn4k/2P5/6K1/8/8/8/8/8 w - - 0 1");
+    assert_eq!(
+        get_moves(&position)
+            .into_iter()
+            .filter(|next_move| next_move.starts_with("c7"))
+            .collect::<Vec<_>>(),
+        sorted_moves(&["c7b8q", "c7b8r", "c7b8b", "c7b8n"])
+    );
+    position.make_move(&Move::from_uci("c7b8r").expect("valid move"));
+    assert_eq!(position.to_string(), "1Rn4k/8/6K1/8/8/8/8/8 b - - 0 1");
+
+    let mut position = setup("8/8/8/8/8/k7/1p6/R1N4K b - - 5 30");
+    position.make_move(&Move::from_uci("b2c1q").expect("valid move"));
+    assert_eq!(position.to_string(), "8/8/8/8/8/k7/8/R1q4K w - - 0 31");
+}
+
+#[test]
+fn pinned_promotions() {
+    // The pawn is pinned along the diagonal and can only promote by capturing
+    // the pinning bishop.
+    let position = setup("2b4k/1P6/K7/8/8/8/8/8 w - - 0 1");
+    assert_eq!(
+        get_moves(&position)
+            .into_iter()
+            .filter(|next_move| next_move.starts_with("b7"))
+            .collect::<Vec<_>>(),
+        sorted_moves(&["b7c8q", "b7c8r", "b7c8b", "b7c8n"])
+    );
+    // Pinned along the file: no promotions at all.
+    let position = setup("1rn5/1P6/8/8/8/8/8/1K5k w - - 0 1");
+    assert!(get_moves(&position)
+        .iter()
+        .all(|next_move| !next_move.starts_with("b7")));
+}
+
 #[test]
 fn castling_reset() {
     let mut position = setup("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1");
@@ -730,6 +714,66 @@ fn perft_complex_middlegame() {
     assert_eq!(perft(&position, 4), 1_352_097);
 }
 
+// En passant traps: discovered checks along the rank and the diagonal after
+// both pawns leave their squares, pinned capturers moving along the pin and en
+// passant captures resolving a check.
+#[test]
+fn perft_en_passant() {
+    // Capturing en passant exposes the king along the rank.
+    let position = setup("3k4/3p4/8/K1P4r/8/8/8/8 b - - 0 1");
+    assert_eq!(perft(&position, 5), 185_429);
+    let position = setup("8/8/4k3/8/2p5/8/B2P2K1/8 w - - 0 1");
+    assert_eq!(perft(&position, 5), 135_655);
+    // Capturing en passant discovers a check to the opponent.
+    let position = setup("8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1");
+    assert_eq!(perft(&position, 5), 206_379);
+}
+
+#[test]
+#[ignore]
+fn perft_en_passant_expensive() {
+    let position = setup("3k4/3p4/8/K1P4r/8/8/8/8 b - - 0 1");
+    assert_eq!(perft(&position, 6), 1_134_888);
+    let position = setup("8/8/4k3/8/2p5/8/B2P2K1/8 w - - 0 1");
+    assert_eq!(perft(&position, 6), 1_015_133);
+    let position = setup("8/8/1k6/2b5/2pP4/8/5K2/8 b - d3 0 1");
+    assert_eq!(perft(&position, 6), 1_440_467);
+}
+
+#[test]
+fn en_passant_pins() {
+    // The capturer is pinned along the rank together with the captured pawn.
+    assert_eq!(
+        get_moves(&setup("8/8/8/KPp4r/8/8/8/6k1 w - c6 0 1")),
+        sorted_moves(&["a5a4", "a5a6", "a5b6", "b5b6"])
+    );
+    // The capturer is pinned along the diagonal and captures along the pin.
+    assert!(get_moves(&setup("8/8/8/1k6/2pP4/8/8/5B1K b - d3 0 1")).contains(&"c4d3".to_string()));
+    // The captured pawn gives check and capturing it is the only pawn move.
+    assert!(get_moves(&setup("8/8/8/2k5/3Pp3/8/8/7K b - d3 0 1")).contains(&"e4d3".to_string()));
+}
+
+#[test]
+fn perft_divide() {
+    let position = setup("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1");
+    let result = divide(&position, 2);
+    assert_eq!(result.nodes, 2039);
+    assert_eq!(result.moves.len(), 48);
+    for (next_move, nodes) in &result.moves {
+        let mut next_position = position.clone();
+        next_position.make_move(next_move);
+        assert_eq!(*nodes, perft(&next_position, 1));
+    }
+    let castle = result
+        .moves
+        .iter()
+        .find(|(next_move, _)| next_move.to_string() == "e1g1")
+        .expect("castling is legal");
+    assert_eq!(castle.1, 43);
+    assert_eq!(divide(&position, 0).nodes, 1);
+    assert!(divide(&position, 0).moves.is_empty());
+}
+
 #[test]
 fn perft_endgame_promotions() {
     let position = setup("8/Pk6/8/8/8/8/6KP/8 w - - 0 1");
@@ -856,3 +900,276 @@ fn move_clock_hash() {
         setup("6qk/8/8/3Pp3/8/8/K7/8 w - - 2 3").hash()
     );
 }
+
+#[test]
+fn augmentation() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "n1n5/PPPk4/8/8/8/8/4Kppp/5N1N b - - 0 1",
+    ] {
+        let position = setup(fen);
+        let flipped = position.flip_colors();
+        assert_eq!(flipped.flip_colors().to_string(), position.to_string());
+        assert_eq!(perft(&flipped, 3), perft(&position, 3), "{flipped}");
+        assert_eq!(
+            pabi::evaluation::evaluate(&flipped),
+            pabi::evaluation::evaluate(&position)
+        );
+
+        // Only positions without castling rights and en passant square can be
+        // mirrored.
+        assert_eq!(
+            position.mirror_horizontally().is_some(),
+            fen.contains(" - - ")
+        );
+        if let Some(mirrored) = position.mirror_horizontally() {
+            assert_eq!(
+                mirrored.mirror_horizontally().unwrap().to_string(),
+                position.to_string()
+            );
+            assert_eq!(perft(&mirrored, 3), perft(&position, 3), "{mirrored}");
+        }
+    }
+}
+
+#[test]
+fn canonicalization() {
+    for (fen, canonical) in [
+        // Black to move is flipped.
+        (
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+            "rnbqkbnr/pppp1ppp/8/4p3/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ),
+        // En passant capture is possible.
+        (
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 1",
+        ),
+        // Castling rights without the rook are dropped.
+        (
+            "r3k3/8/8/8/8/8/8/4K2R w Kkq - 5 20",
+            "r3k3/8/8/8/8/8/8/4K2R w Kq - 0 1",
+        ),
+    ] {
+        let position = setup(fen).canonical();
+        assert_eq!(position.to_string(), canonical);
+        assert_eq!(position.canonical().to_string(), canonical);
+    }
+    // All symmetric versions share the canonical form.
+    let position = setup("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 b - - 3 40");
+    let canonical = position.canonical_hash();
+    for equivalent in pabi::datagen::augment(&position) {
+        assert_eq!(equivalent.canonical_hash(), canonical, "{equivalent}");
+        assert_eq!(
+            equivalent.canonical().to_string(),
+            position.canonical().to_string()
+        );
+    }
+}
+
+#[test]
+fn perft_pseudo_legal_matches() {
+    for (fen, nodes) in [
+        (
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            400,
+        ),
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            2039,
+        ),
+        ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 191),
+        (
+            "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+            264,
+        ),
+        (
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+            1486,
+        ),
+    ] {
+        assert_eq!(perft_pseudo_legal(&setup(fen), 2), nodes, "{fen}");
+    }
+    // Pawn checks on the backrank.
+    let position = setup("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q2/PPPBBPpP/R4K1R w kq - 0 2");
+    assert_eq!(perft_pseudo_legal(&position, 1), 4);
+    assert_eq!(perft_pseudo_legal(&position, 3), perft(&position, 3));
+}
+
+#[test]
+fn make_move_checked() {
+    // The knight is pinned, f2 is attacked by the pawn and castling long passes
+    // through c1 attacked by the rook.
+    let fen = "2r1k3/8/8/b7/8/6p1/3N4/R3K2R w KQ - 0 1";
+    let mut position = setup(fen);
+    let pseudo_legal = position.generate_pseudo_legal_moves();
+    for uci in ["d2f3", "e1f2", "e1c1"] {
+        let next_move = Move::from_uci(uci).expect("valid move");
+        assert!(pseudo_legal.contains(&next_move));
+        assert!(!position.generate_moves().contains(&next_move));
+        assert!(!position.make_move_checked(&next_move), "{uci}");
+        assert_eq!(position.to_string(), fen);
+    }
+    assert!(position.make_move_checked(&Move::from_uci("e1g1").expect("valid move")));
+    assert_eq!(
+        position.to_string(),
+        "2r1k3/8/8/b7/8/6p1/3N4/R4RK1 b - - 1 1"
+    );
+}
+
+/// Returns the Chess960 starting position with the given number, see
+/// https://en.wikipedia.org/wiki/Fischer_random_chess_numbering_scheme. The
+/// standard starting position is 518.
+fn chess960_position(mut number: usize) -> String {
+    // Places the piece on the empty square with the given index.
+    fn place(backrank: &mut [Option<char>; 8], index: usize, piece: char) {
+        let (square, _) = backrank
+            .iter()
+            .enumerate()
+            .filter(|(_, piece)| piece.is_none())
+            .nth(index)
+            .unwrap();
+        backrank[square] = Some(piece);
+    }
+
+    let mut backrank = [None; 8];
+    backrank[2 * (number % 4) + 1] = Some('b');
+    number /= 4;
+    backrank[2 * (number % 4)] = Some('b');
+    number /= 4;
+    place(&mut backrank, number % 6, 'q');
+    number /= 6;
+    let (first, second) = [
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (1, 2),
+        (1, 3),
+        (1, 4),
+        (2, 3),
+        (2, 4),
+        (3, 4),
+    ][number];
+    place(&mut backrank, second, 'n');
+    place(&mut backrank, first, 'n');
+    for piece in ['r', 'k', 'r'] {
+        place(&mut backrank, 0, piece);
+    }
+    let black: String = backrank.iter().map(|piece| piece.unwrap()).collect();
+    format!(
+        "{black}/pppppppp/8/8/8/8/PPPPPPPP/{} w KQkq - 0 1",
+        black.to_ascii_uppercase()
+    )
+}
+
+fn chess960_moves(position: &shakmaty::Chess) -> Vec<String> {
+    position
+        .legal_moves()
+        .iter()
+        .map(|m| m.to_uci(shakmaty::CastlingMode::Chess960).to_string())
+        .sorted()
+        .collect()
+}
+
+// Random games from all Chess960 starting positions, castling whenever
+// possible.
+#[test]
+fn chess960_random_games() {
+    assert_eq!(chess960_position(518), Position::starting().to_string());
+    let mut rng = StdRng::seed_from_u64(960);
+    for number in 0..960 {
+        let fen = chess960_position(number);
+        let mut position = setup(&fen);
+        let mut shakmaty_position: shakmaty::Chess = fen
+            .parse::<shakmaty::fen::Fen>()
+            .unwrap()
+            .into_position(shakmaty::CastlingMode::Chess960)
+            .unwrap();
+        for _ in 0..40 {
+            let expected = chess960_moves(&shakmaty_position);
+            let moves: Vec<String> = position
+                .generate_moves()
+                .iter()
+                .map(|next_move| position.to_uci(next_move, true))
+                .sorted()
+                .collect();
+            assert_eq!(moves, expected, "position: {position}");
+            // The FEN keeps the castling rooks.
+            let reparsed: shakmaty::Chess = position
+                .to_string()
+                .parse::<shakmaty::fen::Fen>()
+                .unwrap()
+                .into_position(shakmaty::CastlingMode::Chess960)
+                .unwrap();
+            assert_eq!(chess960_moves(&reparsed), expected, "position: {position}");
+            let legal_moves = shakmaty_position.legal_moves();
+            if legal_moves.is_empty() {
+                break;
+            }
+            let next_move = legal_moves
+                .iter()
+                .find(|m| m.is_castle())
+                .unwrap_or(&legal_moves[rng.gen_range(0..legal_moves.len())])
+                .clone();
+            let uci = next_move
+                .to_uci(shakmaty::CastlingMode::Chess960)
+                .to_string();
+            let parsed = position.parse_uci(&uci, true).unwrap();
+            position.make_move(&parsed);
+            shakmaty_position.play_unchecked(&next_move);
+        }
+    }
+}
+
+// Position 1 from https://www.chessprogramming.org/Chess960_Perft_Results
+#[test]
+fn perft_chess960() {
+    let position = setup("bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9");
+    assert_eq!(perft(&position, 1), 21);
+    assert_eq!(perft(&position, 2), 528);
+    assert_eq!(perft(&position, 3), 12189);
+    assert_eq!(perft(&position, 4), 326_672);
+}
+
+fn play_random_moves(position: &mut Position, plies: usize, rng: &mut StdRng) {
+    for _ in 0..plies {
+        let moves = position.generate_moves();
+        if moves.is_empty() {
+            *position = Position::starting();
+            continue;
+        }
+        position.make_move(&moves[rng.gen_range(0..moves.len())]);
+    }
+}
+
+#[test]
+fn extremely_long_games() {
+    let mut rng = StdRng::seed_from_u64(42);
+    // Random games from the starting position, restarting after the game is
+    // over.
+    let mut position = Position::starting();
+    play_random_moves(&mut position, 100_000, &mut rng);
+    assert!(
+        Position::from_fen(&position.to_string()).is_ok(),
+        "{position}"
+    );
+
+    // Self-play shuffles without the draw adjudication: both counters
+    // saturate instead of overflowing and the position stays valid.
+    let mut position = Position::from_fen("8/8/4k3/8/8/3K4/8/8 w - - 0 1").unwrap();
+    play_random_moves(&mut position, 200_000, &mut rng);
+    assert_eq!(position.halfmove_clock(), u8::MAX);
+    assert_eq!(position.fullmove_counter(), u16::MAX);
+    assert!(position.halfmove_clock_expired());
+    assert_eq!(
+        Position::from_fen(&position.to_string())
+            .unwrap()
+            .to_string(),
+        position.to_string()
+    );
+}
@@ -0,0 +1,256 @@
+//! Drives the engine binary the way chess GUIs and tournament managers (e.g.
+//! cutechess-cli) do: commands are sent interactively and the responses are
+//! checked for protocol correctness and timing while the engine keeps running.
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use pabi::chess::core::Move;
+use pabi::chess::position::Position;
+
+/// Generous bound for the responses that should be immediate, the tests run
+/// unoptimized binaries on busy machines.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Mock GUI connected to a running engine process.
+struct Gui {
+    engine: Child,
+    input: Option<ChildStdin>,
+    output: Receiver<String>,
+}
+
+impl Gui {
+    fn start() -> Self {
+        let mut engine = Command::new(assert_cmd::cargo::cargo_bin("pabi"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("engine should start");
+        let stdout = engine.stdout.take().expect("stdout is piped");
+        let (sender, output) = mpsc::channel();
+        let _ = thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+        Self {
+            input: engine.stdin.take(),
+            engine,
+            output,
+        }
+    }
+
+    fn send(&mut self, command: &str) {
+        let input = self.input.as_mut().expect("input is open");
+        writeln!(input, "{command}").expect("engine should accept input");
+        input.flush().expect("engine should accept input");
+    }
+
+    /// Reads the responses until the line starting with `prefix` is received
+    /// and returns it with all the lines before it.
+    fn expect(&mut self, prefix: &str, timeout: Duration) -> Vec<String> {
+        let deadline = Instant::now() + timeout;
+        let mut lines = Vec::new();
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.output.recv_timeout(remaining) {
+                Ok(line) => {
+                    let found = line.starts_with(prefix);
+                    lines.push(line);
+                    if found {
+                        return lines;
+                    }
+                },
+                Err(RecvTimeoutError::Timeout) => {
+                    panic!("no {prefix:?} within {timeout:?}, received: {lines:#?}")
+                },
+                Err(RecvTimeoutError::Disconnected) => {
+                    panic!("engine exited before {prefix:?}, received: {lines:#?}")
+                },
+            }
+        }
+    }
+
+    /// Checks that nothing is sent for a while.
+    fn expect_silence(&mut self, duration: Duration) {
+        if let Ok(line) = self.output.recv_timeout(duration) {
+            panic!("unexpected response: {line}");
+        }
+    }
+
+    fn handshake(&mut self) -> Vec<String> {
+        self.send("uci");
+        let lines = self.expect("uciok", RESPONSE_TIMEOUT);
+        self.sync();
+        lines
+    }
+
+    fn sync(&mut self) {
+        self.send("isready");
+        let _ = self.expect("readyok", RESPONSE_TIMEOUT);
+    }
+
+    /// Waits for the best move and checks that it is legal in the position.
+    fn bestmove(&mut self, position: &Position, timeout: Duration) -> Move {
+        let lines = self.expect("bestmove", timeout);
+        for line in &lines[..lines.len() - 1] {
+            assert!(line.starts_with("info "), "unexpected response: {line}");
+        }
+        let tokens: Vec<&str> = lines[lines.len() - 1].split_whitespace().collect();
+        assert!(
+            tokens.len() == 2 || (tokens.len() == 4 && tokens[2] == "ponder"),
+            "malformed best move: {tokens:?}"
+        );
+        position
+            .generate_moves()
+            .iter()
+            .find(|next_move| next_move.to_string() == tokens[1])
+            .copied()
+            .unwrap_or_else(|| panic!("illegal best move {} in {position}", tokens[1]))
+    }
+
+    /// Closes the session and checks that the engine exits in time.
+    fn quit(mut self) {
+        self.send("quit");
+        drop(self.input.take());
+        let deadline = Instant::now() + RESPONSE_TIMEOUT;
+        loop {
+            if let Some(status) = self.engine.try_wait().expect("engine should be running") {
+                assert!(status.success(), "engine failed: {status}");
+                return;
+            }
+            assert!(Instant::now() < deadline, "engine did not quit");
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+impl Drop for Gui {
+    fn drop(&mut self) {
+        let _ = self.engine.kill();
+        let _ = self.engine.wait();
+    }
+}
+
+#[test]
+fn handshake() {
+    let mut gui = Gui::start();
+    let lines = gui.handshake();
+    let id_index = lines
+        .iter()
+        .position(|line| line.starts_with("id name "))
+        .expect("engine should identify itself");
+    // After identification only the protocol responses are sent.
+    for line in &lines[id_index + 1..lines.len() - 1] {
+        assert!(
+            line.starts_with("id author ") || line.starts_with("option name "),
+            "unexpected response: {line}"
+        );
+        if let Some(option) = line.strip_prefix("option name ") {
+            let kind = option
+                .split(" type ")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next());
+            assert!(
+                matches!(kind, Some("check" | "spin" | "combo" | "button" | "string")),
+                "malformed option: {line}"
+            );
+        }
+    }
+    gui.quit();
+}
+
+#[test]
+fn game() {
+    let mut gui = Gui::start();
+    let _ = gui.handshake();
+    gui.send("setoption name MoveOverhead value 20");
+    gui.send("ucinewgame");
+    gui.sync();
+
+    // The engine plays both sides with a shrinking clock, the GUI sends the
+    // whole game in each position command.
+    let time_left = Duration::from_millis(2000);
+    let mut position = Position::starting();
+    let mut moves: Vec<String> = Vec::new();
+    for _ in 0..8 {
+        if position.generate_moves().is_empty() {
+            break;
+        }
+        if moves.is_empty() {
+            gui.send("position startpos");
+        } else {
+            gui.send(&format!("position startpos moves {}", moves.join(" ")));
+        }
+        let millis = time_left.as_millis();
+        gui.send(&format!("go wtime {millis} btime {millis} winc 10 binc 10"));
+        let started = Instant::now();
+        let best_move = gui.bestmove(&position, time_left + RESPONSE_TIMEOUT);
+        assert!(
+            started.elapsed() < time_left,
+            "the engine spent {:?} out of {time_left:?}",
+            started.elapsed()
+        );
+        position.make_move(&best_move);
+        moves.push(best_move.to_string());
+    }
+    gui.sync();
+    gui.quit();
+}
+
+#[test]
+fn stop_cycles() {
+    let mut gui = Gui::start();
+    let _ = gui.handshake();
+    let position = Position::starting();
+    for delay in [0, 1, 10, 50] {
+        gui.send("position startpos");
+        gui.send("go infinite");
+        thread::sleep(Duration::from_millis(delay));
+        gui.send("stop");
+        let _ = gui.bestmove(&position, RESPONSE_TIMEOUT);
+        // Exactly one best move per search.
+        gui.expect_silence(Duration::from_millis(100));
+    }
+    gui.sync();
+    gui.quit();
+}
+
+#[test]
+fn isready_during_search() {
+    let mut gui = Gui::start();
+    let _ = gui.handshake();
+    gui.send("position startpos moves e2e4");
+    gui.send("go infinite");
+    thread::sleep(Duration::from_millis(50));
+    let started = Instant::now();
+    gui.send("isready");
+    let lines = gui.expect("readyok", RESPONSE_TIMEOUT);
+    assert!(started.elapsed() < Duration::from_secs(1));
+    // The search keeps going until it is stopped.
+    assert!(
+        !lines.iter().any(|line| line.starts_with("bestmove")),
+        "{lines:#?}"
+    );
+    gui.send("stop");
+    let mut position = Position::starting();
+    position.make_move(&Move::from_uci("e2e4").unwrap());
+    let _ = gui.bestmove(&position, RESPONSE_TIMEOUT);
+    gui.quit();
+}
+
+#[test]
+fn quit_during_search() {
+    let mut gui = Gui::start();
+    let _ = gui.handshake();
+    gui.send("position startpos");
+    gui.send("go infinite");
+    thread::sleep(Duration::from_millis(50));
+    gui.quit();
+}
@@ -1,6 +1,8 @@
+use std::time::Duration;
+
 use assert_cmd::Command;
 use predicates::boolean::PredicateBooleanExt;
-use predicates::str::contains;
+use predicates::str::{contains, is_match};
 
 const BINARY_NAME: &str = "pabi";
 
@@ -15,20 +17,337 @@ fn uci_setup() {
             .stdout(
                 contains("id name")
                     .and(contains("id author"))
-                    .and(contains("uciok")),
+                    .and(contains("uciok\ninfo string Build: "))
+                    .and(contains(" build, commit ")),
+            ),
+    );
+}
+
+#[test]
+fn tablebase_detection() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    let tablebase = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/syzygy");
+
+    drop(
+        cmd.env("SYZYGY_PATH", format!("/nonexistent:{tablebase}"))
+            .write_stdin("uci\nposition fen 8/8/8/8/8/1k6/8/K6Q w - - 0 1\ngo nodes 10\nquit\n")
+            .assert()
+            .success()
+            .stdout(
+                contains(format!(
+                    "\ninfo string Found tablebases with up to 3 pieces in {tablebase}\n"
+                ))
+                .and(contains("info string tablebase hit: Win")),
+            ),
+    );
+}
+
+#[test]
+fn quit_during_search() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    drop(
+        cmd.write_stdin("position startpos\ngo infinite\nquit\n")
+            .timeout(Duration::from_secs(10))
+            .assert()
+            .success()
+            .stdout(contains("bestmove")),
+    );
+}
+
+#[test]
+fn rapid_go_stop() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    // Stopped searches, searches interrupted by the next `go`, searches
+    // finishing on their own and redundant `stop` commands.
+    let cycles = [
+        "go infinite\nstop\n",
+        "go infinite\n",
+        "go nodes 1\nstop\nstop\n",
+        "position startpos moves e2e4\ngo ponder\nponderhit\nstop\n",
+        "go depth 1\n",
+        "stop\n",
+    ];
+    let mut input = String::from("uci\n");
+    let mut searches = 0;
+    for index in 0..300 {
+        let cycle = cycles[index % cycles.len()];
+        searches += cycle.matches("go ").count();
+        input.push_str(cycle);
+    }
+    input.push_str("isready\nquit\n");
+
+    let output = cmd
+        .write_stdin(input)
+        .timeout(Duration::from_secs(120))
+        .output()
+        .expect("engine should run");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).expect("valid UTF-8");
+    let best_moves: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("bestmove"))
+        .collect();
+    // Exactly one best move per search, never the null move.
+    assert_eq!(best_moves.len(), searches, "{stdout}");
+    assert!(
+        best_moves.iter().all(|line| !line.contains("0000")),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("did not stop"), "{stdout}");
+}
+
+/// Measures the time from sending `go movetime` to receiving the best move
+/// over a number of searches and checks that the search does not overshoot.
+#[test]
+fn movetime_latency() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::process::Stdio;
+    use std::time::Instant;
+
+    const MOVETIME: Duration = Duration::from_millis(100);
+    const SEARCHES: usize = 20;
+    let positions = [
+        "startpos",
+        "fen r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4",
+        "fen 8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    ];
+
+    let mut child = std::process::Command::new(env!("CARGO_BIN_EXE_pabi"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Binary should be built");
+    let mut stdin = child.stdin.take().unwrap();
+    let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+    let mut wait_for = |prefix: &str| loop {
+        let line = lines.next().expect("engine exited").unwrap();
+        if line.starts_with(prefix) {
+            break;
+        }
+    };
+    writeln!(stdin, "uci\nisready").unwrap();
+    wait_for("readyok");
+
+    let mut latencies = Vec::with_capacity(SEARCHES);
+    for index in 0..SEARCHES {
+        writeln!(
+            stdin,
+            "position {}\nisready",
+            positions[index % positions.len()]
+        )
+        .unwrap();
+        wait_for("readyok");
+        let start = Instant::now();
+        writeln!(stdin, "go movetime {}", MOVETIME.as_millis()).unwrap();
+        wait_for("bestmove");
+        latencies.push(start.elapsed());
+    }
+    writeln!(stdin, "quit").unwrap();
+    assert!(child.wait().unwrap().success());
+
+    latencies.sort();
+    let median = latencies[SEARCHES / 2];
+    let max = latencies[SEARCHES - 1];
+    // Generous margins for the debug builds on loaded CI machines (release
+    // builds overshoot by about a millisecond). Mates are proven early.
+    assert!(
+        median < MOVETIME + Duration::from_millis(20),
+        "{latencies:?}"
+    );
+    assert!(max < MOVETIME + Duration::from_millis(100), "{latencies:?}");
+}
+
+#[test]
+fn eval_command() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    drop(
+        cmd.args([
+            "eval",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        ])
+        .assert()
+        .success()
+        .stdout(is_match(r"^-?\d+\n$").unwrap()),
+    );
+}
+
+#[test]
+fn bestmove_command() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    drop(
+        cmd.args([
+            "bestmove",
+            "k7/8/1K6/8/8/8/8/7R w - - 0 1",
+            "--nodes",
+            "1000",
+        ])
+        .assert()
+        .success()
+        .stdout("h1h8 mate 1\n"),
+    );
+}
+
+#[test]
+fn perft_command() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    drop(
+        cmd.args([
+            "perft",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "2",
+        ])
+        .assert()
+        .success()
+        .stdout(
+            contains("e2e4: 20\n")
+                .and(contains("g1f3: 20\n"))
+                .and(contains("\nNodes searched: 400\n")),
+        ),
+    );
+
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    let output = cmd
+        .args(["perft", "8/8/8/8/8/4k3/6Q1/6K1 w - - 0 1", "2", "--json"])
+        .output()
+        .expect("perft should run");
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("output should be valid JSON");
+    assert_eq!(json["depth"], 2);
+    assert_eq!(json["nodes"], 97);
+    assert_eq!(json["moves"].as_array().map(Vec::len), Some(25));
+    assert!(json["time_ms"].is_u64());
+}
+
+#[test]
+fn analyze_command() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    // SAN and UCI moves can be mixed.
+    drop(
+        cmd.args(["analyze", "--moves", "e4 e7e5 Nf3", "--nodes", "50"])
+            .assert()
+            .success()
+            .stdout(
+                contains("6 . . . . . . . .\n")
+                    .and(contains("  a b c d e f g h\n"))
+                    .and(contains(
+                        "FEN: rnbqkbnr/pppp1ppp/8/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R b KQkq - 1 2\n",
+                    ))
+                    .and(is_match(r"\nPV: 2\.\.\. \S+").unwrap())
+                    .and(contains("Nodes: 50\n")),
             ),
     );
+
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(
+        cmd.args([
+            "analyze",
+            "--fen",
+            "k7/8/1K6/8/8/8/8/7R w - - 0 1",
+            "--nodes",
+            "100",
+        ])
+        .assert()
+        .success()
+        .stdout(contains("Best move: Rh8# (h1h8)\n").and(contains("Score: mate 1\n"))),
+    );
+
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(cmd.args(["analyze", "--moves", "e4 e4"]).assert().failure());
 }
 
-// #[test]
-// #[ignore]
-// fn openbench_output() {
-//     let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be
-// built");     let _ = cmd.arg("bench");
+#[test]
+fn annotate_command() {
+    let pgn = std::env::temp_dir().join(format!("pabi-annotate-{}.pgn", std::process::id()));
+    std::fs::write(
+        &pgn,
+        "[White \"A\"]\n\n1. e4 e5 2. Nf3 Qg5 1-0\n\n[White \"B\"]\n\n1. d4 *\n",
+    )
+    .unwrap();
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(
+        cmd.args(["annotate", "--nodes", "200"])
+            .arg(&pgn)
+            .assert()
+            .success()
+            .stdout(
+                contains("[White \"A\"]\n[Annotator \"pabi\"]\n\n")
+                    .and(contains(" Qg5?? {"))
+                    .and(contains("1-0\n\n[White \"B\"]")),
+            ),
+    );
+    std::fs::remove_file(&pgn).unwrap();
+
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(cmd.args(["annotate", "missing.pgn"]).assert().failure());
+}
 
-//     drop(
-//         cmd.assert()
-//             .stdout(is_match(r"^\d+ nodes \d+ nps$").unwrap())
-//             .success(),
-//     );
-// }
+#[test]
+fn config_file() {
+    let config = std::env::temp_dir().join(format!("pabi-config-{}.toml", std::process::id()));
+    std::fs::write(&config, "[options]\nMoveOverhead = 250\n").unwrap();
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(
+        cmd.arg("--config")
+            .arg(&config)
+            .write_stdin("uci\nquit\n")
+            .assert()
+            .success()
+            .stdout(contains("option name MoveOverhead type spin default 250 ")),
+    );
+
+    std::fs::write(&config, "[options]\nMoveOverhed = 250\n").unwrap();
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    drop(
+        cmd.arg("--config")
+            .arg(&config)
+            .write_stdin("uci\nquit\n")
+            .assert()
+            .failure()
+            .stderr(contains("unknown option \"MoveOverhed\"")),
+    );
+    std::fs::remove_file(&config).unwrap();
+}
+
+#[test]
+fn invalid_position() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+
+    drop(cmd.args(["eval", "not a position"]).assert().failure());
+}
+
+#[test]
+#[ignore]
+fn openbench_output() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    let _ = cmd.arg("bench");
+
+    drop(
+        cmd.assert()
+            .stdout(is_match(r"^\d+ nodes \d+ nps\n$").unwrap())
+            .success(),
+    );
+}
+
+#[test]
+fn speedtest_output() {
+    let mut cmd = Command::cargo_bin(BINARY_NAME).expect("Binary should be built");
+    let _ = cmd.args(["speedtest", "--millis", "10"]);
+
+    drop(
+        cmd.assert()
+            .stdout(
+                is_match(r"movegen: +\d+ positions/s\n")
+                    .unwrap()
+                    .and(is_match(r"make_move: +\d+ moves/s\n").unwrap())
+                    .and(is_match(r"evaluation: +\d+ positions/s\n").unwrap())
+                    .and(is_match(r"search: +\d+ nodes/s\n").unwrap()),
+            )
+            .success(),
+    );
+}
@@ -0,0 +1,51 @@
+// Serialization is only available with the `serde` feature: run these tests
+// with `cargo test --features serde`.
+#![cfg(feature = "serde")]
+
+use pabi::chess::core::Move;
+use pabi::chess::position::Position;
+use pabi::environment::GameResult;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn position_round_trip() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "8/8/8/8/8/2k5/1P6/K7 b - - 17 42",
+    ] {
+        let position = Position::from_fen(fen).unwrap();
+        let json = serde_json::to_string(&position).unwrap();
+        assert_eq!(json, format!("\"{fen}\""));
+        let parsed: Position = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_string(), fen);
+        assert_eq!(parsed.hash(), position.hash());
+    }
+    assert!(serde_json::from_str::<Position>("\"8/8/8/8/8/8/8/8 w - - 0 1\"").is_err());
+    assert!(serde_json::from_str::<Position>("42").is_err());
+}
+
+#[test]
+fn move_round_trip() {
+    for uci in ["e2e4", "e7e8q", "a2b1n", "0000"] {
+        let next_move = Move::from_uci(uci).unwrap();
+        let json = serde_json::to_string(&next_move).unwrap();
+        assert_eq!(json, format!("\"{uci}\""));
+        assert_eq!(serde_json::from_str::<Move>(&json).unwrap(), next_move);
+    }
+    let moves: Vec<Move> = serde_json::from_str(r#"["g1f3", "g8f6"]"#).unwrap();
+    assert_eq!(moves.len(), 2);
+    assert!(serde_json::from_str::<Move>("\"e2\"").is_err());
+}
+
+#[test]
+fn game_result_round_trip() {
+    for result in [GameResult::Win, GameResult::Draw, GameResult::Loss] {
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(serde_json::from_str::<GameResult>(&json).unwrap(), result);
+    }
+    assert_eq!(
+        serde_json::to_string(&GameResult::Draw).unwrap(),
+        "\"Draw\""
+    );
+}